@@ -1,7 +1,7 @@
 fn main() {
     if cfg!(target_os = "windows") {
         let mut res = winres::WindowsResource::new();
-        res.set_icon("assets\\chipolata.ico");
+        res.set_icon("..\\assets\\chipolata.ico");
         res.compile().unwrap();
     }
 }
@@ -0,0 +1,202 @@
+use super::*;
+use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
+use serde_derive::{Deserialize, Serialize};
+
+/// An enum to represent the waveforms from which the sound tone can be generated
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Waveform {
+    Square,
+    Triangle,
+    Sine,
+    Noise,
+}
+
+/// The sound settings used to generate the tone played while the sound timer is active
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct AudioConfig {
+    /// The waveform of the tone
+    pub(crate) waveform: Waveform,
+    /// The pitch of the tone in hertz (for noise, the rate at which new random samples are drawn)
+    pub(crate) frequency: f32,
+    /// The playback volume, from 0 (silent) to 1 (full volume)
+    pub(crate) volume: f32,
+    /// Whether to pass the tone through a low-pass filter, approximating the muffled sound of
+    /// the small piezo buzzers in original hardware
+    pub(crate) buzzer_filter: bool,
+}
+
+impl Default for AudioConfig {
+    /// Constructor that returns the original Chipolata beep: a sinewave at the pitch 440hz (A)
+    fn default() -> Self {
+        AudioConfig {
+            waveform: Waveform::Sine,
+            frequency: DEFAULT_AUDIO_FREQUENCY,
+            volume: DEFAULT_AUDIO_VOLUME,
+            buzzer_filter: false,
+        }
+    }
+}
+
+/// Simple struct to represent an audio stream, with a sink that can be paused and resumed
+/// as required
+pub(crate) struct Audio {
+    _stream: OutputStream,
+    stream_handle: OutputStreamHandle,
+    sink: Sink,
+    config: AudioConfig,
+}
+
+impl Audio {
+    /// Constructor that returns an [Audio] instance whose audio source is an endless tone
+    /// generated as per the passed [AudioConfig].  The stream begins in a paused state
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - the sound settings from which to generate the tone
+    pub(crate) fn new(config: AudioConfig) -> Self {
+        let (_stream, stream_handle) = OutputStream::try_default().unwrap();
+        let sink: Sink = Sink::try_new(&stream_handle).unwrap();
+        let audio: Audio = Audio {
+            _stream,
+            stream_handle,
+            sink,
+            config,
+        };
+        audio.sink.append(Tone::new(config));
+        audio.sink.set_volume(config.volume);
+        audio.sink.pause();
+        audio
+    }
+
+    /// Applies new sound settings.  A change of volume takes effect immediately; any other change
+    /// replaces the tone, preserving whether playback is paused
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - the new sound settings
+    pub(crate) fn set_config(&mut self, config: AudioConfig) -> () {
+        if config.waveform != self.config.waveform
+            || config.frequency != self.config.frequency
+            || config.buzzer_filter != self.config.buzzer_filter
+        {
+            if let Ok(sink) = Sink::try_new(&self.stream_handle) {
+                sink.append(Tone::new(config));
+                if self.sink.is_paused() {
+                    sink.pause();
+                }
+                self.sink.stop();
+                self.sink = sink;
+            }
+        }
+        self.sink.set_volume(config.volume);
+        self.config = config;
+    }
+
+    /// Resumes playback if the stream is paused
+    pub(crate) fn play(&self) -> () {
+        self.sink.play();
+    }
+
+    /// Pauses playback if the stream is playing
+    pub(crate) fn pause(&self) -> () {
+        self.sink.pause();
+    }
+}
+
+/// The rodio audio backend: the tone is resumed and paused as Chipolata reports the sound timer
+/// starting and stopping
+impl SoundSink for Audio {
+    fn start_beep(&mut self, _cycle: usize) {
+        self.play();
+    }
+
+    fn stop_beep(&mut self, _cycle: usize) {
+        self.pause();
+    }
+}
+
+/// An endless mono audio source generating a tone as per an [AudioConfig]
+struct Tone {
+    waveform: Waveform,
+    phase_increment: f32, // the fraction of a waveform period elapsing per sample
+    phase: f32,           // the position within the current waveform period, from 0 to 1
+    noise_state: u32,     // the state of the xorshift generator used for noise
+    noise_sample: f32,    // the random sample held for the current noise period
+    filter_factor: Option<f32>, // the low-pass smoothing factor, if the buzzer filter is on
+    filtered_sample: f32, // the last sample output by the low-pass filter
+}
+
+impl Tone {
+    /// Constructor that returns a [Tone] generated as per the passed [AudioConfig]
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - the sound settings from which to generate the tone
+    fn new(config: AudioConfig) -> Self {
+        // A single-pole low-pass filter, whose smoothing factor follows from the cutoff frequency
+        let sample_period: f32 = 1. / AUDIO_SAMPLE_RATE as f32;
+        let time_constant: f32 = 1. / (2. * std::f32::consts::PI * BUZZER_FILTER_CUTOFF_HERTZ);
+        Tone {
+            waveform: config.waveform,
+            phase_increment: config.frequency / AUDIO_SAMPLE_RATE as f32,
+            phase: 0.,
+            noise_state: 0x2545F491,
+            noise_sample: 0.,
+            filter_factor: match config.buzzer_filter {
+                true => Some(sample_period / (time_constant + sample_period)),
+                false => None,
+            },
+            filtered_sample: 0.,
+        }
+    }
+}
+
+impl Iterator for Tone {
+    type Item = f32;
+
+    /// Returns the next sample of the tone, between -1 and 1
+    fn next(&mut self) -> Option<f32> {
+        let sample: f32 = match self.waveform {
+            Waveform::Square if self.phase < 0.5 => 1.,
+            Waveform::Square => -1.,
+            Waveform::Triangle => 1. - 4. * (self.phase - 0.5).abs(),
+            Waveform::Sine => (2. * std::f32::consts::PI * self.phase).sin(),
+            Waveform::Noise => self.noise_sample,
+        };
+        self.phase += self.phase_increment;
+        if self.phase >= 1. {
+            self.phase -= 1.;
+            // Draw a new random noise sample at the start of each period
+            self.noise_state ^= self.noise_state << 13;
+            self.noise_state ^= self.noise_state >> 17;
+            self.noise_state ^= self.noise_state << 5;
+            self.noise_sample = (self.noise_state as f32 / u32::MAX as f32) * 2. - 1.;
+        }
+        match self.filter_factor {
+            Some(factor) => {
+                self.filtered_sample += factor * (sample - self.filtered_sample);
+                Some(self.filtered_sample)
+            }
+            None => Some(sample),
+        }
+    }
+}
+
+impl Source for Tone {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        AUDIO_SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
@@ -0,0 +1,214 @@
+use super::*;
+
+impl ChipolataUi {
+    /// Instantiates a second Chipolata instance, loaded with the same program as the main instance
+    /// but emulating a different level, then spawns a worker thread to own it.  The compare
+    /// instance never plays audio.  If it cannot be initialised (or the program can no longer be
+    /// loaded), the error is kept for display alongside the main instance (which continues to
+    /// run regardless).
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the emulation level of the compare instance
+    /// * `options` - the [Options] of the main instance, on which the compare instance's are based
+    pub(crate) fn instantiate_compare_chipolata(
+        &mut self,
+        emulation_level: EmulationLevel,
+        options: Options,
+    ) {
+        let mut compare_options: Options = options;
+        compare_options.emulation_level = emulation_level;
+        let mut compare_instance: CompareInstance = CompareInstance::default();
+        match self.load_program_file() {
+            Err(error) => compare_instance.last_error_string = error.to_string(),
+            Ok(program) => match Processor::initialise_and_load(program, compare_options) {
+                Err(error) => compare_instance.last_error_string = error.to_string(),
+                Ok(processor) => {
+                    let (message_to_chipolata_tx, message_from_chipolata_rx) =
+                        spawn_chipolata_worker(processor, None, self.egui_ctx.clone());
+                    compare_instance.message_to_chipolata_tx = Some(message_to_chipolata_tx);
+                    compare_instance.message_from_chipolata_rx = Some(message_from_chipolata_rx);
+                }
+            },
+        }
+        self.compare_instance = Some(compare_instance);
+    }
+
+    /// Instructs the worker thread to terminate the compare instance of Chipolata, if there is one
+    pub(crate) fn stop_compare_chipolata(&mut self) {
+        self.send_to_compare_chipolata(MessageToChipolata::Terminate);
+        self.compare_instance = None;
+    }
+
+    /// Helper function to pass a message on to the compare instance's worker thread, so that it
+    /// mirrors the input given to the main instance.  Pause and resume messages are not passed on
+    /// once the compare instance has crashed, as it can then only be reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the message to pass on
+    pub(crate) fn send_to_compare_chipolata(&self, message: MessageToChipolata) {
+        if let Some(CompareInstance {
+            message_to_chipolata_tx: Some(message_to_chipolata_tx),
+            last_error_string,
+            ..
+        }) = &self.compare_instance
+        {
+            if *last_error_string != String::default()
+                && matches!(
+                    message,
+                    MessageToChipolata::Pause | MessageToChipolata::Resume
+                )
+            {
+                return;
+            }
+            if let Err(_) = message_to_chipolata_tx.send(message) {
+                // absorb the error; no need to handle
+            }
+        }
+    }
+
+    /// Instructs the compare instance's worker thread that the UI is ready to receive a new
+    /// (minimal) state snapshot, unless one has already been requested and not yet received
+    pub(crate) fn request_compare_chipolata_update(&mut self) {
+        if let Some(CompareInstance {
+            awaiting_update: false,
+            ..
+        }) = &self.compare_instance
+        {
+            self.send_to_compare_chipolata(MessageToChipolata::ReadyForStateSnapshot {
+                verbosity: StateSnapshotVerbosity::Minimal,
+            });
+            if let Some(compare_instance) = &mut self.compare_instance {
+                compare_instance.awaiting_update = true;
+            }
+        }
+    }
+
+    /// Check whether the compare instance's worker thread has supplied an updated state snapshot
+    /// (without waiting for one) and, if so, keep its frame buffer for rendering.  If an error report is passed instead, the error
+    /// string is kept for display; unlike the main instance, the compare instance is not shut
+    /// down, so that restarting the program recovers it.  If the worker thread has stopped
+    /// unexpectedly then this too is reported, and the channels to it are discarded (so that it
+    /// is no longer waited on, and is not recovered by restarting).
+    pub(crate) fn process_compare_chipolata_update(&mut self) {
+        if let Some(compare_instance) = &mut self.compare_instance {
+            if let Some(message_from_chipolata_rx) = &compare_instance.message_from_chipolata_rx {
+                let message: MessageFromChipolata = match message_from_chipolata_rx.try_recv() {
+                    Ok(message) => message,
+                    Err(mpsc::TryRecvError::Empty) => return,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        compare_instance.last_error_string = ERROR_WORKER_STOPPED.to_string();
+                        compare_instance.message_to_chipolata_tx = None;
+                        compare_instance.message_from_chipolata_rx = None;
+                        return;
+                    }
+                };
+                match message {
+                    MessageFromChipolata::StateSnapshotReport { snapshot, .. } => {
+                        compare_instance.awaiting_update = false;
+                        let frame_buffer: Display = match snapshot {
+                            StateSnapshot::MinimalSnapshot { frame_buffer, .. } => frame_buffer,
+                            StateSnapshot::ExtendedSnapshot { frame_buffer, .. } => frame_buffer,
+                        };
+                        compare_instance.frame_buffer = Some(frame_buffer);
+                    }
+                    MessageFromChipolata::ErrorReport { error, .. } => {
+                        compare_instance.last_error_string = error.to_string();
+                    }
+                    MessageFromChipolata::SaveStateReport { .. }
+                    | MessageFromChipolata::SessionStateReport { .. } => {
+                        // save states are only taken of the main instance
+                    }
+                    MessageFromChipolata::LogReport { .. } => {
+                        // the console only lists events logged by the main instance
+                    }
+                }
+            }
+        }
+    }
+
+    /// Rendering function to display the contents of the "Compare" drop-down menu, from which the
+    /// emulation level of the compare instance is selected (or compare mode turned off)
+    pub(crate) fn render_compare_menu(&mut self, ui: &mut Ui) {
+        let choices: [(Option<EmulationLevel>, &str, &str); 6] = [
+            (None, CAPTION_RADIO_COMPARE_OFF, TOOLTIP_RADIO_COMPARE_OFF),
+            (
+                Some(EmulationLevel::Chip8 {
+                    memory_limit_2k: false,
+                    variable_cycle_timing: false,
+                }),
+                CAPTION_RADIO_CHIP8,
+                TOOLTIP_SELECTABLE_CHIP8,
+            ),
+            (
+                Some(EmulationLevel::Chip48 {
+                    variable_cycle_timing: false,
+                }),
+                CAPTION_RADIO_CHIP48,
+                TOOLTIP_SELECTABLE_CHIP48,
+            ),
+            (
+                Some(EmulationLevel::SuperChip10 {
+                    variable_cycle_timing: false,
+                }),
+                CAPTION_RADIO_SCHIP10,
+                TOOLTIP_SELECTABLE_SUPERCHIP10,
+            ),
+            (
+                Some(EmulationLevel::SuperChip11 {
+                    octo_compatibility_mode: false,
+                    variable_cycle_timing: false,
+                }),
+                CAPTION_RADIO_SCHIP,
+                TOOLTIP_SELECTABLE_SUPERCHIP,
+            ),
+            (
+                Some(EmulationLevel::SuperChip11 {
+                    octo_compatibility_mode: true,
+                    variable_cycle_timing: false,
+                }),
+                CAPTION_RADIO_SCHIP_OCTO,
+                TOOLTIP_CHECKBOX_OCTO_COMPATIBILITY,
+            ),
+        ];
+        // Delegate any click event once the menu has been rendered
+        let mut clicked_choice: Option<Option<EmulationLevel>> = None;
+        for (emulation_level, caption, tooltip) in choices {
+            if ui
+                .radio(self.compare_emulation_level == emulation_level, caption)
+                .on_hover_text(tooltip)
+                .clicked()
+            {
+                clicked_choice = Some(emulation_level);
+            }
+        }
+        if let Some(emulation_level) = clicked_choice {
+            ui.close_menu();
+            self.on_click_compare_level(emulation_level);
+        }
+    }
+}
+
+/// Helper function that returns the caption identifying an emulation level above its display in
+/// compare mode
+///
+/// # Arguments
+///
+/// * `emulation_level` - the emulation level to identify
+pub(crate) fn emulation_level_caption(emulation_level: EmulationLevel) -> &'static str {
+    match emulation_level {
+        EmulationLevel::Chip8 { .. } => CAPTION_RADIO_CHIP8,
+        EmulationLevel::Chip8X { .. } => CAPTION_RADIO_CHIP8X,
+        EmulationLevel::Chip48 { .. } => CAPTION_RADIO_CHIP48,
+        EmulationLevel::SuperChip10 { .. } => CAPTION_RADIO_SCHIP10,
+        EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            ..
+        } => CAPTION_RADIO_SCHIP,
+        EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: true,
+            ..
+        } => CAPTION_RADIO_SCHIP_OCTO,
+    }
+}
@@ -0,0 +1,295 @@
+use super::*;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// An enum to represent the severity of an event shown in the console panel, in increasing order
+/// of severity (so that events below a chosen level can be filtered out)
+#[derive(PartialEq, PartialOrd, Debug, Clone, Copy)]
+pub(crate) enum LogLevel {
+    /// An instruction executed (only logged while instruction tracing is switched on)
+    Trace,
+    /// A change of processor status, or an action taken on the program by the user
+    Info,
+    /// The program has stopped of its own accord (for example having halted)
+    Warning,
+    /// An internal Chipolata error, which stops execution
+    Error,
+}
+
+impl fmt::Display for LogLevel {
+    /// Formatter for [LogLevel], to facilitate `to_string()` usage
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// A struct that holds an event logged by the worker thread hosting Chipolata, for display in
+/// the console panel
+#[derive(Debug, Clone)]
+pub(crate) struct LogEntry {
+    pub(crate) level: LogLevel, // the severity of the event
+    pub(crate) cycle: usize,    // the processor cycle count at which the event occurred
+    pub(crate) message: String, // the description of the event
+}
+
+/// A struct that holds a bounded queue of logged events.  Once the queue is full, the oldest
+/// events are discarded to make room for new ones, so that memory use does not grow without
+/// limit however quickly events are logged (as when tracing instructions); the number discarded
+/// is counted, so that the user can be told events are missing.
+pub(crate) struct LogQueue {
+    entries: VecDeque<LogEntry>, // the events held, oldest first
+    capacity: usize,             // the maximum number of events held
+    dropped: usize,              // the number of events discarded since the queue was last emptied
+}
+
+impl LogQueue {
+    /// Constructor that returns an empty [LogQueue] holding at most the specified number of events
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - the maximum number of events to hold
+    pub(crate) fn new(capacity: usize) -> Self {
+        LogQueue {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            dropped: 0,
+        }
+    }
+
+    /// Adds the passed event to the back of the queue, discarding the oldest event if the queue
+    /// is full
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - the event to add
+    pub(crate) fn push(&mut self, entry: LogEntry) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+            self.dropped += 1;
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Adds the passed events to the back of the queue, along with the number of events already
+    /// discarded before they were passed on (for example by the worker thread's queue)
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - the events to add, oldest first
+    /// * `dropped` - the number of events discarded before these were passed on
+    pub(crate) fn extend(&mut self, entries: Vec<LogEntry>, dropped: usize) {
+        self.dropped += dropped;
+        for entry in entries {
+            self.push(entry);
+        }
+    }
+
+    /// Empties the queue, returning the events it held (oldest first) and the number discarded
+    pub(crate) fn take(&mut self) -> (Vec<LogEntry>, usize) {
+        let dropped: usize = self.dropped;
+        self.dropped = 0;
+        (self.entries.drain(..).collect(), dropped)
+    }
+
+    /// Empties the queue, forgetting the events it held and the number discarded
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+        self.dropped = 0;
+    }
+
+    /// Returns true if the queue holds no events and none have been discarded
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty() && self.dropped == 0
+    }
+}
+
+/// Helper function that adds an event to the passed shared [LogQueue], absorbing the error should
+/// the queue's lock have been poisoned
+///
+/// # Arguments
+///
+/// * `log_queue` - the queue to which the event is added
+/// * `level` - the severity of the event
+/// * `cycle` - the processor cycle count at which the event occurred
+/// * `message` - the description of the event
+pub(crate) fn log_event(
+    log_queue: &Mutex<LogQueue>,
+    level: LogLevel,
+    cycle: usize,
+    message: String,
+) {
+    if let Ok(mut log_queue) = log_queue.lock() {
+        log_queue.push(LogEntry {
+            level,
+            cycle,
+            message,
+        });
+    }
+}
+
+/// Helper function that configures the passed Chipolata instance to log each instruction it
+/// executes to the passed shared [LogQueue] (through its pre-execute hook), or stops it doing so
+///
+/// # Arguments
+///
+/// * `processor` - the Chipolata instance
+/// * `log_queue` - the queue to which executed instructions are logged
+/// * `enabled` - true to start tracing instructions, false to stop
+pub(crate) fn set_instruction_tracing(
+    processor: &mut Processor,
+    log_queue: &Arc<Mutex<LogQueue>>,
+    enabled: bool,
+) {
+    match enabled {
+        true => {
+            let log_queue: Arc<Mutex<LogQueue>> = Arc::clone(log_queue);
+            processor.set_pre_execute_hook(move |processor, instruction| {
+                let state: StateView = processor.state_view();
+                // The program counter has already been advanced past the instruction's opcode
+                log_event(
+                    &log_queue,
+                    LogLevel::Trace,
+                    state.cycles,
+                    format!(
+                        "{:03X}  {}  {:?}",
+                        state.program_counter.wrapping_sub(2),
+                        instruction.name(),
+                        instruction
+                    ),
+                );
+            });
+        }
+        false => processor.clear_pre_execute_hook(),
+    }
+}
+
+impl ChipolataUi {
+    /// Instructs the worker thread whether to log events for the console panel (only while the
+    /// panel is displayed) and whether to include a trace of each instruction executed
+    pub(crate) fn send_console_logging(&self) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::SetConsoleLogging {
+                enabled: self.show_console,
+                trace_instructions: self.console_trace_instructions,
+            }) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+        }
+    }
+
+    /// Adds events reported by the worker thread to the console, unless the console is paused
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - the events reported, oldest first
+    /// * `dropped` - the number of events the worker thread discarded before reporting these
+    pub(crate) fn store_log_events(&mut self, entries: Vec<LogEntry>, dropped: usize) {
+        if !self.console_paused {
+            self.console.extend(entries, dropped);
+        }
+    }
+
+    /// Rendering function to display the console panel, listing the events logged by the worker
+    /// thread (newest at the bottom) at or above the chosen severity.  Controls are provided to
+    /// choose the severity, switch instruction tracing on or off, pause the addition of new
+    /// events and clear those listed.  Docked, the panel sits above the footer.
+    pub(crate) fn render_console(&mut self, ctx: &egui::Context) {
+        let mut tracing_toggled: bool = false;
+        let add_contents = |ui: &mut Ui| {
+            ui.add_space(UI_SPACER_TOP);
+            ui.horizontal(|ui| {
+                ui.heading(RichText::new(CAPTION_HEADING_CONSOLE).color(COLOUR_HEADING));
+                ComboBox::from_id_source(ID_CONSOLE_LEVEL_COMBO)
+                    .selected_text(self.console_level.to_string())
+                    .show_ui(ui, |ui| {
+                        for level in [
+                            LogLevel::Trace,
+                            LogLevel::Info,
+                            LogLevel::Warning,
+                            LogLevel::Error,
+                        ] {
+                            ui.selectable_value(&mut self.console_level, level, level.to_string());
+                        }
+                    })
+                    .response
+                    .on_hover_text(TOOLTIP_COMBO_CONSOLE_LEVEL);
+                if ui
+                    .checkbox(
+                        &mut self.console_trace_instructions,
+                        CAPTION_CHECKBOX_CONSOLE_TRACE,
+                    )
+                    .on_hover_text(TOOLTIP_CHECKBOX_CONSOLE_TRACE)
+                    .changed()
+                {
+                    tracing_toggled = true;
+                }
+                ui.checkbox(&mut self.console_paused, CAPTION_CHECKBOX_CONSOLE_PAUSE)
+                    .on_hover_text(TOOLTIP_CHECKBOX_CONSOLE_PAUSE);
+                if ui
+                    .button(RichText::new(CAPTION_BUTTON_CONSOLE_CLEAR).color(COLOUR_BUTTON))
+                    .on_hover_text(TOOLTIP_BUTTON_CONSOLE_CLEAR)
+                    .clicked()
+                {
+                    self.console.clear();
+                }
+                if self.console.dropped > 0 {
+                    ui.label(
+                        RichText::new(format!(
+                            "{} {}",
+                            self.console.dropped, CAPTION_LABEL_CONSOLE_DROPPED
+                        ))
+                        .color(COLOUR_LABEL),
+                    )
+                    .on_hover_text(TOOLTIP_LABEL_CONSOLE_DROPPED);
+                }
+            });
+            ui.separator();
+            // Render only the events currently scrolled into view, following the newest
+            let entries: Vec<&LogEntry> = self
+                .console
+                .entries
+                .iter()
+                .filter(|entry| entry.level >= self.console_level)
+                .collect();
+            let row_height: f32 = ui.text_style_height(&TextStyle::Monospace);
+            ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .stick_to_bottom(true)
+                .show_rows(ui, row_height, entries.len(), |ui, row_range| {
+                    for entry in &entries[row_range] {
+                        let colour: Color32 = match entry.level {
+                            LogLevel::Trace | LogLevel::Info => COLOUR_LABEL,
+                            LogLevel::Warning => COLOUR_CONSOLE_WARNING,
+                            LogLevel::Error => COLOUR_ERROR,
+                        };
+                        ui.label(
+                            RichText::new(format!(
+                                "{:>12}  {:<7}  {}",
+                                entry.cycle, entry.level, entry.message
+                            ))
+                            .monospace()
+                            .color(colour),
+                        );
+                    }
+                });
+        };
+        match self.detach_debug_panels {
+            true => {
+                egui::Window::new(ID_CONSOLE_PANEL)
+                    .id(Id::new(ID_CONSOLE_PANEL))
+                    .title_bar(false)
+                    .resizable(true)
+                    .show(ctx, add_contents);
+            }
+            false => {
+                TopBottomPanel::bottom(ID_CONSOLE_PANEL)
+                    .resizable(true)
+                    .default_height(CONSOLE_PANEL_HEIGHT)
+                    .show(ctx, add_contents);
+            }
+        }
+        if tracing_toggled {
+            self.send_console_logging();
+        }
+    }
+}
@@ -0,0 +1,654 @@
+use super::*;
+
+impl ChipolataUi {
+    /// Event handler for "Load Program" button
+    pub(crate) fn on_click_load_program(&mut self) {
+        // Open a file load dialogue with appropriate settings, and
+        // save results in program_file_path field.
+        if let Some(file) = FileDialog::new()
+            .set_title(TITLE_LOAD_PROGRAM_WINDOW)
+            .add_filter(FILTER_CHIP8, &["ch8", "sc8", "c8x", "zip"])
+            .add_filter(FILTER_HEX_TEXT, &["hex", "txt"])
+            .add_filter(FILTER_ZIP, &["zip"])
+            .add_filter(FILTER_ALL, &["*"])
+            .set_directory(&self.roms_path)
+            .pick_file()
+        {
+            // Remember the folder, so the next program is loaded from the same place
+            if let Some(folder) = file.parent() {
+                self.roms_path = folder.to_path_buf();
+            }
+            // A zip archive holding more than one ROM is offered to the user to choose from,
+            // loading continuing once an entry has been chosen
+            if Program::is_archive_file(&file) {
+                match Program::list_archive_entries(&file).unwrap_or_default() {
+                    entries if entries.is_empty() => {
+                        MessageDialog::new()
+                            .set_level(MessageLevel::Error)
+                            .set_title(TITLE_ARCHIVE_ERROR_WINDOW)
+                            .set_description(ERROR_EMPTY_ARCHIVE)
+                            .set_buttons(MessageButtons::Ok)
+                            .show();
+                    }
+                    entries if entries.len() == 1 => {
+                        self.load_program(file.display().to_string(), entries.into_iter().next());
+                    }
+                    entries => self.archive_selection = Some((file, entries)),
+                }
+            } else {
+                self.load_program(file.display().to_string(), None);
+            }
+        }
+    }
+
+    /// Event handler for archive entry selectable labels
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - the name of the chosen ROM entry within the archive awaiting selection
+    pub(crate) fn on_click_archive_entry(&mut self, entry: String) {
+        if let Some((file, _)) = self.archive_selection.take() {
+            self.load_program(file.display().to_string(), Some(entry));
+        }
+    }
+
+    /// Event handler for archive selection "Cancel" button
+    pub(crate) fn on_click_archive_cancel(&mut self) {
+        self.archive_selection = None;
+    }
+
+    /// Helper function that stores the location of a newly chosen program, then prompts the user
+    /// for emulation options before it is run
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the location of the program ROM (or of the zip archive holding it)
+    /// * `archive_entry` - the name of the ROM's entry within the zip archive, if any
+    fn load_program(&mut self, file_path: String, archive_entry: Option<String>) {
+        self.program_file_path = file_path;
+        self.program_archive_entry = archive_entry;
+        self.program_builtin_rom = None;
+        self.prompt_for_new_program_options();
+    }
+
+    /// Event handler for the built-in test ROM entries of the "Test ROMs" drop-down menu
+    ///
+    /// # Arguments
+    ///
+    /// * `rom` - the chosen built-in test ROM
+    #[cfg(feature = "builtin-roms")]
+    pub(crate) fn on_click_builtin_rom(&mut self, rom: &'static BuiltinRom) {
+        // The ROM's name stands in for a file path, for display and to enable the run controls
+        self.program_file_path = rom.name.to_owned();
+        self.program_archive_entry = None;
+        self.program_builtin_rom = Some(rom.data);
+        self.prompt_for_new_program_options();
+        // Default to the emulation level for which the ROM was written
+        self.new_options.emulation_level = rom.emulation_level;
+    }
+
+    /// Helper function that readies the UI for a newly chosen program, then prompts the user
+    /// for emulation options before it is run
+    fn prompt_for_new_program_options(&mut self) {
+        self.clear_save_slots();
+        // Loading a program by hand ends any playlist in progress
+        self.playlist = Vec::new();
+        // Mark the Options model dialogue as open for rendering, as we should
+        // immediately prompt the user for emulation opens before running program.
+        // Clone existing options settings into a temporary, working new option set
+        self.new_options = self.options.clone();
+        self.analyse_program();
+        // The new program must be loaded afresh, so the options cannot be applied live
+        self.options_apply_live = false;
+        self.options_modal_open = true;
+    }
+
+    /// Event handler for "Options" button
+    pub(crate) fn on_click_options(&mut self) {
+        // Mark the Options model dialogue as open for rendering.
+        // Clone existing options settings into a temporary, working new option set
+        self.new_options = self.options.clone();
+        self.analyse_program();
+        self.options_apply_live = true;
+        self.options_modal_open = true;
+    }
+
+    /// Event handler for "Stop" button
+    pub(crate) fn on_click_stop(&mut self) {
+        // Stop Chipolata, and clear stored program file path and any playlist
+        self.stop_chipolata();
+        self.program_file_path = String::default();
+        self.program_archive_entry = None;
+        self.program_builtin_rom = None;
+        self.clear_save_slots();
+        self.playlist = Vec::new();
+    }
+
+    /// Event handler for "Playlist" button
+    pub(crate) fn on_click_playlist(&mut self) {
+        // Open a folder selection dialogue, and build a playlist from the CHIP-8 ROM files within
+        // the user-selected folder (sorted by file name)
+        if let Some(folder) = FileDialog::new()
+            .set_title(TITLE_PLAYLIST_WINDOW)
+            .set_directory(&self.roms_path)
+            .pick_folder()
+        {
+            let mut playlist: Vec<PathBuf> = match std::fs::read_dir(folder) {
+                Ok(entries) => entries
+                    .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                    .filter(|path| Program::is_program_file(path))
+                    .collect(),
+                Err(_) => Vec::new(),
+            };
+            if playlist.is_empty() {
+                MessageDialog::new()
+                    .set_level(MessageLevel::Error)
+                    .set_title(TITLE_PLAYLIST_ERROR_WINDOW)
+                    .set_description(ERROR_EMPTY_PLAYLIST)
+                    .set_buttons(MessageButtons::Ok)
+                    .show();
+                return;
+            }
+            playlist.sort();
+            self.playlist = playlist;
+            self.start_playlist_entry(0);
+        }
+    }
+
+    /// Event handler for playlist entry selectable labels
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - the index of the clicked ROM within the playlist
+    pub(crate) fn on_click_playlist_entry(&mut self, position: usize) {
+        self.start_playlist_entry(position);
+    }
+
+    /// Event handler for playlist "Next" button
+    pub(crate) fn on_click_playlist_next(&mut self) {
+        self.start_playlist_entry(self.playlist_position + 1);
+    }
+
+    /// Event handler for playlist "Close" button
+    pub(crate) fn on_click_playlist_close(&mut self) {
+        // Clear the playlist, leaving the current ROM running
+        self.playlist = Vec::new();
+    }
+
+    /// Event handler for "Memory" toggle button
+    pub(crate) fn on_click_memory_viewer(&mut self) {
+        // Abandon any edit in progress when the memory viewer is shown or hidden
+        self.memory_edit = None;
+    }
+
+    /// Event handler for any change to the sound settings in the "Audio" menu; the worker thread
+    /// applies them to the tone immediately
+    pub(crate) fn on_change_audio_config(&mut self) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::SetAudioConfig {
+                audio_config: self.audio_config,
+            }) {
+                // absorb the error; no need to handle
+            }
+        }
+    }
+
+    /// Event handler for the quick save key; saves the state to the selected save state slot
+    pub(crate) fn on_quick_save_key(&mut self) {
+        self.save_state_to_slot(self.selected_save_slot);
+    }
+
+    /// Event handler for the quick load key; restores the state held in the selected save state
+    /// slot
+    pub(crate) fn on_quick_load_key(&mut self) {
+        self.restore_state_from_slot(self.selected_save_slot);
+    }
+
+    /// Event handler for the previous and next save state slot keys
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - the number of slots to move forward by (wrapping around after the last slot)
+    pub(crate) fn on_select_save_slot_key(&mut self, offset: usize) {
+        self.selected_save_slot = (self.selected_save_slot + offset) % SAVE_STATE_SLOT_COUNT;
+        self.show_osd_message(&format!(
+            "{} {}",
+            CAPTION_OSD_SAVE_SLOT, self.selected_save_slot
+        ));
+    }
+
+    /// Event handler for the save state slot labels in the slot browser
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - the slot clicked
+    pub(crate) fn on_click_save_slot_label(&mut self, slot: usize) {
+        self.selected_save_slot = slot;
+    }
+
+    /// Event handler for the "Save" buttons in the save state slot browser
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - the slot in which to save the state
+    pub(crate) fn on_click_save_to_slot(&mut self, slot: usize) {
+        self.selected_save_slot = slot;
+        self.save_state_to_slot(slot);
+    }
+
+    /// Event handler for the "Load" buttons in the save state slot browser
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - the slot holding the state to restore
+    pub(crate) fn on_click_load_from_slot(&mut self, slot: usize) {
+        self.selected_save_slot = slot;
+        self.restore_state_from_slot(slot);
+    }
+
+    /// Event handler for compare mode radio buttons
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the emulation level to compare against, or `None` to turn compare
+    /// mode off
+    pub(crate) fn on_click_compare_level(&mut self, emulation_level: Option<EmulationLevel>) {
+        self.compare_emulation_level = emulation_level;
+        // Restart the program (if one is executing) so that both instances begin in step
+        if self.execution_state != ExecutionState::Stopped {
+            self.reinstantiate_chipolata(self.options);
+        }
+    }
+
+    /// Event handler for "Copy Crash Report" button
+    pub(crate) fn on_click_copy_crash_report(&self, ui: &mut Ui) {
+        // Place the JSON diagnostic report of the last error on the clipboard
+        ui.output_mut(|output| output.copied_text = self.last_crash_report.clone());
+    }
+
+    /// Event handler for "Save Crash Dump" button
+    pub(crate) fn on_click_save_crash_dump(&self) {
+        // Open a file save dialogue with appropriate settings, and write the crash dump of the
+        // last error to this file
+        if let Some(dump) = &self.last_crash_dump {
+            if let Some(file) = FileDialog::new()
+                .set_title(TITLE_SAVE_CRASH_DUMP_WINDOW)
+                .add_filter(FILTER_CRASH_DUMP, &["chipolata-dump"])
+                .add_filter(FILTER_ALL, &["*"])
+                .set_directory(&self.roms_path)
+                .save_file()
+            {
+                let file_path_string: String = file.display().to_string();
+                let file_path: &Path = Path::new(&file_path_string);
+                if let Err(_) = CrashDump::save_to_file(dump, file_path) {
+                    MessageDialog::new()
+                        .set_level(MessageLevel::Error)
+                        .set_title(TITLE_SAVE_CRASH_DUMP_ERROR_WINDOW)
+                        .set_description(ERROR_SAVE_CRASH_DUMP)
+                        .set_buttons(MessageButtons::Ok)
+                        .show();
+                }
+            }
+        }
+    }
+
+    /// Event handler for "Record" / "Stop Recording" button
+    pub(crate) fn on_click_record(&mut self) {
+        // If already recording, complete the recording; otherwise open a file save dialogue with
+        // appropriate settings, and start recording the display to this file in the chosen colours
+        if self.gif_recorder.is_some() {
+            self.stop_recording();
+        } else if let Some(file) = FileDialog::new()
+            .set_title(TITLE_RECORDING_WINDOW)
+            .add_filter(FILTER_GIF, &["gif"])
+            .add_filter(FILTER_ALL, &["*"])
+            .set_directory(&self.roms_path)
+            .save_file()
+        {
+            let file_path_string: String = file.display().to_string();
+            let file_path: &Path = Path::new(&file_path_string);
+            let palette: [[u8; 3]; 4] =
+                [0, 1, 2, 3].map(|colour_index| self.options.palette.colour(colour_index));
+            match GifRecorder::start(
+                file_path,
+                palette,
+                RECORDING_PIXEL_SCALE,
+                self.options.timer_frequency_hertz,
+            ) {
+                Ok(recorder) => {
+                    self.gif_recorder = Some(recorder);
+                    self.show_osd_message(CAPTION_OSD_RECORDING_STARTED);
+                }
+                Err(_) => ChipolataUi::show_recording_error(),
+            }
+        }
+    }
+
+    /// Event handler for "Pause" button
+    pub(crate) fn on_click_pause(&mut self) {
+        // Instruct the worker thread to pause execution of the current instance of Chipolata and
+        // set execution status to Paused
+        self.execution_state = ExecutionState::Paused;
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::Pause) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+        }
+        self.send_to_compare_chipolata(MessageToChipolata::Pause);
+    }
+
+    /// Event handler for "Advance Frame" button
+    pub(crate) fn on_click_advance_frame(&mut self) {
+        // Instruct the worker thread to execute a single frame while execution remains paused
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::AdvanceFrame) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+        }
+        self.send_to_compare_chipolata(MessageToChipolata::AdvanceFrame);
+    }
+
+    /// Event handler for "Slow Motion" toggle
+    pub(crate) fn on_click_slow_motion(&mut self) {
+        self.slow_motion = !self.slow_motion;
+        self.send_time_scale();
+        self.show_osd_message(match self.slow_motion {
+            true => CAPTION_OSD_SLOW_MOTION_ON,
+            false => CAPTION_OSD_SLOW_MOTION_OFF,
+        });
+    }
+
+    /// Event handler for the fast-forward key being pressed or released; execution is
+    /// fast-forwarded only while the key is held down
+    ///
+    /// # Arguments
+    ///
+    /// * `pressed` - true if the key was pressed, false if released
+    pub(crate) fn on_fast_forward_key(&mut self, pressed: bool) {
+        // Ignore repeated press events while the key is held
+        if pressed != self.fast_forward_held {
+            self.fast_forward_held = pressed;
+            self.send_time_scale();
+        }
+    }
+
+    /// Event handler for "Play" button    
+    pub(crate) fn on_click_play(&mut self) {
+        // Instruct the worker thread to resume execution of the current instance of Chipolata and
+        // set execution status to Running
+        self.execution_state = ExecutionState::Running;
+        self.focus_paused = false;
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::Resume) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+        }
+        self.send_to_compare_chipolata(MessageToChipolata::Resume);
+    }
+
+    /// Event handler for the window gaining or losing focus.  Depending on the chosen
+    /// [FocusLossAction], a running program is paused or throttled when focus is lost, and
+    /// restored when focus is regained; a program already paused by the user stays paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `focused` - true if the window has gained focus, false if it has lost it
+    pub(crate) fn on_window_focus_changed(&mut self, focused: bool) {
+        match (focused, self.focus_loss_action) {
+            (false, FocusLossAction::Pause) => {
+                if self.execution_state == ExecutionState::Running {
+                    self.on_click_pause();
+                    self.focus_paused = true;
+                }
+            }
+            (false, FocusLossAction::Throttle) => {
+                self.focus_throttled = true;
+                self.send_time_scale();
+            }
+            (false, FocusLossAction::Continue) => (),
+            (true, _) => {
+                // Only resume if execution was paused on losing focus and is still paused
+                if self.focus_paused && self.execution_state == ExecutionState::Paused {
+                    self.on_click_play();
+                }
+                self.focus_paused = false;
+                if self.focus_throttled {
+                    self.focus_throttled = false;
+                    self.send_time_scale();
+                }
+            }
+        }
+    }
+
+    /// Event handler for "Restart" button    
+    pub(crate) fn on_click_restart(&mut self) {
+        // Instruct the worker thread to reset the current instance of Chipolata (which restarts
+        // the loaded program without re-reading it from disk), and set execution status to Running.
+        // If there is no current instance then instead instantiate Chipolata afresh
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::Reset) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+            self.execution_state = ExecutionState::Running;
+            self.last_error_string = String::default();
+            self.last_crash_report = String::default();
+            self.last_crash_dump = None;
+            self.suggested_emulation_level = None;
+            self.show_osd_message(CAPTION_OSD_RESTARTED);
+            // Likewise reset the compare instance, if compare mode is on (recovering it if it has
+            // crashed, though not if it could not be initialised in the first place)
+            self.send_to_compare_chipolata(MessageToChipolata::Reset);
+            if let Some(compare_instance) = &mut self.compare_instance {
+                if compare_instance.message_to_chipolata_tx.is_some() {
+                    compare_instance.last_error_string = String::default();
+                }
+            }
+        } else {
+            self.reinstantiate_chipolata(self.options);
+        }
+    }
+
+    /// Event handler for "Restart as ..." button, offered when the program crashed on an
+    /// instruction recognised only at another emulation level.  The program is restarted afresh
+    /// at that level, keeping the other options unless they are invalid there.
+    pub(crate) fn on_click_restart_as_suggested_level(&mut self) {
+        if let Some(emulation_level) = self.suggested_emulation_level.take() {
+            let options: Options = Options {
+                emulation_level,
+                ..self.options
+            };
+            if let Err(error) = options.validate() {
+                self.last_error_string = error.to_string();
+                return;
+            }
+            self.options = options;
+            self.new_options = options;
+            self.reinstantiate_chipolata(options);
+        }
+    }
+
+    /// Event handler for a change of palette, whether by choosing a theme or editing a colour.  The
+    /// palette is held in the options (so is saved with them) and applied at once to any running
+    /// program, whose state snapshots then report it.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` - the new palette
+    pub(crate) fn on_change_palette(&mut self, palette: Palette) {
+        self.options.palette = palette;
+        self.new_options.palette = palette;
+        if self.execution_state != ExecutionState::Stopped {
+            // Applying the options resets the processor speed to that configured, so restore
+            // any speed since chosen with the slider
+            let processor_speed: u64 = self.processor_speed;
+            self.update_chipolata_options();
+            self.processor_speed = processor_speed;
+            self.set_chipolata_speed(processor_speed);
+        }
+    }
+
+    /// Event handler for target processor speed slider
+    pub(crate) fn on_changed_speed_slider(&mut self) {
+        // Change Chipolata's speed
+        self.set_chipolata_speed(self.processor_speed);
+    }
+
+    /// Event handler for CHIP-8 emulation mode selectable label
+    pub(crate) fn on_click_chip8_label(&mut self) {
+        // Set emulation_level field of new Options struct, using appropriate defaults
+        self.new_options.emulation_level = EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        };
+    }
+
+    /// Event handler for CHIP-8X emulation mode selectable label
+    pub(crate) fn on_click_chip8x_label(&mut self) {
+        // Set emulation_level field of new Options struct
+        self.new_options.emulation_level = EmulationLevel::Chip8X {
+            variable_cycle_timing: false,
+        };
+    }
+
+    /// Event handler for CHIP-48 emulation mode selectable label
+    pub(crate) fn on_click_chip48_label(&mut self) {
+        // Set emulation_level field of new Options struct
+        self.new_options.emulation_level = EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        };
+    }
+
+    /// Event handler for SUPER-CHIP 1.0 emulation mode selectable label
+    pub(crate) fn on_click_superchip10_label(&mut self) {
+        // Set emulation_level field of new Options struct
+        self.new_options.emulation_level = EmulationLevel::SuperChip10 {
+            variable_cycle_timing: false,
+        };
+    }
+
+    /// Event handler for SUPER-CHIP 1.1 emulation mode selectable label
+    pub(crate) fn on_click_superchip11_label(&mut self) {
+        // Set emulation_level field of new Options struct, using appropriate defaults
+        self.new_options.emulation_level = EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        };
+    }
+
+    /// Event handler for "OK" options button
+    pub(crate) fn on_click_ok_options(&mut self) {
+        // Explain precisely why invalid options cannot be used, leaving the modal dialogue open so
+        // that they can be corrected
+        if let Err(error) = self.new_options.validate() {
+            MessageDialog::new()
+                .set_level(MessageLevel::Error)
+                .set_title(TITLE_INVALID_OPTIONS_ERROR_WINDOW)
+                .set_description(&format!("{} ({})", ERROR_INVALID_OPTIONS, error))
+                .set_buttons(MessageButtons::Ok)
+                .show();
+            return;
+        }
+        // If a program is running and only options that can be changed at runtime differ, apply
+        // these to the running program; otherwise (re-)instantiate Chipolata
+        let apply_live: bool = self.options_apply_live
+            && self.execution_state != ExecutionState::Stopped
+            && self
+                .options
+                .restart_required_by(&self.new_options)
+                .is_none();
+        // Copy the new options over to the main Chipolata Options struct
+        self.options = self.new_options.clone();
+        match apply_live {
+            true => self.update_chipolata_options(),
+            // Instantiate Chipolata using these new options
+            false => self.reinstantiate_chipolata(self.options),
+        }
+        // Mark the modal dialogue as ready to close
+        self.options_modal_open = false;
+    }
+
+    /// Event handler for "Cancel" options button
+    pub(crate) fn on_click_cancel_options(&mut self) {
+        // Mark the modal dialogue as ready to close
+        self.options_modal_open = false;
+    }
+
+    /// Event handler for for modal Options "Load From File"button
+    pub(crate) fn on_click_load_options(&mut self) {
+        // Open a file load dialogue with appropriate settings, and instantiate an Options struct
+        // from the contents of the user-selected file
+        if let Some(file) = FileDialog::new()
+            .set_title(TITLE_LOAD_OPTIONS_WINDOW)
+            .add_filter(FILTER_OPTIONS, &["json", "toml"])
+            .add_filter(FILTER_JSON, &["json"])
+            .add_filter(FILTER_TOML, &["toml"])
+            .add_filter(FILTER_ALL, &["*"])
+            .set_directory(&self.options_path)
+            .pick_file()
+        {
+            // Choose the file format based on the file extension (defaulting to JSON)
+            let file_path_string: String = file.display().to_string();
+            let file_path: &Path = Path::new(&file_path_string);
+            let loaded_options = match ChipolataUi::is_toml_file(file_path) {
+                true => Options::load_from_toml(file_path),
+                false => Options::load_from_file(file_path),
+            };
+            match loaded_options {
+                Ok(options) => self.new_options = options,
+                Err(error) => {
+                    // Explain why options saved by a later release cannot be loaded
+                    let description: String = match error {
+                        ErrorDetail::IncompatibleOptionsVersion { .. } => {
+                            format!("{} ({})", ERROR_LOAD_OPTIONS, error)
+                        }
+                        _ => ERROR_LOAD_OPTIONS.to_string(),
+                    };
+                    MessageDialog::new()
+                        .set_level(MessageLevel::Error)
+                        .set_title(TITLE_LOAD_OPTIONS_ERROR_WINDOW)
+                        .set_description(&description)
+                        .set_buttons(MessageButtons::Ok)
+                        .show();
+                }
+            }
+        }
+    }
+
+    /// Event handler for for modal Options "Save To File"button
+    pub(crate) fn on_click_save_options(&mut self) {
+        // Open a file save dialogue with appropriate settings, and serialise the new Options struct
+        // to this file as JSON or TOML (depending on the file extension chosen)
+        if let Some(file) = FileDialog::new()
+            .set_title(TITLE_SAVE_OPTIONS_WINDOW)
+            .add_filter(FILTER_JSON, &["json"])
+            .add_filter(FILTER_TOML, &["toml"])
+            .add_filter(FILTER_ALL, &["*"])
+            .set_directory(&self.options_path)
+            .save_file()
+        {
+            let file_path_string: String = file.display().to_string();
+            let file_path: &Path = Path::new(&file_path_string);
+            let save_result = match ChipolataUi::is_toml_file(file_path) {
+                true => Options::save_to_toml(&self.new_options, file_path),
+                false => Options::save_to_file(&self.new_options, file_path),
+            };
+            if let Err(_) = save_result {
+                MessageDialog::new()
+                    .set_level(MessageLevel::Error)
+                    .set_title(TITLE_SAVE_OPTIONS_ERROR_WINDOW)
+                    .set_description(ERROR_SAVE_OPTIONS)
+                    .set_buttons(MessageButtons::Ok)
+                    .show();
+            }
+        }
+    }
+
+    /// Helper function that returns true if the passed file path has a `.toml` extension
+    /// (case-insensitive), in which case options files are read and written as TOML
+    fn is_toml_file(file_path: &Path) -> bool {
+        match file_path.extension() {
+            Some(extension) => extension.eq_ignore_ascii_case("toml"),
+            None => false,
+        }
+    }
+}
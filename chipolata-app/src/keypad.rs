@@ -0,0 +1,107 @@
+use super::*;
+
+/// The CHIP-8 keys in the order in which they are laid out on the COSMAC VIP's hex keypad, row
+/// by row from the top left
+const KEYPAD_LAYOUT: [[u8; 4]; 4] = [
+    [0x1, 0x2, 0x3, 0xC],
+    [0x4, 0x5, 0x6, 0xD],
+    [0x7, 0x8, 0x9, 0xE],
+    [0xA, 0x0, 0xB, 0xF],
+];
+
+impl ChipolataUi {
+    /// Notes a change in the state of a CHIP-8 key made from the keyboard, so that the on-screen
+    /// keypad can highlight the keys currently held
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the hex ordinal of the CHIP-8 key
+    /// * `pressed` - the new state of the key (true means pressed)
+    pub(crate) fn note_keypad_state(&mut self, key: u8, pressed: bool) {
+        self.keypad_held[key as usize] = pressed;
+    }
+
+    /// Forgets which keys are held on the on-screen keypad, for use when the program is stopped
+    pub(crate) fn clear_keypad_state(&mut self) {
+        self.keypad_held = [false; 16];
+        self.keypad_pointer_key = None;
+    }
+
+    /// Rendering function to display the on-screen keypad panel: a 4x4 grid of the CHIP-8 keys in
+    /// the COSMAC VIP layout, highlighting those currently held (whether from the keyboard or the
+    /// keypad itself).  Pressing a key with the mouse or a touchscreen sends the same key events
+    /// as the mapped keyboard key, the key being released when the pointer is lifted or moves off.
+    pub(crate) fn render_keypad_panel(&mut self, ctx: &egui::Context) {
+        let mut pointer_key: Option<u8> = None;
+        show_debug_panel(
+            ctx,
+            self.detach_debug_panels,
+            SidePanel::right(ID_KEYPAD_PANEL).default_width(KEYPAD_PANEL_WIDTH),
+            ID_KEYPAD_PANEL,
+            |ui| {
+                ui.add_space(UI_SPACER_TOP);
+                ui.heading(RichText::new(CAPTION_HEADING_KEYPAD).color(COLOUR_HEADING));
+                ui.separator();
+                Grid::new(ID_KEYPAD_GRID)
+                    .spacing(Vec2::splat(KEYPAD_BUTTON_SPACING))
+                    .show(ui, |ui| {
+                        for row in KEYPAD_LAYOUT {
+                            for key in row {
+                                let held: bool = self.keypad_held[key as usize];
+                                let caption: RichText = RichText::new(format!("{:X}", key))
+                                    .monospace()
+                                    .size(KEYPAD_BUTTON_TEXT_SIZE);
+                                let button: Button = match held {
+                                    true => Button::new(caption.color(COLOUR_KEYPAD_HELD_TEXT))
+                                        .fill(COLOUR_KEYPAD_HELD),
+                                    false => Button::new(caption.color(COLOUR_BUTTON)),
+                                };
+                                let response: Response = ui
+                                    .add(button.min_size(Vec2::splat(KEYPAD_BUTTON_SIZE)))
+                                    .on_hover_text(format!(
+                                        "{} {:?}",
+                                        TOOLTIP_BUTTON_KEYPAD_KEY, self.key_mapping[key as usize]
+                                    ));
+                                if response.is_pointer_button_down_on() {
+                                    pointer_key = Some(key);
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            },
+        );
+        self.on_keypad_pointer(pointer_key);
+    }
+
+    /// Sends the key events resulting from the key held down by the pointer on the on-screen
+    /// keypad changing (releasing any key previously held, then pressing any newly held)
+    ///
+    /// # Arguments
+    ///
+    /// * `pointer_key` - the key now held down by the pointer (None if none)
+    fn on_keypad_pointer(&mut self, pointer_key: Option<u8>) {
+        if pointer_key == self.keypad_pointer_key {
+            return;
+        }
+        let mut events: Vec<KeyEvent> = Vec::new();
+        if let Some(key) = self.keypad_pointer_key {
+            self.note_keypad_state(key, false);
+            events.push(KeyEvent {
+                key: chipolata::Key::ALL[key as usize],
+                pressed: false,
+                cycle_offset: Some(events.len()),
+            });
+        }
+        if let Some(key) = pointer_key {
+            self.note_keypad_state(key, true);
+            events.push(KeyEvent {
+                key: chipolata::Key::ALL[key as usize],
+                pressed: true,
+                cycle_offset: Some(events.len()),
+            });
+        }
+        self.keypad_pointer_key = pointer_key;
+        self.send_key_press_events(events);
+    }
+}
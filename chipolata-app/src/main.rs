@@ -0,0 +1,1771 @@
+#![windows_subsystem = "windows"]
+
+use audio::{Audio, AudioConfig, Waveform};
+#[cfg(feature = "builtin-roms")]
+use chipolata::builtin_roms::{self, BuiltinRom};
+use chipolata::{
+    check_compatibility, run_self_test, ChipolataError, CompatibilityReport, CrashDump, Display,
+    DisplayOrientation, EmulationLevel, ErrorDetail, GifRecorder, KeyEvent, KeyWaitMode, Options,
+    Overlay, OverlayPixel, Palette, PaletteTheme, Processor, ProcessorStatus, Program,
+    ProgramAnalysis, ProgramFormat, SaveState, SelfTestReport, SnapshotDiff, SoundEvent, SoundSink,
+    SpriteEdgeMode, StateSnapshot, StateSnapshotVerbosity, StateView, TimingMode, TimingStatistics,
+    COSMAC_VIP_PROCESSOR_SPEED_HERTZ, HP48_PROCESSOR_SPEED_HERTZ, MAX_TIMER_FREQUENCY_HERTZ,
+    OSD_GLYPH_SIZE_PIXELS,
+};
+use console::{log_event, LogEntry, LogLevel, LogQueue};
+use core::fmt;
+use eframe::egui;
+use egui::*;
+use egui_modal::*;
+use image;
+use resource_strings::*;
+use rfd::*;
+use save_slots::SaveSlot;
+use settings::*;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+mod audio; // Sub-module for handling audio
+mod compare; // Sub-module holding the split-screen compare mode
+mod console; // Sub-module holding the console panel listing events logged by the worker thread
+mod event_handlers; // Sub-module holding all event-handling methods
+mod keypad; // Sub-module holding the on-screen keypad panel
+mod memory_viewer; // Sub-module holding the memory viewer debugging panel
+mod register_inspector; // Sub-module holding the register and stack inspector debugging panel
+mod render; // Sub-module containing all resource strings
+mod resource_strings; // Sub-module holding all UI-rendering methods
+mod save_slots; // Sub-module holding the save state quick slots and slot browser panel
+mod session; // Sub-module handling saving and restoring the whole app session
+mod settings; // Sub-module handling UI settings persisted across sessions
+
+/// The version of Chipolata, as defined in the `cargo.toml` file
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// The initial width of the Chipolata UI window in pixels
+const INITIAL_WIDTH: f32 = 940.;
+/// The initial height of the Chipolata UI window in pixels
+const INITIAL_HEIGHT: f32 = 540.;
+/// A byte array (populated at compile-time) holding the Chipolata logo, for display in the taskbar
+/// and app window
+const ICON: &[u8; 4286] = include_bytes!("..\\..\\assets\\chipolata.ico");
+/// The minimum selectable Chipolata processor speed (for use in the UI's slider widget)
+const MIN_SPEED: u64 = 100;
+/// The maximum selectable Chipolata processor speed (for use in the UI's slider widget)
+const MAX_SPEED: u64 = 10000;
+/// The sprite budget selected when the per-frame sprite limit is first enabled in the UI
+const DEFAULT_SPRITES_PER_FRAME: usize = 4;
+/// The maximum selectable per-frame sprite budget (for use in the UI's DragValue widget)
+const MAX_SPRITES_PER_FRAME: usize = 256;
+/// The per-instruction duration in microseconds selected when fixed instruction timing is first
+/// chosen in the UI
+const DEFAULT_INSTRUCTION_MICROSECONDS: u64 = 1000;
+/// The maximum selectable per-instruction duration in microseconds (for use in the UI's DragValue
+/// widget)
+const MAX_INSTRUCTION_MICROSECONDS: u64 = 10000;
+/// The number of instructions per frame selected when per-frame instruction timing is first
+/// chosen in the UI (as is OCTO's default)
+const DEFAULT_INSTRUCTIONS_PER_FRAME: u64 = 15;
+/// The maximum selectable number of instructions per frame (for use in the UI's DragValue widget)
+const MAX_INSTRUCTIONS_PER_FRAME: u64 = 1000;
+/// The colour to use for any title text
+const COLOUR_TITLE: Color32 = Color32::LIGHT_GRAY;
+/// The colour to use for any heading text
+const COLOUR_HEADING: Color32 = Color32::LIGHT_GRAY;
+/// The colour to use for any label text
+const COLOUR_LABEL: Color32 = Color32::LIGHT_GRAY;
+/// The colour to use for any button text
+const COLOUR_BUTTON: Color32 = Color32::LIGHT_GRAY;
+/// The colour to use for any checkbox text
+const COLOUR_CHECKBOX: Color32 = Color32::LIGHT_GRAY;
+/// The colour to use for any error text
+const COLOUR_ERROR: Color32 = Color32::RED;
+/// The colour to use for the text of the on-screen display
+const COLOUR_OSD_TEXT: Color32 = egui::Color32::from_rgb(0, 220, 255);
+/// The colours of the CHIP-8X (VP-590 colour board) palette, by colour code
+const COLOURS_CHIP8X: [Color32; 8] = [
+    Color32::from_rgb(0, 0, 0),
+    Color32::from_rgb(255, 0, 0),
+    Color32::from_rgb(0, 0, 255),
+    Color32::from_rgb(255, 0, 255),
+    Color32::from_rgb(0, 255, 0),
+    Color32::from_rgb(255, 255, 0),
+    Color32::from_rgb(0, 255, 255),
+    Color32::from_rgb(255, 255, 255),
+];
+/// The number of pixels to use for padding widgets at the top of containers
+const UI_SPACER_TOP: f32 = 4.;
+/// The number of pixels to use for padding widgets at the bottom of containers
+const UI_SPACER_BOTTOM: f32 = 2.;
+/// The number of pixels to use for padding text blocks within widgets
+const UI_SPACER_TEXT: f32 = 8.;
+/// The number of pixels to use for horizontal padding of containers/widgets
+const UI_SPACER_HORIZONTAL: f32 = 100.;
+/// The minimum amount by which the use can increment/decrement a DragValue widget's value
+const DRAGVALUE_QUANTUM: f64 = 10.;
+/// The colour (including transparency) with which to draw pixel grid lines over the display
+const COLOUR_PIXEL_GRID: Color32 = Color32::from_rgba_premultiplied(0, 0, 0, 60);
+/// The colour (including transparency) with which to darken alternate lines for the scanline effect
+const COLOUR_SCANLINE: Color32 = Color32::from_rgba_premultiplied(0, 0, 0, 90);
+/// The tint (including transparency) with which to overlay the phosphor glow halo
+const COLOUR_PHOSPHOR_GLOW: Color32 = Color32::from_rgba_premultiplied(40, 40, 40, 40);
+/// The proportion of a Chipolata pixel by which the phosphor glow halo extends beyond each pixel
+const PHOSPHOR_GLOW_SPREAD: f32 = 0.35;
+/// The proportion of each Chipolata pixel's height darkened by the scanline effect
+const SCANLINE_HEIGHT_RATIO: f32 = 0.4;
+/// The minimum selectable phosphor decay (the proportion of an unlit pixel's previous colour
+/// retained each frame when phosphor persistence is enabled)
+const MIN_PHOSPHOR_DECAY: f32 = 0.1;
+/// The maximum selectable phosphor decay
+const MAX_PHOSPHOR_DECAY: f32 = 0.95;
+/// The default phosphor decay
+const DEFAULT_PHOSPHOR_DECAY: f32 = 0.6;
+/// The minimum selectable time (in seconds) each playlist ROM runs before moving to the next
+const MIN_PLAYLIST_INTERVAL: u64 = 5;
+/// The maximum selectable time (in seconds) each playlist ROM runs before moving to the next
+const MAX_PLAYLIST_INTERVAL: u64 = 3600;
+/// The default time (in seconds) each playlist ROM runs before moving to the next
+const DEFAULT_PLAYLIST_INTERVAL: u64 = 60;
+/// The default number of cycles the self-test command runs a program for under each emulation level
+const DEFAULT_SELF_TEST_CYCLES: usize = 100000;
+/// The width in pixels of the playlist side panel
+const PLAYLIST_PANEL_WIDTH: f32 = 200.;
+/// The number of memory bytes shown on each row of the memory viewer
+const MEMORY_VIEWER_BYTES_PER_ROW: usize = 16;
+/// The width in pixels of each byte cell within the memory viewer
+const MEMORY_VIEWER_CELL_WIDTH: f32 = 18.;
+/// The colour with which to highlight the bytes at the program counter in the memory viewer
+const COLOUR_MEMORY_PROGRAM_COUNTER: Color32 = Color32::from_rgb(0, 110, 40);
+/// The colour with which to highlight the byte at the index register in the memory viewer
+const COLOUR_MEMORY_INDEX_REGISTER: Color32 = Color32::from_rgb(120, 60, 0);
+/// The width of the register inspector panel in pixels
+const REGISTER_INSPECTOR_PANEL_WIDTH: f32 = 180.;
+/// The number of variable registers to display per row in the register inspector
+const REGISTER_INSPECTOR_COLUMNS: usize = 4;
+/// The colour with which to render register values that changed since the previous snapshot
+const COLOUR_REGISTER_CHANGED: Color32 = Color32::YELLOW;
+/// The number of save state quick slots
+const SAVE_STATE_SLOT_COUNT: usize = 10;
+/// The width of the save state slot browser panel in pixels
+const SAVE_SLOTS_PANEL_WIDTH: f32 = 160.;
+/// The width in pixels of the thumbnail screenshots in the save state slot browser
+const SAVE_SLOT_THUMBNAIL_WIDTH: f32 = 128.;
+/// The width of the on-screen keypad panel in pixels
+const KEYPAD_PANEL_WIDTH: f32 = 200.;
+/// The width and height of each on-screen keypad button in pixels
+const KEYPAD_BUTTON_SIZE: f32 = 40.;
+/// The gap between on-screen keypad buttons in pixels
+const KEYPAD_BUTTON_SPACING: f32 = 4.;
+/// The text size of the on-screen keypad button captions
+const KEYPAD_BUTTON_TEXT_SIZE: f32 = 20.;
+/// The colour with which to fill on-screen keypad buttons whose key is currently held
+const COLOUR_KEYPAD_HELD: Color32 = Color32::from_rgb(0, 110, 40);
+/// The colour of the caption of on-screen keypad buttons whose key is currently held
+const COLOUR_KEYPAD_HELD_TEXT: Color32 = Color32::WHITE;
+/// The sample rate in hertz at which the sound tone is generated
+const AUDIO_SAMPLE_RATE: u32 = 48000;
+/// The default pitch in hertz of the sound tone
+const DEFAULT_AUDIO_FREQUENCY: f32 = 440.;
+/// The minimum selectable pitch in hertz of the sound tone
+const MIN_AUDIO_FREQUENCY: f32 = 50.;
+/// The maximum selectable pitch in hertz of the sound tone
+const MAX_AUDIO_FREQUENCY: f32 = 2000.;
+/// The default sound volume (from 0 to 1)
+const DEFAULT_AUDIO_VOLUME: f32 = 1.;
+/// The cutoff frequency in hertz of the low-pass "hardware buzzer" sound filter
+const BUZZER_FILTER_CUTOFF_HERTZ: f32 = 800.;
+/// The height in pixels of the caption strip above each display in compare mode
+const COMPARE_CAPTION_HEIGHT: f32 = 20.;
+/// The font size of the caption above each display in compare mode
+const COMPARE_CAPTION_FONT_SIZE: f32 = 14.;
+/// The time for which the worker thread sleeps when it has nothing to execute (while paused,
+/// crashed or awaiting the next fast-forward or slow motion frame), rather than spinning
+const WORKER_IDLE_SLEEP: Duration = Duration::from_millis(1);
+/// The longest time for which the UI goes without repainting when nothing prompts it to (no
+/// input, and no state snapshot arriving), so that timed elements such as on-screen display
+/// messages and the playlist still update
+const IDLE_REPAINT_INTERVAL: Duration = Duration::from_millis(250);
+/// The number of Chipolata frames executed per real frame while fast-forwarding (i.e. 10x speed)
+const FAST_FORWARD_FRAMES: usize = 10;
+/// The number of real frames per Chipolata frame executed in slow motion (i.e. 0.1x speed)
+const SLOW_MOTION_FRAME_INTERVAL: u32 = 10;
+/// The factor by which the on-screen display overlay's resolution exceeds that of the Chipolata
+/// display, so that messages fit across even the 64 x 32 CHIP-8 display
+const OSD_RESOLUTION_SCALE: usize = 4;
+/// The margin in overlay pixels between on-screen display text and the edges of the display
+const OSD_MARGIN: usize = 4;
+/// The length of time for which a transient on-screen display message is shown
+const OSD_MESSAGE_DURATION: Duration = Duration::from_secs(2);
+/// The number of GIF pixels across (and down) each Chipolata pixel in screen recordings
+const RECORDING_PIXEL_SCALE: usize = 4;
+/// The colour (including transparency) of the box behind on-screen display text
+const COLOUR_OSD_BACKING: Color32 = Color32::from_rgba_premultiplied(0, 0, 0, 160);
+/// The maximum number of events listed in the console panel, beyond which the oldest are discarded
+const CONSOLE_MAX_ENTRIES: usize = 5000;
+/// The maximum number of events the worker thread holds for the console panel between state
+/// snapshot requests, beyond which the oldest are discarded
+const WORKER_LOG_QUEUE_CAPACITY: usize = 1000;
+/// The initial height of the console panel, while docked
+const CONSOLE_PANEL_HEIGHT: f32 = 160.;
+/// The colour in which warning events are listed in the console panel
+const COLOUR_CONSOLE_WARNING: Color32 = Color32::YELLOW;
+
+/// Entry point into the binary; uses eframe to start an instance of the Chipolata UI
+fn main() -> Result<(), eframe::Error> {
+    // Run the compatibility self-test instead of the UI, if requested on the command line
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some(CLI_COMMAND_SELF_TEST) {
+        std::process::exit(run_self_test_command(&args[2..]));
+    }
+    // Restore the settings persisted by the previous session, including the window geometry
+    let settings: Settings = Settings::load();
+    let options = eframe::NativeOptions {
+        icon_data: Some(load_icon()),
+        initial_window_size: Some(
+            settings
+                .window_size
+                .map(Vec2::from)
+                .unwrap_or(Vec2::from((INITIAL_WIDTH, INITIAL_HEIGHT))),
+        ),
+        initial_window_pos: settings.window_position.map(Pos2::from),
+        ..Default::default()
+    };
+
+    eframe::run_native(
+        &format!("{} (v{})", TITLE_APP_WINDOW, VERSION),
+        options,
+        Box::new(|cc| Box::new(ChipolataUi::from_settings(settings, cc.egui_ctx.clone()))),
+    )
+}
+
+/// Runs the compatibility self-test command, which runs the ROM named by the first argument under
+/// each emulation level for the number of cycles given by the optional second argument, and
+/// prints the results ranked from most to least compatible.  Returns the process exit code.
+///
+/// # Arguments
+///
+/// * `args` - the command line arguments following the command name
+fn run_self_test_command(args: &[String]) -> i32 {
+    let cycles: Option<usize> = match args.get(1) {
+        Some(cycles) => cycles.parse::<usize>().ok(),
+        None => Some(DEFAULT_SELF_TEST_CYCLES),
+    };
+    let (file_path, cycle_budget): (&String, usize) = match (args, cycles) {
+        ([file_path] | [file_path, _], Some(cycle_budget)) => (file_path, cycle_budget),
+        _ => {
+            eprintln!("{}", CAPTION_CLI_SELF_TEST_USAGE);
+            return 2;
+        }
+    };
+    let program: Program = match Program::load_from_file(Path::new(file_path)) {
+        Ok(program) => program,
+        Err(error) => {
+            eprintln!("{}: {}", ERROR_SELF_TEST_LOAD, error);
+            return 1;
+        }
+    };
+    let report: SelfTestReport = run_self_test(&program, Options::default(), cycle_budget);
+    for result in &report.results {
+        let level_caption: String = match result.emulation_level {
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                ..
+            } => format!(
+                "{} {}",
+                compare::emulation_level_caption(result.emulation_level),
+                CAPTION_CLI_SELF_TEST_2K
+            ),
+            _ => compare::emulation_level_caption(result.emulation_level).to_string(),
+        };
+        let outcome: String = match (&result.error, result.display_drawn) {
+            (Some(error), _) => format!("{} ({})", CAPTION_CLI_SELF_TEST_CRASHED, error),
+            (None, true) => CAPTION_CLI_SELF_TEST_DREW.to_string(),
+            (None, false) => CAPTION_CLI_SELF_TEST_BLANK.to_string(),
+        };
+        println!(
+            "{:<16} {:>10} {}  {}",
+            level_caption, result.cycles_executed, CAPTION_CLI_SELF_TEST_CYCLES, outcome
+        );
+    }
+    match report.suggested_emulation_level() {
+        Some(emulation_level) => println!(
+            "{} {}",
+            CAPTION_CLI_SELF_TEST_SUGGESTION,
+            compare::emulation_level_caption(emulation_level)
+        ),
+        None => println!("{}", CAPTION_CLI_SELF_TEST_NO_SUGGESTION),
+    }
+    0
+}
+
+/// Helper function to create an [eframe::IconData] based on the const byte array [ICON]
+fn load_icon() -> eframe::IconData {
+    let (icon_rgba, icon_width, icon_height) = {
+        let image = image::load_from_memory(ICON).unwrap().into_rgba8();
+        let (width, height) = image.dimensions();
+        let rgba = image.into_raw();
+        (rgba, width, height)
+    };
+
+    eframe::IconData {
+        rgba: icon_rgba,
+        width: icon_width,
+        height: icon_height,
+    }
+}
+
+/// An enum to represent the high-level current execution state of the hosted Chipolata instance
+#[derive(PartialEq, Debug)]
+enum ExecutionState {
+    /// Not started, or crashed
+    Stopped,
+    /// Currently executing (even if the CPU itself is stalled e.g. waiting for input)
+    Running,
+    /// Paused by the user (no instructions will be executed in this state)
+    Paused,
+}
+
+/// An enum to represent the texture filter used when scaling the Chipolata display to the window
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum DisplayFilter {
+    /// Nearest-neighbour scaling, giving sharp-edged pixels
+    Sharp,
+    /// Bilinear scaling, giving softened pixel edges
+    Smooth,
+}
+
+/// The settings affecting how the Chipolata display is rendered to its texture (the palette,
+/// orientation and texture filter), which must be re-rendered if any of these change even though
+/// the frame buffer has not
+type DisplayAppearance = (Palette, DisplayOrientation, DisplayFilter);
+
+/// An enum to represent the rate at which the hosted Chipolata instance executes relative to its
+/// configured speed.  Outside normal speed, execution is paced a frame (timer interval) at a
+/// time, so the delay and sound timers keep in step with the instructions executed
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum TimeScale {
+    /// Execute at the configured processor speed
+    Normal,
+    /// Execute [FAST_FORWARD_FRAMES] frames per real frame
+    FastForward,
+    /// Execute one frame per [SLOW_MOTION_FRAME_INTERVAL] real frames
+    SlowMotion,
+}
+
+impl fmt::Display for ExecutionState {
+    /// Formatter for [ExecutionState], to facilitate `to_string()` usage
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// An enum to represent messages passed from the UI thread to the worker thread hosting Chipolata
+enum MessageToChipolata {
+    /// The UI is ready to render a frame, and is requesting current state from Chipolata
+    ReadyForStateSnapshot { verbosity: StateSnapshotVerbosity },
+    /// A state snapshot the UI has finished with, whose buffers are reused for the next snapshot
+    /// of the same verbosity (so that exporting this need not allocate)
+    RecycleStateSnapshot { snapshot: StateSnapshot },
+    /// The events of the user pressing or releasing keys during a single frame, in order
+    KeyPressEvents { events: Vec<KeyEvent> },
+    /// A change to the current Chipolata CPU speed
+    SetProcessorSpeed { new_speed: u64 },
+    /// Pause execution (if running)
+    Pause,
+    /// Resume execution (if paused)
+    Resume,
+    /// Restart the loaded program from the beginning
+    Reset,
+    /// Overwrite a byte of memory (only valid while paused)
+    WriteMemoryByte { address: usize, value: u8 },
+    /// A change to the rate of execution (fast-forward or slow motion)
+    SetTimeScale { time_scale: TimeScale },
+    /// Execute one frame's worth of instructions (only valid while paused)
+    AdvanceFrame,
+    /// Save the current state, reporting it back for keeping in the specified quick slot
+    SaveState { slot: usize },
+    /// Save the current state, reporting it back for writing to a session file
+    SaveSession,
+    /// Restore a previously saved state
+    RestoreState { state: SaveState },
+    /// A change to the sound settings
+    SetAudioConfig { audio_config: AudioConfig },
+    /// A change to options that can be applied without re-instantiating Chipolata
+    UpdateOptions { options: Options },
+    /// Start or stop logging events for the console panel, optionally tracing each instruction
+    SetConsoleLogging {
+        enabled: bool,
+        trace_instructions: bool,
+    },
+    /// Kill the current Chipolata instance
+    Terminate,
+}
+
+/// An enum to represent messages passed from the worker thread hosting Chipolata to the UI thread
+enum MessageFromChipolata {
+    /// A report of the current state of the Chipolata emulator (including frame buffer contents),
+    /// along with its timing statistics
+    StateSnapshotReport {
+        snapshot: StateSnapshot,
+        timing: TimingStatistics,
+    },
+    /// Surfacing an internal error generated by Chipolata, along with a crash dump for saving
+    ErrorReport {
+        error: ChipolataError,
+        dump: Option<CrashDump>,
+    },
+    /// A saved state, to be kept in the specified quick slot
+    SaveStateReport { slot: usize, state: SaveState },
+    /// A saved state, to be written to a session file along with the UI settings
+    SessionStateReport { state: SaveState },
+    /// The events logged since the last report, for the console panel, along with the number
+    /// discarded because the worker thread's bounded queue was full
+    LogReport {
+        entries: Vec<LogEntry>,
+        dropped: usize,
+    },
+}
+
+/// A struct that holds the second Chipolata instance run alongside the main one in compare mode,
+/// executing the same program at a different emulation level
+#[derive(Default)]
+struct CompareInstance {
+    message_to_chipolata_tx: Option<mpsc::Sender<MessageToChipolata>>, // sends messages to worker thread
+    message_from_chipolata_rx: Option<mpsc::Receiver<MessageFromChipolata>>, // receives messages from worker thread
+    last_error_string: String, // holds the last error string, if an error has occurred
+    awaiting_update: bool,     // a state snapshot has been requested but not yet received
+    frame_buffer: Option<Display>, // the frame buffer from the last state snapshot
+    persistence_buffer: Vec<Color32>, // the blended pixel colours displayed last frame
+    display_texture: Option<TextureHandle>, // texture holding the rendered frame buffer
+}
+
+/// A struct that represents the overall Chipolata user interface
+struct ChipolataUi {
+    // Inter-thread communication channels
+    message_to_chipolata_tx: Option<mpsc::Sender<MessageToChipolata>>, // sends messages to worker thread
+    message_from_chipolata_rx: Option<mpsc::Receiver<MessageFromChipolata>>, // receives messages from worker thread
+    requested_verbosity: Option<StateSnapshotVerbosity>, // the verbosity of the state snapshot requested but not yet received (None if none)
+    egui_ctx: egui::Context, // the UI context, which worker threads ask to repaint on sending a message
+    // Static config
+    roms_path: PathBuf,    // default folder from which to load program ROMs
+    options_path: PathBuf, // default folder from which to load saved option set files
+    // Dynamic config
+    processor_speed: u64,      // configured target Chipolata processor speed
+    options: Options,          // emulation options currently defined
+    new_options: Options, // new options being defined within the modal UI (but not yet applied)
+    program_file_path: String, // file location of the loaded Chipolata ROM
+    program_archive_entry: Option<String>, // the ROM's entry within the zip archive at the file location, if any
+    program_builtin_rom: Option<&'static [u8]>, // the data of the built-in test ROM loaded in place of a file, if any
+    archive_selection: Option<(PathBuf, Vec<String>)>, // a zip archive, and its ROM entries, awaiting the user's choice
+    analysed_program: Option<(Program, ProgramAnalysis)>, // the loaded ROM and its analysis, shown with the options
+    // State fields
+    execution_state: ExecutionState,    // Chipolata execution status
+    last_error_string: String,          // holds the last error string, if an error has occurred
+    last_crash_report: String, // holds the JSON diagnostic report of the last error, if any
+    last_crash_dump: Option<CrashDump>, // holds the crash dump of the last error, if any
+    suggested_emulation_level: Option<EmulationLevel>, // the level the crashed program evidently requires, if any
+    cycles_completed: usize, // the total number of cycles completed (for speed calculation purposes)
+    cycle_timer: Instant,    // the last moment cycles were counted (for speed calculation purposes)
+    cycles_per_second: usize, // current actual processor speed (calculated from cycles completed)
+    frames_completed: usize, // the number of emulated frames completed since cycles were last counted
+    last_frame_count: Option<usize>, // the emulated frame count reported by the last minimal snapshot
+    gif_recorder: Option<GifRecorder>, // the screen recording in progress, if any
+    gif_recorder_frame: usize, // the frame number of the last display recorded to the screen recording
+    frames_per_second: usize,  // current rate at which frames are being emulated
+    timing_statistics: TimingStatistics, // Chipolata's timing statistics, as at the last snapshot
+    options_modal_open: bool,  // boolean indicating whether the modal Options dialogue is open
+    options_apply_live: bool, // whether confirming the Options dialogue may update the running program
+    // Display rendering settings
+    display_filter: DisplayFilter, // texture filter used when scaling the display
+    show_pixel_grid: bool,         // draw grid lines between Chipolata pixels
+    show_scanlines: bool,          // darken part of each pixel row, CRT-style
+    show_phosphor_glow: bool,      // draw a soft halo around lit pixels, CRT-style
+    lock_aspect_ratio: bool,       // keep Chipolata pixels square, letterboxing the display
+    phosphor_persistence: bool,    // fade unlit pixels out gradually rather than immediately
+    phosphor_decay: f32,           // proportion of the previous frame retained per frame
+    persistence_buffer: Vec<Color32>, // the blended pixel colours displayed last frame
+    display_texture: Option<TextureHandle>, // texture holding the rendered frame buffer
+    display_appearance: Option<DisplayAppearance>, // the settings with which the display texture was rendered
+    display_changed: bool, // the frame buffer may have changed since the display texture was rendered
+    frame_buffer: Option<Display>, // the frame buffer from the last state snapshot
+    // On-screen display fields
+    show_osd_stats: bool, // show the frame rate and processor speed over the display
+    osd_message: Option<(String, Instant)>, // transient message shown over the display, and when
+    osd_texture: Option<TextureHandle>, // texture holding the rendered on-screen display overlay
+    // Playlist fields
+    playlist: Vec<PathBuf>, // ROM files in the playlist being cycled through (empty if none)
+    playlist_position: usize, // index of the currently running playlist ROM
+    playlist_interval: u64, // seconds each playlist ROM runs for before moving to the next
+    playlist_timer: Instant, // the moment the current playlist ROM was started
+    last_processor_status: ProcessorStatus, // the processor status from the last state snapshot
+    // Memory viewer fields
+    show_memory_viewer: bool, // display the memory viewer panel (requesting extended snapshots)
+    memory_contents: Vec<u8>, // addressable memory, as at the last extended snapshot
+    memory_program_counter: u16, // program counter, as at the last extended snapshot
+    memory_index_register: u16, // index register, as at the last extended snapshot
+    memory_edit: Option<(usize, String)>, // address and text of the memory cell being edited
+    // Register inspector fields
+    show_register_inspector: bool, // display the register inspector panel (requesting extended snapshots)
+    register_snapshot: Option<StateSnapshot>, // the last extended snapshot received
+    register_changes: SnapshotDiff, // state that changed between the last two extended snapshots
+    // Compare mode fields
+    compare_emulation_level: Option<EmulationLevel>, // emulation level to compare against (None if off)
+    compare_instance: Option<CompareInstance>,       // the instance running alongside the main one
+    // Save state fields
+    show_save_slots: bool,     // display the save state slot browser panel
+    detach_debug_panels: bool, // display the debugging panels in floating windows, rather than docked beside the display
+    save_slots: Vec<Option<SaveSlot>>, // the contents of each save state quick slot
+    selected_save_slot: usize, // the slot used by the quick save and quick load keys
+    // Keypad fields
+    show_keypad: bool,              // display the on-screen keypad panel
+    keypad_held: [bool; 16],        // the CHIP-8 keys currently held, from the keyboard or keypad
+    keypad_pointer_key: Option<u8>, // the key held down by the pointer on the on-screen keypad
+    // Console fields
+    show_console: bool, // display the console panel (the worker thread only logs events while it is)
+    console: LogQueue,  // the events listed in the console panel
+    console_level: LogLevel, // the least severe events listed in the console panel
+    console_paused: bool, // new events are not added to the console panel
+    console_trace_instructions: bool, // the worker thread logs each instruction executed
+    // Time scale fields
+    fast_forward_held: bool, // the fast-forward key is currently held down
+    slow_motion: bool,       // slow motion is toggled on
+    // Window focus fields
+    focus_loss_action: FocusLossAction, // what happens to the running program when the window loses focus
+    window_focused: bool,               // the window had the keyboard focus as at the last update
+    focus_paused: bool, // execution was paused because the window lost focus (rather than by the user)
+    focus_throttled: bool, // execution is throttled because the window lost focus
+    // Audio settings
+    audio_config: AudioConfig, // the waveform, pitch, volume and filtering of the sound tone
+    // Persisted settings fields
+    key_mapping: [Key; 16], // the keyboard key mapped to each CHIP-8 key (0x0 to 0xF)
+    window_size: Option<Vec2>, // the window size as at the last update
+    window_position: Option<Pos2>, // the window position as at the last update
+    // Session fields
+    session_file_path: Option<PathBuf>, // the file to which the session is saved once the worker thread reports its state
+    pending_window_geometry: Option<(Option<Vec2>, Option<Pos2>)>, // window size and position restored from a session, to be applied next update
+}
+
+impl eframe::App for ChipolataUi {
+    /// Top-level method called by eframe when UI update/repaint is required (~60 times per second)
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        // Note the window geometry, so it can be restored next session
+        let window_info: eframe::WindowInfo = frame.info().window_info;
+        self.window_size = Some(window_info.size);
+        self.window_position = window_info.position;
+        // Apply any window geometry restored from a saved session
+        if let Some((window_size, window_position)) = self.pending_window_geometry.take() {
+            if let Some(window_size) = window_size {
+                frame.set_window_size(window_size);
+            }
+            if let Some(window_position) = window_position {
+                frame.set_window_pos(window_position);
+            }
+        }
+        // Pause or throttle execution if the window has lost focus, or restore it if regained
+        let window_focused: bool = ctx.input(|i| i.raw.has_focus);
+        if window_focused != self.window_focused {
+            self.window_focused = window_focused;
+            self.on_window_focus_changed(window_focused);
+        }
+        // Check for key press events
+        self.handle_input(ctx);
+        // Render the archive entry selection window, if a zip archive awaits the user's choice
+        if self.archive_selection.is_some() {
+            self.render_archive_selection_window(ctx);
+        }
+        // Render the Options modal dialogue, if required
+        if self.options_modal_open {
+            self.render_modal_options(ctx).open();
+        }
+        // Render the header panel
+        self.render_header(ctx);
+        // Render the footer panel
+        self.render_footer(ctx);
+        // Render the console panel, if required (even once stopped, so errors can be read)
+        if self.show_console {
+            self.render_console(ctx);
+        }
+        // If a playlist is active, render the playlist panel and move on to the next ROM if required
+        if !self.playlist.is_empty() {
+            self.render_playlist_panel(ctx);
+            self.update_playlist();
+        }
+        // Render the memory viewer panel, if required
+        if self.show_memory_viewer && self.execution_state != ExecutionState::Stopped {
+            self.render_memory_viewer(ctx);
+        }
+        // Render the register inspector panel, if required
+        if self.show_register_inspector && self.execution_state != ExecutionState::Stopped {
+            self.render_register_inspector(ctx);
+        }
+        // Render the save state slot browser panel, if required
+        if self.show_save_slots && self.execution_state != ExecutionState::Stopped {
+            self.render_save_slots_panel(ctx);
+        }
+        // Render the on-screen keypad panel, if required
+        if self.show_keypad && self.execution_state != ExecutionState::Stopped {
+            self.render_keypad_panel(ctx);
+        }
+        // If a program is currently running then ...
+        if self.execution_state != ExecutionState::Stopped {
+            // Process any state snapshot update received from the compare instance, if compare
+            // mode is on, then inform it the UI is ready for the next (processing its update
+            // first as processing the main instance's update may stop both)
+            self.process_compare_chipolata_update();
+            self.request_compare_chipolata_update();
+            // Likewise for the main instance, keeping the frame buffer of any snapshot received
+            if let Some(frame_buffer) = self.process_chipolata_update() {
+                self.frame_buffer = Some(frame_buffer);
+            }
+            self.request_chipolata_update();
+        }
+        match (&self.execution_state, self.frame_buffer.take()) {
+            // Render the welcome screen if no program is running ...
+            (ExecutionState::Stopped, _) => self.render_welcome_screen(ctx),
+            // ... otherwise redraw the last Chipolata frame buffer received
+            (_, Some(frame_buffer)) => {
+                self.render_chipolata_frame_buffer(ctx, &frame_buffer);
+                self.frame_buffer = Some(frame_buffer);
+            }
+            // (unless none has been received yet since the program was started)
+            (_, None) => (),
+        }
+        // The worker threads request a repaint whenever they send a state snapshot, and egui does
+        // likewise on user input, so only timed elements need a repaint requested here
+        ctx.request_repaint_after(IDLE_REPAINT_INTERVAL);
+    }
+
+    /// Called by eframe when the user closes the window; persists the UI settings for next session
+    fn on_close_event(&mut self) -> bool {
+        self.settings().save();
+        true
+    }
+}
+
+impl ChipolataUi {
+    /// Constructor that returns a [ChipolataUi] instance using typical default settings, other
+    /// than those persisted by a previous session
+    ///
+    /// # Arguments
+    ///
+    /// * `settings` - the persisted settings to apply
+    /// * `egui_ctx` - the UI context, for worker threads to request repaints
+    fn from_settings(settings: Settings, egui_ctx: egui::Context) -> Self {
+        let default_ui: ChipolataUi = ChipolataUi::default();
+        ChipolataUi {
+            roms_path: settings
+                .roms_path
+                .clone()
+                .unwrap_or(default_ui.roms_path.clone()),
+            options: settings.options,
+            new_options: settings.options,
+            key_mapping: settings.key_mapping(),
+            audio_config: settings.audio,
+            detach_debug_panels: settings.detach_debug_panels,
+            focus_loss_action: settings.focus_loss_action,
+            egui_ctx,
+            ..default_ui
+        }
+    }
+
+    /// Returns the UI settings to be persisted for the next session
+    fn settings(&self) -> Settings {
+        Settings {
+            foreground_colour: None,
+            background_colour: None,
+            key_mapping: self
+                .key_mapping
+                .iter()
+                .map(|key| format!("{:?}", key))
+                .collect(),
+            window_size: self.window_size.map(|size| [size.x, size.y]),
+            window_position: self
+                .window_position
+                .map(|position| [position.x, position.y]),
+            roms_path: Some(self.roms_path.clone()),
+            options: self.options,
+            audio: self.audio_config,
+            detach_debug_panels: self.detach_debug_panels,
+            focus_loss_action: self.focus_loss_action,
+        }
+    }
+}
+
+impl Default for ChipolataUi {
+    /// Constructor that returns a [ChipolataUi] instance using typical default settings
+    fn default() -> Self {
+        ChipolataUi {
+            message_to_chipolata_tx: None,
+            message_from_chipolata_rx: None,
+            requested_verbosity: None,
+            egui_ctx: egui::Context::default(),
+            roms_path: std::env::current_dir()
+                .unwrap()
+                .join(PATH_RESOURCE_DIRECTORY_NAME)
+                .join(PATH_ROMS_DIRECTORY_NAME),
+            options_path: std::env::current_dir()
+                .unwrap()
+                .join(PATH_RESOURCE_DIRECTORY_NAME)
+                .join(PATH_OPTIONS_DIRECTORY_NAME),
+            processor_speed: 0,
+            options: Options::default(),
+            new_options: Options::default(),
+            program_file_path: String::default(),
+            program_archive_entry: None,
+            program_builtin_rom: None,
+            archive_selection: None,
+            analysed_program: None,
+            execution_state: ExecutionState::Stopped,
+            last_error_string: String::default(),
+            last_crash_report: String::default(),
+            last_crash_dump: None,
+            suggested_emulation_level: None,
+            cycles_completed: 0,
+            cycle_timer: Instant::now(),
+            cycles_per_second: 0,
+            frames_completed: 0,
+            last_frame_count: None,
+            gif_recorder: None,
+            gif_recorder_frame: 0,
+            frames_per_second: 0,
+            timing_statistics: TimingStatistics::default(),
+            options_modal_open: false,
+            options_apply_live: false,
+            display_filter: DisplayFilter::Sharp,
+            show_pixel_grid: false,
+            show_scanlines: false,
+            show_phosphor_glow: false,
+            lock_aspect_ratio: true,
+            phosphor_persistence: false,
+            phosphor_decay: DEFAULT_PHOSPHOR_DECAY,
+            persistence_buffer: Vec::new(),
+            show_osd_stats: false,
+            osd_message: None,
+            osd_texture: None,
+            playlist: Vec::new(),
+            playlist_position: 0,
+            playlist_interval: DEFAULT_PLAYLIST_INTERVAL,
+            playlist_timer: Instant::now(),
+            last_processor_status: ProcessorStatus::StartingUp,
+            show_memory_viewer: false,
+            memory_contents: Vec::new(),
+            memory_program_counter: 0,
+            memory_index_register: 0,
+            memory_edit: None,
+            show_register_inspector: false,
+            register_snapshot: None,
+            register_changes: SnapshotDiff::default(),
+            compare_emulation_level: None,
+            compare_instance: None,
+            show_save_slots: false,
+            detach_debug_panels: false,
+            save_slots: (0..SAVE_STATE_SLOT_COUNT).map(|_| None).collect(),
+            selected_save_slot: 0,
+            show_keypad: false,
+            keypad_held: [false; 16],
+            keypad_pointer_key: None,
+            show_console: false,
+            console: LogQueue::new(CONSOLE_MAX_ENTRIES),
+            console_level: LogLevel::Info,
+            console_paused: false,
+            console_trace_instructions: false,
+            fast_forward_held: false,
+            slow_motion: false,
+            focus_loss_action: FocusLossAction::Continue,
+            window_focused: true,
+            focus_paused: false,
+            focus_throttled: false,
+            audio_config: AudioConfig::default(),
+            key_mapping: DEFAULT_KEY_MAPPING,
+            window_size: None,
+            window_position: None,
+            session_file_path: None,
+            pending_window_geometry: None,
+            display_texture: None,
+            display_appearance: None,
+            display_changed: true,
+            frame_buffer: None,
+        }
+    }
+}
+
+impl ChipolataUi {
+    /// Instantiates and initialises Chipolata based on the passed [Program] and [Options],
+    /// then spawns a new worker thread to own this instance and continually execute cycles,
+    /// passing message to and from the UI thread using dedicated channels
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - a [Program] instance holding the bytes of the ROM to be executed
+    /// * `options` - an [Options] instance holding Chipolata start-up configuration information
+    fn instantiate_chipolata(&mut self, program: Program, options: Options) {
+        // If we already have a running/paused Chipolata instance then stop this before proceeding
+        if self.execution_state != ExecutionState::Stopped {
+            self.stop_chipolata();
+        }
+        // Instantiate a new Chipolata processor with passed options, and load passed program
+        let processor: Processor;
+        // It is possible an error can be generated even at this early stage, for example if the
+        // emulation options specify a 2k memory limit but the specified program requires 4k
+        match Processor::initialise_and_load(program, options) {
+            Err(error) => {
+                self.last_error_string = error.to_string();
+                self.last_crash_report = error.to_json();
+                self.last_crash_dump = None;
+                self.suggested_emulation_level = None;
+                self.stop_chipolata();
+                return;
+            }
+            Ok(proc) => processor = proc,
+        }
+        // Prepare other app fields
+        self.processor_speed = processor.processor_speed();
+        self.cycles_completed = 0;
+        self.cycle_timer = Instant::now();
+        self.cycles_per_second = 0;
+        self.frames_completed = 0;
+        self.last_frame_count = None;
+        self.frames_per_second = 0;
+        self.timing_statistics = TimingStatistics::default();
+        self.osd_message = None;
+        self.last_error_string = String::default();
+        self.last_crash_report = String::default();
+        self.last_crash_dump = None;
+        self.suggested_emulation_level = None;
+        // Spawn a new thread to host the Chipolata processor and continually execute cycles
+        let (message_to_chipolata_tx, message_from_chipolata_rx) =
+            spawn_chipolata_worker(processor, Some(self.audio_config), self.egui_ctx.clone());
+        self.message_to_chipolata_tx = Some(message_to_chipolata_tx);
+        self.message_from_chipolata_rx = Some(message_from_chipolata_rx);
+        self.requested_verbosity = None;
+        self.display_changed = true;
+        // Start the compare instance alongside, if compare mode is enabled
+        if let Some(compare_emulation_level) = self.compare_emulation_level {
+            self.instantiate_compare_chipolata(compare_emulation_level, options);
+        }
+        // Carry over any fast-forward or slow motion already in effect, and console logging
+        self.send_time_scale();
+        self.send_console_logging();
+        self.execution_state = ExecutionState::Running;
+    }
+
+    /// Instantiates Chipolata afresh as per [ChipolataUi::instantiate_chipolata()], reloading the
+    /// program from the stored program file path.  If the program can no longer be loaded (for
+    /// example, if its file has been deleted or its archive entry removed since it was chosen),
+    /// Chipolata is stopped and the error displayed in the footer instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - an [Options] instance holding Chipolata start-up configuration information
+    fn reinstantiate_chipolata(&mut self, options: Options) {
+        match self.load_program_file() {
+            Ok(program) => self.instantiate_chipolata(program, options),
+            Err(error) => {
+                self.stop_chipolata();
+                self.last_error_string = error.to_string();
+                self.last_crash_report = String::default();
+                self.last_crash_dump = None;
+                self.suggested_emulation_level = None;
+            }
+        }
+    }
+
+    /// Instructs the worker thread to terminate the current instance of Chipolata, and resets
+    /// all fields accordingly
+    fn stop_chipolata(&mut self) {
+        self.execution_state = ExecutionState::Stopped;
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::Terminate) {
+                // absorb the error; the worker thread has already stopped
+            }
+        }
+        self.message_from_chipolata_rx = None;
+        self.message_to_chipolata_tx = None;
+        self.requested_verbosity = None;
+        self.frame_buffer = None;
+        self.processor_speed = 0;
+        self.cycles_per_second = 0;
+        self.frames_per_second = 0;
+        self.timing_statistics = TimingStatistics::default();
+        self.register_snapshot = None;
+        self.register_changes = SnapshotDiff::default();
+        self.focus_paused = false;
+        self.clear_keypad_state();
+        self.stop_compare_chipolata();
+        self.stop_recording();
+    }
+
+    /// Captures the passed frame buffer (in the orientation in which it is displayed) to the
+    /// screen recording in progress, abandoning the recording if it cannot be written
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_buffer` - the frame buffer received in the latest state snapshot
+    fn record_frame(&mut self, frame_buffer: &Display) {
+        // Extended snapshots report no frame count, so are recorded as consecutive frames
+        self.gif_recorder_frame = self.last_frame_count.unwrap_or(self.gif_recorder_frame + 1);
+        if let Some(recorder) = &mut self.gif_recorder {
+            let frame_buffer: Display = frame_buffer.rotated(self.options.display_orientation);
+            if let Err(_) = recorder.record_frame(&frame_buffer, self.gif_recorder_frame) {
+                self.gif_recorder = None;
+                ChipolataUi::show_recording_error();
+            }
+        }
+    }
+
+    /// Completes the screen recording in progress, if any
+    fn stop_recording(&mut self) {
+        if let Some(recorder) = self.gif_recorder.take() {
+            match recorder.finish() {
+                Ok(_) => self.show_osd_message(CAPTION_OSD_RECORDING_SAVED),
+                Err(_) => ChipolataUi::show_recording_error(),
+            }
+        }
+    }
+
+    /// Shows an error message dialogue reporting that the screen recording could not be written
+    fn show_recording_error() {
+        MessageDialog::new()
+            .set_level(MessageLevel::Error)
+            .set_title(TITLE_RECORDING_ERROR_WINDOW)
+            .set_description(ERROR_RECORDING)
+            .set_buttons(MessageButtons::Ok)
+            .show();
+    }
+
+    /// Shows a transient message over the display for a short time, replacing any message
+    /// already shown
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - the message to show
+    fn show_osd_message(&mut self, message: &str) {
+        self.osd_message = Some((message.to_string(), Instant::now()));
+    }
+
+    /// Instructs the worker thread to alter the processor speed of the current instance of Chipolata
+    ///
+    /// # Arguments
+    ///
+    /// * `new_speed` - the new target processor speed (cycles per second)
+    fn set_chipolata_speed(&self, new_speed: u64) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) =
+                message_to_chipolata_tx.send(MessageToChipolata::SetProcessorSpeed { new_speed })
+            {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+        }
+        self.send_to_compare_chipolata(MessageToChipolata::SetProcessorSpeed { new_speed });
+    }
+
+    /// Instructs the worker thread to apply the current options to the running instance of
+    /// Chipolata (and likewise the compare instance, at its own emulation level), for use when
+    /// only options that can be changed at runtime have changed
+    fn update_chipolata_options(&mut self) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::UpdateOptions {
+                options: self.options,
+            }) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+        }
+        if let Some(compare_emulation_level) = self.compare_emulation_level {
+            let mut compare_options: Options = self.options;
+            compare_options.emulation_level = compare_emulation_level;
+            self.send_to_compare_chipolata(MessageToChipolata::UpdateOptions {
+                options: compare_options,
+            });
+        }
+        self.processor_speed = self.options.processor_speed_hertz;
+    }
+
+    /// Method to handle user keyboard input (passing relevant keystrokes on to Chipolata for processing)
+    fn handle_input(&mut self, ctx: &egui::Context) {
+        ctx.input(|i| {
+            // we are only interested in key press input events (both press and release events)
+            let key_events: Vec<(&Key, &bool)> = i
+                .events
+                .iter()
+                .filter_map(|e| match e {
+                    Event::Key { key, pressed, .. } => Some((key, pressed)),
+                    _ => None,
+                })
+                .collect();
+            // CHIP-8 key events are batched, each successive event within the frame taking effect
+            // one cycle after the last, so that quick presses and releases are not lost
+            let mut chip8_key_events: Vec<KeyEvent> = Vec::new();
+            for (key, state) in key_events {
+                // Look up the CHIP-8 key (if any) to which the keyboard key is mapped
+                if let Some(chip8_key) = self.key_mapping.iter().position(|mapped| mapped == key) {
+                    self.note_keypad_state(chip8_key as u8, *state);
+                    chip8_key_events.push(KeyEvent {
+                        key: chipolata::Key::ALL[chip8_key],
+                        pressed: *state,
+                        cycle_offset: Some(chip8_key_events.len()),
+                    });
+                } else if *key == Key::Tab {
+                    self.on_fast_forward_key(*state);
+                } else if *state {
+                    match key {
+                        Key::F5 => self.on_quick_save_key(),
+                        Key::F6 => self.on_select_save_slot_key(SAVE_STATE_SLOT_COUNT - 1),
+                        Key::F7 => self.on_select_save_slot_key(1),
+                        Key::F8 => self.on_quick_load_key(),
+                        _ => (),
+                    }
+                }
+            }
+            if !chip8_key_events.is_empty() {
+                self.send_key_press_events(chip8_key_events);
+            }
+        });
+    }
+
+    /// Helper function that returns the time scale currently selected (fast-forward taking
+    /// priority over slow motion while its key is held).  Execution throttled because the window
+    /// has lost focus runs as per slow motion.
+    pub(crate) fn time_scale(&self) -> TimeScale {
+        match (
+            self.fast_forward_held,
+            self.slow_motion || self.focus_throttled,
+        ) {
+            (true, _) => TimeScale::FastForward,
+            (false, true) => TimeScale::SlowMotion,
+            (false, false) => TimeScale::Normal,
+        }
+    }
+
+    /// Helper function to inform the worker thread (and that of the compare instance, if any) of
+    /// the time scale currently selected
+    pub(crate) fn send_time_scale(&self) {
+        let time_scale: TimeScale = self.time_scale();
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) =
+                message_to_chipolata_tx.send(MessageToChipolata::SetTimeScale { time_scale })
+            {
+                // absorb the error; no need to handle
+            }
+        }
+        self.send_to_compare_chipolata(MessageToChipolata::SetTimeScale { time_scale });
+    }
+
+    /// Helper function to inform worker thread of key presses to be handled by Chipolata
+    fn send_key_press_events(&self, events: Vec<KeyEvent>) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::KeyPressEvents {
+                events: events.clone(),
+            }) {
+                // absorb the error; no need to handle
+            }
+        }
+        self.send_to_compare_chipolata(MessageToChipolata::KeyPressEvents { events });
+    }
+
+    /// Helper function that encodes key emulation option information as a tuple of booleans,
+    /// for easy access and matching
+    ///
+    /// First return bool - true if in CHIP-8 emulation mode
+    /// Second return bool - true if in CHIP-8X emulation mode
+    /// Third return bool - true if in CHIP-48 emulation mode
+    /// Fourth return bool - true if in SUPER-CHIP 1.0 emulation mode
+    /// Fifth return bool - true if in SUPER-CHIP 1.1. emulation mode
+    /// Sixth return bool - true if using variable cycle timing (in any emulation mode)
+    fn check_emulation_level(&self) -> (bool, bool, bool, bool, bool, bool) {
+        match self.new_options.emulation_level {
+            EmulationLevel::Chip8 {
+                memory_limit_2k: _,
+                variable_cycle_timing: true,
+            } => return (true, false, false, false, false, true),
+            EmulationLevel::Chip8 {
+                memory_limit_2k: _,
+                variable_cycle_timing: false,
+            } => return (true, false, false, false, false, false),
+            EmulationLevel::Chip8X {
+                variable_cycle_timing,
+            } => return (false, true, false, false, false, variable_cycle_timing),
+            EmulationLevel::Chip48 {
+                variable_cycle_timing,
+            } => return (false, false, true, false, false, variable_cycle_timing),
+            EmulationLevel::SuperChip10 {
+                variable_cycle_timing,
+            } => return (false, false, false, true, false, variable_cycle_timing),
+            EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: _,
+                variable_cycle_timing,
+            } => return (false, false, false, false, true, variable_cycle_timing),
+        };
+    }
+
+    /// Loads and runs the ROM at the specified position in the playlist, using the current options.
+    /// Entries that can no longer be loaded (for example, files deleted or made unreadable since
+    /// the playlist was built) are dropped from the playlist and reported in the footer, and the
+    /// next entry loaded instead; if none can be loaded, the playlist ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - the index of the ROM within the playlist
+    fn start_playlist_entry(&mut self, position: usize) {
+        let mut skipped: Vec<String> = Vec::new();
+        let program: Program = loop {
+            if self.playlist.is_empty() {
+                self.stop_chipolata();
+                self.last_error_string =
+                    format!("{}{}", ERROR_PLAYLIST_ENTRIES_SKIPPED, skipped.join("; "));
+                return;
+            }
+            self.playlist_position = position % self.playlist.len();
+            self.program_file_path = self.playlist[self.playlist_position].display().to_string();
+            self.program_archive_entry = None;
+            self.program_builtin_rom = None;
+            match self.load_program_file() {
+                Ok(program) => break program,
+                Err(error) => {
+                    skipped.push(error.to_string());
+                    self.playlist.remove(self.playlist_position);
+                }
+            }
+        };
+        self.clear_save_slots();
+        self.playlist_timer = Instant::now();
+        self.last_processor_status = ProcessorStatus::StartingUp;
+        self.instantiate_chipolata(program, self.options);
+        if !skipped.is_empty() && self.last_error_string == String::default() {
+            self.last_error_string =
+                format!("{}{}", ERROR_PLAYLIST_ENTRIES_SKIPPED, skipped.join("; "));
+        }
+    }
+
+    /// Moves on to the next ROM in the playlist if the current one has exited (instruction 00FD),
+    /// halted, crashed, or has been running for longer than the playlist interval
+    fn update_playlist(&mut self) {
+        let program_finished: bool = match self.execution_state {
+            ExecutionState::Stopped => true,
+            ExecutionState::Running | ExecutionState::Paused => {
+                matches!(
+                    self.last_processor_status,
+                    ProcessorStatus::Completed | ProcessorStatus::Halted
+                )
+            }
+        };
+        let interval_elapsed: bool = self.execution_state == ExecutionState::Running
+            && self.playlist_timer.elapsed().as_secs() >= self.playlist_interval;
+        if program_finished || interval_elapsed {
+            self.start_playlist_entry(self.playlist_position + 1);
+        }
+    }
+
+    /// Analyses the program held in the file at the stored program file path, keeping the
+    /// program and its analysis for display (and compatibility checking) alongside the emulation
+    /// options.  If the file cannot be loaded, nothing is kept.
+    fn analyse_program(&mut self) {
+        self.analysed_program = self.load_program_file().ok().map(|program| {
+            let analysis: ProgramAnalysis = program.analyse();
+            (program, analysis)
+        });
+    }
+
+    /// Loads the program held at the stored program file path: the stored entry if the file is
+    /// a zip archive, otherwise the file itself (unless a built-in test ROM has been loaded
+    /// instead, in which case the file path holds only its name)
+    fn load_program_file(&self) -> Result<Program, ErrorDetail> {
+        if let Some(data) = self.program_builtin_rom {
+            return Ok(Program::new(data.to_vec()));
+        }
+        let file_path: &Path = &Path::new(&self.program_file_path);
+        match &self.program_archive_entry {
+            Some(entry) => Program::load_from_archive(file_path, Some(entry)),
+            // Detect whether the file holds a binary ROM image or a hex text dump
+            None => Program::load_from_file_with_format(file_path, ProgramFormat::Auto),
+        }
+    }
+
+    /// Instructs the worked thread to notify the current instance of Chipolata that the UI is
+    /// ready to receive a new state snapshot, including frame buffer for rendering, unless one
+    /// has already been requested and not yet received.  An extended snapshot (including memory
+    /// and registers) is requested only while the memory viewer or register inspector is
+    /// displayed; should either be shown or hidden while a snapshot is awaited, it is requested
+    /// afresh at the new verbosity.
+    fn request_chipolata_update(&mut self) {
+        let verbosity: StateSnapshotVerbosity =
+            match self.show_memory_viewer || self.show_register_inspector {
+                true => StateSnapshotVerbosity::Extended,
+                false => StateSnapshotVerbosity::Minimal,
+            };
+        if self.requested_verbosity == Some(verbosity) {
+            return;
+        }
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx
+                .send(MessageToChipolata::ReadyForStateSnapshot { verbosity })
+            {
+                // absorb the error; no need to handle
+            }
+            self.requested_verbosity = Some(verbosity);
+        }
+    }
+
+    /// Hands a state snapshot the UI has finished with back to the worker thread, so that its
+    /// buffers can be reused for the next snapshot rather than allocated afresh
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - the snapshot finished with
+    fn recycle_state_snapshot(&self, snapshot: StateSnapshot) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) =
+                message_to_chipolata_tx.send(MessageToChipolata::RecycleStateSnapshot { snapshot })
+            {
+                // absorb the error; no need to handle
+            }
+        }
+    }
+
+    /// Check whether the worker thread has supplied an updated state snapshot from the hosted
+    /// Chipolata instance (without waiting for one) and, if so, process this to perform the
+    /// following actions:
+    ///
+    /// * Keep track of Chipolata's reported target processor speed
+    /// * Recalculate the actual processor speed based on the timing of actual cycles completed
+    /// * Keep a copy of memory and the program counter and index register for the memory viewer
+    /// (extended snapshots only)
+    /// * Keep a copy of the snapshot, and the differences from the previous one, for the register
+    /// inspector (extended snapshots only)
+    /// * Return the state snapshot's frame buffer, to be rendered in the UI
+    ///
+    /// If the worker thread passes an error report instead of a state snapshot, then the error
+    /// string is extracted and stored (for display in the UI) and the Chipolata instance is
+    /// shut down.  Likewise if the worker thread has stopped unexpectedly.
+    fn process_chipolata_update(&mut self) -> Option<Display> {
+        if let Some(message_from_chipolata_rx) = &self.message_from_chipolata_rx {
+            let message: MessageFromChipolata = match message_from_chipolata_rx.try_recv() {
+                Ok(message) => message,
+                Err(mpsc::TryRecvError::Empty) => return None,
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    // The worker thread has gone away without reporting an error
+                    self.last_error_string = ERROR_WORKER_STOPPED.to_string();
+                    self.last_crash_report = String::default();
+                    self.last_crash_dump = None;
+                    self.suggested_emulation_level = None;
+                    self.stop_chipolata();
+                    return None;
+                }
+            };
+            match message {
+                MessageFromChipolata::StateSnapshotReport { snapshot, timing } => {
+                    self.requested_verbosity = None;
+                    self.timing_statistics = timing;
+                    // Keep the snapshot, and which values changed since the previous one, for
+                    // the register inspector
+                    if let StateSnapshot::ExtendedSnapshot { .. } = snapshot {
+                        self.register_changes = match &self.register_snapshot {
+                            Some(previous_snapshot) => previous_snapshot.diff(&snapshot),
+                            None => SnapshotDiff::default(),
+                        };
+                        // Hand the snapshot replaced back to the worker thread, to be refreshed
+                        // in place as the next one
+                        if let Some(previous_snapshot) =
+                            self.register_snapshot.replace(snapshot.clone())
+                        {
+                            self.recycle_state_snapshot(previous_snapshot);
+                        }
+                    }
+                    let (frame_buffer, status, processor_speed, cycles) = match snapshot {
+                        StateSnapshot::MinimalSnapshot {
+                            frame_buffer,
+                            palette: _,
+                            status,
+                            processor_speed,
+                            play_sound: _,
+                            delay_timer: _,
+                            sound_timer: _,
+                            cycles,
+                            frames,
+                            cycles_since_frame: _,
+                            display_changed,
+                        } => {
+                            // Count the emulated frames completed since the last snapshot, and
+                            // note whether the display needs to be redrawn (as it always does
+                            // with phosphor persistence, as unlit pixels fade each frame)
+                            self.frames_completed +=
+                                frames.saturating_sub(self.last_frame_count.unwrap_or(frames));
+                            self.last_frame_count = Some(frames);
+                            self.display_changed |= display_changed || self.phosphor_persistence;
+                            (frame_buffer, status, processor_speed, cycles)
+                        }
+                        StateSnapshot::ExtendedSnapshot {
+                            frame_buffer,
+                            status,
+                            processor_speed,
+                            cycles,
+                            memory,
+                            program_counter,
+                            index_register,
+                            ..
+                        } => {
+                            // Extended snapshots report no frame pacing information, so count
+                            // each as a frame and always redraw the display
+                            self.frames_completed += 1;
+                            self.last_frame_count = None;
+                            self.display_changed = true;
+                            // Keep the addressable memory and pointer registers for the
+                            // memory viewer
+                            self.memory_contents =
+                                memory.bytes[..memory.max_addressable_size()].to_vec();
+                            self.memory_program_counter = program_counter;
+                            self.memory_index_register = index_register;
+                            (frame_buffer, status, processor_speed, cycles)
+                        }
+                    };
+                    // Capture the display if a screen recording is in progress
+                    if self.gif_recorder.is_some() {
+                        self.record_frame(&frame_buffer);
+                    }
+                    // Keep track of current processor status and speed
+                    self.last_processor_status = status;
+                    self.processor_speed = processor_speed;
+                    // Recalculate cycles and frames per second
+                    let millis_elapsed: u128 = self.cycle_timer.elapsed().as_millis();
+                    if millis_elapsed >= 1000 {
+                        self.cycles_per_second =
+                            (cycles - self.cycles_completed) * 1000 / millis_elapsed as usize;
+                        self.frames_per_second =
+                            self.frames_completed * 1000 / millis_elapsed as usize;
+                        self.cycles_completed = cycles;
+                        self.frames_completed = 0;
+                        self.cycle_timer = Instant::now();
+                    }
+                    // Return frame buffer, for rendering
+                    return Some(frame_buffer);
+                }
+                MessageFromChipolata::SaveStateReport { slot, state } => {
+                    // Keep the saved state, then carry on checking for the state snapshot
+                    self.store_save_state(slot, state);
+                    return self.process_chipolata_update();
+                }
+                MessageFromChipolata::SessionStateReport { state } => {
+                    // Write the session file, then carry on checking for the state snapshot
+                    self.store_session(state);
+                    return self.process_chipolata_update();
+                }
+                MessageFromChipolata::LogReport { entries, dropped } => {
+                    // List the logged events in the console, then carry on checking for the
+                    // state snapshot
+                    self.store_log_events(entries, dropped);
+                    return self.process_chipolata_update();
+                }
+                MessageFromChipolata::ErrorReport { error, dump } => {
+                    // An error has occurred; save the error message and crash dump, and shut
+                    // down the running Chipolata instance
+                    self.last_error_string = error.to_string();
+                    self.last_crash_report = error.to_json();
+                    self.last_crash_dump = dump;
+                    self.suggested_emulation_level = error.suggested_emulation_level();
+                    self.stop_chipolata();
+                }
+            }
+        }
+        return None;
+    }
+}
+
+/// Spawns a new worker thread to own the passed Chipolata instance and continually execute cycles,
+/// returning the channels used to pass messages to and from the worker thread
+///
+/// # Arguments
+///
+/// * `processor` - the Chipolata instance, with program already loaded
+/// * `audio_config` - the sound settings, if this instance should play its sound through the
+/// audio device (otherwise None)
+/// * `egui_ctx` - the UI context, asked to repaint whenever a message is sent to the UI
+fn spawn_chipolata_worker(
+    mut processor: Processor,
+    audio_config: Option<AudioConfig>,
+    egui_ctx: egui::Context,
+) -> (
+    mpsc::Sender<MessageToChipolata>,
+    mpsc::Receiver<MessageFromChipolata>,
+) {
+    // Prepare cross-thread communication channels between UI and Chipolata
+    let (message_to_chipolata_tx, message_to_chipolata_rx) = mpsc::channel();
+    let (message_from_chipolata_tx, message_from_chipolata_rx) = mpsc::channel();
+    // Spawn a new thread to host the Chipolata processor and continually execute cycles,
+    // handling communication with the UI app via the previously created channels
+    thread::spawn(move || 'outer: {
+        // The audio stream is owned by the worker thread, and is started and stopped as soon as
+        // Chipolata reports the sound timer has changed state (via a channel sound sink, as the
+        // audio stream itself cannot be moved into the processor).  Only one instance plays audio
+        let mut audio_stream: Option<Audio> = audio_config.map(Audio::new);
+        let (sound_event_tx, sound_event_rx) = mpsc::channel::<SoundEvent>();
+        processor.set_sound_sink(sound_event_tx);
+        let mut crashed: bool = false;
+        // Events are only logged for the console panel while the UI asks for them, into a
+        // bounded queue (shared with the instruction tracing hook) emptied at each state snapshot
+        let log_queue: Arc<Mutex<LogQueue>> =
+            Arc::new(Mutex::new(LogQueue::new(WORKER_LOG_QUEUE_CAPACITY)));
+        let mut logging: bool = false;
+        let mut last_status: ProcessorStatus = processor.state_view().status;
+        // Outside normal speed, execution is paced a frame at a time; track when the last frame
+        // was due and, for slow motion, how many real frames have passed since one was executed
+        let mut time_scale: TimeScale = TimeScale::Normal;
+        let mut last_frame: Instant = Instant::now();
+        let mut slow_motion_frames: u32 = 0;
+        // A snapshot handed back by the UI once finished with, refreshed in place for the next
+        // update so that the (frequent) export of extended snapshots does not stall emulation
+        // allocating new buffers
+        let mut spare_snapshot: Option<StateSnapshot> = None;
+        // A snapshot requested by the UI is held back while nothing has changed since the last one
+        // was sent (as while paused), so that the UI is not prompted to repaint needlessly
+        let mut snapshot_request: Option<StateSnapshotVerbosity> = None;
+        let mut sent_verbosity: Option<StateSnapshotVerbosity> = None;
+        let mut state_changed: bool = true;
+        // A message from the UI that woke the worker while idle waiting for a key press, to be
+        // processed ahead of any others waiting
+        let mut woken_by: Option<MessageToChipolata> = None;
+        loop {
+            // Process any messages waiting from UI.  Messages acting on the processor are ignored
+            // once it has crashed (until it is reset), and pausing or resuming is ignored once the
+            // program has exited or halted
+            for message_to_chipolata in woken_by
+                .take()
+                .into_iter()
+                .chain(message_to_chipolata_rx.try_iter())
+            {
+                let halted: bool = crashed
+                    || matches!(
+                        processor.state_view().status,
+                        ProcessorStatus::Completed | ProcessorStatus::Halted
+                    );
+                // Note any action taken on the program by the user, for logging once successful
+                let mut action: Option<&str> = match message_to_chipolata {
+                    MessageToChipolata::Reset => Some(CAPTION_LOG_RESET),
+                    MessageToChipolata::WriteMemoryByte { .. } => Some(CAPTION_LOG_MEMORY_WRITTEN),
+                    MessageToChipolata::RestoreState { .. } => Some(CAPTION_LOG_STATE_RESTORED),
+                    MessageToChipolata::UpdateOptions { .. } => Some(CAPTION_LOG_OPTIONS_APPLIED),
+                    _ => None,
+                };
+                // Anything other than a request for, or the return of, a snapshot may change state
+                state_changed |= !matches!(
+                    message_to_chipolata,
+                    MessageToChipolata::ReadyForStateSnapshot { .. }
+                        | MessageToChipolata::RecycleStateSnapshot { .. }
+                );
+                let result: Result<(), ChipolataError> = match message_to_chipolata {
+                    MessageToChipolata::KeyPressEvents { events } if !crashed => {
+                        processor.apply_input_events(&events);
+                        Ok(())
+                    }
+                    MessageToChipolata::ReadyForStateSnapshot { verbosity } => {
+                        // A snapshot at a new verbosity holds state not sent before
+                        state_changed |= sent_verbosity != Some(verbosity);
+                        snapshot_request = Some(verbosity);
+                        Ok(())
+                    }
+                    MessageToChipolata::RecycleStateSnapshot { snapshot } => {
+                        spare_snapshot = Some(snapshot);
+                        Ok(())
+                    }
+                    MessageToChipolata::SetProcessorSpeed { new_speed } => {
+                        processor.set_processor_speed(new_speed);
+                        Ok(())
+                    }
+                    MessageToChipolata::Pause if !halted => processor.pause_execution(),
+                    MessageToChipolata::Resume if !halted => processor.resume_execution(),
+                    MessageToChipolata::Reset => {
+                        crashed = false;
+                        processor.reset()
+                    }
+                    MessageToChipolata::WriteMemoryByte { address, value } if !crashed => {
+                        // A rejected write (for example while running) leaves the program as it
+                        // was, so is logged rather than reported as a crash
+                        if let Err(error) = processor.write_memory_byte(address, value) {
+                            let cycles: usize = processor.state_view().cycles;
+                            log_event(&log_queue, LogLevel::Warning, cycles, error.to_string());
+                            action = None;
+                        }
+                        Ok(())
+                    }
+                    MessageToChipolata::SetTimeScale {
+                        time_scale: new_time_scale,
+                    } => {
+                        time_scale = new_time_scale;
+                        last_frame = Instant::now();
+                        slow_motion_frames = 0;
+                        Ok(())
+                    }
+                    MessageToChipolata::AdvanceFrame if !halted => {
+                        processor.run_frames(1).map(|_| ())
+                    }
+                    MessageToChipolata::SaveState { slot } if !crashed => {
+                        let state: SaveState = processor.save_state();
+                        if let Err(_) = message_from_chipolata_tx
+                            .send(MessageFromChipolata::SaveStateReport { slot, state })
+                        {
+                            break 'outer;
+                        }
+                        egui_ctx.request_repaint();
+                        Ok(())
+                    }
+                    MessageToChipolata::SaveSession if !crashed => {
+                        let state: SaveState = processor.save_state();
+                        if let Err(_) = message_from_chipolata_tx
+                            .send(MessageFromChipolata::SessionStateReport { state })
+                        {
+                            break 'outer;
+                        }
+                        egui_ctx.request_repaint();
+                        Ok(())
+                    }
+                    MessageToChipolata::RestoreState { state } => {
+                        crashed = false;
+                        processor.restore_state(&state)
+                    }
+                    MessageToChipolata::UpdateOptions { options } if !crashed => {
+                        processor.apply_options(&options)
+                    }
+                    MessageToChipolata::SetConsoleLogging {
+                        enabled,
+                        trace_instructions,
+                    } => {
+                        logging = enabled;
+                        console::set_instruction_tracing(
+                            &mut processor,
+                            &log_queue,
+                            enabled && trace_instructions,
+                        );
+                        if !enabled {
+                            if let Ok(mut log_queue) = log_queue.lock() {
+                                log_queue.clear();
+                            }
+                        }
+                        Ok(())
+                    }
+                    MessageToChipolata::SetAudioConfig { audio_config } => {
+                        if let Some(audio_stream) = &mut audio_stream {
+                            audio_stream.set_config(audio_config);
+                        }
+                        Ok(())
+                    }
+                    MessageToChipolata::Terminate => break 'outer,
+                    _ => Ok(()),
+                };
+                match result {
+                    Ok(()) => {
+                        if let (true, Some(action)) = (logging, action) {
+                            let cycles: usize = processor.state_view().cycles;
+                            log_event(&log_queue, LogLevel::Info, cycles, action.to_string());
+                        }
+                    }
+                    Err(error) => {
+                        // An internal Chipolata error occurred; report this back to UI, stopping
+                        // if the UI is no longer listening
+                        crashed = true;
+                        if !report_error(
+                            &message_from_chipolata_tx,
+                            &processor,
+                            error,
+                            logging.then_some(log_queue.as_ref()),
+                        ) {
+                            break 'outer;
+                        }
+                        egui_ctx.request_repaint();
+                    }
+                }
+            }
+            // Run a Chipolata processor cycle or, outside normal speed, any frames now due (none
+            // while paused, as running frames would otherwise resume execution temporarily, nor
+            // once the program has halted).
+            // Execution is paced by the processor itself, which sleeps between batches of cycles;
+            // when there is nothing to execute, the worker likewise sleeps briefly.  While the
+            // program waits for a key press, the worker instead waits for the next message from
+            // the UI (such as key input), for no longer than until the timers are next due
+            let mut idle: bool = crashed;
+            let mut idle_wait: Option<Duration> = None;
+            if !crashed {
+                let result: Result<(), ChipolataError> = match time_scale {
+                    _ if matches!(
+                        processor.state_view().status,
+                        ProcessorStatus::Paused | ProcessorStatus::Halted
+                    ) =>
+                    {
+                        idle = true;
+                        Ok(())
+                    }
+                    TimeScale::Normal => match processor.idle_duration() {
+                        Some(duration) => {
+                            idle = true;
+                            idle_wait = Some(duration);
+                            Ok(())
+                        }
+                        None => processor.execute_cycle().map(|_| ()),
+                    },
+                    _ if last_frame.elapsed() < processor.frame_duration() => {
+                        idle = true;
+                        Ok(())
+                    }
+                    TimeScale::FastForward => {
+                        last_frame = Instant::now();
+                        processor.run_frames(FAST_FORWARD_FRAMES).map(|_| ())
+                    }
+                    TimeScale::SlowMotion => {
+                        last_frame = Instant::now();
+                        slow_motion_frames = (slow_motion_frames + 1) % SLOW_MOTION_FRAME_INTERVAL;
+                        match slow_motion_frames {
+                            0 => processor.run_frames(1).map(|_| ()),
+                            _ => Ok(()),
+                        }
+                    }
+                };
+                if let Err(error) = result {
+                    // An internal Chipolata error occurred; report this back to UI, stopping if
+                    // the UI is no longer listening
+                    crashed = true;
+                    if !report_error(
+                        &message_from_chipolata_tx,
+                        &processor,
+                        error,
+                        logging.then_some(log_queue.as_ref()),
+                    ) {
+                        break 'outer;
+                    }
+                    egui_ctx.request_repaint();
+                }
+            }
+            state_changed |= !idle;
+            // Log any change of processor status (other than crashing, which is reported above)
+            let state: StateView = processor.state_view();
+            if logging && state.status != last_status && state.status != ProcessorStatus::Crashed {
+                let level: LogLevel = match state.status {
+                    ProcessorStatus::Completed | ProcessorStatus::Halted => LogLevel::Warning,
+                    _ => LogLevel::Info,
+                };
+                let message: String = format!("{} {:?}", CAPTION_LOG_STATUS, state.status);
+                log_event(&log_queue, level, state.cycles, message);
+            }
+            last_status = state.status;
+            // Start or stop audio if the sound timer has changed state
+            for sound_event in sound_event_rx.try_iter() {
+                if let Some(audio_stream) = &mut audio_stream {
+                    sound_event.dispatch(audio_stream);
+                }
+            }
+            // Send a state snapshot update back to UI if requested and anything has changed since
+            // the last, then ask the UI to repaint (stopping if the UI is no longer listening)
+            if let (Some(verbosity), true) = (snapshot_request, state_changed) {
+                if !report_log_events(&message_from_chipolata_tx, &log_queue) {
+                    break 'outer;
+                }
+                let snapshot: StateSnapshot = match spare_snapshot.take() {
+                    Some(mut snapshot) => {
+                        processor.export_state_snapshot_into(verbosity, &mut snapshot);
+                        snapshot
+                    }
+                    None => processor.export_state_snapshot(verbosity),
+                };
+                let timing: TimingStatistics = processor.timing_statistics();
+                if let Err(_) = message_from_chipolata_tx
+                    .send(MessageFromChipolata::StateSnapshotReport { snapshot, timing })
+                {
+                    break 'outer;
+                }
+                egui_ctx.request_repaint();
+                snapshot_request = None;
+                sent_verbosity = Some(verbosity);
+                state_changed = false;
+            }
+            match (idle, idle_wait) {
+                (_, Some(duration)) => match message_to_chipolata_rx.recv_timeout(duration) {
+                    Ok(message_to_chipolata) => woken_by = Some(message_to_chipolata),
+                    Err(mpsc::RecvTimeoutError::Timeout) => {}
+                    Err(mpsc::RecvTimeoutError::Disconnected) => break 'outer,
+                },
+                (true, None) => thread::sleep(WORKER_IDLE_SLEEP),
+                (false, None) => {}
+            }
+        }
+    });
+    (message_to_chipolata_tx, message_from_chipolata_rx)
+}
+
+/// Helper function that renders a debugging panel, either docked beside the display as the
+/// specified side panel, or (if detached) in a floating, resizable window over the display, so
+/// that the display is not shrunk to make room for it
+///
+/// # Arguments
+///
+/// * `ctx` - the egui context in which to render the panel
+/// * `detached` - true to render the panel in a floating window
+/// * `docked_panel` - the side panel in which to render the panel while docked
+/// * `id` - the unique identifier of the panel
+/// * `add_contents` - the function rendering the panel contents
+fn show_debug_panel(
+    ctx: &egui::Context,
+    detached: bool,
+    docked_panel: SidePanel,
+    id: &str,
+    add_contents: impl FnOnce(&mut Ui),
+) {
+    match detached {
+        true => {
+            egui::Window::new(id)
+                .id(Id::new(id))
+                .title_bar(false)
+                .resizable(true)
+                .show(ctx, add_contents);
+        }
+        false => {
+            docked_panel.show(ctx, add_contents);
+        }
+    }
+}
+
+/// Helper function that reports an error raised by Chipolata to the UI thread, returning false if
+/// this could not be sent because the UI is no longer listening (in which case the worker thread
+/// should stop).  If events are being logged for the console panel, the error is logged too, and
+/// the events logged are reported ahead of the error (as the UI stops listening on receiving it).
+///
+/// # Arguments
+///
+/// * `message_from_chipolata_tx` - the channel on which to send messages to the UI thread
+/// * `processor` - the Chipolata instance that raised the error, from which a crash dump is taken
+/// * `error` - the error to report
+/// * `log_queue` - the queue of events logged for the console panel, if logging is enabled
+fn report_error(
+    message_from_chipolata_tx: &mpsc::Sender<MessageFromChipolata>,
+    processor: &Processor,
+    error: ChipolataError,
+    log_queue: Option<&Mutex<LogQueue>>,
+) -> bool {
+    if let Some(log_queue) = log_queue {
+        let cycles: usize = processor.state_view().cycles;
+        log_event(log_queue, LogLevel::Error, cycles, error.to_string());
+        if !report_log_events(message_from_chipolata_tx, log_queue) {
+            return false;
+        }
+    }
+    let dump: Option<CrashDump> = Some(processor.crash_dump(&error));
+    message_from_chipolata_tx
+        .send(MessageFromChipolata::ErrorReport { error, dump })
+        .is_ok()
+}
+
+/// Helper function that reports the events logged for the console panel since the last report
+/// to the UI thread (if there are any), emptying the queue.  Returns false if they could not be
+/// sent because the UI is no longer listening (in which case the worker thread should stop).
+///
+/// # Arguments
+///
+/// * `message_from_chipolata_tx` - the channel on which to send messages to the UI thread
+/// * `log_queue` - the queue of events logged for the console panel
+fn report_log_events(
+    message_from_chipolata_tx: &mpsc::Sender<MessageFromChipolata>,
+    log_queue: &Mutex<LogQueue>,
+) -> bool {
+    let (entries, dropped): (Vec<LogEntry>, usize) = match log_queue.lock() {
+        Ok(mut log_queue) if !log_queue.is_empty() => log_queue.take(),
+        _ => return true,
+    };
+    message_from_chipolata_tx
+        .send(MessageFromChipolata::LogReport { entries, dropped })
+        .is_ok()
+}
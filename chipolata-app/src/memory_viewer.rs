@@ -0,0 +1,163 @@
+use super::*;
+
+impl ChipolataUi {
+    /// Rendering function to display the memory viewer debugging panel, showing a scrollable hex
+    /// view of Chipolata's memory as at the last extended state snapshot.  The bytes at the
+    /// program counter and index register are highlighted.  While execution is paused, clicking a
+    /// byte allows it to be edited.
+    pub(crate) fn render_memory_viewer(&mut self, ctx: &egui::Context) {
+        let editable: bool = self.execution_state == ExecutionState::Paused;
+        // Abandon any edit in progress if execution has been resumed
+        if !editable {
+            self.memory_edit = None;
+        }
+        let mut edit_started: Option<usize> = None;
+        let mut edit_committed: Option<(usize, u8)> = None;
+        let mut edit_cancelled: bool = false;
+        show_debug_panel(
+            ctx,
+            self.detach_debug_panels,
+            SidePanel::left(ID_MEMORY_VIEWER_PANEL).resizable(false),
+            ID_MEMORY_VIEWER_PANEL,
+            |ui| {
+                ui.add_space(UI_SPACER_TOP);
+                ui.heading(RichText::new(CAPTION_HEADING_MEMORY_VIEWER).color(COLOUR_HEADING));
+                // Render a key to the highlight colours, giving the current register values
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!(
+                            "{}: {:03X}",
+                            CAPTION_LABEL_MEMORY_PROGRAM_COUNTER, self.memory_program_counter
+                        ))
+                        .monospace()
+                        .color(COLOUR_LABEL)
+                        .background_color(COLOUR_MEMORY_PROGRAM_COUNTER),
+                    )
+                    .on_hover_text(TOOLTIP_LABEL_MEMORY_PROGRAM_COUNTER);
+                    ui.label(
+                        RichText::new(format!(
+                            "{}: {:03X}",
+                            CAPTION_LABEL_MEMORY_INDEX_REGISTER, self.memory_index_register
+                        ))
+                        .monospace()
+                        .color(COLOUR_LABEL)
+                        .background_color(COLOUR_MEMORY_INDEX_REGISTER),
+                    )
+                    .on_hover_text(TOOLTIP_LABEL_MEMORY_INDEX_REGISTER);
+                });
+                ui.separator();
+                // Render only the rows of memory currently scrolled into view
+                let row_height: f32 = ui.text_style_height(&TextStyle::Monospace);
+                let row_count: usize = (self.memory_contents.len() + MEMORY_VIEWER_BYTES_PER_ROW
+                    - 1)
+                    / MEMORY_VIEWER_BYTES_PER_ROW;
+                ScrollArea::vertical().auto_shrink([false; 2]).show_rows(
+                    ui,
+                    row_height,
+                    row_count,
+                    |ui, row_range| {
+                        for row in row_range {
+                            ui.horizontal(|ui| {
+                                let row_start: usize = row * MEMORY_VIEWER_BYTES_PER_ROW;
+                                ui.label(
+                                    RichText::new(format!("{:03X}:", row_start))
+                                        .monospace()
+                                        .color(COLOUR_LABEL),
+                                );
+                                let row_end: usize = (row_start + MEMORY_VIEWER_BYTES_PER_ROW)
+                                    .min(self.memory_contents.len());
+                                for address in row_start..row_end {
+                                    match &mut self.memory_edit {
+                                        // Render the byte being edited as a text box
+                                        Some((edit_address, text)) if *edit_address == address => {
+                                            let response: Response = ui.add(
+                                                TextEdit::singleline(text)
+                                                    .desired_width(MEMORY_VIEWER_CELL_WIDTH)
+                                                    .font(TextStyle::Monospace),
+                                            );
+                                            response.request_focus();
+                                            if ui.input(|i| i.key_pressed(Key::Escape)) {
+                                                edit_cancelled = true;
+                                            } else if response.lost_focus() {
+                                                // Discard the edit if the text is not a valid byte
+                                                match u8::from_str_radix(text.trim(), 16) {
+                                                    Ok(value) => {
+                                                        edit_committed = Some((address, value))
+                                                    }
+                                                    Err(_) => edit_cancelled = true,
+                                                }
+                                            }
+                                        }
+                                        // Render all other bytes as labels, highlighting those
+                                        // at the program counter and index register
+                                        _ => {
+                                            let response: Response = ui.add(
+                                                Label::new(self.memory_cell_text(address))
+                                                    .sense(Sense::click()),
+                                            );
+                                            let response: Response = match editable {
+                                                true => response
+                                                    .on_hover_text(TOOLTIP_MEMORY_CELL_EDITABLE),
+                                                false => response
+                                                    .on_hover_text(TOOLTIP_MEMORY_CELL_READ_ONLY),
+                                            };
+                                            if editable && response.clicked() {
+                                                edit_started = Some(address);
+                                            }
+                                        }
+                                    }
+                                }
+                            });
+                        }
+                    },
+                );
+            },
+        );
+        // Apply any edit events once the memory viewer fields are no longer borrowed
+        if edit_cancelled {
+            self.memory_edit = None;
+        } else if let Some((address, value)) = edit_committed {
+            self.memory_edit = None;
+            self.memory_contents[address] = value;
+            self.send_memory_write(address, value);
+        } else if let Some(address) = edit_started {
+            self.memory_edit = Some((address, format!("{:02X}", self.memory_contents[address])));
+        }
+    }
+
+    /// Helper function returning the text with which to render the memory byte at the specified
+    /// address, highlighted if the program counter or index register points to it
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address of the byte
+    fn memory_cell_text(&self, address: usize) -> RichText {
+        let text: RichText = RichText::new(format!("{:02X}", self.memory_contents[address]))
+            .monospace()
+            .color(COLOUR_LABEL);
+        let program_counter: usize = self.memory_program_counter as usize;
+        if address == program_counter || address == program_counter + 1 {
+            text.background_color(COLOUR_MEMORY_PROGRAM_COUNTER)
+        } else if address == self.memory_index_register as usize {
+            text.background_color(COLOUR_MEMORY_INDEX_REGISTER)
+        } else {
+            text
+        }
+    }
+
+    /// Helper function to instruct the worker thread to overwrite a byte of Chipolata's memory
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address of the byte
+    /// * `value` - the new value of the byte
+    fn send_memory_write(&self, address: usize, value: u8) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) =
+                message_to_chipolata_tx.send(MessageToChipolata::WriteMemoryByte { address, value })
+            {
+                // absorb the error; no need to handle
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use super::*;
+
+impl ChipolataUi {
+    /// Rendering function to display the register inspector debugging panel, showing the
+    /// variable registers, program counter, index register, timers, RPL flags and stack as at the
+    /// last extended state snapshot.  Values that changed since the previous snapshot are
+    /// highlighted.
+    pub(crate) fn render_register_inspector(&self, ctx: &egui::Context) {
+        show_debug_panel(
+            ctx,
+            self.detach_debug_panels,
+            SidePanel::right(ID_REGISTER_INSPECTOR_PANEL)
+                .default_width(REGISTER_INSPECTOR_PANEL_WIDTH),
+            ID_REGISTER_INSPECTOR_PANEL,
+            |ui| {
+                ui.add_space(UI_SPACER_TOP);
+                ui.heading(RichText::new(CAPTION_HEADING_REGISTER_INSPECTOR).color(COLOUR_HEADING));
+                ui.separator();
+                // Nothing to show until the first extended snapshot has been received
+                if let Some(StateSnapshot::ExtendedSnapshot {
+                    stack,
+                    program_counter,
+                    index_register,
+                    variable_registers,
+                    rpl_registers,
+                    delay_timer,
+                    sound_timer,
+                    emulation_level,
+                    ..
+                }) = &self.register_snapshot
+                {
+                    let changes: &SnapshotDiff = &self.register_changes;
+                    ScrollArea::vertical()
+                        .auto_shrink([false; 2])
+                        .show(ui, |ui| {
+                            // Render the variable registers V0 to VF
+                            CollapsingHeader::new(CAPTION_COLLAPSING_VARIABLE_REGISTERS)
+                                .default_open(true)
+                                .show(ui, |ui| {
+                                    render_register_grid(
+                                        ui,
+                                        ID_VARIABLE_REGISTERS_GRID,
+                                        'V',
+                                        variable_registers,
+                                        &changes.variable_registers,
+                                    );
+                                });
+                            // Render the program counter, index register and timers
+                            CollapsingHeader::new(CAPTION_COLLAPSING_POINTERS_AND_TIMERS)
+                                .default_open(true)
+                                .show(ui, |ui| {
+                                    Grid::new(ID_POINTERS_AND_TIMERS_GRID).num_columns(4).show(
+                                        ui,
+                                        |ui| {
+                                            ui.label(register_name_text(
+                                                CAPTION_LABEL_REGISTER_PROGRAM_COUNTER,
+                                            ));
+                                            ui.label(register_value_text(
+                                                &format!("{:03X}", program_counter),
+                                                changes.program_counter,
+                                            ));
+                                            ui.label(register_name_text(
+                                                CAPTION_LABEL_REGISTER_INDEX_REGISTER,
+                                            ));
+                                            ui.label(register_value_text(
+                                                &format!("{:03X}", index_register),
+                                                changes.index_register,
+                                            ));
+                                            ui.end_row();
+                                            ui.label(register_name_text(
+                                                CAPTION_LABEL_REGISTER_DELAY_TIMER,
+                                            ));
+                                            ui.label(register_value_text(
+                                                &format!("{:02X}", delay_timer),
+                                                changes.delay_timer,
+                                            ));
+                                            ui.label(register_name_text(
+                                                CAPTION_LABEL_REGISTER_SOUND_TIMER,
+                                            ));
+                                            ui.label(register_value_text(
+                                                &format!("{:02X}", sound_timer),
+                                                changes.sound_timer,
+                                            ));
+                                            ui.end_row();
+                                        },
+                                    );
+                                });
+                            // Render the RPL user flags, which exist only in SUPER-CHIP mode
+                            if let EmulationLevel::SuperChip10 { .. }
+                            | EmulationLevel::SuperChip11 { .. } = emulation_level
+                            {
+                                CollapsingHeader::new(CAPTION_COLLAPSING_RPL_FLAGS)
+                                    .default_open(true)
+                                    .show(ui, |ui| {
+                                        render_register_grid(
+                                            ui,
+                                            ID_RPL_FLAGS_GRID,
+                                            'R',
+                                            rpl_registers,
+                                            &changes.rpl_registers,
+                                        );
+                                    });
+                            }
+                            // Render the stack entries, most recently pushed first
+                            CollapsingHeader::new(CAPTION_COLLAPSING_STACK)
+                                .default_open(true)
+                                .show(ui, |ui| {
+                                    // Show the current and greatest depths reached, for spotting
+                                    // ROMs close to overflowing the stack
+                                    ui.label(
+                                        RichText::new(format!(
+                                            "{}{} / {}",
+                                            CAPTION_LABEL_STACK_DEPTH,
+                                            stack.depth(),
+                                            stack.max_depth()
+                                        ))
+                                        .color(COLOUR_LABEL),
+                                    )
+                                    .on_hover_text(TOOLTIP_LABEL_STACK_DEPTH);
+                                    let call_trace: Vec<u16> = stack.call_trace();
+                                    if call_trace.is_empty() {
+                                        ui.label(register_value_text(
+                                            CAPTION_LABEL_STACK_EMPTY,
+                                            changes.stack,
+                                        ));
+                                        return;
+                                    }
+                                    Grid::new(ID_STACK_GRID).num_columns(2).show(ui, |ui| {
+                                        for (depth, address) in call_trace.iter().enumerate().rev()
+                                        {
+                                            let name: Response = ui
+                                                .label(register_name_text(&format!("{:X}", depth)));
+                                            if depth == call_trace.len() - 1 {
+                                                name.on_hover_text(TOOLTIP_LABEL_STACK_TOP);
+                                            }
+                                            ui.label(register_value_text(
+                                                &format!("{:03X}", address),
+                                                changes.stack,
+                                            ));
+                                            ui.end_row();
+                                        }
+                                    });
+                                });
+                        });
+                }
+            },
+        );
+    }
+}
+
+/// Helper function to render a bank of 8-bit registers as a grid of names and hexadecimal values,
+/// highlighting those that changed since the previous snapshot
+///
+/// # Arguments
+///
+/// * `ui` - the UI into which the grid is rendered
+/// * `id` - the unique ID of the grid
+/// * `prefix` - the character preceding each register's index in its name
+/// * `values` - the register values
+/// * `changed_indices` - the indices of the registers that changed since the previous snapshot
+fn render_register_grid(
+    ui: &mut Ui,
+    id: &str,
+    prefix: char,
+    values: &[u8],
+    changed_indices: &[usize],
+) {
+    Grid::new(id)
+        .num_columns(REGISTER_INSPECTOR_COLUMNS * 2)
+        .show(ui, |ui| {
+            for (index, value) in values.iter().enumerate() {
+                ui.label(register_name_text(&format!("{}{:X}", prefix, index)));
+                ui.label(register_value_text(
+                    &format!("{:02X}", value),
+                    changed_indices.contains(&index),
+                ));
+                if (index + 1) % REGISTER_INSPECTOR_COLUMNS == 0 {
+                    ui.end_row();
+                }
+            }
+        });
+}
+
+/// Helper function returning the text with which to render a register name in the register
+/// inspector
+///
+/// # Arguments
+///
+/// * `name` - the register name
+fn register_name_text(name: &str) -> RichText {
+    RichText::new(name).monospace().color(COLOUR_LABEL)
+}
+
+/// Helper function returning the text with which to render a register value in the register
+/// inspector, highlighted if it changed since the previous snapshot
+///
+/// # Arguments
+///
+/// * `value` - the formatted register value
+/// * `changed` - whether the value changed since the previous snapshot
+fn register_value_text(value: &str, changed: bool) -> RichText {
+    let text: RichText = RichText::new(value).monospace();
+    match changed {
+        true => text.color(COLOUR_REGISTER_CHANGED).strong(),
+        false => text.color(COLOUR_LABEL),
+    }
+}
@@ -0,0 +1,1840 @@
+use super::*;
+
+impl ChipolataUi {
+    /// Rendering function to display the header panel at the top of the Chipolata UI
+    pub(crate) fn render_header(&mut self, ctx: &egui::Context) {
+        TopBottomPanel::top(ID_TOP_PANEL).show(ctx, |ui| {
+            ui.add_space(UI_SPACER_TOP);
+            // The entire panel is in horizontal layout (thin strip at top of screen)
+            ui.horizontal(|ui| {
+                // Render the "Load Program" button and delegate click event
+                if ui
+                    .button(RichText::new(CAPTION_BUTTON_LOAD_PROGRAM).color(COLOUR_BUTTON))
+                    .on_hover_text(TOOLTIP_BUTTON_LOAD_PROGRAM)
+                    .clicked()
+                {
+                    self.on_click_load_program();
+                }
+                // Render the "Test ROMs" drop-down menu listing the built-in test ROMs, and
+                // delegate click events
+                #[cfg(feature = "builtin-roms")]
+                ui.menu_button(
+                    RichText::new(CAPTION_BUTTON_TEST_ROMS).color(COLOUR_BUTTON),
+                    |ui| {
+                        for rom in builtin_roms::list() {
+                            if ui
+                                .button(rom.name)
+                                .on_hover_text(format!("{} ({})", rom.description, rom.author))
+                                .clicked()
+                            {
+                                ui.close_menu();
+                                self.on_click_builtin_rom(rom);
+                            }
+                        }
+                    },
+                )
+                .response
+                .on_hover_text(TOOLTIP_BUTTON_TEST_ROMS);
+                // Render the "Options" button and delegate click event
+                if ui
+                    .add_enabled(
+                        // Only enabled if we have a program file specified
+                        self.program_file_path != String::default(),
+                        Button::new(RichText::new(CAPTION_BUTTON_OPTIONS).color(COLOUR_BUTTON)),
+                    )
+                    .on_hover_text(TOOLTIP_BUTTON_OPTIONS)
+                    .on_disabled_hover_text(TOOLTIP_BUTTON_OPTIONS_DISABLED)
+                    .clicked()
+                {
+                    self.on_click_options();
+                }
+                // Render the "Playlist" button and delegate click event
+                if ui
+                    .button(RichText::new(CAPTION_BUTTON_PLAYLIST).color(COLOUR_BUTTON))
+                    .on_hover_text(TOOLTIP_BUTTON_PLAYLIST)
+                    .clicked()
+                {
+                    self.on_click_playlist();
+                }
+                // Render the "Session" drop-down menu from which the whole session is saved or
+                // restored, and delegate click events
+                ui.menu_button(
+                    RichText::new(CAPTION_BUTTON_SESSION).color(COLOUR_BUTTON),
+                    |ui| {
+                        if ui
+                            .add_enabled(
+                                // Only enabled if a program is running
+                                self.execution_state != ExecutionState::Stopped,
+                                Button::new(CAPTION_BUTTON_SAVE_SESSION),
+                            )
+                            .on_hover_text(TOOLTIP_BUTTON_SAVE_SESSION)
+                            .on_disabled_hover_text(TOOLTIP_BUTTON_SAVE_SESSION_DISABLED)
+                            .clicked()
+                        {
+                            ui.close_menu();
+                            self.on_click_save_session();
+                        }
+                        if ui
+                            .button(CAPTION_BUTTON_RESTORE_SESSION)
+                            .on_hover_text(TOOLTIP_BUTTON_RESTORE_SESSION)
+                            .clicked()
+                        {
+                            ui.close_menu();
+                            self.on_click_restore_session();
+                        }
+                    },
+                )
+                .response
+                .on_hover_text(TOOLTIP_BUTTON_SESSION);
+                // Render the "Memory" toggle button to show or hide the memory viewer panel
+                if ui
+                    .toggle_value(
+                        &mut self.show_memory_viewer,
+                        RichText::new(CAPTION_BUTTON_MEMORY_VIEWER).color(COLOUR_BUTTON),
+                    )
+                    .on_hover_text(TOOLTIP_BUTTON_MEMORY_VIEWER)
+                    .clicked()
+                {
+                    self.on_click_memory_viewer();
+                }
+                // Render the "Registers" toggle button to show or hide the register inspector panel
+                ui.toggle_value(
+                    &mut self.show_register_inspector,
+                    RichText::new(CAPTION_BUTTON_REGISTER_INSPECTOR).color(COLOUR_BUTTON),
+                )
+                .on_hover_text(TOOLTIP_BUTTON_REGISTER_INSPECTOR);
+                // Render the "Save States" toggle button to show or hide the slot browser panel
+                ui.toggle_value(
+                    &mut self.show_save_slots,
+                    RichText::new(CAPTION_BUTTON_SAVE_SLOTS).color(COLOUR_BUTTON),
+                )
+                .on_hover_text(TOOLTIP_BUTTON_SAVE_SLOTS);
+                // Render the "Keypad" toggle button to show or hide the on-screen keypad panel
+                ui.toggle_value(
+                    &mut self.show_keypad,
+                    RichText::new(CAPTION_BUTTON_KEYPAD).color(COLOUR_BUTTON),
+                )
+                .on_hover_text(TOOLTIP_BUTTON_KEYPAD);
+                // Render the "Console" toggle button to show or hide the console panel
+                if ui
+                    .toggle_value(
+                        &mut self.show_console,
+                        RichText::new(CAPTION_BUTTON_CONSOLE).color(COLOUR_BUTTON),
+                    )
+                    .on_hover_text(TOOLTIP_BUTTON_CONSOLE)
+                    .clicked()
+                {
+                    self.send_console_logging();
+                }
+                // Render the "Compare" drop-down menu from which split-screen compare mode is set
+                ui.menu_button(
+                    RichText::new(CAPTION_BUTTON_COMPARE).color(COLOUR_BUTTON),
+                    |ui| self.render_compare_menu(ui),
+                )
+                .response
+                .on_hover_text(TOOLTIP_BUTTON_COMPARE);
+                // Render the "Display" drop-down menu holding the display rendering settings
+                ui.menu_button(
+                    RichText::new(CAPTION_BUTTON_DISPLAY).color(COLOUR_BUTTON),
+                    |ui| {
+                        ui.radio_value(
+                            &mut self.display_filter,
+                            DisplayFilter::Sharp,
+                            CAPTION_RADIO_FILTER_SHARP,
+                        )
+                        .on_hover_text(TOOLTIP_RADIO_FILTER_SHARP);
+                        ui.radio_value(
+                            &mut self.display_filter,
+                            DisplayFilter::Smooth,
+                            CAPTION_RADIO_FILTER_SMOOTH,
+                        )
+                        .on_hover_text(TOOLTIP_RADIO_FILTER_SMOOTH);
+                        ui.separator();
+                        ui.checkbox(&mut self.show_pixel_grid, CAPTION_CHECKBOX_PIXEL_GRID)
+                            .on_hover_text(TOOLTIP_CHECKBOX_PIXEL_GRID);
+                        ui.checkbox(&mut self.show_scanlines, CAPTION_CHECKBOX_SCANLINES)
+                            .on_hover_text(TOOLTIP_CHECKBOX_SCANLINES);
+                        ui.checkbox(&mut self.show_phosphor_glow, CAPTION_CHECKBOX_PHOSPHOR_GLOW)
+                            .on_hover_text(TOOLTIP_CHECKBOX_PHOSPHOR_GLOW);
+                        ui.checkbox(
+                            &mut self.phosphor_persistence,
+                            CAPTION_CHECKBOX_PHOSPHOR_PERSISTENCE,
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_PHOSPHOR_PERSISTENCE);
+                        ui.add_enabled(
+                            // Only enabled if phosphor persistence is switched on
+                            self.phosphor_persistence,
+                            Slider::new(
+                                &mut self.phosphor_decay,
+                                MIN_PHOSPHOR_DECAY..=MAX_PHOSPHOR_DECAY,
+                            )
+                            .text(CAPTION_SLIDER_PHOSPHOR_DECAY),
+                        )
+                        .on_hover_text(TOOLTIP_SLIDER_PHOSPHOR_DECAY);
+                        ui.checkbox(
+                            &mut self.lock_aspect_ratio,
+                            CAPTION_CHECKBOX_LOCK_ASPECT_RATIO,
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_LOCK_ASPECT_RATIO);
+                        ui.checkbox(&mut self.show_osd_stats, CAPTION_CHECKBOX_OSD_STATS)
+                            .on_hover_text(TOOLTIP_CHECKBOX_OSD_STATS);
+                        ui.separator();
+                        ui.checkbox(
+                            &mut self.detach_debug_panels,
+                            CAPTION_CHECKBOX_DETACH_DEBUG_PANELS,
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_DETACH_DEBUG_PANELS);
+                        ui.separator();
+                        ui.label(RichText::new(CAPTION_LABEL_FOCUS_LOSS).color(COLOUR_LABEL));
+                        ui.radio_value(
+                            &mut self.focus_loss_action,
+                            FocusLossAction::Continue,
+                            CAPTION_RADIO_FOCUS_LOSS_CONTINUE,
+                        )
+                        .on_hover_text(TOOLTIP_RADIO_FOCUS_LOSS_CONTINUE);
+                        ui.radio_value(
+                            &mut self.focus_loss_action,
+                            FocusLossAction::Pause,
+                            CAPTION_RADIO_FOCUS_LOSS_PAUSE,
+                        )
+                        .on_hover_text(TOOLTIP_RADIO_FOCUS_LOSS_PAUSE);
+                        ui.radio_value(
+                            &mut self.focus_loss_action,
+                            FocusLossAction::Throttle,
+                            CAPTION_RADIO_FOCUS_LOSS_THROTTLE,
+                        )
+                        .on_hover_text(TOOLTIP_RADIO_FOCUS_LOSS_THROTTLE);
+                    },
+                )
+                .response
+                .on_hover_text(TOOLTIP_BUTTON_DISPLAY);
+                // Render the "Audio" drop-down menu holding the sound settings, and delegate any
+                // change to them
+                let previous_audio_config: AudioConfig = self.audio_config;
+                ui.menu_button(
+                    RichText::new(CAPTION_BUTTON_AUDIO).color(COLOUR_BUTTON),
+                    |ui| {
+                        for (waveform, caption) in [
+                            (Waveform::Square, CAPTION_RADIO_WAVEFORM_SQUARE),
+                            (Waveform::Triangle, CAPTION_RADIO_WAVEFORM_TRIANGLE),
+                            (Waveform::Sine, CAPTION_RADIO_WAVEFORM_SINE),
+                            (Waveform::Noise, CAPTION_RADIO_WAVEFORM_NOISE),
+                        ] {
+                            ui.radio_value(&mut self.audio_config.waveform, waveform, caption)
+                                .on_hover_text(TOOLTIP_RADIO_WAVEFORM);
+                        }
+                        ui.separator();
+                        ui.add(
+                            Slider::new(
+                                &mut self.audio_config.frequency,
+                                MIN_AUDIO_FREQUENCY..=MAX_AUDIO_FREQUENCY,
+                            )
+                            .logarithmic(true)
+                            .text(CAPTION_SLIDER_AUDIO_FREQUENCY),
+                        )
+                        .on_hover_text(TOOLTIP_SLIDER_AUDIO_FREQUENCY);
+                        ui.add(
+                            Slider::new(&mut self.audio_config.volume, 0.0..=1.0)
+                                .text(CAPTION_SLIDER_AUDIO_VOLUME),
+                        )
+                        .on_hover_text(TOOLTIP_SLIDER_AUDIO_VOLUME);
+                        ui.checkbox(
+                            &mut self.audio_config.buzzer_filter,
+                            CAPTION_CHECKBOX_BUZZER_FILTER,
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_BUZZER_FILTER);
+                    },
+                )
+                .response
+                .on_hover_text(TOOLTIP_BUTTON_AUDIO);
+                if self.audio_config != previous_audio_config {
+                    self.on_change_audio_config();
+                }
+                // Render the palette theme selector and a colour picker widget for each colour of
+                // the palette (background, foreground and the additional plane colours), aligned
+                // to the right of the panel
+                let mut palette: Palette = self.options.palette;
+                ui.with_layout(egui::Layout::right_to_left(Align::Center), |ui| {
+                    for (colour_index, colour) in palette.colours_mut().iter_mut().enumerate().rev()
+                    {
+                        let [red, green, blue] = *colour;
+                        let mut picked_colour: Color32 = Color32::from_rgb(red, green, blue);
+                        let tooltip: &str = match colour_index {
+                            0 => TOOLTIP_COLOUR_PICKER_BACKGROUND,
+                            1 => TOOLTIP_COLOUR_PICKER_FOREGROUND,
+                            2 => TOOLTIP_COLOUR_PICKER_SECOND_PLANE,
+                            3 => TOOLTIP_COLOUR_PICKER_BOTH_PLANES,
+                            _ => TOOLTIP_COLOUR_PICKER_FURTHER_PLANES,
+                        };
+                        if ui
+                            .color_edit_button_srgba(&mut picked_colour)
+                            .on_hover_text(tooltip)
+                            .changed()
+                        {
+                            *colour = [picked_colour.r(), picked_colour.g(), picked_colour.b()];
+                        }
+                    }
+                    ui.label(RichText::new(CAPTION_LABEL_PALETTE_COLOURS).color(COLOUR_LABEL));
+                    let theme: Option<PaletteTheme> = PaletteTheme::matching(&palette);
+                    ComboBox::from_id_source(ID_PALETTE_THEME_COMBO)
+                        .selected_text(
+                            theme.map_or(CAPTION_COMBO_PALETTE_CUSTOM.to_string(), |theme| {
+                                theme.to_string()
+                            }),
+                        )
+                        .show_ui(ui, |ui| {
+                            for option in PaletteTheme::ALL {
+                                if ui
+                                    .selectable_label(theme == Some(option), option.to_string())
+                                    .clicked()
+                                {
+                                    palette = option.palette();
+                                }
+                            }
+                        })
+                        .response
+                        .on_hover_text(TOOLTIP_COMBO_PALETTE_THEME);
+                    ui.label(RichText::new(CAPTION_LABEL_PALETTE_THEME).color(COLOUR_LABEL));
+                });
+                if palette != self.options.palette {
+                    self.on_change_palette(palette);
+                }
+            });
+            // Some padding at the bottom of the panel
+            ui.add_space(UI_SPACER_BOTTOM);
+        });
+    }
+
+    /// Rendering function to display the footer panel at the top of the Chipolata UI
+    pub(crate) fn render_footer(&mut self, ctx: &egui::Context) {
+        TopBottomPanel::bottom(ID_BOTTOM_PANEL).show(ctx, |ui| {
+            ui.add_space(UI_SPACER_TOP);
+            // If an error has occurred then we render an extra horizontal section at the top
+            // of the footer panel, to display the error message
+            if self.last_error_string != String::default() {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(CAPTION_LABEL_ERROR).color(COLOUR_ERROR));
+                    ui.label(
+                        RichText::new(&self.last_error_string)
+                            .color(COLOUR_ERROR)
+                            .monospace(),
+                    );
+                    // Render the "Copy Crash Report" button (if a report is available) and
+                    // delegate click event
+                    if self.last_crash_report != String::default()
+                        && ui
+                            .button(
+                                RichText::new(CAPTION_BUTTON_COPY_CRASH_REPORT)
+                                    .color(COLOUR_BUTTON),
+                            )
+                            .on_hover_text(TOOLTIP_BUTTON_COPY_CRASH_REPORT)
+                            .clicked()
+                    {
+                        self.on_click_copy_crash_report(ui);
+                    }
+                    // Render the "Save Crash Dump" button (if a dump is available) and delegate
+                    // click event
+                    if self.last_crash_dump.is_some()
+                        && ui
+                            .button(
+                                RichText::new(CAPTION_BUTTON_SAVE_CRASH_DUMP).color(COLOUR_BUTTON),
+                            )
+                            .on_hover_text(TOOLTIP_BUTTON_SAVE_CRASH_DUMP)
+                            .clicked()
+                    {
+                        self.on_click_save_crash_dump();
+                    }
+                    // Render the "Restart as ..." button (if the program evidently requires
+                    // another emulation level) and delegate click event
+                    if let Some(emulation_level) = self.suggested_emulation_level {
+                        if ui
+                            .button(
+                                RichText::new(format!(
+                                    "{} {}",
+                                    CAPTION_BUTTON_RESTART_AS,
+                                    compare::emulation_level_caption(emulation_level)
+                                ))
+                                .color(COLOUR_BUTTON),
+                            )
+                            .on_hover_text(TOOLTIP_BUTTON_RESTART_AS)
+                            .clicked()
+                        {
+                            self.on_click_restart_as_suggested_level();
+                        }
+                    }
+                });
+                ui.separator();
+            }
+            // The entire panel is in horizontal layout (thin strip at bottom of screen)
+            ui.horizontal(|ui| {
+                // If program execution is paused, then render a Play button.
+                // If program execution is paused, then render a Pause button instead.
+                // If program execution is stopped then render a Play button, but in a disabled state
+                match self.execution_state {
+                    ExecutionState::Paused => {
+                        // Render the "Play" button and delegate click event
+                        if ui
+                            .button(RichText::new(CAPTION_BUTTON_RUN).color(COLOUR_BUTTON))
+                            .on_hover_text(TOOLTIP_BUTTON_RUN)
+                            .clicked()
+                        {
+                            self.on_click_play();
+                        }
+                    }
+                    ExecutionState::Running => {
+                        // Render the "Pause" button and delegate click event
+                        if ui
+                            .button(RichText::new(CAPTION_BUTTON_PAUSE).color(COLOUR_BUTTON))
+                            .on_hover_text(TOOLTIP_BUTTON_PAUSE)
+                            .clicked()
+                        {
+                            self.on_click_pause();
+                        }
+                    }
+                    // Render the "Play" button in a disabled state (cannot be clicked)
+                    ExecutionState::Stopped => {
+                        ui.add_enabled(
+                            false,
+                            Button::new(RichText::new(CAPTION_BUTTON_RUN).color(COLOUR_BUTTON)),
+                        )
+                        .on_disabled_hover_text(TOOLTIP_BUTTON_RUN_DISABLED);
+                    }
+                }
+                // Check whether the user can decide to restart execution; this is possible either if
+                // the program is currently executing (regardless of whether running or paused), or if
+                // the program is stopped but a program file path is already specified within the UI.
+                // If the program is stopped and no program file is already known then the button is
+                // disabled, and the user must first load a program
+                let can_restart: bool = match self.execution_state {
+                    ExecutionState::Stopped => self.program_file_path != String::default(),
+                    ExecutionState::Paused | ExecutionState::Running => true,
+                };
+                // Render the "Restart" button if the required conditions are met, and delegate click event
+                if ui
+                    .add_enabled(
+                        can_restart,
+                        Button::new(RichText::new(CAPTION_BUTTON_RESTART).color(COLOUR_BUTTON)),
+                    )
+                    .on_hover_text(TOOLTIP_BUTTON_RESTART)
+                    .on_disabled_hover_text(TOOLTIP_BUTTON_RESTART_DISABLED)
+                    .clicked()
+                {
+                    self.on_click_restart();
+                };
+                // If a program is executing (Running or Paused) then render the "Stop" button and
+                // delegate click event.  If program is already stopped then render the "Stop" button
+                // in a disabled state (cannot be clicked)
+                match self.execution_state {
+                    ExecutionState::Paused | ExecutionState::Running => {
+                        if ui
+                            .button(RichText::new(CAPTION_BUTTON_STOP).color(COLOUR_BUTTON))
+                            .on_hover_text(TOOLTIP_BUTTON_STOP)
+                            .clicked()
+                        {
+                            self.on_click_stop();
+                        };
+                    }
+                    ExecutionState::Stopped => {
+                        ui.add_enabled(
+                            false,
+                            Button::new(RichText::new(CAPTION_BUTTON_STOP).color(COLOUR_BUTTON)),
+                        )
+                        .on_disabled_hover_text(TOOLTIP_BUTTON_STOP_DISABLED);
+                    }
+                }
+                // Render the "Advance Frame" button, enabled only while execution is paused, and
+                // delegate click event
+                if ui
+                    .add_enabled(
+                        self.execution_state == ExecutionState::Paused,
+                        Button::new(
+                            RichText::new(CAPTION_BUTTON_ADVANCE_FRAME).color(COLOUR_BUTTON),
+                        ),
+                    )
+                    .on_hover_text(TOOLTIP_BUTTON_ADVANCE_FRAME)
+                    .on_disabled_hover_text(TOOLTIP_BUTTON_ADVANCE_FRAME_DISABLED)
+                    .clicked()
+                {
+                    self.on_click_advance_frame();
+                }
+                // Render the "Record" button (or, while recording, the "Stop Recording" button),
+                // enabled only while a program is executing, and delegate click event
+                let (record_caption, record_tooltip): (&str, &str) = match self.gif_recorder {
+                    Some(_) => (CAPTION_BUTTON_STOP_RECORDING, TOOLTIP_BUTTON_STOP_RECORDING),
+                    None => (CAPTION_BUTTON_RECORD, TOOLTIP_BUTTON_RECORD),
+                };
+                if ui
+                    .add_enabled(
+                        self.execution_state != ExecutionState::Stopped,
+                        Button::new(RichText::new(record_caption).color(COLOUR_BUTTON)),
+                    )
+                    .on_hover_text(record_tooltip)
+                    .on_disabled_hover_text(TOOLTIP_BUTTON_RECORD_DISABLED)
+                    .clicked()
+                {
+                    self.on_click_record();
+                }
+                // Render the "Slow Motion" toggle and delegate click event
+                if ui
+                    .selectable_label(
+                        self.slow_motion,
+                        RichText::new(CAPTION_TOGGLE_SLOW_MOTION).color(COLOUR_BUTTON),
+                    )
+                    .on_hover_text(TOOLTIP_TOGGLE_SLOW_MOTION)
+                    .clicked()
+                {
+                    self.on_click_slow_motion();
+                }
+                // Render the target processor speed slider as long as the emulation options allow this
+                // to be controlled by the user
+                let old_speed: u64 = self.processor_speed; // temporarily store current speed
+                ui.label(RichText::new(CAPTION_LABEL_PROCESSOR_SPEED).color(COLOUR_LABEL));
+                match self.options.emulation_level {
+                    // If emulation options specify to use variable cycle timing, then the processor
+                    // speed slider must be disabled (as speed is fixed)
+                    EmulationLevel::Chip8 {
+                        memory_limit_2k: _,
+                        variable_cycle_timing: true,
+                    }
+                    | EmulationLevel::Chip8X {
+                        variable_cycle_timing: true,
+                    }
+                    | EmulationLevel::Chip48 {
+                        variable_cycle_timing: true,
+                    }
+                    | EmulationLevel::SuperChip10 {
+                        variable_cycle_timing: true,
+                    }
+                    | EmulationLevel::SuperChip11 {
+                        octo_compatibility_mode: _,
+                        variable_cycle_timing: true,
+                    } => {
+                        // Render the slider, but in a disabled state (value cannot be modified)
+                        ui.add_enabled(
+                            false,
+                            Slider::new(&mut self.processor_speed, old_speed..=old_speed)
+                                .text(CAPTION_PROCESSOR_SPEED_SUFFIX),
+                        )
+                        .on_disabled_hover_text(TOOLTIP_SLIDER_PROCESSOR_SPEED_DISABLED);
+                    }
+                    // Otherwise, render the slider, binding its value directly to the processor_speed
+                    // field of the Chipolata UI struct.  If the value is modified
+                    _ => {
+                        if ui
+                            .add(
+                                Slider::new(&mut self.processor_speed, MIN_SPEED..=MAX_SPEED)
+                                    .text(CAPTION_PROCESSOR_SPEED_SUFFIX),
+                            )
+                            .on_hover_text(TOOLTIP_SLIDER_PROCESSOR_SPEED)
+                            .changed()
+                        {
+                            self.on_changed_speed_slider();
+                        };
+                    }
+                }
+                // Render current execution status and actual reported processor speed, aligned to the
+                // right of the panel
+                ui.with_layout(egui::Layout::right_to_left(Align::Center), |ui| {
+                    let state_colour: Color32 = match self.execution_state {
+                        ExecutionState::Stopped => Color32::RED,
+                        ExecutionState::Paused => Color32::YELLOW,
+                        ExecutionState::Running => Color32::GREEN,
+                    };
+                    ui.label(RichText::new(&self.execution_state.to_string()).color(state_colour));
+                    ui.label(RichText::new(CAPTION_LABEL_EXECUTION_STATUS).color(COLOUR_LABEL));
+                    ui.label(RichText::new(
+                        self.cycles_per_second.to_string() + " " + CAPTION_PROCESSOR_SPEED_SUFFIX,
+                    ));
+                    ui.label(RichText::new(CAPTION_LABEL_CYCLES_PER_SECOND).color(COLOUR_LABEL));
+                    ui.label(RichText::new(format!(
+                        "{:+.1}%",
+                        self.timing_statistics.drift_percent()
+                    )))
+                    .on_hover_text(format!(
+                        "{}\n{}{:?}\n{}{:?}\n{}{:?}",
+                        TOOLTIP_LABEL_TIMING_DRIFT,
+                        CAPTION_TIMING_AVERAGE_CYCLE,
+                        self.timing_statistics.average_cycle_duration,
+                        CAPTION_TIMING_TIMER_JITTER,
+                        self.timing_statistics.timer_jitter,
+                        CAPTION_TIMING_VBLANK_JITTER,
+                        self.timing_statistics.vblank_jitter
+                    ));
+                    ui.label(RichText::new(CAPTION_LABEL_TIMING_DRIFT).color(COLOUR_LABEL));
+                });
+            });
+            ui.add_space(UI_SPACER_BOTTOM);
+        });
+    }
+
+    /// Rendering function to display the modal Options dialogue box
+    pub(crate) fn render_modal_options(&mut self, ctx: &egui::Context) -> Modal {
+        // Initial setup and preparation of helper variables
+        let modal_style: ModalStyle = ModalStyle {
+            default_width: Some(0.), // seems necessary to force window to auto-adjust to sensible width
+            ..Default::default()
+        };
+        let modal = Modal::new(ctx, ID_OPTIONS_MODAL).with_style(&modal_style);
+        let (
+            emulate_chip8,
+            emulate_chip8x,
+            emulate_chip48,
+            emulate_superchip10,
+            emulate_superchip,
+            variable_cycle_timing,
+        ): (bool, bool, bool, bool, bool, bool) = self.check_emulation_level();
+        // Rendering code
+        modal.show(|ui| {
+            // Render overall window title
+            modal.title(ui, RichText::new(TITLE_OPTIONS_WINDOW).color(COLOUR_TITLE));
+            // Render the analysis of the loaded program, if available, warning if it suggests
+            // the program was not written for the selected emulation mode or if instructions
+            // it reaches are not supported in that mode
+            if let Some((program, analysis)) = &self.analysed_program {
+                ui.heading(RichText::new(CAPTION_HEADING_PROGRAM_INFO).color(COLOUR_HEADING));
+                egui::Grid::new(ID_PROGRAM_INFO_GRID).show(ui, |ui| {
+                    ui.label(RichText::new(CAPTION_LABEL_PROGRAM_SIZE).color(COLOUR_LABEL));
+                    ui.label(format!(
+                        "{}{}",
+                        analysis.size_bytes, CAPTION_PROGRAM_SIZE_SUFFIX
+                    ));
+                    ui.end_row();
+                    ui.label(RichText::new(CAPTION_LABEL_PROGRAM_CRC32).color(COLOUR_LABEL));
+                    ui.label(format!("{:08X}", analysis.crc32));
+                    ui.end_row();
+                    ui.label(RichText::new(CAPTION_LABEL_PROGRAM_SHA1).color(COLOUR_LABEL));
+                    ui.label(&analysis.sha1);
+                    ui.end_row();
+                    ui.label(RichText::new(CAPTION_LABEL_PROGRAM_START).color(COLOUR_LABEL));
+                    ui.label(format!("{:#05X}", analysis.inferred_start_address));
+                    ui.end_row();
+                    ui.label(RichText::new(CAPTION_LABEL_PROGRAM_SUPERCHIP).color(COLOUR_LABEL));
+                    ui.label(analysis.superchip_instruction_addresses.len().to_string());
+                    ui.end_row();
+                });
+                let report: CompatibilityReport = check_compatibility(program, &self.new_options);
+                if let Some(first) = report.incompatible_instructions.first() {
+                    ui.label(
+                        RichText::new(format!(
+                            "{} ({} found, the first being {} at {:#05X})",
+                            ERROR_INCOMPATIBLE_INSTRUCTIONS,
+                            report.incompatible_instructions.len(),
+                            first.mnemonic,
+                            first.address
+                        ))
+                        .color(COLOUR_ERROR),
+                    );
+                } else if !analysis.suits_emulation_level(self.new_options.emulation_level) {
+                    ui.label(RichText::new(ERROR_EMULATION_LEVEL_MISMATCH).color(COLOUR_ERROR));
+                }
+                ui.separator();
+            }
+            // Render heading for common/shared option section
+            ui.heading(RichText::new(CAPTION_HEADING_OPTIONS_COMMON).color(COLOUR_HEADING));
+            // Render this portion of the UI as 4-row grid, with descriptive labels in the first
+            // column and corresponding user-editable widgets in the second column
+            egui::Grid::new(ID_OPTIONS_MODAL_GRID).show(ui, |ui| {
+                // Render the target CPU label and DragValue widgets
+                ui.label(RichText::new(CAPTION_LABEL_PROCESSOR_SPEED).color(COLOUR_LABEL));
+                // If emulation options specify to use variable cycle timing, then the processor speed
+                // DragValue widget must be disabled (as speed is fixed to that of the original
+                // COSMAC VIP or HP48 hardware)
+                if variable_cycle_timing {
+                    let fixed_speed: u64 = match emulate_chip8 || emulate_chip8x {
+                        true => COSMAC_VIP_PROCESSOR_SPEED_HERTZ,
+                        false => HP48_PROCESSOR_SPEED_HERTZ,
+                    };
+                    self.new_options.processor_speed_hertz = fixed_speed;
+                    ui.add_enabled(
+                        false,
+                        egui::DragValue::new(&mut self.new_options.processor_speed_hertz)
+                            .clamp_range(fixed_speed..=fixed_speed)
+                            .fixed_decimals(0),
+                    )
+                    .on_disabled_hover_text(TOOLTIP_SLIDER_PROCESSOR_SPEED_DISABLED);
+                // Otherwise, render the DragValue, binding its value directly to the processor_speed_hertz
+                // field in the new Options struct
+                } else {
+                    ui.add(
+                        egui::DragValue::new(&mut self.new_options.processor_speed_hertz)
+                            .clamp_range(MIN_SPEED..=MAX_SPEED)
+                            .fixed_decimals(0)
+                            .speed(DRAGVALUE_QUANTUM),
+                    )
+                    .on_hover_text(TOOLTIP_SLIDER_PROCESSOR_SPEED);
+                }
+                ui.label(RichText::new(CAPTION_PROCESSOR_SPEED_SUFFIX));
+                ui.end_row();
+                // Render the program start address label and DragValue widgets
+                ui.label(RichText::new(CAPTION_LABEL_PROGRAM_ADDRESS).color(COLOUR_LABEL));
+                ui.add(
+                    // Bind the DragValue directly to the program_start_address field in the new Options
+                    // struct
+                    egui::DragValue::new(&mut self.new_options.program_start_address)
+                        .clamp_range(0x0..=0xFFFF)
+                        .hexadecimal(1, false, true),
+                )
+                .on_hover_text(TOOLTIP_SLIDER_PROGRAM_ADDRESS);
+                ui.end_row();
+                // Render the font start address label and DragValue widgets
+                ui.label(RichText::new(CAPTION_LABEL_FONT_ADDRESS).color(COLOUR_LABEL));
+                ui.add(
+                    // Bind the DragValue directly to the font_start_address field in the new Options struct
+                    egui::DragValue::new(&mut self.new_options.font_start_address)
+                        .clamp_range(0x0..=0x1FF)
+                        .hexadecimal(1, false, true),
+                )
+                .on_hover_text(TOOLTIP_SLIDER_FONT_ADDRESS);
+                ui.end_row();
+                // Render the timer frequency label and DragValue widgets
+                ui.label(RichText::new(CAPTION_LABEL_TIMER_FREQUENCY).color(COLOUR_LABEL));
+                ui.add(
+                    // Bind the DragValue directly to the timer_frequency_hertz field in the new
+                    // Options struct
+                    egui::DragValue::new(&mut self.new_options.timer_frequency_hertz)
+                        .clamp_range(1..=MAX_TIMER_FREQUENCY_HERTZ)
+                        .fixed_decimals(0),
+                )
+                .on_hover_text(TOOLTIP_SLIDER_TIMER_FREQUENCY);
+                ui.label(RichText::new(CAPTION_PROCESSOR_SPEED_SUFFIX));
+                ui.end_row();
+                // Render the timer synchronisation label and checkbox, binding this directly to the
+                // cycle_synced_timers field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_TIMER_SYNC).color(COLOUR_LABEL));
+                ui.checkbox(
+                    &mut self.new_options.cycle_synced_timers,
+                    RichText::new(CAPTION_CHECKBOX_TIMER_SYNC).color(COLOUR_CHECKBOX),
+                )
+                .on_hover_text(TOOLTIP_CHECKBOX_TIMER_SYNC);
+                ui.end_row();
+                // Render the timer phase label and checkbox, binding this directly to the
+                // timer_phase_emulation field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_TIMER_PHASE).color(COLOUR_LABEL));
+                ui.checkbox(
+                    &mut self.new_options.timer_phase_emulation,
+                    RichText::new(CAPTION_CHECKBOX_TIMER_PHASE).color(COLOUR_CHECKBOX),
+                )
+                .on_hover_text(TOOLTIP_CHECKBOX_TIMER_PHASE);
+                ui.end_row();
+                // Render the instruction timing label, radio buttons and (where the timing mode
+                // takes a parameter) DragValue widgets, binding these to the timing_mode field in
+                // the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_TIMING_MODE).color(COLOUR_LABEL));
+                ui.horizontal(|ui| {
+                    for (timing_mode, caption, tooltip) in [
+                        (
+                            None,
+                            CAPTION_RADIO_TIMING_MODE_LEVEL,
+                            TOOLTIP_RADIO_TIMING_MODE_LEVEL,
+                        ),
+                        (
+                            Some(TimingMode::Fixed {
+                                instruction_microseconds: DEFAULT_INSTRUCTION_MICROSECONDS,
+                            }),
+                            CAPTION_RADIO_TIMING_MODE_FIXED,
+                            TOOLTIP_RADIO_TIMING_MODE_FIXED,
+                        ),
+                        (
+                            Some(TimingMode::CosmacVariable),
+                            CAPTION_RADIO_TIMING_MODE_COSMAC,
+                            TOOLTIP_RADIO_TIMING_MODE_COSMAC,
+                        ),
+                        (
+                            Some(TimingMode::InstructionsPerFrame {
+                                instructions: DEFAULT_INSTRUCTIONS_PER_FRAME,
+                            }),
+                            CAPTION_RADIO_TIMING_MODE_PER_FRAME,
+                            TOOLTIP_RADIO_TIMING_MODE_PER_FRAME,
+                        ),
+                    ] {
+                        // Compare variants only, so that a chosen parameter value is kept
+                        let selected: bool = self
+                            .new_options
+                            .timing_mode
+                            .map(|mode| std::mem::discriminant(&mode))
+                            == timing_mode.map(|mode| std::mem::discriminant(&mode));
+                        if ui.radio(selected, caption).on_hover_text(tooltip).clicked() && !selected
+                        {
+                            self.new_options.timing_mode = timing_mode;
+                        }
+                    }
+                    match &mut self.new_options.timing_mode {
+                        Some(TimingMode::Fixed {
+                            instruction_microseconds,
+                        }) => {
+                            ui.add(
+                                egui::DragValue::new(instruction_microseconds)
+                                    .clamp_range(1..=MAX_INSTRUCTION_MICROSECONDS),
+                            )
+                            .on_hover_text(TOOLTIP_SLIDER_TIMING_MODE_FIXED);
+                            ui.label(RichText::new(CAPTION_TIMING_MODE_FIXED_SUFFIX));
+                        }
+                        Some(TimingMode::InstructionsPerFrame { instructions }) => {
+                            ui.add(
+                                egui::DragValue::new(instructions)
+                                    .clamp_range(1..=MAX_INSTRUCTIONS_PER_FRAME),
+                            )
+                            .on_hover_text(TOOLTIP_SLIDER_TIMING_MODE_PER_FRAME);
+                            ui.label(RichText::new(CAPTION_TIMING_MODE_PER_FRAME_SUFFIX));
+                        }
+                        _ => (),
+                    }
+                });
+                ui.end_row();
+                // Render the sprite budget label, checkbox and (if the budget is enabled) DragValue
+                // widgets, binding these to the sprites_per_frame field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_SPRITE_BUDGET).color(COLOUR_LABEL));
+                ui.horizontal(|ui| {
+                    let mut limit_sprites: bool = self.new_options.sprites_per_frame.is_some();
+                    if ui
+                        .checkbox(
+                            &mut limit_sprites,
+                            RichText::new(CAPTION_CHECKBOX_SPRITE_BUDGET).color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_SPRITE_BUDGET)
+                        .changed()
+                    {
+                        self.new_options.sprites_per_frame =
+                            limit_sprites.then_some(DEFAULT_SPRITES_PER_FRAME);
+                    }
+                    if let Some(sprites_per_frame) = &mut self.new_options.sprites_per_frame {
+                        ui.add(
+                            egui::DragValue::new(sprites_per_frame)
+                                .clamp_range(1..=MAX_SPRITES_PER_FRAME),
+                        )
+                        .on_hover_text(TOOLTIP_SLIDER_SPRITE_BUDGET);
+                    }
+                });
+                ui.end_row();
+                // Render the memory protection label and checkbox, binding this directly to the
+                // protect_reserved_memory field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_MEMORY_PROTECTION).color(COLOUR_LABEL));
+                ui.checkbox(
+                    &mut self.new_options.protect_reserved_memory,
+                    RichText::new(CAPTION_CHECKBOX_MEMORY_PROTECTION).color(COLOUR_CHECKBOX),
+                )
+                .on_hover_text(TOOLTIP_CHECKBOX_MEMORY_PROTECTION);
+                ui.end_row();
+                // Render the key repeat label and checkbox, binding this directly to the
+                // key_repeat_suppression field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_KEY_REPEAT).color(COLOUR_LABEL));
+                ui.checkbox(
+                    &mut self.new_options.key_repeat_suppression,
+                    RichText::new(CAPTION_CHECKBOX_KEY_REPEAT).color(COLOUR_CHECKBOX),
+                )
+                .on_hover_text(TOOLTIP_CHECKBOX_KEY_REPEAT);
+                ui.end_row();
+                // Render the halt detection label and checkbox, binding this directly to the
+                // halt_detection field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_HALT_DETECTION).color(COLOUR_LABEL));
+                ui.checkbox(
+                    &mut self.new_options.halt_detection,
+                    RichText::new(CAPTION_CHECKBOX_HALT_DETECTION).color(COLOUR_CHECKBOX),
+                )
+                .on_hover_text(TOOLTIP_CHECKBOX_HALT_DETECTION);
+                ui.end_row();
+                // Render the sprite edge mode label and radio buttons, binding these directly to the
+                // sprite_edge_mode field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_SPRITE_EDGES).color(COLOUR_LABEL));
+                ui.horizontal(|ui| {
+                    ui.radio_value(
+                        &mut self.new_options.sprite_edge_mode,
+                        SpriteEdgeMode::Clip,
+                        CAPTION_RADIO_SPRITE_EDGES_CLIP,
+                    )
+                    .on_hover_text(TOOLTIP_RADIO_SPRITE_EDGES_CLIP);
+                    ui.radio_value(
+                        &mut self.new_options.sprite_edge_mode,
+                        SpriteEdgeMode::Wrap,
+                        CAPTION_RADIO_SPRITE_EDGES_WRAP,
+                    )
+                    .on_hover_text(TOOLTIP_RADIO_SPRITE_EDGES_WRAP);
+                });
+                ui.end_row();
+                // Render the key wait mode label and radio buttons, binding these directly to the
+                // key_wait_mode field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_KEY_WAIT).color(COLOUR_LABEL));
+                ui.horizontal(|ui| {
+                    ui.radio_value(
+                        &mut self.new_options.key_wait_mode,
+                        KeyWaitMode::OnRelease,
+                        CAPTION_RADIO_KEY_WAIT_RELEASE,
+                    )
+                    .on_hover_text(TOOLTIP_RADIO_KEY_WAIT_RELEASE);
+                    ui.radio_value(
+                        &mut self.new_options.key_wait_mode,
+                        KeyWaitMode::OnPress,
+                        CAPTION_RADIO_KEY_WAIT_PRESS,
+                    )
+                    .on_hover_text(TOOLTIP_RADIO_KEY_WAIT_PRESS);
+                });
+                ui.end_row();
+                // Render the display rotation label and radio buttons, binding these directly to the
+                // display_orientation field in the new Options struct
+                ui.label(RichText::new(CAPTION_LABEL_DISPLAY_ROTATION).color(COLOUR_LABEL));
+                ui.horizontal(|ui| {
+                    for (orientation, caption) in [
+                        (
+                            DisplayOrientation::Rotate0,
+                            CAPTION_RADIO_DISPLAY_ROTATION_0,
+                        ),
+                        (
+                            DisplayOrientation::Rotate90,
+                            CAPTION_RADIO_DISPLAY_ROTATION_90,
+                        ),
+                        (
+                            DisplayOrientation::Rotate180,
+                            CAPTION_RADIO_DISPLAY_ROTATION_180,
+                        ),
+                        (
+                            DisplayOrientation::Rotate270,
+                            CAPTION_RADIO_DISPLAY_ROTATION_270,
+                        ),
+                    ] {
+                        ui.radio_value(
+                            &mut self.new_options.display_orientation,
+                            orientation,
+                            caption,
+                        )
+                        .on_hover_text(TOOLTIP_RADIO_DISPLAY_ROTATION);
+                    }
+                });
+                ui.end_row();
+            });
+            ui.separator();
+            // Render heading for emulation mode section
+            ui.heading(RichText::new(CAPTION_HEADING_EMULATION_MODE).color(COLOUR_HEADING));
+            // Use selectable labels in a horizontal arrangements for choosing between emulation modes
+            // and delegate click events
+            ui.horizontal(|ui| {
+                if ui
+                    .add(egui::SelectableLabel::new(
+                        emulate_chip8,
+                        CAPTION_RADIO_CHIP8,
+                    ))
+                    .on_hover_text(TOOLTIP_SELECTABLE_CHIP8)
+                    .clicked()
+                {
+                    self.on_click_chip8_label();
+                }
+                if ui
+                    .add(egui::SelectableLabel::new(
+                        emulate_chip8x,
+                        CAPTION_RADIO_CHIP8X,
+                    ))
+                    .on_hover_text(TOOLTIP_SELECTABLE_CHIP8X)
+                    .clicked()
+                {
+                    self.on_click_chip8x_label();
+                }
+                if ui
+                    .add(egui::SelectableLabel::new(
+                        emulate_chip48,
+                        CAPTION_RADIO_CHIP48,
+                    ))
+                    .on_hover_text(TOOLTIP_SELECTABLE_CHIP48)
+                    .clicked()
+                {
+                    self.on_click_chip48_label();
+                }
+                if ui
+                    .add(egui::SelectableLabel::new(
+                        emulate_superchip10,
+                        CAPTION_RADIO_SCHIP10,
+                    ))
+                    .on_hover_text(TOOLTIP_SELECTABLE_SUPERCHIP10)
+                    .clicked()
+                {
+                    self.on_click_superchip10_label();
+                }
+                if ui
+                    .add(egui::SelectableLabel::new(
+                        emulate_superchip,
+                        CAPTION_RADIO_SCHIP,
+                    ))
+                    .on_hover_text(TOOLTIP_SELECTABLE_SUPERCHIP)
+                    .clicked()
+                {
+                    self.on_click_superchip11_label();
+                }
+            });
+            // Depending on which selectable label is active, display any mode-specific options the
+            // use may also configure.  This is done via a mutable reference to the emulation_level
+            // enum instance in the new Options struct, so the user can toggle these options on/off
+            // directly
+            match &mut self.new_options.emulation_level {
+                EmulationLevel::Chip8 {
+                    memory_limit_2k,
+                    variable_cycle_timing,
+                } => {
+                    ui.label(
+                        RichText::new(CAPTION_LABEL_MODE_SPECIFIC_OPTIONS).color(COLOUR_LABEL),
+                    );
+                    ui.group(|ui| {
+                        ui.checkbox(
+                            memory_limit_2k,
+                            RichText::new(CAPTION_CHECKBOX_MEMORY_LIMIT).color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_MEMORY_LIMIT);
+                        ui.checkbox(
+                            &mut self.new_options.chip8_hires_mode,
+                            RichText::new(CAPTION_CHECKBOX_CHIP8_HIRES).color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_CHIP8_HIRES);
+                        ui.checkbox(
+                            &mut self.new_options.vip_memory_image,
+                            RichText::new(CAPTION_CHECKBOX_VIP_MEMORY_IMAGE).color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_VIP_MEMORY_IMAGE);
+                        ui.checkbox(
+                            variable_cycle_timing,
+                            RichText::new(CAPTION_CHECKBOX_CYCLE_TIMING).color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_VARIABLE_CYCLE_TIMING);
+                        ui.checkbox(
+                            &mut self.new_options.accurate_display_timing,
+                            RichText::new(CAPTION_CHECKBOX_ACCURATE_DISPLAY_TIMING)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_ACCURATE_DISPLAY_TIMING);
+                        ui.checkbox(
+                            &mut self.new_options.vip_random_generator,
+                            RichText::new(CAPTION_CHECKBOX_VIP_RANDOM_GENERATOR)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_VIP_RANDOM_GENERATOR);
+                    });
+                }
+                EmulationLevel::Chip8X {
+                    variable_cycle_timing,
+                } => {
+                    ui.label(
+                        RichText::new(CAPTION_LABEL_MODE_SPECIFIC_OPTIONS).color(COLOUR_LABEL),
+                    );
+                    ui.group(|ui| {
+                        ui.checkbox(
+                            variable_cycle_timing,
+                            RichText::new(CAPTION_CHECKBOX_CYCLE_TIMING).color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_VARIABLE_CYCLE_TIMING);
+                        ui.checkbox(
+                            &mut self.new_options.accurate_display_timing,
+                            RichText::new(CAPTION_CHECKBOX_ACCURATE_DISPLAY_TIMING)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_ACCURATE_DISPLAY_TIMING);
+                        ui.checkbox(
+                            &mut self.new_options.vip_random_generator,
+                            RichText::new(CAPTION_CHECKBOX_VIP_RANDOM_GENERATOR)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_VIP_RANDOM_GENERATOR);
+                    });
+                }
+                EmulationLevel::Chip48 {
+                    variable_cycle_timing,
+                } => {
+                    ui.label(
+                        RichText::new(CAPTION_LABEL_MODE_SPECIFIC_OPTIONS).color(COLOUR_LABEL),
+                    );
+                    ui.group(|ui| {
+                        ui.checkbox(
+                            variable_cycle_timing,
+                            RichText::new(CAPTION_CHECKBOX_HP48_CYCLE_TIMING)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_HP48_VARIABLE_CYCLE_TIMING);
+                        ui.checkbox(
+                            &mut self.new_options.lowres_display_wait,
+                            RichText::new(CAPTION_CHECKBOX_LOWRES_DISPLAY_WAIT)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_LOWRES_DISPLAY_WAIT);
+                    });
+                }
+                EmulationLevel::SuperChip10 {
+                    variable_cycle_timing,
+                } => {
+                    ui.label(
+                        RichText::new(CAPTION_LABEL_MODE_SPECIFIC_OPTIONS).color(COLOUR_LABEL),
+                    );
+                    ui.group(|ui| {
+                        ui.checkbox(
+                            variable_cycle_timing,
+                            RichText::new(CAPTION_CHECKBOX_HP48_CYCLE_TIMING)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_HP48_VARIABLE_CYCLE_TIMING);
+                        ui.checkbox(
+                            &mut self.new_options.lowres_display_wait,
+                            RichText::new(CAPTION_CHECKBOX_LOWRES_DISPLAY_WAIT)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_LOWRES_DISPLAY_WAIT);
+                        ui.checkbox(
+                            &mut self.new_options.resize_low_resolution_display,
+                            RichText::new(CAPTION_CHECKBOX_RESIZE_LOWRES_DISPLAY)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_RESIZE_LOWRES_DISPLAY);
+                    });
+                }
+                EmulationLevel::SuperChip11 {
+                    octo_compatibility_mode,
+                    variable_cycle_timing,
+                } => {
+                    ui.label(
+                        RichText::new(CAPTION_LABEL_MODE_SPECIFIC_OPTIONS).color(COLOUR_LABEL),
+                    );
+                    ui.group(|ui| {
+                        ui.checkbox(
+                            octo_compatibility_mode,
+                            RichText::new(CAPTION_CHECKBOX_OCTO_COMPATIBILITY)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_OCTO_COMPATIBILITY);
+                        ui.checkbox(
+                            variable_cycle_timing,
+                            RichText::new(CAPTION_CHECKBOX_HP48_CYCLE_TIMING)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_HP48_VARIABLE_CYCLE_TIMING);
+                        ui.checkbox(
+                            &mut self.new_options.lowres_display_wait,
+                            RichText::new(CAPTION_CHECKBOX_LOWRES_DISPLAY_WAIT)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_LOWRES_DISPLAY_WAIT);
+                        ui.checkbox(
+                            &mut self.new_options.resize_low_resolution_display,
+                            RichText::new(CAPTION_CHECKBOX_RESIZE_LOWRES_DISPLAY)
+                                .color(COLOUR_CHECKBOX),
+                        )
+                        .on_hover_text(TOOLTIP_CHECKBOX_RESIZE_LOWRES_DISPLAY);
+                    });
+                }
+            };
+            ui.separator();
+            // Render heading for load and save button section
+            ui.heading(RichText::new(CAPTION_HEADING_OPTIONS_LOAD_SAVE).color(COLOUR_HEADING));
+            // The buttons are rendered in a horizontal layout
+            ui.horizontal(|ui| {
+                // Render the "Load From File" button and delegate click event
+                if ui
+                    .button(RichText::new(CAPTION_BUTTON_LOAD_OPTIONS).color(COLOUR_BUTTON))
+                    .on_hover_text(TOOLTIP_BUTTON_LOAD_OPTIONS)
+                    .clicked()
+                {
+                    self.on_click_load_options();
+                }
+                // Render the "Load From File" button and delegate click event
+                if ui
+                    .button(RichText::new(CAPTION_BUTTON_SAVE_OPTIONS).color(COLOUR_BUTTON))
+                    .on_hover_text(TOOLTIP_BUTTON_SAVE_OPTIONS)
+                    .clicked()
+                {
+                    self.on_click_save_options();
+                }
+            });
+            // Render bottom of dialogue box, with buttons to close modal window
+            modal.buttons(ui, |ui| {
+                // If execution is stopped and no error message is present, then this must be
+                // the first time the modal dialogue has been opened i.e. corresponds to the user
+                // first loading a program.  In this situation it should not be possible for the user
+                // to cancel - they MUST select a set of Options in order to proceed, otherwise it
+                // is ambiguous as to in which mode Chipolata should run.  So, in this situation,
+                // the "Cancel" button is not rendered
+                if self.execution_state != ExecutionState::Stopped
+                    || self.last_error_string != String::default()
+                {
+                    if modal
+                        .button(ui, CAPTION_BUTTON_CANCEL)
+                        .on_hover_text(TOOLTIP_BUTTON_OPTIONS_CANCEL)
+                        .clicked()
+                    {
+                        self.on_click_cancel_options();
+                    };
+                }
+                // Render the "OK" button and delegate click event
+                if modal
+                    .button(ui, CAPTION_BUTTON_OK)
+                    .on_hover_text(TOOLTIP_BUTTON_OPTIONS_OK)
+                    .clicked()
+                {
+                    self.on_click_ok_options();
+                };
+            });
+        });
+        modal
+    }
+
+    /// Rendering function to display the playlist panel at the right of the Chipolata UI
+    pub(crate) fn render_playlist_panel(&mut self, ctx: &egui::Context) {
+        let mut clicked_position: Option<usize> = None;
+        SidePanel::right(ID_PLAYLIST_PANEL)
+            .default_width(PLAYLIST_PANEL_WIDTH)
+            .show(ctx, |ui| {
+                ui.add_space(UI_SPACER_TOP);
+                ui.heading(RichText::new(CAPTION_HEADING_PLAYLIST).color(COLOUR_HEADING));
+                // Render the playlist interval widget
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(CAPTION_LABEL_PLAYLIST_INTERVAL).color(COLOUR_LABEL));
+                    ui.add(
+                        egui::DragValue::new(&mut self.playlist_interval)
+                            .clamp_range(MIN_PLAYLIST_INTERVAL..=MAX_PLAYLIST_INTERVAL)
+                            .suffix(CAPTION_PLAYLIST_INTERVAL_SUFFIX),
+                    )
+                    .on_hover_text(TOOLTIP_DRAGVALUE_PLAYLIST_INTERVAL);
+                });
+                // Render the "Next" and "Close" buttons and delegate click events
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(RichText::new(CAPTION_BUTTON_PLAYLIST_NEXT).color(COLOUR_BUTTON))
+                        .on_hover_text(TOOLTIP_BUTTON_PLAYLIST_NEXT)
+                        .clicked()
+                    {
+                        self.on_click_playlist_next();
+                    }
+                    if ui
+                        .button(RichText::new(CAPTION_BUTTON_PLAYLIST_CLOSE).color(COLOUR_BUTTON))
+                        .on_hover_text(TOOLTIP_BUTTON_PLAYLIST_CLOSE)
+                        .clicked()
+                    {
+                        self.on_click_playlist_close();
+                    }
+                });
+                ui.separator();
+                // Render a selectable label for each ROM in the playlist, highlighting the current one
+                ScrollArea::vertical().show(ui, |ui| {
+                    for (position, path) in self.playlist.iter().enumerate() {
+                        let name: String = match path.file_stem() {
+                            Some(stem) => stem.to_string_lossy().to_string(),
+                            None => path.display().to_string(),
+                        };
+                        if ui
+                            .add(egui::SelectableLabel::new(
+                                position == self.playlist_position,
+                                name,
+                            ))
+                            .clicked()
+                        {
+                            clicked_position = Some(position);
+                        }
+                    }
+                });
+            });
+        // Delegate any playlist entry click event once the playlist is no longer borrowed
+        if let Some(position) = clicked_position {
+            self.on_click_playlist_entry(position);
+        }
+    }
+
+    /// Rendering function to draw the window listing the CHIP-8 ROM entries within a newly chosen
+    /// zip archive, from which the user picks the program to load
+    pub(crate) fn render_archive_selection_window(&mut self, ctx: &egui::Context) {
+        let mut clicked_entry: Option<String> = None;
+        let mut cancel_clicked: bool = false;
+        if let Some((_, entries)) = &self.archive_selection {
+            egui::Window::new(TITLE_ARCHIVE_ENTRY_WINDOW)
+                .collapsible(false)
+                .resizable(false)
+                .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+                .show(ctx, |ui| {
+                    // Render a selectable label for each ROM entry in the archive
+                    ScrollArea::vertical().show(ui, |ui| {
+                        for entry in entries {
+                            if ui
+                                .add(egui::SelectableLabel::new(false, entry.as_str()))
+                                .clicked()
+                            {
+                                clicked_entry = Some(entry.clone());
+                            }
+                        }
+                    });
+                    ui.separator();
+                    // Render the "Cancel" button and delegate click event
+                    cancel_clicked = ui
+                        .button(RichText::new(CAPTION_BUTTON_ARCHIVE_CANCEL).color(COLOUR_BUTTON))
+                        .clicked();
+                });
+        }
+        // Delegate any click event once the archive entries are no longer borrowed
+        if let Some(entry) = clicked_entry {
+            self.on_click_archive_entry(entry);
+        } else if cancel_clicked {
+            self.on_click_archive_cancel();
+        }
+    }
+
+    /// Rendering function to redraw the Chipolata frame buffer.  The frame buffer is converted to a
+    /// texture (one texel per Chipolata pixel) which is scaled to the available space using the
+    /// selected filter, then any enabled CRT-style effects and on-screen display messages are
+    /// painted over the top.  In compare
+    /// mode the space is split in two, with the compare instance's frame buffer drawn on the right
+    /// and each display captioned with its emulation level.
+    pub(crate) fn render_chipolata_frame_buffer(
+        &mut self,
+        ctx: &egui::Context,
+        frame_buffer: &chipolata::Display,
+    ) {
+        let texture_options: TextureOptions = match self.display_filter {
+            DisplayFilter::Sharp => TextureOptions::NEAREST,
+            DisplayFilter::Smooth => TextureOptions::LINEAR,
+        };
+        // The image need only be rebuilt if the frame buffer may have changed since it was last
+        // rendered, or a setting affecting its appearance has changed
+        let appearance: DisplayAppearance = (
+            self.options.palette,
+            self.options.display_orientation,
+            self.display_filter,
+        );
+        let (texture_id, image_size): (TextureId, [usize; 2]) = match &self.display_texture {
+            Some(texture)
+                if !self.display_changed && self.display_appearance == Some(appearance) =>
+            {
+                (texture.id(), texture.size())
+            }
+            _ => {
+                // Build the image and upload it to the GPU, keeping the displayed pixel colours
+                // for use by phosphor persistence next frame
+                let image: ColorImage =
+                    self.build_display_image(frame_buffer, &self.persistence_buffer);
+                let image_size: [usize; 2] = image.size;
+                self.persistence_buffer = image.pixels.clone();
+                self.display_appearance = Some(appearance);
+                self.display_changed = false;
+                let texture_id: TextureId = upload_display_texture(
+                    ctx,
+                    &mut self.display_texture,
+                    ID_DISPLAY_TEXTURE,
+                    image,
+                    texture_options,
+                );
+                (texture_id, image_size)
+            }
+        };
+        // Build the on-screen display overlay and, unless there is nothing to show, upload this too
+        let overlay: Overlay = self.build_osd_overlay(image_size);
+        let osd_texture_id: Option<TextureId> = match overlay.is_empty() {
+            true => None,
+            false => Some(upload_display_texture(
+                ctx,
+                &mut self.osd_texture,
+                ID_OSD_TEXTURE,
+                build_osd_image(&overlay),
+                TextureOptions::NEAREST,
+            )),
+        };
+        // Likewise for the compare instance, if it has reported a frame buffer
+        let mut compare_texture: Option<(TextureId, [usize; 2])> = None;
+        if let Some(mut compare_instance) = self.compare_instance.take() {
+            if let Some(compare_frame_buffer) = &compare_instance.frame_buffer {
+                let image: ColorImage = self.build_display_image(
+                    compare_frame_buffer,
+                    &compare_instance.persistence_buffer,
+                );
+                let compare_image_size: [usize; 2] = image.size;
+                compare_instance.persistence_buffer = image.pixels.clone();
+                let compare_texture_id: TextureId = upload_display_texture(
+                    ctx,
+                    &mut compare_instance.display_texture,
+                    ID_COMPARE_DISPLAY_TEXTURE,
+                    image,
+                    texture_options,
+                );
+                compare_texture = Some((compare_texture_id, compare_image_size));
+            }
+            self.compare_instance = Some(compare_instance);
+        }
+        // Render this as a central panel, taking up all remaining space around the header and footer panels
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let painter = ui.painter();
+            let available_rect: Rect = ui.max_rect();
+            match (&self.compare_emulation_level, &self.compare_instance) {
+                (Some(compare_emulation_level), Some(compare_instance)) => {
+                    // Split the space into left and right halves, each with a caption strip at the top
+                    let centre_x: f32 = available_rect.center().x;
+                    let left_rect: Rect = Rect::from_min_max(
+                        available_rect.min,
+                        Pos2::new(centre_x, available_rect.max.y),
+                    );
+                    let right_rect: Rect = Rect::from_min_max(
+                        Pos2::new(centre_x, available_rect.min.y),
+                        available_rect.max,
+                    );
+                    let left_rect: Rect = paint_compare_caption(
+                        painter,
+                        left_rect,
+                        compare::emulation_level_caption(self.options.emulation_level),
+                        &self.last_error_string,
+                    );
+                    let right_rect: Rect = paint_compare_caption(
+                        painter,
+                        right_rect,
+                        compare::emulation_level_caption(*compare_emulation_level),
+                        &compare_instance.last_error_string,
+                    );
+                    self.paint_display(painter, left_rect, texture_id, image_size, osd_texture_id);
+                    if let Some((compare_texture_id, compare_image_size)) = compare_texture {
+                        self.paint_display(
+                            painter,
+                            right_rect,
+                            compare_texture_id,
+                            compare_image_size,
+                            None,
+                        );
+                    }
+                }
+                _ => self.paint_display(
+                    painter,
+                    available_rect,
+                    texture_id,
+                    image_size,
+                    osd_texture_id,
+                ),
+            }
+        });
+    }
+
+    /// Helper function that builds an image from a frame buffer, retrieving the colour index of
+    /// each pixel from the bit planes to determine which planes (if any) the pixel is "on" in, and
+    /// setting it to the colour of that index in the palette.  In
+    /// CHIP-8X mode the colours are instead taken from the colour zones and background colour of
+    /// the frame buffer (unless the display is rotated, as the zones are not)
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_buffer` - the frame buffer to convert
+    /// * `persistence_buffer` - the pixel colours displayed last frame, for phosphor persistence
+    pub(crate) fn build_display_image(
+        &self,
+        frame_buffer: &chipolata::Display,
+        persistence_buffer: &[Color32],
+    ) -> ColorImage {
+        // Present the frame buffer in the orientation requested by the options
+        let frame_buffer: &chipolata::Display =
+            &frame_buffer.rotated(self.options.display_orientation);
+        let row_pixels: usize = frame_buffer.get_row_size_bytes() * 8;
+        let column_pixels: usize = frame_buffer.get_column_size_pixels();
+        let use_colour_zones: bool = frame_buffer.has_colour_zones()
+            && matches!(
+                self.options.display_orientation,
+                DisplayOrientation::Rotate0
+            );
+        let pixels: Vec<Color32> = frame_buffer
+            .get_colour_indices()
+            .iter()
+            .enumerate()
+            .map(
+                |(index, colour_index)| match (use_colour_zones, colour_index) {
+                    (true, 0) => frame_buffer
+                        .get_background_colour()
+                        .map_or(palette_colour(&self.options.palette, 0), |colour| {
+                            COLOURS_CHIP8X[colour as usize]
+                        }),
+                    (true, _) => frame_buffer
+                        .get_zone_colour(index % row_pixels, index / row_pixels)
+                        .map_or(palette_colour(&self.options.palette, 1), |colour| {
+                            COLOURS_CHIP8X[colour as usize]
+                        }),
+                    (false, &colour_index) => palette_colour(&self.options.palette, colour_index),
+                },
+            )
+            .collect();
+        // If phosphor persistence is enabled then, rather than switching off immediately, pixels
+        // fade from the colour displayed last frame towards their new colour (lit pixels are
+        // always shown at full brightness); this hides the flicker caused by sprites being erased
+        // and redrawn
+        let pixels: Vec<Color32> =
+            match self.phosphor_persistence && persistence_buffer.len() == pixels.len() {
+                true => pixels
+                    .iter()
+                    .zip(frame_buffer.get_colour_indices().iter())
+                    .zip(persistence_buffer.iter())
+                    .map(|((&colour, &colour_index), &previous)| match colour_index {
+                        0 => blend_colours(colour, previous, self.phosphor_decay),
+                        _ => colour,
+                    })
+                    .collect(),
+                false => pixels,
+            };
+        ColorImage {
+            size: [row_pixels, column_pixels],
+            pixels,
+        }
+    }
+
+    /// Helper function that builds the on-screen display overlay for the main display: the
+    /// execution status (if paused or fast-forwarding) at the top-left, the frame rate and
+    /// processor speed at the top-right if enabled, and any transient message along the bottom.
+    /// The overlay is [OSD_RESOLUTION_SCALE] times the resolution of the display image.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_size` - the size of the display image in Chipolata pixels (width, height)
+    fn build_osd_overlay(&mut self, image_size: [usize; 2]) -> Overlay {
+        let [row_pixels, column_pixels]: [usize; 2] = image_size;
+        let mut overlay: Overlay = Overlay::new(
+            row_pixels * OSD_RESOLUTION_SCALE,
+            column_pixels * OSD_RESOLUTION_SCALE,
+        );
+        if self.last_processor_status == ProcessorStatus::Halted {
+            overlay.draw_text(OSD_MARGIN, OSD_MARGIN, CAPTION_OSD_PROGRAM_ENDED);
+        } else if self.execution_state == ExecutionState::Paused {
+            overlay.draw_text(OSD_MARGIN, OSD_MARGIN, CAPTION_OSD_PAUSED);
+        } else if self.fast_forward_held {
+            overlay.draw_text(OSD_MARGIN, OSD_MARGIN, CAPTION_OSD_FAST_FORWARD);
+        }
+        if self.show_osd_stats {
+            let stats: String = format!(
+                "{} {} {} {}",
+                self.frames_per_second,
+                CAPTION_OSD_FPS_SUFFIX,
+                self.cycles_per_second,
+                CAPTION_OSD_SPEED_SUFFIX
+            );
+            let x: usize = overlay
+                .get_width()
+                .saturating_sub(Overlay::text_width(&stats) + OSD_MARGIN);
+            overlay.draw_text(x, OSD_MARGIN, &stats);
+        }
+        // Transient messages are discarded once they have been shown for long enough
+        if let Some((message, shown_at)) = &self.osd_message {
+            match shown_at.elapsed() < OSD_MESSAGE_DURATION {
+                true => {
+                    let y: usize = overlay
+                        .get_height()
+                        .saturating_sub(OSD_GLYPH_SIZE_PIXELS + OSD_MARGIN);
+                    overlay.draw_text_centred(y, message);
+                }
+                false => self.osd_message = None,
+            }
+        }
+        overlay
+    }
+
+    /// Helper function that paints a display texture into the specified screen area, then paints
+    /// any enabled CRT-style effects and the on-screen display overlay over the top
+    ///
+    /// # Arguments
+    ///
+    /// * `painter` - the painter with which to draw
+    /// * `available_rect` - the screen area to draw into
+    /// * `texture_id` - the display texture
+    /// * `image_size` - the size of the display texture in Chipolata pixels (width, height)
+    /// * `osd_texture_id` - the on-screen display overlay texture, if there is anything to show
+    fn paint_display(
+        &self,
+        painter: &Painter,
+        available_rect: Rect,
+        texture_id: TextureId,
+        image_size: [usize; 2],
+        osd_texture_id: Option<TextureId>,
+    ) {
+        let [row_pixels, column_pixels]: [usize; 2] = image_size;
+        // Determine the screen area to draw into; if the aspect ratio is locked then this is the
+        // largest centred rectangle with the same proportions as the Chipolata display
+        let display_rect: Rect = match self.lock_aspect_ratio {
+            true => {
+                let scale: f32 = (available_rect.width() / row_pixels as f32)
+                    .min(available_rect.height() / column_pixels as f32);
+                Rect::from_center_size(
+                    available_rect.center(),
+                    Vec2::new(row_pixels as f32 * scale, column_pixels as f32 * scale),
+                )
+            }
+            false => available_rect,
+        };
+        // Determine the number of screen pixels used to represent each Chipolata pixel
+        let pixel_width: f32 = display_rect.width() / row_pixels as f32;
+        let pixel_height: f32 = display_rect.height() / column_pixels as f32;
+        let uv: Rect = Rect::from_min_max(Pos2::ZERO, Pos2::new(1., 1.));
+        // Draw the display texture itself
+        painter.image(texture_id, display_rect, uv, Color32::WHITE);
+        // Draw the phosphor glow, as a translucent copy of the texture expanded in all directions
+        if self.show_phosphor_glow {
+            let spread: Vec2 = Vec2::new(
+                pixel_width * PHOSPHOR_GLOW_SPREAD,
+                pixel_height * PHOSPHOR_GLOW_SPREAD,
+            );
+            painter.image(
+                texture_id,
+                display_rect.expand2(spread),
+                uv,
+                COLOUR_PHOSPHOR_GLOW,
+            );
+        }
+        // Darken the lower portion of each Chipolata pixel row to simulate CRT scanlines
+        if self.show_scanlines {
+            for j in 0..column_pixels {
+                let row_bottom: f32 = display_rect.min.y + (j + 1) as f32 * pixel_height;
+                painter.rect_filled(
+                    Rect::from_x_y_ranges(
+                        display_rect.x_range(),
+                        (row_bottom - pixel_height * SCANLINE_HEIGHT_RATIO)..=row_bottom,
+                    ),
+                    egui::Rounding::none(),
+                    COLOUR_SCANLINE,
+                );
+            }
+        }
+        // Draw lines between each row and column of Chipolata pixels
+        if self.show_pixel_grid {
+            let stroke: Stroke = Stroke::new(1., COLOUR_PIXEL_GRID);
+            for i in 1..row_pixels {
+                painter.vline(
+                    display_rect.min.x + i as f32 * pixel_width,
+                    display_rect.y_range(),
+                    stroke,
+                );
+            }
+            for j in 1..column_pixels {
+                painter.hline(
+                    display_rect.x_range(),
+                    display_rect.min.y + j as f32 * pixel_height,
+                    stroke,
+                );
+            }
+        }
+        // Draw the on-screen display overlay, stretched over the whole display
+        if let Some(osd_texture_id) = osd_texture_id {
+            painter.image(osd_texture_id, display_rect, uv, Color32::WHITE);
+        }
+    }
+
+    /// Rendering function for the "welcome screen" displayed when no program is executing
+    pub(crate) fn render_welcome_screen(&self, ctx: &egui::Context) {
+        // Render this as a central panel, taking up all remaining space around the header and footer panels
+        egui::CentralPanel::default().show(ctx, |ui| {
+            // This screen consists of two large containers, side-by-side in a horizontal arrangement
+            // The right-hand container is itself split into two groups in a vertical arrangement
+            ui.horizontal(|ui| {
+                // The left-hand container displays the "getting started" text, as a series of vertical labels
+                ui.group(|ui| {
+                    ui.vertical(|ui| {
+                        // Render heading for "getting started" section
+                        ui.heading(CAPTION_HEADING_GETTING_STARTED);
+                        // Render all the body text labels, separated by spacing as required
+                        ui.add_space(UI_SPACER_TEXT);
+                        ui.label(CAPTION_LABEL_GETTING_STARTED_1);
+                        ui.add_space(UI_SPACER_TEXT);
+                        ui.label(CAPTION_LABEL_GETTING_STARTED_2);
+                        ui.add_space(UI_SPACER_TEXT);
+                        ui.label(CAPTION_LABEL_GETTING_STARTED_3);
+                        ui.add_space(UI_SPACER_TEXT);
+                        ui.label(CAPTION_LABEL_GETTING_STARTED_4);
+                        ui.add_space(UI_SPACER_TEXT);
+                        ui.label(CAPTION_LABEL_GETTING_STARTED_5);
+                        ui.add_space(UI_SPACER_TEXT);
+                        ui.label(CAPTION_LABEL_GETTING_STARTED_6);
+                    });
+                });
+                ui.add_space(UI_SPACER_TEXT);
+                // The right-hand container holds two groups in a vertical arrangement.  The top group displays
+                // the "keyboard controls" information
+                ui.vertical(|ui| {
+                    ui.group(|ui| {
+                        ui.vertical(|ui| {
+                            // Render heading for "getting started" section
+                            ui.heading(CAPTION_HEADING_KEYBOARD_CONTROLS);
+                            // Render introductory text
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.label(CAPTION_LABEL_KEYBOARD_CONTROLS_1);
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.horizontal(|ui| {
+                                ui.add_space(UI_SPACER_HORIZONTAL);
+                                // Render a representation of the original CHIP-8 keypad, as a grid of characters
+                                egui::Grid::new(ID_KEYBOARD_CONTROLS_GRID_1)
+                                    .striped(true)
+                                    .show(ui, |ui| {
+                                        ui.label("1");
+                                        ui.label("2");
+                                        ui.label("3");
+                                        ui.label("C");
+                                        ui.end_row();
+                                        ui.label("4");
+                                        ui.label("5");
+                                        ui.label("6");
+                                        ui.label("D");
+                                        ui.end_row();
+                                        ui.label("7");
+                                        ui.label("8");
+                                        ui.label("9");
+                                        ui.label("E");
+                                        ui.end_row();
+                                        ui.label("A");
+                                        ui.label("0");
+                                        ui.label("B");
+                                        ui.label("F");
+                                        ui.end_row();
+                                    });
+                            });
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.label(CAPTION_LABEL_KEYBOARD_CONTROLS_2);
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.horizontal(|ui| {
+                                ui.add_space(UI_SPACER_HORIZONTAL);
+                                // Render a representation of the Chipolata keys, as a grid of characters
+                                egui::Grid::new(ID_KEYBOARD_CONTROLS_GRID_2)
+                                    .striped(true)
+                                    .show(ui, |ui| {
+                                        ui.label("1");
+                                        ui.label("2");
+                                        ui.label("3");
+                                        ui.label("4");
+                                        ui.end_row();
+                                        ui.label("Q");
+                                        ui.label("W");
+                                        ui.label("E");
+                                        ui.label("R");
+                                        ui.end_row();
+                                        ui.label("A");
+                                        ui.label("S");
+                                        ui.label("D");
+                                        ui.label("F");
+                                        ui.end_row();
+                                        ui.label("Z");
+                                        ui.label("X");
+                                        ui.label("C");
+                                        ui.label("V");
+                                        ui.end_row();
+                                    });
+                            });
+                        });
+                    });
+                    // The bottom group in the right-hand container displays the "about" information
+                    ui.group(|ui| {
+                        ui.vertical(|ui| {
+                            // Render heading for "about" section
+                            ui.heading(CAPTION_HEADING_ABOUT);
+                            ui.add_space(UI_SPACER_TEXT);
+                            // Render the current version number
+                            ui.horizontal(|ui| {
+                                ui.label(CAPTION_LABEL_ABOUT_1);
+                                ui.label(
+                                    RichText::new(&format!("v{}", VERSION)).color(COLOUR_LABEL),
+                                );
+                            });
+                            // Render a link to the GitHub page
+                            ui.label(CAPTION_LABEL_ABOUT_2);
+                            ui.add_space(UI_SPACER_TEXT);
+                            ui.add(egui::Hyperlink::new(LINK_GITHUB));
+                        });
+                    });
+                });
+            });
+        });
+    }
+}
+
+/// Helper function that builds an image from an on-screen display overlay, in which text is drawn
+/// in the default foreground colour on a translucent backing box and the rest is transparent
+///
+/// # Arguments
+///
+/// * `overlay` - the overlay to convert
+fn build_osd_image(overlay: &Overlay) -> ColorImage {
+    ColorImage {
+        size: [overlay.get_width(), overlay.get_height()],
+        pixels: overlay
+            .get_pixels()
+            .iter()
+            .map(|pixel| match pixel {
+                OverlayPixel::Transparent => Color32::TRANSPARENT,
+                OverlayPixel::Background => COLOUR_OSD_BACKING,
+                OverlayPixel::Foreground => COLOUR_OSD_TEXT,
+            })
+            .collect(),
+    }
+}
+
+/// Helper function that uploads a display image to the GPU, re-using the existing texture where
+/// there is one, and returns the texture's ID
+///
+/// # Arguments
+///
+/// * `ctx` - the egui context
+/// * `texture` - the texture to re-use, if already created
+/// * `name` - the name with which to create the texture
+/// * `image` - the image to upload
+/// * `texture_options` - the filter with which the texture is scaled
+fn upload_display_texture(
+    ctx: &egui::Context,
+    texture: &mut Option<TextureHandle>,
+    name: &str,
+    image: ColorImage,
+    texture_options: TextureOptions,
+) -> TextureId {
+    match texture {
+        Some(texture) => {
+            texture.set(image, texture_options);
+            texture.id()
+        }
+        None => texture
+            .insert(ctx.load_texture(name, image, texture_options))
+            .id(),
+    }
+}
+
+/// Helper function that paints the caption identifying a display in compare mode (followed by the
+/// error message, if the instance has crashed) along the top of the specified screen area,
+/// returning the remainder of the area for the display itself
+///
+/// # Arguments
+///
+/// * `painter` - the painter with which to draw
+/// * `rect` - the screen area given to the display
+/// * `caption` - the caption identifying the display
+/// * `error_string` - the instance's last error string, if any
+fn paint_compare_caption(painter: &Painter, rect: Rect, caption: &str, error_string: &str) -> Rect {
+    let caption_bottom: f32 = rect.min.y + COMPARE_CAPTION_HEIGHT;
+    let caption_rect: Rect = Rect::from_min_max(rect.min, Pos2::new(rect.max.x, caption_bottom));
+    let display_rect: Rect = Rect::from_min_max(Pos2::new(rect.min.x, caption_bottom), rect.max);
+    let (text, colour): (String, Color32) = match error_string.is_empty() {
+        true => (caption.to_string(), COLOUR_LABEL),
+        false => (format!("{}: {}", caption, error_string), COLOUR_ERROR),
+    };
+    painter.text(
+        caption_rect.center(),
+        Align2::CENTER_CENTER,
+        text,
+        FontId::proportional(COMPARE_CAPTION_FONT_SIZE),
+        colour,
+    );
+    display_rect
+}
+
+/// Helper function that blends two colours, returning `colour` mixed with the given proportion of
+/// `previous` (so a proportion of 0.0 returns `colour` unchanged)
+///
+/// # Arguments
+///
+/// * `colour` - the new colour
+/// * `previous` - the colour being faded out
+/// * `proportion` - the proportion of `previous` to retain (valid range 0.0 to 1.0 inclusive)
+fn blend_colours(colour: Color32, previous: Color32, proportion: f32) -> Color32 {
+    let blend = |new: u8, old: u8| -> u8 {
+        (new as f32 * (1. - proportion) + old as f32 * proportion).round() as u8
+    };
+    Color32::from_rgba_premultiplied(
+        blend(colour.r(), previous.r()),
+        blend(colour.g(), previous.g()),
+        blend(colour.b(), previous.b()),
+        blend(colour.a(), previous.a()),
+    )
+}
+
+/// Helper function that returns the colour with which pixels of the specified display colour
+/// index are rendered in the passed palette
+///
+/// # Arguments
+///
+/// * `palette` - the palette in which the display is rendered
+/// * `colour_index` - the display colour index of the pixel
+fn palette_colour(palette: &Palette, colour_index: u8) -> Color32 {
+    let [red, green, blue] = palette.colour(colour_index);
+    Color32::from_rgb(red, green, blue)
+}
@@ -0,0 +1,541 @@
+// Paths
+pub(super) const PATH_RESOURCE_DIRECTORY_NAME: &str = "resources";
+pub(super) const PATH_ROMS_DIRECTORY_NAME: &str = "roms";
+pub(super) const PATH_OPTIONS_DIRECTORY_NAME: &str = "options";
+pub(super) const PATH_SETTINGS_DIRECTORY_NAME: &str = "Chipolata";
+pub(super) const PATH_SETTINGS_FILE_NAME: &str = "settings.toml";
+
+// Window titles
+pub(super) const TITLE_APP_WINDOW: &str = "Chipolata: CHIP-8 emulator";
+pub(super) const TITLE_LOAD_PROGRAM_WINDOW: &str = "Locate program ROM to load";
+pub(super) const TITLE_LOAD_OPTIONS_WINDOW: &str = "Locate options file to load";
+pub(super) const TITLE_SAVE_OPTIONS_WINDOW: &str = "Locate options file to save";
+pub(super) const TITLE_OPTIONS_WINDOW: &str = "Emulation Options";
+pub(super) const TITLE_LOAD_OPTIONS_ERROR_WINDOW: &str = "Error";
+pub(super) const TITLE_SAVE_OPTIONS_ERROR_WINDOW: &str = "Error";
+pub(super) const TITLE_INVALID_OPTIONS_ERROR_WINDOW: &str = "Error";
+pub(super) const TITLE_SAVE_CRASH_DUMP_WINDOW: &str = "Locate crash dump file to save";
+pub(super) const TITLE_SAVE_CRASH_DUMP_ERROR_WINDOW: &str = "Error";
+pub(super) const TITLE_RECORDING_WINDOW: &str = "Locate GIF file to record to";
+pub(super) const TITLE_RECORDING_ERROR_WINDOW: &str = "Error";
+pub(super) const TITLE_PLAYLIST_WINDOW: &str = "Locate folder of program ROMs to play";
+pub(super) const TITLE_PLAYLIST_ERROR_WINDOW: &str = "Error";
+pub(super) const TITLE_ARCHIVE_ENTRY_WINDOW: &str = "Choose program ROM from archive";
+pub(super) const TITLE_ARCHIVE_ERROR_WINDOW: &str = "Error";
+pub(super) const TITLE_SAVE_SESSION_WINDOW: &str = "Locate session file to save";
+pub(super) const TITLE_RESTORE_SESSION_WINDOW: &str = "Locate session file to restore";
+pub(super) const TITLE_SESSION_ERROR_WINDOW: &str = "Error";
+
+// Command line
+pub(super) const CLI_COMMAND_SELF_TEST: &str = "self-test";
+pub(super) const CAPTION_CLI_SELF_TEST_USAGE: &str =
+    "Usage: chipolata self-test <ROM file> [cycles]";
+pub(super) const CAPTION_CLI_SELF_TEST_2K: &str = "(2k)";
+pub(super) const CAPTION_CLI_SELF_TEST_CYCLES: &str = "cycles";
+pub(super) const CAPTION_CLI_SELF_TEST_CRASHED: &str = "crashed";
+pub(super) const CAPTION_CLI_SELF_TEST_DREW: &str = "ran and drew to the display";
+pub(super) const CAPTION_CLI_SELF_TEST_BLANK: &str = "ran but the display stayed blank";
+pub(super) const CAPTION_CLI_SELF_TEST_SUGGESTION: &str = "Suggested emulation mode:";
+pub(super) const CAPTION_CLI_SELF_TEST_NO_SUGGESTION: &str =
+    "No emulation mode ran without crashing and drew to the display";
+
+// Error messages
+pub(super) const ERROR_SELF_TEST_LOAD: &str = "Could not load program ROM";
+pub(super) const ERROR_LOAD_OPTIONS: &str = "Could not load options from file";
+pub(super) const ERROR_SAVE_OPTIONS: &str = "Could not save options to file";
+pub(super) const ERROR_INVALID_OPTIONS: &str = "These options cannot be used";
+pub(super) const ERROR_SAVE_CRASH_DUMP: &str = "Could not save crash dump to file";
+pub(super) const ERROR_RECORDING: &str = "Could not write screen recording to file";
+pub(super) const ERROR_WORKER_STOPPED: &str = "The emulator stopped unexpectedly";
+pub(super) const ERROR_INCOMPATIBLE_INSTRUCTIONS: &str =
+    "This program uses instructions that will crash in the selected emulation mode";
+pub(super) const ERROR_EMULATION_LEVEL_MISMATCH: &str =
+    "This program does not look like it was written for the selected emulation mode";
+pub(super) const ERROR_EMPTY_ARCHIVE: &str =
+    "No CHIP-8 ROM (.ch8, .sc8 or .c8x) files found in archive";
+pub(super) const ERROR_EMPTY_PLAYLIST: &str =
+    "No CHIP-8 ROM (.ch8, .sc8 or .c8x) files found in folder";
+pub(super) const ERROR_PLAYLIST_ENTRIES_SKIPPED: &str =
+    "Skipped playlist ROMs that could not be loaded: ";
+pub(super) const ERROR_SAVE_SESSION: &str = "Could not save session to file";
+pub(super) const ERROR_RESTORE_SESSION: &str = "Could not restore session from file";
+pub(super) const ERROR_RESTORE_SESSION_PROGRAM: &str =
+    "Could not restore session, as its program ROM could not be loaded";
+
+// Widget captions
+pub(super) const CAPTION_BUTTON_LOAD_PROGRAM: &str = "Load Program";
+pub(super) const CAPTION_BUTTON_OPTIONS: &str = "Options";
+pub(super) const CAPTION_BUTTON_DISPLAY: &str = "Display";
+pub(super) const CAPTION_BUTTON_PLAYLIST: &str = "Playlist";
+pub(super) const CAPTION_BUTTON_PLAYLIST_NEXT: &str = "Next";
+pub(super) const CAPTION_BUTTON_PLAYLIST_CLOSE: &str = "Close";
+pub(super) const CAPTION_BUTTON_ARCHIVE_CANCEL: &str = "Cancel";
+pub(super) const CAPTION_PLAYLIST_INTERVAL_SUFFIX: &str = "s";
+pub(super) const CAPTION_LABEL_PLAYLIST_INTERVAL: &str = "Time per ROM: ";
+pub(super) const CAPTION_BUTTON_MEMORY_VIEWER: &str = "Memory";
+pub(super) const CAPTION_LABEL_MEMORY_PROGRAM_COUNTER: &str = "PC";
+pub(super) const CAPTION_LABEL_MEMORY_INDEX_REGISTER: &str = "I";
+pub(super) const CAPTION_BUTTON_REGISTER_INSPECTOR: &str = "Registers";
+pub(super) const CAPTION_BUTTON_SAVE_SLOTS: &str = "Save States";
+pub(super) const CAPTION_BUTTON_KEYPAD: &str = "Keypad";
+pub(super) const CAPTION_BUTTON_CONSOLE: &str = "Console";
+pub(super) const CAPTION_BUTTON_SESSION: &str = "Session";
+pub(super) const CAPTION_BUTTON_SAVE_SESSION: &str = "Save Session";
+pub(super) const CAPTION_BUTTON_RESTORE_SESSION: &str = "Restore Session";
+pub(super) const CAPTION_BUTTON_CONSOLE_CLEAR: &str = "Clear";
+pub(super) const CAPTION_CHECKBOX_CONSOLE_TRACE: &str = "Trace instructions";
+pub(super) const CAPTION_CHECKBOX_CONSOLE_PAUSE: &str = "Pause";
+pub(super) const CAPTION_LABEL_CONSOLE_DROPPED: &str = "events dropped";
+pub(super) const CAPTION_BUTTON_SAVE_SLOT: &str = "Save";
+pub(super) const CAPTION_BUTTON_LOAD_SLOT: &str = "Load";
+pub(super) const CAPTION_LABEL_SAVE_SLOT: &str = "Slot";
+pub(super) const CAPTION_LABEL_SAVE_SLOT_EMPTY: &str = "(empty)";
+pub(super) const CAPTION_SAVE_SLOT_CYCLES_SUFFIX: &str = "cycles";
+pub(super) const CAPTION_LABEL_REGISTER_PROGRAM_COUNTER: &str = "PC";
+pub(super) const CAPTION_LABEL_REGISTER_INDEX_REGISTER: &str = "I";
+pub(super) const CAPTION_LABEL_REGISTER_DELAY_TIMER: &str = "DT";
+pub(super) const CAPTION_LABEL_REGISTER_SOUND_TIMER: &str = "ST";
+pub(super) const CAPTION_LABEL_STACK_EMPTY: &str = "(empty)";
+pub(super) const CAPTION_LABEL_STACK_DEPTH: &str = "Depth (max): ";
+pub(super) const CAPTION_COLLAPSING_VARIABLE_REGISTERS: &str = "Variable registers";
+pub(super) const CAPTION_COLLAPSING_POINTERS_AND_TIMERS: &str = "Pointers and timers";
+pub(super) const CAPTION_COLLAPSING_RPL_FLAGS: &str = "RPL flags";
+pub(super) const CAPTION_COLLAPSING_STACK: &str = "Stack";
+pub(super) const CAPTION_BUTTON_COMPARE: &str = "Compare";
+#[cfg(feature = "builtin-roms")]
+pub(super) const CAPTION_BUTTON_TEST_ROMS: &str = "Test ROMs";
+pub(super) const CAPTION_RADIO_COMPARE_OFF: &str = "Off";
+pub(super) const CAPTION_RADIO_SCHIP_OCTO: &str = "SUPER-CHIP 1.1 (Octo)";
+pub(super) const CAPTION_BUTTON_RUN: &str = "▶";
+pub(super) const CAPTION_BUTTON_PAUSE: &str = "⏸";
+pub(super) const CAPTION_BUTTON_RESTART: &str = "⏮";
+pub(super) const CAPTION_BUTTON_STOP: &str = "⏹";
+pub(super) const CAPTION_BUTTON_ADVANCE_FRAME: &str = "⏭";
+pub(super) const CAPTION_TOGGLE_SLOW_MOTION: &str = "Slow Motion";
+pub(super) const CAPTION_BUTTON_RECORD: &str = "⏺ Record";
+pub(super) const CAPTION_BUTTON_STOP_RECORDING: &str = "⏹ Stop Recording";
+pub(super) const CAPTION_BUTTON_COPY_CRASH_REPORT: &str = "Copy Crash Report";
+pub(super) const CAPTION_BUTTON_SAVE_CRASH_DUMP: &str = "Save Crash Dump…";
+pub(super) const CAPTION_BUTTON_RESTART_AS: &str = "Restart as";
+pub(super) const CAPTION_BUTTON_LOAD_OPTIONS: &str = "Load From File";
+pub(super) const CAPTION_BUTTON_SAVE_OPTIONS: &str = "Save To File";
+pub(super) const CAPTION_BUTTON_OK: &str = "OK";
+pub(super) const CAPTION_BUTTON_CANCEL: &str = "Cancel";
+pub(super) const CAPTION_PROCESSOR_SPEED_SUFFIX: &str = "hz";
+pub(super) const CAPTION_LABEL_PROCESSOR_SPEED: &str = "CPU cycles/s (target): ";
+pub(super) const CAPTION_LABEL_PROGRAM_ADDRESS: &str = "Program start address (hex): ";
+pub(super) const CAPTION_LABEL_FONT_ADDRESS: &str = "Font start address (hex): ";
+pub(super) const CAPTION_LABEL_TIMER_FREQUENCY: &str = "Timer frequency: ";
+pub(super) const CAPTION_LABEL_TIMER_SYNC: &str = "Timer sync: ";
+pub(super) const CAPTION_CHECKBOX_TIMER_SYNC: &str = "Sync timers to CPU cycles";
+pub(super) const CAPTION_LABEL_TIMER_PHASE: &str = "Timer phase: ";
+pub(super) const CAPTION_CHECKBOX_TIMER_PHASE: &str = "Free-running timer interrupt";
+pub(super) const CAPTION_LABEL_TIMING_MODE: &str = "Instruction timing: ";
+pub(super) const CAPTION_RADIO_TIMING_MODE_LEVEL: &str = "Emulation level";
+pub(super) const CAPTION_RADIO_TIMING_MODE_FIXED: &str = "Fixed";
+pub(super) const CAPTION_RADIO_TIMING_MODE_COSMAC: &str = "Variable";
+pub(super) const CAPTION_RADIO_TIMING_MODE_PER_FRAME: &str = "Per frame";
+pub(super) const CAPTION_TIMING_MODE_FIXED_SUFFIX: &str = "µs";
+pub(super) const CAPTION_TIMING_MODE_PER_FRAME_SUFFIX: &str = "per frame";
+pub(super) const CAPTION_LABEL_SPRITE_BUDGET: &str = "Sprite budget: ";
+pub(super) const CAPTION_CHECKBOX_SPRITE_BUDGET: &str = "Limit sprites per frame";
+pub(super) const CAPTION_LABEL_MEMORY_PROTECTION: &str = "Memory protection: ";
+pub(super) const CAPTION_CHECKBOX_MEMORY_PROTECTION: &str = "Write-protect reserved memory";
+pub(super) const CAPTION_LABEL_KEY_REPEAT: &str = "Key repeat: ";
+pub(super) const CAPTION_CHECKBOX_KEY_REPEAT: &str = "Suppress held key repeats";
+pub(super) const CAPTION_LABEL_HALT_DETECTION: &str = "Infinite loops: ";
+pub(super) const CAPTION_CHECKBOX_HALT_DETECTION: &str = "End program when stuck";
+pub(super) const CAPTION_LABEL_SPRITE_EDGES: &str = "Sprite edges: ";
+pub(super) const CAPTION_RADIO_SPRITE_EDGES_CLIP: &str = "Clip";
+pub(super) const CAPTION_RADIO_SPRITE_EDGES_WRAP: &str = "Wrap";
+pub(super) const CAPTION_LABEL_KEY_WAIT: &str = "Key wait (FX0A): ";
+pub(super) const CAPTION_RADIO_KEY_WAIT_RELEASE: &str = "On release";
+pub(super) const CAPTION_RADIO_KEY_WAIT_PRESS: &str = "On press";
+pub(super) const CAPTION_LABEL_DISPLAY_ROTATION: &str = "Display rotation: ";
+pub(super) const CAPTION_RADIO_DISPLAY_ROTATION_0: &str = "0°";
+pub(super) const CAPTION_RADIO_DISPLAY_ROTATION_90: &str = "90°";
+pub(super) const CAPTION_RADIO_DISPLAY_ROTATION_180: &str = "180°";
+pub(super) const CAPTION_RADIO_DISPLAY_ROTATION_270: &str = "270°";
+pub(super) const CAPTION_RADIO_FILTER_SHARP: &str = "Sharp pixels";
+pub(super) const CAPTION_RADIO_FILTER_SMOOTH: &str = "Smooth pixels";
+pub(super) const CAPTION_CHECKBOX_PIXEL_GRID: &str = "Pixel grid";
+pub(super) const CAPTION_CHECKBOX_SCANLINES: &str = "CRT scanlines";
+pub(super) const CAPTION_CHECKBOX_PHOSPHOR_GLOW: &str = "Phosphor glow";
+pub(super) const CAPTION_CHECKBOX_PHOSPHOR_PERSISTENCE: &str = "Phosphor persistence";
+pub(super) const CAPTION_SLIDER_PHOSPHOR_DECAY: &str = "Decay";
+pub(super) const CAPTION_CHECKBOX_LOCK_ASPECT_RATIO: &str = "Lock aspect ratio";
+pub(super) const CAPTION_CHECKBOX_OSD_STATS: &str = "Show frame rate and speed";
+pub(super) const CAPTION_CHECKBOX_DETACH_DEBUG_PANELS: &str = "Detach debugging panels";
+pub(super) const CAPTION_LABEL_FOCUS_LOSS: &str = "When the window loses focus:";
+pub(super) const CAPTION_RADIO_FOCUS_LOSS_CONTINUE: &str = "Keep running";
+pub(super) const CAPTION_RADIO_FOCUS_LOSS_PAUSE: &str = "Pause";
+pub(super) const CAPTION_RADIO_FOCUS_LOSS_THROTTLE: &str = "Slow to 10%";
+pub(super) const CAPTION_BUTTON_AUDIO: &str = "Audio";
+pub(super) const CAPTION_RADIO_WAVEFORM_SQUARE: &str = "Square wave";
+pub(super) const CAPTION_RADIO_WAVEFORM_TRIANGLE: &str = "Triangle wave";
+pub(super) const CAPTION_RADIO_WAVEFORM_SINE: &str = "Sine wave";
+pub(super) const CAPTION_RADIO_WAVEFORM_NOISE: &str = "Noise";
+pub(super) const CAPTION_SLIDER_AUDIO_FREQUENCY: &str = "Pitch (Hz)";
+pub(super) const CAPTION_SLIDER_AUDIO_VOLUME: &str = "Volume";
+pub(super) const CAPTION_CHECKBOX_BUZZER_FILTER: &str = "Hardware buzzer filter";
+pub(super) const CAPTION_OSD_PAUSED: &str = "PAUSED";
+pub(super) const CAPTION_OSD_PROGRAM_ENDED: &str = "PROGRAM ENDED";
+pub(super) const CAPTION_OSD_FAST_FORWARD: &str = "FAST FORWARD";
+pub(super) const CAPTION_OSD_SLOW_MOTION_ON: &str = "SLOW MOTION ON";
+pub(super) const CAPTION_OSD_SLOW_MOTION_OFF: &str = "SLOW MOTION OFF";
+pub(super) const CAPTION_OSD_RESTARTED: &str = "RESTARTED";
+pub(super) const CAPTION_OSD_SAVE_SLOT: &str = "SLOT";
+pub(super) const CAPTION_OSD_SAVE_SLOT_EMPTY: &str = "EMPTY";
+pub(super) const CAPTION_OSD_SAVE_SLOT_WRONG_MODE: &str = "IS FROM ANOTHER MODE";
+pub(super) const CAPTION_OSD_STATE_SAVED: &str = "SAVED TO SLOT";
+pub(super) const CAPTION_OSD_RECORDING_STARTED: &str = "RECORDING";
+pub(super) const CAPTION_OSD_RECORDING_SAVED: &str = "RECORDING SAVED";
+pub(super) const CAPTION_OSD_STATE_LOADED: &str = "LOADED SLOT";
+pub(super) const CAPTION_OSD_SESSION_SAVED: &str = "SESSION SAVED";
+pub(super) const CAPTION_OSD_SESSION_RESTORED: &str = "SESSION RESTORED";
+pub(super) const CAPTION_OSD_FPS_SUFFIX: &str = "FPS";
+pub(super) const CAPTION_OSD_SPEED_SUFFIX: &str = "HZ";
+pub(super) const CAPTION_LOG_STATUS: &str = "Processor status:";
+pub(super) const CAPTION_LOG_RESET: &str = "Program restarted";
+pub(super) const CAPTION_LOG_MEMORY_WRITTEN: &str = "Memory edited";
+pub(super) const CAPTION_LOG_STATE_RESTORED: &str = "Save state loaded";
+pub(super) const CAPTION_LOG_OPTIONS_APPLIED: &str = "Options applied";
+pub(super) const CAPTION_LABEL_PALETTE_THEME: &str = "Palette: ";
+pub(super) const CAPTION_LABEL_PALETTE_COLOURS: &str = "Colours: ";
+pub(super) const CAPTION_COMBO_PALETTE_CUSTOM: &str = "Custom";
+pub(super) const CAPTION_LABEL_EXECUTION_STATUS: &str = "Execution status: ";
+pub(super) const CAPTION_LABEL_ERROR: &str = "ERROR: ";
+pub(super) const CAPTION_LABEL_MODE_SPECIFIC_OPTIONS: &str = "Mode-specific options: ";
+pub(super) const CAPTION_LABEL_CYCLES_PER_SECOND: &str = "CPU cycles/s (actual): ";
+pub(super) const CAPTION_LABEL_TIMING_DRIFT: &str = "Drift: ";
+pub(super) const CAPTION_TIMING_AVERAGE_CYCLE: &str = "Average cycle: ";
+pub(super) const CAPTION_TIMING_TIMER_JITTER: &str = "Timer jitter: ";
+pub(super) const CAPTION_TIMING_VBLANK_JITTER: &str = "VBlank jitter: ";
+pub(super) const CAPTION_LABEL_GETTING_STARTED_1: &str =
+    "Welcome to Chipolata, a CHIP-8 interpreter with compatibility options to enable
+emulation of key historic interpreters: CHIP-8, CHIP-48 and SUPER-CHIP 1.1.";
+pub(super) const CAPTION_LABEL_GETTING_STARTED_2: &str =
+    "To begin, click the 'Load Program' button above and specify a CHIP-8 ROM file
+for Chipolata to run.  Before execution begins, you will be prompted to specify
+emulation options (or load a set of pre-configured options from a file), however
+for most ROMs the default options should be sufficient. Emulation options can be
+reconfigured at a later point by clicking the 'Options' button.";
+pub(super) const CAPTION_LABEL_GETTING_STARTED_3: &str =
+    "While a program is running, execution can be paused and resumed using the
+▶/⏸ button at the bottom left of the window, and the program can be reset and
+restarted by clicking ⏮.  The ⏹ button resets the emulator entirely.";
+pub(super) const CAPTION_LABEL_GETTING_STARTED_4: &str =
+    "Overall emulation speed can be controlled using the 'CPU cycles/s (target)'
+slider, which sets the number of CHIP-8 instructions Chipolata will aim to execute
+every second.  The actual speed achieved - along with current execution status -
+is displayed at the bottom right of the window.  This speed may be adjusted in
+real-time while a program is running without interrupting execution; changing
+any other emulation options will trigger a program restart.";
+pub(super) const CAPTION_LABEL_GETTING_STARTED_5: &str =
+    "CHIP-8/SUPER-CHIP can only display two colours; these can be configured using
+the 'Foreground colour' and 'Background colour' colour pickers at the top right
+of the window.";
+pub(super) const CAPTION_LABEL_GETTING_STARTED_6: &str =
+    "If an error occurs during execution, Chipolata will alert you to this in bold, red
+text above the status bar at the bottom of the window.  In most cases you can click
+the ⏮ button to recover and restart the program; worst case you may choose to
+load a different ROM file instead.";
+pub(super) const CAPTION_LABEL_KEYBOARD_CONTROLS_1: &str =
+    "The early computers for which CHIP-8 was designed had hexadecimal
+keypads for user input, with 16 keys in a 4x4 grid:";
+pub(super) const CAPTION_LABEL_KEYBOARD_CONTROLS_2: &str =
+    "For convenience when using a modern QWERTY keyboard, Chipolata maps
+the original CHIP-8 buttons to your keyboard as follows:";
+pub(super) const CAPTION_LABEL_ABOUT_1: &str = "This version of the software: ";
+pub(super) const CAPTION_LABEL_ABOUT_2: &str =
+    "Chipolata is created by Jon Axon. Source code and latest release on Github:";
+pub(super) const CAPTION_RADIO_CHIP8: &str = "CHIP-8";
+pub(super) const CAPTION_RADIO_CHIP8X: &str = "CHIP-8X";
+pub(super) const CAPTION_RADIO_CHIP48: &str = "CHIP-48";
+pub(super) const CAPTION_RADIO_SCHIP10: &str = "SUPER-CHIP 1.0";
+pub(super) const CAPTION_RADIO_SCHIP: &str = "SUPER-CHIP 1.1";
+pub(super) const CAPTION_CHECKBOX_MEMORY_LIMIT: &str = "2KB memory limit";
+pub(super) const CAPTION_CHECKBOX_CHIP8_HIRES: &str = "Hi-Res (64x64 display)";
+pub(super) const CAPTION_CHECKBOX_CYCLE_TIMING: &str = "Variable cycle timing";
+pub(super) const CAPTION_CHECKBOX_HP48_CYCLE_TIMING: &str = "Approximate variable cycle timing";
+pub(super) const CAPTION_CHECKBOX_VIP_MEMORY_IMAGE: &str = "COSMAC VIP memory image";
+pub(super) const CAPTION_CHECKBOX_LOWRES_DISPLAY_WAIT: &str = "Low-res display wait";
+pub(super) const CAPTION_CHECKBOX_ACCURATE_DISPLAY_TIMING: &str = "Scanline display timing";
+pub(super) const CAPTION_CHECKBOX_VIP_RANDOM_GENERATOR: &str = "COSMAC VIP random numbers";
+pub(super) const CAPTION_CHECKBOX_RESIZE_LOWRES_DISPLAY: &str = "Resize display in low-res";
+pub(super) const CAPTION_CHECKBOX_OCTO_COMPATIBILITY: &str = "Octo compatibility mode";
+pub(super) const CAPTION_HEADING_EMULATION_MODE: &str = "Emulation Mode";
+pub(super) const CAPTION_HEADING_OPTIONS_COMMON: &str = "Common Settings";
+pub(super) const CAPTION_HEADING_PROGRAM_INFO: &str = "Program Information";
+pub(super) const CAPTION_LABEL_PROGRAM_SIZE: &str = "Size: ";
+pub(super) const CAPTION_LABEL_PROGRAM_CRC32: &str = "CRC-32: ";
+pub(super) const CAPTION_LABEL_PROGRAM_SHA1: &str = "SHA-1: ";
+pub(super) const CAPTION_LABEL_PROGRAM_START: &str = "Execution starts: ";
+pub(super) const CAPTION_LABEL_PROGRAM_SUPERCHIP: &str = "SUPER-CHIP instructions: ";
+pub(super) const CAPTION_PROGRAM_SIZE_SUFFIX: &str = " bytes";
+pub(super) const CAPTION_HEADING_OPTIONS_LOAD_SAVE: &str = "Load/Save Options";
+pub(super) const CAPTION_HEADING_GETTING_STARTED: &str = "Getting Started";
+pub(super) const CAPTION_HEADING_KEYBOARD_CONTROLS: &str = "Keyboard Controls";
+pub(super) const CAPTION_HEADING_ABOUT: &str = "About";
+pub(super) const CAPTION_HEADING_PLAYLIST: &str = "Playlist";
+pub(super) const CAPTION_HEADING_MEMORY_VIEWER: &str = "Memory";
+pub(super) const CAPTION_HEADING_REGISTER_INSPECTOR: &str = "Registers";
+pub(super) const CAPTION_HEADING_SAVE_SLOTS: &str = "Save States";
+pub(super) const CAPTION_HEADING_KEYPAD: &str = "Keypad";
+pub(super) const CAPTION_HEADING_CONSOLE: &str = "Console";
+
+// File dialog filters
+pub(super) const FILTER_CHIP8: &str = "CHIP-8";
+pub(super) const FILTER_HEX_TEXT: &str = "Hex text";
+pub(super) const FILTER_ZIP: &str = "Zip archive";
+pub(super) const FILTER_JSON: &str = "JSON";
+pub(super) const FILTER_TOML: &str = "TOML";
+pub(super) const FILTER_OPTIONS: &str = "Options files";
+pub(super) const FILTER_CRASH_DUMP: &str = "Chipolata crash dump";
+pub(super) const FILTER_GIF: &str = "Animated GIF";
+pub(super) const FILTER_SESSION: &str = "Chipolata session";
+pub(super) const FILTER_ALL: &str = "All";
+
+// Ui element IDs
+pub(super) const ID_TOP_PANEL: &str = "top_panel";
+pub(super) const ID_DISPLAY_TEXTURE: &str = "display_texture";
+pub(super) const ID_COMPARE_DISPLAY_TEXTURE: &str = "compare_display_texture";
+pub(super) const ID_OSD_TEXTURE: &str = "osd_texture";
+pub(super) const ID_BOTTOM_PANEL: &str = "bottom_panel";
+pub(super) const ID_PLAYLIST_PANEL: &str = "playlist_panel";
+pub(super) const ID_MEMORY_VIEWER_PANEL: &str = "memory_viewer_panel";
+pub(super) const ID_REGISTER_INSPECTOR_PANEL: &str = "register_inspector_panel";
+pub(super) const ID_SAVE_SLOTS_PANEL: &str = "save_slots_panel";
+pub(super) const ID_KEYPAD_PANEL: &str = "keypad_panel";
+pub(super) const ID_KEYPAD_GRID: &str = "keypad_grid";
+pub(super) const ID_CONSOLE_PANEL: &str = "console_panel";
+pub(super) const ID_CONSOLE_LEVEL_COMBO: &str = "console_level_combo";
+pub(super) const ID_PALETTE_THEME_COMBO: &str = "palette_theme_combo";
+pub(super) const ID_SAVE_SLOT_THUMBNAIL: &str = "save_slot_thumbnail";
+pub(super) const ID_VARIABLE_REGISTERS_GRID: &str = "variable_registers_grid";
+pub(super) const ID_POINTERS_AND_TIMERS_GRID: &str = "pointers_and_timers_grid";
+pub(super) const ID_RPL_FLAGS_GRID: &str = "rpl_flags_grid";
+pub(super) const ID_STACK_GRID: &str = "stack_grid";
+pub(super) const ID_OPTIONS_MODAL: &str = "options_modal";
+pub(super) const ID_OPTIONS_MODAL_GRID: &str = "options_modal_grid";
+pub(super) const ID_PROGRAM_INFO_GRID: &str = "program_info_grid";
+pub(super) const ID_KEYBOARD_CONTROLS_GRID_1: &str = "keyboard_controls_grid_1";
+pub(super) const ID_KEYBOARD_CONTROLS_GRID_2: &str = "keyboard_controls_grid_2";
+
+// Links
+pub(super) const LINK_GITHUB: &str = "https://github.com/jon-axon/chipolata";
+
+// Tooltips
+pub(super) const TOOLTIP_BUTTON_LOAD_PROGRAM: &str = "Load and run a CHIP-8 ROM file from disk";
+pub(super) const TOOLTIP_BUTTON_OPTIONS: &str =
+    "Configure Chipolata emulation options and compatibility settings";
+pub(super) const TOOLTIP_BUTTON_OPTIONS_DISABLED: &str =
+    "Configure Chipolata emulation options and compatibility settings.  Disabled when no program ROM is loaded";
+pub(super) const TOOLTIP_BUTTON_RUN: &str = "Resume execution of the current program";
+pub(super) const TOOLTIP_BUTTON_RUN_DISABLED: &str =
+    "Resume execution of the current program.  Disabled if no program ROM is loaded, or if execution has crashed";
+pub(super) const TOOLTIP_BUTTON_PAUSE: &str = "Pause execution of the current program";
+pub(super) const TOOLTIP_BUTTON_RESTART: &str =
+    "Reset and restart the currently loaded program ROM";
+pub(super) const TOOLTIP_BUTTON_RESTART_DISABLED: &str =
+    "Reset and restart the currently loaded program ROM.  Disabled when no program ROM is loaded";
+pub(super) const TOOLTIP_BUTTON_STOP: &str = "Stop and reset Chipolata";
+pub(super) const TOOLTIP_BUTTON_STOP_DISABLED: &str =
+    "Stop and reset Chipolata.  Disabled when no program is running";
+pub(super) const TOOLTIP_BUTTON_ADVANCE_FRAME: &str =
+    "Advance execution by one frame (1/60th of a second), remaining paused";
+pub(super) const TOOLTIP_BUTTON_ADVANCE_FRAME_DISABLED: &str =
+    "Advance execution by one frame (1/60th of a second).  Disabled unless execution is paused";
+pub(super) const TOOLTIP_BUTTON_RECORD: &str =
+    "Record the display to an animated GIF file, at the emulated frame rate and in the chosen colours";
+pub(super) const TOOLTIP_BUTTON_RECORD_DISABLED: &str = "No program is running";
+pub(super) const TOOLTIP_BUTTON_STOP_RECORDING: &str = "Stop recording and complete the GIF file";
+pub(super) const TOOLTIP_TOGGLE_SLOW_MOTION: &str =
+    "Run at one tenth of normal speed.  Hold Tab to fast-forward at ten times normal speed";
+pub(super) const TOOLTIP_BUTTON_LOAD_OPTIONS: &str =
+    "Load pre-configured options settings file from disk";
+pub(super) const TOOLTIP_BUTTON_SAVE_OPTIONS: &str =
+    "Save current options to disk as a settings file";
+pub(super) const TOOLTIP_BUTTON_PLAYLIST: &str =
+    "Select a folder of CHIP-8 ROM files to run one after another";
+pub(super) const TOOLTIP_BUTTON_PLAYLIST_NEXT: &str = "Skip to the next ROM in the playlist";
+pub(super) const TOOLTIP_BUTTON_PLAYLIST_CLOSE: &str =
+    "Close the playlist, leaving the current ROM running";
+pub(super) const TOOLTIP_DRAGVALUE_PLAYLIST_INTERVAL: &str =
+    "Drag or type to set how long each ROM runs before moving on to the next (ROMs that exit or crash are skipped immediately)";
+pub(super) const TOOLTIP_BUTTON_MEMORY_VIEWER: &str =
+    "Show or hide a live hex view of Chipolata's memory";
+pub(super) const TOOLTIP_LABEL_MEMORY_PROGRAM_COUNTER: &str =
+    "Highlighted bytes hold the instruction at the program counter";
+pub(super) const TOOLTIP_LABEL_MEMORY_INDEX_REGISTER: &str =
+    "Highlighted byte is at the address held in the index register";
+pub(super) const TOOLTIP_MEMORY_CELL_EDITABLE: &str =
+    "Click to edit this byte (enter a hexadecimal value, then press Enter)";
+pub(super) const TOOLTIP_MEMORY_CELL_READ_ONLY: &str = "Pause execution to edit memory";
+pub(super) const TOOLTIP_BUTTON_REGISTER_INSPECTOR: &str =
+    "Show or hide a live view of Chipolata's registers, timers and stack (values that changed in the last frame are highlighted)";
+pub(super) const TOOLTIP_BUTTON_SAVE_SLOTS: &str =
+    "Show or hide the save state slots (F5 quick saves to the selected slot, F8 quick loads from it, and F6/F7 select the previous/next slot)";
+pub(super) const TOOLTIP_BUTTON_KEYPAD: &str =
+    "Show or hide an on-screen CHIP-8 keypad, highlighting the keys currently held; keys can be pressed with the mouse or by touch";
+pub(super) const TOOLTIP_BUTTON_KEYPAD_KEY: &str =
+    "Press and hold to send this key; mapped to keyboard key";
+pub(super) const TOOLTIP_BUTTON_CONSOLE: &str =
+    "Show or hide a console listing status changes, errors and (optionally) each instruction executed";
+pub(super) const TOOLTIP_BUTTON_SESSION: &str =
+    "Save the whole session to a file, or restore one saved earlier to continue where you left off";
+pub(super) const TOOLTIP_BUTTON_SAVE_SESSION: &str =
+    "Save the running program's state, its location, the options, colours and window layout to a file";
+pub(super) const TOOLTIP_BUTTON_SAVE_SESSION_DISABLED: &str =
+    "Save the whole session to a file.  Disabled when no program is running";
+pub(super) const TOOLTIP_BUTTON_RESTORE_SESSION: &str =
+    "Restore a session saved earlier, continuing the program exactly where it left off";
+pub(super) const TOOLTIP_COMBO_CONSOLE_LEVEL: &str =
+    "Choose the least severe events to list (Trace lists everything)";
+pub(super) const TOOLTIP_CHECKBOX_CONSOLE_TRACE: &str =
+    "Log each instruction executed (its address, mnemonic and operands); this slows execution";
+pub(super) const TOOLTIP_CHECKBOX_CONSOLE_PAUSE: &str =
+    "Stop adding new events to the console, so that those listed can be read";
+pub(super) const TOOLTIP_BUTTON_CONSOLE_CLEAR: &str = "Remove all events from the console";
+pub(super) const TOOLTIP_LABEL_CONSOLE_DROPPED: &str =
+    "Events are logged faster than they can be kept, so the oldest have been discarded";
+pub(super) const TOOLTIP_LABEL_SAVE_SLOT: &str =
+    "Select this slot for the quick save (F5) and quick load (F8) keys";
+pub(super) const TOOLTIP_BUTTON_SAVE_SLOT: &str =
+    "Save the current state to this slot, replacing any state already held";
+pub(super) const TOOLTIP_BUTTON_LOAD_SLOT: &str = "Restore the state held in this slot";
+pub(super) const TOOLTIP_LABEL_STACK_TOP: &str = "The most recently pushed return address";
+pub(super) const TOOLTIP_LABEL_STACK_DEPTH: &str =
+    "The number of return addresses on the stack, and the greatest number it has held since the program was loaded or reset";
+pub(super) const TOOLTIP_BUTTON_COPY_CRASH_REPORT: &str =
+    "Copy a diagnostic report of the error (including the offending opcode, registers, stack and memory) to the clipboard, as JSON";
+pub(super) const TOOLTIP_BUTTON_SAVE_CRASH_DUMP: &str =
+    "Save a crash dump bundling a copy of the program ROM, the emulation options, the diagnostic report and the instructions executed just before the error, for reproducing the crash";
+pub(super) const TOOLTIP_BUTTON_RESTART_AS: &str =
+    "The program uses an instruction only recognised at this emulation level; restart it at this level";
+#[cfg(feature = "builtin-roms")]
+pub(super) const TOOLTIP_BUTTON_TEST_ROMS: &str =
+    "Load and run one of the public-domain test ROMs built into Chipolata";
+pub(super) const TOOLTIP_BUTTON_COMPARE: &str =
+    "Run the program a second time alongside, at a different emulation level, to compare behaviour side by side (key presses are sent to both)";
+pub(super) const TOOLTIP_RADIO_COMPARE_OFF: &str = "Run the program on its own";
+pub(super) const TOOLTIP_BUTTON_DISPLAY: &str =
+    "Configure how the Chipolata display is scaled and rendered";
+pub(super) const TOOLTIP_RADIO_FILTER_SHARP: &str =
+    "Scale the display using nearest-neighbour filtering, for crisp square pixels";
+pub(super) const TOOLTIP_RADIO_FILTER_SMOOTH: &str =
+    "Scale the display using bilinear filtering, softening the edges of pixels";
+pub(super) const TOOLTIP_CHECKBOX_PIXEL_GRID: &str =
+    "Draw faint grid lines between individual pixels";
+pub(super) const TOOLTIP_CHECKBOX_SCANLINES: &str =
+    "Darken the lower part of each pixel row, imitating the scanlines of a CRT monitor";
+pub(super) const TOOLTIP_CHECKBOX_PHOSPHOR_GLOW: &str =
+    "Draw a soft halo around lit pixels, imitating the phosphor glow of a CRT monitor";
+pub(super) const TOOLTIP_CHECKBOX_PHOSPHOR_PERSISTENCE: &str =
+    "Fade pixels out gradually when they are switched off, reducing the flicker of sprites being erased and redrawn";
+pub(super) const TOOLTIP_SLIDER_PHOSPHOR_DECAY: &str =
+    "Drag to set how slowly switched-off pixels fade out (higher values leave longer trails)";
+pub(super) const TOOLTIP_CHECKBOX_LOCK_ASPECT_RATIO: &str =
+    "Keep pixels square by letterboxing the display, rather than stretching it to fill the window";
+pub(super) const TOOLTIP_CHECKBOX_DETACH_DEBUG_PANELS: &str =
+    "Show the memory viewer, register inspector and save state panels in floating, resizable windows over the display, rather than docked beside it (which shrinks the display)";
+pub(super) const TOOLTIP_RADIO_FOCUS_LOSS_CONTINUE: &str =
+    "Carry on running the program as normal while another window has focus";
+pub(super) const TOOLTIP_RADIO_FOCUS_LOSS_PAUSE: &str =
+    "Pause the program while another window has focus, resuming when Chipolata regains focus (unless you paused it yourself)";
+pub(super) const TOOLTIP_RADIO_FOCUS_LOSS_THROTTLE: &str =
+    "Run the program at a tenth of its speed while another window has focus, as per slow motion";
+pub(super) const TOOLTIP_CHECKBOX_OSD_STATS: &str =
+    "Show the emulated frame rate and actual processor speed in the corner of the display";
+pub(super) const TOOLTIP_LABEL_TIMING_DRIFT: &str =
+    "How far execution has fallen behind (+) or run ahead of (-) the configured speed since the program started, as a percentage of the time it should have taken";
+pub(super) const TOOLTIP_BUTTON_AUDIO: &str =
+    "Configure the tone played while the sound timer is active (changes apply immediately)";
+pub(super) const TOOLTIP_RADIO_WAVEFORM: &str =
+    "Select the waveform from which the tone is generated";
+pub(super) const TOOLTIP_SLIDER_AUDIO_FREQUENCY: &str =
+    "Drag to set the pitch of the tone (for noise, how often a new random sample is drawn)";
+pub(super) const TOOLTIP_SLIDER_AUDIO_VOLUME: &str = "Drag to set the volume of the tone";
+pub(super) const TOOLTIP_CHECKBOX_BUZZER_FILTER: &str =
+    "Muffle the tone with a low-pass filter, imitating the small piezo buzzers of original hardware";
+pub(super) const TOOLTIP_COLOUR_PICKER_FOREGROUND: &str =
+    "Change the colour used to render 'on' pixels";
+pub(super) const TOOLTIP_COLOUR_PICKER_BACKGROUND: &str =
+    "Change the colour used to render 'off' pixels";
+pub(super) const TOOLTIP_COLOUR_PICKER_SECOND_PLANE: &str =
+    "Change the colour used to render pixels set only in the second plane";
+pub(super) const TOOLTIP_COLOUR_PICKER_BOTH_PLANES: &str =
+    "Change the colour used to render pixels set in both planes";
+pub(super) const TOOLTIP_COLOUR_PICKER_FURTHER_PLANES: &str =
+    "Change the colour used to render pixels of this combination of planes";
+pub(super) const TOOLTIP_COMBO_PALETTE_THEME: &str =
+    "Choose a built-in palette, which can then be customised colour by colour (the palette is saved with the options)";
+pub(super) const TOOLTIP_SLIDER_PROCESSOR_SPEED: &str =
+    "Drag or type to set the target processor speed (cycles per second)";
+pub(super) const TOOLTIP_SLIDER_PROCESSOR_SPEED_DISABLED: &str =
+    "Drag or type to set the target processor speed (cycles per second).  Disabled when emulating CHIP-8 variable cycle timing";
+pub(super) const TOOLTIP_SLIDER_PROGRAM_ADDRESS: &str =
+    "Drag or type to set the memory address into which the program ROM will start to be loaded";
+pub(super) const TOOLTIP_SLIDER_FONT_ADDRESS: &str =
+    "Drag or type to set the memory address into which the CHIP-8 font will start to be loaded";
+pub(super) const TOOLTIP_SLIDER_TIMER_FREQUENCY: &str =
+    "Drag or type to set how many times per second the delay and sound timers count down (60 by default; some ROMs written for PAL platforms expect 50)";
+pub(super) const TOOLTIP_CHECKBOX_TIMER_SYNC: &str =
+    "Count the timers down according to the number of CPU cycles executed rather than elapsed time, so timing stays correct relative to the program when running faster or slower than real time";
+pub(super) const TOOLTIP_CHECKBOX_TIMER_PHASE: &str =
+    "Keep the timer interrupt ticking at a fixed phase even while the timers are idle, as on original hardware, so a newly set delay timer first counts down part-way through a frame; gives authentic pacing for programs that time loops by reading the delay timer";
+pub(super) const TOOLTIP_RADIO_TIMING_MODE_LEVEL: &str =
+    "Time instructions as per the emulation level: one CPU cycle each, or as on the original interpreter if variable cycle timing is selected";
+pub(super) const TOOLTIP_RADIO_TIMING_MODE_FIXED: &str =
+    "Give every instruction the same duration in microseconds, regardless of CPU speed";
+pub(super) const TOOLTIP_RADIO_TIMING_MODE_COSMAC: &str =
+    "Give each instruction as many CPU cycles as on the original interpreter (COSMAC VIP or HP48), whether or not variable cycle timing is selected";
+pub(super) const TOOLTIP_RADIO_TIMING_MODE_PER_FRAME: &str =
+    "Execute a fixed number of instructions per frame, as in OCTO, regardless of CPU speed";
+pub(super) const TOOLTIP_SLIDER_TIMING_MODE_FIXED: &str =
+    "Drag or type to set the duration of every instruction in microseconds";
+pub(super) const TOOLTIP_SLIDER_TIMING_MODE_PER_FRAME: &str =
+    "Drag or type to set the number of instructions executed per frame";
+pub(super) const TOOLTIP_CHECKBOX_SPRITE_BUDGET: &str =
+    "Limit the number of sprites drawn per frame, with further draws waiting for the next display refresh, so that games paced by slow drawing on original hardware run at authentic speeds regardless of CPU speed";
+pub(super) const TOOLTIP_SLIDER_SPRITE_BUDGET: &str =
+    "Drag or type to set the maximum number of sprites drawn per frame";
+pub(super) const TOOLTIP_CHECKBOX_KEY_REPEAT: &str =
+    "Treat a key as released once a key-skip instruction has seen it pressed, so each press is acted on only once; leave unticked for games that need keys held down for continuous movement";
+pub(super) const TOOLTIP_CHECKBOX_HALT_DETECTION: &str =
+    "Stop execution once the program jumps to itself or loops without reading keys or changing anything, showing that the program has ended";
+pub(super) const TOOLTIP_CHECKBOX_MEMORY_PROTECTION: &str =
+    "Crash with an error if the program writes to memory below the program start address (where the font is held), rather than silently corrupting the font; useful for catching bugs in ROMs under development";
+pub(super) const TOOLTIP_RADIO_KEY_WAIT_RELEASE: &str =
+    "The wait-for-key instruction registers a key once it has been pressed and released, as on the original COSMAC VIP";
+pub(super) const TOOLTIP_RADIO_KEY_WAIT_PRESS: &str =
+    "The wait-for-key instruction registers a key as soon as it is pressed, as do many modern interpreters";
+pub(super) const TOOLTIP_RADIO_SPRITE_EDGES_CLIP: &str =
+    "Sprite pixels overflowing the right-hand or bottom edges of the display are not drawn";
+pub(super) const TOOLTIP_RADIO_SPRITE_EDGES_WRAP: &str =
+    "Sprite pixels overflowing the right-hand or bottom edges of the display wrap around to the opposite edge";
+pub(super) const TOOLTIP_RADIO_DISPLAY_ROTATION: &str =
+    "Rotate the display clockwise by this many degrees, for ROMs written for platforms whose screens were mounted in portrait";
+pub(super) const TOOLTIP_SELECTABLE_CHIP8: &str =
+    "Emulate the classic COSMAC VIP CHIP-8 interpreter";
+pub(super) const TOOLTIP_SELECTABLE_CHIP8X: &str =
+    "Emulate the CHIP-8X interpreter for the COSMAC VP, with colour zones and a second keypad";
+pub(super) const TOOLTIP_SELECTABLE_CHIP48: &str =
+    "Emulate the reimplementation of CHIP-8 for the HP48 graphing calculators";
+pub(super) const TOOLTIP_SELECTABLE_SUPERCHIP10: &str =
+    "Emulate version 1.0 of the SUPER-CHIP interpreter, which lacks the scroll instructions of version 1.1";
+pub(super) const TOOLTIP_SELECTABLE_SUPERCHIP: &str =
+    "Emulate version 1.1 of the enhanced SUPER-CHIP interpreter";
+pub(super) const TOOLTIP_BUTTON_OPTIONS_OK: &str =
+    "Apply the selected options.  If a program is already running, this will cause it to restart";
+pub(super) const TOOLTIP_BUTTON_OPTIONS_CANCEL: &str = "Discard any options changes";
+pub(super) const TOOLTIP_CHECKBOX_MEMORY_LIMIT: &str = "Emulate a COSMAC VIP with only 2KB of memory rather than 4KB.  WARNING: likely to crash most ROMs!";
+pub(super) const TOOLTIP_CHECKBOX_CHIP8_HIRES: &str = "Emulate the Hi-Res CHIP-8 interpreter, with a 64x64 pixel display.  Only needed for ROMs written for Hi-Res CHIP-8 (typically beginning with a jump to 0x260)";
+pub(super) const TOOLTIP_CHECKBOX_VARIABLE_CYCLE_TIMING: &str = "Rather than using fixed cycle lengths for all opcodes, emulate original COSMAC VIP opcode timings and processor speed.  Experimental feature!";
+pub(super) const TOOLTIP_CHECKBOX_VIP_MEMORY_IMAGE: &str = "Populate the memory below the program as on a COSMAC VIP, using the VIP's own hex digit sprites as the font (several characters differ in shape from the default font)";
+pub(super) const TOOLTIP_CHECKBOX_RESIZE_LOWRES_DISPLAY: &str = "Shrink the display to 64 x 32 pixels in low resolution mode rather than drawing each pixel as a 2x2 block at 128 x 64 (scrolling then moves whole low resolution pixels, as in OCTO)";
+pub(super) const TOOLTIP_CHECKBOX_LOWRES_DISPLAY_WAIT: &str = "Wait for the next display refresh before drawing each sprite in low resolution mode, as on real HP48 hardware (try enabling this if scrolling demos run too fast)";
+pub(super) const TOOLTIP_CHECKBOX_ACCURATE_DISPLAY_TIMING: &str = "Model the COSMAC VIP display scanline by scanline, so that each sprite waits only until the raster has passed its rows rather than for the next display refresh (slower to emulate, but draws are timed by sprite position as on original hardware)";
+pub(super) const TOOLTIP_CHECKBOX_VIP_RANDOM_GENERATOR: &str = "Generate random numbers (CXNN) with the COSMAC VIP interpreter's own routine rather than a uniform generator, reproducing the sequences some programs rely on (authentic only with the interpreter image loaded)";
+pub(super) const TOOLTIP_CHECKBOX_HP48_VARIABLE_CYCLE_TIMING: &str = "Rather than using fixed cycle lengths for all opcodes, pace opcodes by approximate HP48 timings (estimated, not measured) and processor speed.  Experimental feature!";
+pub(super) const TOOLTIP_CHECKBOX_OCTO_COMPATIBILITY: &str = "Emulate deviations from the original SUPER-CHIP 1.1 specification implemented by the popular Octo interpreter (try enabling this for any problematic SUPER-CHIP ROMs)";
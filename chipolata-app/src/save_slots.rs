@@ -0,0 +1,191 @@
+use super::*;
+
+/// A struct that holds the contents of a save state quick slot
+pub(crate) struct SaveSlot {
+    state: SaveState,                 // the saved state
+    thumbnail: Option<TextureHandle>, // texture holding the saved frame buffer (once rendered)
+}
+
+impl ChipolataUi {
+    /// Instructs the worker thread to save the state of the current instance of Chipolata; the
+    /// state is kept in the specified slot once reported back
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - the quick slot in which to keep the saved state
+    pub(crate) fn save_state_to_slot(&self, slot: usize) {
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::SaveState { slot }) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+        }
+    }
+
+    /// Instructs the worker thread to restore the state held in the specified slot to the current
+    /// instance of Chipolata.  If the slot is empty, or holds a state saved at a different
+    /// emulation level, the user is told so instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - the quick slot holding the state to restore
+    pub(crate) fn restore_state_from_slot(&mut self, slot: usize) {
+        let state: SaveState = match &self.save_slots[slot] {
+            None => {
+                self.show_osd_message(&format!(
+                    "{} {} {}",
+                    CAPTION_OSD_SAVE_SLOT, slot, CAPTION_OSD_SAVE_SLOT_EMPTY
+                ));
+                return;
+            }
+            Some(save_slot)
+                if save_slot.state.emulation_level() != self.options.emulation_level =>
+            {
+                self.show_osd_message(&format!(
+                    "{} {} {}",
+                    CAPTION_OSD_SAVE_SLOT, slot, CAPTION_OSD_SAVE_SLOT_WRONG_MODE
+                ));
+                return;
+            }
+            Some(save_slot) => save_slot.state.clone(),
+        };
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::RestoreState { state })
+            {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+            self.show_osd_message(&format!("{} {}", CAPTION_OSD_STATE_LOADED, slot));
+        }
+    }
+
+    /// Keeps a state reported back by the worker thread in the specified slot (replacing any
+    /// state already held there), and confirms this to the user
+    ///
+    /// # Arguments
+    ///
+    /// * `slot` - the quick slot in which to keep the state
+    /// * `state` - the saved state
+    pub(crate) fn store_save_state(&mut self, slot: usize, state: SaveState) {
+        self.save_slots[slot] = Some(SaveSlot {
+            state,
+            thumbnail: None,
+        });
+        self.show_osd_message(&format!("{} {}", CAPTION_OSD_STATE_SAVED, slot));
+    }
+
+    /// Empties every save state quick slot, as their states belong to the program previously
+    /// loaded
+    pub(crate) fn clear_save_slots(&mut self) {
+        self.save_slots = (0..SAVE_STATE_SLOT_COUNT).map(|_| None).collect();
+    }
+
+    /// Rendering function to display the save state slot browser panel, listing every quick slot
+    /// with a thumbnail of the display as at the moment its state was saved.  Slots can be
+    /// selected (for the quick save and quick load keys), saved to and loaded from.
+    pub(crate) fn render_save_slots_panel(&mut self, ctx: &egui::Context) {
+        // Render a thumbnail texture for any slot saved since the panel was last displayed
+        for slot in 0..SAVE_STATE_SLOT_COUNT {
+            if let Some(SaveSlot {
+                state,
+                thumbnail: None,
+            }) = &self.save_slots[slot]
+            {
+                let image: ColorImage = self.build_display_image(state.frame_buffer(), &[]);
+                let texture: TextureHandle =
+                    ctx.load_texture(ID_SAVE_SLOT_THUMBNAIL, image, TextureOptions::NEAREST);
+                if let Some(save_slot) = &mut self.save_slots[slot] {
+                    save_slot.thumbnail = Some(texture);
+                }
+            }
+        }
+        // Delegate any click events once the panel has been rendered
+        let mut selected_slot: Option<usize> = None;
+        let mut save_slot: Option<usize> = None;
+        let mut load_slot: Option<usize> = None;
+        show_debug_panel(
+            ctx,
+            self.detach_debug_panels,
+            SidePanel::right(ID_SAVE_SLOTS_PANEL).default_width(SAVE_SLOTS_PANEL_WIDTH),
+            ID_SAVE_SLOTS_PANEL,
+            |ui| {
+                ui.add_space(UI_SPACER_TOP);
+                ui.heading(RichText::new(CAPTION_HEADING_SAVE_SLOTS).color(COLOUR_HEADING));
+                ui.separator();
+                ScrollArea::vertical()
+                    .auto_shrink([false; 2])
+                    .show(ui, |ui| {
+                        for slot in 0..SAVE_STATE_SLOT_COUNT {
+                            if ui
+                                .selectable_label(
+                                    slot == self.selected_save_slot,
+                                    format!("{} {}", CAPTION_LABEL_SAVE_SLOT, slot),
+                                )
+                                .on_hover_text(TOOLTIP_LABEL_SAVE_SLOT)
+                                .clicked()
+                            {
+                                selected_slot = Some(slot);
+                            }
+                            match &self.save_slots[slot] {
+                                Some(SaveSlot {
+                                    state,
+                                    thumbnail: Some(thumbnail),
+                                }) => {
+                                    let size: Vec2 = thumbnail.size_vec2();
+                                    ui.image(
+                                        thumbnail.id(),
+                                        Vec2::new(
+                                            SAVE_SLOT_THUMBNAIL_WIDTH,
+                                            SAVE_SLOT_THUMBNAIL_WIDTH * size.y / size.x,
+                                        ),
+                                    );
+                                    ui.label(format!(
+                                        "{} {}",
+                                        state.cycles(),
+                                        CAPTION_SAVE_SLOT_CYCLES_SUFFIX
+                                    ));
+                                }
+                                _ => {
+                                    ui.label(CAPTION_LABEL_SAVE_SLOT_EMPTY);
+                                }
+                            }
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .button(
+                                        RichText::new(CAPTION_BUTTON_SAVE_SLOT)
+                                            .color(COLOUR_BUTTON),
+                                    )
+                                    .on_hover_text(TOOLTIP_BUTTON_SAVE_SLOT)
+                                    .clicked()
+                                {
+                                    save_slot = Some(slot);
+                                }
+                                if ui
+                                    .add_enabled(
+                                        // Only enabled if the slot holds a state
+                                        self.save_slots[slot].is_some(),
+                                        Button::new(
+                                            RichText::new(CAPTION_BUTTON_LOAD_SLOT)
+                                                .color(COLOUR_BUTTON),
+                                        ),
+                                    )
+                                    .on_hover_text(TOOLTIP_BUTTON_LOAD_SLOT)
+                                    .clicked()
+                                {
+                                    load_slot = Some(slot);
+                                }
+                            });
+                            ui.separator();
+                        }
+                    });
+            },
+        );
+        if let Some(slot) = selected_slot {
+            self.on_click_save_slot_label(slot);
+        }
+        if let Some(slot) = save_slot {
+            self.on_click_save_to_slot(slot);
+        }
+        if let Some(slot) = load_slot {
+            self.on_click_load_from_slot(slot);
+        }
+    }
+}
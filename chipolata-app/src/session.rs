@@ -0,0 +1,253 @@
+use super::*;
+use serde_derive::{Deserialize, Serialize};
+use std::fs::File;
+
+/// The whole app session, saved to a JSON file so that the user can continue exactly where they
+/// left off (even after a reboot): the running program's location and complete processor state,
+/// along with the emulation options (including the palette) and window layout in use
+#[derive(Serialize, Deserialize)]
+pub(crate) struct Session {
+    /// The file location of the program ROM (or the name of the built-in test ROM)
+    program_file_path: String,
+    /// The ROM's entry within the zip archive at the file location, if any
+    program_archive_entry: Option<String>,
+    /// Whether the program is a built-in test ROM, rather than a file
+    program_builtin_rom: bool,
+    /// The emulation options in use
+    options: Options,
+    /// The complete processor state
+    state: SaveState,
+    /// Whether execution was paused by the user
+    paused: bool,
+    /// The size of the window
+    window_size: Option<[f32; 2]>,
+    /// The position of the window
+    window_position: Option<[f32; 2]>,
+    /// Whether the debugging panels were displayed in floating windows
+    detach_debug_panels: bool,
+    /// Whether the memory viewer panel was displayed
+    show_memory_viewer: bool,
+    /// Whether the register inspector panel was displayed
+    show_register_inspector: bool,
+    /// Whether the save state slot browser panel was displayed
+    show_save_slots: bool,
+    /// Whether the on-screen keypad panel was displayed
+    show_keypad: bool,
+    /// Whether the console panel was displayed
+    show_console: bool,
+}
+
+impl Session {
+    /// Builder method that instantiates a [Session] from the specified JSON file, as written by
+    /// [Session::save_to_file()].  Returns [ErrorDetail::FileError] if the file cannot be read
+    /// or does not hold a valid session.
+    fn load_from_file(file_path: &Path) -> Result<Session, ErrorDetail> {
+        // attempt to open the file
+        if let Ok(json_file) = File::open(file_path) {
+            // parse the file as JSON and deserialise into a Session instance, refusing any whose
+            // processor state is inconsistent (as restoring it could then panic)
+            if let Ok(session) = serde_json::from_reader::<File, Session>(json_file) {
+                if session.state.is_consistent() {
+                    return Ok(session);
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred reading from the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Method that serialises the [Session] to the specified JSON file
+    fn save_to_file(&self, file_path: &Path) -> Result<(), ErrorDetail> {
+        if let Ok(serialised_session) = serde_json::to_string(self) {
+            if std::fs::write(file_path, serialised_session).is_ok() {
+                return Ok(());
+            }
+        }
+        // if we fall through to here, an error has occurred writing to the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+}
+
+/// Helper function that returns the data of the built-in test ROM with the specified name, if
+/// there is one
+///
+/// # Arguments
+///
+/// * `name` - the name of the built-in test ROM
+#[cfg(feature = "builtin-roms")]
+fn builtin_rom_data(name: &str) -> Option<&'static [u8]> {
+    builtin_roms::list()
+        .iter()
+        .find(|rom| rom.name == name)
+        .map(|rom| rom.data)
+}
+
+/// Helper function that returns the data of the built-in test ROM with the specified name; as no
+/// ROMs are built in without the `builtin-roms` feature, this is always None
+///
+/// # Arguments
+///
+/// * `name` - the name of the built-in test ROM
+#[cfg(not(feature = "builtin-roms"))]
+fn builtin_rom_data(_name: &str) -> Option<&'static [u8]> {
+    None
+}
+
+/// Helper function that shows an error dialogue explaining why a session could not be saved or
+/// restored
+///
+/// # Arguments
+///
+/// * `description` - the explanation of the error
+fn show_session_error(description: &str) {
+    MessageDialog::new()
+        .set_level(MessageLevel::Error)
+        .set_title(TITLE_SESSION_ERROR_WINDOW)
+        .set_description(description)
+        .set_buttons(MessageButtons::Ok)
+        .show();
+}
+
+impl ChipolataUi {
+    /// Event handler for "Save Session" button
+    pub(crate) fn on_click_save_session(&mut self) {
+        // Open a file save dialogue, then instruct the worker thread to report the current state
+        // so that the session can be written to the chosen file once it arrives
+        if let Some(file) = FileDialog::new()
+            .set_title(TITLE_SAVE_SESSION_WINDOW)
+            .add_filter(FILTER_SESSION, &["json"])
+            .add_filter(FILTER_ALL, &["*"])
+            .save_file()
+        {
+            self.session_file_path = Some(file);
+            if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+                if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::SaveSession) {
+                    // absorb the error; a stopped worker thread is detected when awaiting its update
+                }
+            }
+        }
+    }
+
+    /// Writes the session, including the state reported back by the worker thread, to the file
+    /// chosen by the user, and confirms this to the user
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - the saved processor state
+    pub(crate) fn store_session(&mut self, state: SaveState) {
+        if let Some(file_path) = self.session_file_path.take() {
+            let session: Session = Session {
+                program_file_path: self.program_file_path.clone(),
+                program_archive_entry: self.program_archive_entry.clone(),
+                program_builtin_rom: self.program_builtin_rom.is_some(),
+                options: self.options,
+                state,
+                paused: self.execution_state == ExecutionState::Paused && !self.focus_paused,
+                window_size: self.window_size.map(|size| [size.x, size.y]),
+                window_position: self
+                    .window_position
+                    .map(|position| [position.x, position.y]),
+                detach_debug_panels: self.detach_debug_panels,
+                show_memory_viewer: self.show_memory_viewer,
+                show_register_inspector: self.show_register_inspector,
+                show_save_slots: self.show_save_slots,
+                show_keypad: self.show_keypad,
+                show_console: self.show_console,
+            };
+            match session.save_to_file(&file_path) {
+                Ok(()) => self.show_osd_message(CAPTION_OSD_SESSION_SAVED),
+                Err(_) => show_session_error(ERROR_SAVE_SESSION),
+            }
+        }
+    }
+
+    /// Event handler for "Restore Session" button
+    pub(crate) fn on_click_restore_session(&mut self) {
+        // Open a file load dialogue, and restore the session held in the user-selected file
+        if let Some(file) = FileDialog::new()
+            .set_title(TITLE_RESTORE_SESSION_WINDOW)
+            .add_filter(FILTER_SESSION, &["json"])
+            .add_filter(FILTER_ALL, &["*"])
+            .pick_file()
+        {
+            match Session::load_from_file(&file) {
+                Ok(session) => self.restore_session(session),
+                Err(_) => show_session_error(ERROR_RESTORE_SESSION),
+            }
+        }
+    }
+
+    /// Helper function that reloads the passed session's program, restores its processor state
+    /// (pausing execution if it was paused), and applies its options and window layout
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - the session to restore
+    fn restore_session(&mut self, session: Session) {
+        // Locate the session's program, leaving any program already running untouched if it
+        // cannot be loaded
+        let previous_program: (String, Option<String>, Option<&'static [u8]>) = (
+            std::mem::replace(&mut self.program_file_path, session.program_file_path),
+            std::mem::replace(
+                &mut self.program_archive_entry,
+                session.program_archive_entry,
+            ),
+            self.program_builtin_rom,
+        );
+        self.program_builtin_rom = match session.program_builtin_rom {
+            true => builtin_rom_data(&self.program_file_path),
+            false => None,
+        };
+        let program: Result<Program, ErrorDetail> =
+            match (session.program_builtin_rom, self.program_builtin_rom) {
+                // the built-in test ROM is not included in this build
+                (true, None) => Err(ErrorDetail::FileError {
+                    file_path: self.program_file_path.clone(),
+                }),
+                _ => self.load_program_file(),
+            };
+        let program: Program = match program {
+            Ok(program) => program,
+            Err(_) => {
+                (
+                    self.program_file_path,
+                    self.program_archive_entry,
+                    self.program_builtin_rom,
+                ) = previous_program;
+                show_session_error(ERROR_RESTORE_SESSION_PROGRAM);
+                return;
+            }
+        };
+        // Apply the session's settings, then run the program and restore its state
+        self.pending_window_geometry = Some((
+            session.window_size.map(Vec2::from),
+            session.window_position.map(Pos2::from),
+        ));
+        self.detach_debug_panels = session.detach_debug_panels;
+        self.show_memory_viewer = session.show_memory_viewer;
+        self.show_register_inspector = session.show_register_inspector;
+        self.show_save_slots = session.show_save_slots;
+        self.show_keypad = session.show_keypad;
+        self.show_console = session.show_console;
+        self.options = session.options;
+        self.new_options = session.options;
+        self.clear_save_slots();
+        self.playlist = Vec::new();
+        self.instantiate_chipolata(program, session.options);
+        if let Some(message_to_chipolata_tx) = &self.message_to_chipolata_tx {
+            if let Err(_) = message_to_chipolata_tx.send(MessageToChipolata::RestoreState {
+                state: session.state,
+            }) {
+                // absorb the error; a stopped worker thread is detected when awaiting its update
+            }
+            self.show_osd_message(CAPTION_OSD_SESSION_RESTORED);
+            if session.paused {
+                self.on_click_pause();
+            }
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use super::*;
+use serde_derive::{Deserialize, Serialize};
+
+/// The keyboard keys that may be mapped to the CHIP-8 keypad in the settings file, where each is
+/// named as per its [Key] variant (e.g. "Num1" or "Q")
+const MAPPABLE_KEYS: [Key; 36] = [
+    Key::Num0,
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Num4,
+    Key::Num5,
+    Key::Num6,
+    Key::Num7,
+    Key::Num8,
+    Key::Num9,
+    Key::A,
+    Key::B,
+    Key::C,
+    Key::D,
+    Key::E,
+    Key::F,
+    Key::G,
+    Key::H,
+    Key::I,
+    Key::J,
+    Key::K,
+    Key::L,
+    Key::M,
+    Key::N,
+    Key::O,
+    Key::P,
+    Key::Q,
+    Key::R,
+    Key::S,
+    Key::T,
+    Key::U,
+    Key::V,
+    Key::W,
+    Key::X,
+    Key::Y,
+    Key::Z,
+];
+/// The default keyboard key mapped to each CHIP-8 key (0x0 to 0xF), laid out so that the left
+/// of the keyboard mirrors the COSMAC VIP's 4x4 hex keypad
+pub(crate) const DEFAULT_KEY_MAPPING: [Key; 16] = [
+    Key::X,
+    Key::Num1,
+    Key::Num2,
+    Key::Num3,
+    Key::Q,
+    Key::W,
+    Key::E,
+    Key::A,
+    Key::S,
+    Key::D,
+    Key::Z,
+    Key::C,
+    Key::Num4,
+    Key::R,
+    Key::F,
+    Key::V,
+];
+
+/// An enum to represent what happens to a running program when the Chipolata window loses focus
+/// (it returns to normal when the window regains focus)
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum FocusLossAction {
+    /// Carry on running as normal
+    Continue,
+    /// Pause execution
+    Pause,
+    /// Throttle execution to a tenth of normal speed, as per slow motion
+    Throttle,
+}
+
+/// The UI settings persisted across sessions, in a `settings.toml` file within the user's
+/// configuration directory.  Any setting missing from the file takes its default value.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct Settings {
+    /// The colour with which to render Chipolata foreground pixels, as RGB components; superseded
+    /// by the palette held in the options, so only read from settings files that predate it
+    #[serde(skip_serializing)]
+    pub(crate) foreground_colour: Option<[u8; 3]>,
+    /// The colour with which to render Chipolata background pixels, as RGB components; superseded
+    /// by the palette held in the options, so only read from settings files that predate it
+    #[serde(skip_serializing)]
+    pub(crate) background_colour: Option<[u8; 3]>,
+    /// The name of the keyboard key mapped to each CHIP-8 key (0x0 to 0xF)
+    pub(crate) key_mapping: Vec<String>,
+    /// The size of the window when last closed
+    pub(crate) window_size: Option<[f32; 2]>,
+    /// The position of the window when last closed
+    pub(crate) window_position: Option<[f32; 2]>,
+    /// The folder from which a program ROM was last loaded
+    pub(crate) roms_path: Option<PathBuf>,
+    /// The emulation options last used
+    pub(crate) options: Options,
+    /// The sound settings last used
+    pub(crate) audio: AudioConfig,
+    /// Whether the debugging panels were displayed in floating windows
+    pub(crate) detach_debug_panels: bool,
+    /// What happens to a running program when the window loses focus
+    pub(crate) focus_loss_action: FocusLossAction,
+}
+
+impl Default for Settings {
+    /// Constructor that returns the settings used when no settings file exists
+    fn default() -> Self {
+        Settings {
+            foreground_colour: None,
+            background_colour: None,
+            key_mapping: DEFAULT_KEY_MAPPING
+                .iter()
+                .map(|key| format!("{:?}", key))
+                .collect(),
+            window_size: None,
+            window_position: None,
+            roms_path: None,
+            options: Options::default(),
+            audio: AudioConfig::default(),
+            detach_debug_panels: false,
+            focus_loss_action: FocusLossAction::Continue,
+        }
+    }
+}
+
+impl Settings {
+    /// Builder method that loads the settings file, returning the default settings if it does
+    /// not exist or cannot be parsed
+    pub(crate) fn load() -> Self {
+        if let Some(file_path) = settings_file_path() {
+            if let Ok(toml_string) = std::fs::read_to_string(file_path) {
+                if let Ok(settings) = toml::from_str::<Settings>(&toml_string) {
+                    // Bring options saved by an earlier release up to date, falling back to the
+                    // defaults if they were saved by a later one, and carry over any colours
+                    // saved before the palette was held in the options
+                    let mut options: Options = settings.options.migrate().unwrap_or_default();
+                    if let Some(colour) = settings.background_colour {
+                        options.palette.colours_mut()[0] = colour;
+                    }
+                    if let Some(colour) = settings.foreground_colour {
+                        options.palette.colours_mut()[1] = colour;
+                    }
+                    return Settings {
+                        options,
+                        foreground_colour: None,
+                        background_colour: None,
+                        ..settings
+                    };
+                }
+            }
+        }
+        Settings::default()
+    }
+
+    /// Writes the settings file, creating its folder if required
+    pub(crate) fn save(&self) {
+        if let Some(file_path) = settings_file_path() {
+            if let (Some(folder), Ok(toml_string)) =
+                (file_path.parent(), toml::to_string_pretty(self))
+            {
+                if let Err(_) = std::fs::create_dir_all(folder)
+                    .and_then(|_| std::fs::write(file_path, toml_string))
+                {
+                    // absorb the error; settings are simply not persisted
+                }
+            }
+        }
+    }
+
+    /// Returns the keyboard key mapped to each CHIP-8 key, falling back to the default mapping
+    /// for any entry that is missing or does not name a mappable key
+    pub(crate) fn key_mapping(&self) -> [Key; 16] {
+        let mut key_mapping: [Key; 16] = DEFAULT_KEY_MAPPING;
+        for (mapped_key, name) in key_mapping.iter_mut().zip(&self.key_mapping) {
+            if let Some(key) = MAPPABLE_KEYS
+                .iter()
+                .find(|key| format!("{:?}", key).eq_ignore_ascii_case(name))
+            {
+                *mapped_key = *key;
+            }
+        }
+        key_mapping
+    }
+}
+
+/// Helper function that returns the path of the settings file within the user's configuration
+/// directory (the roaming application data folder on Windows, otherwise the XDG configuration
+/// folder), or None if this cannot be determined
+fn settings_file_path() -> Option<PathBuf> {
+    let config_path: PathBuf = std::env::var_os("APPDATA")
+        .or_else(|| std::env::var_os("XDG_CONFIG_HOME"))
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(
+        config_path
+            .join(PATH_SETTINGS_DIRECTORY_NAME)
+            .join(PATH_SETTINGS_FILE_NAME),
+    )
+}
@@ -0,0 +1,397 @@
+use crate::instruction::Instruction;
+use crate::options::Options;
+use crate::prelude::*;
+use crate::processor::{
+    EmulationLevel, CHIP8_HIRES_ENTRY_OPCODE, CHIP8_HIRES_PROGRAM_START_ADDRESS,
+};
+use crate::program::Program;
+use alloc::collections::BTreeSet;
+
+/// An instruction found by [check_compatibility()] that would raise
+/// [ErrorDetail::UnknownInstruction](crate::ErrorDetail::UnknownInstruction) if executed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompatibleInstruction {
+    /// The address of the instruction, with the program loaded at the configured start address
+    pub address: u16,
+    /// The opcode of the instruction
+    pub opcode: u16,
+    /// The mnemonic of the instruction (such as "FX30"), or "????" if it is not recognised at
+    /// any emulation level
+    pub mnemonic: String,
+}
+
+/// The results of a static compatibility check, as returned by [check_compatibility()].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompatibilityReport {
+    /// The emulation level the program was checked against
+    pub emulation_level: EmulationLevel,
+    /// The reachable instructions that would raise an unknown instruction error, in address order
+    pub incompatible_instructions: Vec<IncompatibleInstruction>,
+}
+
+impl CompatibilityReport {
+    /// Returns true if no reachable instruction would raise an unknown instruction error
+    pub fn is_compatible(&self) -> bool {
+        self.incompatible_instructions.is_empty()
+    }
+}
+
+/// Scans the specified program for instructions that would raise
+/// [ErrorDetail::UnknownInstruction](crate::ErrorDetail::UnknownInstruction) at the emulation
+/// level given by the [Options] (such as 00FF or FX30 in CHIP-8 emulation mode), returning a
+/// [CompatibilityReport] listing them.  Rather than decoding every byte pair, which would mostly
+/// find sprites and other data, the scan follows the program's control flow from its entry
+/// point: both outcomes of each skip instruction are followed, as are subroutine calls and
+/// jumps, while paths end at returns, at computed jumps (BNNN) and at the exit instruction, as
+/// well as at any incompatible instruction found (beyond which execution could not continue).
+/// Code reached only through computed jumps is therefore not checked.
+///
+/// # Arguments
+///
+/// * `program` - the [Program] to check
+/// * `options` - the [Options] holding the emulation level and program start address to use
+pub fn check_compatibility(program: &Program, options: &Options) -> CompatibilityReport {
+    let data: &Vec<u8> = program.program_data();
+    let start_address: u16 = options.program_start_address;
+    // Execution starts beyond the interpreter patch for Hi-Res CHIP-8 programs
+    let entry_address: u16 = match options.emulation_level {
+        EmulationLevel::Chip8 { .. }
+            if options.chip8_hires_mode && data.starts_with(&CHIP8_HIRES_ENTRY_OPCODE) =>
+        {
+            CHIP8_HIRES_PROGRAM_START_ADDRESS
+        }
+        _ => start_address,
+    };
+    let mut incompatible_instructions: Vec<IncompatibleInstruction> = Vec::new();
+    let mut visited: BTreeSet<u16> = BTreeSet::new();
+    let mut pending: Vec<u16> = vec![entry_address];
+    while let Some(address) = pending.pop() {
+        if !visited.insert(address) {
+            continue;
+        }
+        // Paths leaving the program data (for example into the font) are not followed
+        let offset: usize = match address.checked_sub(start_address) {
+            Some(offset) if (offset as usize) + 1 < data.len() => offset as usize,
+            _ => continue,
+        };
+        let opcode: u16 = u16::from_be_bytes([data[offset], data[offset + 1]]);
+        let next_address: u16 = address.wrapping_add(2);
+        let instruction: Instruction = match Instruction::decode_from(opcode) {
+            Ok(instruction) => instruction,
+            Err(_) => {
+                incompatible_instructions.push(IncompatibleInstruction {
+                    address,
+                    opcode,
+                    mnemonic: "????".to_string(),
+                });
+                continue;
+            }
+        };
+        let superchip_incompatible: bool = match options.emulation_level {
+            EmulationLevel::SuperChip11 { .. } => false,
+            EmulationLevel::SuperChip10 { .. } => is_superchip11_instruction(&instruction),
+            _ => is_superchip_instruction(&instruction),
+        };
+        if superchip_incompatible {
+            incompatible_instructions.push(IncompatibleInstruction {
+                address,
+                opcode,
+                mnemonic: instruction.name().to_string(),
+            });
+            continue;
+        }
+        let chip8x: bool = matches!(options.emulation_level, EmulationLevel::Chip8X { .. });
+        if is_chip8x_instruction(&instruction) && !chip8x {
+            incompatible_instructions.push(IncompatibleInstruction {
+                address,
+                opcode,
+                mnemonic: instruction.name().to_string(),
+            });
+            continue;
+        }
+        match instruction {
+            // At the CHIP-8X level BNNN sets a colour rather than jumping
+            Instruction::OpBNNN { .. } if chip8x => pending.push(next_address),
+            Instruction::Op00EE | Instruction::Op00FD | Instruction::OpBNNN { .. } => (),
+            Instruction::Op1NNN { nnn } => pending.push(nnn),
+            Instruction::Op2NNN { nnn } => {
+                pending.push(nnn);
+                pending.push(next_address);
+            }
+            Instruction::Op5XY1 { .. } if chip8x => pending.push(next_address),
+            Instruction::Op3XNN { .. }
+            | Instruction::Op4XNN { .. }
+            | Instruction::Op5XY0 { .. }
+            | Instruction::Op5XY1 { .. }
+            | Instruction::Op9XY0 { .. }
+            | Instruction::OpEX9E { .. }
+            | Instruction::OpEXA1 { .. }
+            | Instruction::OpEXF2 { .. }
+            | Instruction::OpEXF5 { .. } => {
+                pending.push(next_address);
+                pending.push(next_address.wrapping_add(2));
+            }
+            _ => pending.push(next_address),
+        }
+    }
+    incompatible_instructions.sort_by_key(|instruction| instruction.address);
+    CompatibilityReport {
+        emulation_level: options.emulation_level,
+        incompatible_instructions,
+    }
+}
+
+/// Returns the lowest emulation level that recognises the specified opcode, if the opcode would
+/// raise [ErrorDetail::UnknownInstruction](crate::ErrorDetail::UnknownInstruction) at the
+/// emulation level passed but is recognised at another: SUPER-CHIP 1.0 for the SUPER-CHIP
+/// instructions other than scrolling, SUPER-CHIP 1.1 for the scroll instructions, and CHIP-8X
+/// for the instructions reading the second keypad or setting the tone pitch.  Hosts can so offer
+/// to restart a program at the level it evidently requires when it crashes.  The suggested level
+/// keeps the variable cycle timing setting of the level passed.  Returns None if the opcode is
+/// recognised at the level passed, or at no level.
+///
+/// # Arguments
+///
+/// * `opcode` - the opcode that could not be executed
+/// * `emulation_level` - the emulation level at which the opcode could not be executed
+pub fn suggest_emulation_level(
+    opcode: u16,
+    emulation_level: EmulationLevel,
+) -> Option<EmulationLevel> {
+    let instruction: Instruction = Instruction::decode_from(opcode).ok()?;
+    let variable_cycle_timing: bool = match emulation_level {
+        EmulationLevel::Chip8 {
+            variable_cycle_timing,
+            ..
+        }
+        | EmulationLevel::Chip8X {
+            variable_cycle_timing,
+        }
+        | EmulationLevel::Chip48 {
+            variable_cycle_timing,
+        }
+        | EmulationLevel::SuperChip10 {
+            variable_cycle_timing,
+        }
+        | EmulationLevel::SuperChip11 {
+            variable_cycle_timing,
+            ..
+        } => variable_cycle_timing,
+    };
+    let superchip11: EmulationLevel = EmulationLevel::SuperChip11 {
+        octo_compatibility_mode: false,
+        variable_cycle_timing,
+    };
+    match emulation_level {
+        EmulationLevel::SuperChip11 { .. } => None,
+        EmulationLevel::SuperChip10 { .. } => {
+            is_superchip11_instruction(&instruction).then_some(superchip11)
+        }
+        _ if is_superchip11_instruction(&instruction) => Some(superchip11),
+        _ if is_superchip_instruction(&instruction) => Some(EmulationLevel::SuperChip10 {
+            variable_cycle_timing,
+        }),
+        EmulationLevel::Chip8X { .. } => None,
+        _ if is_chip8x_instruction(&instruction) => Some(EmulationLevel::Chip8X {
+            variable_cycle_timing,
+        }),
+        _ => None,
+    }
+}
+
+/// Helper function that returns true if the passed instruction is only recognised in SUPER-CHIP
+/// (1.0 or 1.1) emulation mode
+///
+/// # Arguments
+///
+/// * `instruction` - the decoded instruction to check
+pub(crate) fn is_superchip_instruction(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Op00CN { .. }
+            | Instruction::Op00FB
+            | Instruction::Op00FC
+            | Instruction::Op00FD
+            | Instruction::Op00FE
+            | Instruction::Op00FF
+            | Instruction::OpFX30 { .. }
+            | Instruction::OpFX75 { .. }
+            | Instruction::OpFX85 { .. }
+    )
+}
+
+/// Helper function that returns true if the passed instruction is only recognised in SUPER-CHIP
+/// 1.1 emulation mode (the scroll instructions, which SUPER-CHIP 1.0 lacks)
+///
+/// # Arguments
+///
+/// * `instruction` - the decoded instruction to check
+pub(crate) fn is_superchip11_instruction(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Op00CN { .. } | Instruction::Op00FB | Instruction::Op00FC
+    )
+}
+
+/// Helper function that returns true if the passed instruction is only recognised in CHIP-8X
+/// emulation mode
+///
+/// # Arguments
+///
+/// * `instruction` - the decoded instruction to check
+pub(crate) fn is_chip8x_instruction(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::OpEXF2 { .. } | Instruction::OpEXF5 { .. } | Instruction::OpFXF8 { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_test_options(emulation_level: EmulationLevel) -> Options {
+        let mut options: Options = Options::default();
+        options.emulation_level = emulation_level;
+        options
+    }
+
+    #[test]
+    fn test_check_compatibility_chip8() {
+        // 0x200 hires on, 0x202 skip, 0x204 FX30, 0x206 loop; trailing "data" is never reached
+        let program: Program = Program::new(vec![
+            0x00, 0xFF, 0x30, 0x00, 0xF1, 0x30, 0x12, 0x06, 0xF0, 0x90,
+        ]);
+        let options: Options = setup_test_options(EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        });
+        let report: CompatibilityReport = check_compatibility(&program, &options);
+        assert_eq!(
+            report.incompatible_instructions,
+            vec![IncompatibleInstruction {
+                address: 0x200,
+                opcode: 0x00FF,
+                mnemonic: "00FF".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_compatibility_follows_skips() {
+        // 0x200 skip, 0x202 jump to end, 0x204 FX30, 0x206 loop
+        let program: Program = Program::new(vec![0x30, 0x00, 0x12, 0x06, 0xF1, 0x30, 0x12, 0x06]);
+        let options: Options = setup_test_options(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        let report: CompatibilityReport = check_compatibility(&program, &options);
+        assert!(
+            !report.is_compatible()
+                && report.incompatible_instructions[0].address == 0x204
+                && report.incompatible_instructions[0].mnemonic == "FX30"
+        );
+    }
+
+    #[test]
+    fn test_check_compatibility_superchip() {
+        // 0x200 hires on, 0x202 call 0x208, 0x204 loop, 0x206 data, 0x208 FX30, 0x20A return
+        let program: Program = Program::new(vec![
+            0x00, 0xFF, 0x22, 0x08, 0x12, 0x04, 0xF0, 0x01, 0xF1, 0x30, 0x00, 0xEE,
+        ]);
+        let options: Options = setup_test_options(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        assert!(check_compatibility(&program, &options).is_compatible());
+    }
+
+    #[test]
+    fn test_check_compatibility_chip8x() {
+        // 0x300 set zone colour, 0x302 skip on second keypad, 0x304 loop, 0x306 loop
+        let program: Program = Program::new(vec![0xB0, 0x10, 0xE1, 0xF2, 0x13, 0x04, 0x13, 0x06]);
+        let mut options: Options = setup_test_options(EmulationLevel::Chip8X {
+            variable_cycle_timing: false,
+        });
+        options.program_start_address = 0x300;
+        let chip8x_report: CompatibilityReport = check_compatibility(&program, &options);
+        options.emulation_level = EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        };
+        let chip8_report: CompatibilityReport = check_compatibility(&program, &options);
+        // At the CHIP-8 level B010 is a jump to 0x010 + V0, so EXF2 is never reached
+        assert!(chip8x_report.is_compatible() && chip8_report.is_compatible());
+        options.program_start_address = 0x302;
+        let program: Program = Program::new(vec![0xE1, 0xF2, 0x13, 0x04]);
+        assert_eq!(
+            check_compatibility(&program, &options).incompatible_instructions,
+            vec![IncompatibleInstruction {
+                address: 0x302,
+                opcode: 0xE1F2,
+                mnemonic: "EXF2".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_compatibility_unknown_opcode() {
+        let program: Program = Program::new(vec![0x60, 0x01, 0xE0, 0x01]);
+        let options: Options = setup_test_options(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        let report: CompatibilityReport = check_compatibility(&program, &options);
+        assert_eq!(
+            report.incompatible_instructions,
+            vec![IncompatibleInstruction {
+                address: 0x202,
+                opcode: 0xE001,
+                mnemonic: "????".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_suggest_emulation_level() {
+        let chip8: EmulationLevel = EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: true,
+        };
+        assert_eq!(
+            suggest_emulation_level(0x00FF, chip8),
+            Some(EmulationLevel::SuperChip10 {
+                variable_cycle_timing: true
+            })
+        );
+        assert_eq!(
+            suggest_emulation_level(0x00C4, chip8),
+            Some(EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: true
+            })
+        );
+        assert_eq!(
+            suggest_emulation_level(0xE1F2, chip8),
+            Some(EmulationLevel::Chip8X {
+                variable_cycle_timing: true
+            })
+        );
+        assert_eq!(suggest_emulation_level(0x6001, chip8), None);
+        assert_eq!(suggest_emulation_level(0xE001, chip8), None);
+    }
+
+    #[test]
+    fn test_suggest_emulation_level_superchip() {
+        let superchip10: EmulationLevel = EmulationLevel::SuperChip10 {
+            variable_cycle_timing: false,
+        };
+        assert_eq!(suggest_emulation_level(0xF130, superchip10), None);
+        assert_eq!(
+            suggest_emulation_level(0x00FB, superchip10),
+            Some(EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false
+            })
+        );
+    }
+}
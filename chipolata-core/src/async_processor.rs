@@ -0,0 +1,419 @@
+use crate::{
+    ChipolataError, ErrorDetail, Key, Options, Processor, ProcessorStatus, Program, StateSnapshot,
+    StateSnapshotVerbosity,
+};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender, TryRecvError};
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+
+/// An enum with variants representing the messages an async host may send to a [Processor]
+/// driven by an [AsyncProcessorHandle]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsyncProcessorCommand {
+    /// Request a state snapshot of the specified verbosity, to be returned as an
+    /// [AsyncProcessorEvent::StateSnapshotReport]
+    RequestStateSnapshot { verbosity: StateSnapshotVerbosity },
+    /// Notify of a key press or release event
+    KeyPressEvent { key: Key, pressed: bool },
+    /// Change the processor speed
+    SetProcessorSpeed { new_speed: u64 },
+    /// Pause execution
+    Pause,
+    /// Resume execution, if paused
+    Resume,
+    /// Reset the processor to its state immediately after the program was loaded (recovering
+    /// from a crash, if one has occurred)
+    Reset,
+    /// Overwrite a byte of memory (execution must be paused)
+    WriteMemoryByte { address: usize, value: u8 },
+}
+
+/// An enum with variants representing the messages an [AsyncProcessorHandle] passes back to its
+/// async host
+#[derive(Debug, PartialEq)]
+pub enum AsyncProcessorEvent {
+    /// A state snapshot, as requested through [AsyncProcessorCommand::RequestStateSnapshot]
+    StateSnapshotReport { snapshot: StateSnapshot },
+    /// An error raised by the processor, whether while executing cycles or carrying out a
+    /// command.  No further cycles are executed until an [AsyncProcessorCommand::Reset] is
+    /// successfully carried out.
+    ErrorReport { error: ChipolataError },
+    /// A command that could not be carried out (such as a memory write while execution is not
+    /// paused, or a pause while already paused), which leaves the processor as it was and
+    /// execution continuing
+    CommandRejected { error: ErrorDetail },
+}
+
+/// A handle to a [Processor] whose execution loop is owned by a tokio task, for use by async
+/// hosts such as web servers or networked front-ends.  This mirrors the worker thread design of
+/// the Chipolata desktop app: the task continually executes cycles at the configured processor
+/// speed, carrying out any [AsyncProcessorCommand] sent to it between cycles and passing back
+/// [AsyncProcessorEvent] messages through an async channel.
+///
+/// As the execution loop is CPU-bound it runs on tokio's blocking thread pool, so it does not
+/// starve other tasks on the runtime; while paused, crashed or idle waiting for a key press it
+/// blocks on the command channel rather than spinning.  The loop ends when [AsyncProcessorHandle::terminate()] is
+/// called or the handle is dropped.
+///
+/// Only available when the `async` feature is enabled.
+pub struct AsyncProcessorHandle {
+    command_tx: Sender<AsyncProcessorCommand>,
+    event_rx: UnboundedReceiver<AsyncProcessorEvent>,
+    task: JoinHandle<()>,
+}
+
+impl AsyncProcessorHandle {
+    /// Constructor that instantiates a [Processor] with the supplied program loaded, then spawns a
+    /// tokio task to own it and continually execute cycles.  Returns the [ChipolataError] raised
+    /// if the processor cannot be initialised.  Must be called from within a tokio runtime.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - a [Program] instance holding the bytes of the ROM to be executed
+    /// * `options` - an [Options] instance holding Chipolata start-up configuration information
+    pub fn spawn(program: Program, options: Options) -> Result<Self, ChipolataError> {
+        let processor: Processor = Processor::initialise_and_load(program, options)?;
+        let (command_tx, command_rx) = mpsc::channel();
+        let (event_tx, event_rx) = tokio::sync::mpsc::unbounded_channel();
+        let task: JoinHandle<()> = tokio::task::spawn_blocking(move || {
+            run_execution_loop(processor, command_rx, event_tx)
+        });
+        Ok(AsyncProcessorHandle {
+            command_tx,
+            event_rx,
+            task,
+        })
+    }
+
+    /// Sends a command to the processor, to be carried out before the next cycle executes.
+    /// Returns false if the execution loop has ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - the command to send
+    pub fn send(&self, command: AsyncProcessorCommand) -> bool {
+        self.command_tx.send(command).is_ok()
+    }
+
+    /// Waits for the next event from the processor.  Returns `None` if the execution loop has
+    /// ended and all events have been received.
+    pub async fn recv(&mut self) -> Option<AsyncProcessorEvent> {
+        self.event_rx.recv().await
+    }
+
+    /// Ends the execution loop, waiting for the task to finish
+    pub async fn terminate(self) {
+        let AsyncProcessorHandle {
+            command_tx,
+            event_rx,
+            task,
+        } = self;
+        // Closing the command channel signals the execution loop to end
+        drop(command_tx);
+        drop(event_rx);
+        if let Err(_) = task.await {
+            // absorb the error; the loop has ended either way
+        }
+    }
+}
+
+/// The execution loop owned by the tokio task spawned by [AsyncProcessorHandle::spawn()], which
+/// carries out any waiting commands then executes a cycle, until the command channel is closed.
+/// While there is nothing to execute the loop waits on the command channel instead, so commands
+/// (such as key presses) are carried out as soon as they arrive.
+///
+/// # Arguments
+///
+/// * `processor` - the processor to drive
+/// * `command_rx` - the channel on which commands are received from the host
+/// * `event_tx` - the channel on which events are sent to the host
+fn run_execution_loop(
+    mut processor: Processor,
+    command_rx: Receiver<AsyncProcessorCommand>,
+    event_tx: UnboundedSender<AsyncProcessorEvent>,
+) {
+    let mut crashed: bool = false;
+    loop {
+        // Carry out any commands waiting from the host; once crashed or paused there is nothing to
+        // execute, so block until a command arrives rather than spinning, and while idle waiting
+        // for a key press block until a command arrives or the next timer tick is due
+        let mut commands: Vec<AsyncProcessorCommand> = Vec::new();
+        let paused: bool = processor.state_view().status == ProcessorStatus::Paused;
+        if crashed || paused {
+            match command_rx.recv() {
+                Ok(command) => commands.push(command),
+                Err(_) => return,
+            }
+        } else if let Some(duration) = processor.idle_duration() {
+            match command_rx.recv_timeout(duration) {
+                Ok(command) => commands.push(command),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        loop {
+            match command_rx.try_recv() {
+                Ok(command) => commands.push(command),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+        for command in commands {
+            let result: Result<(), ChipolataError> = match command {
+                AsyncProcessorCommand::RequestStateSnapshot { verbosity } => {
+                    let snapshot: StateSnapshot = processor.export_state_snapshot(verbosity);
+                    send_event(
+                        &event_tx,
+                        AsyncProcessorEvent::StateSnapshotReport { snapshot },
+                    );
+                    Ok(())
+                }
+                AsyncProcessorCommand::KeyPressEvent { key, pressed } => {
+                    processor.set_key_status(key, pressed);
+                    Ok(())
+                }
+                AsyncProcessorCommand::SetProcessorSpeed { new_speed } => {
+                    processor.set_processor_speed(new_speed);
+                    Ok(())
+                }
+                AsyncProcessorCommand::Pause => {
+                    match refused_transition(&processor, ProcessorStatus::Paused) {
+                        Some(error) => {
+                            send_event(&event_tx, AsyncProcessorEvent::CommandRejected { error });
+                            Ok(())
+                        }
+                        None => processor.pause_execution(),
+                    }
+                }
+                AsyncProcessorCommand::Resume => {
+                    match refused_transition(&processor, ProcessorStatus::Running) {
+                        Some(error) => {
+                            send_event(&event_tx, AsyncProcessorEvent::CommandRejected { error });
+                            Ok(())
+                        }
+                        None => processor.resume_execution(),
+                    }
+                }
+                AsyncProcessorCommand::Reset => match processor.reset() {
+                    Ok(_) => {
+                        crashed = false;
+                        Ok(())
+                    }
+                    Err(error) => Err(error),
+                },
+                AsyncProcessorCommand::WriteMemoryByte { address, value } => {
+                    if let Err(error) = processor.write_memory_byte(address, value) {
+                        send_event(&event_tx, AsyncProcessorEvent::CommandRejected { error });
+                    }
+                    Ok(())
+                }
+            };
+            if let Err(error) = result {
+                crashed = true;
+                send_event(&event_tx, AsyncProcessorEvent::ErrorReport { error });
+            }
+        }
+        // Run a processor cycle, unless crashed, paused or still idle waiting for a key press (in
+        // which case the wait on the command channel above is repeated)
+        let paused: bool = processor.state_view().status == ProcessorStatus::Paused;
+        if !crashed && !paused && processor.idle_duration().is_none() {
+            if let Err(error) = processor.execute_cycle() {
+                crashed = true;
+                send_event(&event_tx, AsyncProcessorEvent::ErrorReport { error });
+            }
+        }
+    }
+}
+
+/// Helper function that returns the error with which a pause or resume commanded by the host is
+/// rejected, if the processor's current status does not permit it (such as resuming while
+/// waiting for a key press, or pausing once halted), or `None` if it can be carried out.  Such
+/// commands are rejected rather than passed on to [Processor::pause_execution()] or
+/// [Processor::resume_execution()], which would crash the processor.
+///
+/// # Arguments
+///
+/// * `processor` - the processor to pause or resume
+/// * `new_state` - [ProcessorStatus::Paused] to pause, or [ProcessorStatus::Running] to resume
+fn refused_transition(processor: &Processor, new_state: ProcessorStatus) -> Option<ErrorDetail> {
+    let old_state: ProcessorStatus = processor.state_view().status;
+    let permitted: bool = match new_state {
+        ProcessorStatus::Paused => matches!(
+            old_state,
+            ProcessorStatus::ProgramLoaded
+                | ProcessorStatus::Running
+                | ProcessorStatus::WaitingForKeypress
+                | ProcessorStatus::Paused
+        ),
+        _ => matches!(
+            old_state,
+            ProcessorStatus::ProgramLoaded | ProcessorStatus::Running | ProcessorStatus::Paused
+        ),
+    };
+    match permitted {
+        true => None,
+        false => Some(ErrorDetail::StateTransitionError {
+            old_state,
+            new_state,
+        }),
+    }
+}
+
+/// Helper function that sends an event to the host, ignoring the error raised if the host has
+/// dropped its receiver
+///
+/// # Arguments
+///
+/// * `event_tx` - the channel on which events are sent to the host
+/// * `event` - the event to send
+fn send_event(event_tx: &UnboundedSender<AsyncProcessorEvent>, event: AsyncProcessorEvent) {
+    if let Err(_) = event_tx.send(event) {
+        // absorb the error; no need to handle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EmulationLevel, ProcessorStatus};
+
+    fn setup_test_options() -> Options {
+        let mut options: Options = Options::default();
+        options.emulation_level = EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        };
+        options
+    }
+
+    /// Sends a request for a minimal state snapshot and waits for it to be returned
+    async fn request_snapshot(handle: &mut AsyncProcessorHandle) -> StateSnapshot {
+        assert!(handle.send(AsyncProcessorCommand::RequestStateSnapshot {
+            verbosity: StateSnapshotVerbosity::Minimal,
+        }));
+        match handle.recv().await {
+            Some(AsyncProcessorEvent::StateSnapshotReport { snapshot }) => snapshot,
+            event => panic!("unexpected event {:?}", event),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_state_snapshot() {
+        // 6005 (V0 = 5), 1202 (loop forever)
+        let program: Program = Program::new(vec![0x60, 0x05, 0x12, 0x02]);
+        let mut handle: AsyncProcessorHandle =
+            AsyncProcessorHandle::spawn(program, setup_test_options()).unwrap();
+        assert!(handle.send(AsyncProcessorCommand::Pause));
+        match request_snapshot(&mut handle).await {
+            StateSnapshot::MinimalSnapshot { status, .. } => {
+                assert_eq!(status, ProcessorStatus::Paused)
+            }
+            snapshot => panic!("unexpected snapshot {:?}", snapshot),
+        }
+        handle.terminate().await;
+    }
+
+    #[tokio::test]
+    async fn test_error_report_and_reset() {
+        // 00FF (high resolution mode; unknown in CHIP-48 emulation mode)
+        let program: Program = Program::new(vec![0x00, 0xFF]);
+        let mut handle: AsyncProcessorHandle =
+            AsyncProcessorHandle::spawn(program, setup_test_options()).unwrap();
+        assert!(matches!(
+            handle.recv().await,
+            Some(AsyncProcessorEvent::ErrorReport { .. })
+        ));
+        // Once reset, execution resumes (and so the same error is raised again)
+        assert!(handle.send(AsyncProcessorCommand::Reset));
+        assert!(matches!(
+            handle.recv().await,
+            Some(AsyncProcessorEvent::ErrorReport { .. })
+        ));
+        handle.terminate().await;
+    }
+
+    #[tokio::test]
+    async fn test_resume_rejected_while_waiting_for_keypress() {
+        // F00A (wait for a key press), 1202 (loop forever)
+        let program: Program = Program::new(vec![0xF0, 0x0A, 0x12, 0x02]);
+        let mut handle: AsyncProcessorHandle =
+            AsyncProcessorHandle::spawn(program, setup_test_options()).unwrap();
+        // Once waiting, a resume is refused without crashing the processor
+        while !matches!(
+            request_snapshot(&mut handle).await,
+            StateSnapshot::MinimalSnapshot {
+                status: ProcessorStatus::WaitingForKeypress,
+                ..
+            }
+        ) {}
+        assert!(handle.send(AsyncProcessorCommand::Resume));
+        assert!(matches!(
+            handle.recv().await,
+            Some(AsyncProcessorEvent::CommandRejected {
+                error: ErrorDetail::StateTransitionError { .. }
+            })
+        ));
+        // The processor carries on waiting
+        match request_snapshot(&mut handle).await {
+            StateSnapshot::MinimalSnapshot { status, .. } => {
+                assert_eq!(status, ProcessorStatus::WaitingForKeypress)
+            }
+            snapshot => panic!("unexpected snapshot {:?}", snapshot),
+        }
+        handle.terminate().await;
+    }
+
+    #[tokio::test]
+    async fn test_command_received_while_idle() {
+        // F00A (wait for a key press), 1202 (loop forever)
+        let program: Program = Program::new(vec![0xF0, 0x0A, 0x12, 0x02]);
+        // At one timer tick a second, an idle wait lasts up to a second
+        let mut options: Options = setup_test_options();
+        options.timer_frequency_hertz = 1;
+        let mut handle: AsyncProcessorHandle =
+            AsyncProcessorHandle::spawn(program, options).unwrap();
+        while !matches!(
+            request_snapshot(&mut handle).await,
+            StateSnapshot::MinimalSnapshot {
+                status: ProcessorStatus::WaitingForKeypress,
+                ..
+            }
+        ) {}
+        // A command sent while idle is carried out without waiting for the next timer tick
+        let sent: std::time::Instant = std::time::Instant::now();
+        assert!(handle.send(AsyncProcessorCommand::Pause));
+        match request_snapshot(&mut handle).await {
+            StateSnapshot::MinimalSnapshot { status, .. } => {
+                assert_eq!(status, ProcessorStatus::Paused)
+            }
+            snapshot => panic!("unexpected snapshot {:?}", snapshot),
+        }
+        assert!(sent.elapsed() < std::time::Duration::from_millis(500));
+        handle.terminate().await;
+    }
+
+    #[tokio::test]
+    async fn test_write_memory_byte_rejected_while_running() {
+        // 1200 (loop forever)
+        let program: Program = Program::new(vec![0x12, 0x00]);
+        let mut handle: AsyncProcessorHandle =
+            AsyncProcessorHandle::spawn(program, setup_test_options()).unwrap();
+        assert!(handle.send(AsyncProcessorCommand::WriteMemoryByte {
+            address: 0x300,
+            value: 0xAB
+        }));
+        assert!(matches!(
+            handle.recv().await,
+            Some(AsyncProcessorEvent::CommandRejected {
+                error: ErrorDetail::ProcessorNotPaused { .. }
+            })
+        ));
+        // Execution continues
+        match request_snapshot(&mut handle).await {
+            StateSnapshot::MinimalSnapshot { status, .. } => {
+                assert_eq!(status, ProcessorStatus::Running)
+            }
+            snapshot => panic!("unexpected snapshot {:?}", snapshot),
+        }
+        handle.terminate().await;
+    }
+}
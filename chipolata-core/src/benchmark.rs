@@ -0,0 +1,45 @@
+use core::time::Duration;
+
+/// The results of a benchmark run, as returned by
+/// [Processor::run_benchmark()](crate::Processor::run_benchmark).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BenchmarkReport {
+    /// The number of processor cycles (instructions) executed during the run
+    pub cycles_executed: usize,
+    /// The wall-clock time taken to execute those cycles
+    pub duration: Duration,
+}
+
+impl BenchmarkReport {
+    /// Returns the average number of instructions executed per second over the run (or 0 if
+    /// no measurable time elapsed)
+    pub fn instructions_per_second(&self) -> u64 {
+        match self.duration.as_nanos() {
+            0 => 0,
+            nanos => (self.cycles_executed as u128 * 1_000_000_000_u128 / nanos) as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instructions_per_second() {
+        let report: BenchmarkReport = BenchmarkReport {
+            cycles_executed: 5000,
+            duration: Duration::from_millis(250),
+        };
+        assert_eq!(report.instructions_per_second(), 20000);
+    }
+
+    #[test]
+    fn test_instructions_per_second_zero_duration() {
+        let report: BenchmarkReport = BenchmarkReport {
+            cycles_executed: 5000,
+            duration: Duration::ZERO,
+        };
+        assert_eq!(report.instructions_per_second(), 0);
+    }
+}
@@ -0,0 +1,170 @@
+//! A small library of public-domain test ROMs embedded in the crate (only when the
+//! `builtin-roms` feature is enabled), so that hosts can offer them without shipping ROM files
+//! and tests can run them without depending on the filesystem.
+
+use crate::processor::EmulationLevel;
+use crate::program::Program;
+
+/// A test ROM embedded in the crate, as listed by [list()]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BuiltinRom {
+    /// The name by which the ROM is retrieved with [get()]
+    pub name: &'static str,
+    /// The author of the ROM
+    pub author: &'static str,
+    /// A short description of what the ROM tests
+    pub description: &'static str,
+    /// The emulation level for which the ROM was written
+    pub emulation_level: EmulationLevel,
+    /// The bytes of the ROM
+    pub data: &'static [u8],
+}
+
+impl BuiltinRom {
+    /// Returns a [Program] holding a copy of the ROM's data
+    pub fn program(&self) -> Program {
+        Program::new(self.data.to_vec())
+    }
+}
+
+/// The emulation level of the original COSMAC VIP interpreter, for which most test ROMs are written
+const CHIP8: EmulationLevel = EmulationLevel::Chip8 {
+    memory_limit_2k: false,
+    variable_cycle_timing: false,
+};
+
+/// The ROMs embedded in the crate, in the order they are listed
+const BUILTIN_ROMS: [BuiltinRom; 7] = [
+    BuiltinRom {
+        name: "IBM Logo",
+        author: "unknown",
+        description: "Draws the IBM logo, using only the most basic instructions",
+        emulation_level: CHIP8,
+        data: include_bytes!("../../resources/roms/tests/IBM Logo.ch8"),
+    },
+    BuiltinRom {
+        name: "BC_test",
+        author: "BestCoder",
+        description: "Tests each instruction in turn, displaying \"BON\" if all pass or an \
+                      error code for the first that fails",
+        emulation_level: EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        },
+        data: include_bytes!("../../resources/roms/tests/BonCoder.ch8"),
+    },
+    BuiltinRom {
+        name: "CHIP-8 Test Suite",
+        author: "Timendus",
+        description: "A menu of tests covering the instructions, flags, quirks and keypad \
+                      (including corax89's opcode test)",
+        emulation_level: CHIP8,
+        data: include_bytes!("../../resources/roms/tests/chip8-test-suite.ch8"),
+    },
+    BuiltinRom {
+        name: "Delay Timer Test",
+        author: "Matthew Mikolay",
+        description: "Counts the delay timer down from a value set with keys 2 and 8, \
+                      starting when key 5 is pressed",
+        emulation_level: CHIP8,
+        data: include_bytes!(
+            "../../resources/roms/tests/Delay Timer Test [Matthew Mikolay, 2010].ch8"
+        ),
+    },
+    BuiltinRom {
+        name: "Division Test",
+        author: "Sergey Naydenov",
+        description: "Performs integer division using repeated subtraction, testing \
+                      arithmetic and carry flags",
+        emulation_level: CHIP8,
+        data: include_bytes!(
+            "../../resources/roms/tests/Division Test [Sergey Naydenov, 2010].ch8"
+        ),
+    },
+    BuiltinRom {
+        name: "Random Number Test",
+        author: "Matthew Mikolay",
+        description: "Displays a new random number each time a key is pressed",
+        emulation_level: CHIP8,
+        data: include_bytes!(
+            "../../resources/roms/tests/Random Number Test [Matthew Mikolay, 2010].ch8"
+        ),
+    },
+    BuiltinRom {
+        name: "SQRT Test",
+        author: "Sergey Naydenov",
+        description: "Calculates integer square roots, testing arithmetic and carry flags",
+        emulation_level: CHIP8,
+        data: include_bytes!("../../resources/roms/tests/SQRT Test [Sergey Naydenov, 2010].ch8"),
+    },
+];
+
+/// Returns details of every ROM embedded in the crate
+pub fn list() -> &'static [BuiltinRom] {
+    &BUILTIN_ROMS
+}
+
+/// Returns a [Program] holding the embedded ROM with the specified name (as listed by [list()]),
+/// or None if there is no such ROM
+///
+/// # Arguments
+///
+/// * `name` - the name of the ROM
+pub fn get(name: &str) -> Option<Program> {
+    BUILTIN_ROMS
+        .iter()
+        .find(|rom| rom.name == name)
+        .map(BuiltinRom::program)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headless::{run_rom_to_completion, HeadlessRunReport};
+    use crate::options::Options;
+
+    fn run_builtin_rom(name: &str, cycle_budget: usize) -> HeadlessRunReport {
+        let rom: &BuiltinRom = list().iter().find(|rom| rom.name == name).unwrap();
+        let mut options: Options = Options::default();
+        options.emulation_level = rom.emulation_level;
+        run_rom_to_completion(rom.program(), options, cycle_budget).unwrap()
+    }
+
+    #[test]
+    fn test_list() {
+        let roms: &[BuiltinRom] = list();
+        assert!(
+            roms.iter().all(|rom| !rom.data.is_empty())
+                && roms
+                    .iter()
+                    .enumerate()
+                    .all(|(index, rom)| roms[..index].iter().all(|other| other.name != rom.name))
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        assert!(
+            get("IBM Logo").unwrap().program_data().as_slice() == BUILTIN_ROMS[0].data
+                && get("Unknown ROM").is_none()
+        );
+    }
+
+    #[test]
+    fn test_ibm_logo() {
+        // Golden value as per the ROM file integration test
+        assert_eq!(
+            run_builtin_rom("IBM Logo", 1000).frame_buffer_hash,
+            0xE2B469D06EA428D1
+        );
+    }
+
+    #[test]
+    fn test_bc_test() {
+        // Golden value as per the ROM file integration test
+        assert_eq!(
+            run_builtin_rom("BC_test", 5000).frame_buffer_hash,
+            0x1A59A10941817845
+        );
+    }
+}
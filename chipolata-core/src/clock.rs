@@ -0,0 +1,211 @@
+use crate::prelude::*;
+use core::ops::{Add, AddAssign, Sub};
+use core::time::Duration;
+
+/// The final part of each [SystemClock] wait (in microseconds) spent spinning rather than
+/// sleeping, as thread sleeps are not precise enough to wake on time
+#[cfg(feature = "std")]
+const SYSTEM_CLOCK_SPIN_MICROSECONDS: u64 = 500;
+
+/// An extensibility point allowing hosting applications to supply the time source by which the
+/// processor measures wall-clock time (to decrement the timers, raise the vblank interrupt and
+/// pace execution to the configured processor speed) and through which it waits.
+///
+/// With the `std` feature the processor uses a [SystemClock] unless another is registered
+/// through [Processor::set_clock()](crate::Processor::set_clock).  Without it there is no system
+/// clock, so the processor starts with a [VirtualClock]; embedded hosts will normally register
+/// a clock reading a hardware timer instead.
+pub trait Clock: Send {
+    /// Returns the time elapsed since some fixed moment (for example, since the clock was
+    /// created or the device powered on).  Successive calls must never go backwards.
+    fn now(&self) -> Duration;
+
+    /// Waits for (at least) the specified duration, called when execution has run ahead of the
+    /// configured processor speed.  Implementations may sleep, busy-wait or do other work.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - the time to wait
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// A [Clock] reading the system's monotonic clock, which waits by sleeping the thread (spinning
+/// for the final [SYSTEM_CLOCK_SPIN_MICROSECONDS] so as to wake on time)
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone)]
+pub struct SystemClock {
+    epoch: std::time::Instant, // The moment from which the time is measured
+}
+
+#[cfg(feature = "std")]
+impl SystemClock {
+    /// Constructor that returns a [SystemClock] measuring time from now
+    pub fn new() -> Self {
+        SystemClock {
+            epoch: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.epoch.elapsed()
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        let wake: std::time::Instant = std::time::Instant::now() + duration;
+        let spin: Duration = Duration::from_micros(SYSTEM_CLOCK_SPIN_MICROSECONDS);
+        if duration > spin {
+            std::thread::sleep(duration - spin);
+        }
+        while std::time::Instant::now() < wake {
+            // spin
+        }
+    }
+}
+
+/// A [Clock] whose time only advances when the processor waits, by exactly the time waited, so
+/// that execution runs as fast as it is driven while the timers and vblank interrupt count
+/// emulated time (the cycles executed at the configured processor speed).  This is the default
+/// clock without the `std` feature; it does not advance in turbo mode, where nothing waits.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct VirtualClock {
+    now: Duration, // The time waited in total
+}
+
+impl VirtualClock {
+    /// Constructor that returns a [VirtualClock] starting from zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Duration {
+        self.now
+    }
+
+    fn sleep(&mut self, duration: Duration) {
+        self.now += duration;
+    }
+}
+
+/// How far each [Instant] is offset from the reading of the [Clock] from which it is taken, so
+/// that moments shortly before a clock started can still be represented (for example when the
+/// progress through the timer interval is carried over to a newly registered clock)
+const INSTANT_OFFSET: Duration = Duration::from_secs(3600);
+
+/// Returns the clock with which the processor starts: a [SystemClock] with the `std` feature,
+/// otherwise a [VirtualClock]
+pub(crate) fn default_clock() -> Box<dyn Clock> {
+    #[cfg(feature = "std")]
+    let clock: Box<dyn Clock> = Box::new(SystemClock::new());
+    #[cfg(not(feature = "std"))]
+    let clock: Box<dyn Clock> = Box::new(VirtualClock::new());
+    clock
+}
+
+/// A moment in time read from a [Clock], held as the duration since the clock's fixed starting
+/// point (plus [INSTANT_OFFSET]).  Mirrors the parts of the `std::time::Instant` interface used
+/// by the processor.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Instant(Duration);
+
+impl Instant {
+    /// Returns the current moment according to the passed clock
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - the clock to read
+    pub(crate) fn now(clock: &dyn Clock) -> Self {
+        Instant(clock.now() + INSTANT_OFFSET)
+    }
+
+    /// Returns the time elapsed from the passed (earlier) moment to this one, or zero if the
+    /// passed moment is later
+    ///
+    /// # Arguments
+    ///
+    /// * `earlier` - the moment from which to measure
+    pub(crate) fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+
+    /// Returns the moment the passed duration before this one, or None if it would precede the
+    /// clock's starting point
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - the time to go back
+    pub(crate) fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+        self.0.checked_sub(duration).map(Instant)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    fn add(self, duration: Duration) -> Instant {
+        Instant(self.0 + duration)
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Instant;
+
+    fn sub(self, duration: Duration) -> Instant {
+        Instant(self.0 - duration)
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, duration: Duration) {
+        self.0 += duration;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_clock_advances_by_sleeps() {
+        let mut clock: VirtualClock = VirtualClock::new();
+        assert_eq!(clock.now(), Duration::ZERO);
+        clock.sleep(Duration::from_micros(1500));
+        clock.sleep(Duration::from_micros(500));
+        assert_eq!(clock.now(), Duration::from_millis(2));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_system_clock_sleep() {
+        let mut clock: SystemClock = SystemClock::new();
+        let start: Duration = clock.now();
+        clock.sleep(Duration::from_millis(2));
+        assert!(clock.now() - start >= Duration::from_millis(2));
+    }
+
+    #[test]
+    fn test_instant_arithmetic() {
+        let mut clock: VirtualClock = VirtualClock::new();
+        clock.sleep(Duration::from_millis(5));
+        let now: Instant = Instant::now(&clock);
+        let later: Instant = now + Duration::from_millis(3);
+        assert!(
+            later.saturating_duration_since(now) == Duration::from_millis(3)
+                && now.saturating_duration_since(later) == Duration::ZERO
+                && now - Duration::from_millis(3) == later - Duration::from_millis(6)
+                && now.checked_sub(INSTANT_OFFSET) == Some(Instant(Duration::from_millis(5)))
+                && now.checked_sub(INSTANT_OFFSET * 2).is_none()
+        );
+    }
+}
@@ -0,0 +1,2286 @@
+use crate::prelude::*;
+use crate::{
+    error::{ErrorDetail, Operand},
+    EmulationLevel,
+};
+use core::cmp;
+use core::ops::{Index, IndexMut};
+use serde_derive::{Deserialize, Serialize};
+
+/// The default CHIP-8 display size (64 x 32 pixels).
+const LOW_RES_ROW_SIZE_PIXELS: usize = 64;
+const LOW_RES_COLUMN_SIZE_PIXELS: usize = 32;
+/// The high-resolution SUPER-CHIP 1.1 display size (128 x 64 pixels).
+const HIGH_RES_ROW_SIZE_PIXELS: usize = 128;
+const HIGH_RES_COLUMN_SIZE_PIXELS: usize = 64;
+/// The Hi-Res CHIP-8 display size (64 x 64 pixels, two pages of the default display).
+const CHIP8_HIRES_ROW_SIZE_PIXELS: usize = 64;
+const CHIP8_HIRES_COLUMN_SIZE_PIXELS: usize = 64;
+
+/// The number of bit planes held by the display (allowing up to four colours per pixel).
+pub const DISPLAY_PLANE_COUNT: usize = 2;
+/// The bitmask of the bit planes selected for drawing by default (only the first plane).
+const DEFAULT_SELECTED_PLANES: u8 = 0b01;
+/// The width in pixels of a CHIP-8X colour zone (each zone is one pixel row high).
+pub const CHIP8X_ZONE_WIDTH_PIXELS: usize = 8;
+/// The number of colour zones in each row of the CHIP-8X display.
+const CHIP8X_ZONES_PER_ROW: usize = LOW_RES_ROW_SIZE_PIXELS / CHIP8X_ZONE_WIDTH_PIXELS;
+/// The colour of every CHIP-8X zone at start-up (red).
+const CHIP8X_DEFAULT_ZONE_COLOUR: u8 = 1;
+/// The CHIP-8X background colours, in the order through which instruction 02A0 cycles them
+/// (blue, black, green, red).
+const CHIP8X_BACKGROUND_COLOURS: [u8; 4] = [2, 0, 4, 1];
+/// The frame type byte of a [encode_delta()] encoding holding the frame in full
+const DELTA_KEY_FRAME: u8 = 0x00;
+/// The frame type byte of a [encode_delta()] encoding holding the changes from the previous frame
+const DELTA_CHANGE_FRAME: u8 = 0x01;
+/// The intensity of a lit pixel when ghost trails are tracked (see [Display::get_intensities()])
+pub const FULL_INTENSITY: u8 = 0xFF;
+
+/// An enum to indicate how sprite pixels overflowing the right-hand or bottom edges of the display
+/// are drawn.  Note the starting coordinates of a sprite always wrap, regardless of this setting.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum SpriteEdgeMode {
+    /// Overflowing pixels are not drawn
+    Clip,
+    /// Overflowing pixels wrap around to the opposite edge of the display
+    Wrap,
+}
+
+impl Default for SpriteEdgeMode {
+    /// Constructor that returns the default [SpriteEdgeMode] (clipping, as per the original
+    /// CHIP-8 interpreters)
+    fn default() -> Self {
+        SpriteEdgeMode::Clip
+    }
+}
+
+/// An enum to indicate the orientation in which the display should be presented, for ROMs written
+/// for platforms whose screens were mounted rotated (in portrait rather than landscape).  Each
+/// variant rotates the display clockwise by the specified number of degrees.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum DisplayOrientation {
+    /// Presented as drawn
+    Rotate0,
+    /// Rotated a quarter turn clockwise
+    Rotate90,
+    /// Rotated a half turn
+    Rotate180,
+    /// Rotated three quarters of a turn clockwise (i.e. a quarter turn anti-clockwise)
+    Rotate270,
+}
+
+impl Default for DisplayOrientation {
+    /// Constructor that returns the default [DisplayOrientation] (presented as drawn)
+    fn default() -> Self {
+        DisplayOrientation::Rotate0
+    }
+}
+
+/// An abstraction of the CHIP-8 frame buffer.
+///
+/// This is only instantiated and written to from within the Chipolata crate, but is exposed
+/// publically for read access by hosting applications so the display can be graphically rendered,
+/// via a [StateSnapshot](crate::StateSnapshot) obtained from a call to
+/// [Processor::export_state_snapshot()](crate::Processor::export_state_snapshot).
+///
+/// The display holds [DISPLAY_PLANE_COUNT] bit planes, as per the XO-CHIP extension.  Each pixel
+/// therefore has a colour index, with bit `n` of the index set if the pixel is on in plane `n`.
+/// Drawing, clearing and scrolling only affect the currently selected planes; by default only
+/// the first plane is selected, in which case the display behaves exactly as a traditional
+/// monochrome CHIP-8 display.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Display {
+    /// Logically this is a two-dimensional array (per bit plane) to hold the state of the display
+    /// pixels (1 means on, 0 means off).  Physically, due to the fact the array size isn't know at
+    /// compile time (as the display size varies depending on [EmulationLevel], this is implemented
+    /// as a heap-allocated one-dimensional byte array holding each plane in turn, with the
+    /// [core::ops::Index] trait implemented so as to simulate the expected 2D array indexing of the
+    /// first plane.
+    ///
+    /// Each inner array of bytes represents a row of the display, using one bit per pixel.  The outer
+    /// array is the collection of rows.  A coordinate within the display is therefore accessed
+    /// as `display[row][column]`. Note that (0, 0) is the top-left of the display, with positive
+    /// coordinates extending right and down.
+    row_size_bytes: usize,
+    column_size_pixels: usize,
+    pixels: Box<[u8]>,
+    /// Bitmask of the planes affected by drawing, clearing and scrolling (bit `n` selects plane `n`)
+    selected_planes: u8,
+    /// CHIP-8X only: the foreground colour of each zone (8 x 1 pixels), one row after another,
+    /// as a VP-590 colour code.  Empty at other emulation levels.
+    zone_colours: Box<[u8]>,
+    /// CHIP-8X only: the index into [CHIP8X_BACKGROUND_COLOURS] of the background colour
+    background_colour_index: usize,
+    /// Ghost trails only: the intensity of each pixel (one byte per pixel, row by row from the
+    /// top-left) as at the last frame, lit pixels being at [FULL_INTENSITY] and unlit pixels
+    /// fading each frame.  Empty unless intensities are being tracked.
+    #[serde(default)]
+    intensities: Box<[u8]>,
+}
+
+impl Clone for Display {
+    fn clone(&self) -> Self {
+        Display {
+            row_size_bytes: self.row_size_bytes,
+            column_size_pixels: self.column_size_pixels,
+            pixels: self.pixels.clone(),
+            selected_planes: self.selected_planes,
+            zone_colours: self.zone_colours.clone(),
+            background_colour_index: self.background_colour_index,
+            intensities: self.intensities.clone(),
+        }
+    }
+
+    // Copy into the existing pixel, zone colour and intensity arrays where their sizes match, so
+    // that refreshing a snapshot's frame buffer need not allocate
+    fn clone_from(&mut self, source: &Self) {
+        self.row_size_bytes = source.row_size_bytes;
+        self.column_size_pixels = source.column_size_pixels;
+        match self.pixels.len() == source.pixels.len() {
+            true => self.pixels.copy_from_slice(&source.pixels),
+            false => self.pixels = source.pixels.clone(),
+        }
+        self.selected_planes = source.selected_planes;
+        match self.zone_colours.len() == source.zone_colours.len() {
+            true => self.zone_colours.copy_from_slice(&source.zone_colours),
+            false => self.zone_colours = source.zone_colours.clone(),
+        }
+        self.background_colour_index = source.background_colour_index;
+        match self.intensities.len() == source.intensities.len() {
+            true => self.intensities.copy_from_slice(&source.intensities),
+            false => self.intensities = source.intensities.clone(),
+        }
+    }
+}
+
+// Allow the 1D Box<[u8]> to be indexed as a 2D array (of the first plane)
+impl Index<usize> for Display {
+    type Output = [u8];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.pixels[index * self.row_size_bytes..(index + 1) * self.row_size_bytes]
+    }
+}
+
+// Allow the 1D Box<[u8]> to be indexed as a 2D array (of the first plane) mutably
+impl IndexMut<usize> for Display {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.pixels[index * self.row_size_bytes..(index + 1) * self.row_size_bytes]
+    }
+}
+
+impl Display {
+    /// Constructor that returns a [Display] instance of default row and column size with all pixels
+    /// set to off.
+    pub(crate) fn new(emulation_level: EmulationLevel) -> Self {
+        // At the SUPER-CHIP 1.1 emulation level the display is by default always held at high
+        // resolution, with low-resolution mode simulated by drawing 2x2 pixels
+        let (row_size, column_size): (usize, usize) = Display::native_size(emulation_level, true);
+        // Only the CHIP-8X display holds colour attributes
+        let zone_count: usize = match emulation_level {
+            EmulationLevel::Chip8X { .. } => CHIP8X_ZONES_PER_ROW * column_size,
+            _ => 0,
+        };
+        Self {
+            row_size_bytes: row_size,
+            column_size_pixels: column_size,
+            pixels: vec![0x0; row_size * column_size * DISPLAY_PLANE_COUNT].into_boxed_slice(),
+            selected_planes: DEFAULT_SELECTED_PLANES,
+            zone_colours: vec![CHIP8X_DEFAULT_ZONE_COLOUR; zone_count].into_boxed_slice(),
+            background_colour_index: 0,
+            intensities: Box::new([]),
+        }
+    }
+
+    /// Constructor that returns a [Display] instance of the Hi-Res CHIP-8 size (64 x 64 pixels)
+    /// with all pixels set to off.
+    pub(crate) fn new_chip8_hires() -> Self {
+        let row_size: usize = CHIP8_HIRES_ROW_SIZE_PIXELS / 8;
+        let column_size: usize = CHIP8_HIRES_COLUMN_SIZE_PIXELS;
+        Self {
+            row_size_bytes: row_size,
+            column_size_pixels: column_size,
+            pixels: vec![0x0; row_size * column_size * DISPLAY_PLANE_COUNT].into_boxed_slice(),
+            selected_planes: DEFAULT_SELECTED_PLANES,
+            zone_colours: Box::new([]),
+            background_colour_index: 0,
+            intensities: Box::new([]),
+        }
+    }
+
+    /// Constructor that returns a [Display] instance of the specified size holding the specified
+    /// bit plane contents (laid out as returned by [Display::get_plane()], one plane after
+    /// another).  Returns `None` if the number of bytes supplied does not match the size.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_size_bytes` - the display row size in bytes
+    /// * `column_size_pixels` - the display column size in pixels
+    /// * `planes` - the contents of every bit plane
+    pub(crate) fn from_planes(
+        row_size_bytes: usize,
+        column_size_pixels: usize,
+        planes: Vec<u8>,
+    ) -> Option<Self> {
+        if planes.len() != row_size_bytes * column_size_pixels * DISPLAY_PLANE_COUNT {
+            return None;
+        }
+        Some(Self {
+            row_size_bytes,
+            column_size_pixels,
+            pixels: planes.into_boxed_slice(),
+            selected_planes: DEFAULT_SELECTED_PLANES,
+            zone_colours: Box::new([]),
+            background_colour_index: 0,
+            intensities: Box::new([]),
+        })
+    }
+
+    /// Getter that returns the display row size in bytes
+    pub fn get_row_size_bytes(&self) -> usize {
+        self.row_size_bytes
+    }
+
+    /// Getter that returns the display column size in pixels
+    pub fn get_column_size_pixels(&self) -> usize {
+        self.column_size_pixels
+    }
+
+    /// Getter that returns the display resolution in pixels, as (width, height)
+    pub fn get_resolution(&self) -> (usize, usize) {
+        (self.row_size_bytes * 8, self.column_size_pixels)
+    }
+
+    /// Getter that returns the number of bit planes held by the display
+    pub fn get_plane_count(&self) -> usize {
+        DISPLAY_PLANE_COUNT
+    }
+
+    /// Getter that returns the bitmask of the planes currently selected for drawing
+    pub fn get_selected_planes(&self) -> u8 {
+        self.selected_planes
+    }
+
+    /// Returns true if the display holds CHIP-8X colour attributes (i.e. the display belongs to a
+    /// processor emulating [EmulationLevel::Chip8X])
+    pub fn has_colour_zones(&self) -> bool {
+        !self.zone_colours.is_empty()
+    }
+
+    /// Returns the CHIP-8X foreground colour of the zone containing the specified pixel, as a
+    /// VP-590 colour code: 0 black, 1 red, 2 blue, 3 violet, 4 green, 5 yellow, 6 aqua or 7 white.
+    /// Each zone is [CHIP8X_ZONE_WIDTH_PIXELS] wide and one pixel high.  Returns `None` if the
+    /// display holds no colour attributes or the pixel is outside the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the x coordinate of the pixel
+    /// * `y` - the y coordinate of the pixel
+    pub fn get_zone_colour(&self, x: usize, y: usize) -> Option<u8> {
+        if !self.has_colour_zones() || x >= self.row_size_bytes * 8 || y >= self.column_size_pixels
+        {
+            return None;
+        }
+        Some(self.zone_colours[y * CHIP8X_ZONES_PER_ROW + x / CHIP8X_ZONE_WIDTH_PIXELS])
+    }
+
+    /// Returns true if the pixel and zone colour arrays match the display size, and the background
+    /// colour is valid, as they must for a display deserialised from file to be used
+    pub(crate) fn is_consistent(&self) -> bool {
+        self.pixels.len() == self.row_size_bytes * self.column_size_pixels * DISPLAY_PLANE_COUNT
+            && (self.zone_colours.is_empty()
+                || self.zone_colours.len() == self.column_size_pixels * CHIP8X_ZONES_PER_ROW)
+            && self.background_colour_index < CHIP8X_BACKGROUND_COLOURS.len()
+    }
+
+    /// Returns the CHIP-8X foreground colour of every zone, one row of zones after another (see
+    /// [Display::get_zone_colour()]).  Empty if the display holds no colour attributes.
+    pub fn get_zone_colours(&self) -> &[u8] {
+        &self.zone_colours
+    }
+
+    /// Returns the CHIP-8X background colour as a VP-590 colour code (see
+    /// [Display::get_zone_colour()]), or `None` if the display holds no colour attributes.
+    pub fn get_background_colour(&self) -> Option<u8> {
+        match self.has_colour_zones() {
+            true => Some(CHIP8X_BACKGROUND_COLOURS[self.background_colour_index]),
+            false => None,
+        }
+    }
+
+    /// Sets the CHIP-8X foreground colour of a rectangle of zones, clipping any part of the
+    /// rectangle beyond the display.  Does nothing if the display holds no colour attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `zone_x` - the zero-based column of the top-left zone (in zones, not pixels)
+    /// * `y` - the y coordinate of the top row of zones (in pixels)
+    /// * `width_zones` - the width of the rectangle in zones
+    /// * `height_pixels` - the height of the rectangle in pixels
+    /// * `colour` - the VP-590 colour code (only the three least significant bits are used)
+    pub(crate) fn set_zone_colours(
+        &mut self,
+        zone_x: usize,
+        y: usize,
+        width_zones: usize,
+        height_pixels: usize,
+        colour: u8,
+    ) {
+        if !self.has_colour_zones() {
+            return;
+        }
+        let columns: core::ops::Range<usize> =
+            zone_x.min(CHIP8X_ZONES_PER_ROW)..(zone_x + width_zones).min(CHIP8X_ZONES_PER_ROW);
+        let rows: core::ops::Range<usize> =
+            y.min(self.column_size_pixels)..(y + height_pixels).min(self.column_size_pixels);
+        for row in rows {
+            self.zone_colours[row * CHIP8X_ZONES_PER_ROW + columns.start
+                ..row * CHIP8X_ZONES_PER_ROW + columns.end]
+                .fill(colour & 0x7);
+        }
+    }
+
+    /// Advances the CHIP-8X background colour to the next in the cycle blue, black, green, red.
+    /// Does nothing if the display holds no colour attributes.
+    pub(crate) fn cycle_background_colour(&mut self) {
+        if self.has_colour_zones() {
+            self.background_colour_index =
+                (self.background_colour_index + 1) % CHIP8X_BACKGROUND_COLOURS.len();
+        }
+    }
+
+    /// Returns the bytes of the specified bit plane, one row after another, using one bit per pixel
+    /// (in the same layout as the rows returned by indexing the display).  Returns `None` if the
+    /// plane does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `plane` - the zero-based index of the bit plane
+    pub fn get_plane(&self, plane: usize) -> Option<&[u8]> {
+        if plane >= DISPLAY_PLANE_COUNT {
+            return None;
+        }
+        let plane_size_bytes: usize = self.row_size_bytes * self.column_size_pixels;
+        Some(&self.pixels[plane * plane_size_bytes..(plane + 1) * plane_size_bytes])
+    }
+
+    /// Returns the colour index of the pixel at the specified coordinates, with bit `n` of the
+    /// index set if the pixel is on in plane `n` (so 0 means the pixel is off in all planes).
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - the zero-based row (y coordinate) of the pixel
+    /// * `column` - the zero-based column (x coordinate) of the pixel
+    pub fn get_pixel_colour_index(&self, row: usize, column: usize) -> u8 {
+        let mut colour_index: u8 = 0;
+        for plane in 0..DISPLAY_PLANE_COUNT {
+            if self.plane_row(plane, row)[column / 8] & (0x80 >> (column % 8)) > 0 {
+                colour_index |= 1 << plane;
+            }
+        }
+        colour_index
+    }
+
+    /// Returns the colour index of every pixel in the display, row by row from the top-left, with
+    /// one byte per pixel (see [Display::get_pixel_colour_index()])
+    pub fn get_colour_indices(&self) -> Vec<u8> {
+        let row_size_pixels: usize = self.row_size_bytes * 8;
+        let mut colour_indices: Vec<u8> =
+            Vec::with_capacity(row_size_pixels * self.column_size_pixels);
+        for row in 0..self.column_size_pixels {
+            for column in 0..row_size_pixels {
+                colour_indices.push(self.get_pixel_colour_index(row, column));
+            }
+        }
+        colour_indices
+    }
+
+    /// Sets the pixel at the specified coordinates to the specified colour index (turning it on in
+    /// plane `n` if bit `n` of the index is set, and off otherwise), regardless of the planes
+    /// currently selected.  Coordinates lying outside the display are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the zero-based column of the pixel
+    /// * `y` - the zero-based row of the pixel
+    /// * `colour_index` - the colour index to give the pixel
+    pub(crate) fn set_pixel_colour_index(&mut self, x: usize, y: usize, colour_index: u8) {
+        if x >= self.row_size_bytes * 8 || y >= self.column_size_pixels {
+            return;
+        }
+        for plane in 0..DISPLAY_PLANE_COUNT {
+            let row: &mut [u8] = self.plane_row_mut(plane, y);
+            match colour_index & (1 << plane) > 0 {
+                true => row[x / 8] |= 0x80 >> (x % 8),
+                false => row[x / 8] &= !(0x80 >> (x % 8)),
+            }
+        }
+    }
+
+    /// Returns true if the pixel at the specified coordinates is on (in any plane), or false if it
+    /// is off or the coordinates lie outside the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the zero-based column of the pixel
+    /// * `y` - the zero-based row of the pixel
+    pub fn get_pixel(&self, x: usize, y: usize) -> bool {
+        if x >= self.row_size_bytes * 8 || y >= self.column_size_pixels {
+            return false;
+        }
+        self.get_pixel_colour_index(y, x) > 0
+    }
+
+    /// Returns an iterator over the rows of the display from top to bottom, each row being a
+    /// vector holding the on/off state (see [Display::get_pixel()]) of its pixels from left to
+    /// right
+    pub fn iter_rows(&self) -> impl Iterator<Item = Vec<bool>> + '_ {
+        (0..self.column_size_pixels).map(move |y| {
+            (0..self.row_size_bytes * 8)
+                .map(|x| self.get_pixel(x, y))
+                .collect()
+        })
+    }
+
+    /// Returns an iterator over every pixel of the display, row by row from the top-left, yielding
+    /// the `(x, y)` coordinates and on/off state (see [Display::get_pixel()]) of each
+    pub fn iter_pixels(&self) -> impl Iterator<Item = ((usize, usize), bool)> + '_ {
+        (0..self.column_size_pixels).flat_map(move |y| {
+            (0..self.row_size_bytes * 8).map(move |x| ((x, y), self.get_pixel(x, y)))
+        })
+    }
+
+    /// Returns true if the display tracks the intensity of each pixel for ghost trails (i.e. the
+    /// display belongs to a processor with [Options::ghost_trail_decay](crate::Options::ghost_trail_decay) set)
+    pub fn has_intensities(&self) -> bool {
+        !self.intensities.is_empty()
+    }
+
+    /// Returns the intensity of the pixel at the specified coordinates, from 0 (unlit) to
+    /// [FULL_INTENSITY].  Lit pixels are always at full intensity; if ghost trails are tracked,
+    /// unlit pixels that were recently lit are reported at an intensity fading frame by frame,
+    /// and otherwise at zero.  Coordinates lying outside the display are reported as zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the zero-based column of the pixel
+    /// * `y` - the zero-based row of the pixel
+    pub fn get_pixel_intensity(&self, x: usize, y: usize) -> u8 {
+        if x >= self.row_size_bytes * 8 || y >= self.column_size_pixels {
+            return 0x0;
+        }
+        match (self.get_pixel(x, y), self.intensities.is_empty()) {
+            (true, _) => FULL_INTENSITY,
+            (false, true) => 0x0,
+            (false, false) => self.intensities[y * self.row_size_bytes * 8 + x],
+        }
+    }
+
+    /// Returns the intensity of every pixel in the display (see [Display::get_pixel_intensity()]),
+    /// row by row from the top-left with one byte per pixel, as a grayscale image that front-ends
+    /// can render (or blend with the colours of the palette) to show erased pixels fading out.
+    /// Returns `None` if ghost trails are not tracked.
+    pub fn get_intensities(&self) -> Option<Vec<u8>> {
+        if self.intensities.is_empty() {
+            return None;
+        }
+        Some(
+            self.iter_pixels()
+                .map(|((x, y), _)| self.get_pixel_intensity(x, y))
+                .collect(),
+        )
+    }
+
+    /// Starts or stops tracking the intensity of each pixel for ghost trails.  Tracking starts
+    /// with lit pixels at full intensity and all others at zero; stopping discards the
+    /// intensities.  Starting while already tracking leaves the intensities untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to track intensities
+    pub(crate) fn track_intensities(&mut self, enabled: bool) {
+        match (enabled, self.intensities.is_empty()) {
+            (true, true) => {
+                self.intensities = self
+                    .iter_pixels()
+                    .map(|(_, lit)| match lit {
+                        true => FULL_INTENSITY,
+                        false => 0x0,
+                    })
+                    .collect();
+            }
+            (false, false) => self.intensities = Box::new([]),
+            _ => {}
+        }
+    }
+
+    /// Advances the ghost trails by one frame: lit pixels are set to full intensity, and the
+    /// intensity of unlit pixels is reduced by the specified amount (down to zero).  Returns true
+    /// if any intensity changed, so that hosts can be told the display needs repainting.  Does
+    /// nothing if intensities are not tracked.
+    ///
+    /// # Arguments
+    ///
+    /// * `decay` - the intensity lost each frame by an unlit pixel
+    pub(crate) fn decay_intensities(&mut self, decay: u8) -> bool {
+        let row_size_pixels: usize = self.row_size_bytes * 8;
+        let mut changed: bool = false;
+        for index in 0..self.intensities.len() {
+            let intensity: u8 =
+                match self.get_pixel(index % row_size_pixels, index / row_size_pixels) {
+                    true => FULL_INTENSITY,
+                    false => self.intensities[index].saturating_sub(decay),
+                };
+            changed |= intensity != self.intensities[index];
+            self.intensities[index] = intensity;
+        }
+        changed
+    }
+
+    /// Returns a copy of the display rotated clockwise as per the specified [DisplayOrientation]
+    /// (every bit plane being rotated alike), so that renderers can honour
+    /// [Options::display_orientation](crate::Options::display_orientation).  A quarter turn
+    /// swaps the row and column sizes of the display.  Any CHIP-8X colour zones are copied
+    /// unrotated.
+    ///
+    /// # Arguments
+    ///
+    /// * `orientation` - the rotation to apply
+    pub fn rotated(&self, orientation: DisplayOrientation) -> Display {
+        let width: usize = self.row_size_bytes * 8;
+        let height: usize = self.column_size_pixels;
+        let (rotated_width, rotated_height): (usize, usize) = match orientation {
+            DisplayOrientation::Rotate0 | DisplayOrientation::Rotate180 => (width, height),
+            DisplayOrientation::Rotate90 | DisplayOrientation::Rotate270 => (height, width),
+        };
+        let mut rotated: Display = Self {
+            row_size_bytes: rotated_width / 8,
+            column_size_pixels: rotated_height,
+            pixels: vec![0x0; rotated_width / 8 * rotated_height * DISPLAY_PLANE_COUNT]
+                .into_boxed_slice(),
+            selected_planes: self.selected_planes,
+            zone_colours: self.zone_colours.clone(),
+            background_colour_index: self.background_colour_index,
+            intensities: vec![0x0; self.intensities.len()].into_boxed_slice(),
+        };
+        for y in 0..rotated_height {
+            for x in 0..rotated_width {
+                // Find the source pixel that lands at these coordinates once rotated
+                let (source_x, source_y): (usize, usize) = match orientation {
+                    DisplayOrientation::Rotate0 => (x, y),
+                    DisplayOrientation::Rotate90 => (y, height - 1 - x),
+                    DisplayOrientation::Rotate180 => (width - 1 - x, height - 1 - y),
+                    DisplayOrientation::Rotate270 => (width - 1 - y, x),
+                };
+                for plane in 0..DISPLAY_PLANE_COUNT {
+                    if self.plane_row(plane, source_y)[source_x / 8] & (0x80 >> (source_x % 8)) > 0
+                    {
+                        rotated.plane_row_mut(plane, y)[x / 8] |= 0x80 >> (x % 8);
+                    }
+                }
+                if !self.intensities.is_empty() {
+                    rotated.intensities[y * rotated_width + x] =
+                        self.intensities[source_y * width + source_x];
+                }
+            }
+        }
+        rotated
+    }
+
+    /// Resizes the display to the native resolution of the specified emulation level in high- or
+    /// low-resolution mode: 128 x 64 pixels in SUPER-CHIP 1.1 high-resolution mode, and 64 x 32
+    /// pixels otherwise.  The contents of every plane are rescaled to the new size (each pixel
+    /// taking the colour of the nearest pixel at the old size) so the picture is preserved as
+    /// closely as possible, and the selected planes are unchanged.  Resizing to the current
+    /// size leaves the display untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the CHIP-8 variant whose native resolution is required
+    /// * `high_resolution` - whether the SUPER-CHIP 1.1 high-resolution mode resolution is
+    ///   required (ignored at other emulation levels)
+    pub(crate) fn resize(&mut self, emulation_level: EmulationLevel, high_resolution: bool) {
+        let (row_size, column_size): (usize, usize) =
+            Display::native_size(emulation_level, high_resolution);
+        if row_size == self.row_size_bytes && column_size == self.column_size_pixels {
+            return;
+        }
+        let mut resized: Display = Display {
+            row_size_bytes: row_size,
+            column_size_pixels: column_size,
+            pixels: vec![0x0; row_size * column_size * DISPLAY_PLANE_COUNT].into_boxed_slice(),
+            selected_planes: self.selected_planes,
+            zone_colours: self.zone_colours.clone(),
+            background_colour_index: self.background_colour_index,
+            intensities: Box::new([]),
+        };
+        let (old_width, old_height): (usize, usize) = self.get_resolution();
+        let (new_width, new_height): (usize, usize) = resized.get_resolution();
+        for y in 0..new_height {
+            for x in 0..new_width {
+                let colour_index: u8 = self
+                    .get_pixel_colour_index(y * old_height / new_height, x * old_width / new_width);
+                resized.set_pixel_colour_index(x, y, colour_index);
+            }
+        }
+        // Trails do not survive a change of resolution; intensities restart from the pixels lit
+        resized.track_intensities(!self.intensities.is_empty());
+        *self = resized;
+    }
+
+    /// Helper function that returns the native display size (row size in bytes, column size in
+    /// pixels) of the specified emulation level in high- or low-resolution mode
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the CHIP-8 variant being emulated
+    /// * `high_resolution` - whether SUPER-CHIP 1.1 high-resolution mode is in effect
+    fn native_size(emulation_level: EmulationLevel, high_resolution: bool) -> (usize, usize) {
+        match (emulation_level, high_resolution) {
+            (EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. }, true) => {
+                (HIGH_RES_ROW_SIZE_PIXELS / 8, HIGH_RES_COLUMN_SIZE_PIXELS)
+            }
+            _ => (LOW_RES_ROW_SIZE_PIXELS / 8, LOW_RES_COLUMN_SIZE_PIXELS),
+        }
+    }
+
+    /// Selects the bit planes to be affected by subsequent drawing, clearing and scrolling.  Returns
+    /// [ErrorDetail::OperandsOutOfBounds] if the mask selects a plane that does not exist.  No
+    /// supported emulation level has a plane selection instruction, so hosts drawing to a display
+    /// of their own select its planes through this.
+    ///
+    /// # Arguments
+    ///
+    /// * `plane_mask` - bitmask of the planes to select (bit `n` selects plane `n`)
+    pub fn select_planes(&mut self, plane_mask: u8) -> Result<(), ErrorDetail> {
+        Display::plane_indices(plane_mask)?;
+        self.selected_planes = plane_mask;
+        Ok(())
+    }
+
+    /// Clears the display by setting all pixels in the selected planes to off.
+    pub(crate) fn clear(&mut self) {
+        for plane in self.selected_plane_indices() {
+            let plane_size_bytes: usize = self.row_size_bytes * self.column_size_pixels;
+            self.pixels[plane * plane_size_bytes..(plane + 1) * plane_size_bytes].fill(0x0);
+        }
+    }
+
+    /// Draws a sprite to the display as per the CHIP-8 specification.  Returns a tuple: the first u8
+    /// is the number of rows that collide with another sprite, the second u8 is the number of rows
+    /// that are clipped by the bottom of the screen
+    ///
+    /// The sprite is drawn to each selected plane in turn.  As per the XO-CHIP specification, if more
+    /// than one plane is selected then the sprite data for each plane follows on from that of the
+    /// previous plane (so `sprite` must hold one sprite's worth of bytes for each selected plane).
+    /// Where more than one plane is drawn to, the collision count returned is the largest of
+    /// those for each individual plane.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_start_pixel` - An zero-based integer giving the starting x coordinate of the sprite
+    /// * `y_start_pixel` - An zero-based integer giving the starting y coordinate of the sprite
+    /// * `sprite` - An array slice holding the bytes that make up the sprite
+    /// * `double_width_sprite` - A boolean set to true if the sprite is two bytes wide
+    /// * `edge_mode` - Whether sprite pixels overflowing the display edges are clipped or wrapped
+    pub(crate) fn draw_sprite(
+        &mut self,
+        x_start_pixel: usize,
+        y_start_pixel: usize,
+        sprite: &[u8],
+        double_width_sprite: bool,
+        edge_mode: SpriteEdgeMode,
+    ) -> Result<(u8, u8), ErrorDetail> {
+        let planes: Vec<usize> = self.selected_plane_indices();
+        let mut rows_with_collisions: u8 = 0;
+        let mut rows_clipped: u8 = 0;
+        // Divide the sprite data equally between the selected planes, drawing each portion in turn
+        if !planes.is_empty() {
+            let plane_sprite_size: usize = sprite.len() / planes.len();
+            for (i, plane) in planes.into_iter().enumerate() {
+                let (plane_collisions, plane_clipped) = self.draw_sprite_to_plane(
+                    plane,
+                    x_start_pixel,
+                    y_start_pixel,
+                    &sprite[i * plane_sprite_size..(i + 1) * plane_sprite_size],
+                    double_width_sprite,
+                    edge_mode,
+                )?;
+                rows_with_collisions = cmp::max(rows_with_collisions, plane_collisions);
+                rows_clipped = cmp::max(rows_clipped, plane_clipped);
+            }
+        }
+        Ok((rows_with_collisions, rows_clipped))
+    }
+
+    /// Helper function that draws a sprite to a single bit plane, returning the same tuple as
+    /// [Display::draw_sprite()]
+    ///
+    /// # Arguments
+    ///
+    /// * `plane` - The zero-based index of the bit plane to draw to
+    /// * `x_start_pixel` - An zero-based integer giving the starting x coordinate of the sprite
+    /// * `y_start_pixel` - An zero-based integer giving the starting y coordinate of the sprite
+    /// * `sprite` - An array slice holding the bytes that make up the sprite
+    /// * `double_width_sprite` - A boolean set to true if the sprite is two bytes wide
+    /// * `edge_mode` - Whether sprite pixels overflowing the display edges are clipped or wrapped
+    fn draw_sprite_to_plane(
+        &mut self,
+        plane: usize,
+        x_start_pixel: usize,
+        y_start_pixel: usize,
+        sprite: &[u8],
+        double_width_sprite: bool,
+        edge_mode: SpriteEdgeMode,
+    ) -> Result<(u8, u8), ErrorDetail> {
+        // Determine the width of the sprite in bytes, and its height in pixels (based on the length
+        // of the sprite byte array and whether the sprite is one or two bytes in width)
+        let sprite_width_bytes: usize = match double_width_sprite {
+            true => 2,
+            false => 1,
+        };
+        let sprite_height: usize = sprite.len() / sprite_width_bytes;
+        // The starting coordinates always wrap, regardless of the edge mode
+        let y_start_pixel: usize = y_start_pixel % self.column_size_pixels;
+        // Determine how many rows to draw; if clipping, rows overflowing the bottom of the display
+        // are not drawn
+        let pixel_rows_to_draw: usize = match edge_mode {
+            SpriteEdgeMode::Clip => cmp::min(
+                sprite_height,
+                self.column_size_pixels - cmp::min(self.column_size_pixels, y_start_pixel),
+            ),
+            SpriteEdgeMode::Wrap => sprite_height,
+        };
+        // Number of rows clipped (to be returned from method) is sprite height minus rows to draw
+        // Allegedly this is used by SUPER-CHIP 1.1 however testing and further investigation suggests
+        // this is unintended, and this definitely causes issues with some games.  So, this is hardwired
+        // to 0 for now
+        //let rows_clipped: u8 = (sprite_height - pixel_rows_to_draw) as u8;
+        let rows_clipped: u8 = 0_u8;
+        // Calculate the offset (in pixels) of the sprite X position relative to the start of the byte
+        let x_offset = x_start_pixel % 8;
+        // Calculate which horizontal display byte the sprite starts in (allowing wrapping)
+        let x_byte = (x_start_pixel / 8) % self.row_size_bytes;
+        // Keep track of whether any pixels are turned off as a result of drawing each sprite row
+        let mut any_pixel_turned_off: bool;
+        let mut rows_with_collisions: u8 = 0;
+        // Loop for each row in the sprite
+        for j in 0..pixel_rows_to_draw {
+            // Get the display row affected (wrapping back to the top if necessary; when clipping
+            // the rows to draw have already been limited to those on-screen)
+            let display_row: usize = (y_start_pixel + j) % self.column_size_pixels;
+            // Get the byte index of the sprite left-hand byte for this row (for a normal width sprite
+            // this will just equal j, but for a double-width sprite it will be 2 * j)
+            let byte_index: usize = j * sprite_width_bytes;
+            any_pixel_turned_off = false;
+            // Unless aligned to the start of a display byte, each sprite row spills over into one
+            // more display byte than the sprite is wide
+            for k in 0..=sprite_width_bytes {
+                // Build the portion of the sprite row falling within this display byte: the kth sprite
+                // byte right bit-shifted to align with the display byte, combined with the overspill
+                // portion of the previous sprite byte (left-shifted to isolate and align it)
+                let mut sprite_byte: u8 = 0x0;
+                if k < sprite_width_bytes {
+                    sprite_byte |= sprite[byte_index + k] >> (x_offset as u8);
+                }
+                if k > 0 && x_offset > 0 {
+                    sprite_byte |= sprite[byte_index + k - 1] << (8 - x_offset as u8);
+                }
+                // Determine the display byte affected; if this is beyond the right-hand edge of the
+                // display then either stop (if clipping) or wrap back to the left-hand edge
+                let mut display_column: usize = x_byte + k;
+                if display_column >= self.row_size_bytes {
+                    match edge_mode {
+                        SpriteEdgeMode::Clip => break,
+                        SpriteEdgeMode::Wrap => display_column %= self.row_size_bytes,
+                    }
+                }
+                // Reference to the display byte affected
+                let display_byte: &mut u8 =
+                    &mut self.plane_row_mut(plane, display_row)[display_column];
+                // Check if display bit will be turned off by this operation (i.e. if a display bit and
+                // a corresponding sprite bit are both set to 1 prior to the XOR operation)
+                if (*display_byte & sprite_byte) > 0 {
+                    any_pixel_turned_off = true;
+                }
+                // Carry out the XOR operation to apply the sprite byte to the display byte
+                *display_byte ^= sprite_byte;
+                // If tracking ghost trails, pixels turned on are lit at full intensity, so that even
+                // those erased again before the next frame leave a trail
+                let pixels_turned_on: u8 = *display_byte & sprite_byte;
+                if pixels_turned_on > 0 && !self.intensities.is_empty() {
+                    let row_start: usize = display_row * self.row_size_bytes * 8;
+                    for bit in 0..8 {
+                        if pixels_turned_on & (0x80 >> bit) > 0 {
+                            self.intensities[row_start + display_column * 8 + bit] = FULL_INTENSITY;
+                        }
+                    }
+                }
+            }
+            if any_pixel_turned_off {
+                rows_with_collisions += 1;
+            }
+        }
+        Ok((rows_with_collisions, rows_clipped))
+    }
+
+    /// Scrolls the selected planes right by 4 pixels (4 pixels as per the high-resolution display
+    /// mode i.e. if in low-resolution mode this is the equivalent of 2 low-resolution pixels)
+    pub(crate) fn scroll_display_right(&mut self) -> Result<(), ErrorDetail> {
+        self.scroll_planes_right(self.selected_planes)
+    }
+
+    /// Scrolls the selected planes left by 4 pixels (4 pixels as per the high-resolution display
+    /// mode i.e. if in low-resolution mode this is the equivalent of 2 low-resolution pixels)
+    pub(crate) fn scroll_display_left(&mut self) -> Result<(), ErrorDetail> {
+        self.scroll_planes_left(self.selected_planes)
+    }
+
+    /// Scrolls the selected planes down by N pixels (N pixels as per the high-resolution display
+    /// mode i.e. if in low-resolution mode this is the equivalent of N/2 low-resolution pixels)
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of pixels by which to scroll down
+    pub(crate) fn scroll_display_down(&mut self, n: u8) -> Result<(), ErrorDetail> {
+        self.scroll_planes_down(self.selected_planes, n)
+    }
+
+    /// Scrolls the selected planes up by N pixels (N pixels as per the high-resolution display
+    /// mode i.e. if in low-resolution mode this is the equivalent of N/2 low-resolution pixels).
+    /// No supported emulation level has a scroll up instruction, so hosts drawing to a display of
+    /// their own scroll it up through this.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of pixels by which to scroll up
+    pub fn scroll_display_up(&mut self, n: u8) -> Result<(), ErrorDetail> {
+        self.scroll_planes_up(self.selected_planes, n)
+    }
+
+    /// Scrolls the planes selected by the passed bitmask right by 4 pixels (regardless of the
+    /// planes currently selected for drawing).  Returns [ErrorDetail::OperandsOutOfBounds] if the
+    /// mask selects a plane that does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `plane_mask` - bitmask of the planes to scroll (bit `n` selects plane `n`)
+    pub(crate) fn scroll_planes_right(&mut self, plane_mask: u8) -> Result<(), ErrorDetail> {
+        let n: usize = self.get_row_size_bytes();
+        for plane in Display::plane_indices(plane_mask)? {
+            // Iterate through each row in turn, shifting the bytes in that row
+            for row_index in 0..self.get_column_size_pixels() {
+                let row: &mut [u8] = self.plane_row_mut(plane, row_index);
+                // For each byte except the first, carry out the scroll as follows:
+                // consider two consecutive bytes: ABCD EFGH | IJKL MNOP.  To scroll the second we move
+                // the first nibble of the second byte into the second nibble, then move the second nibble
+                // of the first byte into the first nibble of the second byte i.e. ABCD EFGH | EFGH IJKL
+                // This is achieved by i) right-shifting the second byte by 4 bits, then
+                // ii) left-shifting the first byte by 4 bits, then
+                // iii) combining the results into the first byte with a bitwise OR
+                for column_index in (1..n).rev() {
+                    row[column_index] = (row[column_index] >> 4) | (row[column_index - 1] << 4);
+                }
+                row[0] = row[0] >> 4;
+            }
+        }
+        Ok(())
+    }
+
+    /// Scrolls the planes selected by the passed bitmask left by 4 pixels (regardless of the
+    /// planes currently selected for drawing).  Returns [ErrorDetail::OperandsOutOfBounds] if the
+    /// mask selects a plane that does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `plane_mask` - bitmask of the planes to scroll (bit `n` selects plane `n`)
+    pub(crate) fn scroll_planes_left(&mut self, plane_mask: u8) -> Result<(), ErrorDetail> {
+        let n: usize = self.get_row_size_bytes() - 1;
+        for plane in Display::plane_indices(plane_mask)? {
+            // Iterate through each row in turn, shifting the bytes in that row
+            for row_index in 0..self.get_column_size_pixels() {
+                let row: &mut [u8] = self.plane_row_mut(plane, row_index);
+                // For each byte except the last, carry out the scroll as follows:
+                // consider two consecutive bytes: ABCD EFGH | IJKL MNOP.  To scroll the first we move
+                // the second nibble of the first byte into the first nibble, then move the first nibble
+                // of the second byte into the second nibble of the first byte i.e. EFGH IJKL | IJKL MNOP
+                // This is achieved by i) left-shifting the first byte by 4 bits, then
+                // ii) right-shifting the second byte by 4 bits, then
+                // iii) combining the results into the first byte with a bitwise OR
+                for column_index in 0..n {
+                    row[column_index] = (row[column_index] << 4) | (row[column_index + 1] >> 4);
+                }
+                row[n] = row[n] << 4;
+            }
+        }
+        Ok(())
+    }
+
+    /// Scrolls the planes selected by the passed bitmask down by N pixels (regardless of the
+    /// planes currently selected for drawing).  Returns [ErrorDetail::OperandsOutOfBounds] if the
+    /// mask selects a plane that does not exist, or if N exceeds the height of the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `plane_mask` - bitmask of the planes to scroll (bit `n` selects plane `n`)
+    /// * `n` - The number of pixels by which to scroll down
+    pub(crate) fn scroll_planes_down(&mut self, plane_mask: u8, n: u8) -> Result<(), ErrorDetail> {
+        let planes: Vec<usize> = Display::plane_indices(plane_mask)?;
+        let n: usize = self.vertical_scroll_amount(n)?;
+        for plane in planes {
+            let plane_offset: usize = plane * self.row_size_bytes * self.column_size_pixels;
+            // Iterate through each row of the plane in reverse from the last row back to the (n+1)th row
+            for row_index in (n..self.get_column_size_pixels()).rev() {
+                // By exploiting offsets within the internal 1D array representing the 2D display,
+                // Set each row's bytes to the bytes from that n rows earlier
+                self.pixels.copy_within(
+                    plane_offset + (row_index - n) * self.row_size_bytes
+                        ..plane_offset + (row_index + 1 - n) * self.row_size_bytes,
+                    plane_offset + row_index * self.row_size_bytes,
+                );
+            }
+            // Finally, fill the top n rows with 0s (effectively newly-created rows to replace those
+            // scrolled off the bottom of the display)
+            for row_index in 0..n {
+                self.plane_row_mut(plane, row_index).fill(0x00);
+            }
+        }
+        Ok(())
+    }
+
+    /// Scrolls the planes selected by the passed bitmask up by N pixels (regardless of the planes
+    /// currently selected for drawing).  Returns [ErrorDetail::OperandsOutOfBounds] if the mask
+    /// selects a plane that does not exist, or if N exceeds the height of the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `plane_mask` - bitmask of the planes to scroll (bit `n` selects plane `n`)
+    /// * `n` - The number of pixels by which to scroll up
+    pub fn scroll_planes_up(&mut self, plane_mask: u8, n: u8) -> Result<(), ErrorDetail> {
+        let planes: Vec<usize> = Display::plane_indices(plane_mask)?;
+        let n: usize = self.vertical_scroll_amount(n)?;
+        let rows: usize = self.get_column_size_pixels();
+        for plane in planes {
+            let plane_offset: usize = plane * self.row_size_bytes * rows;
+            // Move every row from the (n+1)th onwards up by n rows in one go, by exploiting offsets
+            // within the internal 1D array representing the 2D display
+            self.pixels.copy_within(
+                plane_offset + n * self.row_size_bytes..plane_offset + rows * self.row_size_bytes,
+                plane_offset,
+            );
+            // Finally, fill the bottom n rows with 0s (effectively newly-created rows to replace
+            // those scrolled off the top of the display)
+            for row_index in rows - n..rows {
+                self.plane_row_mut(plane, row_index).fill(0x00);
+            }
+        }
+        Ok(())
+    }
+
+    /// Helper function that returns the indices of the planes selected by the passed bitmask, in
+    /// ascending order.  Returns [ErrorDetail::OperandsOutOfBounds] if the mask selects a plane
+    /// that does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `plane_mask` - bitmask of the planes (bit `n` selects plane `n`)
+    fn plane_indices(plane_mask: u8) -> Result<Vec<usize>, ErrorDetail> {
+        if (plane_mask as usize) >= (1 << DISPLAY_PLANE_COUNT) {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::PlaneMask(plane_mask)],
+            });
+        }
+        Ok((0..DISPLAY_PLANE_COUNT)
+            .filter(|plane| plane_mask & (1 << plane) > 0)
+            .collect())
+    }
+
+    /// Helper function that returns the number of pixels by which to scroll vertically as a
+    /// usize, or [ErrorDetail::OperandsOutOfBounds] if this exceeds the height of the display
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of pixels by which to scroll
+    fn vertical_scroll_amount(&self, n: u8) -> Result<usize, ErrorDetail> {
+        if n as usize > self.column_size_pixels {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::N(n)],
+            });
+        }
+        Ok(n as usize)
+    }
+
+    /// Helper function that returns the indices of the currently selected planes, in ascending order
+    fn selected_plane_indices(&self) -> Vec<usize> {
+        (0..DISPLAY_PLANE_COUNT)
+            .filter(|plane| self.selected_planes & (1 << plane) > 0)
+            .collect()
+    }
+
+    /// Helper function that returns the bytes of the specified row of the specified plane
+    fn plane_row(&self, plane: usize, row: usize) -> &[u8] {
+        let offset: usize = (plane * self.column_size_pixels + row) * self.row_size_bytes;
+        &self.pixels[offset..offset + self.row_size_bytes]
+    }
+
+    /// Helper function that returns the bytes of the specified row of the specified plane mutably
+    fn plane_row_mut(&mut self, plane: usize, row: usize) -> &mut [u8] {
+        let offset: usize = (plane * self.column_size_pixels + row) * self.row_size_bytes;
+        &mut self.pixels[offset..offset + self.row_size_bytes]
+    }
+}
+
+/// Encodes the pixels of a frame buffer compactly, as the changes from the previous frame buffer
+/// where one of the same size is supplied, for sending over a network or storing in a recording.
+/// The encoding is decoded by [decode_delta()], which must be passed the same previous frame.
+///
+/// All multi-byte values are big-endian.  The encoding holds the row size in bytes (u16) and
+/// column size in pixels (u16) of the frame, then a frame type byte: 0 if the frame is held in
+/// full (a key frame), or 1 if it is held as the changes from the previous frame.  There follow
+/// the bytes of every bit plane in turn (laid out as per [Display::get_plane()]), XORed with
+/// those of the previous frame in the latter case, and run-length encoded as a series of runs:
+/// the number of zero (unchanged) bytes, then the number of literal bytes, each a LEB128 varint,
+/// then the literal bytes themselves.  An unchanged frame therefore encodes to a handful of
+/// bytes.  Only the pixels are encoded, not the selected planes nor any CHIP-8X colour zones.
+///
+/// # Arguments
+///
+/// * `previous` - the frame buffer previously encoded, if any
+/// * `current` - the frame buffer to encode
+pub fn encode_delta(previous: Option<&Display>, current: &Display) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::new();
+    bytes.extend((current.row_size_bytes as u16).to_be_bytes());
+    bytes.extend((current.column_size_pixels as u16).to_be_bytes());
+    // Only a previous frame of the same size can be used as the basis of the changes
+    let reference: Option<&[u8]> = previous
+        .filter(|previous| {
+            previous.row_size_bytes == current.row_size_bytes
+                && previous.column_size_pixels == current.column_size_pixels
+        })
+        .map(|previous| &previous.pixels[..]);
+    bytes.push(match reference {
+        Some(_) => DELTA_CHANGE_FRAME,
+        None => DELTA_KEY_FRAME,
+    });
+    let delta = |index: usize| current.pixels[index] ^ reference.map_or(0x0, |r| r[index]);
+    let length: usize = current.pixels.len();
+    let mut position: usize = 0;
+    while position < length {
+        let zeros_start: usize = position;
+        while position < length && delta(position) == 0x0 {
+            position += 1;
+        }
+        // Continue the literal bytes over any single zero byte, which costs no more to include
+        // than to start a new run for
+        let literals_start: usize = position;
+        while position < length
+            && !(delta(position) == 0x0 && (position + 1 == length || delta(position + 1) == 0x0))
+        {
+            position += 1;
+        }
+        write_varint(&mut bytes, literals_start - zeros_start);
+        write_varint(&mut bytes, position - literals_start);
+        bytes.extend((literals_start..position).map(delta));
+    }
+    bytes
+}
+
+/// Decodes a frame buffer encoded by [encode_delta()].  Returns `None` if the encoding is
+/// malformed, or holds the changes from a previous frame but no previous frame of the same size
+/// is supplied.
+///
+/// # Arguments
+///
+/// * `previous` - the frame buffer passed to [encode_delta()] as the previous frame, if any
+/// * `encoded` - the encoding to decode
+pub fn decode_delta(previous: Option<&Display>, encoded: &[u8]) -> Option<Display> {
+    if encoded.len() < 5 {
+        return None;
+    }
+    let row_size_bytes: usize = u16::from_be_bytes([encoded[0], encoded[1]]) as usize;
+    let column_size_pixels: usize = u16::from_be_bytes([encoded[2], encoded[3]]) as usize;
+    let length: usize = row_size_bytes * column_size_pixels * DISPLAY_PLANE_COUNT;
+    let mut pixels: Vec<u8> = match encoded[4] {
+        DELTA_KEY_FRAME => vec![0x0; length],
+        DELTA_CHANGE_FRAME => match previous {
+            Some(previous)
+                if previous.row_size_bytes == row_size_bytes
+                    && previous.column_size_pixels == column_size_pixels =>
+            {
+                previous.pixels.to_vec()
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let mut cursor: usize = 5;
+    let mut position: usize = 0;
+    while position < length {
+        let zeros: usize = read_varint(encoded, &mut cursor)?;
+        let literals: usize = read_varint(encoded, &mut cursor)?;
+        position = position.checked_add(zeros)?;
+        let end: usize = position.checked_add(literals)?;
+        if end > length || encoded.len() - cursor < literals {
+            return None;
+        }
+        for (pixel, delta) in pixels[position..end]
+            .iter_mut()
+            .zip(&encoded[cursor..cursor + literals])
+        {
+            *pixel ^= delta;
+        }
+        cursor += literals;
+        position = end;
+    }
+    if cursor != encoded.len() {
+        return None;
+    }
+    Display::from_planes(row_size_bytes, column_size_pixels, pixels)
+}
+
+/// Helper function that appends the passed value to the passed bytes as a LEB128 varint (seven
+/// bits per byte, least significant first, with the top bit set on all but the last byte)
+///
+/// # Arguments
+///
+/// * `bytes` - the bytes to append to
+/// * `value` - the value to append
+fn write_varint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Helper function that reads a LEB128 varint from the passed bytes at the passed position,
+/// advancing the position past it.  Returns `None` if the bytes end before the varint does, or
+/// the value is too large.
+///
+/// # Arguments
+///
+/// * `bytes` - the bytes to read from
+/// * `cursor` - the position at which to read, advanced past the varint
+fn read_varint(bytes: &[u8], cursor: &mut usize) -> Option<usize> {
+    let mut value: usize = 0;
+    let mut shift: u32 = 0;
+    loop {
+        let byte: u8 = *bytes.get(*cursor)?;
+        *cursor += 1;
+        value |= ((byte & 0x7F) as usize).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+        shift += 7;
+        if shift >= usize::BITS {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_test_display_low_res() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows:
+        // 00001111 01010101   (i.e. 0F 55 in hex)
+        // 11110000 10101010   (i.e. F0 AA in hex)
+        // 00110011 11001100   (i.e. 33 CC in hex)
+        display[0][0] = 0x0F;
+        display[0][1] = 0x55;
+        display[1][0] = 0xF0;
+        display[1][1] = 0xAA;
+        display[2][0] = 0x33;
+        display[2][1] = 0xCC;
+        display
+    }
+
+    #[test]
+    fn test_encode_decode_delta() {
+        let previous: Display = setup_test_display_low_res();
+        let mut current: Display = previous.clone();
+        current[2][1] = 0x0C;
+        current[31][7] = 0xFF;
+        // A key frame decodes without the previous frame; a change frame needs it, and encodes
+        // only the changed bytes
+        let key_frame: Vec<u8> = encode_delta(None, &current);
+        assert_eq!(decode_delta(None, &key_frame), Some(current.clone()));
+        let change_frame: Vec<u8> = encode_delta(Some(&previous), &current);
+        assert!(change_frame[4] == DELTA_CHANGE_FRAME && change_frame.len() < 16);
+        assert_eq!(decode_delta(Some(&previous), &change_frame), Some(current));
+        assert_eq!(decode_delta(None, &change_frame), None);
+    }
+
+    #[test]
+    fn test_encode_delta_unchanged() {
+        let display: Display = setup_test_display_low_res();
+        let encoded: Vec<u8> = encode_delta(Some(&display), &display);
+        // Header, then a single run of (64 / 8) * 32 * 2 = 512 zero bytes and no literals
+        assert_eq!(
+            encoded,
+            vec![0x00, 0x08, 0x00, 0x20, 0x01, 0x80, 0x04, 0x00]
+        );
+        assert_eq!(decode_delta(Some(&display), &encoded), Some(display));
+    }
+
+    #[test]
+    fn test_encode_delta_resized() {
+        // A previous frame of a different size cannot be the basis of the changes
+        let previous: Display = setup_test_display_low_res();
+        let current: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        let encoded: Vec<u8> = encode_delta(Some(&previous), &current);
+        assert_eq!(encoded[4], DELTA_KEY_FRAME);
+        assert_eq!(decode_delta(Some(&previous), &encoded), Some(current));
+    }
+
+    #[test]
+    fn test_decode_delta_malformed() {
+        let display: Display = setup_test_display_low_res();
+        let mut encoded: Vec<u8> = encode_delta(None, &display);
+        encoded.push(0x00);
+        assert_eq!(decode_delta(None, &encoded), None);
+        encoded.truncate(encoded.len() - 3);
+        assert_eq!(decode_delta(None, &encoded), None);
+        assert_eq!(decode_delta(None, &[0x00, 0x08, 0x00, 0x20, 0x02]), None);
+    }
+
+    #[test]
+    fn test_rotated() {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        display[0][0] = 0x80; // top-left pixel on
+        let rotated: Display = display.rotated(DisplayOrientation::Rotate90);
+        assert_eq!(rotated.get_row_size_bytes(), LOW_RES_COLUMN_SIZE_PIXELS / 8);
+        assert_eq!(rotated.get_column_size_pixels(), LOW_RES_ROW_SIZE_PIXELS);
+        assert!(rotated.get_pixel(LOW_RES_COLUMN_SIZE_PIXELS - 1, 0));
+        let rotated: Display = display.rotated(DisplayOrientation::Rotate180);
+        assert!(rotated.get_pixel(LOW_RES_ROW_SIZE_PIXELS - 1, LOW_RES_COLUMN_SIZE_PIXELS - 1));
+        let rotated: Display = display.rotated(DisplayOrientation::Rotate270);
+        assert!(rotated.get_pixel(0, LOW_RES_ROW_SIZE_PIXELS - 1));
+        assert_eq!(display.rotated(DisplayOrientation::Rotate0), display);
+    }
+
+    #[test]
+    fn test_rotated_full_turn() {
+        let display: Display = setup_test_display_low_res();
+        let rotated: Display = display
+            .rotated(DisplayOrientation::Rotate90)
+            .rotated(DisplayOrientation::Rotate270);
+        assert_eq!(rotated, display);
+    }
+
+    fn setup_test_display_low_res_right() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows:
+        // 00001111 01010101   (i.e. 0F 55 in hex)
+        // 11110000 10101010   (i.e. F0 AA in hex)
+        // 00110011 11001100   (i.e. 33 CC in hex)
+        display[0][6] = 0x0F;
+        display[0][7] = 0x55;
+        display[1][6] = 0xF0;
+        display[1][7] = 0xAA;
+        display[2][6] = 0x33;
+        display[2][7] = 0xCC;
+        display
+    }
+
+    fn setup_test_display_low_res_bottom() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows (at bottom of screen)
+        // At row MAX-1:  00001111 01010101   (i.e. 0F 55 in hex)
+        // At row MAX:    11110000 10101010   (i.e. F0 AA in hex)
+        display[LOW_RES_COLUMN_SIZE_PIXELS - 2][0] = 0x0F;
+        display[LOW_RES_COLUMN_SIZE_PIXELS - 2][1] = 0x55;
+        display[LOW_RES_COLUMN_SIZE_PIXELS - 1][0] = 0xF0;
+        display[LOW_RES_COLUMN_SIZE_PIXELS - 1][1] = 0xAA;
+        display
+    }
+
+    fn setup_test_display_high_res() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows:
+        // 00001111 01010101 11100010  (i.e. 0F 55 E2 in hex)
+        // 11110000 10101010 00011101  (i.e. F0 AA 1D in hex)
+        // 00110011 11001100 10110100  (i.e. 33 CC B4 in hex)
+        display[0][0] = 0x0F;
+        display[0][1] = 0x55;
+        display[0][2] = 0xE2;
+        display[1][0] = 0xF0;
+        display[1][1] = 0xAA;
+        display[1][2] = 0x1D;
+        display[2][0] = 0x33;
+        display[2][1] = 0xCC;
+        display[2][2] = 0xB4;
+        display
+    }
+
+    fn setup_test_display_high_res_right() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows:
+        // 00001111 01010101 11100010  (i.e. 0F 55 E2 in hex)
+        // 11110000 10101010 00011101  (i.e. F0 AA 1D in hex)
+        // 00110011 11001100 10110100  (i.e. 33 CC B4 in hex)
+        display[0][13] = 0x0F;
+        display[0][14] = 0x55;
+        display[0][15] = 0xE2;
+        display[1][13] = 0xF0;
+        display[1][14] = 0xAA;
+        display[1][15] = 0x1D;
+        display[2][13] = 0x33;
+        display[2][14] = 0xCC;
+        display[2][15] = 0xB4;
+        display
+    }
+
+    fn setup_test_display_high_res_bottom() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows (at bottom of screen)
+        // At row MAX-1:  00001111 01010101   (i.e. 0F 55 in hex)
+        // At row MAX:    11110000 10101010   (i.e. F0 AA in hex)
+        display[HIGH_RES_COLUMN_SIZE_PIXELS - 2][0] = 0x0F;
+        display[HIGH_RES_COLUMN_SIZE_PIXELS - 2][1] = 0x55;
+        display[HIGH_RES_COLUMN_SIZE_PIXELS - 1][0] = 0xF0;
+        display[HIGH_RES_COLUMN_SIZE_PIXELS - 1][1] = 0xAA;
+        display
+    }
+
+    fn setup_test_display_high_res_scroll_left() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows, for every row:
+        // 00011001 00011001 .. 00011001  (i.e. 19 19 .. 19)
+        for i in 0..display.get_column_size_pixels() {
+            for j in 0..display.get_row_size_bytes() {
+                display[i][j] = 0x19;
+            }
+        }
+        display
+    }
+
+    fn setup_test_display_high_res_scroll_right() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows, for every row:
+        // 01110100 01110100 .. 01110100  (i.e. 74 74 .. 74)
+        for i in 0..display.get_column_size_pixels() {
+            for j in 0..display.get_row_size_bytes() {
+                display[i][j] = 0x74;
+            }
+        }
+        display
+    }
+
+    fn setup_test_display_high_res_scroll_down() -> Display {
+        let mut display: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        // Setup test display as follows.  First row has all pixels turned on i.e. all bytes are 0xFF
+        // All other rows have all pixels turned off i.e. all bytes are 0x00
+        // 11111111 11111111 .. 11111111    (i.e. FF FF .. FF)
+        // 00000000 00000000 .. 00000000    (i.e. 00 00 .. 00)
+        //    ..       ..          ..
+        // 00000000 00000000 .. 00000000    (i.e. 00 00 .. 00)
+        for i in 1..display.get_column_size_pixels() {
+            for j in 0..display.get_row_size_bytes() {
+                display[i][j] = 0x00;
+            }
+        }
+        for j in 0..display.get_row_size_bytes() {
+            display[0][j] = 0xFF;
+        }
+        display
+    }
+
+    fn setup_test_sprite() -> [u8; 2] {
+        // Setup test sprite as follows:
+        // 10110110   (i.e. B6 in hex)
+        // 11100011   (i.e. E3 in hex)
+        let sprite: [u8; 2] = [0xB6, 0xE3];
+        sprite
+    }
+
+    fn setup_test_sprite_large() -> [u8; 32] {
+        // Setup test sprite as follows:
+        // 10110110 11000101  (i.e. B6 C5 in hex)
+        // 11100011 00010011  (i.e. E3 13 in hex)
+        // 01111011 11000001  (i.e. 7B C1 in hex)
+        // 01001100 11000011  (i.e. 4C C3 in hex)
+        // 10110110 11000101  (i.e. B6 C5 in hex)
+        // 11100011 00010011  (i.e. E3 13 in hex)
+        // 01111011 11000001  (i.e. 7B C1 in hex)
+        // 01001100 11000011  (i.e. 4C C3 in hex)
+        // 10110110 11000101  (i.e. B6 C5 in hex)
+        // 11100011 00010011  (i.e. E3 13 in hex)
+        // 01111011 11000001  (i.e. 7B C1 in hex)
+        // 01001100 11000011  (i.e. 4C C3 in hex)
+        // 10110110 11000101  (i.e. B6 C5 in hex)
+        // 11100011 00010011  (i.e. E3 13 in hex)
+        // 01111011 11000001  (i.e. 7B C1 in hex)
+        // 01001100 11000011  (i.e. 4C C3 in hex)
+        let sprite: [u8; 32] = [
+            0xB6, 0xC5, 0xE3, 0x13, 0x7B, 0xC1, 0x4C, 0xC3, 0xB6, 0xC5, 0xE3, 0x13, 0x7B, 0xC1,
+            0x4C, 0xC3, 0xB6, 0xC5, 0xE3, 0x13, 0x7B, 0xC1, 0x4C, 0xC3, 0xB6, 0xC5, 0xE3, 0x13,
+            0x7B, 0xC1, 0x4C, 0xC3,
+        ];
+        sprite
+    }
+
+    #[test]
+    fn test_draw_sprite_aligned() {
+        let mut display: Display = setup_test_display_low_res();
+        let sprite: [u8; 2] = setup_test_sprite();
+        // Draw sprite at coordinate (0, 0)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(0, 0, &sprite, false, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Result should be:
+        // 10111001 01010101   (i.e. B9 55 in hex)
+        // 00010011 10101010   (i.e. 13 AA in hex)
+        // 00110011 11001100   (i.e. 33 CC in hex)
+        assert!(
+            rows_with_collisions == 2
+                && rows_clipped == 0
+                && display[0][0] == 0xB9
+                && display[0][1] == 0x55
+                && display[1][0] == 0x13
+                && display[1][1] == 0xAA
+                && display[2][0] == 0x33
+                && display[2][1] == 0xCC
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_unaligned() {
+        let mut display: Display = setup_test_display_low_res();
+        let sprite: [u8; 2] = setup_test_sprite();
+        // Draw sprite at coordinate (3, 0)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(3, 0, &sprite, false, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Result should be:
+        // 00011001 10010101   (i.e. 19 95 in hex)
+        // 11101100 11001010   (i.e. EC CA in hex)
+        // 00110011 11001100   (i.e. 33 CC in hex)
+        assert!(
+            rows_with_collisions == 2
+                && rows_clipped == 0
+                && display[0][0] == 0x19
+                && display[0][1] == 0x95
+                && display[1][0] == 0xEC
+                && display[1][1] == 0xCA
+                && display[2][0] == 0x33
+                && display[2][1] == 0xCC
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_unaligned_overflow_right() {
+        let mut display: Display = setup_test_display_low_res_right();
+        let sprite: [u8; 2] = setup_test_sprite();
+        // Draw sprite at coordinate (57, 0)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(57, 0, &sprite, false, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Result should be:
+        // 00001111 00001110   (i.e. 0F 0E in hex)
+        // 11110000 11011011   (i.e. F0 DB in hex)
+        // 00110011 11001100   (i.e. 33 CC in hex)
+        assert!(
+            rows_with_collisions == 2
+                && rows_clipped == 0
+                && display[0][6] == 0x0F
+                && display[0][7] == 0x0E
+                && display[1][6] == 0xF0
+                && display[1][7] == 0xDB
+                && display[2][6] == 0x33
+                && display[2][7] == 0xCC
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_unaligned_wrap_right() {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        let sprite: [u8; 2] = setup_test_sprite();
+        // Draw sprite at coordinate (57, 0) with wrapping
+        let (rows_with_collisions, _) = display
+            .draw_sprite(57, 0, &sprite, false, SpriteEdgeMode::Wrap)
+            .unwrap();
+        // Final pixel of each sprite row should wrap to the first pixel of the same display row:
+        // 10110110 -> 01011011 at byte 7, 0 at byte 0 (i.e. 5B and 00 in hex)
+        // 11100011 -> 01110001 at byte 7, 1 at byte 0 (i.e. 71 and 80 in hex)
+        assert!(
+            rows_with_collisions == 0
+                && display[0][7] == 0x5B
+                && display[0][0] == 0x00
+                && display[1][7] == 0x71
+                && display[1][0] == 0x80
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_aligned_wrap_bottom() {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        let sprite: [u8; 2] = setup_test_sprite();
+        // Draw sprite at coordinate (0, final row) with wrapping; second row should wrap to the top
+        display
+            .draw_sprite(
+                0,
+                LOW_RES_COLUMN_SIZE_PIXELS - 1,
+                &sprite,
+                false,
+                SpriteEdgeMode::Wrap,
+            )
+            .unwrap();
+        assert!(display[LOW_RES_COLUMN_SIZE_PIXELS - 1][0] == 0xB6 && display[0][0] == 0xE3)
+    }
+
+    #[test]
+    fn test_draw_sprite_aligned_overflow_bottom() {
+        let mut display: Display = setup_test_display_low_res_bottom();
+        let sprite: [u8; 2] = setup_test_sprite();
+        // Draw sprite at coordinate (0, final row)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(
+                0,
+                LOW_RES_COLUMN_SIZE_PIXELS - 1,
+                &sprite,
+                false,
+                SpriteEdgeMode::Clip,
+            )
+            .unwrap();
+        // Result should be:
+        // 00001111 01010101   (i.e. 0F 55 in hex)
+        // 01000110 10101010   (i.e. 46 AA in hex)
+        assert!(
+            rows_with_collisions == 1
+                && rows_clipped == 0 // this is disabled; would be 1
+                && display[LOW_RES_COLUMN_SIZE_PIXELS - 2][0] == 0x0F
+                && display[LOW_RES_COLUMN_SIZE_PIXELS - 2][1] == 0x55
+                && display[LOW_RES_COLUMN_SIZE_PIXELS - 1][0] == 0x46
+                && display[LOW_RES_COLUMN_SIZE_PIXELS - 1][1] == 0xAA
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_large_aligned() {
+        let mut display: Display = setup_test_display_high_res();
+        let sprite: [u8; 32] = setup_test_sprite_large();
+        // Draw sprite at coordinate (0, 0)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(0, 0, &sprite, true, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Result should be:
+        // 10111001 10010000 11100010   (i.e. B9 90 E2 in hex)
+        // 00010011 10111001 00011101   (i.e. 13 B9 1D in hex)
+        // 01001000 00001101 10110100   (i.e. 48 0D B4 in hex)
+        // 01001100 11000011 00000000   (i.e. 4C C3 00 in hex)
+        // 10110110 11000101 00000000   (i.e. B6 C5 00 in hex)
+        // 11100011 00010011 00000000   (i.e. E3 13 00 in hex)
+        // 01111011 11000001 00000000   (i.e. 7B C1 00 in hex)
+        // 01001100 11000011 00000000   (i.e. 4C C3 00 in hex)
+        // 10110110 11000101 00000000   (i.e. B6 C5 00 in hex)
+        // 11100011 00010011 00000000   (i.e. E3 13 00 in hex)
+        // 01111011 11000001 00000000   (i.e. 7B C1 00 in hex)
+        // 01001100 11000011 00000000   (i.e. 4C C3 00 in hex)
+        // 10110110 11000101 00000000   (i.e. B6 C5 00 in hex)
+        // 11100011 00010011 00000000   (i.e. E3 13 00 in hex)
+        // 01111011 11000001 00000000   (i.e. 7B C1 00 in hex)
+        // 01001100 11000011 00000000   (i.e. 4C C3 00 in hex)
+        assert!(
+            rows_with_collisions == 3
+                && rows_clipped == 0
+                && display[0][0] == 0xB9
+                && display[0][1] == 0x90
+                && display[0][2] == 0xE2
+                && display[1][0] == 0x13
+                && display[1][1] == 0xB9
+                && display[1][2] == 0x1D
+                && display[2][0] == 0x48
+                && display[2][1] == 0x0D
+                && display[2][2] == 0xB4
+                && display[3][0] == 0x4C
+                && display[3][1] == 0xC3
+                && display[3][2] == 0x00
+                && display[4][0] == 0xB6
+                && display[4][1] == 0xC5
+                && display[4][2] == 0x00
+                && display[5][0] == 0xE3
+                && display[5][1] == 0x13
+                && display[5][2] == 0x00
+                && display[6][0] == 0x7B
+                && display[6][1] == 0xC1
+                && display[6][2] == 0x00
+                && display[7][0] == 0x4C
+                && display[7][1] == 0xC3
+                && display[7][2] == 0x00
+                && display[8][0] == 0xB6
+                && display[8][1] == 0xC5
+                && display[8][2] == 0x00
+                && display[9][0] == 0xE3
+                && display[9][1] == 0x13
+                && display[9][2] == 0x00
+                && display[10][0] == 0x7B
+                && display[10][1] == 0xC1
+                && display[10][2] == 0x00
+                && display[11][0] == 0x4C
+                && display[11][1] == 0xC3
+                && display[11][2] == 0x00
+                && display[12][0] == 0xB6
+                && display[12][1] == 0xC5
+                && display[12][2] == 0x00
+                && display[13][0] == 0xE3
+                && display[13][1] == 0x13
+                && display[13][2] == 0x00
+                && display[14][0] == 0x7B
+                && display[14][1] == 0xC1
+                && display[14][2] == 0x00
+                && display[15][0] == 0x4C
+                && display[15][1] == 0xC3
+                && display[15][2] == 0x00
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_large_unaligned() {
+        let mut display: Display = setup_test_display_high_res();
+        let sprite: [u8; 32] = setup_test_sprite_large();
+        // Draw sprite at coordinate (0, 0)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(3, 0, &sprite, true, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Result should be:
+        // 00011001 10001101 01000010   (i.e. 19 8D 42 in hex)
+        // 11101100 11001000 01111101   (i.e. EC C8 7D in hex)
+        // 00111100 10110100 10010100   (i.e. 3C B4 94 in hex)
+        // 00001001 10011000 01100000   (i.e. 09 98 60 in hex)
+        // 00010110 11011000 10100000   (i.e. 16 D8 A0 in hex)
+        // 00011100 01100010 01100000   (i.e. 1C 62 60 in hex)
+        // 00001111 01111000 00100000   (i.e. 0F 78 20 in hex)
+        // 00001001 10011000 01100000   (i.e. 09 98 60 in hex)
+        // 00010110 11011000 10100000   (i.e. 16 D8 A0 in hex)
+        // 00011100 01100010 01100000   (i.e. 1C 62 60 in hex)
+        // 00001111 01111000 00100000   (i.e. 0F 78 20 in hex)
+        // 00001001 10011000 01100000   (i.e. 09 98 60 in hex)
+        // 00010110 11011000 10100000   (i.e. 16 D8 A0 in hex)
+        // 00011100 01100010 01100000   (i.e. 1C 62 60 in hex)
+        // 00001111 01111000 00100000   (i.e. 0F 78 20 in hex)
+        // 00001001 10011000 01100000   (i.e. 09 98 60 in hex)
+        assert!(
+            rows_with_collisions == 3
+                && rows_clipped == 0
+                && display[0][0] == 0x19
+                && display[0][1] == 0x8D
+                && display[0][2] == 0x42
+                && display[1][0] == 0xEC
+                && display[1][1] == 0xC8
+                && display[1][2] == 0x7D
+                && display[2][0] == 0x3C
+                && display[2][1] == 0xB4
+                && display[2][2] == 0x94
+                && display[3][0] == 0x09
+                && display[3][1] == 0x98
+                && display[3][2] == 0x60
+                && display[4][0] == 0x16
+                && display[4][1] == 0xD8
+                && display[4][2] == 0xA0
+                && display[5][0] == 0x1C
+                && display[5][1] == 0x62
+                && display[5][2] == 0x60
+                && display[6][0] == 0x0F
+                && display[6][1] == 0x78
+                && display[6][2] == 0x20
+                && display[7][0] == 0x09
+                && display[7][1] == 0x98
+                && display[7][2] == 0x60
+                && display[8][0] == 0x16
+                && display[8][1] == 0xD8
+                && display[8][2] == 0xA0
+                && display[9][0] == 0x1C
+                && display[9][1] == 0x62
+                && display[9][2] == 0x60
+                && display[10][0] == 0x0F
+                && display[10][1] == 0x78
+                && display[10][2] == 0x20
+                && display[11][0] == 0x09
+                && display[11][1] == 0x98
+                && display[11][2] == 0x60
+                && display[12][0] == 0x16
+                && display[12][1] == 0xD8
+                && display[12][2] == 0xA0
+                && display[13][0] == 0x1C
+                && display[13][1] == 0x62
+                && display[13][2] == 0x60
+                && display[14][0] == 0x0F
+                && display[14][1] == 0x78
+                && display[14][2] == 0x20
+                && display[15][0] == 0x09
+                && display[15][1] == 0x98
+                && display[15][2] == 0x60
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_large_unaligned_overflow_right() {
+        let mut display: Display = setup_test_display_high_res_right();
+        let sprite: [u8; 32] = setup_test_sprite_large();
+        // Draw sprite at coordinate (114, 0)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(114, 0, &sprite, true, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Result should be:
+        // 00001111 01111000 01010011   (i.e. 0F 78 53 in hex)
+        // 11110000 10010010 11011001   (i.e. F0 92 D9 in hex)
+        // 00110011 11010010 01000110   (i.e. 33 D2 44 in hex)
+        // 00000000 00010011 00110000   (i.e. 00 13 30 in hex)
+        // 00000000 00101101 10110001   (i.e. 00 2D B1 in hex)
+        // 00000000 00111000 11000100   (i.e. 00 38 C4 in hex)
+        // 00000000 00011110 11110000   (i.e. 00 1E F0 in hex)
+        // 00000000 00010011 00110000   (i.e. 00 13 30 in hex)
+        // 00000000 00101101 10110001   (i.e. 00 2D B1 in hex)
+        // 00000000 00111000 11000100   (i.e. 00 38 C4 in hex)
+        // 00000000 00011110 11110000   (i.e. 00 1E F0 in hex)
+        // 00000000 00010011 00110000   (i.e. 00 13 30 in hex)
+        // 00000000 00101101 10110001   (i.e. 00 2D B1 in hex)
+        // 00000000 00111000 11000100   (i.e. 00 38 C4 in hex)
+        // 00000000 00011110 11110000   (i.e. 00 1E F0 in hex)
+        // 00000000 00010011 00110000   (i.e. 00 13 30 in hex)
+        assert!(
+            rows_with_collisions == 3
+                && rows_clipped == 0
+                && display[0][13] == 0x0F
+                && display[0][14] == 0x78
+                && display[0][15] == 0x53
+                && display[1][13] == 0xF0
+                && display[1][14] == 0x92
+                && display[1][15] == 0xD9
+                && display[2][13] == 0x33
+                && display[2][14] == 0xD2
+                && display[2][15] == 0x44
+                && display[3][13] == 0x00
+                && display[3][14] == 0x13
+                && display[3][15] == 0x30
+                && display[4][13] == 0x00
+                && display[4][14] == 0x2D
+                && display[4][15] == 0xB1
+                && display[5][13] == 0x00
+                && display[5][14] == 0x38
+                && display[5][15] == 0xC4
+                && display[6][13] == 0x00
+                && display[6][14] == 0x1E
+                && display[6][15] == 0xF0
+                && display[7][13] == 0x00
+                && display[7][14] == 0x13
+                && display[7][15] == 0x30
+                && display[8][13] == 0x00
+                && display[8][14] == 0x2D
+                && display[8][15] == 0xB1
+                && display[9][13] == 0x00
+                && display[9][14] == 0x38
+                && display[9][15] == 0xC4
+                && display[10][13] == 0x00
+                && display[10][14] == 0x1E
+                && display[10][15] == 0xF0
+                && display[11][13] == 0x00
+                && display[11][14] == 0x13
+                && display[11][15] == 0x30
+                && display[12][13] == 0x00
+                && display[12][14] == 0x2D
+                && display[12][15] == 0xB1
+                && display[13][13] == 0x00
+                && display[13][14] == 0x38
+                && display[13][15] == 0xC4
+                && display[14][13] == 0x00
+                && display[14][14] == 0x1E
+                && display[14][15] == 0xF0
+                && display[15][13] == 0x00
+                && display[15][14] == 0x13
+                && display[15][15] == 0x30
+        );
+    }
+
+    #[test]
+    fn test_draw_sprite_large_aligned_overflow_bottom() {
+        let mut display: Display = setup_test_display_high_res_bottom();
+        let sprite: [u8; 32] = setup_test_sprite_large();
+        // Draw sprite at coordinate (0, final row)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(
+                0,
+                HIGH_RES_COLUMN_SIZE_PIXELS - 1,
+                &sprite,
+                true,
+                SpriteEdgeMode::Clip,
+            )
+            .unwrap();
+        // Result should be:
+        // 00001111 01010101    (i.e. 0F 55 in hex)
+        // 01000110 01101111    (i.e. 46 6F in hex)
+
+        assert!(
+            rows_with_collisions == 1
+                && rows_clipped == 0 // this is disabled; would be 15
+                && display[HIGH_RES_COLUMN_SIZE_PIXELS - 2][0] == 0x0F
+                && display[HIGH_RES_COLUMN_SIZE_PIXELS - 2][1] == 0x55
+                && display[HIGH_RES_COLUMN_SIZE_PIXELS - 1][0] == 0x46
+                && display[HIGH_RES_COLUMN_SIZE_PIXELS - 1][1] == 0x6F
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_no_pixels_unset() {
+        let mut display: Display = setup_test_display_low_res();
+        let sprite: [u8; 2] = [0x0, 0x0];
+        // Draw sprite at coordinate (0, 0)
+        let (rows_with_collisions, rows_clipped) = display
+            .draw_sprite(0, 0, &sprite, false, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Result should be:
+        // 00001111 01010101   (i.e. 0F 55 in hex)
+        // 11110000 10101010   (i.e. F0 AA in hex)
+        // 00110011 11001100   (i.e. 33 CC in hex)
+        assert!(
+            rows_with_collisions == 0
+                && rows_clipped == 0
+                && display[0][0] == 0x0F
+                && display[0][1] == 0x55
+                && display[1][0] == 0xF0
+                && display[1][1] == 0xAA
+                && display[2][0] == 0x33
+                && display[2][1] == 0xCC
+        )
+    }
+
+    #[test]
+    fn test_scroll_display_left() {
+        let mut display: Display = setup_test_display_high_res_scroll_left();
+        display.scroll_display_left().unwrap();
+        let mut all_bytes_correct: bool = true;
+        // Each byte should have scrolled from 00011001 (i.e. 0x19) to 10010001 (i.e. 0x91)
+        // except for the last byte in each row, which will be 10010000 (i.e. 0x90)
+        'outer: for i in 0..display.get_column_size_pixels() {
+            for j in 0..display.get_row_size_bytes() - 1 {
+                if display[i][j] != 0x91 {
+                    all_bytes_correct = false;
+                    break 'outer;
+                }
+            }
+            if display[i][display.get_row_size_bytes() - 1] != 0x90 {
+                all_bytes_correct = false;
+                break 'outer;
+            }
+        }
+        assert!(all_bytes_correct);
+    }
+
+    #[test]
+    fn test_scroll_display_right() {
+        let mut display: Display = setup_test_display_high_res_scroll_right();
+        display.scroll_display_right().unwrap();
+        let mut all_bytes_correct: bool = true;
+        // Each byte should have scrolled from 01110100 (i.e. 0x74) to 01000111 (i.e. 0x47)
+        // except for the first byte in each row, which will be 00000111 (i.e. 0x07)
+        'outer: for i in 0..display.get_column_size_pixels() {
+            for j in 1..display.get_row_size_bytes() {
+                if display[i][j] != 0x47 {
+                    all_bytes_correct = false;
+                    break 'outer;
+                }
+            }
+            if display[i][0] != 0x07 {
+                all_bytes_correct = false;
+                break 'outer;
+            }
+        }
+        assert!(all_bytes_correct);
+    }
+
+    #[test]
+    fn test_scroll_display_down() {
+        let mut display: Display = setup_test_display_high_res_scroll_down();
+        display.scroll_display_down(7).unwrap();
+        let mut all_bytes_correct: bool = true;
+        // Row 0 should be fully filled with 0x00 bytes
+        // Row 6 should be fully filled with 0x00 bytes
+        // Row 7 should be fully filled with 0xFF bytes
+        // Row 8 should be fully filled with 0x00 bytes
+        // except for the first byte in each row, which will be 00000111 (i.e. 0x07)
+        for j in 0..display.get_row_size_bytes() {
+            if display[0][j] != 0x00 {
+                all_bytes_correct = false;
+                break;
+            }
+            if display[6][j] != 0x00 {
+                all_bytes_correct = false;
+                break;
+            }
+            if display[7][j] != 0xFF {
+                all_bytes_correct = false;
+                break;
+            }
+            if display[8][j] != 0x00 {
+                all_bytes_correct = false;
+                break;
+            }
+        }
+        assert!(all_bytes_correct);
+    }
+
+    #[test]
+    fn test_scroll_display_up() {
+        let mut display: Display = setup_test_display_low_res();
+        display.scroll_display_up(1).unwrap();
+        // Rows 1 and 2 of the test display move up to rows 0 and 1, and the last row is cleared
+        assert!(
+            display[0][0] == 0xF0
+                && display[0][1] == 0xAA
+                && display[1][0] == 0x33
+                && display[1][1] == 0xCC
+                && display[2][0] == 0x00
+                && display[LOW_RES_COLUMN_SIZE_PIXELS - 1][0] == 0x00
+        );
+    }
+
+    #[test]
+    fn test_scroll_display_up_full_height() {
+        let mut display: Display = setup_test_display_low_res();
+        display
+            .scroll_display_up(LOW_RES_COLUMN_SIZE_PIXELS as u8)
+            .unwrap();
+        assert!(display
+            .get_plane(0)
+            .unwrap()
+            .iter()
+            .all(|byte| *byte == 0x00));
+    }
+
+    #[test]
+    fn test_scroll_display_up_out_of_bounds_error() {
+        let mut display: Display = setup_test_display_low_res();
+        let operands: Vec<Operand> = vec![Operand::N(LOW_RES_COLUMN_SIZE_PIXELS as u8 + 1)];
+        assert_eq!(
+            display
+                .scroll_display_up(LOW_RES_COLUMN_SIZE_PIXELS as u8 + 1)
+                .unwrap_err(),
+            ErrorDetail::OperandsOutOfBounds { operands }
+        );
+    }
+
+    #[test]
+    fn test_scroll_planes_second_plane_only() {
+        let mut display: Display = setup_test_display_low_res();
+        display.select_planes(0b10).unwrap();
+        display
+            .draw_sprite(0, 1, &[0xF0], false, SpriteEdgeMode::Clip)
+            .unwrap();
+        // Scroll only the second plane, regardless of the planes selected for drawing
+        display.select_planes(0b01).unwrap();
+        display.scroll_planes_up(0b10, 1).unwrap();
+        display.scroll_planes_right(0b10).unwrap();
+        let second_plane: &[u8] = display.get_plane(1).unwrap();
+        assert!(
+            display[0][0] == 0x0F
+                && display[1][0] == 0xF0
+                && second_plane[0] == 0x0F
+                && second_plane[display.get_row_size_bytes()] == 0x00
+                && display.get_pixel_colour_index(0, 0) == 0b00
+                && display.get_pixel_colour_index(0, 4) == 0b11
+        );
+    }
+
+    #[test]
+    fn test_scroll_planes_out_of_bounds_error() {
+        let mut display: Display = setup_test_display_low_res();
+        let operands: Vec<Operand> = vec![Operand::PlaneMask(0b100)];
+        assert_eq!(
+            display.scroll_planes_down(0b100, 1).unwrap_err(),
+            ErrorDetail::OperandsOutOfBounds { operands }
+        );
+    }
+
+    #[test]
+    fn test_select_planes_out_of_bounds_error() {
+        let mut display: Display = setup_test_display_low_res();
+        let operands: Vec<Operand> = vec![Operand::PlaneMask(0b100)];
+        assert_eq!(
+            display.select_planes(0b100).unwrap_err(),
+            ErrorDetail::OperandsOutOfBounds { operands }
+        );
+        assert_eq!(display.get_selected_planes(), 0b01);
+    }
+
+    #[test]
+    fn test_draw_sprite_second_plane() {
+        let mut display: Display = setup_test_display_low_res();
+        display.select_planes(0b10).unwrap();
+        // Draw 0xF0 to the second plane at (0, 0); the first plane (0x0F) should be unchanged
+        let (rows_with_collisions, _) = display
+            .draw_sprite(0, 0, &[0xF0], false, SpriteEdgeMode::Clip)
+            .unwrap();
+        assert!(
+            rows_with_collisions == 0
+                && display[0][0] == 0x0F
+                && display.get_plane(1).unwrap()[0] == 0xF0
+                && display.get_pixel_colour_index(0, 0) == 0b10
+                && display.get_pixel_colour_index(0, 4) == 0b01
+        )
+    }
+
+    #[test]
+    fn test_draw_sprite_both_planes() {
+        let mut display: Display = setup_test_display_low_res();
+        display.select_planes(0b11).unwrap();
+        // Sprite data for the first plane (0xF0) is followed by that for the second plane (0xFF)
+        let (rows_with_collisions, _) = display
+            .draw_sprite(0, 0, &[0xF0, 0xFF], false, SpriteEdgeMode::Clip)
+            .unwrap();
+        // First plane becomes 0xFF, second plane becomes 0xFF, so every pixel has colour index 3
+        assert!(
+            rows_with_collisions == 0
+                && display[0][0] == 0xFF
+                && display.get_plane(1).unwrap()[0] == 0xFF
+                && (0..8).all(|i| display.get_pixel_colour_index(0, i) == 0b11)
+        )
+    }
+
+    #[test]
+    fn test_clear_selected_planes_only() {
+        let mut display: Display = setup_test_display_low_res();
+        display.select_planes(0b10).unwrap();
+        display
+            .draw_sprite(0, 0, &[0xF0], false, SpriteEdgeMode::Clip)
+            .unwrap();
+        display.clear();
+        assert!(display[0][0] == 0x0F && display.get_plane(1).unwrap()[0] == 0x00)
+    }
+
+    #[test]
+    fn test_get_colour_indices() {
+        let display: Display = setup_test_display_low_res();
+        let colour_indices: Vec<u8> = display.get_colour_indices();
+        assert!(
+            colour_indices.len() == LOW_RES_ROW_SIZE_PIXELS * LOW_RES_COLUMN_SIZE_PIXELS
+                && colour_indices[0..8] == [0, 0, 0, 0, 1, 1, 1, 1]
+                && colour_indices[LOW_RES_ROW_SIZE_PIXELS..LOW_RES_ROW_SIZE_PIXELS + 8]
+                    == [1, 1, 1, 1, 0, 0, 0, 0]
+        )
+    }
+
+    #[test]
+    fn test_resize_up_and_down() {
+        let superchip11: EmulationLevel = EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        };
+        let mut display: Display = setup_test_display_low_res();
+        display.set_pixel_colour_index(63, 31, 0b10);
+        display.resize(superchip11, true);
+        assert!(
+            display.get_resolution() == (HIGH_RES_ROW_SIZE_PIXELS, HIGH_RES_COLUMN_SIZE_PIXELS)
+                && display[0][0] == 0x00
+                && display[0][1] == 0xFF
+                && display[1][1] == 0xFF
+                && display[2][0] == 0xFF
+                && display.get_pixel_colour_index(63, 127) == 0b10
+                && display.get_pixel_colour_index(62, 126) == 0b10
+        );
+        display.resize(superchip11, false);
+        assert_eq!(display, {
+            let mut original: Display = setup_test_display_low_res();
+            original.set_pixel_colour_index(63, 31, 0b10);
+            original
+        });
+    }
+
+    #[test]
+    fn test_resize_same_size() {
+        let mut display: Display = setup_test_display_low_res();
+        display.resize(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            true,
+        );
+        assert_eq!(display, setup_test_display_low_res());
+    }
+
+    #[test]
+    fn test_set_zone_colours_clipped() {
+        let mut display: Display = Display::new(EmulationLevel::Chip8X {
+            variable_cycle_timing: false,
+        });
+        display.set_zone_colours(6, 30, 4, 4, 0xFC);
+        assert!(
+            display.get_zone_colours().len() == CHIP8X_ZONES_PER_ROW * LOW_RES_COLUMN_SIZE_PIXELS
+                && display.get_zone_colour(47, 30) == Some(CHIP8X_DEFAULT_ZONE_COLOUR)
+                && display.get_zone_colour(48, 30) == Some(0x4)
+                && display.get_zone_colour(63, 31) == Some(0x4)
+                && display.get_zone_colour(48, 29) == Some(CHIP8X_DEFAULT_ZONE_COLOUR)
+                && display.get_zone_colour(64, 31) == None
+        )
+    }
+
+    #[test]
+    fn test_cycle_background_colour() {
+        let mut display: Display = Display::new(EmulationLevel::Chip8X {
+            variable_cycle_timing: false,
+        });
+        let mut colours: Vec<Option<u8>> = Vec::new();
+        for _ in 0..5 {
+            colours.push(display.get_background_colour());
+            display.cycle_background_colour();
+        }
+        assert_eq!(colours, vec![Some(2), Some(0), Some(4), Some(1), Some(2)]);
+    }
+
+    #[test]
+    fn test_no_colour_zones_outside_chip8x() {
+        let mut display: Display = setup_test_display_low_res();
+        display.set_zone_colours(0, 0, 1, 1, 0x7);
+        display.cycle_background_colour();
+        assert!(
+            !display.has_colour_zones()
+                && display.get_zone_colour(0, 0) == None
+                && display.get_background_colour() == None
+        )
+    }
+
+    #[test]
+    fn test_get_pixel() {
+        let display: Display = setup_test_display_low_res();
+        assert!(
+            !display.get_pixel(0, 0)
+                && display.get_pixel(4, 0)
+                && display.get_pixel(0, 1)
+                && !display.get_pixel(4, 1)
+                && !display.get_pixel(LOW_RES_ROW_SIZE_PIXELS, 0)
+                && !display.get_pixel(0, LOW_RES_COLUMN_SIZE_PIXELS)
+        )
+    }
+
+    #[test]
+    fn test_iter_rows() {
+        let display: Display = setup_test_display_low_res();
+        let rows: Vec<Vec<bool>> = display.iter_rows().collect();
+        assert!(
+            rows.len() == LOW_RES_COLUMN_SIZE_PIXELS
+                && rows.iter().all(|row| row.len() == LOW_RES_ROW_SIZE_PIXELS)
+                && rows[0][0..8] == [false, false, false, false, true, true, true, true]
+                && rows[1][0..8] == [true, true, true, true, false, false, false, false]
+        )
+    }
+
+    #[test]
+    fn test_track_intensities() {
+        let mut display: Display = setup_test_display_low_res();
+        assert!(!display.has_intensities() && display.get_intensities().is_none());
+        assert_eq!(display.get_pixel_intensity(4, 0), FULL_INTENSITY);
+        display.track_intensities(true);
+        let intensities: Vec<u8> = display.get_intensities().unwrap();
+        assert!(
+            intensities.len() == LOW_RES_ROW_SIZE_PIXELS * LOW_RES_COLUMN_SIZE_PIXELS
+                && intensities[0..8] == [0, 0, 0, 0, 255, 255, 255, 255]
+        );
+        display.track_intensities(false);
+        assert!(!display.has_intensities());
+    }
+
+    #[test]
+    fn test_decay_intensities() {
+        let mut display: Display = setup_test_display_low_res();
+        display.track_intensities(true);
+        // Erase the lit pixels at the start of the first row, which then fade frame by frame
+        display[0][0] = 0x00;
+        assert_eq!(display.get_pixel_intensity(4, 0), FULL_INTENSITY);
+        assert!(display.decay_intensities(0x60));
+        assert_eq!(display.get_pixel_intensity(4, 0), 0x9F);
+        assert!(display.decay_intensities(0x60));
+        assert!(display.decay_intensities(0x60));
+        assert_eq!(display.get_pixel_intensity(4, 0), 0x00);
+        assert!(!display.decay_intensities(0x60));
+        // Lit pixels remain at full intensity
+        assert_eq!(display.get_pixel_intensity(0, 1), FULL_INTENSITY);
+    }
+
+    #[test]
+    fn test_draw_sprite_lights_intensities() {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        display.track_intensities(true);
+        // Draw then erase a sprite within a frame; its pixels still leave a trail
+        let sprite: [u8; 1] = [0xC0];
+        display
+            .draw_sprite(3, 2, &sprite, false, SpriteEdgeMode::Clip)
+            .unwrap();
+        display
+            .draw_sprite(3, 2, &sprite, false, SpriteEdgeMode::Clip)
+            .unwrap();
+        display.decay_intensities(0x10);
+        assert!(
+            display.get_pixel_intensity(3, 2) == 0xEF
+                && display.get_pixel_intensity(4, 2) == 0xEF
+                && display.get_pixel_intensity(5, 2) == 0x00
+        );
+    }
+
+    #[test]
+    fn test_rotated_intensities() {
+        let mut display: Display = setup_test_display_low_res();
+        display.track_intensities(true);
+        display[0][0] = 0x00;
+        display.decay_intensities(0x10);
+        let rotated: Display = display.rotated(DisplayOrientation::Rotate90);
+        assert_eq!(
+            rotated.get_pixel_intensity(LOW_RES_COLUMN_SIZE_PIXELS - 1, 4),
+            0xEF
+        );
+    }
+
+    #[test]
+    fn test_iter_pixels() {
+        let display: Display = setup_test_display_low_res();
+        let lit_pixels: Vec<(usize, usize)> = display
+            .iter_pixels()
+            .filter(|(_, on)| *on)
+            .map(|(coordinates, _)| coordinates)
+            .collect();
+        assert!(
+            display.iter_pixels().count() == LOW_RES_ROW_SIZE_PIXELS * LOW_RES_COLUMN_SIZE_PIXELS
+                && lit_pixels[0..4] == [(4, 0), (5, 0), (6, 0), (7, 0)]
+                && lit_pixels[4..8] == [(9, 0), (11, 0), (13, 0), (15, 0)]
+                && lit_pixels[8] == (0, 1)
+        )
+    }
+}
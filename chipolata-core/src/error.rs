@@ -0,0 +1,701 @@
+use crate::analysis::suggest_emulation_level;
+use crate::prelude::*;
+use crate::EmulationLevel;
+use crate::ProcessorStatus;
+use crate::StateSnapshot;
+use core::fmt;
+use serde_derive::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::error;
+
+/// An Error enum used throughout the Chipolata crate to communicate details of runtime errors
+/// that have occurred.
+///
+/// Instances of [ErrorDetail] are bubbled-up to the hosting application through the public
+/// API methods.
+#[derive(Debug, PartialEq)]
+pub enum ErrorDetail {
+    /// An unrecognised opcode was read from memory
+    UnknownInstruction { opcode: u16 },
+    /// A valid opcode was read from memory but which is not implemented by Chipolata
+    UnimplementedInstruction { opcode: u16 },
+    /// One or more operands fall outside expected ranges and cannot be safely used
+    /// The Vec field holds each potentially faulty [Operand] along with its value
+    OperandsOutOfBounds { operands: Vec<Operand> },
+    /// An attempt was made to pop an item off the Chipolata stack while it is empty
+    PopEmptyStack,
+    /// An attempt was made to push an item on to the Chipolata stack while it is full.  Holds the
+    /// return addresses on the stack at the time (outermost call first), to help diagnose runaway
+    /// recursion
+    PushFullStack { call_trace: Vec<u16> },
+    /// An attempt was made to read/write from an address outside the addressable range
+    MemoryAddressOutOfBounds { address: u16 },
+    /// A write was made to the reserved memory region (below the program) while it is
+    /// write-protected
+    ReservedMemoryWrite { address: u16 },
+    /// A key ordinal was referenced that is outside the valid CHIP-8 keypad range (0x0 to 0xF)
+    InvalidKey { key: u8 },
+    /// A key was named (for example in a configuration file) which is not a CHIP-8 key
+    InvalidKeyName { name: String },
+    /// A palette was specified holding a number of colours other than a power of two between 2
+    /// and [PALETTE_MAX_COLOURS](crate::PALETTE_MAX_COLOURS) inclusive
+    InvalidPalette { colour_count: usize },
+    /// Font data was specified whose character size is not between 1 and
+    /// [CUSTOM_FONT_MAX_CHAR_SIZE](crate::CUSTOM_FONT_MAX_CHAR_SIZE) bytes, or which does not
+    /// hold a whole number of characters between
+    /// [CUSTOM_FONT_MIN_CHARS](crate::CUSTOM_FONT_MIN_CHARS) and
+    /// [CUSTOM_FONT_MAX_CHARS](crate::CUSTOM_FONT_MAX_CHARS) inclusive
+    InvalidFont { char_size: usize, data_size: usize },
+    /// Error used for any file I/O issues
+    FileError { file_path: String },
+    /// A zip archive held no CHIP-8 ROM entry with the requested name (or, if no name was
+    /// requested, no CHIP-8 ROM entry at all)
+    ArchiveEntryNotFound {
+        file_path: String,
+        entry: Option<String>,
+    },
+    /// Error causes by invalid processor state transition
+    StateTransitionError {
+        old_state: ProcessorStatus,
+        new_state: ProcessorStatus,
+    },
+    /// An attempt was made to start input recording or replay once execution had already begun
+    InputRecordingError { status: ProcessorStatus },
+    /// An attempt was made to modify processor state while execution was not paused
+    ProcessorNotPaused { status: ProcessorStatus },
+    /// Program data could not be parsed in the specified format; holds the (1-based) line number
+    /// at which the problem was found, and a description of the problem
+    MalformedProgram { line: usize, detail: String },
+    /// A watch expression could not be parsed; holds the (0-based) character position at which
+    /// the problem was found, and a description of the problem
+    MalformedWatchExpression { position: usize, detail: String },
+    /// An attempt was made to restore a save state taken from a processor with a different
+    /// emulation level
+    IncompatibleSaveState {
+        saved: EmulationLevel,
+        current: EmulationLevel,
+    },
+    /// An attempt was made to change, without re-initialising the processor, an option which can
+    /// only be set at initialisation; holds the name of the option
+    OptionNotChangeable { option: String },
+    /// Options were saved by a later release of Chipolata, with a schema version this release
+    /// cannot read; holds the version of the options and the latest version supported
+    IncompatibleOptionsVersion { version: u64, supported: u32 },
+    /// A script could not be compiled, or raised an error while handling an event (`scripting`
+    /// feature only); holds the name of the event function, if the error arose in one, and a
+    /// description of the problem
+    ScriptError {
+        function: Option<String>,
+        message: String,
+    },
+    /// General bucket for any unknown issues (to return *something* rather than panicking)
+    UnknownError,
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ErrorDetail {}
+
+impl fmt::Display for ErrorDetail {
+    /// Returns a textual description of each enum variant for display purposes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorDetail::UnknownInstruction { opcode } => {
+                write!(f, "an unrecognised opcode {:#X} was decoded", opcode)
+            }
+            ErrorDetail::UnimplementedInstruction { opcode } => {
+                write!(f, "an unimplemented opcode {} was executed", opcode)
+            }
+            ErrorDetail::OperandsOutOfBounds { operands } => {
+                write!(
+                    f,
+                    "an opcode contains invalid operands: {}",
+                    operands
+                        .iter()
+                        .map(|operand| operand.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
+            ErrorDetail::PopEmptyStack => {
+                write!(f, "an attempt was made to pop the stack while empty")
+            }
+            ErrorDetail::PushFullStack { call_trace } => {
+                write!(
+                    f,
+                    "an attempt was made to push to the stack while full (call trace: {})",
+                    call_trace
+                        .iter()
+                        .map(|address| format!("{:#X}", address))
+                        .collect::<Vec<String>>()
+                        .join(" -> ")
+                )
+            }
+            ErrorDetail::MemoryAddressOutOfBounds { address } => {
+                write!(f, "invalid memory address {} was accessed", address)
+            }
+            ErrorDetail::ReservedMemoryWrite { address } => {
+                write!(
+                    f,
+                    "write-protected reserved memory address {:#05X} was written to",
+                    address
+                )
+            }
+            ErrorDetail::InvalidKey { key } => {
+                write!(f, "invalid key {} was specified", key)
+            }
+            ErrorDetail::InvalidKeyName { name } => {
+                write!(f, "invalid key name {} was specified", name)
+            }
+            ErrorDetail::InvalidPalette { colour_count } => {
+                write!(
+                    f,
+                    "invalid palette of {} colours was specified",
+                    colour_count
+                )
+            }
+            ErrorDetail::InvalidFont {
+                char_size,
+                data_size,
+            } => {
+                write!(
+                    f,
+                    "invalid font of {} bytes with {}-byte characters was specified",
+                    data_size, char_size
+                )
+            }
+            ErrorDetail::FileError { file_path } => {
+                write!(
+                    f,
+                    "invalid file path {} was specified",
+                    file_path.to_string()
+                )
+            }
+            ErrorDetail::ArchiveEntryNotFound { file_path, entry } => match entry {
+                Some(entry) => write!(f, "archive {} holds no ROM named {}", file_path, entry),
+                None => write!(f, "archive {} holds no CHIP-8 ROM", file_path),
+            },
+            ErrorDetail::StateTransitionError {
+                old_state,
+                new_state,
+            } => {
+                write!(
+                    f,
+                    "invalid state transition from {:?} to {:?} was triggered",
+                    old_state, new_state
+                )
+            }
+            ErrorDetail::InputRecordingError { status } => {
+                write!(
+                    f,
+                    "input recording or replay cannot begin from processor status {:?}",
+                    status
+                )
+            }
+            ErrorDetail::ProcessorNotPaused { status } => {
+                write!(
+                    f,
+                    "processor state cannot be modified while in status {:?}",
+                    status
+                )
+            }
+            ErrorDetail::MalformedProgram { line, detail } => {
+                write!(f, "malformed program data on line {}: {}", line, detail)
+            }
+            ErrorDetail::MalformedWatchExpression { position, detail } => {
+                write!(
+                    f,
+                    "malformed watch expression at position {}: {}",
+                    position, detail
+                )
+            }
+            ErrorDetail::IncompatibleSaveState { saved, current } => {
+                write!(
+                    f,
+                    "a save state from emulation level {:?} cannot be restored at emulation level {:?}",
+                    saved, current
+                )
+            }
+            ErrorDetail::OptionNotChangeable { option } => {
+                write!(
+                    f,
+                    "option {} cannot be changed without re-initialising the processor",
+                    option
+                )
+            }
+            ErrorDetail::IncompatibleOptionsVersion { version, supported } => {
+                write!(
+                    f,
+                    "options version {} was saved by a later release (versions up to {} are supported)",
+                    version, supported
+                )
+            }
+            ErrorDetail::ScriptError { function, message } => match function {
+                Some(function) => write!(f, "script error in {}: {}", function, message),
+                None => write!(f, "script error: {}", message),
+            },
+            ErrorDetail::UnknownError => {
+                write!(f, "an unknown error occurred")
+            }
+        }
+    }
+}
+
+/// An enum identifying an operand reported by [ErrorDetail::OperandsOutOfBounds], with its
+/// value.  Most are the operands of the instruction being executed; the remainder are values
+/// supplied through the API or [Options](crate::Options) that would be used as operands.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operand {
+    /// The X register index of an instruction (e.g. `8XY4`)
+    X(usize),
+    /// The Y register index of an instruction (e.g. `8XY4`)
+    Y(usize),
+    /// The nibble operand of an instruction (e.g. the sprite height of `DXYN`, or the rows
+    /// scrolled by `00CN`)
+    N(u8),
+    /// The character whose font sprite was requested (by `FX29` or `FX30`)
+    Character(u8),
+    /// A variable register index passed to the debugging API
+    Register(usize),
+    /// A bitmask of display planes
+    PlaneMask(u8),
+    /// [Options::stack_depth_override](crate::Options::stack_depth_override)
+    StackDepthOverride(usize),
+    /// [Options::sprites_per_frame](crate::Options::sprites_per_frame)
+    SpritesPerFrame(usize),
+    /// [Options::timer_frequency_hertz](crate::Options::timer_frequency_hertz)
+    TimerFrequencyHertz(u64),
+    /// [Options::timing_mode](crate::Options::timing_mode), which gives instructions no time in
+    /// which to execute
+    TimingMode,
+    /// [Options::ghost_trail_decay](crate::Options::ghost_trail_decay)
+    GhostTrailDecay(u8),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::X(x) => write!(f, "x = {:#X}", x),
+            Operand::Y(y) => write!(f, "y = {:#X}", y),
+            Operand::N(n) => write!(f, "n = {:#X}", n),
+            Operand::Character(character) => write!(f, "character = {:#X}", character),
+            Operand::Register(register) => write!(f, "register = {:#X}", register),
+            Operand::PlaneMask(plane_mask) => write!(f, "plane_mask = {:#b}", plane_mask),
+            Operand::StackDepthOverride(depth) => write!(f, "stack_depth_override = {}", depth),
+            Operand::SpritesPerFrame(sprites) => write!(f, "sprites_per_frame = {}", sprites),
+            Operand::TimerFrequencyHertz(frequency) => {
+                write!(f, "timer_frequency_hertz = {}", frequency)
+            }
+            Operand::TimingMode => write!(f, "timing_mode"),
+            Operand::GhostTrailDecay(decay) => write!(f, "ghost_trail_decay = {}", decay),
+        }
+    }
+}
+
+/// An Error enum describing why an [Options](crate::Options) instance is invalid, as returned by
+/// [Options::validate()](crate::Options::validate) and
+/// [OptionsBuilder::build()](crate::OptionsBuilder::build).  Each variant identifies the
+/// offending setting precisely, so that hosts can explain the problem before attempting to
+/// initialise a processor with the options.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum OptionsError {
+    /// The processor speed is zero or exceeds
+    /// [MAX_PROCESSOR_SPEED_HERTZ](crate::MAX_PROCESSOR_SPEED_HERTZ)
+    ProcessorSpeedOutOfRange { processor_speed_hertz: u64 },
+    /// The timer frequency is zero or exceeds
+    /// [MAX_TIMER_FREQUENCY_HERTZ](crate::MAX_TIMER_FREQUENCY_HERTZ)
+    TimerFrequencyOutOfRange { timer_frequency_hertz: u64 },
+    /// The program start address lies outside the memory addressable at the emulation level;
+    /// holds the address and the size of the addressable memory
+    ProgramStartAddressOutOfRange {
+        program_start_address: u16,
+        addressable_bytes: usize,
+    },
+    /// The font data loaded at the font start address would reach the program start address;
+    /// holds the address at which the font data ends and the program start address
+    FontOverlapsProgram {
+        font_end_address: usize,
+        program_start_address: u16,
+    },
+    /// The stack depth override is zero or exceeds [MAX_STACK_DEPTH](crate::MAX_STACK_DEPTH)
+    StackDepthOutOfRange { stack_depth: usize },
+    /// The per-frame sprite budget is zero, under which nothing could ever be drawn
+    ZeroSpritesPerFrame,
+    /// The timing mode gives instructions no time in which to execute
+    ZeroTimingMode,
+    /// The ghost trail decay is zero, under which erased pixels would never fade
+    ZeroGhostTrailDecay,
+    /// A custom font's characters are not the height at which programs draw that font; holds
+    /// the name of the option, the font's character size and that required (in bytes)
+    CustomFontCharSize {
+        option: &'static str,
+        char_size: usize,
+        required_char_size: usize,
+    },
+    /// An option was set which only applies at other emulation levels; holds the name of the
+    /// option and the emulation level in use
+    OptionNotApplicable {
+        option: &'static str,
+        emulation_level: EmulationLevel,
+    },
+}
+
+#[cfg(feature = "std")]
+impl error::Error for OptionsError {}
+
+impl fmt::Display for OptionsError {
+    /// Returns a textual description of each enum variant for display purposes.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OptionsError::ProcessorSpeedOutOfRange {
+                processor_speed_hertz,
+            } => {
+                write!(
+                    f,
+                    "processor speed {}Hz is outside the supported range",
+                    processor_speed_hertz
+                )
+            }
+            OptionsError::TimerFrequencyOutOfRange {
+                timer_frequency_hertz,
+            } => {
+                write!(
+                    f,
+                    "timer frequency {}Hz is outside the supported range",
+                    timer_frequency_hertz
+                )
+            }
+            OptionsError::ProgramStartAddressOutOfRange {
+                program_start_address,
+                addressable_bytes,
+            } => {
+                write!(
+                    f,
+                    "program start address {:#05X} lies outside the {} bytes of addressable memory",
+                    program_start_address, addressable_bytes
+                )
+            }
+            OptionsError::FontOverlapsProgram {
+                font_end_address,
+                program_start_address,
+            } => {
+                write!(
+                    f,
+                    "the font data (ending at {:#05X}) overlaps the program start address {:#05X}",
+                    font_end_address, program_start_address
+                )
+            }
+            OptionsError::StackDepthOutOfRange { stack_depth } => {
+                write!(
+                    f,
+                    "stack depth {} is outside the supported range",
+                    stack_depth
+                )
+            }
+            OptionsError::ZeroSpritesPerFrame => {
+                write!(f, "the per-frame sprite budget must be at least 1")
+            }
+            OptionsError::ZeroTimingMode => {
+                write!(f, "the timing mode must give each instruction some time")
+            }
+            OptionsError::ZeroGhostTrailDecay => {
+                write!(f, "the ghost trail decay must be at least 1")
+            }
+            OptionsError::CustomFontCharSize {
+                option,
+                char_size,
+                required_char_size,
+            } => {
+                write!(
+                    f,
+                    "option {} has {}-byte characters where {}-byte characters are required",
+                    option, char_size, required_char_size
+                )
+            }
+            OptionsError::OptionNotApplicable {
+                option,
+                emulation_level,
+            } => {
+                write!(
+                    f,
+                    "option {} does not apply at emulation level {:?}",
+                    option, emulation_level
+                )
+            }
+        }
+    }
+}
+
+/// The point in execution at which a [ChipolataError] was raised: the instruction most recently
+/// fetched (or being fetched) and the cycle on which this happened.  For errors raised outside
+/// instruction execution, such as an invalid key being reported by the host, this describes the
+/// instruction executed most recently.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ExecutionContext {
+    /// The address from which the opcode was fetched
+    pub program_counter: u16,
+    /// The opcode fetched, or `None` if no instruction has been executed or the fetch itself failed
+    pub opcode: Option<u16>,
+    /// The cycle on which the opcode was fetched (zero if no instruction has been executed)
+    pub cycle: usize,
+}
+
+impl fmt::Display for ExecutionContext {
+    /// Returns a textual description of the execution context
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.opcode {
+            Some(opcode) => write!(
+                f,
+                "opcode {:#06X} at address {:#06X}, cycle {}",
+                opcode, self.program_counter, self.cycle
+            ),
+            None => write!(
+                f,
+                "address {:#06X}, cycle {}",
+                self.program_counter, self.cycle
+            ),
+        }
+    }
+}
+
+/// An Error struct used to bubble up Chipolata errors to the hosting application.  This wraps
+/// the more specific [ErrorDetail] error enum, and provides the execution context and overall
+/// processor state at the point of the failure
+#[derive(Debug, PartialEq)]
+pub struct ChipolataError {
+    pub state_snapshot_dump: StateSnapshot,
+    pub inner_error: ErrorDetail,
+    pub context: ExecutionContext,
+}
+
+#[cfg(feature = "std")]
+impl error::Error for ChipolataError {}
+
+impl fmt::Display for ChipolataError {
+    /// Returns a textual description of the error
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.inner_error, self.context)
+    }
+}
+
+/// The number of memory bytes listed on each row of a crash report
+const CRASH_REPORT_MEMORY_BYTES_PER_ROW: usize = 16;
+
+impl ChipolataError {
+    /// Returns the opcode being executed when the error occurred (or, for errors raised outside
+    /// instruction execution, such as an invalid key being reported by the host, the opcode most
+    /// recently executed).  Returns `None` if no instruction had yet been executed.
+    pub fn offending_opcode(&self) -> Option<u16> {
+        match self.inner_error {
+            ErrorDetail::UnknownInstruction { opcode }
+            | ErrorDetail::UnimplementedInstruction { opcode } => Some(opcode),
+            _ => self.context.opcode,
+        }
+    }
+
+    /// Returns the lowest emulation level at which the opcode that raised
+    /// [ErrorDetail::UnknownInstruction] would have been recognised (for example SUPER-CHIP 1.0
+    /// for 00FF raised in CHIP-8 emulation mode), as per
+    /// [suggest_emulation_level()](crate::suggest_emulation_level), so that hosts can offer to
+    /// restart the program at that level.  Returns None for any other error, or if the opcode is
+    /// recognised at no emulation level.
+    pub fn suggested_emulation_level(&self) -> Option<EmulationLevel> {
+        match (&self.inner_error, &self.state_snapshot_dump) {
+            (
+                ErrorDetail::UnknownInstruction { opcode },
+                StateSnapshot::ExtendedSnapshot {
+                    emulation_level, ..
+                },
+            ) => suggest_emulation_level(*opcode, *emulation_level),
+            _ => None,
+        }
+    }
+
+    /// Returns a diagnostic report of the error as pretty-printed JSON, holding the error
+    /// description, the offending opcode and the full processor state bundled with the error
+    /// (registers, stack, memory and frame buffer), so that the failure can be reproduced
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&CrashReport::from(self)).unwrap_or_default()
+    }
+
+    /// Returns the same diagnostic report as [ChipolataError::to_json()], formatted as
+    /// human-readable text
+    pub fn to_text_report(&self) -> String {
+        CrashReport::from(self).to_string()
+    }
+}
+
+/// A serialisable diagnostic report of a [ChipolataError], as returned by
+/// [ChipolataError::to_json()] and [ChipolataError::to_text_report()].  Addresses, opcodes and
+/// memory are formatted as hexadecimal strings for readability.
+#[derive(Debug, Serialize)]
+struct CrashReport {
+    error: String,
+    opcode: Option<String>,
+    opcode_address: String,
+    opcode_cycle: usize,
+    status: String,
+    cycles: usize,
+    processor_speed: u64,
+    state: Option<CrashReportState>,
+    frame_buffer: Vec<String>,
+}
+
+/// The processor state held within a [CrashReport] (only available if the [ChipolataError]
+/// holds an extended state snapshot)
+#[derive(Debug, Serialize)]
+struct CrashReportState {
+    emulation_level: String,
+    program_counter: String,
+    index_register: String,
+    variable_registers: Vec<String>,
+    rpl_registers: Vec<String>,
+    delay_timer: u8,
+    sound_timer: u8,
+    high_resolution_mode: bool,
+    stack: Vec<String>,
+    memory: Vec<String>,
+}
+
+impl From<&ChipolataError> for CrashReport {
+    /// Builds a [CrashReport] from a [ChipolataError] and the state snapshot it holds
+    fn from(error: &ChipolataError) -> Self {
+        let (frame_buffer, status, processor_speed, cycles, state) =
+            match &error.state_snapshot_dump {
+                StateSnapshot::MinimalSnapshot {
+                    frame_buffer,
+                    status,
+                    processor_speed,
+                    cycles,
+                    ..
+                } => (frame_buffer, status, processor_speed, cycles, None),
+                StateSnapshot::ExtendedSnapshot {
+                    frame_buffer,
+                    status,
+                    processor_speed,
+                    cycles,
+                    stack,
+                    memory,
+                    program_counter,
+                    index_register,
+                    variable_registers,
+                    rpl_registers,
+                    delay_timer,
+                    sound_timer,
+                    high_resolution_mode,
+                    emulation_level,
+                    ..
+                } => (
+                    frame_buffer,
+                    status,
+                    processor_speed,
+                    cycles,
+                    Some(CrashReportState {
+                        emulation_level: format!("{:?}", emulation_level),
+                        program_counter: format!("{:#06X}", program_counter),
+                        index_register: format!("{:#06X}", index_register),
+                        variable_registers: hex_bytes(variable_registers),
+                        rpl_registers: hex_bytes(rpl_registers),
+                        delay_timer: *delay_timer,
+                        sound_timer: *sound_timer,
+                        high_resolution_mode: *high_resolution_mode,
+                        stack: stack
+                            .call_trace()
+                            .iter()
+                            .map(|address| format!("{:#06X}", address))
+                            .collect(),
+                        memory: memory.bytes[..memory.max_addressable_size()]
+                            .chunks(CRASH_REPORT_MEMORY_BYTES_PER_ROW)
+                            .enumerate()
+                            .map(|(row, bytes)| {
+                                format!(
+                                    "{:#06X}: {}",
+                                    row * CRASH_REPORT_MEMORY_BYTES_PER_ROW,
+                                    hex_bytes(bytes).join(" ")
+                                )
+                            })
+                            .collect(),
+                    }),
+                ),
+            };
+        CrashReport {
+            error: error.inner_error.to_string(),
+            opcode: error
+                .offending_opcode()
+                .map(|opcode| format!("{:#06X}", opcode)),
+            opcode_address: format!("{:#06X}", error.context.program_counter),
+            opcode_cycle: error.context.cycle,
+            status: format!("{:?}", status),
+            cycles: *cycles,
+            processor_speed: *processor_speed,
+            state,
+            // One string per display row, with the bytes of each bit plane in turn
+            frame_buffer: (0..frame_buffer.get_column_size_pixels())
+                .map(|y| {
+                    (0..frame_buffer.get_plane_count())
+                        .filter_map(|plane| frame_buffer.get_plane(plane))
+                        .map(|plane_bytes| {
+                            let row_size_bytes: usize = frame_buffer.get_row_size_bytes();
+                            hex_bytes(&plane_bytes[y * row_size_bytes..(y + 1) * row_size_bytes])
+                                .join("")
+                        })
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for CrashReport {
+    /// Formats the report as human-readable text
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Chipolata crash report")?;
+        writeln!(f, "Error: {}", self.error)?;
+        writeln!(
+            f,
+            "Opcode: {}",
+            self.opcode.as_deref().unwrap_or("(none executed)")
+        )?;
+        writeln!(
+            f,
+            "Fetched from: {} on cycle {}",
+            self.opcode_address, self.opcode_cycle
+        )?;
+        writeln!(f, "Status: {}", self.status)?;
+        writeln!(f, "Cycles: {}", self.cycles)?;
+        writeln!(f, "Processor speed: {}hz", self.processor_speed)?;
+        if let Some(state) = &self.state {
+            writeln!(f, "Emulation level: {}", state.emulation_level)?;
+            writeln!(f, "Program counter: {}", state.program_counter)?;
+            writeln!(f, "Index register: {}", state.index_register)?;
+            writeln!(
+                f,
+                "Variable registers: {}",
+                state.variable_registers.join(" ")
+            )?;
+            writeln!(f, "RPL registers: {}", state.rpl_registers.join(" "))?;
+            writeln!(f, "Delay timer: {}", state.delay_timer)?;
+            writeln!(f, "Sound timer: {}", state.sound_timer)?;
+            writeln!(f, "High resolution mode: {}", state.high_resolution_mode)?;
+            writeln!(f, "Stack: {}", state.stack.join(" -> "))?;
+            writeln!(f, "Memory:")?;
+            for row in &state.memory {
+                writeln!(f, "    {}", row)?;
+            }
+        }
+        writeln!(f, "Frame buffer:")?;
+        for row in &self.frame_buffer {
+            writeln!(f, "    {}", row)?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper function that formats each of a slice of bytes as a two-digit hexadecimal string
+///
+/// # Arguments
+///
+/// * `bytes` - the bytes to format
+fn hex_bytes(bytes: &[u8]) -> Vec<String> {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
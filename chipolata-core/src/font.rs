@@ -0,0 +1,452 @@
+use crate::error::ErrorDetail;
+use crate::prelude::*;
+use core::fmt;
+use serde_derive::{Deserialize, Serialize};
+
+/// The size of each character of the default CHIP-8 font in bytes.
+pub(crate) const CHIP8_CHAR_SIZE: usize = 5;
+/// The sprites of the default CHIP-8 font, where each character is one byte wide
+/// and `CHIP8_CHAR_SIZE` bytes tall.  Each bit represents one pixel in the sprite.
+const CHIP8_FONT_DATA: [u8; 80] = [
+    0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
+    0x20, 0x60, 0x20, 0x20, 0x70, // 1
+    0xF0, 0x10, 0xF0, 0x80, 0xF0, // 2
+    0xF0, 0x10, 0xF0, 0x10, 0xF0, // 3
+    0x90, 0x90, 0xF0, 0x10, 0x10, // 4
+    0xF0, 0x80, 0xF0, 0x10, 0xF0, // 5
+    0xF0, 0x80, 0xF0, 0x90, 0xF0, // 6
+    0xF0, 0x10, 0x20, 0x40, 0x40, // 7
+    0xF0, 0x90, 0xF0, 0x90, 0xF0, // 8
+    0xF0, 0x90, 0xF0, 0x10, 0xF0, // 9
+    0xF0, 0x90, 0xF0, 0x90, 0x90, // A
+    0xE0, 0x90, 0xE0, 0x90, 0xE0, // B
+    0xF0, 0x80, 0x80, 0x80, 0xF0, // C
+    0xE0, 0x90, 0x90, 0x90, 0xE0, // D
+    0xF0, 0x80, 0xF0, 0x80, 0xF0, // E
+    0xF0, 0x80, 0xF0, 0x80, 0x80, // F
+];
+/// The size of each character of the default SUPER-CHIP 1.1 font in bytes.
+pub(crate) const SUPERCHIP11_CHAR_SIZE: usize = 10;
+/// The sprites of the default SUPER-CHIP 1.1 font, where each character is one byte wide
+/// and `SUPERCHIP11_CHAR_SIZE` bytes tall.  Each bit represents one pixel in the sprite.
+const SUPERCHIP11_FONT_DATA: [u8; 100] = [
+    0x3C, 0x7E, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0x7E, 0x3C, // 0
+    0x18, 0x38, 0x58, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x3C, // 1
+    0x3E, 0x7F, 0xC3, 0x06, 0x0C, 0x18, 0x30, 0x60, 0xFF, 0xFF, // 2
+    0x3C, 0x7E, 0xC3, 0x03, 0x0E, 0x0E, 0x03, 0xC3, 0x7E, 0x3C, // 3
+    0x06, 0x0E, 0x1E, 0x36, 0x66, 0xC6, 0xFF, 0xFF, 0x06, 0x06, // 4
+    0xFF, 0xFF, 0xC0, 0xC0, 0xFC, 0xFE, 0x03, 0xC3, 0x7E, 0x3C, // 5
+    0x3E, 0x7C, 0xC0, 0xC0, 0xFC, 0xFE, 0xC3, 0xC3, 0x7E, 0x3C, // 6
+    0xFF, 0xFF, 0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x60, 0x60, // 7
+    0x3C, 0x7E, 0xC3, 0xC3, 0x7E, 0x7E, 0xC3, 0xC3, 0x7E, 0x3C, // 8
+    0x3C, 0x7E, 0xC3, 0xC3, 0x7F, 0x3F, 0x03, 0x03, 0x3E, 0x7C, // 9
+];
+/// The sprites of the hexadecimal digits held in the COSMAC VIP monitor ROM, which the original
+/// CHIP-8 interpreter used; several characters (1, 4, 7, B and D) differ in shape from the
+/// default CHIP-8 font.  Each character is one byte wide and `CHIP8_CHAR_SIZE` bytes tall.
+const COSMAC_VIP_FONT_DATA: [u8; 80] = [
+    0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
+    0x60, 0x20, 0x20, 0x20, 0x70, // 1
+    0xF0, 0x10, 0xF0, 0x80, 0xF0, // 2
+    0xF0, 0x10, 0xF0, 0x10, 0xF0, // 3
+    0xA0, 0xA0, 0xF0, 0x20, 0x20, // 4
+    0xF0, 0x80, 0xF0, 0x10, 0xF0, // 5
+    0xF0, 0x80, 0xF0, 0x90, 0xF0, // 6
+    0xF0, 0x10, 0x10, 0x10, 0x10, // 7
+    0xF0, 0x90, 0xF0, 0x90, 0xF0, // 8
+    0xF0, 0x90, 0xF0, 0x10, 0xF0, // 9
+    0xF0, 0x90, 0xF0, 0x90, 0x90, // A
+    0xF0, 0x50, 0x70, 0x50, 0xF0, // B
+    0xF0, 0x80, 0x80, 0x80, 0xF0, // C
+    0xF0, 0x50, 0x50, 0x50, 0xF0, // D
+    0xF0, 0x80, 0xF0, 0x80, 0xF0, // E
+    0xF0, 0x80, 0xF0, 0x80, 0x80, // F
+];
+
+/// The OCTO emulator high-resolution SUPER-CHIP font, which includes characters A-F
+const OCTO_FONT_DATA: [u8; 160] = [
+    0xFF, 0xFF, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xFF, 0xFF, // 0
+    0x18, 0x78, 0x78, 0x18, 0x18, 0x18, 0x18, 0x18, 0xFF, 0xFF, // 1
+    0xFF, 0xFF, 0x03, 0x03, 0xFF, 0xFF, 0xC0, 0xC0, 0xFF, 0xFF, // 2
+    0xFF, 0xFF, 0x03, 0x03, 0xFF, 0xFF, 0x03, 0x03, 0xFF, 0xFF, // 3
+    0xC3, 0xC3, 0xC3, 0xC3, 0xFF, 0xFF, 0x03, 0x03, 0x03, 0x03, // 4
+    0xFF, 0xFF, 0xC0, 0xC0, 0xFF, 0xFF, 0x03, 0x03, 0xFF, 0xFF, // 5
+    0xFF, 0xFF, 0xC0, 0xC0, 0xFF, 0xFF, 0xC3, 0xC3, 0xFF, 0xFF, // 6
+    0xFF, 0xFF, 0x03, 0x03, 0x06, 0x0C, 0x18, 0x18, 0x18, 0x18, // 7
+    0xFF, 0xFF, 0xC3, 0xC3, 0xFF, 0xFF, 0xC3, 0xC3, 0xFF, 0xFF, // 8
+    0xFF, 0xFF, 0xC3, 0xC3, 0xFF, 0xFF, 0x03, 0x03, 0xFF, 0xFF, // 9
+    0x7E, 0xFF, 0xC3, 0xC3, 0xC3, 0xFF, 0xFF, 0xC3, 0xC3, 0xC3, // A
+    0xFC, 0xFC, 0xC3, 0xC3, 0xFC, 0xFC, 0xC3, 0xC3, 0xFC, 0xFC, // B
+    0x3C, 0xFF, 0xC3, 0xC0, 0xC0, 0xC0, 0xC0, 0xC3, 0xFF, 0x3C, // C
+    0xFC, 0xFE, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xC3, 0xFE, 0xFC, // D
+    0xFF, 0xFF, 0xC0, 0xC0, 0xFF, 0xFF, 0xC0, 0xC0, 0xFF, 0xFF, // E
+    0xFF, 0xFF, 0xC0, 0xC0, 0xFF, 0xFF, 0xC0, 0xC0, 0xC0, 0xC0, // F
+];
+
+/// The sprites of the hexadecimal digits of the DREAM 6800 CHIPOS interpreter, which are three
+/// pixels wide.  Each character is one byte wide and `CHIP8_CHAR_SIZE` bytes tall.
+const DREAM6800_FONT_DATA: [u8; 80] = [
+    0xE0, 0xA0, 0xA0, 0xA0, 0xE0, // 0
+    0x40, 0x40, 0x40, 0x40, 0x40, // 1
+    0xE0, 0x20, 0xE0, 0x80, 0xE0, // 2
+    0xE0, 0x20, 0xE0, 0x20, 0xE0, // 3
+    0x80, 0xA0, 0xA0, 0xE0, 0x20, // 4
+    0xE0, 0x80, 0xE0, 0x20, 0xE0, // 5
+    0xE0, 0x80, 0xE0, 0xA0, 0xE0, // 6
+    0xE0, 0x20, 0x20, 0x20, 0x20, // 7
+    0xE0, 0xA0, 0xE0, 0xA0, 0xE0, // 8
+    0xE0, 0xA0, 0xE0, 0x20, 0xE0, // 9
+    0xE0, 0xA0, 0xE0, 0xA0, 0xA0, // A
+    0xC0, 0xA0, 0xE0, 0xA0, 0xC0, // B
+    0xE0, 0x80, 0x80, 0x80, 0xE0, // C
+    0xC0, 0xA0, 0xA0, 0xA0, 0xC0, // D
+    0xE0, 0x80, 0xE0, 0x80, 0xE0, // E
+    0xE0, 0x80, 0xC0, 0x80, 0x80, // F
+];
+/// The sprites of the hexadecimal digits of the ETI-660 interpreter, which are three pixels wide
+/// with lower-case B and D.  Each character is one byte wide and `CHIP8_CHAR_SIZE` bytes tall.
+const ETI660_FONT_DATA: [u8; 80] = [
+    0xE0, 0xA0, 0xA0, 0xA0, 0xE0, // 0
+    0x20, 0x20, 0x20, 0x20, 0x20, // 1
+    0xE0, 0x20, 0xE0, 0x80, 0xE0, // 2
+    0xE0, 0x20, 0xE0, 0x20, 0xE0, // 3
+    0xA0, 0xA0, 0xE0, 0x20, 0x20, // 4
+    0xE0, 0x80, 0xE0, 0x20, 0xE0, // 5
+    0xE0, 0x80, 0xE0, 0xA0, 0xE0, // 6
+    0xE0, 0x20, 0x20, 0x20, 0x20, // 7
+    0xE0, 0xA0, 0xE0, 0xA0, 0xE0, // 8
+    0xE0, 0xA0, 0xE0, 0x20, 0xE0, // 9
+    0xE0, 0xA0, 0xE0, 0xA0, 0xA0, // A
+    0x80, 0x80, 0xE0, 0xA0, 0xE0, // B
+    0xE0, 0x80, 0x80, 0x80, 0xE0, // C
+    0x20, 0x20, 0xE0, 0xA0, 0xE0, // D
+    0xE0, 0x80, 0xE0, 0x80, 0xE0, // E
+    0xE0, 0x80, 0xC0, 0x80, 0x80, // F
+];
+
+/// The greatest number of characters a [CustomFont] may hold (one for each hexadecimal digit)
+pub const CUSTOM_FONT_MAX_CHARS: usize = 16;
+/// The smallest number of characters a [CustomFont] may hold (one for each decimal digit)
+pub const CUSTOM_FONT_MIN_CHARS: usize = 10;
+/// The greatest size of each character of a [CustomFont] in bytes (that of the SUPER-CHIP 1.1
+/// high-resolution font)
+pub const CUSTOM_FONT_MAX_CHAR_SIZE: usize = SUPERCHIP11_CHAR_SIZE;
+
+/// User-provided font data, loaded in place of a built-in font through
+/// [Options::custom_low_res_font](crate::Options::custom_low_res_font) or
+/// [Options::custom_high_res_font](crate::Options::custom_high_res_font).  The font holds
+/// between [CUSTOM_FONT_MIN_CHARS] and [CUSTOM_FONT_MAX_CHARS] characters (the digits 0 to 9,
+/// optionally followed by A to F), each one byte wide and up to [CUSTOM_FONT_MAX_CHAR_SIZE] bytes
+/// tall, stored one after another.  A number of historical fonts are offered by [FontPreset].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "CustomFontData", into = "CustomFontData")]
+pub struct CustomFont {
+    char_size: usize,  // the size of each character in bytes
+    char_count: usize, // the number of characters held
+    data: [u8; CUSTOM_FONT_MAX_CHARS * CUSTOM_FONT_MAX_CHAR_SIZE], // the sprites, of which only the first few bytes are used
+}
+
+/// The form in which a [CustomFont] is serialised
+#[derive(Deserialize, Serialize)]
+struct CustomFontData {
+    char_size: usize,
+    data: Vec<u8>,
+}
+
+impl CustomFont {
+    /// Constructor that returns a [CustomFont] holding the passed sprite data.  Returns
+    /// [ErrorDetail::InvalidFont] unless the character size is between 1 and
+    /// [CUSTOM_FONT_MAX_CHAR_SIZE] bytes, and the data holds a whole number of characters
+    /// between [CUSTOM_FONT_MIN_CHARS] and [CUSTOM_FONT_MAX_CHARS] inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `char_size` - the size of each character in bytes (its height in pixels)
+    /// * `data` - the sprite of each character in turn
+    pub fn new(char_size: usize, data: &[u8]) -> Result<Self, ErrorDetail> {
+        let invalid = || ErrorDetail::InvalidFont {
+            char_size,
+            data_size: data.len(),
+        };
+        if !(1..=CUSTOM_FONT_MAX_CHAR_SIZE).contains(&char_size) || data.len() % char_size != 0 {
+            return Err(invalid());
+        }
+        let char_count: usize = data.len() / char_size;
+        if !(CUSTOM_FONT_MIN_CHARS..=CUSTOM_FONT_MAX_CHARS).contains(&char_count) {
+            return Err(invalid());
+        }
+        let mut font: CustomFont = CustomFont {
+            char_size,
+            char_count,
+            data: [0x0; CUSTOM_FONT_MAX_CHARS * CUSTOM_FONT_MAX_CHAR_SIZE],
+        };
+        font.data[..data.len()].copy_from_slice(data);
+        Ok(font)
+    }
+
+    /// Returns the size of each character in bytes
+    pub fn char_size(&self) -> usize {
+        self.char_size
+    }
+
+    /// Returns the number of characters held
+    pub fn char_count(&self) -> usize {
+        self.char_count
+    }
+
+    /// Returns the sprite data of the characters, one after another
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.char_size * self.char_count]
+    }
+}
+
+impl TryFrom<CustomFontData> for CustomFont {
+    type Error = ErrorDetail;
+
+    /// Converts a font as serialised into a [CustomFont], returning [ErrorDetail::InvalidFont]
+    /// if the font data is not supported
+    fn try_from(font: CustomFontData) -> Result<Self, Self::Error> {
+        CustomFont::new(font.char_size, &font.data)
+    }
+}
+
+impl From<CustomFont> for CustomFontData {
+    /// Converts a [CustomFont] into the form in which it is serialised
+    fn from(font: CustomFont) -> Self {
+        CustomFontData {
+            char_size: font.char_size,
+            data: font.data().to_vec(),
+        }
+    }
+}
+
+/// An enum of the built-in fonts offered as presets for [CustomFont], from the historical
+/// interpreters whose programs may look best with them
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FontPreset {
+    /// The default CHIP-8 font used by most modern interpreters
+    Chip8,
+    /// The hexadecimal digits of the COSMAC VIP monitor ROM
+    CosmacVip,
+    /// The narrow hexadecimal digits of the DREAM 6800
+    Dream6800,
+    /// The narrow hexadecimal digits of the ETI-660
+    Eti660,
+    /// The SUPER-CHIP 1.1 high-resolution font (digits only)
+    SuperChip11,
+    /// The OCTO high-resolution font (digits and A to F)
+    Octo,
+}
+
+impl FontPreset {
+    /// The presets suitable as a low-resolution font (whose characters are
+    /// [CHIP8_CHAR_SIZE] bytes tall)
+    pub const LOW_RESOLUTION: [FontPreset; 4] = [
+        FontPreset::Chip8,
+        FontPreset::CosmacVip,
+        FontPreset::Dream6800,
+        FontPreset::Eti660,
+    ];
+    /// The presets suitable as a high-resolution font (whose characters are
+    /// [SUPERCHIP11_CHAR_SIZE] bytes tall)
+    pub const HIGH_RESOLUTION: [FontPreset; 2] = [FontPreset::SuperChip11, FontPreset::Octo];
+
+    /// Returns the [CustomFont] holding the preset's font data
+    pub fn font(&self) -> CustomFont {
+        let (char_size, data): (usize, &[u8]) = match self {
+            FontPreset::Chip8 => (CHIP8_CHAR_SIZE, &CHIP8_FONT_DATA),
+            FontPreset::CosmacVip => (CHIP8_CHAR_SIZE, &COSMAC_VIP_FONT_DATA),
+            FontPreset::Dream6800 => (CHIP8_CHAR_SIZE, &DREAM6800_FONT_DATA),
+            FontPreset::Eti660 => (CHIP8_CHAR_SIZE, &ETI660_FONT_DATA),
+            FontPreset::SuperChip11 => (SUPERCHIP11_CHAR_SIZE, &SUPERCHIP11_FONT_DATA),
+            FontPreset::Octo => (SUPERCHIP11_CHAR_SIZE, &OCTO_FONT_DATA),
+        };
+        // The built-in font data is always valid
+        CustomFont::new(char_size, data).unwrap()
+    }
+
+    /// Returns the preset whose font is that passed, or None if the font is not one of the
+    /// presets
+    ///
+    /// # Arguments
+    ///
+    /// * `font` - the font to identify
+    pub fn matching(font: &CustomFont) -> Option<FontPreset> {
+        FontPreset::LOW_RESOLUTION
+            .into_iter()
+            .chain(FontPreset::HIGH_RESOLUTION)
+            .find(|preset| preset.font() == *font)
+    }
+}
+
+impl fmt::Display for FontPreset {
+    /// Formatter for [FontPreset], giving the name of the preset as shown to users
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name: &str = match self {
+            FontPreset::Chip8 => "CHIP-8",
+            FontPreset::CosmacVip => "COSMAC VIP",
+            FontPreset::Dream6800 => "DREAM 6800",
+            FontPreset::Eti660 => "ETI-660",
+            FontPreset::SuperChip11 => "SUPER-CHIP 1.1",
+            FontPreset::Octo => "OCTO",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// An abstraction of the Chipolata font (prior to loading to memory).
+pub(crate) struct Font {
+    /// The size of each character in the font in bytes.
+    char_size: usize,
+    /// A vector containing the font sprite data.
+    font_data: Vec<u8>,
+}
+
+impl Font {
+    /// Constructor that returns the default CHIP-8 font data
+    pub fn default_low_resolution() -> Self {
+        Font {
+            char_size: CHIP8_CHAR_SIZE,
+            font_data: Vec::from(CHIP8_FONT_DATA),
+        }
+    }
+
+    /// Constructor that returns the COSMAC VIP hexadecimal digit font data
+    pub fn cosmac_vip_low_resolution() -> Self {
+        Font {
+            char_size: CHIP8_CHAR_SIZE,
+            font_data: Vec::from(COSMAC_VIP_FONT_DATA),
+        }
+    }
+
+    /// Constructor that returns the default SUPER_CHIP 1.1 high-resolution font data
+    pub fn default_high_resolution() -> Self {
+        Font {
+            char_size: SUPERCHIP11_CHAR_SIZE,
+            font_data: Vec::from(SUPERCHIP11_FONT_DATA),
+        }
+    }
+
+    /// Constructor that returns the extended OCTO SUPER_CHIP 1.1 high-resolution font data
+    pub fn octo_high_resolution() -> Self {
+        Font {
+            char_size: SUPERCHIP11_CHAR_SIZE,
+            font_data: Vec::from(OCTO_FONT_DATA),
+        }
+    }
+
+    /// Constructor that returns the font data held by the passed [CustomFont]
+    ///
+    /// # Arguments
+    ///
+    /// * `font` - the user-provided font
+    pub fn custom(font: &CustomFont) -> Self {
+        Font {
+            char_size: font.char_size(),
+            font_data: font.data().to_vec(),
+        }
+    }
+
+    /// Returns a reference to the font data vector.
+    pub(crate) fn font_data(&self) -> &Vec<u8> {
+        &self.font_data
+    }
+
+    /// Returns the length of the font data vector.
+    pub(crate) fn font_data_size(&self) -> usize {
+        self.font_data.len()
+    }
+
+    /// Returns the size of each character in the font in bytes.
+    pub(crate) fn char_size(&self) -> usize {
+        self.char_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_data_low_resolution() {
+        let font: Font = Font::default_low_resolution();
+        assert_eq!(font.font_data()[4], CHIP8_FONT_DATA[4]);
+    }
+
+    #[test]
+    fn test_font_data_cosmac_vip() {
+        let font: Font = Font::cosmac_vip_low_resolution();
+        assert_eq!(font.font_data()[5], COSMAC_VIP_FONT_DATA[5]);
+        assert_eq!(font.char_size(), CHIP8_CHAR_SIZE);
+    }
+
+    #[test]
+    fn test_font_data_high_resolution() {
+        let font: Font = Font::default_high_resolution();
+        assert_eq!(font.font_data()[4], SUPERCHIP11_FONT_DATA[4]);
+    }
+
+    #[test]
+    fn test_font_data_size_low_resolution() {
+        let font: Font = Font::default_low_resolution();
+        assert_eq!(font.font_data_size(), CHIP8_FONT_DATA.len());
+    }
+
+    #[test]
+    fn test_font_data_size_high_resolution() {
+        let font: Font = Font::default_high_resolution();
+        assert_eq!(font.font_data_size(), SUPERCHIP11_FONT_DATA.len());
+    }
+
+    #[test]
+    fn test_char_size_low_resolution() {
+        let font: Font = Font::default_low_resolution();
+        assert_eq!(font.char_size, CHIP8_CHAR_SIZE);
+    }
+
+    #[test]
+    fn test_char_size_high_resolution() {
+        let font: Font = Font::default_high_resolution();
+        assert_eq!(font.char_size, SUPERCHIP11_CHAR_SIZE);
+    }
+
+    #[test]
+    fn test_custom_font_invalid() {
+        for (char_size, data_size) in [(0, 80), (11, 110), (5, 82), (5, 45), (5, 85)] {
+            assert_eq!(
+                CustomFont::new(char_size, &vec![0x0; data_size]).unwrap_err(),
+                ErrorDetail::InvalidFont {
+                    char_size,
+                    data_size
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_font_data() {
+        let font: Font = Font::custom(&FontPreset::Dream6800.font());
+        assert_eq!(font.font_data(), &Vec::from(DREAM6800_FONT_DATA));
+        assert_eq!(font.char_size(), CHIP8_CHAR_SIZE);
+    }
+
+    #[test]
+    fn test_font_presets() {
+        for preset in FontPreset::LOW_RESOLUTION {
+            assert_eq!(preset.font().char_size(), CHIP8_CHAR_SIZE);
+            assert_eq!(FontPreset::matching(&preset.font()), Some(preset));
+        }
+        for preset in FontPreset::HIGH_RESOLUTION {
+            assert_eq!(preset.font().char_size(), SUPERCHIP11_CHAR_SIZE);
+            assert_eq!(FontPreset::matching(&preset.font()), Some(preset));
+        }
+    }
+
+    #[test]
+    fn test_custom_font_serde_round_trip() {
+        let font: CustomFont = FontPreset::Eti660.font();
+        let json: String = serde_json::to_string(&font).unwrap();
+        assert_eq!(serde_json::from_str::<CustomFont>(&json).unwrap(), font);
+        assert!(serde_json::from_str::<CustomFont>(r#"{"char_size":5,"data":[1,2,3]}"#).is_err());
+    }
+}
@@ -0,0 +1,225 @@
+use crate::display::Display;
+use crate::prelude::*;
+
+/// The region of the display that changed between successive frames pushed to a [FrameSink]: the
+/// smallest rectangle (in pixels from the top-left) holding every pixel whose colour index
+/// changed, so that displays updated over slow buses (such as I2C or SPI panels) need only have
+/// that window rewritten
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FrameDamage {
+    /// The column of the leftmost changed pixel
+    pub x: usize,
+    /// The row of the topmost changed pixel
+    pub y: usize,
+    /// The width of the changed region in pixels
+    pub width: usize,
+    /// The height of the changed region in pixels
+    pub height: usize,
+    /// True if the whole display must be redrawn, as for the first frame pushed to a sink or
+    /// after the resolution has changed (in which case the region covers the whole display)
+    pub full_frame: bool,
+}
+
+impl FrameDamage {
+    /// Returns the damage covering the whole of a display of the specified resolution
+    ///
+    /// # Arguments
+    ///
+    /// * `resolution` - the resolution of the display as (width, height)
+    fn full((width, height): (usize, usize)) -> Self {
+        FrameDamage {
+            x: 0,
+            y: 0,
+            width,
+            height,
+            full_frame: true,
+        }
+    }
+
+    /// Returns the damage between two frames of the same resolution, given as the colour index
+    /// of each pixel row by row from the top-left, or None if no pixel changed
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - the colour indices of the earlier frame
+    /// * `current` - the colour indices of the later frame
+    /// * `width` - the width of both frames in pixels
+    fn between(previous: &[u8], current: &[u8], width: usize) -> Option<Self> {
+        let mut changed = previous
+            .iter()
+            .zip(current)
+            .enumerate()
+            .filter(|(_, (before, after))| before != after)
+            .map(|(index, _)| (index % width, index / width));
+        let (first_x, first_y): (usize, usize) = changed.next()?;
+        let (mut left, mut right, mut bottom): (usize, usize, usize) = (first_x, first_x, first_y);
+        for (x, y) in changed {
+            left = left.min(x);
+            right = right.max(x);
+            bottom = y;
+        }
+        Some(FrameDamage {
+            x: left,
+            y: first_y,
+            width: right - left + 1,
+            height: bottom - first_y + 1,
+            full_frame: false,
+        })
+    }
+}
+
+/// An extensibility point allowing hosting applications to have frames pushed to them as they
+/// are completed, rather than polling for state snapshots.
+///
+/// Whenever a frame ends (on the vblank interrupt) with the display changed since the frame last
+/// pushed, the processor passes the frame buffer to the sink registered through
+/// [Processor::set_frame_sink()](crate::Processor::set_frame_sink), along with the
+/// [FrameDamage] describing which part of it changed.  This suits microcontrollers driving small
+/// OLED or LED matrix displays, which can redraw just the damaged window as each frame arrives.
+/// Changes are detected by colour index, so the fading of ghost trails is not reported.
+pub trait FrameSink {
+    /// Called with each completed frame that differs from the frame last pushed
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_buffer` - the display frame buffer at the end of the frame
+    /// * `damage` - the region of the frame buffer that changed
+    fn present_frame(&mut self, frame_buffer: &Display, damage: FrameDamage);
+}
+
+/// The processor's frame output state: the registered [FrameSink] (if any), and the frame last
+/// pushed to it, against which the damage of the next frame is measured
+#[derive(Default)]
+pub(crate) struct FrameSinkState {
+    sink: Option<Box<dyn FrameSink + Send>>,
+    presented: Option<((usize, usize), Vec<u8>)>, // The resolution and colour indices last pushed
+    current: Vec<u8>, // The colour indices of the frame being pushed (reused between frames)
+}
+
+impl FrameSinkState {
+    /// Registers the passed sink, replacing any existing one.  The first frame pushed to the sink
+    /// is reported as wholly damaged
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - the [FrameSink] to which frames are pushed
+    pub(crate) fn set_sink(&mut self, sink: Box<dyn FrameSink + Send>) {
+        self.sink = Some(sink);
+        self.presented = None;
+    }
+
+    /// Removes any registered sink
+    pub(crate) fn clear_sink(&mut self) {
+        self.sink = None;
+        self.presented = None;
+    }
+
+    /// Pushes the passed frame buffer to the registered sink, if any, unless it is unchanged
+    /// since the frame last pushed
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_buffer` - the display frame buffer at the end of the frame
+    pub(crate) fn present(&mut self, frame_buffer: &Display) {
+        if let Some(sink) = &mut self.sink {
+            let resolution: (usize, usize) = frame_buffer.get_resolution();
+            let (width, height): (usize, usize) = resolution;
+            self.current.clear();
+            for row in 0..height {
+                for column in 0..width {
+                    self.current
+                        .push(frame_buffer.get_pixel_colour_index(row, column));
+                }
+            }
+            let damage: Option<FrameDamage> = match &self.presented {
+                Some((previous_resolution, previous)) if *previous_resolution == resolution => {
+                    FrameDamage::between(previous, &self.current, width)
+                }
+                _ => Some(FrameDamage::full(resolution)),
+            };
+            if let Some(damage) = damage {
+                sink.present_frame(frame_buffer, damage);
+                // The pushed frame is kept for comparison, and the buffer it replaces reused
+                let mut previous: Vec<u8> = match self.presented.take() {
+                    Some((_, previous)) => previous,
+                    None => Vec::new(),
+                };
+                core::mem::swap(&mut previous, &mut self.current);
+                self.presented = Some((resolution, previous));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::EmulationLevel;
+    use std::sync::{Arc, Mutex};
+
+    const CHIP8: EmulationLevel = EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    };
+
+    /// A [FrameSink] recording the damage of each frame pushed to it
+    struct RecordingSink {
+        damage: Arc<Mutex<Vec<FrameDamage>>>,
+    }
+
+    impl FrameSink for RecordingSink {
+        fn present_frame(&mut self, _frame_buffer: &Display, damage: FrameDamage) {
+            self.damage.lock().unwrap().push(damage);
+        }
+    }
+
+    #[test]
+    fn test_damage_between_frames() {
+        let damage: Arc<Mutex<Vec<FrameDamage>>> = Arc::new(Mutex::new(Vec::new()));
+        let mut state: FrameSinkState = FrameSinkState::default();
+        state.set_sink(Box::new(RecordingSink {
+            damage: Arc::clone(&damage),
+        }));
+        let mut display: Display = Display::new(CHIP8);
+        state.present(&display);
+        // An unchanged frame is not pushed
+        state.present(&display);
+        display.set_pixel_colour_index(10, 3, 1);
+        display.set_pixel_colour_index(4, 7, 1);
+        state.present(&display);
+        assert_eq!(
+            *damage.lock().unwrap(),
+            vec![
+                FrameDamage {
+                    x: 0,
+                    y: 0,
+                    width: 64,
+                    height: 32,
+                    full_frame: true
+                },
+                FrameDamage {
+                    x: 4,
+                    y: 3,
+                    width: 7,
+                    height: 5,
+                    full_frame: false
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_damage_after_resolution_change() {
+        let damage: Arc<Mutex<Vec<FrameDamage>>> = Arc::new(Mutex::new(Vec::new()));
+        let mut state: FrameSinkState = FrameSinkState::default();
+        state.set_sink(Box::new(RecordingSink {
+            damage: Arc::clone(&damage),
+        }));
+        state.present(&Display::new(CHIP8));
+        state.present(&Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        }));
+        assert!(damage.lock().unwrap()[1] == FrameDamage::full((128, 64)));
+    }
+}
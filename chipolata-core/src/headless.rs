@@ -0,0 +1,383 @@
+use crate::display::Display;
+use crate::error::{ChipolataError, ErrorDetail};
+use crate::options::{Options, CHIP8X_PROGRAM_ADDRESS, DEFAULT_PROGRAM_ADDRESS};
+use crate::prelude::*;
+use crate::processor::{EmulationLevel, Processor, ProcessorStatus, UnknownInstructionPolicy};
+use crate::program::Program;
+
+/// The random seed used by [run_rom_to_completion()] when the supplied [Options] do not specify one
+pub const HEADLESS_DEFAULT_RNG_SEED: u64 = 0;
+/// The number of cycles between checks of whether anything has been drawn to the display during
+/// a [run_self_test()] run
+const SELF_TEST_DISPLAY_SAMPLE_CYCLES: usize = 16;
+/// The emulation levels tried by [run_self_test()], in order of preference should several run
+/// equally well
+pub const SELF_TEST_EMULATION_LEVELS: [EmulationLevel; 7] = [
+    EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    },
+    EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    },
+    EmulationLevel::SuperChip11 {
+        octo_compatibility_mode: false,
+        variable_cycle_timing: false,
+    },
+    EmulationLevel::SuperChip11 {
+        octo_compatibility_mode: true,
+        variable_cycle_timing: false,
+    },
+    EmulationLevel::SuperChip10 {
+        variable_cycle_timing: false,
+    },
+    EmulationLevel::Chip8X {
+        variable_cycle_timing: false,
+    },
+    EmulationLevel::Chip8 {
+        memory_limit_2k: true,
+        variable_cycle_timing: false,
+    },
+];
+
+/// The results of a headless run, as returned by [run_rom_to_completion()].
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadlessRunReport {
+    /// The number of processor cycles (instructions) executed during the run
+    pub cycles_executed: usize,
+    /// The processor status at the end of the run
+    pub status: ProcessorStatus,
+    /// The state of the display at the end of the run
+    pub frame_buffer: Display,
+    /// A hash of the display at the end of the run, as returned by [frame_buffer_hash()]
+    pub frame_buffer_hash: u64,
+}
+
+/// Runs the specified program headlessly (without throttling, host input or a user interface)
+/// until either the cycle budget is exhausted or the program exits (SUPER-CHIP 1.1 emulation
+/// mode only) or halts (if [Options::halt_detection] is set), and returns a [HeadlessRunReport]
+/// describing the final display.  Execution is fully deterministic: the delay and sound timers
+/// and the CHIP-8 vblank interrupt are driven by cycle count rather than wall-clock time, and if
+/// the [Options] do not specify a random seed then [HEADLESS_DEFAULT_RNG_SEED] is used.  This
+/// makes the resulting frame buffer hash suitable for comparison against stored golden values,
+/// for example when regression-testing against well-known test ROMs.
+///
+/// # Arguments
+///
+/// * `program` - a [Program] instance holding the bytes of the ROM to be executed
+/// * `options` - an [Options] instance holding Chipolata start-up configuration information
+/// * `cycle_budget` - the maximum number of cycles to execute
+pub fn run_rom_to_completion(
+    program: Program,
+    mut options: Options,
+    cycle_budget: usize,
+) -> Result<HeadlessRunReport, ChipolataError> {
+    options.rng_seed = Some(options.rng_seed.unwrap_or(HEADLESS_DEFAULT_RNG_SEED));
+    let mut processor: Processor = initialise_headless(program, options)?;
+    let mut cycles_executed: usize = 0;
+    while cycles_executed < cycle_budget {
+        if let ProcessorStatus::Completed | ProcessorStatus::Halted = processor.state_view().status
+        {
+            break;
+        }
+        processor.execute_cycle()?;
+        cycles_executed += 1;
+    }
+    let frame_buffer: Display = processor.state_view().frame_buffer.clone();
+    Ok(HeadlessRunReport {
+        cycles_executed,
+        status: processor.state_view().status,
+        frame_buffer_hash: frame_buffer_hash(&frame_buffer),
+        frame_buffer,
+    })
+}
+
+/// Internal helper function that returns a [Processor] loaded with the specified program and set
+/// to run headlessly, with timers driven by cycle count rather than wall-clock time
+///
+/// # Arguments
+///
+/// * `program` - a [Program] instance holding the bytes of the ROM to be executed
+/// * `options` - an [Options] instance holding Chipolata start-up configuration information
+fn initialise_headless(program: Program, options: Options) -> Result<Processor, ChipolataError> {
+    let mut processor: Processor = Processor::initialise_and_load(program, options)?;
+    processor.set_turbo_mode(true);
+    processor.set_cycle_based_timing(true);
+    Ok(processor)
+}
+
+/// The outcome of running a program under one emulation level, as part of a [SelfTestReport].
+#[derive(Debug, PartialEq)]
+pub struct SelfTestResult {
+    /// The emulation level the program was run under
+    pub emulation_level: EmulationLevel,
+    /// The number of processor cycles (instructions) executed before the run ended
+    pub cycles_executed: usize,
+    /// The processor status at the end of the run
+    pub status: ProcessorStatus,
+    /// The error that ended the run, if the program crashed (or could not be loaded)
+    pub error: Option<ErrorDetail>,
+    /// Whether anything was drawn to the display at any point during the run
+    pub display_drawn: bool,
+}
+
+impl SelfTestResult {
+    /// Returns true if the program ran without crashing, and drew something to the display
+    pub fn is_compatible(&self) -> bool {
+        self.error.is_none() && self.display_drawn
+    }
+}
+
+/// The results of a compatibility self-test, as returned by [run_self_test()].
+#[derive(Debug, PartialEq)]
+pub struct SelfTestReport {
+    /// The outcome under each emulation level tried, ranked from most to least compatible
+    pub results: Vec<SelfTestResult>,
+}
+
+impl SelfTestReport {
+    /// Returns the emulation level under which the program ran best, or None if it crashed or
+    /// drew nothing under every emulation level tried
+    pub fn suggested_emulation_level(&self) -> Option<EmulationLevel> {
+        self.results
+            .first()
+            .filter(|result| result.is_compatible())
+            .map(|result| result.emulation_level)
+    }
+}
+
+/// Runs the specified program headlessly (as per [run_rom_to_completion()]) under each of the
+/// [SELF_TEST_EMULATION_LEVELS] in turn, for up to the specified number of cycles apiece, and
+/// returns a [SelfTestReport] ranking how well it ran under each.  Runs that crash (for example
+/// with [ErrorDetail::UnknownInstruction]) rank below those that do not, and runs that never
+/// draw to the display rank below those that do; otherwise, the longer a crashing run lasts the
+/// higher it ranks, and remaining ties keep the order of [SELF_TEST_EMULATION_LEVELS].  Each run
+/// uses the [Options] passed with the emulation level replaced, the program start address
+/// replaced by the level's conventional one (0x300 for CHIP-8X, and 0x200 otherwise) and unknown
+/// instructions always crashing, so that incompatibility is not masked.  No keys are pressed, so
+/// programs that wait on a title screen for input are judged on the title screen alone.
+///
+/// # Arguments
+///
+/// * `program` - a [Program] instance holding the bytes of the ROM to be tested
+/// * `options` - an [Options] instance holding the other Chipolata configuration to use
+/// * `cycle_budget` - the maximum number of cycles to execute under each emulation level
+pub fn run_self_test(program: &Program, options: Options, cycle_budget: usize) -> SelfTestReport {
+    let mut results: Vec<SelfTestResult> = SELF_TEST_EMULATION_LEVELS
+        .iter()
+        .map(|emulation_level| {
+            let mut options: Options = options;
+            options.emulation_level = *emulation_level;
+            options.program_start_address = match emulation_level {
+                EmulationLevel::Chip8X { .. } => CHIP8X_PROGRAM_ADDRESS,
+                _ => DEFAULT_PROGRAM_ADDRESS,
+            };
+            options.on_unknown_instruction = UnknownInstructionPolicy::Crash;
+            options.rng_seed = Some(options.rng_seed.unwrap_or(HEADLESS_DEFAULT_RNG_SEED));
+            self_test_emulation_level(
+                Program::new(program.program_data().clone()),
+                options,
+                cycle_budget,
+            )
+        })
+        .collect();
+    // A stable sort, so that equally ranked results keep their order of preference
+    results.sort_by_key(|result| {
+        (
+            result.error.is_some(),
+            !result.display_drawn,
+            core::cmp::Reverse(match result.error {
+                Some(_) => result.cycles_executed,
+                None => 0,
+            }),
+        )
+    });
+    SelfTestReport { results }
+}
+
+/// Internal helper function that runs the specified program headlessly for one configuration of
+/// a [run_self_test()] self-test, returning the outcome
+///
+/// # Arguments
+///
+/// * `program` - a [Program] instance holding the bytes of the ROM to be tested
+/// * `options` - an [Options] instance holding the Chipolata configuration to test
+/// * `cycle_budget` - the maximum number of cycles to execute
+fn self_test_emulation_level(
+    program: Program,
+    options: Options,
+    cycle_budget: usize,
+) -> SelfTestResult {
+    let mut result: SelfTestResult = SelfTestResult {
+        emulation_level: options.emulation_level,
+        cycles_executed: 0,
+        status: ProcessorStatus::StartingUp,
+        error: None,
+        display_drawn: false,
+    };
+    let mut processor: Processor = match initialise_headless(program, options) {
+        Ok(processor) => processor,
+        Err(error) => {
+            result.status = ProcessorStatus::Crashed;
+            result.error = Some(error.inner_error);
+            return result;
+        }
+    };
+    while result.cycles_executed < cycle_budget {
+        if let ProcessorStatus::Completed | ProcessorStatus::Halted = processor.state_view().status
+        {
+            break;
+        }
+        if let Err(error) = processor.execute_cycle() {
+            result.error = Some(error.inner_error);
+            break;
+        }
+        result.cycles_executed += 1;
+        if !result.display_drawn && result.cycles_executed % SELF_TEST_DISPLAY_SAMPLE_CYCLES == 0 {
+            result.display_drawn = !is_blank(processor.state_view().frame_buffer);
+        }
+    }
+    if !result.display_drawn {
+        result.display_drawn = !is_blank(processor.state_view().frame_buffer);
+    }
+    result.status = processor.state_view().status;
+    result
+}
+
+/// Internal helper function that returns true if no pixel is set in any plane of the display
+///
+/// # Arguments
+///
+/// * `display` - the display to check
+fn is_blank(display: &Display) -> bool {
+    (0..display.get_plane_count())
+        .filter_map(|plane| display.get_plane(plane))
+        .all(|plane| plane.iter().all(|byte| *byte == 0))
+}
+
+/// Returns a 64-bit FNV-1a hash of the dimensions and pixel colour indices of the specified
+/// display.  Unlike [core::hash::Hash] implementations the result is stable across platforms and
+/// compiler versions, so may be stored as a golden value.
+///
+/// # Arguments
+///
+/// * `display` - the display to hash
+pub fn frame_buffer_hash(display: &Display) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xCBF29CE484222325;
+    const FNV_PRIME: u64 = 0x100000001B3;
+    let dimensions: [u64; 2] = [
+        display.get_row_size_bytes() as u64,
+        display.get_column_size_pixels() as u64,
+    ];
+    dimensions
+        .iter()
+        .flat_map(|dimension| dimension.to_le_bytes())
+        .chain(display.get_colour_indices())
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EmulationLevel;
+
+    fn setup_test_options() -> Options {
+        let mut options: Options = Options::default();
+        options.emulation_level = EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        };
+        options
+    }
+
+    #[test]
+    fn test_run_rom_to_completion() {
+        // 6005 (V0 = 5), F029 (I = font character 5), D115 (draw at 0,0), 1206 (loop forever)
+        let program: Program = Program::new(vec![0x60, 0x05, 0xF0, 0x29, 0xD1, 0x15, 0x12, 0x06]);
+        let report: HeadlessRunReport =
+            run_rom_to_completion(program, setup_test_options(), 50).unwrap();
+        assert!(
+            report.cycles_executed == 50
+                && report.status == ProcessorStatus::Running
+                && report.frame_buffer[0][0] == 0xF0
+                && report.frame_buffer_hash == frame_buffer_hash(&report.frame_buffer)
+        );
+    }
+
+    #[test]
+    fn test_run_rom_to_completion_exit() {
+        // 00FD (exit interpreter)
+        let mut options: Options = Options::default();
+        options.emulation_level = EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        };
+        let report: HeadlessRunReport =
+            run_rom_to_completion(Program::new(vec![0x00, 0xFD]), options, 50).unwrap();
+        assert!(report.cycles_executed == 1 && report.status == ProcessorStatus::Completed);
+    }
+
+    #[test]
+    fn test_run_rom_to_completion_deterministic() {
+        // C00F (V0 = random), F029 (I = font character V0), D115 (draw at 0,0), 1200 (repeat)
+        let program: Vec<u8> = vec![0xC0, 0x0F, 0xF0, 0x29, 0xD1, 0x15, 0x12, 0x00];
+        let first: HeadlessRunReport =
+            run_rom_to_completion(Program::new(program.clone()), setup_test_options(), 400)
+                .unwrap();
+        let second: HeadlessRunReport =
+            run_rom_to_completion(Program::new(program), setup_test_options(), 400).unwrap();
+        assert_eq!(first.frame_buffer_hash, second.frame_buffer_hash);
+    }
+
+    #[test]
+    fn test_run_self_test_ranks_superchip() {
+        // 00FF (high resolution), 6005 (V0 = 5), F029 (I = font character 5), D115 (draw at 0,0),
+        // 1208 (loop forever)
+        let program: Program = Program::new(vec![
+            0x00, 0xFF, 0x60, 0x05, 0xF0, 0x29, 0xD1, 0x15, 0x12, 0x08,
+        ]);
+        let report: SelfTestReport = run_self_test(&program, Options::default(), 100);
+        assert_eq!(report.results.len(), SELF_TEST_EMULATION_LEVELS.len());
+        assert_eq!(
+            report.suggested_emulation_level(),
+            Some(EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false,
+            })
+        );
+        assert!(matches!(
+            report.results.last().unwrap().error,
+            Some(ErrorDetail::UnknownInstruction { opcode: 0x00FF })
+        ));
+    }
+
+    #[test]
+    fn test_run_self_test_blank_display() {
+        // 1200 (loop forever without drawing, except at CHIP-8X level where the program starts
+        // at 0x300 so the jump leaves it)
+        let report: SelfTestReport =
+            run_self_test(&Program::new(vec![0x12, 0x00]), Options::default(), 100);
+        assert!(
+            report.suggested_emulation_level().is_none()
+                && report.results.iter().all(|result| !result.display_drawn)
+                && report.results[0].error.is_none()
+                && report.results[0].cycles_executed == 100
+        );
+    }
+
+    #[test]
+    fn test_frame_buffer_hash_dimensions() {
+        let chip8_display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        let superchip_display: Display = Display::new(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        assert_ne!(
+            frame_buffer_hash(&chip8_display),
+            frame_buffer_hash(&superchip_display)
+        );
+    }
+}
@@ -1,8 +1,303 @@
+use crate::analysis::{
+    is_chip8x_instruction, is_superchip11_instruction, is_superchip_instruction,
+};
 use crate::error::ErrorDetail;
+use crate::prelude::*;
+use crate::processor::{nominal_instruction_cycles, EmulationLevel};
+
+/// The instructions recognised by [Instruction::decode_from()], in the order in which it matches
+/// them: the mnemonic (as returned by [Instruction::name()]), the mask selecting the opcode bits
+/// that identify the instruction, the value those bits must hold, and a description.  The first
+/// entry whose pattern an opcode matches is the instruction it decodes to (the unit tests check
+/// this against the decoder for every possible opcode).
+const INSTRUCTION_PATTERNS: [(&str, u16, u16, &str); 50] = [
+    ("004B", 0xFFFF, 0x004B, "Turn on the COSMAC VIP display"),
+    (
+        "00CN",
+        0xFFF0,
+        0x00C0,
+        "Scroll the display down N pixels (N/2 in low-resolution mode)",
+    ),
+    ("00E0", 0xFFFF, 0x00E0, "Clear the display"),
+    ("00EE", 0xFFFF, 0x00EE, "Return from a subroutine"),
+    (
+        "00FB",
+        0xFFFF,
+        0x00FB,
+        "Scroll the display right 4 pixels (2 in low-resolution mode)",
+    ),
+    (
+        "00FC",
+        0xFFFF,
+        0x00FC,
+        "Scroll the display left 4 pixels (2 in low-resolution mode)",
+    ),
+    ("00FD", 0xFFFF, 0x00FD, "Exit the interpreter"),
+    ("00FE", 0xFFFF, 0x00FE, "Disable high-resolution mode"),
+    ("00FF", 0xFFFF, 0x00FF, "Enable high-resolution mode"),
+    ("02A0", 0xFFFF, 0x02A0, "Cycle the background colour"),
+    (
+        "0NNN",
+        0xF000,
+        0x0000,
+        "Execute the machine language routine at NNN",
+    ),
+    ("1NNN", 0xF000, 0x1000, "Jump to NNN"),
+    ("2NNN", 0xF000, 0x2000, "Call the subroutine at NNN"),
+    (
+        "3XNN",
+        0xF000,
+        0x3000,
+        "Skip the next instruction if VX equals NN",
+    ),
+    (
+        "4XNN",
+        0xF000,
+        0x4000,
+        "Skip the next instruction if VX does not equal NN",
+    ),
+    (
+        "5XY1",
+        0xF00F,
+        0x5001,
+        "Add each colour component of VY to VX",
+    ),
+    (
+        "5XY0",
+        0xF000,
+        0x5000,
+        "Skip the next instruction if VX equals VY",
+    ),
+    ("6XNN", 0xF000, 0x6000, "Set VX to NN"),
+    ("7XNN", 0xF000, 0x7000, "Add NN to VX"),
+    ("8XY0", 0xF00F, 0x8000, "Set VX to VY"),
+    ("8XY1", 0xF00F, 0x8001, "Set VX to VX OR VY"),
+    ("8XY2", 0xF00F, 0x8002, "Set VX to VX AND VY"),
+    ("8XY3", 0xF00F, 0x8003, "Set VX to VX XOR VY"),
+    (
+        "8XY4",
+        0xF00F,
+        0x8004,
+        "Add VY to VX, setting VF to the carry",
+    ),
+    (
+        "8XY5",
+        0xF00F,
+        0x8005,
+        "Subtract VY from VX, setting VF to NOT borrow",
+    ),
+    (
+        "8XY6",
+        0xF00F,
+        0x8006,
+        "Shift right one bit, setting VF to the bit shifted out",
+    ),
+    (
+        "8XY7",
+        0xF00F,
+        0x8007,
+        "Set VX to VY minus VX, setting VF to NOT borrow",
+    ),
+    (
+        "8XYE",
+        0xF00F,
+        0x800E,
+        "Shift left one bit, setting VF to the bit shifted out",
+    ),
+    (
+        "9XY0",
+        0xF000,
+        0x9000,
+        "Skip the next instruction if VX does not equal VY",
+    ),
+    ("ANNN", 0xF000, 0xA000, "Set I to NNN"),
+    ("BNNN", 0xF000, 0xB000, "Jump to NNN plus V0"),
+    ("CXNN", 0xF000, 0xC000, "Set VX to a random number AND NN"),
+    (
+        "DXYN",
+        0xF000,
+        0xD000,
+        "Draw the N-row sprite at I at (VX, VY), setting VF on collision",
+    ),
+    (
+        "EX9E",
+        0xF0FF,
+        0xE09E,
+        "Skip the next instruction if the key in VX is pressed",
+    ),
+    (
+        "EXA1",
+        0xF0FF,
+        0xE0A1,
+        "Skip the next instruction if the key in VX is not pressed",
+    ),
+    (
+        "EXF2",
+        0xF0FF,
+        0xE0F2,
+        "Skip the next instruction if the second keypad key in VX is pressed",
+    ),
+    (
+        "EXF5",
+        0xF0FF,
+        0xE0F5,
+        "Skip the next instruction if the second keypad key in VX is not pressed",
+    ),
+    (
+        "FX07",
+        0xF0FF,
+        0xF007,
+        "Set VX to the value of the delay timer",
+    ),
+    ("FX15", 0xF0FF, 0xF015, "Set the delay timer to VX"),
+    ("FX18", 0xF0FF, 0xF018, "Set the sound timer to VX"),
+    ("FX1E", 0xF0FF, 0xF01E, "Add VX to I"),
+    (
+        "FX0A",
+        0xF0FF,
+        0xF00A,
+        "Wait for a key press and store the key in VX",
+    ),
+    (
+        "FX29",
+        0xF0FF,
+        0xF029,
+        "Set I to the address of the font character in VX",
+    ),
+    (
+        "FX30",
+        0xF0FF,
+        0xF030,
+        "Set I to the address of the high-resolution font character in VX",
+    ),
+    (
+        "FX33",
+        0xF0FF,
+        0xF033,
+        "Store the binary-coded decimal digits of VX at I, I+1 and I+2",
+    ),
+    (
+        "FX55",
+        0xF0FF,
+        0xF055,
+        "Store registers V0 to VX in memory starting at I",
+    ),
+    (
+        "FX65",
+        0xF0FF,
+        0xF065,
+        "Load registers V0 to VX from memory starting at I",
+    ),
+    (
+        "FX75",
+        0xF0FF,
+        0xF075,
+        "Store registers V0 to VX in the RPL user flags",
+    ),
+    (
+        "FX85",
+        0xF0FF,
+        0xF085,
+        "Load registers V0 to VX from the RPL user flags",
+    ),
+    (
+        "FXF8",
+        0xF0FF,
+        0xF0F8,
+        "Output VX to the tone generator, setting its pitch",
+    ),
+];
+
+/// A struct describing one instruction in the instruction set of an emulation level, as returned
+/// by [instruction_set()]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstructionInfo {
+    /// The mnemonic of the instruction, as returned by [Instruction::name()] e.g. "8XY4"
+    pub mnemonic: &'static str,
+    /// The mask selecting the opcode bits that identify the instruction (the remaining bits
+    /// being its operands)
+    pub opcode_mask: u16,
+    /// The value the opcode bits selected by [InstructionInfo::opcode_mask] hold
+    pub opcode_value: u16,
+    /// A short description of what the instruction does at the emulation level
+    pub description: &'static str,
+    /// The nominal number of machine cycles the instruction takes to execute (COSMAC VIP cycles
+    /// for CHIP-8 and CHIP-8X, approximate HP48 cycles for CHIP-48 and SUPER-CHIP 1.1), or None
+    /// where this is not defined.  COSMAC VIP costs are the minimum, as some vary with the
+    /// operands and processor state; HP48 costs are those in low-resolution mode.
+    pub cycles: Option<u64>,
+}
+
+impl InstructionInfo {
+    /// Returns true if the passed opcode falls within this instruction's opcode pattern
+    ///
+    /// # Arguments
+    ///
+    /// * `opcode` - a (big-endian) two-byte representation of the opcode to be checked
+    pub fn matches(&self, opcode: u16) -> bool {
+        opcode & self.opcode_mask == self.opcode_value
+    }
+}
+
+/// Returns the instructions supported at the passed [EmulationLevel], in decoding order, for
+/// use by (for example) documentation panels and disassemblers.  The table is built from the
+/// same patterns the decoder is tested against; the first entry matching an opcode gives the
+/// instruction it decodes to.  02A0 and 5XY1 are only listed at the CHIP-8X level, as elsewhere
+/// they are executed as 0NNN and 5XY0 respectively.
+///
+/// # Arguments
+///
+/// * `emulation_level` - the emulation level whose instruction set is required
+pub fn instruction_set(emulation_level: EmulationLevel) -> Vec<InstructionInfo> {
+    let chip8x: bool = matches!(emulation_level, EmulationLevel::Chip8X { .. });
+    let superchip: bool = matches!(
+        emulation_level,
+        EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. }
+    );
+    let superchip11: bool = matches!(emulation_level, EmulationLevel::SuperChip11 { .. });
+    INSTRUCTION_PATTERNS
+        .iter()
+        .filter_map(|&(mnemonic, opcode_mask, opcode_value, description)| {
+            let instruction: Instruction = Instruction::decode_from(opcode_value).ok()?;
+            let supported: bool = match instruction {
+                Instruction::Op02A0 | Instruction::Op5XY1 { .. } => chip8x,
+                _ if is_superchip11_instruction(&instruction) => superchip11,
+                _ if is_superchip_instruction(&instruction) => superchip,
+                _ if is_chip8x_instruction(&instruction) => chip8x,
+                _ => true,
+            };
+            if !supported {
+                return None;
+            }
+            let description: &'static str = match (instruction, emulation_level) {
+                (Instruction::OpBNNN { .. }, EmulationLevel::Chip8X { .. }) => {
+                    "Set the colour of the display zones given by VX and VX+1 to VY (BXYN)"
+                }
+                (
+                    Instruction::OpBNNN { .. },
+                    EmulationLevel::Chip48 { .. }
+                    | EmulationLevel::SuperChip10 { .. }
+                    | EmulationLevel::SuperChip11 { .. },
+                ) => "Jump to XNN plus VX",
+                _ => description,
+            };
+            Some(InstructionInfo {
+                mnemonic,
+                opcode_mask,
+                opcode_value,
+                description,
+                cycles: nominal_instruction_cycles(&instruction, emulation_level),
+            })
+        })
+        .collect()
+}
 
 /// An enum with a variant for each instruction within the CHIP-8 instruction set.
-#[derive(Debug, PartialEq)]
-pub(crate) enum Instruction {
+///
+/// Decoded instructions are exposed to hosting applications through the callback registered with
+/// [Processor::set_pre_execute_hook()](crate::Processor::set_pre_execute_hook).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Instruction {
     Op004B,                               // Turn on COSMAC VIP display
     Op00CN { n: u8 },                     // [SUPER-CHIP 1.1] Scroll N pixels down (N/2 in low res)
     Op00E0,                               // Clear screen
@@ -12,12 +307,14 @@ pub(crate) enum Instruction {
     Op00FD,                               // [SUPER-CHIP 1.1] Exit the interpreter
     Op00FE,                               // [SUPER-CHIP 1.1] Disable high-resolution mode
     Op00FF,                               // [SUPER-CHIP 1.1] Enable high-resolution mode
+    Op02A0,                               // [CHIP-8X] Cycle the background colour
     Op0NNN { nnn: u16 },                  // Execute machine language routine
     Op1NNN { nnn: u16 },                  // Jump to NNN
     Op2NNN { nnn: u16 },                  // Subroutine (return)
     Op3XNN { x: usize, nn: u8 },          // Skip (if Vx = NN)
     Op4XNN { x: usize, nn: u8 },          // Skip (if Vx != NN)
     Op5XY0 { x: usize, y: usize },        // Skip (if Vx = Vy)
+    Op5XY1 { x: usize, y: usize },        // [CHIP-8X] Add each colour component (Vy to Vx)
     Op6XNN { x: usize, nn: u8 },          // Set register
     Op7XNN { x: usize, nn: u8 },          // Add (NN to Vx)
     Op8XY0 { x: usize, y: usize },        // Set
@@ -31,11 +328,13 @@ pub(crate) enum Instruction {
     Op8XYE { x: usize, y: usize },        // Vx = Vy then shift Vx << 1, set Vf to shifted-out bit
     Op9XY0 { x: usize, y: usize },        // Skip (if Vx != Vy)
     OpANNN { nnn: u16 },                  // Set I = NNN
-    OpBNNN { nnn: u16 },                  // Jump to NNN + V0
+    OpBNNN { nnn: u16 },                  // Jump to NNN + V0 ([CHIP-8X] BXYN: set zone colour)
     OpCXNN { x: usize, nn: u8 },          // Rnd & NN, insert to Vx
     OpDXYN { x: usize, y: usize, n: u8 }, // Draw sprite
     OpEX9E { x: usize },                  // Skip if Vx key is pressed
     OpEXA1 { x: usize },                  // Skip if Vx key is not pressed
+    OpEXF2 { x: usize },                  // [CHIP-8X] Skip if Vx key on second keypad is pressed
+    OpEXF5 { x: usize },                  // [CHIP-8X] Skip if Vx key on second keypad not pressed
     OpFX07 { x: usize },                  // Vx = value of delay timer
     OpFX15 { x: usize },                  // value of delay timer = Vx
     OpFX18 { x: usize },                  // value of sound timer = Vx
@@ -48,6 +347,7 @@ pub(crate) enum Instruction {
     OpFX65 { x: usize },                  // Load V registers from memory
     OpFX75 { x: usize },                  // [SUPER-CHIP 1.1] Store V registers to RPL user flags
     OpFX85 { x: usize },                  // [SUPER-CHIP 1.1] Load V registers from RPL user flags
+    OpFXF8 { x: usize },                  // [CHIP-8X] Output Vx to the tone generator (pitch)
 }
 
 impl Instruction {
@@ -78,6 +378,7 @@ impl Instruction {
             (0x0, 0x0, 0xF, 0xD) => Ok(Instruction::Op00FD),
             (0x0, 0x0, 0xF, 0xE) => Ok(Instruction::Op00FE),
             (0x0, 0x0, 0xF, 0xF) => Ok(Instruction::Op00FF),
+            (0x0, 0x2, 0xA, 0x0) => Ok(Instruction::Op02A0),
             (0x0, ..) => Ok(Instruction::Op0NNN {
                 nnn: opcode & 0x0FFF,
             }),
@@ -95,6 +396,10 @@ impl Instruction {
                 x: second_nibble as usize,
                 nn: (opcode & 0x00ff) as u8,
             }),
+            (0x5, _, _, 0x1) => Ok(Instruction::Op5XY1 {
+                x: second_nibble as usize,
+                y: third_nibble as usize,
+            }),
             (0x5, ..) => Ok(Instruction::Op5XY0 {
                 x: second_nibble as usize,
                 y: third_nibble as usize,
@@ -168,6 +473,12 @@ impl Instruction {
             (0xE, _, 0xA, 0x1) => Ok(Instruction::OpEXA1 {
                 x: second_nibble as usize,
             }),
+            (0xE, _, 0xF, 0x2) => Ok(Instruction::OpEXF2 {
+                x: second_nibble as usize,
+            }),
+            (0xE, _, 0xF, 0x5) => Ok(Instruction::OpEXF5 {
+                x: second_nibble as usize,
+            }),
             (0xF, _, 0x0, 0x7) => Ok(Instruction::OpFX07 {
                 x: second_nibble as usize,
             }),
@@ -204,6 +515,9 @@ impl Instruction {
             (0xF, _, 0x8, 0x5) => Ok(Instruction::OpFX85 {
                 x: second_nibble as usize,
             }),
+            (0xF, _, 0xF, 0x8) => Ok(Instruction::OpFXF8 {
+                x: second_nibble as usize,
+            }),
             // If we have not matched by this point then we cannot identify the
             // instruction; return an Error
             _ => Err(ErrorDetail::UnknownInstruction { opcode }),
@@ -211,8 +525,7 @@ impl Instruction {
     }
 
     /// Returns a textual representation of each enum variant.
-    #[allow(dead_code)]
-    pub(crate) fn name(&self) -> &str {
+    pub fn name(&self) -> &str {
         match self {
             Instruction::Op004B => "004B",
             Instruction::Op00CN { .. } => "00CN",
@@ -223,12 +536,14 @@ impl Instruction {
             Instruction::Op00FD => "00FD",
             Instruction::Op00FE => "00FE",
             Instruction::Op00FF => "00FF",
+            Instruction::Op02A0 => "02A0",
             Instruction::Op0NNN { .. } => "0NNN",
             Instruction::Op1NNN { .. } => "1NNN",
             Instruction::Op2NNN { .. } => "2NNN",
             Instruction::Op3XNN { .. } => "3XNN",
             Instruction::Op4XNN { .. } => "4XNN",
             Instruction::Op5XY0 { .. } => "5XY0",
+            Instruction::Op5XY1 { .. } => "5XY1",
             Instruction::Op6XNN { .. } => "6XNN",
             Instruction::Op7XNN { .. } => "7XNN",
             Instruction::Op8XY0 { .. } => "8XY0",
@@ -247,6 +562,8 @@ impl Instruction {
             Instruction::OpDXYN { .. } => "DXYN",
             Instruction::OpEX9E { .. } => "EX9E",
             Instruction::OpEXA1 { .. } => "EXA1",
+            Instruction::OpEXF2 { .. } => "EXF2",
+            Instruction::OpEXF5 { .. } => "EXF5",
             Instruction::OpFX07 { .. } => "FX07",
             Instruction::OpFX15 { .. } => "FX15",
             Instruction::OpFX18 { .. } => "FX18",
@@ -259,6 +576,7 @@ impl Instruction {
             Instruction::OpFX65 { .. } => "FX65",
             Instruction::OpFX75 { .. } => "FX75",
             Instruction::OpFX85 { .. } => "FX85",
+            Instruction::OpFXF8 { .. } => "FXF8",
         }
     }
 }
@@ -268,6 +586,51 @@ mod tests {
     #![allow(non_snake_case)]
     use super::*;
 
+    #[test]
+    fn test_instruction_patterns_match_decoder() {
+        // Every opcode must decode to the first instruction whose pattern it matches
+        for opcode in 0..=u16::MAX {
+            let pattern = INSTRUCTION_PATTERNS
+                .iter()
+                .find(|&&(_, mask, value, _)| opcode & mask == value);
+            match (Instruction::decode_from(opcode), pattern) {
+                (Ok(instruction), Some(&(mnemonic, ..))) => {
+                    assert_eq!(instruction.name(), mnemonic, "opcode {:04X}", opcode)
+                }
+                (Err(_), None) => (),
+                _ => panic!("decoder and patterns disagree on opcode {:04X}", opcode),
+            }
+        }
+    }
+
+    #[test]
+    fn test_instruction_set_by_emulation_level() {
+        let chip8: Vec<InstructionInfo> = instruction_set(EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        });
+        let chip8x: Vec<InstructionInfo> = instruction_set(EmulationLevel::Chip8X {
+            variable_cycle_timing: false,
+        });
+        let superchip: Vec<InstructionInfo> = instruction_set(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+        let mnemonics = |table: &Vec<InstructionInfo>| -> Vec<&str> {
+            table.iter().map(|info| info.mnemonic).collect()
+        };
+        assert!(!mnemonics(&chip8).contains(&"00FF") && !mnemonics(&chip8).contains(&"5XY1"));
+        assert!(mnemonics(&chip8x).contains(&"5XY1") && mnemonics(&chip8x).contains(&"FXF8"));
+        assert!(
+            mnemonics(&superchip).contains(&"00FF") && !mnemonics(&superchip).contains(&"02A0")
+        );
+        assert_eq!(chip8.len(), 36);
+        assert_eq!(chip8x.len(), 41);
+        assert_eq!(superchip.len(), 45);
+        let draw: &InstructionInfo = chip8.iter().find(|info| info.matches(0xD125)).unwrap();
+        assert_eq!((draw.mnemonic, draw.cycles), ("DXYN", Some(238)));
+    }
+
     #[test]
     fn test_decode_004B() {
         assert_eq!(
@@ -340,6 +703,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_02A0() {
+        assert_eq!(
+            Instruction::decode_from(0x02A0).unwrap(),
+            Instruction::Op02A0
+        );
+    }
+
     #[test]
     fn test_decode_0NNN() {
         assert_eq!(
@@ -388,6 +759,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_5XY1() {
+        assert_eq!(
+            Instruction::decode_from(0x5341).unwrap(),
+            Instruction::Op5XY1 { x: 0x3, y: 0x4 }
+        );
+    }
+
     #[test]
     fn test_decode_6XNN() {
         assert_eq!(
@@ -536,6 +915,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_EXF2() {
+        assert_eq!(
+            Instruction::decode_from(0xE5F2).unwrap(),
+            Instruction::OpEXF2 { x: 0x5 }
+        );
+    }
+
+    #[test]
+    fn test_decode_EXF5() {
+        assert_eq!(
+            Instruction::decode_from(0xE7F5).unwrap(),
+            Instruction::OpEXF5 { x: 0x7 }
+        );
+    }
+
     #[test]
     fn test_decode_FX07() {
         assert_eq!(
@@ -632,6 +1027,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_FXF8() {
+        assert_eq!(
+            Instruction::decode_from(0xF3F8).unwrap(),
+            Instruction::OpFXF8 { x: 0x3 }
+        );
+    }
+
     #[test]
     fn test_decode_unrecognised_opcode() {
         assert_eq!(
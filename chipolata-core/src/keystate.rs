@@ -0,0 +1,305 @@
+#![allow(non_snake_case)]
+
+use crate::error::ErrorDetail;
+use crate::prelude::*;
+use core::fmt;
+use core::str::FromStr;
+use serde_derive::{Deserialize, Serialize};
+
+/// The default number of keys in the CHIP-8 keypad.
+const NUMBER_OF_KEYS: u8 = 16;
+
+/// A key on the CHIP-8 hex keypad.  Using this type, rather than a raw hex ordinal, rules out
+/// invalid keys at compile time; conversions to and from `u8` are provided for hosts holding
+/// ordinals (converting from `u8` fails with [ErrorDetail::InvalidKey] for values above 0xF),
+/// and keys can be parsed from their names (such as `"A"` or `"0xA"`) so that they can be
+/// written readably in configuration files.  Keys are serialised as their ordinals.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum Key {
+    Key0 = 0x0,
+    Key1 = 0x1,
+    Key2 = 0x2,
+    Key3 = 0x3,
+    Key4 = 0x4,
+    Key5 = 0x5,
+    Key6 = 0x6,
+    Key7 = 0x7,
+    Key8 = 0x8,
+    Key9 = 0x9,
+    KeyA = 0xA,
+    KeyB = 0xB,
+    KeyC = 0xC,
+    KeyD = 0xD,
+    KeyE = 0xE,
+    KeyF = 0xF,
+}
+
+impl Key {
+    /// Every key on the keypad, in order of ordinal (so that a key's ordinal indexes it)
+    pub const ALL: [Key; NUMBER_OF_KEYS as usize] = [
+        Key::Key0,
+        Key::Key1,
+        Key::Key2,
+        Key::Key3,
+        Key::Key4,
+        Key::Key5,
+        Key::Key6,
+        Key::Key7,
+        Key::Key8,
+        Key::Key9,
+        Key::KeyA,
+        Key::KeyB,
+        Key::KeyC,
+        Key::KeyD,
+        Key::KeyE,
+        Key::KeyF,
+    ];
+}
+
+impl From<Key> for u8 {
+    /// Returns the hex ordinal of the key
+    fn from(key: Key) -> Self {
+        key as u8
+    }
+}
+
+impl TryFrom<u8> for Key {
+    type Error = ErrorDetail;
+
+    /// Returns the key with the specified hex ordinal, or [ErrorDetail::InvalidKey] if the
+    /// ordinal is above 0xF
+    fn try_from(key: u8) -> Result<Self, Self::Error> {
+        match Key::ALL.get(key as usize) {
+            Some(key) => Ok(*key),
+            None => Err(ErrorDetail::InvalidKey { key }),
+        }
+    }
+}
+
+impl FromStr for Key {
+    type Err = ErrorDetail;
+
+    /// Parses a key from its hex digit (in either case), optionally prefixed by `0x`, returning
+    /// [ErrorDetail::InvalidKeyName] if the string names no key
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let trimmed: &str = name.trim();
+        let digit: &str = match trimmed.get(..2) {
+            Some("0x" | "0X") => &trimmed[2..],
+            _ => trimmed,
+        };
+        match (digit.len(), u8::from_str_radix(digit, 16)) {
+            (1, Ok(key)) => Key::try_from(key),
+            _ => Err(ErrorDetail::InvalidKeyName {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    /// Formats the key as its hex digit, as accepted by [Key::from_str()]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:X}", u8::from(*self))
+    }
+}
+
+/// A single key press or release event supplied by the host as part of a batch (see
+/// [Processor::apply_input_events()](crate::Processor::apply_input_events)).
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KeyEvent {
+    /// The key pressed or released
+    pub key: Key,
+    /// The new state of the key (true means pressed)
+    pub pressed: bool,
+    /// The number of processor cycles, counted from when the batch is applied, after which
+    /// the event takes effect; `None` (or zero) applies the event immediately.
+    pub cycle_offset: Option<usize>,
+}
+
+/// An abstraction of the state of each key on the CHIP-8 keypad
+/// (pressed / not pressed).
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub(crate) struct KeyState {
+    /// Array holding a boolean for each key (true means pressed, false means not pressed).
+    keys_pressed: [bool; NUMBER_OF_KEYS as usize],
+}
+
+impl KeyState {
+    /// Constructor that returns a [KeyState] instance with no keys pressed.
+    pub(crate) fn new() -> Self {
+        KeyState {
+            keys_pressed: [false; NUMBER_OF_KEYS as usize],
+        }
+    }
+
+    /// Returns true if the specified key is pressed, false if the specified key is not
+    /// pressed, and returns an [ErrorDetail::InvalidKey](crate::error::ErrorDetail::InvalidKey) if
+    /// the specified key is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the hex ordinal of the key (valid range 0x0 to 0xF inclusive)
+    pub(crate) fn is_key_pressed(&self, key: u8) -> Result<bool, ErrorDetail> {
+        match key {
+            n if n < NUMBER_OF_KEYS => Ok(self.keys_pressed[n as usize]),
+            _ => Err(ErrorDetail::InvalidKey { key }),
+        }
+    }
+
+    /// Sets the state of the specified key; returns an [ErrorDetail::InvalidKey] if the
+    /// specified key is invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the hex ordinal of the key (valid range 0x0 to 0xF inclusive)
+    /// * `status` - boolean representing key state (true meaning pressed)
+    pub(crate) fn set_key_status(&mut self, key: u8, status: bool) -> Result<(), ErrorDetail> {
+        match key {
+            n if n < NUMBER_OF_KEYS => Ok(self.keys_pressed[n as usize] = status),
+            _ => Err(ErrorDetail::InvalidKey { key }),
+        }
+    }
+
+    /// Sets the state of the specified key (which, unlike a hex ordinal, is always valid)
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the key
+    /// * `status` - boolean representing key state (true meaning pressed)
+    pub(crate) fn set_key(&mut self, key: Key, status: bool) {
+        self.keys_pressed[u8::from(key) as usize] = status;
+    }
+
+    /// Returns a byte vector holding the hex ordinals of all keys currently pressed.
+    pub(crate) fn get_keys_pressed(&self) -> Option<Vec<u8>> {
+        let mut keys: Vec<u8> = Vec::new();
+        // Iterate through each key, adding to the output vector if pressed
+        for i in 0..NUMBER_OF_KEYS {
+            if self.is_key_pressed(i).unwrap() {
+                keys.push(i);
+            }
+        }
+        // If the output vector contains at least one key then return it, otherwise `None`
+        if keys.len() > 0 {
+            return Some(keys);
+        } else {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_key_pressed_yes() {
+        let mut keys: KeyState = KeyState::new();
+        keys.keys_pressed[0x2] = true;
+        assert!(keys.is_key_pressed(0x2).unwrap());
+    }
+
+    #[test]
+    fn test_is_key_pressed_no() {
+        let mut keys: KeyState = KeyState::new();
+        keys.keys_pressed[0x2] = false;
+        assert!(!keys.is_key_pressed(0x2).unwrap());
+    }
+
+    #[test]
+    fn test_is_key_pressed_error() {
+        let keys: KeyState = KeyState::new();
+        assert_eq!(
+            keys.is_key_pressed(NUMBER_OF_KEYS).unwrap_err(),
+            ErrorDetail::InvalidKey {
+                key: NUMBER_OF_KEYS
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_key_status() {
+        let mut keys: KeyState = KeyState::new();
+        keys.set_key_status(0x2, true).unwrap();
+        assert!(keys.keys_pressed[0x2] == true);
+    }
+
+    #[test]
+    fn test_set_key_status_error() {
+        let mut keys: KeyState = KeyState::new();
+        assert_eq!(
+            keys.set_key_status(NUMBER_OF_KEYS, true).unwrap_err(),
+            ErrorDetail::InvalidKey {
+                key: NUMBER_OF_KEYS
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_key() {
+        let mut keys: KeyState = KeyState::new();
+        keys.set_key(Key::KeyE, true);
+        assert!(keys.keys_pressed[0xE]);
+    }
+
+    #[test]
+    fn test_key_from_u8() {
+        assert!(
+            Key::try_from(0xB).unwrap() == Key::KeyB
+                && u8::from(Key::KeyB) == 0xB
+                && Key::try_from(NUMBER_OF_KEYS).unwrap_err()
+                    == ErrorDetail::InvalidKey {
+                        key: NUMBER_OF_KEYS
+                    }
+        );
+    }
+
+    #[test]
+    fn test_key_from_str() {
+        assert!(
+            "A".parse::<Key>().unwrap() == Key::KeyA
+                && "f".parse::<Key>().unwrap() == Key::KeyF
+                && "0x7".parse::<Key>().unwrap() == Key::Key7
+                && " 0XC ".parse::<Key>().unwrap() == Key::KeyC
+                && Key::KeyD.to_string().parse::<Key>().unwrap() == Key::KeyD
+        );
+    }
+
+    #[test]
+    fn test_key_from_str_error() {
+        for name in ["G", "10", "0x", "", "+1"] {
+            assert_eq!(
+                name.parse::<Key>().unwrap_err(),
+                ErrorDetail::InvalidKeyName {
+                    name: name.to_string()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_key_serde() {
+        assert!(
+            serde_json::to_string(&Key::KeyA).unwrap() == "10"
+                && serde_json::from_str::<Key>("10").unwrap() == Key::KeyA
+                && serde_json::from_str::<Key>("16").is_err()
+        );
+    }
+
+    #[test]
+    fn test_get_keys_pressed() {
+        let mut keys: KeyState = KeyState::new();
+        keys.keys_pressed[0x2] = true;
+        keys.keys_pressed[0x7] = true;
+        keys.keys_pressed[0xF] = true;
+        let key_vector: Vec<u8> = keys.get_keys_pressed().unwrap();
+        assert_eq!(key_vector, vec![0x2, 0x7, 0xF]);
+    }
+
+    #[test]
+    fn test_get_keys_pressed_none() {
+        let keys: KeyState = KeyState::new();
+        assert!(keys.get_keys_pressed().is_none());
+    }
+}
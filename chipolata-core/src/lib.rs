@@ -0,0 +1,100 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod analysis;
+#[cfg(feature = "async")]
+mod async_processor;
+mod benchmark;
+#[cfg(feature = "builtin-roms")]
+pub mod builtin_roms;
+mod clock;
+mod display;
+mod error;
+mod font;
+mod frame_sink;
+mod headless;
+mod instruction;
+mod keystate;
+mod memory;
+mod options;
+mod osd;
+mod palette;
+mod prelude;
+mod processor;
+mod program;
+#[cfg(feature = "std")]
+mod recorder;
+mod recording;
+#[cfg(feature = "remote")]
+mod remote;
+#[cfg(feature = "scripting")]
+mod scripting;
+mod snapshot_diff;
+mod sound;
+mod stack;
+mod system_routine;
+pub mod testing;
+mod timing_stats;
+mod watch;
+
+// Re-exports
+pub use crate::analysis::{
+    check_compatibility, suggest_emulation_level, CompatibilityReport, IncompatibleInstruction,
+};
+#[cfg(feature = "async")]
+pub use crate::async_processor::{
+    AsyncProcessorCommand, AsyncProcessorEvent, AsyncProcessorHandle,
+};
+pub use crate::benchmark::BenchmarkReport;
+#[cfg(feature = "std")]
+pub use crate::clock::SystemClock;
+pub use crate::clock::{Clock, VirtualClock};
+pub use crate::display::{
+    decode_delta, encode_delta, Display, DisplayOrientation, SpriteEdgeMode,
+    CHIP8X_ZONE_WIDTH_PIXELS, DISPLAY_PLANE_COUNT, FULL_INTENSITY,
+};
+pub use crate::error::{ChipolataError, ErrorDetail, ExecutionContext, Operand, OptionsError};
+pub use crate::font::{
+    CustomFont, FontPreset, CUSTOM_FONT_MAX_CHARS, CUSTOM_FONT_MAX_CHAR_SIZE, CUSTOM_FONT_MIN_CHARS,
+};
+pub use crate::frame_sink::{FrameDamage, FrameSink};
+pub use crate::headless::{
+    frame_buffer_hash, run_rom_to_completion, run_self_test, HeadlessRunReport, SelfTestReport,
+    SelfTestResult, HEADLESS_DEFAULT_RNG_SEED, SELF_TEST_EMULATION_LEVELS,
+};
+pub use crate::instruction::{instruction_set, Instruction, InstructionInfo};
+pub use crate::keystate::{Key, KeyEvent};
+pub use crate::memory::{Memory, MemoryProfile, MemoryRegion};
+pub use crate::options::{Options, OptionsBuilder};
+pub use crate::options::{
+    COSMAC_VIP_PROCESSOR_SPEED_HERTZ, DEFAULT_TIMER_FREQUENCY_HERTZ, HP48_PROCESSOR_SPEED_HERTZ,
+    MAX_PROCESSOR_SPEED_HERTZ, MAX_TIMER_FREQUENCY_HERTZ, OPTIONS_VERSION,
+};
+pub use crate::osd::{Overlay, OverlayPixel, OSD_GLYPH_SIZE_PIXELS};
+pub use crate::palette::{Palette, PaletteTheme, PALETTE_MAX_COLOURS};
+pub use crate::processor::{
+    CrashDump, EmulationLevel, FuzzOutcome, KeyWaitMode, PreExecuteHook, Processor,
+    ProcessorStatus, SaveState, StateSnapshot, StateSnapshotVerbosity, StateView, TimingMode,
+    UnknownInstructionPolicy, CRASH_DUMP_OPTIONS_FILE, CRASH_DUMP_PROGRAM_FILE,
+    CRASH_DUMP_REPORT_FILE, CRASH_DUMP_TRACE_FILE, CRASH_DUMP_TRACE_LENGTH,
+};
+pub use crate::program::{Program, ProgramAnalysis, ProgramFormat, PROGRAM_FILE_EXTENSIONS};
+#[cfg(feature = "std")]
+pub use crate::recorder::{GifRecorder, RECORDER_DEFAULT_PALETTE};
+pub use crate::recording::{InputRecording, RecordedKeyEvent};
+#[cfg(feature = "remote")]
+pub use crate::remote::{
+    RemoteDisplayClient, RemoteDisplayServer, RemoteMessage, RemoteServeOutcome, MESSAGE_ERROR,
+    MESSAGE_FRAME, MESSAGE_KEY_EVENT, REMOTE_FRAME_INTERVAL_MICROSECONDS,
+};
+#[cfg(feature = "scripting")]
+pub use crate::scripting::{ScriptHost, SCRIPT_MAX_OPERATIONS};
+pub use crate::snapshot_diff::SnapshotDiff;
+pub use crate::sound::{AudioCallback, SoundEvent, SoundSink};
+pub use crate::stack::{CallTreeNode, Stack, StackEvent, MAX_STACK_DEPTH, STACK_EVENT_CAPACITY};
+pub use crate::system_routine::{
+    SystemRoutineContext, SystemRoutineHandler, DEFAULT_SYSTEM_ROUTINE_CYCLES,
+};
+pub use crate::timing_stats::TimingStatistics;
+pub use crate::watch::{DebugReport, WatchExpression, WatchResult};
@@ -0,0 +1,926 @@
+use crate::prelude::*;
+use crate::{EmulationLevel, ErrorDetail};
+use core::ops::Range;
+use rand::Rng;
+use serde_derive::{Deserialize, Serialize};
+
+/// The default memory size for all system variants (in bytes).
+pub(crate) const CHIPOLATA_MEMORY_SIZE_BYTES: usize = 0x1000;
+// The COSMAC VIP had either 2048 bytes or 4096 bytes of RAM; we allow this to be configured.
+// From this, the last 352 bytes are reserved
+const CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES: usize = 0x6A0;
+const CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES: usize = 0xEA0;
+// For CHIP-48 the full 4096 bytes are addressable
+const CHIP48_ADDRESSABLE_MEMORY_BYTES: usize = 0x1000;
+// For SUPER-CHIP 1.1 the final byte is reserved (presumably by mistake), so 4095 are addressable
+const SUPERCHIP11_ADDRESSABLE_MEMORY_BYTES: usize = 0xFFF;
+
+/// The regions into which the addressable memory space is divided, as returned by
+/// [Memory::region()].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum MemoryRegion {
+    /// Memory below the program start address reserved for the interpreter, other than the font
+    Interpreter,
+    /// The font data (including the high-resolution font, if loaded)
+    Font,
+    /// The program loaded into memory
+    Program,
+    /// Addressable memory beyond the end of the program, free for the program's own use
+    Free,
+}
+
+/// An abstraction of the CHIP-8 memory space.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Memory {
+    /// A stack-allocated array of bytes representing the entire CHIP-8 memory space
+    #[serde(with = "memory_bytes")]
+    pub bytes: [u8; CHIPOLATA_MEMORY_SIZE_BYTES],
+    /// The number of addressable memory slots
+    address_limit: usize,
+    /// The addresses holding the font data
+    font_range: Range<usize>,
+    /// The addresses holding the loaded program (all memory below is reserved)
+    program_range: Range<usize>,
+    /// Whether writes to the reserved region (below the program) are refused
+    reserved_write_protected: bool,
+}
+
+/// Serialisation of the memory bytes as a sequence, as serde does not derive implementations for
+/// arrays of this size
+mod memory_bytes {
+    use super::CHIPOLATA_MEMORY_SIZE_BYTES;
+    use crate::prelude::*;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        bytes: &[u8; CHIPOLATA_MEMORY_SIZE_BYTES],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(bytes.iter())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; CHIPOLATA_MEMORY_SIZE_BYTES], D::Error> {
+        let bytes: Vec<u8> = Vec::deserialize(deserializer)?;
+        bytes
+            .try_into()
+            .map_err(|bytes: Vec<u8>| D::Error::invalid_length(bytes.len(), &"4096 bytes"))
+    }
+}
+
+impl Memory {
+    /// Constructor that returns a [Memory] instance initialised with all bytes 0x00.  If
+    /// the emulation level is [EmulationLevel::SuperChip10] or [EmulationLevel::SuperChip11] then
+    /// the memory will instead
+    /// be randomised on startup, mirroring original behaviour.
+    ///
+    /// The addressable memory space will be (soft) limited depending on emulation level.
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the CHIP-8 variant to be emulated (impacts addressable memory)
+    /// * `rng` - the random number generator used to randomise memory, where applicable
+    pub(crate) fn new(emulation_level: EmulationLevel, rng: &mut impl Rng) -> Self {
+        let mut bytes: [u8; CHIPOLATA_MEMORY_SIZE_BYTES] = [0x0; CHIPOLATA_MEMORY_SIZE_BYTES];
+        // For SUPER-CHIP emulation, assign each memory slot a random byte value
+        if let EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } =
+            emulation_level
+        {
+            rng.fill(&mut bytes[..]);
+        }
+        Self {
+            bytes,
+            address_limit: Memory::addressable_bytes(emulation_level),
+            font_range: 0..0,
+            program_range: 0..0,
+            reserved_write_protected: false,
+        }
+    }
+
+    /// Returns the number of memory bytes addressable at the specified emulation level
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the CHIP-8 variant to be emulated
+    pub(crate) fn addressable_bytes(emulation_level: EmulationLevel) -> usize {
+        match emulation_level {
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: _,
+            } => CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES,
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+                CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES
+            }
+            EmulationLevel::Chip48 { .. } => CHIP48_ADDRESSABLE_MEMORY_BYTES,
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
+                SUPERCHIP11_ADDRESSABLE_MEMORY_BYTES
+            }
+        }
+    }
+
+    /// Records where the font and program have been loaded, from which the memory regions are
+    /// determined.  Until this is called, all addressable memory is deemed free.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_range` - the addresses holding the font data
+    /// * `program_range` - the addresses holding the loaded program
+    pub(crate) fn set_layout(&mut self, font_range: Range<usize>, program_range: Range<usize>) {
+        self.font_range = font_range;
+        self.program_range = program_range;
+    }
+
+    /// Enables or disables write protection of the reserved region (all memory below the
+    /// program, including the font).  While enabled, any write to the region made through
+    /// [Memory::write_byte()], [Memory::write_bytes()] or [Memory::checked_slice_mut()] fails
+    /// with [ErrorDetail::ReservedMemoryWrite].
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to refuse writes to the reserved region
+    pub(crate) fn set_reserved_write_protection(&mut self, enabled: bool) {
+        self.reserved_write_protected = enabled;
+    }
+
+    /// Returns the [MemoryRegion] in which the specified address falls, or None if the address
+    /// is outside the addressable range
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address of interest
+    pub fn region(&self, address: usize) -> Option<MemoryRegion> {
+        if address >= self.address_limit {
+            None
+        } else if self.font_range.contains(&address) {
+            Some(MemoryRegion::Font)
+        } else if address < self.program_range.start {
+            Some(MemoryRegion::Interpreter)
+        } else if self.program_range.contains(&address) {
+            Some(MemoryRegion::Program)
+        } else {
+            Some(MemoryRegion::Free)
+        }
+    }
+
+    /// Returns an array slice of the specified range of memory.  If the range extends beyond
+    /// addressable memory (or ends before it starts) then returns
+    /// [ErrorDetail::MemoryAddressOutOfBounds].
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - the range of memory addresses to read
+    pub fn read_range(&self, range: Range<usize>) -> Result<&[u8], ErrorDetail> {
+        self.check_range(&range)?;
+        Ok(&self.bytes[range])
+    }
+
+    /// Returns a mutable array slice of the specified range of memory.  If the range extends
+    /// beyond addressable memory (or ends before it starts) then returns
+    /// [ErrorDetail::MemoryAddressOutOfBounds], and if it overlaps the reserved region while this
+    /// is write-protected then returns [ErrorDetail::ReservedMemoryWrite].
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - the range of memory addresses to be written
+    pub(crate) fn checked_slice_mut(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<&mut [u8], ErrorDetail> {
+        self.check_range(&range)?;
+        if self.reserved_write_protected
+            && !range.is_empty()
+            && range.start < self.program_range.start
+        {
+            return Err(ErrorDetail::ReservedMemoryWrite {
+                address: range.start as u16,
+            });
+        }
+        Ok(&mut self.bytes[range])
+    }
+
+    /// Writes a byte to the specified memory address regardless of any write protection, as the
+    /// interpreter itself or a debugger may.  If the address is outside the addressable range,
+    /// returns [ErrorDetail::MemoryAddressOutOfBounds].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address at which the byte should be written
+    /// * `value` - the byte value to be written
+    pub(crate) fn write_byte_privileged(
+        &mut self,
+        address: usize,
+        value: u8,
+    ) -> Result<(), ErrorDetail> {
+        self.check_range(&(address..address + 1))?;
+        self.bytes[address] = value;
+        Ok(())
+    }
+
+    /// Helper function that returns [ErrorDetail::MemoryAddressOutOfBounds] if the specified
+    /// range extends beyond addressable memory or ends before it starts
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - the range of memory addresses to check
+    fn check_range(&self, range: &Range<usize>) -> Result<(), ErrorDetail> {
+        if range.end < range.start {
+            return Err(ErrorDetail::MemoryAddressOutOfBounds {
+                address: range.start as u16,
+            });
+        }
+        // Checking the final address in the range reports the first unaddressable address read
+        // by a multi-byte read, as before; an empty range is always valid
+        if !range.is_empty() && range.end > self.address_limit {
+            return Err(ErrorDetail::MemoryAddressOutOfBounds {
+                address: (range.end - 1) as u16,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns a copy of the byte in memory at the specified address.  If the address
+    /// is outside the addressable range, returns
+    /// [ErrorDetail::MemoryAddressOutOfBounds](crate::error::ErrorDetail::MemoryAddressOutOfBounds).
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address at which the byte should be read
+    pub fn read_byte(&self, address: usize) -> Result<u8, ErrorDetail> {
+        if address >= self.address_limit {
+            return Err(ErrorDetail::MemoryAddressOutOfBounds {
+                address: address as u16,
+            });
+        }
+        Ok(self.bytes[address])
+    }
+
+    /// Writes the passed byte to the specified memory address.  If the address is
+    /// outside the addressable range, returns
+    /// [ErrorDetail::MemoryAddressOutOfBounds], and if it is in the reserved region while this
+    /// is write-protected, returns [ErrorDetail::ReservedMemoryWrite].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address at which the byte should be written
+    /// * `value` - the byte value to be written
+    pub(crate) fn write_byte(&mut self, address: usize, value: u8) -> Result<(), ErrorDetail> {
+        self.checked_slice_mut(address..address + 1)?[0] = value;
+        Ok(())
+    }
+
+    /// Returns an array slice from memory as per the specified start address and
+    /// number of bytes.  If the operands are such that the array slice would extend beyond
+    /// addressable memory then returns [ErrorDetail::MemoryAddressOutOfBounds].
+    ///
+    /// # Arguments
+    ///
+    /// * `start_address` - the memory address at the start of the range from which to read
+    /// * `num_bytes` - the number of bytes to read from memory
+    pub fn read_bytes(&self, start_address: usize, num_bytes: usize) -> Result<&[u8], ErrorDetail> {
+        // Reading no bytes always succeeds, wherever the start address
+        if num_bytes == 0 {
+            return Ok(&[]);
+        }
+        self.read_range(start_address..start_address + num_bytes)
+    }
+
+    /// Returns a 16-bit unsigned integer constructed by reading two consecutive bytes from memory
+    /// starting from the specified address.  The construction is big-endian.  In the unlikely
+    /// event that the second byte would fall outside the addressable memory space, this returns
+    /// [ErrorDetail::MemoryAddressOutOfBounds].
+    ///
+    /// The method is generally used as a convenience for reading opcodes from memory, as
+    /// CHIP-8 opcodes are 16-bits in size.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_address` - the memory address of the first (most significant) byte to read
+    pub fn read_two_bytes(&self, start_address: usize) -> Result<u16, ErrorDetail> {
+        if start_address + 1 >= self.address_limit {
+            return Err(ErrorDetail::MemoryAddressOutOfBounds {
+                address: 1 + start_address as u16,
+            });
+        }
+        // Construct the u16 from the two u8s through bit shifting and a bitwise OR
+        Ok(((self.bytes[start_address] as u16) << 8) | self.bytes[start_address + 1] as u16)
+    }
+
+    /// Writes the passed byte array slice to memory starting at the specified address.
+    /// If the operands are such that the operation would write to addresses extending beyond
+    /// the addressable memory then returns [ErrorDetail::MemoryAddressOutOfBounds], and if it
+    /// would write to the reserved region while this is write-protected, returns
+    /// [ErrorDetail::ReservedMemoryWrite].
+    ///
+    /// # Arguments
+    ///
+    /// * `start_address` - the memory address at the start of the range to which to write
+    /// * `bytes_to_write` - the array slice containing the bytes to write to memory
+    pub(crate) fn write_bytes(
+        &mut self,
+        start_address: usize,
+        bytes_to_write: &[u8],
+    ) -> Result<(), ErrorDetail> {
+        // Writing no bytes always succeeds, wherever the start address
+        if bytes_to_write.is_empty() {
+            return Ok(());
+        }
+        self.checked_slice_mut(start_address..start_address + bytes_to_write.len())?
+            .copy_from_slice(bytes_to_write);
+        Ok(())
+    }
+
+    /// Returns the size of the addressable memory space in bytes
+    pub fn max_addressable_size(&self) -> usize {
+        self.address_limit
+    }
+
+    /// Returns true if the addressable memory and the font and program regions all lie within
+    /// the memory space, as they must for a memory deserialised from file to be used
+    pub(crate) fn is_consistent(&self) -> bool {
+        self.address_limit <= CHIPOLATA_MEMORY_SIZE_BYTES
+            && self.font_range.start <= self.font_range.end
+            && self.font_range.end <= self.address_limit
+            && self.program_range.start <= self.program_range.end
+            && self.program_range.end <= CHIPOLATA_MEMORY_SIZE_BYTES
+    }
+}
+
+/// Per-address memory access counts gathered while profiling is enabled through
+/// [Processor::set_memory_profiling()](crate::Processor::set_memory_profiling), for example to
+/// render a heatmap highlighting a program's hot loops and data areas.  Each vector holds one
+/// count per byte of the full memory space.  Only accesses made by executing instructions are
+/// counted (instruction fetches, sprite reads and register/BCD loads and stores), so inspecting
+/// memory through snapshots or debugging methods does not affect the counts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryProfile {
+    /// The number of times each address has been read as data
+    pub reads: Vec<u64>,
+    /// The number of times each address has been written to
+    pub writes: Vec<u64>,
+    /// The number of times each address has been fetched as part of an instruction
+    pub executes: Vec<u64>,
+}
+
+impl MemoryProfile {
+    /// Constructor that returns a [MemoryProfile] instance with all counts zero
+    pub(crate) fn new() -> Self {
+        MemoryProfile {
+            reads: vec![0; CHIPOLATA_MEMORY_SIZE_BYTES],
+            writes: vec![0; CHIPOLATA_MEMORY_SIZE_BYTES],
+            executes: vec![0; CHIPOLATA_MEMORY_SIZE_BYTES],
+        }
+    }
+
+    /// Returns the total number of reads, writes and executes recorded for the specified
+    /// address, or zero if the address is outside the memory space
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address of interest
+    pub fn total_accesses(&self, address: usize) -> u64 {
+        match (
+            self.reads.get(address),
+            self.writes.get(address),
+            self.executes.get(address),
+        ) {
+            (Some(reads), Some(writes), Some(executes)) => reads + writes + executes,
+            _ => 0,
+        }
+    }
+
+    /// Returns the highest total access count across all addresses, which front-ends may use
+    /// to normalise heatmap intensities
+    pub fn max_total_accesses(&self) -> u64 {
+        (0..self.reads.len())
+            .map(|address| self.total_accesses(address))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Records a data read of `length` bytes starting at `address`
+    pub(crate) fn record_read(&mut self, address: usize, length: usize) {
+        MemoryProfile::increment(&mut self.reads, address, length);
+    }
+
+    /// Records a write of `length` bytes starting at `address`
+    pub(crate) fn record_write(&mut self, address: usize, length: usize) {
+        MemoryProfile::increment(&mut self.writes, address, length);
+    }
+
+    /// Records an instruction fetch of `length` bytes starting at `address`
+    pub(crate) fn record_execute(&mut self, address: usize, length: usize) {
+        MemoryProfile::increment(&mut self.executes, address, length);
+    }
+
+    // Private helper that increments the counts for a run of addresses, ignoring any that
+    // fall outside the memory space (such accesses will fail with their own error)
+    fn increment(counts: &mut [u64], address: usize, length: usize) {
+        for count in counts.iter_mut().skip(address).take(length) {
+            *count = count.saturating_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_initialisation_chip8() {
+        let instance_one_first_byte: u8 = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        )
+        .read_byte(0x0)
+        .unwrap();
+        let instance_two_first_byte: u8 = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        )
+        .read_byte(0x0)
+        .unwrap();
+        assert_eq!(instance_one_first_byte, instance_two_first_byte);
+    }
+
+    #[test]
+    fn test_zero_initialisation_chip48() {
+        let instance_one_first_byte: u8 = Memory::new(
+            EmulationLevel::Chip48 {
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        )
+        .read_byte(0x0)
+        .unwrap();
+        let instance_two_first_byte: u8 = Memory::new(
+            EmulationLevel::Chip48 {
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        )
+        .read_byte(0x0)
+        .unwrap();
+        assert_eq!(instance_one_first_byte, instance_two_first_byte);
+    }
+
+    #[test]
+    fn test_random_initialisation_superchip11() {
+        let instance_one_first_byte: u8 = Memory::new(
+            EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        )
+        .read_byte(0x0)
+        .unwrap();
+        let instance_two_first_byte: u8 = Memory::new(
+            EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        )
+        .read_byte(0x0)
+        .unwrap();
+        assert_ne!(instance_one_first_byte, instance_two_first_byte);
+    }
+
+    #[test]
+    fn test_read_byte() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        memory.bytes[0x3] = 0xF2;
+        assert_eq!(memory.read_byte(0x3).unwrap(), 0xF2);
+    }
+
+    #[test]
+    fn test_read_byte_out_of_bounds_chip8_small_error() {
+        let memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_byte(CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_byte_out_of_bounds_chip8_large_error() {
+        let memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_byte(CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_byte_out_of_bounds_error_chip48_mode() {
+        let memory = Memory::new(
+            EmulationLevel::Chip48 {
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_byte(CHIP48_ADDRESSABLE_MEMORY_BYTES)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP48_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_byte_out_of_bounds_error_superchip11_mode() {
+        let memory = Memory::new(
+            EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_byte(SUPERCHIP11_ADDRESSABLE_MEMORY_BYTES)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: SUPERCHIP11_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_two_bytes() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        memory.bytes[0x3] = 0xF2;
+        memory.bytes[0x4] = 0x1C;
+        assert_eq!(memory.read_two_bytes(0x3).unwrap(), 0xF21C);
+    }
+
+    #[test]
+    fn test_read_two_bytes_out_of_bounds_chip8_small_error() {
+        let memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_two_bytes(CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES - 1)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_two_bytes_out_of_bounds_chip8_large_error() {
+        let memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_two_bytes(CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES - 1)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_write_byte() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert!(memory.write_byte(0x3, 0xF2).is_ok() && memory.bytes[0x3] == 0xF2);
+    }
+
+    #[test]
+    fn test_write_byte_out_of_bounds_chip8_small_error() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .write_byte(CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES, 0xF2)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_write_byte_out_of_bounds_chip8_large_error() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .write_byte(CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES, 0xF2)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_bytes() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        memory.bytes[0x3] = 0xF2;
+        memory.bytes[0x4] = 0x18;
+        memory.bytes[0x5] = 0xCC;
+        let mem_slice: &[u8] = memory.read_bytes(0x3, 3).unwrap();
+        assert!(mem_slice[0] == 0xF2 && mem_slice[1] == 0x18 && mem_slice[2] == 0xCC);
+    }
+
+    #[test]
+    fn test_read_write_no_bytes() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip48 {
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert!(memory.read_bytes(0x0, 0).unwrap().is_empty());
+        assert_eq!(memory.write_bytes(0x0, &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_read_bytes_out_of_bounds_chip8_small_error() {
+        let memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_bytes(CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES - 1, 2)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_bytes_out_of_bounds_chip8_large_error() {
+        let memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_bytes(CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES - 1, 2)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_write_bytes() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        let bytes_to_write: [u8; 3] = [0xF2, 0x18, 0xCC];
+        memory.write_bytes(0x3, &bytes_to_write).unwrap();
+        assert!(
+            memory.bytes[0x3] == 0xF2 && memory.bytes[0x4] == 0x18 && memory.bytes[0x5] == 0xCC
+        );
+    }
+
+    #[test]
+    fn test_write_bytes_out_of_bounds_chip8_small_error() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        let bytes_to_write: [u8; 2] = [0xF2, 0x18];
+        assert_eq!(
+            memory
+                .write_bytes(CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES - 1, &bytes_to_write)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_write_bytes_out_of_bounds_chip8_large_error() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        let bytes_to_write: [u8; 2] = [0xF2, 0x18];
+        assert_eq!(
+            memory
+                .write_bytes(CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES - 1, &bytes_to_write)
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_read_range() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        memory.bytes[0x10] = 0xAB;
+        memory.bytes[0x11] = 0xCD;
+        assert!(
+            memory.read_range(0x10..0x12).unwrap() == [0xAB, 0xCD]
+                && memory.read_range(0x10..0x10).unwrap().is_empty()
+        );
+    }
+
+    #[test]
+    fn test_read_range_out_of_bounds_error() {
+        let memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        assert_eq!(
+            memory
+                .read_range(
+                    CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES - 1
+                        ..CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES + 1
+                )
+                .unwrap_err(),
+            ErrorDetail::MemoryAddressOutOfBounds {
+                address: CHIP8_SMALL_ADDRESSABLE_MEMORY_BYTES as u16
+            }
+        );
+    }
+
+    #[test]
+    fn test_checked_slice_mut() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        memory
+            .checked_slice_mut(0x300..0x302)
+            .unwrap()
+            .copy_from_slice(&[0x12, 0x34]);
+        assert!(memory.bytes[0x300] == 0x12 && memory.bytes[0x301] == 0x34);
+    }
+
+    #[test]
+    fn test_region() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        memory.set_layout(0x50..0xA0, 0x200..0x280);
+        assert!(
+            memory.region(0x0) == Some(MemoryRegion::Interpreter)
+                && memory.region(0x50) == Some(MemoryRegion::Font)
+                && memory.region(0xA0) == Some(MemoryRegion::Interpreter)
+                && memory.region(0x200) == Some(MemoryRegion::Program)
+                && memory.region(0x280) == Some(MemoryRegion::Free)
+                && memory
+                    .region(CHIP8_LARGE_ADDRESSABLE_MEMORY_BYTES)
+                    .is_none()
+        );
+    }
+
+    #[test]
+    fn test_reserved_write_protection_error() {
+        let mut memory = Memory::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            &mut rand::thread_rng(),
+        );
+        memory.set_layout(0x50..0xA0, 0x200..0x280);
+        memory.set_reserved_write_protection(true);
+        assert!(
+            memory.write_byte(0x1FF, 0x1)
+                == Err(ErrorDetail::ReservedMemoryWrite { address: 0x1FF })
+                && memory.write_bytes(0x1FE, &[0x1, 0x2, 0x3])
+                    == Err(ErrorDetail::ReservedMemoryWrite { address: 0x1FE })
+                && memory.write_byte(0x200, 0x1).is_ok()
+                && memory.write_byte_privileged(0x1FF, 0x2).is_ok()
+                && memory.bytes[0x1FF] == 0x2
+                && memory.bytes[0x1FE] == 0x0
+        );
+    }
+
+    #[test]
+    fn test_memory_profile_counts() {
+        let mut profile: MemoryProfile = MemoryProfile::new();
+        profile.record_execute(0x200, 2);
+        profile.record_read(0x201, 3);
+        profile.record_write(0x203, 1);
+        profile.record_write(CHIPOLATA_MEMORY_SIZE_BYTES - 1, 4);
+        assert!(
+            profile.total_accesses(0x200) == 1
+                && profile.total_accesses(0x201) == 2
+                && profile.total_accesses(0x203) == 2
+                && profile.total_accesses(0x204) == 0
+                && profile.total_accesses(CHIPOLATA_MEMORY_SIZE_BYTES - 1) == 1
+                && profile.total_accesses(CHIPOLATA_MEMORY_SIZE_BYTES) == 0
+                && profile.max_total_accesses() == 2
+        );
+    }
+}
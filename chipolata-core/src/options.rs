@@ -0,0 +1,1231 @@
+use crate::font::{CHIP8_CHAR_SIZE, SUPERCHIP11_CHAR_SIZE};
+use crate::{
+    CustomFont, DisplayOrientation, EmulationLevel, ErrorDetail, KeyWaitMode, Memory, OptionsError,
+    Palette, Processor, SpriteEdgeMode, TimingMode, UnknownInstructionPolicy, MAX_STACK_DEPTH,
+};
+use serde_derive::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// The original COSMAC VIP processor speed in hertz.  When instantiating an [Options] instance
+/// to pass to Chipolata, this value should normally be supplied as the starting
+/// [Options::processor_speed_hertz] choice when specifying [EmulationLevel::Chip8] with
+/// `variable_cycle_timing` set to true.
+/// to be true.
+pub const COSMAC_VIP_PROCESSOR_SPEED_HERTZ: u64 = 1760900;
+/// The HP48S/HP48SX (Saturn) processor speed in hertz.  When instantiating an [Options] instance
+/// to pass to Chipolata, this value should normally be supplied as the starting
+/// [Options::processor_speed_hertz] choice when specifying [EmulationLevel::Chip48],
+/// [EmulationLevel::SuperChip10] or [EmulationLevel::SuperChip11] with `variable_cycle_timing` set
+/// to true (instructions are then paced by approximate HP48 cycle counts, which are estimates
+/// rather than measured timings).
+pub const HP48_PROCESSOR_SPEED_HERTZ: u64 = 2000000;
+/// The default frequency in hertz at which the delay and sound timers are decremented (and, in
+/// CHIP-8 emulation mode, the vblank interrupt occurs), as per the NTSC COSMAC VIP
+pub const DEFAULT_TIMER_FREQUENCY_HERTZ: u64 = 60;
+/// The maximum frequency in hertz that may be specified through [Options::timer_frequency_hertz]
+pub const MAX_TIMER_FREQUENCY_HERTZ: u64 = 1000;
+/// The maximum processor speed in hertz that may be specified through
+/// [Options::processor_speed_hertz]
+pub const MAX_PROCESSOR_SPEED_HERTZ: u64 = 10_000_000;
+/// The version of the [Options] schema used by this release of Chipolata, as recorded in
+/// [Options::version].  This is incremented whenever options saved by this release would need
+/// migrating to be read correctly by a later one.
+pub const OPTIONS_VERSION: u32 = 1;
+/// The default CHIP-8 processor speed in hertz
+const DEFAULT_PROCESSOR_SPEED_HERTZ: u64 = 1000;
+/// The default CHIP-8 program start address within memory
+pub(crate) const DEFAULT_PROGRAM_ADDRESS: u16 = 0x200;
+/// The CHIP-8X program start address within memory (the CHIP-8X interpreter being larger)
+pub(crate) const CHIP8X_PROGRAM_ADDRESS: u16 = 0x300;
+/// The default CHIP-8 font start address within memory
+const DEFAULT_FONT_ADDRESS: u16 = 0x50;
+
+/// A struct to allow specification of Chipolata start-up parameters.
+///
+/// Chipolata provides many configurable options, for example the (initial) processor speed and
+/// a number of choices around how ambiguous instructions should be handled (so as to allow
+/// fine-grained mimicking of specific historic CHIP-8 interpreters).  Configuration of these
+/// options is done through the [Options] struct, an instance of which is passed to
+/// [Processor::initialise_and_load()](crate::processor::Processor::initialise_and_load) when
+/// instantiating [Processor](crate::Processor).
+///
+/// Options saved to file by an earlier release of Chipolata can always be loaded: any options
+/// missing from the file take their default values, and the file is migrated to the current
+/// [OPTIONS_VERSION].
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct Options {
+    /// The version of the schema with which the options were saved.  Files that predate
+    /// versioning are read as version 0; loading files saved by a later release of Chipolata
+    /// (with a version above [OPTIONS_VERSION]) fails with
+    /// [ErrorDetail::IncompatibleOptionsVersion].
+    #[serde(default)]
+    pub version: u32,
+    /// The number of complete fetch->decode->execute cycles Chipolata will carry out per second
+    /// while in default fixed cycle timing mode.  When emulating the variable length instruction
+    /// timings of the COSMAC VIP (or approximating those of the HP48), this simulates the
+    /// underlying original processor speed (where each instruction may take make CPU cycles to
+    /// fetch->decode->execute, not just one).
+    pub processor_speed_hertz: u64,
+    /// The location into memory at which the program should be loaded (and program counter set).
+    pub program_start_address: u16,
+    /// The location into memory at which the system font should be loaded.
+    pub font_start_address: u16,
+    /// Specification of the variant of CHIP-8 to emulate.
+    pub emulation_level: EmulationLevel,
+    /// The seed for the random number generator (used by instruction CXNN, amongst others).  If
+    /// `None` then a seed is chosen at random; specify a value to make emulation deterministic.
+    pub rng_seed: Option<u64>,
+    /// Whether sprite pixels overflowing the right-hand or bottom edges of the display are
+    /// clipped (the default) or wrap around to the opposite edge.
+    #[serde(default)]
+    pub sprite_edge_mode: SpriteEdgeMode,
+    /// The orientation in which the display should be presented, for ROMs written for platforms
+    /// whose screens were mounted in portrait.  This is metadata for renderers and does not
+    /// affect emulation; renderers apply it via [Display::rotated](crate::Display::rotated).
+    #[serde(default)]
+    pub display_orientation: DisplayOrientation,
+    /// The colours in which the display should be rendered, reported to renderers in each
+    /// [StateSnapshot](crate::StateSnapshot).  Like the display orientation, this does not affect
+    /// emulation.  Defaults to the palette of [PaletteTheme::Chipolata](crate::PaletteTheme).
+    #[serde(default)]
+    pub palette: Palette,
+    /// The number of entries the call stack can hold.  If `None` then the stack depth of the
+    /// emulated interpreter is used; specify a value (between 1 and
+    /// [MAX_STACK_DEPTH](crate::MAX_STACK_DEPTH) inclusive) to run ROMs that nest subroutines
+    /// more deeply.
+    #[serde(default)]
+    pub stack_depth_override: Option<usize>,
+    /// How the processor responds to an opcode it cannot execute: by crashing (the default), or
+    /// by skipping over it or treating it as a no-op, so execution can continue past data that
+    /// is interpreted as code.
+    #[serde(default)]
+    pub on_unknown_instruction: UnknownInstructionPolicy,
+    /// Whether to emulate the Hi-Res CHIP-8 interpreter for the COSMAC VIP, which uses a 64 x 64
+    /// pixel display.  Hi-Res programs begin with a jump to 0x260 (opcode 1260) which starts
+    /// execution at 0x2C0 instead, and clear the screen with opcode 0230.  Ignored unless in
+    /// CHIP-8 emulation mode.
+    #[serde(default)]
+    pub chip8_hires_mode: bool,
+    /// The frequency in hertz at which the delay and sound timers are decremented (and, in CHIP-8
+    /// emulation mode, the vblank interrupt occurs), between 1 and [MAX_TIMER_FREQUENCY_HERTZ]
+    /// inclusive.  Defaults to [DEFAULT_TIMER_FREQUENCY_HERTZ]; some ROMs written for PAL
+    /// platforms assume 50Hz.
+    #[serde(default = "default_timer_frequency_hertz")]
+    pub timer_frequency_hertz: u64,
+    /// Whether timer decrements (and, in CHIP-8 emulation mode, vblank interrupts) are driven by
+    /// the number of cycles executed at the configured processor speed rather than by wall-clock
+    /// time, so timers keep the correct timing relative to program execution when running in
+    /// turbo mode or replaying input deterministically.
+    #[serde(default)]
+    pub cycle_synced_timers: bool,
+    /// Whether the timer interrupt runs continuously at a fixed phase, as on original hardware,
+    /// rather than restarting its interval whenever a timer is set from idle.  A timer set by
+    /// FX15 then first decrements at the next tick (anywhere up to a full interval later), so
+    /// programs that calibrate loops by spinning on FX07 see authentic, uneven pacing.  The
+    /// progress through the current interval is reported by
+    /// [Processor::timer_phase()](crate::Processor::timer_phase).
+    #[serde(default)]
+    pub timer_phase_emulation: bool,
+    /// Whether sprite drawing (DXYN) waits for the next display refresh in low resolution mode,
+    /// as on real HP48 hardware, so that programs (such as scrolling demos) which rely on this
+    /// to pace themselves run at authentic speeds.  Applies in CHIP-48 emulation mode and in
+    /// SUPER-CHIP 1.1 emulation mode while in low resolution mode; CHIP-8 emulation mode always
+    /// waits for the vblank interrupt regardless (unless [Options::accurate_display_timing] is
+    /// set).
+    #[serde(default)]
+    pub lowres_display_wait: bool,
+    /// Whether the COSMAC VIP display is modelled scanline by scanline rather than by its vblank
+    /// interrupt alone.  The simulated raster position is then tracked through each frame, and
+    /// sprite drawing (DXYN) waits only until the raster has passed the last display row the
+    /// sprite occupies, rather than always for the next vblank interrupt; the cost of a draw
+    /// therefore depends on the sprite's Y position, as on original hardware.  This adds overhead
+    /// to every sprite draw, so is off by default.  Applies in CHIP-8 and CHIP-8X emulation modes
+    /// only.
+    #[serde(default)]
+    pub accurate_display_timing: bool,
+    /// Whether CXNN generates its random numbers with the routine of the original COSMAC VIP
+    /// interpreter rather than a uniform random number generator.  That routine adds the byte at
+    /// 0x100 + R9.0 (a counter advanced as instructions execute, here derived from the elapsed
+    /// cycles) to R9.1, so programs relying on its reproducible sequences behave as on original
+    /// hardware.  As it reads the interpreter's own code, authentic sequences require the
+    /// interpreter image to have been loaded via
+    /// [Processor::load_interpreter_image()](crate::Processor::load_interpreter_image).  Applies
+    /// in CHIP-8 and CHIP-8X emulation modes only.
+    #[serde(default)]
+    pub vip_random_generator: bool,
+    /// The maximum number of sprites that may be drawn (DXYN instructions executed) per frame,
+    /// or None for no limit.  Once the budget is used up, further sprite draws wait for the next
+    /// vblank interrupt, approximating the pacing of original hardware (on which drawing was
+    /// slow) independently of processor speed.  Must be at least 1 if specified.
+    #[serde(default)]
+    pub sprites_per_frame: Option<usize>,
+    /// Whether memory below the program start address is populated as on a COSMAC VIP, for
+    /// programs that read from the area the original interpreter occupied.  The VIP's own hex
+    /// digit sprites (several of which differ in shape from the default font) are loaded as the
+    /// font, and any interpreter image supplied via [crate::Processor::load_interpreter_image]
+    /// is retained across resets.  Applies in CHIP-8 emulation mode only.
+    #[serde(default)]
+    pub vip_memory_image: bool,
+    /// Whether memory below the program start address (holding the font, and any interpreter
+    /// image) is write-protected.  A program writing there (via FX33 or FX55) then crashes with
+    /// [ErrorDetail::ReservedMemoryWrite] rather than silently corrupting the font, helping ROM
+    /// developers catch such bugs early.
+    #[serde(default)]
+    pub protect_reserved_memory: bool,
+    /// Whether a key found to be pressed by EX9E (or EXA1) is then treated as released until the
+    /// host next reports it pressed, so that a single press is acted on only once by programs
+    /// polling in a tight loop.  When false (the default, in every emulation mode) keys remain
+    /// pressed for as long as they are held, as on original hardware, which games relying on
+    /// held keys for continuous movement require.
+    #[serde(default)]
+    pub key_repeat_suppression: bool,
+    /// Whether the FX0A instruction registers a key once it has been pressed and released (the
+    /// default, as on the original COSMAC VIP) or as soon as it is pressed.
+    #[serde(default)]
+    pub key_wait_mode: KeyWaitMode,
+    /// Whether the display is resized to 64 x 32 pixels while in SUPER-CHIP 1.1 low-resolution
+    /// mode (and back to 128 x 64 pixels on entering high-resolution mode), rather than always
+    /// being held at 128 x 64 pixels with each low-resolution pixel drawn as a 2x2 block.  Front
+    /// ends then scale the display by its effective resolution, as reported by
+    /// [StateSnapshot::resolution()](crate::StateSnapshot::resolution).  While the display is
+    /// resized, scroll instructions move whole low-resolution pixels (as in OCTO).  Applies in
+    /// SUPER-CHIP 1.1 emulation mode only.
+    #[serde(default)]
+    pub resize_low_resolution_display: bool,
+    /// Whether a program stuck in an infinite loop is reported as
+    /// [ProcessorStatus::Halted](crate::ProcessorStatus::Halted), after which no further cycles
+    /// execute.  This recognises the common `1NNN` jump-to-self idiom used to end programs, and
+    /// tight loops that repeat without reading input or otherwise changing any state, so hosts
+    /// can show that the program has ended rather than burning CPU time forever.
+    #[serde(default)]
+    pub halt_detection: bool,
+    /// How long each instruction takes to execute.  If `None` (the default) then each takes one
+    /// cycle at [Options::processor_speed_hertz], or as many machine cycles as on the original
+    /// interpreter (approximately so, for the HP48 interpreters) if the emulation level's
+    /// `variable_cycle_timing` flag is set; specify a
+    /// [TimingMode] to give each instruction a fixed cost in microseconds, or to run a fixed
+    /// number of instructions per frame as OCTO does.  Cycle-synced timers follow the same
+    /// timing, so a frame is then always the configured number of instructions.
+    #[serde(default)]
+    pub timing_mode: Option<TimingMode>,
+    /// Font data to load in place of the emulation level's (low-resolution) font, or None to use
+    /// the built-in font.  Its characters must be 5 bytes tall, as programs draw them with
+    /// 5-row sprites; [FontPreset](crate::FontPreset) offers several historical fonts.
+    #[serde(default)]
+    pub custom_low_res_font: Option<CustomFont>,
+    /// Font data to load in place of the SUPER-CHIP high-resolution font (used by FX30), or None
+    /// to use the built-in font.  Its characters must be 10 bytes tall.  Applies in SUPER-CHIP
+    /// 1.0 and 1.1 emulation modes only.
+    #[serde(default)]
+    pub custom_high_res_font: Option<CustomFont>,
+    /// The intensity (out of 255) lost each frame by a pixel once it is erased, or None (the
+    /// default) for no ghost trails.  When set, the display tracks an intensity for every pixel
+    /// alongside its on/off state, so front-ends can render erased pixels fading out smoothly
+    /// (see [Display::get_intensities()](crate::Display::get_intensities)), reducing the flicker
+    /// of programs that erase and redraw sprites each frame.  Must be at least 1 if specified.
+    #[serde(default)]
+    pub ghost_trail_decay: Option<u8>,
+}
+
+/// Returns the default timer frequency, for options deserialised from files that predate the
+/// [Options::timer_frequency_hertz] setting
+fn default_timer_frequency_hertz() -> u64 {
+    DEFAULT_TIMER_FREQUENCY_HERTZ
+}
+
+/// Converts a JSON value to the equivalent TOML value, omitting null values (which TOML cannot
+/// represent) from tables; as every optional setting defaults to None, omitted options load
+/// unchanged.  Returns None if the value holds a null outside a table, or an integer too large
+/// for TOML.
+///
+/// # Arguments
+///
+/// * `value` - the JSON value to convert
+#[cfg(feature = "std")]
+fn json_to_toml(value: serde_json::Value) -> Option<toml::Value> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::Bool(boolean) => Some(toml::Value::Boolean(boolean)),
+        serde_json::Value::Number(number) => match number.as_i64() {
+            Some(integer) => Some(toml::Value::Integer(integer)),
+            None if number.is_f64() => number.as_f64().map(toml::Value::Float),
+            None => None,
+        },
+        serde_json::Value::String(string) => Some(toml::Value::String(string)),
+        serde_json::Value::Array(array) => array
+            .into_iter()
+            .map(json_to_toml)
+            .collect::<Option<Vec<toml::Value>>>()
+            .map(toml::Value::Array),
+        serde_json::Value::Object(object) => {
+            let mut table: toml::Table = toml::Table::new();
+            for (key, value) in object {
+                if !value.is_null() {
+                    table.insert(key, json_to_toml(value)?);
+                }
+            }
+            Some(toml::Value::Table(table))
+        }
+    }
+}
+
+impl Options {
+    /// Typical constructor that allows specification of processor speed and emulation level, but
+    /// useful default values for less commonly set properties (including the conventional program
+    /// start address of the emulation level: 0x300 for CHIP-8X, and 0x200 otherwise)
+    pub fn new(processor_speed_hertz: u64, emulation_level: EmulationLevel) -> Self {
+        Options {
+            version: OPTIONS_VERSION,
+            processor_speed_hertz,
+            emulation_level,
+            program_start_address: match emulation_level {
+                EmulationLevel::Chip8X { .. } => CHIP8X_PROGRAM_ADDRESS,
+                _ => DEFAULT_PROGRAM_ADDRESS,
+            },
+            font_start_address: DEFAULT_FONT_ADDRESS,
+            rng_seed: None,
+            sprite_edge_mode: SpriteEdgeMode::Clip,
+            display_orientation: DisplayOrientation::Rotate0,
+            palette: Palette::default(),
+            stack_depth_override: None,
+            on_unknown_instruction: UnknownInstructionPolicy::Crash,
+            chip8_hires_mode: false,
+            timer_frequency_hertz: DEFAULT_TIMER_FREQUENCY_HERTZ,
+            cycle_synced_timers: false,
+            timer_phase_emulation: false,
+            lowres_display_wait: false,
+            accurate_display_timing: false,
+            vip_random_generator: false,
+            sprites_per_frame: None,
+            vip_memory_image: false,
+            protect_reserved_memory: false,
+            key_repeat_suppression: false,
+            key_wait_mode: KeyWaitMode::OnRelease,
+            resize_low_resolution_display: false,
+            halt_detection: false,
+            timing_mode: None,
+            custom_low_res_font: None,
+            custom_high_res_font: None,
+            ghost_trail_decay: None,
+        }
+    }
+
+    /// Returns the name of the first option differing between these options and those passed
+    /// which can only be set when a [Processor](crate::Processor) is initialised (the emulation
+    /// level, program and font start addresses, random seed, stack depth override, Hi-Res CHIP-8
+    /// mode, COSMAC VIP memory image, low-resolution display resizing and custom fonts), or None if every difference can be applied to a
+    /// running processor via [Processor::apply_options()](crate::Processor::apply_options)
+    ///
+    /// # Arguments
+    ///
+    /// * `new_options` - the options to compare against these
+    pub fn restart_required_by(&self, new_options: &Options) -> Option<&'static str> {
+        if new_options.emulation_level != self.emulation_level {
+            Some("emulation_level")
+        } else if new_options.program_start_address != self.program_start_address {
+            Some("program_start_address")
+        } else if new_options.font_start_address != self.font_start_address {
+            Some("font_start_address")
+        } else if new_options.rng_seed != self.rng_seed {
+            Some("rng_seed")
+        } else if new_options.stack_depth_override != self.stack_depth_override {
+            Some("stack_depth_override")
+        } else if new_options.chip8_hires_mode != self.chip8_hires_mode {
+            Some("chip8_hires_mode")
+        } else if new_options.vip_memory_image != self.vip_memory_image {
+            Some("vip_memory_image")
+        } else if new_options.resize_low_resolution_display != self.resize_low_resolution_display {
+            Some("resize_low_resolution_display")
+        } else if new_options.custom_low_res_font != self.custom_low_res_font {
+            Some("custom_low_res_font")
+        } else if new_options.custom_high_res_font != self.custom_high_res_font {
+            Some("custom_high_res_font")
+        } else {
+            None
+        }
+    }
+
+    /// Returns a new [OptionsBuilder], through which options can be specified one by one and then
+    /// validated as a whole
+    pub fn builder() -> OptionsBuilder {
+        OptionsBuilder::default()
+    }
+
+    /// Checks that the options are consistent with one another and within their supported
+    /// ranges, returning an [OptionsError] identifying the first problem found.  Options passing
+    /// these checks will not be rejected by
+    /// [Processor::initialise_and_load()](crate::Processor::initialise_and_load) (though the
+    /// program itself may still be too large to fit in memory).
+    pub fn validate(&self) -> Result<(), OptionsError> {
+        if self.processor_speed_hertz == 0 || self.processor_speed_hertz > MAX_PROCESSOR_SPEED_HERTZ
+        {
+            return Err(OptionsError::ProcessorSpeedOutOfRange {
+                processor_speed_hertz: self.processor_speed_hertz,
+            });
+        }
+        if self.timer_frequency_hertz == 0 || self.timer_frequency_hertz > MAX_TIMER_FREQUENCY_HERTZ
+        {
+            return Err(OptionsError::TimerFrequencyOutOfRange {
+                timer_frequency_hertz: self.timer_frequency_hertz,
+            });
+        }
+        // Programs draw the characters of each font with sprites of a fixed height
+        for (option, font, required_char_size) in [
+            (
+                "custom_low_res_font",
+                self.custom_low_res_font,
+                CHIP8_CHAR_SIZE,
+            ),
+            (
+                "custom_high_res_font",
+                self.custom_high_res_font,
+                SUPERCHIP11_CHAR_SIZE,
+            ),
+        ] {
+            if let Some(font) = font {
+                if font.char_size() != required_char_size {
+                    return Err(OptionsError::CustomFontCharSize {
+                        option,
+                        char_size: font.char_size(),
+                        required_char_size,
+                    });
+                }
+            }
+        }
+        // The program must start within addressable memory, and the font data (both fonts, for
+        // the SUPER-CHIP emulation levels) must end before it
+        let addressable_bytes: usize = Memory::addressable_bytes(self.emulation_level);
+        if self.program_start_address as usize >= addressable_bytes {
+            return Err(OptionsError::ProgramStartAddressOutOfRange {
+                program_start_address: self.program_start_address,
+                addressable_bytes,
+            });
+        }
+        let (low_resolution_font, high_resolution_font) = Processor::initial_fonts(self);
+        let font_end_address: usize = self.font_start_address as usize
+            + low_resolution_font.font_data_size()
+            + high_resolution_font.map_or(0, |font| font.font_data_size());
+        if font_end_address >= self.program_start_address as usize {
+            return Err(OptionsError::FontOverlapsProgram {
+                font_end_address,
+                program_start_address: self.program_start_address,
+            });
+        }
+        if let Some(stack_depth) = self.stack_depth_override {
+            if stack_depth == 0 || stack_depth > MAX_STACK_DEPTH {
+                return Err(OptionsError::StackDepthOutOfRange { stack_depth });
+            }
+        }
+        if self.sprites_per_frame == Some(0) {
+            return Err(OptionsError::ZeroSpritesPerFrame);
+        }
+        if self.ghost_trail_decay == Some(0) {
+            return Err(OptionsError::ZeroGhostTrailDecay);
+        }
+        if let Some(
+            TimingMode::Fixed {
+                instruction_microseconds: 0,
+            }
+            | TimingMode::InstructionsPerFrame { instructions: 0 },
+        ) = self.timing_mode
+        {
+            return Err(OptionsError::ZeroTimingMode);
+        }
+        Ok(())
+    }
+
+    /// Migrates options saved by an earlier release of Chipolata to the current
+    /// [OPTIONS_VERSION], returning them with [Options::version] updated.  Options added since
+    /// they were saved will already have taken their default values when deserialised.  Returns
+    /// [ErrorDetail::IncompatibleOptionsVersion] if the options were saved by a later release.
+    pub fn migrate(mut self) -> Result<Options, ErrorDetail> {
+        Options::check_version(Some(self.version as u64))?;
+        // Version 0 (predating versioning) options need nothing more than the defaults already
+        // applied (their legacy emulation level shapes having been rewritten before
+        // deserialisation when loaded from file); migrations for later versions are to be
+        // added here, oldest first
+        self.version = OPTIONS_VERSION;
+        Ok(self)
+    }
+
+    /// Internal helper function that returns [ErrorDetail::IncompatibleOptionsVersion] if options
+    /// of the passed schema version (None for options predating versioning) cannot be read
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - the version with which the options were saved, if recorded
+    fn check_version(version: Option<u64>) -> Result<(), ErrorDetail> {
+        match version {
+            Some(version) if version > OPTIONS_VERSION as u64 => {
+                Err(ErrorDetail::IncompatibleOptionsVersion {
+                    version,
+                    supported: OPTIONS_VERSION,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Internal helper function that rewrites the emulation level of options predating
+    /// versioning (deserialised as a JSON value, but not yet as [Options]) into its current shape:
+    /// CHIP-48 was then a unit variant saved as the string `"Chip48"`, and neither it nor
+    /// SUPER-CHIP 1.1 held the `variable_cycle_timing` setting, which is added switched off.
+    /// Options saved with a version are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the options as read from file
+    #[cfg(feature = "std")]
+    fn migrate_unversioned_value(value: &mut serde_json::Value) {
+        if value.get("version").and_then(|v| v.as_u64()).unwrap_or(0) > 0 {
+            return;
+        }
+        if let Some(emulation_level) = value.get_mut("emulation_level") {
+            if emulation_level.as_str() == Some("Chip48") {
+                *emulation_level = serde_json::json!({ "Chip48": {} });
+            }
+            for variant in ["Chip48", "SuperChip11"] {
+                if let Some(settings) = emulation_level
+                    .get_mut(variant)
+                    .and_then(|settings| settings.as_object_mut())
+                {
+                    settings
+                        .entry("variable_cycle_timing")
+                        .or_insert(serde_json::Value::Bool(false));
+                }
+            }
+        }
+    }
+
+    /// Builder method that instantiates Options from the specified JSON file, migrating them from
+    /// the version with which they were saved (see [Options::migrate()])
+    #[cfg(feature = "std")]
+    pub fn load_from_file(file_path: &Path) -> Result<Options, ErrorDetail> {
+        // attempt to open the file
+        if let Ok(json_file) = File::open(file_path) {
+            // parse the file as JSON, checking its version can be read before deserialising it
+            // into an Options instance
+            if let Ok(mut value) = serde_json::from_reader::<File, serde_json::Value>(json_file) {
+                Options::check_version(value.get("version").and_then(|v| v.as_u64()))?;
+                Options::migrate_unversioned_value(&mut value);
+                if let Ok(options) = serde_json::from_value::<Options>(value) {
+                    return options.migrate();
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred reading from the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Method that serialises the passed [Options] instance to the specified JSON file
+    #[cfg(feature = "std")]
+    pub fn save_to_file(options: &Options, file_path: &Path) -> Result<(), ErrorDetail> {
+        // attempt to open the file; create it if it does not exist and truncate if it does
+        if let Ok(_) = File::create(file_path) {
+            if let Ok(serialised_options) = serde_json::to_string_pretty(options) {
+                if std::fs::write(file_path, serialised_options).is_ok() {
+                    return Ok(());
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred writing to the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Builder method that instantiates Options from the specified TOML file, migrating them from
+    /// the version with which they were saved (see [Options::migrate()])
+    #[cfg(feature = "std")]
+    pub fn load_from_toml(file_path: &Path) -> Result<Options, ErrorDetail> {
+        // attempt to read the file as a string
+        if let Ok(toml_string) = std::fs::read_to_string(file_path) {
+            // parse the string as TOML, checking its version can be read before deserialising it
+            // into an Options instance
+            if let Ok(value) = toml::from_str::<toml::Value>(&toml_string) {
+                // the TOML is read through the same JSON value model it was written from (see
+                // Options::save_to_toml())
+                if let Ok(mut value) = serde_json::to_value(value) {
+                    Options::check_version(value.get("version").and_then(|v| v.as_u64()))?;
+                    Options::migrate_unversioned_value(&mut value);
+                    if let Ok(options) = serde_json::from_value::<Options>(value) {
+                        return options.migrate();
+                    }
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred reading from the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Method that serialises the passed [Options] instance to the specified TOML file.  Fails
+    /// with [ErrorDetail::FileError] if [Options::rng_seed] is too large for a TOML integer
+    /// (above `i64::MAX`)
+    #[cfg(feature = "std")]
+    pub fn save_to_toml(options: &Options, file_path: &Path) -> Result<(), ErrorDetail> {
+        // TOML cannot represent enum variants holding fields (such as those of EmulationLevel)
+        // directly, so the options are serialised as they would be to JSON, with each such
+        // variant becoming a table keyed by the variant name
+        if let Some(value) = serde_json::to_value(options).ok().and_then(json_to_toml) {
+            if let Ok(serialised_options) = toml::to_string_pretty(&value) {
+                // write to the file; create it if it does not exist and truncate if it does
+                if std::fs::write(file_path, serialised_options).is_ok() {
+                    return Ok(());
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred writing to the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+}
+
+impl Default for Options {
+    /// Constructor that returns an [Options] instance using typical default settings
+    fn default() -> Self {
+        Options {
+            version: OPTIONS_VERSION,
+            processor_speed_hertz: DEFAULT_PROCESSOR_SPEED_HERTZ,
+            program_start_address: DEFAULT_PROGRAM_ADDRESS,
+            font_start_address: DEFAULT_FONT_ADDRESS,
+            emulation_level: EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false,
+            },
+            rng_seed: None,
+            sprite_edge_mode: SpriteEdgeMode::Clip,
+            display_orientation: DisplayOrientation::Rotate0,
+            palette: Palette::default(),
+            stack_depth_override: None,
+            on_unknown_instruction: UnknownInstructionPolicy::Crash,
+            chip8_hires_mode: false,
+            timer_frequency_hertz: DEFAULT_TIMER_FREQUENCY_HERTZ,
+            cycle_synced_timers: false,
+            timer_phase_emulation: false,
+            lowres_display_wait: false,
+            accurate_display_timing: false,
+            vip_random_generator: false,
+            sprites_per_frame: None,
+            vip_memory_image: false,
+            protect_reserved_memory: false,
+            key_repeat_suppression: false,
+            key_wait_mode: KeyWaitMode::OnRelease,
+            resize_low_resolution_display: false,
+            halt_detection: false,
+            timing_mode: None,
+            custom_low_res_font: None,
+            custom_high_res_font: None,
+            ghost_trail_decay: None,
+        }
+    }
+}
+
+/// A builder for [Options], offering a fluent setter for each option (and for the settings held
+/// within the [EmulationLevel]).  Options not set take their default values, except that the
+/// program start address defaults to the conventional one for the emulation level.  The options
+/// are validated as a whole by [OptionsBuilder::build()], so that hosts can report precisely
+/// what is wrong with them rather than failing later when initialising a processor.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct OptionsBuilder {
+    options: Options,                      // the options set so far
+    program_start_address: Option<u16>,    // the program start address, if set
+    memory_limit_2k: Option<bool>,         // CHIP-8 only; the memory limit, if set
+    octo_compatibility_mode: Option<bool>, // SUPER-CHIP 1.1 only; OCTO compatibility, if set
+    variable_cycle_timing: Option<bool>,   // whether to use variable cycle timing, if set
+}
+
+impl OptionsBuilder {
+    /// Sets [Options::processor_speed_hertz]
+    pub fn processor_speed_hertz(mut self, processor_speed_hertz: u64) -> Self {
+        self.options.processor_speed_hertz = processor_speed_hertz;
+        self
+    }
+
+    /// Sets [Options::emulation_level] (any settings made through
+    /// [OptionsBuilder::memory_limit_2k()], [OptionsBuilder::octo_compatibility_mode()] or
+    /// [OptionsBuilder::variable_cycle_timing()] take precedence over those it holds)
+    pub fn emulation_level(mut self, emulation_level: EmulationLevel) -> Self {
+        self.options.emulation_level = emulation_level;
+        self
+    }
+
+    /// Sets the `memory_limit_2k` setting of [EmulationLevel::Chip8]; may only be set to true if
+    /// emulating CHIP-8
+    pub fn memory_limit_2k(mut self, memory_limit_2k: bool) -> Self {
+        self.memory_limit_2k = Some(memory_limit_2k);
+        self
+    }
+
+    /// Sets the `octo_compatibility_mode` setting of [EmulationLevel::SuperChip11]; may only be
+    /// set to true if emulating SUPER-CHIP 1.1
+    pub fn octo_compatibility_mode(mut self, octo_compatibility_mode: bool) -> Self {
+        self.octo_compatibility_mode = Some(octo_compatibility_mode);
+        self
+    }
+
+    /// Sets the `variable_cycle_timing` setting of the emulation level (exact COSMAC VIP timings
+    /// for CHIP-8, approximate HP48 timings for CHIP-48 and SUPER-CHIP)
+    pub fn variable_cycle_timing(mut self, variable_cycle_timing: bool) -> Self {
+        self.variable_cycle_timing = Some(variable_cycle_timing);
+        self
+    }
+
+    /// Sets [Options::program_start_address]
+    pub fn program_start_address(mut self, program_start_address: u16) -> Self {
+        self.program_start_address = Some(program_start_address);
+        self
+    }
+
+    /// Sets [Options::font_start_address]
+    pub fn font_start_address(mut self, font_start_address: u16) -> Self {
+        self.options.font_start_address = font_start_address;
+        self
+    }
+
+    /// Sets [Options::rng_seed]
+    pub fn rng_seed(mut self, rng_seed: u64) -> Self {
+        self.options.rng_seed = Some(rng_seed);
+        self
+    }
+
+    /// Sets [Options::sprite_edge_mode]
+    pub fn sprite_edge_mode(mut self, sprite_edge_mode: SpriteEdgeMode) -> Self {
+        self.options.sprite_edge_mode = sprite_edge_mode;
+        self
+    }
+
+    /// Sets [Options::display_orientation]
+    pub fn display_orientation(mut self, display_orientation: DisplayOrientation) -> Self {
+        self.options.display_orientation = display_orientation;
+        self
+    }
+
+    /// Sets [Options::palette]
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.options.palette = palette;
+        self
+    }
+
+    /// Sets [Options::custom_low_res_font]
+    pub fn custom_low_res_font(mut self, font: CustomFont) -> Self {
+        self.options.custom_low_res_font = Some(font);
+        self
+    }
+
+    /// Sets [Options::custom_high_res_font]
+    pub fn custom_high_res_font(mut self, font: CustomFont) -> Self {
+        self.options.custom_high_res_font = Some(font);
+        self
+    }
+
+    /// Sets [Options::stack_depth_override]
+    pub fn stack_depth_override(mut self, stack_depth: usize) -> Self {
+        self.options.stack_depth_override = Some(stack_depth);
+        self
+    }
+
+    /// Sets [Options::on_unknown_instruction]
+    pub fn on_unknown_instruction(mut self, policy: UnknownInstructionPolicy) -> Self {
+        self.options.on_unknown_instruction = policy;
+        self
+    }
+
+    /// Sets [Options::chip8_hires_mode]; may only be set to true if emulating CHIP-8
+    pub fn chip8_hires_mode(mut self, chip8_hires_mode: bool) -> Self {
+        self.options.chip8_hires_mode = chip8_hires_mode;
+        self
+    }
+
+    /// Sets [Options::timer_frequency_hertz]
+    pub fn timer_frequency_hertz(mut self, timer_frequency_hertz: u64) -> Self {
+        self.options.timer_frequency_hertz = timer_frequency_hertz;
+        self
+    }
+
+    /// Sets [Options::cycle_synced_timers]
+    pub fn cycle_synced_timers(mut self, cycle_synced_timers: bool) -> Self {
+        self.options.cycle_synced_timers = cycle_synced_timers;
+        self
+    }
+
+    /// Sets [Options::timer_phase_emulation]
+    pub fn timer_phase_emulation(mut self, timer_phase_emulation: bool) -> Self {
+        self.options.timer_phase_emulation = timer_phase_emulation;
+        self
+    }
+
+    /// Sets [Options::lowres_display_wait]; may only be set to true if emulating CHIP-48 or
+    /// SUPER-CHIP
+    pub fn lowres_display_wait(mut self, lowres_display_wait: bool) -> Self {
+        self.options.lowres_display_wait = lowres_display_wait;
+        self
+    }
+
+    /// Sets [Options::accurate_display_timing]; may only be set to true if emulating CHIP-8 or
+    /// CHIP-8X
+    pub fn accurate_display_timing(mut self, accurate_display_timing: bool) -> Self {
+        self.options.accurate_display_timing = accurate_display_timing;
+        self
+    }
+
+    /// Sets [Options::vip_random_generator]; may only be set to true if emulating CHIP-8 or
+    /// CHIP-8X
+    pub fn vip_random_generator(mut self, vip_random_generator: bool) -> Self {
+        self.options.vip_random_generator = vip_random_generator;
+        self
+    }
+
+    /// Sets [Options::sprites_per_frame]
+    pub fn sprites_per_frame(mut self, sprites_per_frame: usize) -> Self {
+        self.options.sprites_per_frame = Some(sprites_per_frame);
+        self
+    }
+
+    /// Sets [Options::vip_memory_image]; may only be set to true if emulating CHIP-8
+    pub fn vip_memory_image(mut self, vip_memory_image: bool) -> Self {
+        self.options.vip_memory_image = vip_memory_image;
+        self
+    }
+
+    /// Sets [Options::protect_reserved_memory]
+    pub fn protect_reserved_memory(mut self, protect_reserved_memory: bool) -> Self {
+        self.options.protect_reserved_memory = protect_reserved_memory;
+        self
+    }
+
+    /// Sets [Options::key_repeat_suppression]
+    pub fn key_repeat_suppression(mut self, key_repeat_suppression: bool) -> Self {
+        self.options.key_repeat_suppression = key_repeat_suppression;
+        self
+    }
+
+    /// Sets [Options::key_wait_mode]
+    pub fn key_wait_mode(mut self, key_wait_mode: KeyWaitMode) -> Self {
+        self.options.key_wait_mode = key_wait_mode;
+        self
+    }
+
+    /// Sets [Options::resize_low_resolution_display]; may only be set to true if emulating
+    /// SUPER-CHIP
+    pub fn resize_low_resolution_display(mut self, resize_low_resolution_display: bool) -> Self {
+        self.options.resize_low_resolution_display = resize_low_resolution_display;
+        self
+    }
+
+    /// Sets [Options::halt_detection]
+    pub fn halt_detection(mut self, halt_detection: bool) -> Self {
+        self.options.halt_detection = halt_detection;
+        self
+    }
+
+    /// Sets [Options::timing_mode]
+    pub fn timing_mode(mut self, timing_mode: TimingMode) -> Self {
+        self.options.timing_mode = Some(timing_mode);
+        self
+    }
+
+    /// Sets [Options::ghost_trail_decay]
+    pub fn ghost_trail_decay(mut self, decay: u8) -> Self {
+        self.options.ghost_trail_decay = Some(decay);
+        self
+    }
+
+    /// Returns the [Options] specified, having checked that every option set applies at the
+    /// chosen emulation level and that the options are valid as a whole (see
+    /// [Options::validate()]).  Returns an [OptionsError] identifying the first problem found.
+    pub fn build(self) -> Result<Options, OptionsError> {
+        let mut options: Options = self.options;
+        let emulation_level: EmulationLevel = options.emulation_level;
+        let not_applicable = |option: &'static str| OptionsError::OptionNotApplicable {
+            option,
+            emulation_level,
+        };
+        // Apply the settings held within the emulation level, rejecting any set to true at an
+        // emulation level which lacks them
+        match (&mut options.emulation_level, self.memory_limit_2k) {
+            (
+                EmulationLevel::Chip8 {
+                    memory_limit_2k, ..
+                },
+                Some(limit),
+            ) => *memory_limit_2k = limit,
+            (_, Some(true)) => return Err(not_applicable("memory_limit_2k")),
+            _ => (),
+        }
+        match (&mut options.emulation_level, self.octo_compatibility_mode) {
+            (
+                EmulationLevel::SuperChip11 {
+                    octo_compatibility_mode,
+                    ..
+                },
+                Some(octo),
+            ) => *octo_compatibility_mode = octo,
+            (_, Some(true)) => return Err(not_applicable("octo_compatibility_mode")),
+            _ => (),
+        }
+        if let Some(variable) = self.variable_cycle_timing {
+            match &mut options.emulation_level {
+                EmulationLevel::Chip8 {
+                    variable_cycle_timing,
+                    ..
+                }
+                | EmulationLevel::Chip8X {
+                    variable_cycle_timing,
+                }
+                | EmulationLevel::Chip48 {
+                    variable_cycle_timing,
+                }
+                | EmulationLevel::SuperChip10 {
+                    variable_cycle_timing,
+                }
+                | EmulationLevel::SuperChip11 {
+                    variable_cycle_timing,
+                    ..
+                } => *variable_cycle_timing = variable,
+            }
+        }
+        // Likewise reject any other option set to true at an emulation level where it has no
+        // effect
+        let chip8: bool = matches!(emulation_level, EmulationLevel::Chip8 { .. });
+        let cosmac: bool = chip8 || matches!(emulation_level, EmulationLevel::Chip8X { .. });
+        let superchip: bool = matches!(
+            emulation_level,
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. }
+        );
+        for (option, set, applicable) in [
+            ("chip8_hires_mode", options.chip8_hires_mode, chip8),
+            ("vip_memory_image", options.vip_memory_image, chip8),
+            (
+                "accurate_display_timing",
+                options.accurate_display_timing,
+                cosmac,
+            ),
+            ("vip_random_generator", options.vip_random_generator, cosmac),
+            ("lowres_display_wait", options.lowres_display_wait, !cosmac),
+            (
+                "resize_low_resolution_display",
+                options.resize_low_resolution_display,
+                superchip,
+            ),
+            (
+                "custom_high_res_font",
+                options.custom_high_res_font.is_some(),
+                superchip,
+            ),
+        ] {
+            if set && !applicable {
+                return Err(not_applicable(option));
+            }
+        }
+        // Use the conventional program start address for the emulation level, unless another
+        // was set
+        options.program_start_address = match self.program_start_address {
+            Some(program_start_address) => program_start_address,
+            None => {
+                Options::new(options.processor_speed_hertz, emulation_level).program_start_address
+            }
+        };
+        options.validate()?;
+        Ok(options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FontPreset;
+
+    #[test]
+    fn test_builder_defaults() {
+        assert_eq!(Options::builder().build().unwrap(), Options::default());
+    }
+
+    #[test]
+    fn test_builder_chip8x_program_address() {
+        let options: Options = Options::builder()
+            .emulation_level(EmulationLevel::Chip8X {
+                variable_cycle_timing: false,
+            })
+            .variable_cycle_timing(true)
+            .build()
+            .unwrap();
+        assert!(
+            options.program_start_address == CHIP8X_PROGRAM_ADDRESS
+                && options.emulation_level
+                    == EmulationLevel::Chip8X {
+                        variable_cycle_timing: true
+                    }
+        );
+    }
+
+    #[test]
+    fn test_builder_memory_limit_not_applicable() {
+        assert_eq!(
+            Options::builder()
+                .memory_limit_2k(true)
+                .build()
+                .unwrap_err(),
+            OptionsError::OptionNotApplicable {
+                option: "memory_limit_2k",
+                emulation_level: Options::default().emulation_level
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_custom_high_res_font_not_applicable() {
+        let emulation_level: EmulationLevel = EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        };
+        assert_eq!(
+            Options::builder()
+                .emulation_level(emulation_level)
+                .custom_high_res_font(FontPreset::Octo.font())
+                .build()
+                .unwrap_err(),
+            OptionsError::OptionNotApplicable {
+                option: "custom_high_res_font",
+                emulation_level
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_custom_font_char_size() {
+        assert_eq!(
+            Options::builder()
+                .custom_low_res_font(FontPreset::SuperChip11.font())
+                .build()
+                .unwrap_err(),
+            OptionsError::CustomFontCharSize {
+                option: "custom_low_res_font",
+                char_size: SUPERCHIP11_CHAR_SIZE,
+                required_char_size: CHIP8_CHAR_SIZE
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_memory_limit_chip8() {
+        let options: Options = Options::builder()
+            .emulation_level(EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            })
+            .memory_limit_2k(true)
+            .chip8_hires_mode(true)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            options.emulation_level,
+            EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_builder_font_overlaps_program() {
+        assert_eq!(
+            Options::builder()
+                .font_start_address(0x1C0)
+                .build()
+                .unwrap_err(),
+            OptionsError::FontOverlapsProgram {
+                font_end_address: 0x1C0 + 80 + 100,
+                program_start_address: DEFAULT_PROGRAM_ADDRESS
+            }
+        );
+    }
+
+    #[test]
+    fn test_builder_processor_speed_out_of_range() {
+        assert_eq!(
+            Options::builder()
+                .processor_speed_hertz(0)
+                .build()
+                .unwrap_err(),
+            OptionsError::ProcessorSpeedOutOfRange {
+                processor_speed_hertz: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_validate_program_address_out_of_range() {
+        let mut options: Options = Options::default();
+        options.program_start_address = 0xFFF;
+        assert_eq!(
+            options.validate().unwrap_err(),
+            OptionsError::ProgramStartAddressOutOfRange {
+                program_start_address: 0xFFF,
+                addressable_bytes: 0xFFF
+            }
+        );
+    }
+
+    #[test]
+    fn test_validate_zero_ghost_trail_decay() {
+        let options: Options = Options::default();
+        assert_eq!(options.validate(), Ok(()));
+        assert_eq!(
+            Options::builder().ghost_trail_decay(0).build().unwrap_err(),
+            OptionsError::ZeroGhostTrailDecay
+        );
+    }
+
+    #[test]
+    fn test_save_load() {
+        const FILENAME: &str = "unit_test_save_load.json";
+        let options: Options = Options::default();
+        Options::save_to_file(&options, Path::new(FILENAME)).unwrap();
+        let new_options = Options::load_from_file(Path::new(FILENAME)).unwrap();
+        assert_eq!(options, new_options);
+        std::fs::remove_file(FILENAME).unwrap();
+    }
+
+    #[test]
+    fn test_save_load_toml() {
+        const FILENAME: &str = "unit_test_save_load.toml";
+        // Enum variants holding fields, and options both set and unset, must survive the trip
+        let options: Options = Options {
+            emulation_level: EmulationLevel::Chip8 {
+                memory_limit_2k: true,
+                variable_cycle_timing: false,
+            },
+            rng_seed: Some(42),
+            ghost_trail_decay: Some(4),
+            timing_mode: Some(TimingMode::InstructionsPerFrame { instructions: 11 }),
+            ..Options::default()
+        };
+        Options::save_to_toml(&options, Path::new(FILENAME)).unwrap();
+        let new_options = Options::load_from_toml(Path::new(FILENAME)).unwrap();
+        assert_eq!(options, new_options);
+        std::fs::remove_file(FILENAME).unwrap();
+    }
+
+    #[test]
+    fn test_load_bundled_options() {
+        // Every options file shipped with the app must load, including those saved before the
+        // emulation levels gained their later settings
+        let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("../resources/options");
+        let mut loaded: usize = 0;
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let file_path = entry.unwrap().path();
+            let options = Options::load_from_file(&file_path);
+            assert!(options.is_ok(), "{} failed to load", file_path.display());
+            loaded += 1;
+        }
+        assert!(loaded > 0);
+    }
+
+    #[test]
+    fn test_load_unversioned() {
+        const FILENAME: &str = "unit_test_load_unversioned.json";
+        // Options saved before versioning (as resources/options/SCHIP-octo.json), lacking any
+        // options added since and the SUPER-CHIP 1.1 variable_cycle_timing setting
+        std::fs::write(
+            FILENAME,
+            r#"{
+  "processor_speed_hertz": 2500,
+  "program_start_address": 512,
+  "font_start_address": 0,
+  "emulation_level": {
+    "SuperChip11": {
+      "octo_compatibility_mode": true
+    }
+  }
+}"#,
+        )
+        .unwrap();
+        let options = Options::load_from_file(Path::new(FILENAME)).unwrap();
+        std::fs::remove_file(FILENAME).unwrap();
+        assert!(
+            options.version == OPTIONS_VERSION
+                && options.processor_speed_hertz == 2500
+                && options.font_start_address == 0
+                && options.timer_frequency_hertz == DEFAULT_TIMER_FREQUENCY_HERTZ
+                && options.emulation_level
+                    == EmulationLevel::SuperChip11 {
+                        octo_compatibility_mode: true,
+                        variable_cycle_timing: false
+                    }
+        );
+    }
+
+    #[test]
+    fn test_load_unversioned_chip48() {
+        const FILENAME: &str = "unit_test_load_unversioned_chip48.toml";
+        // Before versioning, CHIP-48 was saved as a unit variant
+        std::fs::write(
+            FILENAME,
+            "processor_speed_hertz = 700\nemulation_level = \"Chip48\"\n",
+        )
+        .unwrap();
+        let options = Options::load_from_toml(Path::new(FILENAME)).unwrap();
+        std::fs::remove_file(FILENAME).unwrap();
+        assert!(
+            options.processor_speed_hertz == 700
+                && options.emulation_level
+                    == EmulationLevel::Chip48 {
+                        variable_cycle_timing: false
+                    }
+        );
+    }
+
+    #[test]
+    fn test_load_future_version_error() {
+        const FILENAME: &str = "unit_test_load_future_version.json";
+        // Options saved by a later release, including an option unknown to this one
+        std::fs::write(
+            FILENAME,
+            format!(
+                r#"{{"version": {}, "future_option": {{"Unknown": 1}}}}"#,
+                OPTIONS_VERSION + 1
+            ),
+        )
+        .unwrap();
+        let result = Options::load_from_file(Path::new(FILENAME));
+        std::fs::remove_file(FILENAME).unwrap();
+        assert_eq!(
+            result.unwrap_err(),
+            ErrorDetail::IncompatibleOptionsVersion {
+                version: OPTIONS_VERSION as u64 + 1,
+                supported: OPTIONS_VERSION
+            }
+        );
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut options: Options = Options::default();
+        options.version = 0;
+        assert_eq!(options.migrate().unwrap(), Options::default());
+        options.version = OPTIONS_VERSION + 1;
+        assert!(options.migrate().is_err());
+    }
+
+    #[test]
+    fn test_restart_required_by() {
+        let options: Options = Options::default();
+        let mut new_options: Options = options;
+        new_options.processor_speed_hertz *= 2;
+        new_options.lowres_display_wait = true;
+        assert_eq!(options.restart_required_by(&new_options), None);
+        new_options.font_start_address += 0x10;
+        assert_eq!(
+            options.restart_required_by(&new_options),
+            Some("font_start_address")
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use crate::display::Display;
+use crate::prelude::*;
+
+/// The width and height in pixels of each character cell drawn by [Overlay::draw_text()]
+pub const OSD_GLYPH_SIZE_PIXELS: usize = 8;
+
+/// The 8x8 font used to draw on-screen display text, one byte per glyph row (most significant
+/// bit leftmost).  Lower-case letters are drawn using the upper-case glyphs, and any character
+/// without a glyph is drawn as '?'.
+const OSD_FONT: [(char, [u8; OSD_GLYPH_SIZE_PIXELS]); 55] = [
+    (' ', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    ('!', [0x10, 0x10, 0x10, 0x10, 0x10, 0x00, 0x10, 0x00]),
+    ('#', [0x28, 0x28, 0x7C, 0x28, 0x7C, 0x28, 0x28, 0x00]),
+    ('%', [0x60, 0x64, 0x08, 0x10, 0x20, 0x4C, 0x0C, 0x00]),
+    ('\'', [0x10, 0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    ('(', [0x08, 0x10, 0x20, 0x20, 0x20, 0x10, 0x08, 0x00]),
+    (')', [0x20, 0x10, 0x08, 0x08, 0x08, 0x10, 0x20, 0x00]),
+    ('*', [0x00, 0x10, 0x54, 0x38, 0x54, 0x10, 0x00, 0x00]),
+    ('+', [0x00, 0x10, 0x10, 0x7C, 0x10, 0x10, 0x00, 0x00]),
+    (',', [0x00, 0x00, 0x00, 0x00, 0x30, 0x10, 0x20, 0x00]),
+    ('-', [0x00, 0x00, 0x00, 0x7C, 0x00, 0x00, 0x00, 0x00]),
+    ('.', [0x00, 0x00, 0x00, 0x00, 0x00, 0x30, 0x30, 0x00]),
+    ('/', [0x00, 0x04, 0x08, 0x10, 0x20, 0x40, 0x00, 0x00]),
+    ('0', [0x38, 0x44, 0x4C, 0x54, 0x64, 0x44, 0x38, 0x00]),
+    ('1', [0x10, 0x30, 0x10, 0x10, 0x10, 0x10, 0x38, 0x00]),
+    ('2', [0x38, 0x44, 0x04, 0x08, 0x10, 0x20, 0x7C, 0x00]),
+    ('3', [0x7C, 0x08, 0x10, 0x08, 0x04, 0x44, 0x38, 0x00]),
+    ('4', [0x08, 0x18, 0x28, 0x48, 0x7C, 0x08, 0x08, 0x00]),
+    ('5', [0x7C, 0x40, 0x78, 0x04, 0x04, 0x44, 0x38, 0x00]),
+    ('6', [0x18, 0x20, 0x40, 0x78, 0x44, 0x44, 0x38, 0x00]),
+    ('7', [0x7C, 0x04, 0x08, 0x10, 0x20, 0x20, 0x20, 0x00]),
+    ('8', [0x38, 0x44, 0x44, 0x38, 0x44, 0x44, 0x38, 0x00]),
+    ('9', [0x38, 0x44, 0x44, 0x3C, 0x04, 0x08, 0x30, 0x00]),
+    (':', [0x00, 0x30, 0x30, 0x00, 0x30, 0x30, 0x00, 0x00]),
+    ('<', [0x08, 0x10, 0x20, 0x40, 0x20, 0x10, 0x08, 0x00]),
+    ('=', [0x00, 0x00, 0x7C, 0x00, 0x7C, 0x00, 0x00, 0x00]),
+    ('>', [0x20, 0x10, 0x08, 0x04, 0x08, 0x10, 0x20, 0x00]),
+    ('?', [0x38, 0x44, 0x04, 0x08, 0x10, 0x00, 0x10, 0x00]),
+    ('A', [0x38, 0x44, 0x44, 0x7C, 0x44, 0x44, 0x44, 0x00]),
+    ('B', [0x78, 0x44, 0x44, 0x78, 0x44, 0x44, 0x78, 0x00]),
+    ('C', [0x38, 0x44, 0x40, 0x40, 0x40, 0x44, 0x38, 0x00]),
+    ('D', [0x78, 0x44, 0x44, 0x44, 0x44, 0x44, 0x78, 0x00]),
+    ('E', [0x7C, 0x40, 0x40, 0x78, 0x40, 0x40, 0x7C, 0x00]),
+    ('F', [0x7C, 0x40, 0x40, 0x78, 0x40, 0x40, 0x40, 0x00]),
+    ('G', [0x38, 0x44, 0x40, 0x5C, 0x44, 0x44, 0x3C, 0x00]),
+    ('H', [0x44, 0x44, 0x44, 0x7C, 0x44, 0x44, 0x44, 0x00]),
+    ('I', [0x38, 0x10, 0x10, 0x10, 0x10, 0x10, 0x38, 0x00]),
+    ('J', [0x1C, 0x08, 0x08, 0x08, 0x08, 0x48, 0x30, 0x00]),
+    ('K', [0x44, 0x48, 0x50, 0x60, 0x50, 0x48, 0x44, 0x00]),
+    ('L', [0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x7C, 0x00]),
+    ('M', [0x44, 0x6C, 0x54, 0x54, 0x44, 0x44, 0x44, 0x00]),
+    ('N', [0x44, 0x44, 0x64, 0x54, 0x4C, 0x44, 0x44, 0x00]),
+    ('O', [0x38, 0x44, 0x44, 0x44, 0x44, 0x44, 0x38, 0x00]),
+    ('P', [0x78, 0x44, 0x44, 0x78, 0x40, 0x40, 0x40, 0x00]),
+    ('Q', [0x38, 0x44, 0x44, 0x44, 0x54, 0x48, 0x34, 0x00]),
+    ('R', [0x78, 0x44, 0x44, 0x78, 0x50, 0x48, 0x44, 0x00]),
+    ('S', [0x3C, 0x40, 0x40, 0x38, 0x04, 0x04, 0x78, 0x00]),
+    ('T', [0x7C, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00]),
+    ('U', [0x44, 0x44, 0x44, 0x44, 0x44, 0x44, 0x38, 0x00]),
+    ('V', [0x44, 0x44, 0x44, 0x44, 0x44, 0x28, 0x10, 0x00]),
+    ('W', [0x44, 0x44, 0x44, 0x54, 0x54, 0x54, 0x28, 0x00]),
+    ('X', [0x44, 0x44, 0x28, 0x10, 0x28, 0x44, 0x44, 0x00]),
+    ('Y', [0x44, 0x44, 0x28, 0x10, 0x10, 0x10, 0x10, 0x00]),
+    ('Z', [0x7C, 0x04, 0x08, 0x10, 0x20, 0x40, 0x7C, 0x00]),
+    ('_', [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7C, 0x00]),
+];
+
+/// The state of a single pixel of an [Overlay]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum OverlayPixel {
+    /// The display beneath shows through
+    Transparent,
+    /// Part of the backing box behind text, drawn in the background colour
+    Background,
+    /// Part of a text glyph, drawn in the foreground colour
+    Foreground,
+}
+
+/// An on-screen display layer for host messages (such as "PAUSED", "STATE SAVED" or a frame rate
+/// counter) composited over the CHIP-8 display.  An overlay is owned by the host and is never
+/// written to emulated memory or the processor's frame buffer, so it has no effect on emulation;
+/// it may be drawn at the resolution of the display itself (and combined with a state snapshot's
+/// frame buffer through [Overlay::composite()]) or at any other resolution for the renderer to
+/// scale over the display.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overlay {
+    width: usize,
+    height: usize,
+    pixels: Vec<OverlayPixel>,
+}
+
+impl Overlay {
+    /// Constructor that returns a transparent [Overlay] of the specified size
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - the width of the overlay in pixels
+    /// * `height` - the height of the overlay in pixels
+    pub fn new(width: usize, height: usize) -> Self {
+        Overlay {
+            width,
+            height,
+            pixels: vec![OverlayPixel::Transparent; width * height],
+        }
+    }
+
+    /// Constructor that returns a transparent [Overlay] of the same size as the specified display
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - the display the overlay will be composited over
+    pub fn for_display(display: &Display) -> Self {
+        Overlay::new(
+            display.get_row_size_bytes() * 8,
+            display.get_column_size_pixels(),
+        )
+    }
+
+    /// Returns the width of the overlay in pixels
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the height of the overlay in pixels
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the state of the pixel at the specified coordinates (transparent if the
+    /// coordinates lie outside the overlay)
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the zero-based column of the pixel
+    /// * `y` - the zero-based row of the pixel
+    pub fn get_pixel(&self, x: usize, y: usize) -> OverlayPixel {
+        match x < self.width && y < self.height {
+            true => self.pixels[y * self.width + x],
+            false => OverlayPixel::Transparent,
+        }
+    }
+
+    /// Returns the state of every pixel in the overlay, row by row from the top-left
+    pub fn get_pixels(&self) -> &[OverlayPixel] {
+        &self.pixels
+    }
+
+    /// Returns true if no part of the overlay has been drawn on
+    pub fn is_empty(&self) -> bool {
+        self.pixels
+            .iter()
+            .all(|pixel| *pixel == OverlayPixel::Transparent)
+    }
+
+    /// Makes every pixel of the overlay transparent again
+    pub fn clear(&mut self) {
+        self.pixels.fill(OverlayPixel::Transparent);
+    }
+
+    /// Returns the width in pixels that the specified text occupies when drawn
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - the text to measure
+    pub fn text_width(text: &str) -> usize {
+        text.chars().count() * OSD_GLYPH_SIZE_PIXELS
+    }
+
+    /// Draws a single line of text on a backing box of background pixels (so it remains legible
+    /// whatever the display beneath shows), with the top-left corner of the first character at
+    /// the specified coordinates.  Any part of the text falling outside the overlay is clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the zero-based column at which the text starts
+    /// * `y` - the zero-based row at which the text starts
+    /// * `text` - the text to draw
+    pub fn draw_text(&mut self, x: usize, y: usize, text: &str) {
+        for (position, character) in text.chars().enumerate() {
+            let glyph: [u8; OSD_GLYPH_SIZE_PIXELS] = glyph(character);
+            let cell_x: usize = x + position * OSD_GLYPH_SIZE_PIXELS;
+            for (row, row_bits) in glyph.iter().enumerate() {
+                for column in 0..OSD_GLYPH_SIZE_PIXELS {
+                    let pixel: OverlayPixel = match row_bits & (0x80 >> column) > 0 {
+                        true => OverlayPixel::Foreground,
+                        false => OverlayPixel::Background,
+                    };
+                    self.set_pixel(cell_x + column, y + row, pixel);
+                }
+            }
+        }
+    }
+
+    /// Draws a single line of text horizontally centred within the overlay, with its top at the
+    /// specified row
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - the zero-based row at which the text starts
+    /// * `text` - the text to draw
+    pub fn draw_text_centred(&mut self, y: usize, text: &str) {
+        let x: usize = self.width.saturating_sub(Overlay::text_width(text)) / 2;
+        self.draw_text(x, y, text);
+    }
+
+    /// Returns a copy of the specified display with the overlay drawn over it: foreground pixels
+    /// are turned on in the first plane only (so they show in the foreground colour), background
+    /// pixels are turned off in all planes, and transparent pixels are left untouched.  The
+    /// overlay is aligned with the top-left of the display, and any part of it lying beyond the
+    /// display is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - the display over which to composite the overlay
+    pub fn composite(&self, display: &Display) -> Display {
+        let mut composited: Display = display.clone();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match self.pixels[y * self.width + x] {
+                    OverlayPixel::Transparent => (),
+                    OverlayPixel::Background => composited.set_pixel_colour_index(x, y, 0),
+                    OverlayPixel::Foreground => composited.set_pixel_colour_index(x, y, 1),
+                }
+            }
+        }
+        composited
+    }
+
+    /// Internal helper function that sets the state of the pixel at the specified coordinates,
+    /// ignoring coordinates outside the overlay
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - the zero-based column of the pixel
+    /// * `y` - the zero-based row of the pixel
+    /// * `pixel` - the state to give the pixel
+    fn set_pixel(&mut self, x: usize, y: usize, pixel: OverlayPixel) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = pixel;
+        }
+    }
+}
+
+/// Helper function that returns the glyph with which the specified character is drawn
+///
+/// # Arguments
+///
+/// * `character` - the character to look up
+fn glyph(character: char) -> [u8; OSD_GLYPH_SIZE_PIXELS] {
+    let character: char = character.to_ascii_uppercase();
+    OSD_FONT
+        .iter()
+        .find(|(glyph_character, _)| *glyph_character == character)
+        .or_else(|| {
+            OSD_FONT
+                .iter()
+                .find(|(glyph_character, _)| *glyph_character == '?')
+        })
+        .map(|(_, glyph)| *glyph)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EmulationLevel;
+
+    #[test]
+    fn test_draw_text() {
+        let mut overlay: Overlay = Overlay::new(16, 8);
+        overlay.draw_text(0, 0, "I!");
+        // 'I' is a vertical bar centred in its cell, with serifs on the top and bottom rows
+        assert_eq!(overlay.get_pixel(2, 0), OverlayPixel::Foreground);
+        assert_eq!(overlay.get_pixel(3, 3), OverlayPixel::Foreground);
+        assert_eq!(overlay.get_pixel(2, 3), OverlayPixel::Background);
+        assert_eq!(overlay.get_pixel(11, 0), OverlayPixel::Foreground);
+        assert_eq!(overlay.get_pixel(11, 5), OverlayPixel::Background);
+        assert!(!overlay.is_empty());
+        overlay.clear();
+        assert!(overlay.is_empty());
+    }
+
+    #[test]
+    fn test_draw_text_clipped() {
+        let mut overlay: Overlay = Overlay::new(12, 4);
+        overlay.draw_text(4, 0, "HELLO");
+        assert_eq!(overlay.get_pixel(0, 0), OverlayPixel::Transparent);
+        assert_eq!(overlay.get_pixel(5, 0), OverlayPixel::Foreground);
+        assert_eq!(overlay.get_pixel(12, 0), OverlayPixel::Transparent);
+    }
+
+    #[test]
+    fn test_draw_text_centred() {
+        let mut overlay: Overlay = Overlay::new(32, 8);
+        overlay.draw_text_centred(0, "AB");
+        assert_eq!(overlay.get_pixel(7, 0), OverlayPixel::Transparent);
+        assert_eq!(overlay.get_pixel(8, 0), OverlayPixel::Background);
+        assert_eq!(overlay.get_pixel(24, 0), OverlayPixel::Transparent);
+    }
+
+    #[test]
+    fn test_unknown_character_drawn_as_question_mark() {
+        assert_eq!(glyph('~'), glyph('?'));
+        assert_eq!(glyph('a'), glyph('A'));
+    }
+
+    #[test]
+    fn test_composite() {
+        let mut display: Display = Display::new(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+        display[0][0] = 0xFF;
+        display[0][2] = 0xFF;
+        let mut overlay: Overlay = Overlay::for_display(&display);
+        overlay.draw_text(0, 0, "-");
+        let composited: Display = overlay.composite(&display);
+        // The backing box clears the pixels beneath, the glyph lights its own, and transparent
+        // pixels leave the display as it was; the original display is unaffected
+        assert!(!composited.get_pixel(0, 0));
+        assert!(composited.get_pixel(1, 3));
+        assert!(composited.get_pixel(16, 0));
+        assert!(display.get_pixel(0, 0));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::display::DISPLAY_PLANE_COUNT;
+use crate::error::ErrorDetail;
+use crate::prelude::*;
+use core::fmt;
+use serde_derive::{Deserialize, Serialize};
+
+/// The maximum number of colours a [Palette] may hold (one for each colour index of a display
+/// with four bit planes)
+pub const PALETTE_MAX_COLOURS: usize = 16;
+/// The smallest number of colours a [Palette] may hold (background and foreground)
+const PALETTE_MIN_COLOURS: usize = 2;
+
+/// The colours with which a display is rendered, as RGB triples indexed by display colour index
+/// (see [Display::get_colour_indices()](crate::Display::get_colour_indices)): background,
+/// foreground, pixels set only in the second plane, pixels set in both planes, and so on.  A
+/// palette holds a power of two colours, from 2 (background and foreground only) up to
+/// [PALETTE_MAX_COLOURS]; pixels with a colour index beyond those held are rendered in the
+/// foreground colour.
+///
+/// The palette in use is set through [Options::palette](crate::Options::palette) and reported
+/// in each [StateSnapshot](crate::StateSnapshot), so that every host renders the display in the
+/// same colours.  A number of built-in palettes are offered by [PaletteTheme].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "Vec<[u8; 3]>", into = "Vec<[u8; 3]>")]
+pub struct Palette {
+    colours: [[u8; 3]; PALETTE_MAX_COLOURS], // the colours, of which only the first few are used
+    colour_count: usize,                     // the number of colours held
+}
+
+impl Palette {
+    /// Constructor that returns a [Palette] holding the passed colours, in colour index order.
+    /// Returns [ErrorDetail::InvalidPalette] unless the number of colours is a power of two
+    /// between 2 and [PALETTE_MAX_COLOURS] inclusive.
+    ///
+    /// # Arguments
+    ///
+    /// * `colours` - the RGB colour of each display colour index
+    pub fn new(colours: &[[u8; 3]]) -> Result<Self, ErrorDetail> {
+        let colour_count: usize = colours.len();
+        if !(PALETTE_MIN_COLOURS..=PALETTE_MAX_COLOURS).contains(&colour_count)
+            || !colour_count.is_power_of_two()
+        {
+            return Err(ErrorDetail::InvalidPalette { colour_count });
+        }
+        let mut palette: Palette = Palette {
+            colours: [[0x0; 3]; PALETTE_MAX_COLOURS],
+            colour_count,
+        };
+        palette.colours[..colour_count].copy_from_slice(colours);
+        Ok(palette)
+    }
+
+    /// Returns the colours held, in colour index order
+    pub fn colours(&self) -> &[[u8; 3]] {
+        &self.colours[..self.colour_count]
+    }
+
+    /// Returns the colours held, in colour index order, for editing
+    pub fn colours_mut(&mut self) -> &mut [[u8; 3]] {
+        &mut self.colours[..self.colour_count]
+    }
+
+    /// Returns the colour with which pixels of the specified display colour index are rendered
+    /// (the foreground colour, if the palette holds no colour for that index)
+    ///
+    /// # Arguments
+    ///
+    /// * `colour_index` - the display colour index of the pixel
+    pub fn colour(&self, colour_index: u8) -> [u8; 3] {
+        match (colour_index as usize) < self.colour_count {
+            true => self.colours[colour_index as usize],
+            false => self.foreground(),
+        }
+    }
+
+    /// Returns the background colour (that of colour index 0)
+    pub fn background(&self) -> [u8; 3] {
+        self.colours[0]
+    }
+
+    /// Returns the foreground colour (that of colour index 1)
+    pub fn foreground(&self) -> [u8; 3] {
+        self.colours[1]
+    }
+}
+
+impl Default for Palette {
+    /// Constructor that returns the default [Palette], that of [PaletteTheme::Chipolata]
+    fn default() -> Self {
+        PaletteTheme::Chipolata.palette()
+    }
+}
+
+impl TryFrom<Vec<[u8; 3]>> for Palette {
+    type Error = ErrorDetail;
+
+    /// Converts a list of colours (as a [Palette] is serialised) into a [Palette], returning
+    /// [ErrorDetail::InvalidPalette] if the number of colours is not supported
+    fn try_from(colours: Vec<[u8; 3]>) -> Result<Self, Self::Error> {
+        Palette::new(&colours)
+    }
+}
+
+impl From<Palette> for Vec<[u8; 3]> {
+    /// Converts a [Palette] into its list of colours, as which it is serialised
+    fn from(palette: Palette) -> Self {
+        palette.colours().to_vec()
+    }
+}
+
+/// An enum of the built-in [Palette] themes, each holding a colour for every colour index of the
+/// display (that is, 2 to the power of [DISPLAY_PLANE_COUNT] colours)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PaletteTheme {
+    /// Chipolata's own blue and cyan colours
+    Chipolata,
+    /// The yellow and brown default colours of the Octo development environment
+    OctoClassic,
+    /// The shades of green of an early handheld LCD screen
+    LcdGreen,
+    /// The glow of an amber monochrome CRT monitor
+    AmberCrt,
+}
+
+impl PaletteTheme {
+    /// All built-in themes, in the order in which hosts should offer them
+    pub const ALL: [PaletteTheme; 4] = [
+        PaletteTheme::Chipolata,
+        PaletteTheme::OctoClassic,
+        PaletteTheme::LcdGreen,
+        PaletteTheme::AmberCrt,
+    ];
+
+    /// Returns the colours of the theme, in colour index order
+    fn colours(&self) -> [[u8; 3]; 1 << DISPLAY_PLANE_COUNT] {
+        match self {
+            PaletteTheme::Chipolata => [
+                [0x09, 0x49, 0x92],
+                [0x00, 0xDC, 0xFF],
+                [0xFF, 0xAA, 0x00],
+                [0xFF, 0xFF, 0xFF],
+            ],
+            PaletteTheme::OctoClassic => [
+                [0x99, 0x66, 0x00],
+                [0xFF, 0xCC, 0x00],
+                [0xFF, 0x66, 0x00],
+                [0x66, 0x22, 0x00],
+            ],
+            PaletteTheme::LcdGreen => [
+                [0x9B, 0xBC, 0x0F],
+                [0x0F, 0x38, 0x0F],
+                [0x8B, 0xAC, 0x0F],
+                [0x30, 0x62, 0x30],
+            ],
+            PaletteTheme::AmberCrt => [
+                [0x1A, 0x10, 0x00],
+                [0xFF, 0xB0, 0x00],
+                [0x99, 0x5C, 0x00],
+                [0xFF, 0xE0, 0x99],
+            ],
+        }
+    }
+
+    /// Returns the [Palette] of the theme
+    pub fn palette(&self) -> Palette {
+        let colours: [[u8; 3]; 1 << DISPLAY_PLANE_COUNT] = self.colours();
+        let mut palette: Palette = Palette {
+            colours: [[0x0; 3]; PALETTE_MAX_COLOURS],
+            colour_count: colours.len(),
+        };
+        palette.colours[..colours.len()].copy_from_slice(&colours);
+        palette
+    }
+
+    /// Returns the built-in theme whose palette is that passed, or None if the palette is not
+    /// one of the built-in themes (for example having been customised)
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` - the palette to identify
+    pub fn matching(palette: &Palette) -> Option<PaletteTheme> {
+        PaletteTheme::ALL
+            .into_iter()
+            .find(|theme| theme.palette() == *palette)
+    }
+}
+
+impl fmt::Display for PaletteTheme {
+    /// Formatter for [PaletteTheme], giving the name of the theme as shown to users
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name: &str = match self {
+            PaletteTheme::Chipolata => "Chipolata",
+            PaletteTheme::OctoClassic => "Octo classic",
+            PaletteTheme::LcdGreen => "LCD green",
+            PaletteTheme::AmberCrt => "Amber CRT",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_invalid_colour_count() {
+        for colour_count in [0, 1, 3, 5, 32] {
+            assert_eq!(
+                Palette::new(&vec![[0x0; 3]; colour_count]).unwrap_err(),
+                ErrorDetail::InvalidPalette { colour_count }
+            );
+        }
+    }
+
+    #[test]
+    fn test_colour_beyond_palette() {
+        let palette: Palette = Palette::new(&[[0x10; 3], [0x20; 3]]).unwrap();
+        assert_eq!(palette.colour(0), [0x10; 3]);
+        assert_eq!(palette.colour(3), [0x20; 3]);
+    }
+
+    #[test]
+    fn test_themes_hold_every_plane_colour() {
+        for theme in PaletteTheme::ALL {
+            assert_eq!(theme.palette().colours().len(), 1 << DISPLAY_PLANE_COUNT);
+            assert_eq!(PaletteTheme::matching(&theme.palette()), Some(theme));
+        }
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let palette: Palette = PaletteTheme::AmberCrt.palette();
+        let json: String = serde_json::to_string(&palette).unwrap();
+        assert_eq!(serde_json::from_str::<Palette>(&json).unwrap(), palette);
+        assert!(serde_json::from_str::<Palette>("[[1, 2, 3]]").is_err());
+    }
+}
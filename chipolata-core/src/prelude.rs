@@ -0,0 +1,7 @@
+//! The `alloc` items that the standard library prelude would otherwise supply, imported by each
+//! module that uses them so that the crate also builds without the `std` feature
+pub(crate) use alloc::borrow::ToOwned;
+pub(crate) use alloc::boxed::Box;
+pub(crate) use alloc::string::{String, ToString};
+pub(crate) use alloc::vec::Vec;
+pub(crate) use alloc::{format, vec};
@@ -0,0 +1,2445 @@
+#![allow(non_snake_case)]
+
+use super::benchmark::BenchmarkReport;
+use super::clock::{default_clock, Clock, Instant};
+use super::display::{Display, SpriteEdgeMode, CHIP8X_ZONE_WIDTH_PIXELS};
+use super::error::{ChipolataError, ErrorDetail, ExecutionContext, Operand};
+use super::font::Font;
+use super::frame_sink::{FrameSink, FrameSinkState};
+use super::instruction::Instruction;
+use super::keystate::{Key, KeyEvent, KeyState};
+use super::memory::{Memory, MemoryProfile, CHIPOLATA_MEMORY_SIZE_BYTES};
+use super::options::{Options, MAX_TIMER_FREQUENCY_HERTZ};
+use super::palette::Palette;
+use super::prelude::*;
+use super::program::Program;
+use super::recording::InputRecording;
+use super::sound::{SoundEvent, SoundSink, SoundState};
+use super::stack::{CallTreeNode, Stack, StackEvent, MAX_STACK_DEPTH};
+use super::system_routine::{SystemRoutineContext, SystemRoutineHandler};
+use super::timing_stats::{TimingRecorder, TimingStatistics};
+use super::watch::{DebugReport, RegisteredWatch, WatchContext, WatchExpression, WatchResult};
+use alloc::collections::VecDeque;
+use core::cell::Cell;
+use core::time::Duration;
+use halt::HaltWatch;
+use instruction_cache::InstructionCache;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_derive::{Deserialize, Serialize};
+
+mod crash_dump; // Separate sub-module for the crash dump capture and writing methods
+mod debug; // Separate sub-module for all the debugger state mutation methods
+mod execute; // Separate sub-module for all the instruction execution methods
+mod fuzz; // Separate sub-module for the fuzzing entry point
+mod halt; // Separate sub-module for the infinite loop (halt) detection methods
+mod instruction_cache; // Separate sub-module for the decoded instruction cache
+mod profile; // Separate sub-module for the memory access profiling methods
+mod run_ahead; // Separate sub-module for the speculative run-ahead methods
+mod savestate; // Separate sub-module for the save state capture and restore methods
+#[cfg(test)]
+mod tests; // Functional unit tests
+mod timing; // Separate sub-module for the instruction cycle timing tables
+#[cfg(test)]
+mod timing_tests; // Non-functional (timing-related) unit tests
+
+pub use crash_dump::{
+    CrashDump, CRASH_DUMP_OPTIONS_FILE, CRASH_DUMP_PROGRAM_FILE, CRASH_DUMP_REPORT_FILE,
+    CRASH_DUMP_TRACE_FILE, CRASH_DUMP_TRACE_LENGTH,
+};
+pub use fuzz::FuzzOutcome;
+pub use savestate::SaveState;
+pub(crate) use timing::nominal_instruction_cycles;
+
+/// The number of variable registers available
+const VARIABLE_REGISTER_COUNT: usize = 16;
+/// The number of RPL user flags; SUPER-CHIP 1.1 emulation mode only
+const RPL_REGISTER_COUNT: usize = 8;
+/// How far ahead of schedule (in microseconds) execution may run before the processor sleeps, so
+/// that cycles are executed in batches between sleeps rather than each being waited for
+const PACING_BATCH_MICROSECONDS: u64 = 2000;
+/// The maximum sprite height (pixels)
+const MAX_SPRITE_HEIGHT: u8 = 15;
+/// The height of the sprite drawn by DXY0 in low-resolution mode; SUPER-CHIP 1.0 emulation mode
+/// only (SUPER-CHIP 1.1 draws nothing)
+const SUPERCHIP10_LOW_RES_DXY0_HEIGHT: u8 = 16;
+/// The number of COSMAC VIP cycles used to execute one CHIP-8 interpreter cycle
+/// (used when emulating original COSMAC VIP variable instruction timings)
+const COSMAC_VIP_MACHINE_CYCLES_PER_CYCLE: u64 = 8;
+/// The number of scanlines in each COSMAC VIP video frame (an NTSC field)
+const VIP_SCANLINES_PER_FRAME: usize = 262;
+/// The number of COSMAC VIP scanlines on which the display is drawn
+const VIP_DISPLAY_SCANLINES: usize = 128;
+/// The scanline, counting from the vblank interrupt, on which the COSMAC VIP starts drawing the
+/// display (the interrupt routine runs on the scanlines in between)
+const VIP_DISPLAY_FIRST_SCANLINE: usize = 2;
+/// The start of the memory page (holding the COSMAC VIP interpreter's code) from which the VIP's
+/// random number routine draws the bytes it accumulates
+const VIP_RANDOM_TABLE_ADDRESS: usize = 0x100;
+/// The opcode (1260, jump to 0x260) with which Hi-Res CHIP-8 programs begin; CHIP-8 emulation
+/// mode with Hi-Res enabled only
+pub(crate) const CHIP8_HIRES_ENTRY_OPCODE: [u8; 2] = [0x12, 0x60];
+/// The address at which Hi-Res CHIP-8 programs proper start; CHIP-8 emulation mode with Hi-Res
+/// enabled only
+pub(crate) const CHIP8_HIRES_PROGRAM_START_ADDRESS: u16 = 0x2C0;
+/// The machine language routine (called by opcode 0230) that clears the Hi-Res CHIP-8 display;
+/// CHIP-8 emulation mode with Hi-Res enabled only
+const CHIP8_HIRES_CLEAR_SCREEN_ROUTINE: u16 = 0x230;
+
+/// An enum to indicate which extension of CHIP-8 is to be emulated.  See external
+/// documentation for details of the differences in each case.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum EmulationLevel {
+    /// The original CHIP-8 interpreter for the RCA COSMAC VIP, optionally limited to 2k RAM
+    /// and optionally set to simulate original COSMAC VIP cycles-per-instruction timings
+    Chip8 {
+        memory_limit_2k: bool,
+        variable_cycle_timing: bool,
+    },
+    /// Re-implemented CHIP-8 interpreter for the HP48 graphing calculators, optionally set to
+    /// pace instructions by approximate HP48 cycles-per-instruction timings (off for options
+    /// saved without the setting)
+    Chip48 {
+        #[serde(default)]
+        variable_cycle_timing: bool,
+    },
+    /// Version 1.0 of the SUPER-CHIP interpreter for HP48 graphing calculators, which added the
+    /// high-resolution mode but not the scroll instructions (00CN, 00FB and 00FC) of version 1.1.
+    /// Unlike 1.1, FX55 and FX65 increment the index register by X (as CHIP-48 does) and DXY0
+    /// draws an 8x16 sprite in low-resolution mode.  Optionally set to pace instructions by
+    /// approximate HP48 cycles-per-instruction timings
+    SuperChip10 { variable_cycle_timing: bool },
+    /// Version 1.1 of the SUPER-CHIP interpreter for HP48S and HP48SX graphing calculators
+    /// Optionally includes OCTO-specific SCHIP instruction quirks, and optionally set to pace
+    /// instructions by approximate HP48 cycles-per-instruction timings (off for options saved
+    /// without the setting)
+    SuperChip11 {
+        octo_compatibility_mode: bool,
+        #[serde(default)]
+        variable_cycle_timing: bool,
+    },
+    /// The CHIP-8X interpreter for the COSMAC VIP fitted with the VP-590 colour board and VP-580
+    /// second keypad, adding instructions to set the colour of zones of the display (BXYN), cycle
+    /// the background colour (02A0), add colour values (5XY1), read the second keypad
+    /// (EXF2/EXF5) and set the tone pitch (FXF8).  Otherwise behaves as CHIP-8, optionally
+    /// simulating original COSMAC VIP cycles-per-instruction timings.  CHIP-8X programs
+    /// conventionally start at address 0x300
+    Chip8X { variable_cycle_timing: bool },
+}
+
+/// An enum to indicate how the processor responds to an opcode it cannot execute: either one that
+/// is not recognised at the configured [EmulationLevel] ([ErrorDetail::UnknownInstruction]), or a
+/// `0NNN` machine code routine call with no
+/// [SystemRoutineHandler](crate::SystemRoutineHandler) registered
+/// ([ErrorDetail::UnimplementedInstruction]).
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum UnknownInstructionPolicy {
+    /// The opcode is passed over and the next instruction is executed in the same cycle, as if
+    /// the opcode were not present
+    Skip,
+    /// The opcode is treated as an instruction that does nothing, taking a cycle to execute
+    Nop,
+    /// The processor crashes, returning the error to the hosting application
+    Crash,
+}
+
+impl Default for UnknownInstructionPolicy {
+    /// Constructor that returns the default [UnknownInstructionPolicy] (crashing, so that
+    /// problems with ROMs or emulation settings are reported)
+    fn default() -> Self {
+        UnknownInstructionPolicy::Crash
+    }
+}
+
+/// An enum to indicate when the FX0A instruction, which waits for a key, registers the key.  Some
+/// ROMs behave differently under each: for example, those that follow FX0A with EX9E may see the
+/// key still held if it is registered on press.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum KeyWaitMode {
+    /// The key is registered once it has been pressed and then released, as on the original
+    /// COSMAC VIP interpreter
+    OnRelease,
+    /// The key is registered as soon as it is pressed, as by many modern interpreters
+    OnPress,
+}
+
+impl Default for KeyWaitMode {
+    /// Constructor that returns the default [KeyWaitMode] (registering on release, as per the
+    /// original CHIP-8 interpreter)
+    fn default() -> Self {
+        KeyWaitMode::OnRelease
+    }
+}
+
+/// An enum to indicate how long each instruction takes to execute, overriding the timing implied
+/// by the [EmulationLevel] (see [Options::timing_mode]).  Timers and the vblank interrupt are
+/// unaffected, so this determines how many instructions execute per frame.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum TimingMode {
+    /// Every instruction takes the specified number of microseconds (at least 1), regardless of
+    /// the processor speed
+    Fixed { instruction_microseconds: u64 },
+    /// Each instruction takes as many machine cycles as on the original interpreter, at the
+    /// configured processor speed: COSMAC VIP cycles in CHIP-8 and CHIP-8X emulation modes, and
+    /// approximate HP48 Saturn processor cycles in CHIP-48 and SUPER-CHIP emulation modes.  This is as
+    /// if the emulation level's `variable_cycle_timing` flag were set.
+    CosmacVariable,
+    /// The specified number of instructions (at least 1) execute in each timer interval, as in
+    /// OCTO, every instruction taking an equal share of the interval regardless of the processor
+    /// speed
+    InstructionsPerFrame { instructions: u64 },
+}
+
+/// An enum used internally within the Chipolata crate to keep track of the processor
+/// execution status.
+#[derive(Debug, Copy, Clone, PartialEq, Deserialize, Serialize)]
+pub enum ProcessorStatus {
+    /// The processor has been instantiated but memory is empty
+    StartingUp,
+    /// The processor has been instantiated and font data loaded
+    Initialised,
+    /// A program has been loaded into the processor's memory
+    ProgramLoaded,
+    /// The program is being executed (the decode->fetch->execute cycle has begun)
+    Running,
+    /// The processor is stalled waiting for a keypress (instruction FX0A)
+    WaitingForKeypress,
+    /// The processor is in an error state, having generated an `Error`
+    Crashed,
+    /// Execution paused (by host)
+    Paused,
+    /// Execution completed (program exited); SUPER-CHIP emulation mode only
+    Completed,
+    /// The program is stuck in an infinite loop that makes no further state changes (such as
+    /// the `1NNN` jump-to-self idiom), so has effectively ended; only reported if
+    /// [Options::halt_detection] is set
+    Halted,
+}
+
+/// An enum used to indicate which variant of [StateSnapshot] should be returned when a call is
+/// made to [Processor::export_state_snapshot()].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StateSnapshotVerbosity {
+    /// Only the frame buffer state will be reported
+    Minimal,
+    /// The frame buffer, registers, stack and memory state will all be reported
+    Extended,
+}
+
+/// An enum with variants representing the different Chipolata state snapshots that can be
+/// returned to hosting applications for processing
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateSnapshot {
+    /// Minimal snapshot containing only the frame buffer state, processor status, and a boolean
+    /// to indicate whether a sound should be playing, along with frame pacing information: the
+    /// number of frames (vblank intervals) elapsed since the program was loaded, the cycles
+    /// executed since the current frame began, and whether the display may have changed since
+    /// the previous minimal snapshot was exported (so hosts can skip repainting unchanged frames),
+    /// and the palette in which to render the display.  The delay and sound timer values are also
+    /// included, for hosts showing them in a HUD or shaping the beep by its remaining duration.
+    MinimalSnapshot {
+        frame_buffer: Display,
+        palette: Palette,
+        status: ProcessorStatus,
+        processor_speed: u64,
+        play_sound: bool,
+        delay_timer: u8,
+        sound_timer: u8,
+        cycles: usize,
+        frames: usize,
+        cycles_since_frame: usize,
+        display_changed: bool,
+    },
+    /// Extended snapshot containing the minimal state along with all registers,
+    /// stack and memory
+    ExtendedSnapshot {
+        frame_buffer: Display,
+        palette: Palette,
+        status: ProcessorStatus,
+        processor_speed: u64,
+        play_sound: bool,
+        cycles: usize,
+        stack: Stack,
+        memory: Memory,
+        program_counter: u16,
+        index_register: u16,
+        variable_registers: [u8; VARIABLE_REGISTER_COUNT],
+        rpl_registers: [u8; RPL_REGISTER_COUNT],
+        delay_timer: u8,
+        sound_timer: u8,
+        high_resolution_mode: bool,
+        emulation_level: EmulationLevel,
+    },
+}
+
+impl StateSnapshot {
+    /// Returns the effective resolution of the snapshot's frame buffer in pixels, as (width,
+    /// height), by which front ends should scale the display
+    pub fn resolution(&self) -> (usize, usize) {
+        match self {
+            StateSnapshot::MinimalSnapshot { frame_buffer, .. }
+            | StateSnapshot::ExtendedSnapshot { frame_buffer, .. } => frame_buffer.get_resolution(),
+        }
+    }
+}
+
+/// A borrowed, read-only view of the full processor state, as returned by
+/// [Processor::state_view()].
+///
+/// This holds the same information as [StateSnapshot::ExtendedSnapshot] but references the
+/// processor's [Display], [Stack] and [Memory] rather than copying them, so is cheap enough to
+/// obtain on every frame (for example by a debugger UI).  The processor cannot execute cycles
+/// while a view is held; use [StateView::to_snapshot()] to retain an owned copy.
+#[derive(Debug, Copy, Clone)]
+pub struct StateView<'a> {
+    pub frame_buffer: &'a Display,
+    pub palette: Palette,
+    pub status: ProcessorStatus,
+    pub processor_speed: u64,
+    pub play_sound: bool,
+    pub cycles: usize,
+    pub stack: &'a Stack,
+    pub memory: &'a Memory,
+    pub program_counter: u16,
+    pub index_register: u16,
+    pub variable_registers: &'a [u8; VARIABLE_REGISTER_COUNT],
+    pub rpl_registers: &'a [u8; RPL_REGISTER_COUNT],
+    pub delay_timer: u8,
+    pub sound_timer: u8,
+    pub high_resolution_mode: bool,
+    pub emulation_level: EmulationLevel,
+}
+
+impl<'a> StateView<'a> {
+    /// Returns an owned [StateSnapshot::ExtendedSnapshot] copy of the viewed state
+    pub fn to_snapshot(&self) -> StateSnapshot {
+        StateSnapshot::ExtendedSnapshot {
+            frame_buffer: self.frame_buffer.clone(),
+            palette: self.palette,
+            status: self.status,
+            processor_speed: self.processor_speed,
+            play_sound: self.play_sound,
+            cycles: self.cycles,
+            stack: self.stack.clone(),
+            memory: self.memory.clone(),
+            program_counter: self.program_counter,
+            index_register: self.index_register,
+            variable_registers: *self.variable_registers,
+            rpl_registers: *self.rpl_registers,
+            delay_timer: self.delay_timer,
+            sound_timer: self.sound_timer,
+            high_resolution_mode: self.high_resolution_mode,
+            emulation_level: self.emulation_level,
+        }
+    }
+}
+
+/// The type of the callback that may be registered through [Processor::set_pre_execute_hook()]
+pub type PreExecuteHook = Box<dyn FnMut(&Processor, &Instruction) + Send>;
+
+/// An enum used to keep track of the state of the vertical blank interrupt, for accurate display
+/// emulation in CHIP-8 mode (and, optionally, CHIP-48 and SUPER-CHIP 1.1 low resolution mode)
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum VBlankStatus {
+    /// No display instruction has been processed yet this frame
+    Idle,
+    /// A display instruction is queued, awaiting v-blank interrupt
+    WaitingForVBlank,
+    /// THe v-blank interrupt has been set; drawing can proceed
+    ReadyToDraw,
+}
+
+/// The state set aside while execution is paused, from which it is resumed exactly as if no pause
+/// had occurred: whether an FX0A key wait was in progress (so that it carries on waiting, rather
+/// than starting afresh), and how far each wall clock had advanced through its interval
+#[derive(Debug, Copy, Clone, Default)]
+struct Suspension {
+    waiting_for_keypress: bool,
+    since_timer_decrement: Duration,
+    since_vblank_interrupt: Duration,
+    since_execution_cycle_complete: Duration,
+    cycle_deadline_ahead: Duration,
+    cycle_deadline_behind: Duration,
+}
+
+/// An abstraction of the CHIP-8 processor, and the core public interface to the Chipolata crate.
+///
+/// This struct holds representations of all CHIP-8 sub-components, and exposes methods through which
+/// a program can be loaded to memory and executed one cycle at a time, as well as methods for
+/// supplying input to the processor (in the form of keypresses) and output to the host application
+/// (in the form of a bitmapped display).
+pub struct Processor {
+    // CHIP-8 COMPONENT STATE FIELDS
+    frame_buffer: Display, // The display frame buffer
+    stack: Stack,          // The call stack (holds return addresses for subroutines)
+    memory: Memory,        // The system memory
+    program_counter: u16, // The program counter register (points to next opcode location in memory)
+    index_register: u16,  // The index counter register (used to point to memory addresses)
+    variable_registers: [u8; VARIABLE_REGISTER_COUNT], // General purposes registers
+    rpl_registers: [u8; RPL_REGISTER_COUNT], // SUPER-CHIP 1.1 emulation mode only; RPL registers
+    delay_timer: u8,      // Delay timer, decrements automatically at 60hz when non-zero
+    sound_timer: u8,      // Sounds timer, decrements automatically at 60hz when non-zero
+    cycles: usize,        // The number of processor cycles that have been executed
+    execution_context: ExecutionContext, // The opcode most recently fetched, reported with errors
+    trace: VecDeque<ExecutionContext>, // The most recently fetched opcodes, for crash dumps
+    instruction_cache: InstructionCache, // The instructions decoded from each memory address
+    high_resolution_mode: bool, // SUPER-CHIP 1.1 emulation mode only; true when when in high-res mode
+    // ADDITIONAL STATE FIELDS
+    keystate: KeyState, // A representation of the state (pressed/not pressed) of each key
+    second_keystate: KeyState, // CHIP-8X emulation mode only; the state of the second keypad
+    waiting_original_keystate: KeyState, // Keystate as at the start of an FX0A instruction
+    keys_pressed_since_wait: Vec<u8>, // Keys pressed (but not released) during FX0A wait
+    status: ProcessorStatus, // The current execution status of the processor
+    last_timer_decrement: Instant, //  The moment the delay and sound timers were last decremented
+    last_execution_cycle_complete: Instant, // The moment the execute cycle was last completed
+    cycle_deadline: Instant, // The moment by which the cycles executed should have completed
+    last_vblank_interrupt: Instant, // Display wait only; the last vblank interrupt time
+    clock: Box<dyn Clock>, // The time source by which the instants above are measured
+    vblank_status: VBlankStatus, // Display wait only; state of v-blank interrupt
+    suspension: Option<Suspension>, // The timing and wait state set aside while paused
+    rng: StdRng,        // Seeded random number generator (used by CXNN and timings)
+    rng_seed: u64,      // The seed from which the random number generator was created
+    input_recording: Option<InputRecording>, // Key events captured while recording is active
+    input_replay: Option<InputRecording>, // Recorded key events being replayed, if any
+    input_replay_position: usize, // Index of the next recorded key event to replay
+    pending_key_events: Vec<(usize, Key, bool)>, // Batched key events awaiting their due cycle
+    key_input_unseen: bool, // True if key input has been received since the last cycle executed
+    turbo_mode: bool, // When true, cycles are executed as fast as possible (speed is not throttled)
+    cycle_based_timing: bool, // When true, timers and vblank are driven by cycle count, not wall clock
+    timer_phase_emulation: bool, // When true, the timer interrupt runs continuously at a fixed phase
+    timer_interval_microseconds: u128, // The time between timer decrements (and vblank interrupts)
+    pre_execute_hook: Option<PreExecuteHook>, // Host callback invoked before each instruction executes
+    system_routine_handler: Option<Box<dyn SystemRoutineHandler>>, // Host emulation of 0NNN routines
+    sound_state: SoundState, // The host sound sink, notified when sound starts or stops
+    frame_sink: FrameSinkState, // The host frame sink, pushed each frame in which the display changed
+    memory_profile: Option<MemoryProfile>, // Per-address access counts, if profiling is enabled
+    watches: Vec<RegisteredWatch>, // Watch expressions evaluated after each instruction
+    next_watch_id: usize,       // The identifier to give the next watch registered
+    break_watch_id: Option<usize>, // The watch that most recently paused execution, if any
+    // CONFIG AND SETUP FIELDS
+    low_resolution_font: Font, // The font loaded into the processor (only used during initialisation)
+    high_resolution_font: Option<Font>, // SUPER-CHIP 1.1 emulation mode only; the high resolution font data
+    program: Program, // The program loaded into the processor (used on reset and in crash dumps)
+    font_start_address: usize, // The start address in memory at which the font is loaded
+    high_resolution_font_start_address: usize, // SUPER-CHIP 1.1 emulation mode only
+    program_start_address: usize, // The start address in memory at which the program is loaded
+    processor_speed_hertz: u64, // Used to calculate the time between execute cycles
+    emulation_level: EmulationLevel, // Component and instruction-compatibility configuration
+    sprite_edge_mode: SpriteEdgeMode, // Whether sprites overflowing the display clip or wrap
+    configured_rng_seed: Option<u64>, // The random seed specified at start-up, if any
+    stack_depth_override: Option<usize>, // The stack depth specified at start-up, if any
+    unknown_instruction_policy: UnknownInstructionPolicy, // Response to unexecutable opcodes
+    chip8_hires_mode: bool, // CHIP-8 emulation mode only; emulate the Hi-Res CHIP-8 interpreter
+    lowres_display_wait: bool, // CHIP-48 / SUPER-CHIP 1.1 only; DXYN waits for vblank in low-res
+    accurate_display_timing: bool, // CHIP-8 / CHIP-8X only; DXYN waits for the raster, not vblank
+    vip_random_generator: bool, // CHIP-8 / CHIP-8X only; CXNN uses the VIP interpreter's routine
+    vip_random_register: u8, // The high byte of the VIP's R9, accumulated by its random routine
+    sprites_per_frame: Option<usize>, // Sprite draw budget per frame, if any
+    sprites_drawn_this_frame: usize, // Sprites drawn since the last vblank interrupt
+    frames: usize, // The number of frames (vblank intervals) elapsed since the program was loaded
+    frame_start_cycle: usize, // The cycle count at the start of the current frame
+    display_changed: Cell<bool>, // The display may have changed since the last snapshot export
+    timing_recorder: TimingRecorder, // Wall-clock timing measurements, for timing statistics
+    interpreter_image: Option<Vec<u8>>, // Host-supplied image of memory below the program
+    protect_reserved_memory: bool, // Whether programs writing below the program start crash
+    key_repeat_suppression: bool, // Whether EX9E/EXA1 release a key found to be pressed
+    key_wait_mode: KeyWaitMode, // Whether FX0A registers a key on press or on release
+    timing_mode: Option<TimingMode>, // Overrides the instruction timing of the emulation level
+    halt_detection: bool, // Whether programs stuck in an infinite loop are reported as halted
+    halt_watch: HaltWatch, // The loop state used to detect that the program has halted
+    resize_low_resolution_display: bool, // SUPER-CHIP 1.1 only; the display is 64x32 in low-res
+    options: Options, // The options in effect, against which changes are checked
+}
+
+impl Processor {
+    /// Constructor/builder function that returns a freshly-initialised [Processor] instance
+    /// with the supplied program data loaded into memory ready for execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - a [Program] instance holding the bytes of the ROM to be executed
+    /// * `options` - an [Options] instance holding Chipolata start-up configuration information
+    pub fn initialise_and_load(program: Program, options: Options) -> Result<Self, ChipolataError> {
+        let (low_res_font, high_res_font): (Font, Option<Font>) =
+            Processor::initial_fonts(&options);
+        let clock: Box<dyn Clock> = default_clock();
+        let now: Instant = Instant::now(clock.as_ref());
+        // Use the seed specified in the options if present, otherwise pick one at random
+        let rng_seed: u64 = options
+            .rng_seed
+            .unwrap_or_else(|| Processor::random_seed(clock.as_ref()));
+        let mut rng: StdRng = StdRng::seed_from_u64(rng_seed);
+        // Hi-Res CHIP-8 emulation only applies to CHIP-8 emulation mode
+        let chip8_hires_mode: bool = match options.emulation_level {
+            EmulationLevel::Chip8 { .. } => options.chip8_hires_mode,
+            _ => false,
+        };
+        let mut processor = Processor {
+            frame_buffer: Processor::initial_display(
+                options.emulation_level,
+                chip8_hires_mode,
+                options.resize_low_resolution_display,
+                options.ghost_trail_decay.is_some(),
+            ),
+            stack: Stack::new(options.emulation_level, options.stack_depth_override),
+            memory: Memory::new(options.emulation_level, &mut rng),
+            program_counter: options.program_start_address,
+            index_register: 0x0,
+            variable_registers: [0x0; VARIABLE_REGISTER_COUNT],
+            rpl_registers: [0x0; RPL_REGISTER_COUNT],
+            delay_timer: 0x0,
+            sound_timer: 0x0,
+            cycles: 0,
+            execution_context: ExecutionContext::default(),
+            trace: VecDeque::with_capacity(CRASH_DUMP_TRACE_LENGTH),
+            instruction_cache: InstructionCache::new(CHIPOLATA_MEMORY_SIZE_BYTES),
+            high_resolution_mode: false,
+            keystate: KeyState::new(),
+            second_keystate: KeyState::new(),
+            waiting_original_keystate: KeyState::new(),
+            keys_pressed_since_wait: Vec::new(),
+            status: ProcessorStatus::StartingUp,
+            last_timer_decrement: now,
+            last_execution_cycle_complete: now,
+            cycle_deadline: now,
+            last_vblank_interrupt: now,
+            clock,
+            vblank_status: VBlankStatus::Idle,
+            suspension: None,
+            rng,
+            rng_seed,
+            input_recording: None,
+            input_replay: None,
+            input_replay_position: 0,
+            pending_key_events: Vec::new(),
+            key_input_unseen: false,
+            turbo_mode: false,
+            cycle_based_timing: options.cycle_synced_timers,
+            timer_phase_emulation: options.timer_phase_emulation,
+            timer_interval_microseconds: 1_000_000
+                / options
+                    .timer_frequency_hertz
+                    .clamp(1, MAX_TIMER_FREQUENCY_HERTZ) as u128,
+            pre_execute_hook: None,
+            system_routine_handler: None,
+            sound_state: SoundState::default(),
+            frame_sink: FrameSinkState::default(),
+            memory_profile: None,
+            watches: Vec::new(),
+            next_watch_id: 0,
+            break_watch_id: None,
+            low_resolution_font: low_res_font,
+            high_resolution_font: high_res_font,
+            program: program,
+            font_start_address: options.font_start_address as usize,
+            high_resolution_font_start_address: 0x0,
+            program_start_address: options.program_start_address as usize,
+            processor_speed_hertz: options.processor_speed_hertz,
+            emulation_level: options.emulation_level,
+            sprite_edge_mode: options.sprite_edge_mode,
+            configured_rng_seed: options.rng_seed,
+            stack_depth_override: options.stack_depth_override,
+            unknown_instruction_policy: options.on_unknown_instruction,
+            chip8_hires_mode,
+            lowres_display_wait: options.lowres_display_wait,
+            accurate_display_timing: options.accurate_display_timing,
+            vip_random_generator: options.vip_random_generator,
+            vip_random_register: 0,
+            sprites_per_frame: options.sprites_per_frame,
+            sprites_drawn_this_frame: 0,
+            frames: 0,
+            frame_start_cycle: 0,
+            display_changed: Cell::new(true),
+            timing_recorder: TimingRecorder::default(),
+            interpreter_image: None,
+            protect_reserved_memory: options.protect_reserved_memory,
+            key_repeat_suppression: options.key_repeat_suppression,
+            key_wait_mode: options.key_wait_mode,
+            timing_mode: options.timing_mode,
+            halt_detection: options.halt_detection,
+            halt_watch: HaltWatch::default(),
+            resize_low_resolution_display: options.resize_low_resolution_display,
+            options,
+        };
+        // Reject a stack depth override outside the supported range
+        if let Some(depth) = options.stack_depth_override {
+            if depth == 0 || depth > MAX_STACK_DEPTH {
+                return Err(processor.crash(ErrorDetail::OperandsOutOfBounds {
+                    operands: vec![Operand::StackDepthOverride(depth)],
+                }));
+            }
+        }
+        // Likewise reject any out-of-range settings that could otherwise be changed at runtime
+        if let Err(e) = Processor::check_runtime_options(&options) {
+            return Err(processor.crash(e));
+        }
+        if let Err(e) = processor.load_font_data() {
+            return Err(processor.crash(e));
+        }
+        processor.set_status(ProcessorStatus::Initialised);
+        if let Err(e) = processor.load_program() {
+            return Err(processor.crash(e));
+        }
+        processor.set_memory_layout();
+        processor.program_counter = processor.initial_program_counter();
+        processor.set_status(ProcessorStatus::ProgramLoaded);
+        Ok(processor)
+    }
+
+    /// Helper function that returns the low-resolution font, and high-resolution font (if any),
+    /// that a processor initialised with the specified options loads into memory
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - the options with which the processor is initialised
+    pub(crate) fn initial_fonts(options: &Options) -> (Font, Option<Font>) {
+        // Any custom font takes the place of the built-in font
+        let low_res_font: Font = match (&options.custom_low_res_font, options.emulation_level) {
+            (Some(font), _) => Font::custom(font),
+            (None, EmulationLevel::Chip8 { .. }) if options.vip_memory_image => {
+                Font::cosmac_vip_low_resolution()
+            }
+            _ => Font::default_low_resolution(),
+        };
+        let high_res_font: Option<Font> =
+            match (&options.custom_high_res_font, options.emulation_level) {
+                (
+                    Some(font),
+                    EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. },
+                ) => Some(Font::custom(font)),
+                (
+                    None,
+                    EmulationLevel::SuperChip11 {
+                        octo_compatibility_mode: true,
+                        variable_cycle_timing: _,
+                    },
+                ) => Some(Font::octo_high_resolution()),
+                (
+                    None,
+                    EmulationLevel::SuperChip10 { .. }
+                    | EmulationLevel::SuperChip11 {
+                        octo_compatibility_mode: false,
+                        variable_cycle_timing: _,
+                    },
+                ) => Some(Font::default_high_resolution()),
+                _ => None,
+            };
+        (low_res_font, high_res_font)
+    }
+
+    /// Helper function that checks the settings which may be changed at runtime via
+    /// [Processor::apply_options()], returning [ErrorDetail::OperandsOutOfBounds] for any
+    /// outside their supported ranges
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - the options to check
+    fn check_runtime_options(options: &Options) -> Result<(), ErrorDetail> {
+        // Reject a sprite budget of zero, under which nothing could ever be drawn
+        if options.sprites_per_frame == Some(0) {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::SpritesPerFrame(0)],
+            });
+        }
+        // Reject a ghost trail decay of zero, under which erased pixels would never fade
+        if options.ghost_trail_decay == Some(0) {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::GhostTrailDecay(0)],
+            });
+        }
+        // Reject a timer frequency outside the supported range
+        if options.timer_frequency_hertz == 0
+            || options.timer_frequency_hertz > MAX_TIMER_FREQUENCY_HERTZ
+        {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::TimerFrequencyHertz(options.timer_frequency_hertz)],
+            });
+        }
+        // Reject a timing mode under which instructions would take no time
+        if let Some(
+            TimingMode::Fixed {
+                instruction_microseconds: 0,
+            }
+            | TimingMode::InstructionsPerFrame { instructions: 0 },
+        ) = options.timing_mode
+        {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::TimingMode],
+            });
+        }
+        Ok(())
+    }
+
+    /// Applies changed [Options] without re-initialising the processor, so that settings such as
+    /// the processor speed, timer frequency, sprite edge mode and display wait can be changed
+    /// mid-run.  Options which determine the structure of the emulated machine (see
+    /// [Options::restart_required_by()]) can only be set at initialisation; if any of these
+    /// differ from those in effect, [ErrorDetail::OptionNotChangeable] is returned.  As they are
+    /// applied by the host when rendering, [Options::display_orientation] and [Options::palette]
+    /// are simply recorded (the palette being reported in each [StateSnapshot]).
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - the options to apply
+    pub fn apply_options(&mut self, options: &Options) -> Result<(), ChipolataError> {
+        if let Some(option) = self.options.restart_required_by(options) {
+            return Err(self.crash(ErrorDetail::OptionNotChangeable {
+                option: option.to_string(),
+            }));
+        }
+        if let Err(e) = Processor::check_runtime_options(options) {
+            return Err(self.crash(e));
+        }
+        if options.cycle_synced_timers != self.cycle_based_timing {
+            // Timers and vblank interrupts moving to the wall clock count their intervals from
+            // now, and neither timing basis is measured across the switch
+            self.last_timer_decrement = self.now();
+            self.last_vblank_interrupt = self.now();
+            if let Some(suspension) = &mut self.suspension {
+                suspension.since_timer_decrement = Duration::ZERO;
+                suspension.since_vblank_interrupt = Duration::ZERO;
+            }
+            self.timing_recorder.interrupt();
+        }
+        self.processor_speed_hertz = options.processor_speed_hertz;
+        self.sprite_edge_mode = options.sprite_edge_mode;
+        self.unknown_instruction_policy = options.on_unknown_instruction;
+        self.timer_interval_microseconds = 1_000_000 / options.timer_frequency_hertz as u128;
+        self.cycle_based_timing = options.cycle_synced_timers;
+        self.timer_phase_emulation = options.timer_phase_emulation;
+        if options.accurate_display_timing != self.accurate_display_timing {
+            // A draw waiting for the vblank interrupt re-evaluates how long it must wait under
+            // the new display timing model
+            self.vblank_status = VBlankStatus::Idle;
+        }
+        self.lowres_display_wait = options.lowres_display_wait;
+        self.accurate_display_timing = options.accurate_display_timing;
+        self.vip_random_generator = options.vip_random_generator;
+        self.sprites_per_frame = options.sprites_per_frame;
+        self.protect_reserved_memory = options.protect_reserved_memory;
+        self.key_repeat_suppression = options.key_repeat_suppression;
+        self.key_wait_mode = options.key_wait_mode;
+        self.timing_mode = options.timing_mode;
+        self.halt_detection = options.halt_detection;
+        self.halt_watch.clear();
+        self.memory
+            .set_reserved_write_protection(options.protect_reserved_memory);
+        self.frame_buffer
+            .track_intensities(options.ghost_trail_decay.is_some());
+        self.options = *options;
+        Ok(())
+    }
+
+    /// Switches SUPER-CHIP high-resolution mode on or off, as the 00FF and 00FE instructions do
+    /// (though without clearing the display, as these do in OCTO compatibility mode).  If
+    /// [Options::resize_low_resolution_display] is set, the display is resized accordingly, its
+    /// contents being rescaled.  Returns [ErrorDetail::UnknownInstruction] for the corresponding
+    /// instruction if not emulating SUPER-CHIP 1.0 or 1.1.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to enter high-resolution mode, false to enter low-resolution mode
+    pub fn set_high_resolution_mode(&mut self, enabled: bool) -> Result<(), ChipolataError> {
+        if !matches!(
+            self.emulation_level,
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. }
+        ) {
+            return Err(self.crash(ErrorDetail::UnknownInstruction {
+                opcode: match enabled {
+                    true => 0x00FF,
+                    false => 0x00FE,
+                },
+            }));
+        }
+        self.set_resolution_mode(enabled);
+        Ok(())
+    }
+
+    /// Sets the current processor speed in hertz
+    ///
+    /// # Arguments
+    ///
+    /// * `speed_hertz` - the new processor speed
+    pub fn set_processor_speed(&mut self, speed_hertz: u64) {
+        self.processor_speed_hertz = speed_hertz;
+    }
+
+    /// Returns the current processor speed in hertz
+    pub fn processor_speed(&self) -> u64 {
+        self.processor_speed_hertz
+    }
+
+    /// Enables or disables turbo mode.  While in turbo mode each call to
+    /// [Processor::execute_cycle()] returns as soon as the cycle has executed, ignoring the
+    /// processor speed (and any variable cycle timing) that would otherwise throttle execution.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to enable turbo mode, false to return to normal speed
+    pub fn set_turbo_mode(&mut self, enabled: bool) {
+        self.turbo_mode = enabled;
+    }
+
+    /// Returns true if turbo mode is enabled
+    pub fn turbo_mode(&self) -> bool {
+        self.turbo_mode
+    }
+
+    /// Enables or disables cycle-based timing, as initially set by
+    /// [Options::cycle_synced_timers] (the headless runner always enables this so that execution
+    /// is fully deterministic).  While enabled, the delay and sound timers and the CHIP-8 vblank
+    /// interrupt tick once every timer interval's worth of cycles at the configured processor
+    /// speed, rather than once every timer interval of wall-clock time.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to enable cycle-based timing, false to return to wall-clock timing
+    pub(crate) fn set_cycle_based_timing(&mut self, enabled: bool) {
+        self.cycle_based_timing = enabled;
+    }
+
+    /// Returns [TimingStatistics] comparing the wall-clock timing achieved since the program was
+    /// loaded (or last reset) with that configured: the average cycle duration against its target
+    /// at the configured processor speed, the cumulative drift between the two, and the accuracy
+    /// of the intervals between timer decrements and between vblank interrupts.  Only wall-clock
+    /// timing is measured, so cycles executed in turbo mode or with cycle-based timing are not.
+    pub fn timing_statistics(&self) -> TimingStatistics {
+        self.timing_recorder.statistics()
+    }
+
+    /// Executes up to the specified number of cycles as fast as possible (in turbo mode), and
+    /// returns a [BenchmarkReport] giving the number of cycles executed and the time taken.  Turbo
+    /// mode is restored to its previous setting afterwards.  Fewer cycles are executed if the
+    /// program exits (SUPER-CHIP 1.1 emulation mode only) or halts, or the processor is paused.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle_count` - the number of cycles to execute
+    pub fn run_benchmark(&mut self, cycle_count: usize) -> Result<BenchmarkReport, ChipolataError> {
+        let previous_turbo_mode: bool = self.turbo_mode;
+        self.turbo_mode = true;
+        let starting_cycles: usize = self.cycles;
+        let start_time: Instant = self.now();
+        for _ in 0..cycle_count {
+            if let ProcessorStatus::Completed | ProcessorStatus::Halted | ProcessorStatus::Paused =
+                self.status
+            {
+                break;
+            }
+            if let Err(e) = self.execute_cycle() {
+                self.turbo_mode = previous_turbo_mode;
+                return Err(e);
+            }
+        }
+        let duration: Duration = self.elapsed(start_time);
+        self.turbo_mode = previous_turbo_mode;
+        Ok(BenchmarkReport {
+            cycles_executed: self.cycles - starting_cycles,
+            duration,
+        })
+    }
+
+    /// Returns the wall-clock duration of one frame i.e. of one timer interval at the configured
+    /// timer frequency (1/60th of a second by default), so hosts pacing execution with
+    /// [Processor::run_frames()] know how often to call it
+    pub fn frame_duration(&self) -> Duration {
+        Duration::from_micros(self.timer_interval_microseconds as u64)
+    }
+
+    /// Returns the progress through the current timer interval, from 0.0 (the timers have just
+    /// ticked) up to but excluding 1.0 (the timers are about to tick).  With wall-clock timing this
+    /// is measured from the last tick, which (unless [Options::timer_phase_emulation] is set) only
+    /// occurs while a timer is running; with cycle-synced timers it is measured in cycles.
+    pub fn timer_phase(&self) -> f64 {
+        let phase: f64 = match self.cycle_based_timing {
+            true => {
+                let interval_cycles: u128 = self.interval_cycles(self.timer_interval_microseconds);
+                (self.cycles as u128 % interval_cycles) as f64 / interval_cycles as f64
+            }
+            false => {
+                // The phase is frozen while paused
+                let elapsed: Duration = match &self.suspension {
+                    Some(suspension) => suspension.since_timer_decrement,
+                    None => self.elapsed(self.last_timer_decrement),
+                };
+                elapsed.as_micros() as f64 / self.timer_interval_microseconds as f64
+            }
+        };
+        phase.min(1.0 - f64::EPSILON)
+    }
+
+    /// Returns how long the host may sleep instead of executing cycles, if the processor is idle
+    /// waiting for a key press (FX0A) with no key input yet unseen by the instruction.  Executing
+    /// FX0A repeatedly while waiting only polls the key state, so a host may instead sleep until
+    /// either key input arrives or this duration elapses, whichever comes first; the duration
+    /// runs until the next vblank interrupt or (while the timers are running) the next timer
+    /// tick is due, so that timers keep counting down at the configured frequency.  Returns
+    /// `None` if cycles should be executed as normal, including while a recording is being
+    /// replayed, while batched key events are pending, and with cycle-synced timers (where
+    /// sleeping would not advance the timers).
+    pub fn idle_duration(&self) -> Option<Duration> {
+        if self.status != ProcessorStatus::WaitingForKeypress
+            || self.key_input_unseen
+            || !self.pending_key_events.is_empty()
+            || self.input_replay.is_some()
+            || self.cycle_based_timing
+        {
+            return None;
+        }
+        let interval: Duration = self.frame_duration();
+        let mut wake: Instant = self.last_vblank_interrupt + interval;
+        if (self.delay_timer | self.sound_timer) > 0x0 || self.timer_phase_emulation {
+            wake = wake.min(self.last_timer_decrement + interval);
+        }
+        match wake.saturating_duration_since(self.now()) {
+            Duration::ZERO => None,
+            duration => Some(duration),
+        }
+    }
+
+    /// Executes cycles as fast as possible (in turbo mode) until the delay and sound timers have
+    /// ticked the specified number of times, returning whether the display was updated.  For the
+    /// duration of the call, timers (and the CHIP-8 vblank interrupt) are driven by the cycle
+    /// count, so that each frame is exactly one timer interval's worth of cycles at the configured
+    /// processor speed; the first frame runs only up to the next timer tick, so successive calls
+    /// stay aligned with the timer.  Turbo mode and timing are restored to their previous settings
+    /// afterwards.  If the processor is paused, it is resumed for the duration of the call and
+    /// then paused again, allowing a debugger to advance one frame at a time.  Fewer frames are
+    /// executed if the program exits (SUPER-CHIP 1.1 emulation mode only) or halts, or execution
+    /// is paused by a watch expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - the number of frames to execute
+    pub fn run_frames(&mut self, frames: usize) -> Result<bool, ChipolataError> {
+        let previous_turbo_mode: bool = self.turbo_mode;
+        let previous_cycle_based_timing: bool = self.cycle_based_timing;
+        let was_paused: bool = self.status == ProcessorStatus::Paused;
+        if was_paused {
+            self.set_status(self.resumed_status());
+        }
+        self.turbo_mode = true;
+        self.cycle_based_timing = true;
+        let frame_cycles: usize = self.interval_cycles(self.timer_interval_microseconds) as usize;
+        let target_cycles: usize = (self.cycles / frame_cycles + frames) * frame_cycles;
+        let mut display_updated: bool = false;
+        let mut result: Result<(), ChipolataError> = Ok(());
+        while self.cycles < target_cycles {
+            if let ProcessorStatus::Completed | ProcessorStatus::Halted | ProcessorStatus::Paused =
+                self.status
+            {
+                break;
+            }
+            match self.execute_cycle() {
+                Ok(updated) => display_updated |= updated,
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        self.turbo_mode = previous_turbo_mode;
+        self.cycle_based_timing = previous_cycle_based_timing;
+        self.timing_recorder.interrupt();
+        result?;
+        if was_paused {
+            if let ProcessorStatus::Running | ProcessorStatus::WaitingForKeypress = self.status {
+                self.set_status(ProcessorStatus::Paused);
+            }
+        }
+        Ok(display_updated)
+    }
+
+    /// Registers a callback to be invoked immediately before each instruction is executed, replacing
+    /// any previously registered callback.  The callback receives a reference to the processor (so
+    /// can inspect its state, for example through [Processor::export_state_snapshot()]) and to the
+    /// decoded [Instruction] about to be executed.  Note that when the callback is invoked the
+    /// program counter has already been advanced past the instruction's opcode.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - the callback function or closure
+    pub fn set_pre_execute_hook(
+        &mut self,
+        hook: impl FnMut(&Processor, &Instruction) + Send + 'static,
+    ) {
+        self.pre_execute_hook = Some(Box::new(hook));
+    }
+
+    /// Removes any callback registered through [Processor::set_pre_execute_hook()]
+    pub fn clear_pre_execute_hook(&mut self) {
+        self.pre_execute_hook = None;
+    }
+
+    /// Registers a [SystemRoutineHandler] to be called whenever a 0NNN instruction is executed,
+    /// replacing any previously registered handler.  Without a handler, 0NNN instructions return
+    /// [ErrorDetail::UnimplementedInstruction].
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - the handler that emulates the machine code routines called by 0NNN
+    pub fn set_system_routine_handler(&mut self, handler: impl SystemRoutineHandler + 'static) {
+        self.system_routine_handler = Some(Box::new(handler));
+    }
+
+    /// Removes any handler registered through [Processor::set_system_routine_handler()]
+    pub fn clear_system_routine_handler(&mut self) {
+        self.system_routine_handler = None;
+    }
+
+    /// Replaces the [Clock] by which the processor measures wall-clock time and through which it
+    /// waits to pace execution (by default a [SystemClock](crate::SystemClock) with the `std`
+    /// feature, and a [VirtualClock](crate::VirtualClock) without it).  The timers, the vblank
+    /// interrupt and the pacing of cycles carry on from the point through their intervals they
+    /// had reached on the previous clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - the clock to use from now on
+    pub fn set_clock(&mut self, clock: impl Clock + 'static) {
+        match self.suspension {
+            // The clocks are resumed from the suspension when execution resumes
+            Some(_) => self.clock = Box::new(clock),
+            None => {
+                let suspension: Suspension = self.suspend_clocks(self.status);
+                self.clock = Box::new(clock);
+                self.resume_clocks(&suspension);
+            }
+        }
+        self.timing_recorder.interrupt();
+    }
+
+    /// Registers a callback to be invoked whenever the sound timer transitions between zero and
+    /// non-zero, replacing any previously registered callback.  The callback receives a
+    /// [SoundEvent] indicating whether sound should now start or stop, and the cycle at which the
+    /// transition occurred, allowing hosting applications to schedule audio precisely rather than
+    /// polling [Processor::sound_timer_active()].  Sound is assumed to be stopped at the point the
+    /// callback is registered, so if the sound timer is already active a start event will fire at
+    /// the end of the next cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - the callback function or closure
+    pub fn set_audio_callback(&mut self, callback: impl FnMut(SoundEvent) + Send + 'static) {
+        self.sound_state.set_callback(Box::new(callback));
+    }
+
+    /// Removes any callback registered through [Processor::set_audio_callback()] (or sink
+    /// registered through [Processor::set_sound_sink()])
+    pub fn clear_audio_callback(&mut self) {
+        self.sound_state.clear_sink();
+    }
+
+    /// Registers a [SoundSink] to be notified whenever the sound timer transitions between zero
+    /// and non-zero, replacing any previously registered sink (or audio callback).  As with
+    /// [Processor::set_audio_callback()], sound is assumed to be stopped at the point the sink is
+    /// registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - the sound sink, for example a host audio backend or an [std::sync::mpsc::Sender]
+    pub fn set_sound_sink(&mut self, sink: impl SoundSink + Send + 'static) {
+        self.sound_state.set_sink(Box::new(sink));
+    }
+
+    /// Removes any sink registered through [Processor::set_sound_sink()] (or callback registered
+    /// through [Processor::set_audio_callback()])
+    pub fn clear_sound_sink(&mut self) {
+        self.sound_state.clear_sink();
+    }
+
+    /// Registers a [FrameSink] to which the frame buffer is pushed at the end of each frame (on
+    /// the vblank interrupt) in which the display changed, replacing any previously registered
+    /// sink.  The first frame pushed to the sink is reported as wholly damaged.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - the frame sink, for example a driver for an embedded display
+    pub fn set_frame_sink(&mut self, sink: impl FrameSink + Send + 'static) {
+        self.frame_sink.set_sink(Box::new(sink));
+    }
+
+    /// Removes any sink registered through [Processor::set_frame_sink()]
+    pub fn clear_frame_sink(&mut self) {
+        self.frame_sink.clear_sink();
+    }
+
+    /// Pushes the frame buffer to the sink registered through [Processor::set_frame_sink()]
+    /// straight away, if it has changed since the frame last pushed, rather than at the end of
+    /// the frame.  Useful after changing the display while execution is paused (for example by
+    /// restoring a save state), when no vblank interrupt occurs.
+    pub fn present_frame(&mut self) {
+        self.frame_sink.present(&self.frame_buffer);
+    }
+
+    /// Enables or disables memory access profiling.  While enabled, the processor counts the
+    /// reads, writes and instruction fetches made at each memory address, which may be retrieved
+    /// through [Processor::export_memory_profile()].  Enabling profiling starts from zero counts
+    /// (even if it was already enabled), and disabling it discards the counts gathered.
+    /// Profiling is disabled by default, as counting accesses adds a small overhead to each cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to enable profiling, false to disable it
+    pub fn set_memory_profiling(&mut self, enabled: bool) {
+        self.memory_profile = match enabled {
+            true => Some(MemoryProfile::new()),
+            false => None,
+        };
+    }
+
+    /// Returns a copy of the [MemoryProfile] gathered since profiling was enabled (or the
+    /// processor was last reset), or None if profiling is not enabled
+    pub fn export_memory_profile(&self) -> Option<MemoryProfile> {
+        self.memory_profile.clone()
+    }
+
+    /// Restores the processor to its state immediately after the program was loaded, clearing the
+    /// registers, timers, stack, display and keypad state, re-initialising memory (reloading the
+    /// font and program) and resetting the program counter.  The loaded program and start-up
+    /// [Options] are retained, so no program data need be re-read; the current processor speed,
+    /// turbo mode setting, and any registered hooks, handlers and callbacks are also retained,
+    /// whereas any input recording or replay is ended.  The random number generator is re-seeded,
+    /// using the seed specified in the [Options] if present, otherwise a new random seed.  Memory
+    /// profiling remains enabled if it was, but its counts are cleared, and likewise any watch
+    /// expressions remain registered but their results are cleared (as do any stack events and
+    /// call tree being recorded).  If sound was playing,
+    /// the audio callback is informed that it has stopped.  May be called in any processor
+    /// status (including after a crash or program exit).
+    pub fn reset(&mut self) -> Result<(), ChipolataError> {
+        self.set_status(ProcessorStatus::StartingUp);
+        self.frame_buffer = Processor::initial_display(
+            self.emulation_level,
+            self.chip8_hires_mode,
+            self.resize_low_resolution_display,
+            self.options.ghost_trail_decay.is_some(),
+        );
+        let mut stack: Stack = Stack::new(self.emulation_level, self.stack_depth_override);
+        if self.stack.is_recording_events() {
+            stack.set_event_recording(true);
+        }
+        self.stack = stack;
+        self.index_register = 0x0;
+        self.variable_registers = [0x0; VARIABLE_REGISTER_COUNT];
+        self.rpl_registers = [0x0; RPL_REGISTER_COUNT];
+        self.delay_timer = 0x0;
+        self.sound_timer = 0x0;
+        self.cycles = 0;
+        self.execution_context = ExecutionContext::default();
+        self.trace.clear();
+        self.high_resolution_mode = false;
+        self.keystate = KeyState::new();
+        self.second_keystate = KeyState::new();
+        self.waiting_original_keystate = KeyState::new();
+        self.keys_pressed_since_wait.clear();
+        self.restart_clocks(false);
+        self.vblank_status = VBlankStatus::Idle;
+        self.sprites_drawn_this_frame = 0;
+        self.frames = 0;
+        self.frame_start_cycle = 0;
+        self.display_changed.set(true);
+        self.timing_recorder = TimingRecorder::default();
+        self.input_recording = None;
+        self.input_replay = None;
+        self.input_replay_position = 0;
+        self.pending_key_events.clear();
+        self.key_input_unseen = false;
+        self.halt_watch.clear();
+        self.vip_random_register = 0;
+        self.rng_seed = self
+            .configured_rng_seed
+            .unwrap_or_else(|| Processor::random_seed(self.clock.as_ref()));
+        self.rng = StdRng::seed_from_u64(self.rng_seed);
+        if self.memory_profile.is_some() {
+            self.memory_profile = Some(MemoryProfile::new());
+        }
+        for watch in &mut self.watches {
+            watch.value = None;
+        }
+        self.break_watch_id = None;
+        if let Err(e) = self.reload_memory() {
+            return Err(self.crash(e));
+        }
+        self.program_counter = self.initial_program_counter();
+        self.set_status(ProcessorStatus::ProgramLoaded);
+        self.notify_sound_transition();
+        Ok(())
+    }
+
+    /// Sets the processor to a paused state (no cycles will execute).  Timers, the vblank interrupt
+    /// and the pacing of cycles are suspended along with execution (as is any FX0A key wait in
+    /// progress), and resumed by [Processor::resume_execution()] from exactly the point reached,
+    /// so that execution proceeds identically whether or not a pause occurred.
+    pub fn pause_execution(&mut self) -> Result<(), ChipolataError> {
+        match self.status {
+            ProcessorStatus::ProgramLoaded
+            | ProcessorStatus::Running
+            | ProcessorStatus::WaitingForKeypress
+            | ProcessorStatus::Paused => {
+                self.set_status(ProcessorStatus::Paused);
+                Ok(())
+            }
+            ProcessorStatus::StartingUp
+            | ProcessorStatus::Initialised
+            | ProcessorStatus::Completed
+            | ProcessorStatus::Halted
+            | ProcessorStatus::Crashed => {
+                return Err(self.crash(ErrorDetail::StateTransitionError {
+                    old_state: self.status,
+                    new_state: ProcessorStatus::Paused,
+                }));
+            }
+        }
+    }
+
+    /// Sets the processor to a running state, if paused, resuming timers and the vblank interrupt
+    /// from the point at which they were paused (and any FX0A key wait that was in progress)
+    pub fn resume_execution(&mut self) -> Result<(), ChipolataError> {
+        match self.status {
+            ProcessorStatus::Paused => {
+                self.set_status(self.resumed_status());
+                Ok(())
+            }
+            ProcessorStatus::ProgramLoaded | ProcessorStatus::Running => {
+                self.set_status(ProcessorStatus::Running);
+                Ok(())
+            }
+            ProcessorStatus::StartingUp
+            | ProcessorStatus::Initialised
+            | ProcessorStatus::WaitingForKeypress
+            | ProcessorStatus::Completed
+            | ProcessorStatus::Halted
+            | ProcessorStatus::Crashed => {
+                return Err(self.crash(ErrorDetail::StateTransitionError {
+                    old_state: self.status,
+                    new_state: ProcessorStatus::Running,
+                }));
+            }
+        }
+    }
+
+    /// Returns a copy of the current state of Chipolata.
+    ///
+    /// The minimal level of state reporting returns just a copy of the [Display] frame buffer
+    /// instance, from which the bitmapped pixel array can be interrogated for rendering purposes,
+    /// together with frame pacing information.  Its `display_changed` flag reports whether the
+    /// display may have changed since the last minimal snapshot was exported, so exporting one
+    /// clears the flag.
+    ///
+    /// The extended level of state reporting returns a copy of the [Display] frame buffer instance
+    /// in addition to a copy of all registers and timers, the [Stack] and the [Memory].  Where the
+    /// state is inspected at high frequency, [Processor::state_view()] avoids these copies.
+    ///
+    /// # Arguments
+    ///
+    /// * `verbosity` - the amount of state that should be returned
+    pub fn export_state_snapshot(&self, verbosity: StateSnapshotVerbosity) -> StateSnapshot {
+        match verbosity {
+            StateSnapshotVerbosity::Minimal => StateSnapshot::MinimalSnapshot {
+                frame_buffer: self.frame_buffer.clone(),
+                palette: self.options.palette,
+                status: self.status,
+                processor_speed: self.processor_speed_hertz,
+                play_sound: self.sound_timer_active(),
+                delay_timer: self.delay_timer,
+                sound_timer: self.sound_timer,
+                cycles: self.cycles,
+                frames: self.frames,
+                cycles_since_frame: self.cycles - self.frame_start_cycle,
+                display_changed: self.display_changed.replace(false),
+            },
+            StateSnapshotVerbosity::Extended => StateSnapshot::ExtendedSnapshot {
+                frame_buffer: self.frame_buffer.clone(),
+                palette: self.options.palette,
+                status: self.status,
+                processor_speed: self.processor_speed_hertz,
+                play_sound: self.sound_timer_active(),
+                stack: self.stack.clone(),
+                memory: self.memory.clone(),
+                program_counter: self.program_counter,
+                index_register: self.index_register,
+                variable_registers: self.variable_registers,
+                rpl_registers: self.rpl_registers,
+                delay_timer: self.delay_timer,
+                sound_timer: self.sound_timer,
+                cycles: self.cycles,
+                high_resolution_mode: self.high_resolution_mode,
+                emulation_level: self.emulation_level,
+            },
+        }
+    }
+
+    /// Refreshes a snapshot previously returned by [Processor::export_state_snapshot()] with the
+    /// current state of Chipolata, as if exporting a new one.  Where the snapshot is of the
+    /// requested verbosity its frame buffer and stack are overwritten in place (their heap
+    /// allocations being reused if the sizes match); otherwise it is replaced by a new snapshot.
+    /// Hosts requesting snapshots at high frequency can so recycle snapshots they are finished
+    /// with, for example swapping a pair of buffers with a rendering thread, keeping the cost of
+    /// each export to the copying of state.
+    ///
+    /// # Arguments
+    ///
+    /// * `verbosity` - the amount of state that should be exported
+    /// * `snapshot` - the snapshot to refresh
+    pub fn export_state_snapshot_into(
+        &self,
+        verbosity: StateSnapshotVerbosity,
+        snapshot: &mut StateSnapshot,
+    ) {
+        match (verbosity, snapshot) {
+            (
+                StateSnapshotVerbosity::Minimal,
+                StateSnapshot::MinimalSnapshot {
+                    frame_buffer,
+                    palette,
+                    status,
+                    processor_speed,
+                    play_sound,
+                    delay_timer,
+                    sound_timer,
+                    cycles,
+                    frames,
+                    cycles_since_frame,
+                    display_changed,
+                },
+            ) => {
+                frame_buffer.clone_from(&self.frame_buffer);
+                *palette = self.options.palette;
+                *status = self.status;
+                *processor_speed = self.processor_speed_hertz;
+                *play_sound = self.sound_timer_active();
+                *delay_timer = self.delay_timer;
+                *sound_timer = self.sound_timer;
+                *cycles = self.cycles;
+                *frames = self.frames;
+                *cycles_since_frame = self.cycles - self.frame_start_cycle;
+                *display_changed = self.display_changed.replace(false);
+            }
+            (
+                StateSnapshotVerbosity::Extended,
+                StateSnapshot::ExtendedSnapshot {
+                    frame_buffer,
+                    palette,
+                    status,
+                    processor_speed,
+                    play_sound,
+                    cycles,
+                    stack,
+                    memory,
+                    program_counter,
+                    index_register,
+                    variable_registers,
+                    rpl_registers,
+                    delay_timer,
+                    sound_timer,
+                    high_resolution_mode,
+                    emulation_level,
+                },
+            ) => {
+                frame_buffer.clone_from(&self.frame_buffer);
+                *palette = self.options.palette;
+                *status = self.status;
+                *processor_speed = self.processor_speed_hertz;
+                *play_sound = self.sound_timer_active();
+                *cycles = self.cycles;
+                stack.clone_from(&self.stack);
+                memory.clone_from(&self.memory);
+                *program_counter = self.program_counter;
+                *index_register = self.index_register;
+                *variable_registers = self.variable_registers;
+                *rpl_registers = self.rpl_registers;
+                *delay_timer = self.delay_timer;
+                *sound_timer = self.sound_timer;
+                *high_resolution_mode = self.high_resolution_mode;
+                *emulation_level = self.emulation_level;
+            }
+            (verbosity, snapshot) => *snapshot = self.export_state_snapshot(verbosity),
+        }
+    }
+
+    /// Returns a [StateView] borrowing the processor's full internal state.  Unlike
+    /// [Processor::export_state_snapshot()] no copy of the frame buffer, stack or memory is made,
+    /// making this suitable for hosting applications that inspect state at high frequency.
+    pub fn state_view(&self) -> StateView<'_> {
+        StateView {
+            frame_buffer: &self.frame_buffer,
+            palette: self.options.palette,
+            status: self.status,
+            processor_speed: self.processor_speed_hertz,
+            play_sound: self.sound_timer_active(),
+            cycles: self.cycles,
+            stack: &self.stack,
+            memory: &self.memory,
+            program_counter: self.program_counter,
+            index_register: self.index_register,
+            variable_registers: &self.variable_registers,
+            rpl_registers: &self.rpl_registers,
+            delay_timer: self.delay_timer,
+            sound_timer: self.sound_timer,
+            high_resolution_mode: self.high_resolution_mode,
+            emulation_level: self.emulation_level,
+        }
+    }
+
+    /// Provides key press input to Chipolata, by setting the state of the specified key
+    /// in the internal representation to pressed / not pressed as per supplied value.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the key (hosts holding a hex ordinal can convert it with `Key::try_from()`)
+    /// * `status` - the value to set for the specified key (true means pressed)
+    pub fn set_key_status(&mut self, key: Key, status: bool) {
+        // Host input is ignored while a recording is being replayed
+        if self.input_replay.is_some() {
+            return;
+        }
+        self.keystate.set_key(key, status);
+        self.key_input_unseen = true;
+        if let Some(recording) = &mut self.input_recording {
+            recording.record_key_event(self.cycles, key, status);
+        }
+    }
+
+    /// Provides key press input from the second keypad to Chipolata (CHIP-8X emulation mode only,
+    /// where it is read by the EXF2 and EXF5 instructions), by setting the state of the specified
+    /// key to pressed / not pressed as per supplied value.  Unlike [Processor::set_key_status()],
+    /// second keypad input is not captured by input recordings.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the key (hosts holding a hex ordinal can convert it with `Key::try_from()`)
+    /// * `status` - the value to set for the specified key (true means pressed)
+    pub fn set_second_key_status(&mut self, key: Key, status: bool) {
+        if self.input_replay.is_some() {
+            return;
+        }
+        self.second_keystate.set_key(key, status);
+    }
+
+    /// Provides a batch of key events to Chipolata, such as all those received by the host during
+    /// a single frame, so that their relative order is preserved.  Events without a cycle offset
+    /// (or with an offset of zero) are applied immediately, in the order supplied; the remainder
+    /// are applied immediately before the cycle at that offset from the current cycle count
+    /// executes, so that (for example) a press and release within one frame are both seen by
+    /// an FX0A instruction.  As with [Processor::set_key_status()], events are ignored while a
+    /// recording is being replayed.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - the [KeyEvent] instances to apply, in the order they occurred
+    pub fn apply_input_events(&mut self, events: &[KeyEvent]) {
+        if self.input_replay.is_some() {
+            return;
+        }
+        for event in events {
+            match event.cycle_offset {
+                None | Some(0) => self.set_key_status(event.key, event.pressed),
+                Some(offset) => {
+                    let due_cycle: usize = self.cycles + offset;
+                    // Insert after any events already due at the same cycle, preserving order
+                    let position: usize = self
+                        .pending_key_events
+                        .partition_point(|(cycle, _, _)| *cycle <= due_cycle);
+                    self.pending_key_events
+                        .insert(position, (due_cycle, event.key, event.pressed));
+                }
+            }
+        }
+    }
+
+    /// Applies any batched key events (see [Processor::apply_input_events()]) due before the
+    /// next cycle executes.
+    fn apply_pending_key_events(&mut self) {
+        let due_count: usize = self
+            .pending_key_events
+            .partition_point(|(cycle, _, _)| *cycle <= self.cycles);
+        let due_events: Vec<(usize, Key, bool)> =
+            self.pending_key_events.drain(..due_count).collect();
+        for (_, key, pressed) in due_events {
+            self.set_key_status(key, pressed);
+        }
+    }
+
+    /// Begins recording all key events supplied through [Processor::set_key_status()], along
+    /// with the random seed in use, so the session can later be replayed deterministically.
+    /// Recording must begin before the first cycle is executed, otherwise an
+    /// [ErrorDetail::InputRecordingError] is returned.
+    pub fn start_input_recording(&mut self) -> Result<(), ChipolataError> {
+        if self.status != ProcessorStatus::ProgramLoaded {
+            return Err(self.crash(ErrorDetail::InputRecordingError {
+                status: self.status,
+            }));
+        }
+        self.input_recording = Some(InputRecording::new(self.rng_seed));
+        Ok(())
+    }
+
+    /// Writes the passed image (typically a dump of the original COSMAC VIP interpreter) to memory
+    /// from address 0x000, so that programs reading from the reserved area below the program
+    /// start address see the bytes they would on the original machine.  The font is then
+    /// reloaded over the image, so the font start address should be moved clear of any bytes
+    /// such programs read.  The image is retained and rewritten whenever the processor is
+    /// reset.  If the image would overlap the program, an [ErrorDetail::MemoryAddressOutOfBounds]
+    /// is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - the bytes to write from address 0x000
+    pub fn load_interpreter_image(&mut self, image: &[u8]) -> Result<(), ChipolataError> {
+        if image.len() > self.program_start_address {
+            return Err(self.crash(ErrorDetail::MemoryAddressOutOfBounds {
+                address: image.len() as u16,
+            }));
+        }
+        // The image and font are written to the reserved region, so lift any write protection
+        // of it while loading them
+        self.memory.set_reserved_write_protection(false);
+        let loaded: Result<(), ErrorDetail> = self.memory.write_bytes(0x0, image);
+        self.instruction_cache.clear();
+        if loaded.is_ok() {
+            self.interpreter_image = Some(image.to_vec());
+        }
+        let loaded: Result<(), ErrorDetail> = loaded.and_then(|_| self.load_font_data());
+        self.memory
+            .set_reserved_write_protection(self.protect_reserved_memory);
+        if let Err(e) = loaded {
+            return Err(self.crash(e));
+        }
+        Ok(())
+    }
+
+    /// Stops recording key events, and returns the [InputRecording] captured (or `None` if
+    /// recording was not active).
+    pub fn stop_input_recording(&mut self) -> Option<InputRecording> {
+        self.input_recording.take()
+    }
+
+    /// Begins deterministic replay of the passed [InputRecording].  The random number generator
+    /// is re-seeded from the recording and memory re-initialised accordingly, after which the
+    /// recorded key events are applied at the same cycles as they were originally received;
+    /// any key events supplied by the host are ignored until the replay completes.  Replay must
+    /// begin before the first cycle is executed, otherwise an [ErrorDetail::InputRecordingError]
+    /// is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `recording` - the [InputRecording] to replay
+    pub fn start_input_replay(&mut self, recording: InputRecording) -> Result<(), ChipolataError> {
+        if self.status != ProcessorStatus::ProgramLoaded {
+            return Err(self.crash(ErrorDetail::InputRecordingError {
+                status: self.status,
+            }));
+        }
+        // Re-seed the random number generator and rebuild memory, so that any randomised memory
+        // contents match those of the recorded session
+        self.rng_seed = recording.rng_seed;
+        self.rng = StdRng::seed_from_u64(self.rng_seed);
+        if let Err(e) = self.reload_memory() {
+            return Err(self.crash(e));
+        }
+        self.input_replay = Some(recording);
+        self.input_replay_position = 0;
+        Ok(())
+    }
+
+    /// Returns true if a recorded session is currently being replayed
+    pub fn input_replay_active(&self) -> bool {
+        self.input_replay.is_some()
+    }
+
+    /// Returns the seed of the random number generator in use
+    pub fn rng_seed(&self) -> u64 {
+        self.rng_seed
+    }
+
+    /// Applies any recorded key events due before the next cycle executes, if replaying input.
+    /// Once all recorded events have been applied the replay ends.
+    fn apply_replayed_input(&mut self) -> Result<(), ErrorDetail> {
+        if let Some(replay) = &self.input_replay {
+            while let Some(event) = replay.events.get(self.input_replay_position) {
+                if event.cycle > self.cycles {
+                    return Ok(());
+                }
+                self.keystate.set_key(event.key, event.pressed);
+                self.input_replay_position += 1;
+            }
+            self.input_replay = None;
+        }
+        Ok(())
+    }
+
+    /// Re-initialises memory (using the current random number generator where memory contents are
+    /// randomised) then reloads the font data and program into it
+    fn reload_memory(&mut self) -> Result<(), ErrorDetail> {
+        self.memory = Memory::new(self.emulation_level, &mut self.rng);
+        self.instruction_cache.clear();
+        if let Some(interpreter_image) = &self.interpreter_image {
+            self.memory.write_bytes(0x0, interpreter_image)?;
+        }
+        self.load_font_data()?;
+        self.load_program()?;
+        self.set_memory_layout();
+        Ok(())
+    }
+
+    /// Records in memory where the font data and program have been loaded, from which its
+    /// [MemoryRegion](crate::MemoryRegion)s are determined, and write-protects the reserved
+    /// region below the program if so configured
+    fn set_memory_layout(&mut self) {
+        let font_end: usize = match &self.high_resolution_font {
+            Some(high_resolution_font) => {
+                self.high_resolution_font_start_address + high_resolution_font.font_data_size()
+            }
+            None => self.font_start_address + self.low_resolution_font.font_data_size(),
+        };
+        self.memory.set_layout(
+            self.font_start_address..font_end,
+            self.program_start_address
+                ..self.program_start_address + self.program.program_data_size(),
+        );
+        self.memory
+            .set_reserved_write_protection(self.protect_reserved_memory);
+    }
+
+    /// Loads the processor's font data into memory.  If the size of the font data combined with
+    /// the specified start location in memory would cause a write to unaddressable memory, then
+    /// return an [ErrorDetail::MemoryAddressOutOfBounds].  This will always load the standard
+    /// low-resolution CHIP-8 font into memory, however if in SUPER-CHIP 1.1 emulation mode this
+    /// will also load the high-resolution SUPER-CHIP font as well
+    fn load_font_data(&mut self) -> Result<(), ErrorDetail> {
+        // Load the low-resolution font
+        if self.font_start_address + self.low_resolution_font.font_data_size()
+            >= self.program_start_address
+        {
+            return Err(ErrorDetail::MemoryAddressOutOfBounds {
+                address: (self.font_start_address + self.low_resolution_font.font_data_size())
+                    as u16,
+            });
+        }
+        self.memory.write_bytes(
+            self.font_start_address,
+            self.low_resolution_font.font_data(),
+        )?;
+        // Load the high-resolution font, if present
+        if let Some(high_resolution_font) = &self.high_resolution_font {
+            self.high_resolution_font_start_address =
+                self.font_start_address as usize + self.low_resolution_font.font_data_size();
+            if self.high_resolution_font_start_address + high_resolution_font.font_data_size()
+                >= self.program_start_address
+            {
+                return Err(ErrorDetail::MemoryAddressOutOfBounds {
+                    address: (self.high_resolution_font_start_address
+                        + high_resolution_font.font_data_size())
+                        as u16,
+                });
+            }
+            self.memory.write_bytes(
+                self.high_resolution_font_start_address,
+                high_resolution_font.font_data(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Loads the processor's program data into memory.  If the size of the program data combined
+    /// with the specified start location in memory would cause a write to unaddressable memory,
+    /// then return an [ErrorDetail::MemoryAddressOutOfBounds].
+    fn load_program(&mut self) -> Result<(), ErrorDetail> {
+        if self.program_start_address + self.program.program_data_size()
+            > self.memory.max_addressable_size()
+        {
+            return Err(ErrorDetail::MemoryAddressOutOfBounds {
+                address: (self.program_start_address + self.program.program_data_size()) as u16,
+            });
+        }
+        self.memory
+            .write_bytes(self.program_start_address, self.program.program_data())?;
+        Ok(())
+    }
+
+    /// Returns a blank [Display] of the size used at start-up by the emulated interpreter
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the CHIP-8 variant being emulated
+    /// * `chip8_hires_mode` - whether the Hi-Res CHIP-8 interpreter is being emulated
+    /// * `resize_low_resolution_display` - whether the display is resized in SUPER-CHIP 1.1
+    ///   low-resolution mode (in which emulation starts)
+    /// * `ghost_trails` - whether the display tracks pixel intensities for ghost trails
+    fn initial_display(
+        emulation_level: EmulationLevel,
+        chip8_hires_mode: bool,
+        resize_low_resolution_display: bool,
+        ghost_trails: bool,
+    ) -> Display {
+        let mut display: Display = match chip8_hires_mode {
+            true => Display::new_chip8_hires(),
+            false => {
+                let mut display: Display = Display::new(emulation_level);
+                if resize_low_resolution_display {
+                    display.resize(emulation_level, false);
+                }
+                display
+            }
+        };
+        display.track_intensities(ghost_trails);
+        display
+    }
+
+    /// Switches SUPER-CHIP 1.1 high-resolution mode on or off, resizing the display accordingly
+    /// if configured to resize it in low-resolution mode
+    ///
+    /// # Arguments
+    ///
+    /// * `high_resolution` - true to enter high-resolution mode, false to enter low-resolution mode
+    fn set_resolution_mode(&mut self, high_resolution: bool) {
+        self.high_resolution_mode = high_resolution;
+        if self.resize_low_resolution_display {
+            self.frame_buffer
+                .resize(self.emulation_level, high_resolution);
+            self.display_changed.set(true);
+        }
+    }
+
+    /// Returns the address at which execution of the loaded program begins.  This is the program
+    /// start address, unless emulating the Hi-Res CHIP-8 interpreter and the program begins with
+    /// the conventional jump to 0x260 that enters Hi-Res mode, in which case execution begins at
+    /// 0x2C0 (where Hi-Res programs proper start, following the interpreter patch).
+    fn initial_program_counter(&self) -> u16 {
+        let start_address: usize = self.program_start_address;
+        if self.chip8_hires_mode
+            && self.memory.bytes[start_address..start_address + 2] == CHIP8_HIRES_ENTRY_OPCODE
+        {
+            return CHIP8_HIRES_PROGRAM_START_ADDRESS;
+        }
+        start_address as u16
+    }
+
+    /// Helper method that "crashes" the processor when an [ErrorDetail] instance is returned from a
+    /// function call, and wraps this is in an appropriate [ChipolataError] instance before returning
+    fn crash(&mut self, inner_error: ErrorDetail) -> ChipolataError {
+        #[cfg(feature = "tracing")]
+        tracing::error!(
+            error = %inner_error,
+            context = %self.execution_context,
+            "processor crashed"
+        );
+        self.set_status(ProcessorStatus::Crashed);
+        ChipolataError {
+            state_snapshot_dump: self.export_state_snapshot(StateSnapshotVerbosity::Extended),
+            inner_error,
+            context: self.execution_context,
+        }
+    }
+
+    /// Internal helper function that changes the processor status, emitting a tracing event
+    /// recording the transition if the `tracing` feature is enabled
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - the new processor status
+    fn set_status(&mut self, status: ProcessorStatus) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            old_status = ?self.status,
+            new_status = ?status,
+            "processor status changed"
+        );
+        // Time spent paused is not counted towards the timing statistics
+        if let ProcessorStatus::Paused = status {
+            self.timing_recorder.interrupt();
+        }
+        // Suspend the wall clocks on pausing, and resume them from the same point on leaving
+        // the paused state
+        match (self.status, status) {
+            (ProcessorStatus::Paused, ProcessorStatus::Paused) => (),
+            (previous_status, ProcessorStatus::Paused) => {
+                self.suspension = Some(self.suspend_clocks(previous_status));
+            }
+            (ProcessorStatus::Paused, _) => {
+                if let Some(suspension) = self.suspension.take() {
+                    self.resume_clocks(&suspension);
+                }
+            }
+            _ => (),
+        }
+        self.status = status;
+    }
+
+    /// Internal helper function that returns the status in which execution resumes from a pause:
+    /// waiting for a key press if an FX0A key wait was in progress when paused, otherwise running
+    fn resumed_status(&self) -> ProcessorStatus {
+        match self.suspension {
+            Some(Suspension {
+                waiting_for_keypress: true,
+                ..
+            }) => ProcessorStatus::WaitingForKeypress,
+            _ => ProcessorStatus::Running,
+        }
+    }
+
+    /// Internal helper function that records how far each wall clock has advanced through its
+    /// interval, for execution to be resumed from that point
+    ///
+    /// # Arguments
+    ///
+    /// * `previous_status` - the status of the processor prior to pausing
+    fn suspend_clocks(&self, previous_status: ProcessorStatus) -> Suspension {
+        let now: Instant = self.now();
+        Suspension {
+            waiting_for_keypress: previous_status == ProcessorStatus::WaitingForKeypress,
+            since_timer_decrement: now.saturating_duration_since(self.last_timer_decrement),
+            since_vblank_interrupt: now.saturating_duration_since(self.last_vblank_interrupt),
+            since_execution_cycle_complete: now
+                .saturating_duration_since(self.last_execution_cycle_complete),
+            cycle_deadline_ahead: self.cycle_deadline.saturating_duration_since(now),
+            cycle_deadline_behind: now.saturating_duration_since(self.cycle_deadline),
+        }
+    }
+
+    /// Internal helper function that sets each wall clock to the point through its interval it
+    /// had reached when execution was paused
+    ///
+    /// # Arguments
+    ///
+    /// * `suspension` - the state recorded on pausing
+    fn resume_clocks(&mut self, suspension: &Suspension) {
+        let now: Instant = self.now();
+        let before_now = |elapsed: Duration| now.checked_sub(elapsed).unwrap_or(now);
+        self.last_timer_decrement = before_now(suspension.since_timer_decrement);
+        self.last_vblank_interrupt = before_now(suspension.since_vblank_interrupt);
+        self.last_execution_cycle_complete = before_now(suspension.since_execution_cycle_complete);
+        self.cycle_deadline = (now + suspension.cycle_deadline_ahead)
+            .checked_sub(suspension.cycle_deadline_behind)
+            .unwrap_or(now);
+    }
+
+    /// Internal helper function that restarts the wall clocks by which timers, the vblank
+    /// interrupt and the pacing of cycles are measured, counting their intervals from now (or,
+    /// if paused, from the moment execution resumes)
+    ///
+    /// # Arguments
+    ///
+    /// * `waiting_for_keypress` - whether execution resumes waiting for a key press, if paused
+    fn restart_clocks(&mut self, waiting_for_keypress: bool) {
+        let now: Instant = self.now();
+        self.last_timer_decrement = now;
+        self.last_execution_cycle_complete = now;
+        self.cycle_deadline = now;
+        self.last_vblank_interrupt = now;
+        if self.suspension.is_some() {
+            self.suspension = Some(Suspension {
+                waiting_for_keypress,
+                ..Suspension::default()
+            });
+        }
+    }
+
+    /// Executes one iteration of the Chipolata fetch -> decode -> execute cycle.  Returns a boolean
+    /// indicating whether the display frame buffer was updated this cycle.
+    pub fn execute_cycle(&mut self) -> Result<bool, ChipolataError> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("execute_cycle", cycle = self.cycles + 1).entered();
+        // Change processor status if appropriate
+        match self.status {
+            ProcessorStatus::ProgramLoaded => self.set_status(ProcessorStatus::Running),
+            ProcessorStatus::Paused => return Ok(false),
+            ProcessorStatus::Running | ProcessorStatus::WaitingForKeypress => {
+                // no change
+            }
+            ProcessorStatus::StartingUp
+            | ProcessorStatus::Initialised
+            | ProcessorStatus::Completed
+            | ProcessorStatus::Halted
+            | ProcessorStatus::Crashed => {
+                return Err(self.crash(ErrorDetail::StateTransitionError {
+                    old_state: self.status,
+                    new_state: ProcessorStatus::Running,
+                }));
+            }
+        }
+        // Apply any recorded input due at this point, if replaying a recording
+        if let Err(e) = self.apply_replayed_input() {
+            return Err(self.crash(e));
+        }
+        // Apply any batched host input due at this point; the instruction about to execute sees
+        // all input received so far
+        self.apply_pending_key_events();
+        self.key_input_unseen = false;
+        // Increment the cycles counter
+        self.cycles += 1;
+        // Decrement the delay and sound timers, if appropriate
+        self.decrement_timers();
+        // Fetch, decode and execute the next instruction, returning whether it updated the display
+        // and the machine cycles it took.  This is performed in a loop so that, if the policy for
+        // unknown instructions is to skip them, execution can move straight on to the next opcode
+        let (display_updated, machine_cycles): (bool, u64) = loop {
+            // Fetch two byte opcode from current Program Counter memory location, recording where
+            // and when this happened so that any error raised by the instruction can report it
+            self.execution_context = ExecutionContext {
+                program_counter: self.program_counter,
+                opcode: None,
+                cycle: self.cycles,
+            };
+            // Instructions already decoded from this address are taken from the cache, saving
+            // both the memory read and the decoding
+            let cached: Option<(u16, Instruction)> =
+                self.instruction_cache.get(self.program_counter as usize);
+            let opcode: u16 = match cached {
+                Some((opcode, _)) => opcode,
+                None => match self.memory.read_two_bytes(self.program_counter as usize) {
+                    Ok(opcode) => opcode,
+                    Err(e) => {
+                        self.record_trace();
+                        return Err(self.crash(e));
+                    }
+                },
+            };
+            self.execution_context.opcode = Some(opcode);
+            self.record_trace();
+            if let Some(profile) = &mut self.memory_profile {
+                profile.record_execute(self.program_counter as usize, 2);
+            }
+            // Increment Program Counter (by two bytes, as we have 16-bit opcodes)
+            self.program_counter += 0x2;
+            // Decode the opcode into an instruction (caching it for next time), setting processor
+            // state to Crashed on error (unless the opcode is unknown and the policy is to
+            // tolerate this)
+            let instruction: Instruction = match cached {
+                Some((_, instruction)) => instruction,
+                None => match Instruction::decode_from(opcode) {
+                    Ok(instruction) => {
+                        let address: usize = self.program_counter as usize - 0x2;
+                        self.instruction_cache.insert(address, opcode, instruction);
+                        instruction
+                    }
+                    Err(e) => match self.tolerate_unknown_instruction(e)? {
+                        UnknownInstructionPolicy::Skip => continue,
+                        _ => break (false, self.unknown_instruction_cycles()),
+                    },
+                },
+            };
+            #[cfg(feature = "tracing")]
+            tracing::trace!(
+                address = self.program_counter - 0x2,
+                opcode,
+                instruction = ?instruction,
+                "executing instruction"
+            );
+            // Invoke the host's pre-execute callback, if one is registered (temporarily taking
+            // ownership of the callback so it can be passed an immutable reference to the processor)
+            if let Some(mut hook) = self.pre_execute_hook.take() {
+                hook(self, &instruction);
+                self.pre_execute_hook = Some(hook);
+            }
+            // If the instruction is one that updates the display, set a local flag to true
+            let display_updated: bool = match instruction {
+                Instruction::Op00E0 => true,
+                Instruction::Op0NNN {
+                    nnn: CHIP8_HIRES_CLEAR_SCREEN_ROUTINE,
+                } => self.chip8_hires_mode,
+                Instruction::OpDXYN { .. } => true,
+                _ => false,
+            };
+            // Likewise note whether the instruction may change the display at all (including by
+            // scrolling it, changing resolution or calling a host routine), for hosts skipping
+            // the repainting of unchanged frames
+            let display_changed: bool = display_updated
+                || matches!(
+                    instruction,
+                    Instruction::Op00CN { .. }
+                        | Instruction::Op00FB
+                        | Instruction::Op00FC
+                        | Instruction::Op00FE
+                        | Instruction::Op00FF
+                        | Instruction::Op02A0
+                        | Instruction::Op0NNN { .. }
+                )
+                || (matches!(instruction, Instruction::OpBNNN { .. })
+                    && matches!(self.emulation_level, EmulationLevel::Chip8X { .. }));
+            // Look up the number of cycles the HP48 interpreters would have used for this
+            // instruction (this must be done prior to execution, as the cost depends on the
+            // pre-execution state)
+            let hp48_cycles: u64 = self.hp48_approximate_instruction_cycles(&instruction);
+            // Likewise record the memory accesses the instruction makes, if profiling is enabled
+            self.profile_instruction_memory_accesses(&instruction);
+            // Execute the instruction, setting processor state to Crashed on error (as above),
+            // and returning the number of cycles the original COSMAC VIP interpreter would have
+            // used for this
+            let cosmac_cycles: u64 = match self.execute(instruction) {
+                Ok(timing) => {
+                    if display_changed {
+                        self.display_changed.set(true);
+                    }
+                    self.detect_halt(&instruction, display_changed);
+                    timing
+                }
+                Err(e) => match self.tolerate_unknown_instruction(e)? {
+                    UnknownInstructionPolicy::Skip => continue,
+                    _ => break (false, self.unknown_instruction_cycles()),
+                },
+            };
+            // Choose the appropriate instruction timing for the emulation level
+            break match self.emulation_level {
+                EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+                    (display_updated, cosmac_cycles)
+                }
+                EmulationLevel::Chip48 { .. }
+                | EmulationLevel::SuperChip10 { .. }
+                | EmulationLevel::SuperChip11 { .. } => (display_updated, hp48_cycles),
+            };
+        };
+        // Evaluate any watch expressions, pausing execution if a break condition is met
+        self.evaluate_watches();
+        // In order to simulate the configured processor speed, we now wait if execution has got
+        // too far ahead of schedule (unless running in turbo mode)
+        let target_cycle_duration: Option<Duration> = match self.turbo_mode {
+            true => None,
+            false => Some(self.calculate_cycle_duration(machine_cycles)),
+        };
+        if let Some(target_cycle_duration) = target_cycle_duration {
+            self.pace_cycle(target_cycle_duration);
+        }
+        self.last_execution_cycle_complete = self.now();
+        self.timing_recorder
+            .record_cycle(self.last_execution_cycle_complete, target_cycle_duration);
+        // Inform the host if sound has started or stopped this cycle
+        self.notify_sound_transition();
+        // Return successfully, passing the flag indicating whether the display was updated this cycle
+        return Ok(display_updated);
+    }
+
+    /// Internal helper function that applies the configured [UnknownInstructionPolicy] to an
+    /// error raised while decoding or executing an instruction.  Returns the policy if the error
+    /// is an unknown or unimplemented instruction that the policy tolerates (i.e. it is to be
+    /// skipped or treated as a no-op); otherwise crashes the processor and returns the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - the error raised by the instruction
+    fn tolerate_unknown_instruction(
+        &mut self,
+        error: ErrorDetail,
+    ) -> Result<UnknownInstructionPolicy, ChipolataError> {
+        match (self.unknown_instruction_policy, &error) {
+            (UnknownInstructionPolicy::Crash, _) => Err(self.crash(error)),
+            (
+                policy,
+                ErrorDetail::UnknownInstruction { .. }
+                | ErrorDetail::UnimplementedInstruction { .. },
+            ) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %error, policy = ?policy, "unknown instruction tolerated");
+                Ok(policy)
+            }
+            _ => Err(self.crash(error)),
+        }
+    }
+
+    /// Internal helper function that paces execution to the configured processor speed, by
+    /// advancing the schedule by the time the cycle just executed should take and then waiting
+    /// as required.  Rather than waiting out every cycle (busy-waiting for cycles far shorter
+    /// than a thread sleep), cycles may run up to [PACING_BATCH_MICROSECONDS] ahead of schedule;
+    /// beyond that, the [Clock] waits until the schedule catches up.  Execution that has fallen behind schedule (for example while
+    /// paused, or if the host stops calling [Processor::execute_cycle()] for a while) is only
+    /// allowed to catch up by at most one batch, rather than running a long burst of cycles.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_cycle_duration` - the time the cycle just executed should take
+    fn pace_cycle(&mut self, target_cycle_duration: Duration) {
+        let batch: Duration = Duration::from_micros(PACING_BATCH_MICROSECONDS);
+        let now: Instant = self.now();
+        if let Some(earliest_deadline) = now.checked_sub(batch) {
+            self.cycle_deadline = self.cycle_deadline.max(earliest_deadline);
+        }
+        self.cycle_deadline += target_cycle_duration;
+        let time_ahead: Duration = self.cycle_deadline.saturating_duration_since(now);
+        if time_ahead > batch {
+            self.clock.sleep(time_ahead);
+        }
+    }
+
+    /// Internal helper function that returns the Duration a cycle should be emulated to take,
+    /// based on the specified processor speed and emulation mode (fixed cycles vs COSMAC VIP or
+    /// approximate HP48 variable instruction timing), or on the [TimingMode] if one is configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `machine_cycles` - if using variable instruction timings, this is the number of COSMAC
+    ///   VIP interpreter cycles (CHIP-8) or the approximate number of HP48 Saturn processor cycles
+    ///   (CHIP-48 and SUPER-CHIP) taken to execute the instruction in question.  If using fixed
+    ///   cycle timings, this parameter is ignored by the function.
+    fn calculate_cycle_duration(&self, machine_cycles: u64) -> Duration {
+        let variable_cycle_timing: bool = match self.timing_mode {
+            Some(TimingMode::Fixed {
+                instruction_microseconds,
+            }) => return Duration::from_micros(instruction_microseconds),
+            Some(TimingMode::InstructionsPerFrame { instructions }) => {
+                // Share the timer interval equally between the instructions (measuring in
+                // nanoseconds, so that the shares add up to the interval as closely as possible)
+                return Duration::from_nanos(
+                    (self.timer_interval_microseconds * 1_000 / instructions as u128) as u64,
+                );
+            }
+            Some(TimingMode::CosmacVariable) => true,
+            None => match self.emulation_level {
+                EmulationLevel::Chip8 {
+                    variable_cycle_timing,
+                    ..
+                }
+                | EmulationLevel::Chip48 {
+                    variable_cycle_timing,
+                }
+                | EmulationLevel::SuperChip10 {
+                    variable_cycle_timing,
+                }
+                | EmulationLevel::SuperChip11 {
+                    variable_cycle_timing,
+                    ..
+                }
+                | EmulationLevel::Chip8X {
+                    variable_cycle_timing,
+                } => variable_cycle_timing,
+            },
+        };
+        let execution_duration: Duration;
+        match (self.emulation_level, variable_cycle_timing) {
+            (EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. }, true) => {
+                // Define the cycle duration to be the COSMAC VIP original instruction timing
+                // (in cycles) running at the specified processor speed
+                execution_duration = Duration::from_micros(
+                    machine_cycles * COSMAC_VIP_MACHINE_CYCLES_PER_CYCLE * 1_000_000_u64
+                        / self.processor_speed_hertz,
+                );
+            }
+            (
+                EmulationLevel::Chip48 { .. }
+                | EmulationLevel::SuperChip10 { .. }
+                | EmulationLevel::SuperChip11 { .. },
+                true,
+            ) => {
+                // Define the cycle duration to be the approximate HP48 instruction timing (in
+                // Saturn processor cycles) running at the specified processor speed
+                execution_duration = Duration::from_micros(
+                    machine_cycles * 1_000_000_u64 / self.processor_speed_hertz,
+                );
+            }
+            _ => {
+                // Drive the cycle duration purely from specified processor speed
+                execution_duration =
+                    Duration::from_micros(1_000_000_u64 / self.processor_speed_hertz);
+            }
+        }
+        execution_duration
+    }
+
+    /// Checks if the required time has passed since the sound and delay timers were last decremented
+    /// and if so, decrements them.  Also counts down to vblank interrupt, counting frames.
+    fn decrement_timers(&mut self) {
+        // Check the vblank interrupt timer, counting a new frame each time it elapses.  If drawing
+        // waits for the display, or is limited to a budget of sprites per frame, also set the
+        // interrupt accordingly (replenishing the budget)
+        if self.interval_elapsed(self.last_vblank_interrupt, self.timer_interval_microseconds) {
+            self.frames += 1;
+            self.frame_start_cycle = self.cycles;
+            self.last_vblank_interrupt = self.now();
+            // Fade the ghost trails of erased pixels, if tracked
+            if let Some(decay) = self.options.ghost_trail_decay {
+                if self.frame_buffer.decay_intensities(decay) {
+                    self.display_changed.set(true);
+                }
+            }
+            // Push the completed frame to the host, if it has registered a frame sink
+            self.frame_sink.present(&self.frame_buffer);
+            if self.display_wait_enabled() || self.sprites_per_frame.is_some() {
+                if let VBlankStatus::WaitingForVBlank = self.vblank_status {
+                    self.vblank_status = VBlankStatus::ReadyToDraw;
+                }
+                self.sprites_drawn_this_frame = 0;
+                #[cfg(feature = "tracing")]
+                tracing::trace!("vblank interrupt");
+                if !self.cycle_based_timing {
+                    self.timing_recorder
+                        .record_vblank_interrupt(self.last_vblank_interrupt, self.frame_duration());
+                }
+            }
+        }
+        // Nothing to do for delay and sound timers unless timers are running, or the timer
+        // interrupt is emulated as running continuously (keeping its phase while timers are idle)
+        if (self.delay_timer | self.sound_timer) > 0x0 || self.timer_phase_emulation {
+            // Check how long it has been since the timers were last decremented; if the interval
+            // is greater than the specified threshold then we should decrement again
+            if self.interval_elapsed(self.last_timer_decrement, self.timer_interval_microseconds) {
+                self.last_timer_decrement = self.next_timer_tick();
+                if self.delay_timer > 0x0 {
+                    self.delay_timer -= 1;
+                }
+                if self.sound_timer > 0x0 {
+                    self.sound_timer -= 1;
+                }
+                if !self.cycle_based_timing {
+                    // The actual moment of the tick is measured, rather than its phase-aligned
+                    // instant, and ticks are measured throughout when they run continuously
+                    self.timing_recorder.record_timer_decrement(
+                        self.now(),
+                        self.frame_duration(),
+                        (self.delay_timer | self.sound_timer) > 0x0 || self.timer_phase_emulation,
+                    );
+                }
+                #[cfg(feature = "tracing")]
+                tracing::trace!(
+                    delay_timer = self.delay_timer,
+                    sound_timer = self.sound_timer,
+                    "timers decremented"
+                );
+            }
+        }
+    }
+
+    /// Internal helper function that returns the instant to record as that of the timer tick just
+    /// elapsed.  With timer phase emulation the tick is placed exactly one interval after the
+    /// last, so ticks do not drift later by however long each check was delayed; if more than
+    /// one interval has been missed (e.g. while paused) the phase is re-established from now.
+    fn next_timer_tick(&self) -> Instant {
+        let interval: Duration = self.frame_duration();
+        let now: Instant = self.now();
+        match self.timer_phase_emulation
+            && now.saturating_duration_since(self.last_timer_decrement) < interval * 2
+        {
+            true => self.last_timer_decrement + interval,
+            false => now,
+        }
+    }
+
+    /// Internal helper function that returns true if sprite drawing (DXYN) must currently wait for
+    /// the vblank interrupt.  This is the case in CHIP-8 and CHIP-8X emulation modes (unless the
+    /// display is modelled scanline by scanline instead), and is the case in CHIP-48 and
+    /// SUPER-CHIP low resolution mode if the display wait option is enabled.
+    fn display_wait_enabled(&self) -> bool {
+        match self.emulation_level {
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+                !self.accurate_display_timing
+            }
+            EmulationLevel::Chip48 { .. } => self.lowres_display_wait,
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
+                self.lowres_display_wait && !self.high_resolution_mode
+            }
+        }
+    }
+
+    /// Internal helper function that returns true if the next sprite draw (DXYN) must wait for the
+    /// vblank interrupt, either because the display wait applies or because the per-frame sprite
+    /// budget has been used up
+    fn sprite_draw_must_wait(&self) -> bool {
+        self.display_wait_enabled()
+            || matches!(self.sprites_per_frame, Some(budget) if self.sprites_drawn_this_frame >= budget)
+    }
+
+    /// Internal helper function that returns true if the COSMAC VIP display is modelled scanline
+    /// by scanline, so that sprite drawing (DXYN) waits for the raster rather than the vblank
+    /// interrupt (see [Options::accurate_display_timing])
+    fn scanline_display_timing(&self) -> bool {
+        self.accurate_display_timing
+            && matches!(
+                self.emulation_level,
+                EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. }
+            )
+    }
+
+    /// Internal helper function that returns the scanline the simulated COSMAC VIP raster has
+    /// reached in the current frame, counting from the vblank interrupt (between 0 and
+    /// [VIP_SCANLINES_PER_FRAME] exclusive).  The raster is taken to advance evenly through the
+    /// frame, measured in cycles if timers are cycle-synced and in wall-clock time otherwise.
+    fn raster_scanline(&self) -> usize {
+        let scanline: u128 = match self.cycle_based_timing {
+            true => {
+                (self.cycles - self.frame_start_cycle) as u128 * VIP_SCANLINES_PER_FRAME as u128
+                    / self.interval_cycles(self.timer_interval_microseconds)
+            }
+            false => {
+                self.elapsed(self.last_vblank_interrupt).as_micros()
+                    * VIP_SCANLINES_PER_FRAME as u128
+                    / self.timer_interval_microseconds
+            }
+        };
+        (scanline as usize).min(VIP_SCANLINES_PER_FRAME - 1)
+    }
+
+    /// Internal helper function that returns the next random byte generated by the COSMAC VIP
+    /// interpreter's routine (see [Options::vip_random_generator]).  R9.0 is taken as the low
+    /// byte of the elapsed cycle count, then incremented as the routine does, and the byte at
+    /// that offset into the interpreter's page of memory is added to R9.1, which is returned.
+    fn vip_random_byte(&mut self) -> Result<u8, ErrorDetail> {
+        let r9_low: u8 = (self.cycles as u8).wrapping_add(1);
+        let byte: u8 = self
+            .memory
+            .read_byte(VIP_RANDOM_TABLE_ADDRESS + r9_low as usize)?;
+        self.vip_random_register = self.vip_random_register.wrapping_add(byte);
+        Ok(self.vip_random_register)
+    }
+
+    /// Internal helper function that returns true if, with the display modelled scanline by
+    /// scanline, a sprite draw (DXYN) must wait because the raster has not yet passed the last
+    /// display row the sprite occupies
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - the index of the variable register holding the sprite's Y coordinate
+    /// * `n` - the height of the sprite in rows
+    fn raster_wait_required(&self, y: usize, n: u8) -> bool {
+        if !self.scanline_display_timing() {
+            return false;
+        }
+        // Each display row is drawn over several scanlines (four in the standard 64 x 32 mode)
+        let (_, height): (usize, usize) = self.frame_buffer.get_resolution();
+        let scanlines_per_row: usize = VIP_DISPLAY_SCANLINES / height;
+        let top_row: usize = self.variable_registers[y] as usize % height;
+        let bottom_row: usize = (top_row + n as usize).min(height);
+        self.raster_scanline() < VIP_DISPLAY_FIRST_SCANLINE + bottom_row * scanlines_per_row
+    }
+
+    /// Internal helper function that returns a random number generator seed, for use when none
+    /// is specified in the options.  Without the `std` feature there is no source of entropy, so
+    /// the seed is taken from the [Clock] (embedders wanting unpredictable programs should set
+    /// [Options::rng_seed], for example from a hardware random number generator).
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - the clock in use
+    #[cfg_attr(feature = "std", allow(unused_variables))]
+    fn random_seed(clock: &dyn Clock) -> u64 {
+        #[cfg(feature = "std")]
+        let seed: u64 = rand::thread_rng().gen();
+        #[cfg(not(feature = "std"))]
+        let seed: u64 = clock.now().as_nanos() as u64;
+        seed
+    }
+
+    /// Internal helper function that returns the current instant according to the [Clock]
+    fn now(&self) -> Instant {
+        Instant::now(self.clock.as_ref())
+    }
+
+    /// Internal helper function that returns the time elapsed on the [Clock] since the specified
+    /// instant (zero if it is in the future)
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - the instant from which the time is measured
+    fn elapsed(&self, since: Instant) -> Duration {
+        self.now().saturating_duration_since(since)
+    }
+
+    /// Internal helper function that returns true if the specified interval has passed since the
+    /// specified instant.  When cycle-based timing is enabled, the interval is instead converted
+    /// to a number of cycles at the configured processor speed, and is deemed to have passed on
+    /// every cycle that is a multiple of that number.
+    ///
+    /// # Arguments
+    ///
+    /// * `since` - the instant from which the interval is measured (wall-clock timing only)
+    /// * `interval_microseconds` - the length of the interval
+    fn interval_elapsed(&self, since: Instant, interval_microseconds: u128) -> bool {
+        match self.cycle_based_timing {
+            true => self.cycles as u128 % self.interval_cycles(interval_microseconds) == 0,
+            false => self.elapsed(since).as_micros() >= interval_microseconds,
+        }
+    }
+
+    /// Internal helper function that returns the number of cycles executed at the configured
+    /// processor speed (or under the configured [TimingMode], if this fixes the cycle rate) in
+    /// the specified interval (at least one)
+    ///
+    /// # Arguments
+    ///
+    /// * `interval_microseconds` - the length of the interval
+    fn interval_cycles(&self, interval_microseconds: u128) -> u128 {
+        match self.timing_mode {
+            Some(TimingMode::Fixed {
+                instruction_microseconds,
+            }) => interval_microseconds / instruction_microseconds.max(1) as u128,
+            Some(TimingMode::InstructionsPerFrame { instructions }) => {
+                instructions as u128 * interval_microseconds / self.timer_interval_microseconds
+            }
+            _ => self.processor_speed_hertz as u128 * interval_microseconds / 1_000_000,
+        }
+        .max(1)
+    }
+
+    /// Internal helper function that notifies the sound sink, if one is registered, when the
+    /// sound timer has transitioned between zero and non-zero since the sink was last notified
+    fn notify_sound_transition(&mut self) {
+        let playing: bool = self.sound_timer_active();
+        self.sound_state.update(playing, self.cycles);
+    }
+
+    /// Returns true if the sound timer is active i.e. if the hosting application should play audio
+    pub fn sound_timer_active(&self) -> bool {
+        match self.sound_timer {
+            0 => false,
+            _ => true,
+        }
+    }
+
+    /// Executes the passed Instruction.  Returns [ErrorDetail::UnimplementedInstruction] if Chipolata is
+    /// unable to process opcodes of this type.
+    ///
+    /// # Arguments
+    ///
+    /// * `instr` - the instruction to be executed
+    fn execute(&mut self, instr: Instruction) -> Result<u64, ErrorDetail> {
+        match instr {
+            Instruction::Op004B => self.execute_004B(),
+            Instruction::Op00CN { n } => self.execute_00CN(n),
+            Instruction::Op00E0 => self.execute_00E0(),
+            Instruction::Op00EE => self.execute_00EE(),
+            Instruction::Op00FB => self.execute_00FB(),
+            Instruction::Op00FC => self.execute_00FC(),
+            Instruction::Op00FD => self.execute_00FD(),
+            Instruction::Op00FE => self.execute_00FE(),
+            Instruction::Op00FF => self.execute_00FF(),
+            Instruction::Op02A0 => self.execute_02A0(),
+            Instruction::Op0NNN { nnn } => self.execute_0NNN(nnn),
+            Instruction::Op1NNN { nnn } => self.execute_1NNN(nnn),
+            Instruction::Op2NNN { nnn } => self.execute_2NNN(nnn),
+            Instruction::Op3XNN { x, nn } => self.execute_3XNN(x, nn),
+            Instruction::Op4XNN { x, nn } => self.execute_4XNN(x, nn),
+            Instruction::Op5XY0 { x, y } => self.execute_5XY0(x, y),
+            Instruction::Op5XY1 { x, y } => self.execute_5XY1(x, y),
+            Instruction::Op6XNN { x, nn } => self.execute_6XNN(x, nn),
+            Instruction::Op7XNN { x, nn } => self.execute_7XNN(x, nn),
+            Instruction::Op8XY0 { x, y } => self.execute_8XY0(x, y),
+            Instruction::Op8XY1 { x, y } => self.execute_8XY1(x, y),
+            Instruction::Op8XY2 { x, y } => self.execute_8XY2(x, y),
+            Instruction::Op8XY3 { x, y } => self.execute_8XY3(x, y),
+            Instruction::Op8XY4 { x, y } => self.execute_8XY4(x, y),
+            Instruction::Op8XY5 { x, y } => self.execute_8XY5(x, y),
+            Instruction::Op8XY6 { x, y } => self.execute_8XY6(x, y),
+            Instruction::Op8XY7 { x, y } => self.execute_8XY7(x, y),
+            Instruction::Op8XYE { x, y } => self.execute_8XYE(x, y),
+            Instruction::Op9XY0 { x, y } => self.execute_9XY0(x, y),
+            Instruction::OpANNN { nnn } => self.execute_ANNN(nnn),
+            Instruction::OpBNNN { nnn } => self.execute_BNNN(nnn),
+            Instruction::OpCXNN { x, nn } => self.execute_CXNN(x, nn),
+            Instruction::OpDXYN { x, y, n } => self.execute_DXYN(x, y, n),
+            Instruction::OpEX9E { x } => self.execute_EX9E(x),
+            Instruction::OpEXA1 { x } => self.execute_EXA1(x),
+            Instruction::OpEXF2 { x } => self.execute_EXF2(x),
+            Instruction::OpEXF5 { x } => self.execute_EXF5(x),
+            Instruction::OpFX07 { x } => self.execute_FX07(x),
+            Instruction::OpFX15 { x } => self.execute_FX15(x),
+            Instruction::OpFX18 { x } => self.execute_FX18(x),
+            Instruction::OpFX1E { x } => self.execute_FX1E(x),
+            Instruction::OpFX0A { x } => self.execute_FX0A(x),
+            Instruction::OpFX29 { x } => self.execute_FX29(x),
+            Instruction::OpFX30 { x } => self.execute_FX30(x),
+            Instruction::OpFX33 { x } => self.execute_FX33(x),
+            Instruction::OpFX55 { x } => self.execute_FX55(x),
+            Instruction::OpFX65 { x } => self.execute_FX65(x),
+            Instruction::OpFX75 { x } => self.execute_FX75(x),
+            Instruction::OpFX85 { x } => self.execute_FX85(x),
+            Instruction::OpFXF8 { x } => self.execute_FXF8(x),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use super::*;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use zip::write::FileOptions;
+#[cfg(feature = "std")]
+use zip::{CompressionMethod, ZipWriter};
+
+/// The number of most recently fetched instructions retained for inclusion in a [CrashDump]
+pub const CRASH_DUMP_TRACE_LENGTH: usize = 64;
+
+/// The name of the file holding the program ROM within a crash dump archive
+pub const CRASH_DUMP_PROGRAM_FILE: &str = "program.ch8";
+/// The name of the file holding the options (as JSON) within a crash dump archive
+pub const CRASH_DUMP_OPTIONS_FILE: &str = "options.json";
+/// The name of the file holding the error report (as JSON) within a crash dump archive
+pub const CRASH_DUMP_REPORT_FILE: &str = "report.json";
+/// The name of the file holding the instruction trace (as text) within a crash dump archive
+pub const CRASH_DUMP_TRACE_FILE: &str = "trace.txt";
+
+/// A bundle of everything required to investigate and reproduce a processor crash, as returned
+/// by [Processor::crash_dump()]: a copy of the program ROM, the options in effect, the error
+/// report (including the extended state snapshot taken at the point of the crash) and the
+/// instructions fetched immediately beforehand.  Written to a zip archive (conventionally with
+/// the `.chipolata-dump` extension) by [CrashDump::save_to_file()].
+#[derive(Debug, Clone)]
+pub struct CrashDump {
+    program: Vec<u8>,
+    options: Options,
+    report: String,
+    trace: Vec<ExecutionContext>,
+}
+
+impl CrashDump {
+    /// Returns the bytes of the program ROM that was running
+    pub fn program_data(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// Returns the options in effect when the processor crashed
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Returns the error report, as pretty-printed JSON (see [ChipolataError::to_json()])
+    pub fn report(&self) -> &str {
+        &self.report
+    }
+
+    /// Returns the most recently fetched instructions, oldest first, the last being the
+    /// instruction during which the processor crashed (if the crash occurred during execution)
+    pub fn trace(&self) -> &[ExecutionContext] {
+        &self.trace
+    }
+
+    /// Returns the instruction trace as text, one instruction per line, oldest first
+    pub fn trace_text(&self) -> String {
+        self.trace
+            .iter()
+            .map(|context| format!("{}\n", context))
+            .collect()
+    }
+
+    /// Method that writes the passed [CrashDump] instance to the specified file as a zip archive,
+    /// holding the program ROM, the options and error report (as JSON) and the instruction trace
+    /// (as text)
+    #[cfg(feature = "std")]
+    pub fn save_to_file(dump: &CrashDump, file_path: &Path) -> Result<(), ErrorDetail> {
+        if CrashDump::write_archive(dump, file_path).is_none() {
+            return Err(ErrorDetail::FileError {
+                file_path: file_path.to_str().unwrap_or_default().to_owned(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Internal helper function that writes the archive, returning None if any step fails
+    #[cfg(feature = "std")]
+    fn write_archive(dump: &CrashDump, file_path: &Path) -> Option<()> {
+        let serialised_options: String = serde_json::to_string_pretty(&dump.options).ok()?;
+        let trace_text: String = dump.trace_text();
+        let mut archive: ZipWriter<File> = ZipWriter::new(File::create(file_path).ok()?);
+        let file_options: FileOptions =
+            FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let entries: [(&str, &[u8]); 4] = [
+            (CRASH_DUMP_PROGRAM_FILE, &dump.program),
+            (CRASH_DUMP_OPTIONS_FILE, serialised_options.as_bytes()),
+            (CRASH_DUMP_REPORT_FILE, dump.report.as_bytes()),
+            (CRASH_DUMP_TRACE_FILE, trace_text.as_bytes()),
+        ];
+        for (name, contents) in entries {
+            archive.start_file(name, file_options).ok()?;
+            archive.write_all(contents).ok()?;
+        }
+        archive.finish().ok()?;
+        Some(())
+    }
+}
+
+impl Processor {
+    /// Returns a [CrashDump] bundling the specified error (as returned by this processor) with
+    /// a copy of the program ROM, the options in effect (reflecting any change of processor
+    /// speed since initialisation) and the most recently fetched instructions, for saving with
+    /// [CrashDump::save_to_file()].  Intended to be called as soon as the processor has crashed,
+    /// before it is reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - the [ChipolataError] returned when the processor crashed
+    pub fn crash_dump(&self, error: &ChipolataError) -> CrashDump {
+        let mut options: Options = self.options;
+        options.processor_speed_hertz = self.processor_speed_hertz;
+        CrashDump {
+            program: self.program.program_data().clone(),
+            options,
+            report: error.to_json(),
+            trace: self.trace_tail(),
+        }
+    }
+
+    /// Returns the most recently fetched instructions (up to [CRASH_DUMP_TRACE_LENGTH] of them),
+    /// oldest first, each described by the address it was fetched from, its opcode and the cycle
+    /// on which it was fetched
+    pub fn trace_tail(&self) -> Vec<ExecutionContext> {
+        self.trace.iter().copied().collect()
+    }
+
+    /// Internal helper function that records the current execution context in the instruction
+    /// trace, discarding the oldest entry if the trace is full
+    pub(super) fn record_trace(&mut self) {
+        if self.trace.len() == CRASH_DUMP_TRACE_LENGTH {
+            self.trace.pop_front();
+        }
+        self.trace.push_back(self.execution_context);
+    }
+}
@@ -0,0 +1,228 @@
+use super::*;
+
+impl Processor {
+    /// Sets the value of the specified variable register (V0 to VF).  The processor must be
+    /// paused, otherwise [ErrorDetail::ProcessorNotPaused] is returned.  Like the other debugger
+    /// setters, an error leaves the processor status unchanged rather than crashing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `register` - the index of the variable register (valid range 0x0 to 0xF inclusive)
+    /// * `value` - the value to store in the register
+    pub fn set_variable_register(&mut self, register: usize, value: u8) -> Result<(), ErrorDetail> {
+        self.check_paused()?;
+        if register >= VARIABLE_REGISTER_COUNT {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::Register(register)],
+            });
+        }
+        self.variable_registers[register] = value;
+        Ok(())
+    }
+
+    /// Sets the value of the index register.  The processor must be paused, otherwise
+    /// [ErrorDetail::ProcessorNotPaused] is returned
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to store in the index register
+    pub fn set_index_register(&mut self, value: u16) -> Result<(), ErrorDetail> {
+        self.check_paused()?;
+        self.index_register = value;
+        Ok(())
+    }
+
+    /// Sets the value of the program counter.  The processor must be paused, otherwise
+    /// [ErrorDetail::ProcessorNotPaused] is returned
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the memory address at which execution should continue
+    pub fn set_program_counter(&mut self, value: u16) -> Result<(), ErrorDetail> {
+        self.check_paused()?;
+        self.program_counter = value;
+        Ok(())
+    }
+
+    /// Sets the value of the delay timer.  The processor must be paused, otherwise
+    /// [ErrorDetail::ProcessorNotPaused] is returned
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the new delay timer value
+    pub fn set_delay_timer(&mut self, value: u8) -> Result<(), ErrorDetail> {
+        self.check_paused()?;
+        self.delay_timer = value;
+        Ok(())
+    }
+
+    /// Sets the value of the sound timer.  The processor must be paused, otherwise
+    /// [ErrorDetail::ProcessorNotPaused] is returned
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the new sound timer value
+    pub fn set_sound_timer(&mut self, value: u8) -> Result<(), ErrorDetail> {
+        self.check_paused()?;
+        self.sound_timer = value;
+        Ok(())
+    }
+
+    /// Writes a byte to the specified memory address.  The processor must be paused, otherwise
+    /// [ErrorDetail::ProcessorNotPaused] is returned.  If the address is outside the addressable
+    /// range, returns [ErrorDetail::MemoryAddressOutOfBounds].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address at which the byte should be written
+    /// * `value` - the byte value to be written
+    pub fn write_memory_byte(&mut self, address: usize, value: u8) -> Result<(), ErrorDetail> {
+        self.check_paused()?;
+        self.memory.write_byte_privileged(address, value)?;
+        self.instruction_cache.invalidate(address..address + 1);
+        self.halt_watch.clear();
+        Ok(())
+    }
+
+    /// Registers a watch expression to be evaluated after each instruction executes, returning an
+    /// identifier by which it may later be removed.  If `break_on_true` is set, execution is
+    /// paused whenever the expression evaluates to a non-zero value.  Watches are retained when
+    /// the processor is reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `expression` - the parsed [WatchExpression] to evaluate
+    /// * `break_on_true` - whether to pause execution when the expression is true (non-zero)
+    pub fn add_watch(&mut self, expression: WatchExpression, break_on_true: bool) -> usize {
+        let id: usize = self.next_watch_id;
+        self.next_watch_id += 1;
+        self.watches.push(RegisteredWatch {
+            id,
+            expression,
+            break_on_true,
+            value: None,
+        });
+        id
+    }
+
+    /// Removes the watch with the specified identifier, returning false if there was none
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the identifier returned by [Processor::add_watch()]
+    pub fn remove_watch(&mut self, id: usize) -> bool {
+        let count: usize = self.watches.len();
+        self.watches.retain(|watch| watch.id != id);
+        self.watches.len() != count
+    }
+
+    /// Removes all registered watches
+    pub fn clear_watches(&mut self) {
+        self.watches.clear();
+        self.break_watch_id = None;
+    }
+
+    /// Starts or stops recording subroutine calls and returns, for profiling the call structure
+    /// of the running program (see [Stack::set_event_recording()]).  Recording continues across
+    /// resets and restored states, though the events and call tree are cleared on reset.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to start recording, false to stop
+    pub fn set_stack_event_recording(&mut self, enabled: bool) {
+        self.stack.set_event_recording(enabled);
+    }
+
+    /// Removes and returns the subroutine call and return events recorded since this was last
+    /// called (oldest first), or an empty vector if recording is not enabled
+    pub fn take_stack_events(&mut self) -> Vec<StackEvent> {
+        self.stack.take_events()
+    }
+
+    /// Returns a summary of the subroutine calls recorded, as a tree whose root is the program's
+    /// top level, or None if recording is not enabled
+    pub fn call_tree(&self) -> Option<CallTreeNode> {
+        self.stack.call_tree()
+    }
+
+    /// Returns a [DebugReport] holding the latest result of each registered watch
+    pub fn debug_report(&self) -> DebugReport {
+        DebugReport {
+            cycles: self.cycles,
+            program_counter: self.program_counter,
+            watches: self
+                .watches
+                .iter()
+                .map(|watch| WatchResult {
+                    id: watch.id,
+                    expression: watch.expression.to_string(),
+                    break_on_true: watch.break_on_true,
+                    value: watch.value,
+                })
+                .collect(),
+            break_watch_id: self.break_watch_id,
+        }
+    }
+
+    /// Evaluates each registered watch against the current state, pausing execution if a watch
+    /// with break-on-true semantics evaluates to a non-zero value (the first such watch being
+    /// recorded as the cause) while the processor is running
+    pub(super) fn evaluate_watches(&mut self) {
+        if self.watches.is_empty() {
+            return;
+        }
+        let values: Vec<Option<i64>> = self
+            .watches
+            .iter()
+            .map(|watch| watch.expression.evaluate(&*self))
+            .collect();
+        let mut break_watch_id: Option<usize> = None;
+        for (watch, value) in self.watches.iter_mut().zip(values) {
+            watch.value = value;
+            if watch.break_on_true && break_watch_id.is_none() && value.unwrap_or(0) != 0 {
+                break_watch_id = Some(watch.id);
+            }
+        }
+        if break_watch_id.is_some() && self.status == ProcessorStatus::Running {
+            self.break_watch_id = break_watch_id;
+            self.set_status(ProcessorStatus::Paused);
+        }
+    }
+
+    /// Helper method that returns [ErrorDetail::ProcessorNotPaused] unless the processor is
+    /// currently paused (state may only be modified by the host while execution is suspended)
+    fn check_paused(&self) -> Result<(), ErrorDetail> {
+        match self.status {
+            ProcessorStatus::Paused => Ok(()),
+            _ => Err(ErrorDetail::ProcessorNotPaused {
+                status: self.status,
+            }),
+        }
+    }
+}
+
+impl WatchContext for Processor {
+    fn variable_register(&self, register: usize) -> u8 {
+        self.variable_registers[register]
+    }
+
+    fn index_register(&self) -> u16 {
+        self.index_register
+    }
+
+    fn program_counter(&self) -> u16 {
+        self.program_counter
+    }
+
+    fn delay_timer(&self) -> u8 {
+        self.delay_timer
+    }
+
+    fn sound_timer(&self) -> u8 {
+        self.sound_timer
+    }
+
+    fn memory_byte(&self, address: usize) -> Option<u8> {
+        self.memory.read_byte(address).ok()
+    }
+}
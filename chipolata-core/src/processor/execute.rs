@@ -1,5 +1,4 @@
 use super::*;
-use std::collections::HashMap;
 
 impl Processor {
     /// Executes the 004B instruction - [turn on COSMAC VIP display]
@@ -11,14 +10,18 @@ impl Processor {
 
     /// Executes the 00CN instruction - SCD nibble
     /// Purpose: [SUPER-CHIP 1.1] scroll display N pixels down (N/2 in low-resolution mode)
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    ///          [CHIP-8 / CHIP-8X / CHIP-48 / SUPER-CHIP 1.0] this will error as an
+    ///          [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_00CN(&mut self, n: u8) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
             EmulationLevel::SuperChip11 { .. } => {
                 self.frame_buffer.scroll_display_down(n)?;
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. } => {
                 let opcode: u16 = 0x00C0 | (n as u16);
                 Err(ErrorDetail::UnknownInstruction { opcode })
             }
@@ -37,21 +40,25 @@ impl Processor {
     /// Purpose: return from a subroutine
     pub(super) fn execute_00EE(&mut self) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 50;
-        let address: u16 = self.stack.pop()?;
+        let address: u16 = self.stack.ret(self.cycles)?;
         self.program_counter = address;
         Ok(CYCLES)
     }
 
     /// Executes the 00FB instruction - SCR
     /// Purpose: [SUPER-CHIP 1.1] scroll right by 4 pixels (2 in low-resolution mode)
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    ///          [CHIP-8 / CHIP-8X / CHIP-48 / SUPER-CHIP 1.0] this will error as an
+    ///          [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_00FB(&mut self) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
             EmulationLevel::SuperChip11 { .. } => {
                 self.frame_buffer.scroll_display_right()?;
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. } => {
                 Err(ErrorDetail::UnknownInstruction { opcode: 0x00FB })
             }
         }
@@ -59,80 +66,143 @@ impl Processor {
 
     /// Executes the 00FC instruction - SCL
     /// Purpose: [SUPER-CHIP 1.1] scroll left by 4 pixels (2 in low-resolution mode)
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    ///          [CHIP-8 / CHIP-8X / CHIP-48 / SUPER-CHIP 1.0] this will error as an
+    ///          [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_00FC(&mut self) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
             EmulationLevel::SuperChip11 { .. } => {
                 self.frame_buffer.scroll_display_left()?;
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. } => {
                 Err(ErrorDetail::UnknownInstruction { opcode: 0x00FC })
             }
         }
     }
 
     /// Executes the 00FD instruction - EXIT
-    /// Purpose: [SUPER-CHIP 1.1] exit the interpreter (set status to [ProcessorStatus::Complete])
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    /// Purpose: [SUPER-CHIP 1.0 / 1.1] exit the interpreter (set status to [ProcessorStatus::Complete])
+    ///          [CHIP-8 / CHIP-8X / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_00FD(&mut self) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
-            EmulationLevel::SuperChip11 { .. } => {
-                self.status = ProcessorStatus::Completed;
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
+                self.set_status(ProcessorStatus::Completed);
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. } => {
                 Err(ErrorDetail::UnknownInstruction { opcode: 0x00FD })
             }
         }
     }
 
     /// Executes the 00FE instruction - LOW
-    /// Purpose: [SUPER-CHIP 1.1] disable high-resolution mode
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    /// Purpose: [SUPER-CHIP 1.0 / 1.1] disable high-resolution mode
+    ///          [CHIP-8 / CHIP-8X / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_00FE(&mut self) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
             EmulationLevel::SuperChip11 {
                 octo_compatibility_mode,
+                variable_cycle_timing: _,
             } => {
-                self.high_resolution_mode = false;
+                self.set_resolution_mode(false);
                 if octo_compatibility_mode {
                     // only clear screen in OCTO mode
                     self.frame_buffer.clear();
                 }
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::SuperChip10 { .. } => {
+                self.set_resolution_mode(false);
+                Ok(0)
+            }
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. } => {
                 Err(ErrorDetail::UnknownInstruction { opcode: 0x00FE })
             }
         }
     }
 
     /// Executes the 00FF instruction - HIGH
-    /// Purpose: [SUPER-CHIP 1.1] enable high-resolution mode
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    /// Purpose: [SUPER-CHIP 1.0 / 1.1] enable high-resolution mode
+    ///          [CHIP-8 / CHIP-8X / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_00FF(&mut self) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
             EmulationLevel::SuperChip11 {
                 octo_compatibility_mode,
+                variable_cycle_timing: _,
             } => {
-                self.high_resolution_mode = true;
+                self.set_resolution_mode(true);
                 if octo_compatibility_mode {
                     // only clear screen in OCTO mode
                     self.frame_buffer.clear();
                 }
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::SuperChip10 { .. } => {
+                self.set_resolution_mode(true);
+                Ok(0)
+            }
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. } => {
                 Err(ErrorDetail::UnknownInstruction { opcode: 0x00FF })
             }
         }
     }
 
+    /// Executes the 02A0 instruction - CLR BG
+    /// Purpose: [CHIP-8X] cycle the background colour through blue, black, green and red
+    ///          [CHIP-8 / CHIP-48 / SUPER-CHIP 1.1] this is a 0NNN machine code routine call
+    pub(super) fn execute_02A0(&mut self) -> Result<u64, ErrorDetail> {
+        // Timing is estimated, as per a typical machine code routine call
+        const CYCLES: u64 = 48;
+        match self.emulation_level {
+            EmulationLevel::Chip8X { .. } => {
+                self.frame_buffer.cycle_background_colour();
+                Ok(CYCLES)
+            }
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => self.execute_0NNN(0x2A0),
+        }
+    }
+
     /// Executes the 0NNN instruction - SYS addr
-    /// Purpose: jump to a machine code routine at NNN
+    /// Purpose: jump to a machine code routine at NNN (delegated to the host's
+    ///          [SystemRoutineHandler], if one is registered, otherwise this will error)
+    ///          [Hi-Res CHIP-8] 0230 clears the display, as per 00E0
     pub(super) fn execute_0NNN(&mut self, nnn: u16) -> Result<u64, ErrorDetail> {
-        Err(ErrorDetail::UnimplementedInstruction { opcode: nnn })
+        // The Hi-Res CHIP-8 interpreter clears its display through a built-in routine
+        if self.chip8_hires_mode && nnn == CHIP8_HIRES_CLEAR_SCREEN_ROUTINE {
+            return self.execute_00E0();
+        }
+        // Temporarily take ownership of the handler so it can be passed mutable references to
+        // the processor state
+        let mut handler: Box<dyn SystemRoutineHandler> = match self.system_routine_handler.take() {
+            Some(handler) => handler,
+            None => return Err(ErrorDetail::UnimplementedInstruction { opcode: nnn }),
+        };
+        let mut context: SystemRoutineContext = SystemRoutineContext {
+            variable_registers: &mut self.variable_registers,
+            index_register: &mut self.index_register,
+            program_counter: &mut self.program_counter,
+            delay_timer: &mut self.delay_timer,
+            sound_timer: &mut self.sound_timer,
+            memory: &mut self.memory,
+            frame_buffer: &mut self.frame_buffer,
+        };
+        let result: Result<u64, ErrorDetail> = handler.call_routine(nnn, &mut context);
+        self.system_routine_handler = Some(handler);
+        // The routine may have written anywhere in memory, so nothing decoded can be relied upon
+        self.instruction_cache.clear();
+        result
     }
 
     /// Executes the 1NNN instruction - JP addr
@@ -147,7 +217,7 @@ impl Processor {
     /// Purpose: call subroutine at NNN
     pub(super) fn execute_2NNN(&mut self, nnn: u16) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 94;
-        self.stack.push(self.program_counter)?;
+        self.stack.call(nnn, self.program_counter, self.cycles)?;
         self.program_counter = nnn;
         Ok(CYCLES)
     }
@@ -158,9 +228,9 @@ impl Processor {
         const CYCLES_IF_TRUE: u64 = 82;
         const CYCLES_IF_FALSE: u64 = 78;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         // Compare the value in register Vx to passed value NN
         if self.variable_registers[x] == nn {
@@ -178,9 +248,9 @@ impl Processor {
         const CYCLES_IF_TRUE: u64 = 82;
         const CYCLES_IF_FALSE: u64 = 78;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         // Compare the value in register Vx to passed value NN
         if self.variable_registers[x] != nn {
@@ -198,10 +268,9 @@ impl Processor {
         const CYCLES_IF_TRUE: u64 = 86;
         const CYCLES_IF_FALSE: u64 = 82;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         // Compare the value in registers Vx and Vy
         if self.variable_registers[x] == self.variable_registers[y] {
@@ -213,14 +282,42 @@ impl Processor {
         }
     }
 
+    /// Executes the 5XY1 instruction - ADD Vx, Vy (colour)
+    /// Purpose: [CHIP-8X] add each nibble of Vy to the corresponding nibble of Vx, each result
+    ///          wrapping modulo 8 (as each nibble holds a three-bit colour value)
+    ///          [CHIP-8 / CHIP-48 / SUPER-CHIP 1.1] the final nibble is ignored, as per 5XY0
+    pub(super) fn execute_5XY1(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
+        // Timing is estimated, as per the comparable 8XY4 instruction
+        const CYCLES: u64 = 112;
+        match self.emulation_level {
+            EmulationLevel::Chip8X { .. } => {
+                if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::X(x), Operand::Y(y)],
+                    });
+                }
+                let (vx, vy): (u8, u8) = (self.variable_registers[x], self.variable_registers[y]);
+                // Add the high and low nibbles separately, discarding any carry between them
+                let high_nibble: u8 = ((vx >> 4) + (vy >> 4)) & 0x07;
+                let low_nibble: u8 = ((vx & 0x0F) + (vy & 0x0F)) & 0x07;
+                self.variable_registers[x] = (high_nibble << 4) | low_nibble;
+                Ok(CYCLES)
+            }
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => self.execute_5XY0(x, y),
+        }
+    }
+
     /// Executes the 6XNN instruction - LD Vx, byte
     /// Purpose: set Vx = NN
     pub(super) fn execute_6XNN(&mut self, x: usize, nn: u8) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 74;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         self.variable_registers[x] = nn;
         Ok(CYCLES)
@@ -231,9 +328,9 @@ impl Processor {
     pub(super) fn execute_7XNN(&mut self, x: usize, nn: u8) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 78;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         // Set Vx equal to itself plus NN
         self.variable_registers[x] =
@@ -246,10 +343,9 @@ impl Processor {
     pub(super) fn execute_8XY0(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 80;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         self.variable_registers[x] = self.variable_registers[y];
         Ok(CYCLES)
@@ -260,14 +356,16 @@ impl Processor {
     pub(super) fn execute_8XY1(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         // Set Vx = Vx | Vy
         self.variable_registers[x] = self.variable_registers[x] | self.variable_registers[y];
-        if let EmulationLevel::Chip8 { .. } = self.emulation_level {
+        if matches!(
+            self.emulation_level,
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. }
+        ) {
             self.variable_registers[0xF] = 0;
         }
         Ok(CYCLES)
@@ -278,14 +376,16 @@ impl Processor {
     pub(super) fn execute_8XY2(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         // Set Vx = Vx & Vy
         self.variable_registers[x] = self.variable_registers[x] & self.variable_registers[y];
-        if let EmulationLevel::Chip8 { .. } = self.emulation_level {
+        if matches!(
+            self.emulation_level,
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. }
+        ) {
             self.variable_registers[0xF] = 0;
         }
         Ok(CYCLES)
@@ -296,14 +396,16 @@ impl Processor {
     pub(super) fn execute_8XY3(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         // Set Vx = Vx ^ Vy
         self.variable_registers[x] = self.variable_registers[x] ^ self.variable_registers[y];
-        if let EmulationLevel::Chip8 { .. } = self.emulation_level {
+        if matches!(
+            self.emulation_level,
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. }
+        ) {
             self.variable_registers[0xF] = 0;
         }
         Ok(CYCLES)
@@ -314,10 +416,9 @@ impl Processor {
     pub(super) fn execute_8XY4(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         // Cast Vx and Vy as u16 (to allow overflow beyond u8 range), add, and store in temp variable
         let result: u16 = (self.variable_registers[x] as u16) + (self.variable_registers[y] as u16);
@@ -337,10 +438,9 @@ impl Processor {
     pub(super) fn execute_8XY5(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         // Cast Vx and Vy as i16 (to allow signed result), subtract, and store in temp variable
         let result: i16 = (self.variable_registers[x] as i16) - (self.variable_registers[y] as i16);
@@ -362,16 +462,19 @@ impl Processor {
     pub(super) fn execute_8XY6(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         match self.emulation_level {
             // CHIP-8 first sets Vx to Vy
-            EmulationLevel::Chip8 { .. } => self.variable_registers[x] = self.variable_registers[y],
-            // CHIP-48 and SUPER-CHIP 1.1 ignore Vy
-            EmulationLevel::Chip48 | EmulationLevel::SuperChip11 { .. } => {}
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+                self.variable_registers[x] = self.variable_registers[y]
+            }
+            // CHIP-48 and SUPER-CHIP ignore Vy
+            EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => {}
         }
         // Check if least significant bit of Vx is 1; if so at the end we set Vf to 1 otherwise 0
         let flag_value: u8 = match self.variable_registers[x] & 0x01 == 0x01 {
@@ -389,10 +492,9 @@ impl Processor {
     pub(super) fn execute_8XY7(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         // Cast Vx and Vy as i16 (to allow signed result), subtract, and store in temp variable
         let result: i16 = (self.variable_registers[y] as i16) - (self.variable_registers[x] as i16);
@@ -413,16 +515,19 @@ impl Processor {
     pub(super) fn execute_8XYE(&mut self, x: usize, y: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 112;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         }
         match self.emulation_level {
             // CHIP-8 first sets Vx to Vy
-            EmulationLevel::Chip8 { .. } => self.variable_registers[x] = self.variable_registers[y],
-            // CHIP-48 and SUPER-CHIP 1.1 ignore Vy
-            EmulationLevel::Chip48 | EmulationLevel::SuperChip11 { .. } => {}
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+                self.variable_registers[x] = self.variable_registers[y]
+            }
+            // CHIP-48 and SUPER-CHIP ignore Vy
+            EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => {}
         }
         // Check if most significant bit of Vx is 1; if so at the end we set Vf to 1 otherwise 0
         let flag_value: u8 = match self.variable_registers[x] & 0x80 == 0x80 {
@@ -441,10 +546,9 @@ impl Processor {
         const CYCLES_IF_TRUE: u64 = 86;
         const CYCLES_IF_FALSE: u64 = 82;
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
         } else if self.variable_registers[x] != self.variable_registers[y] {
             // Compare the value in registers Vx and Vy.  If they are not equal, increment the
             // program counter by 2 bytes (1 opcode)
@@ -466,19 +570,27 @@ impl Processor {
     /// Executes the BNNN instruction - JP V0, addr
     /// Purpose: [CHIP-8] jump to location NNN + V0
     ///          [CHIP-48 / SUPER-CHIP 1.1] jump to location xNN + Vx   
+    ///          [CHIP-8X] this is instead BXYN (see [Processor::execute_BXYN_chip8x()])
     pub(super) fn execute_BNNN(&mut self, nnn: u16) -> Result<u64, ErrorDetail> {
         const CYCLES_IF_PAGE_CROSSED: u64 = 92;
         const CYCLES_IF_PAGE_NOT_CROSSED: u64 = 90;
+        if let EmulationLevel::Chip8X { .. } = self.emulation_level {
+            let x: usize = ((nnn & 0x0F00) >> 8) as usize;
+            let y: usize = ((nnn & 0x00F0) >> 4) as usize;
+            return self.execute_BXYN_chip8x(x, y, (nnn & 0x000F) as u8);
+        }
         // Check if the jump is across page boundaries, by comparing the 3rd least significant
         // nibble of the jump address and current program counters
         let page_boundary_crossed: bool =
             ((nnn + (self.variable_registers[0] as u16)) & 0xF00) != (self.program_counter & 0xF00);
         self.program_counter = match self.emulation_level {
-            EmulationLevel::Chip8 { .. } => {
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
                 // Set the program counter to NNN plus the value in register V0
                 nnn + (self.variable_registers[0] as u16)
             }
-            EmulationLevel::Chip48 | EmulationLevel::SuperChip11 { .. } => {
+            EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => {
                 // isolate the first hex digit
                 let x: u16 = (nnn & 0x0F00) >> 8;
                 // Set the program counter to XNN plus the value in register VX
@@ -492,18 +604,58 @@ impl Processor {
         }
     }
 
+    /// Executes the BXYN instruction - COL Vx, Vy, nibble
+    /// Purpose: [CHIP-8X] set the foreground colour of an area of the display to Vy
+    ///          N = 0: the low nibbles of Vx and Vx+1 give the left column and top row of the area
+    ///          in 8x4 pixel blocks, and the high nibbles give the number of further blocks across
+    ///          and down to be coloured
+    ///          N > 0: colour N rows of 8x1 pixel zones starting at the zone containing the pixel
+    ///          at coordinates (Vx, Vx+1)
+    fn execute_BXYN_chip8x(&mut self, x: usize, y: usize, n: u8) -> Result<u64, ErrorDetail> {
+        // Timing is estimated, as per the comparable 00E0 instruction
+        const CYCLES: u64 = 64;
+        const BLOCK_HEIGHT_PIXELS: usize = 4;
+        if x + 1 >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT {
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y)],
+            });
+        }
+        let horizontal: usize = self.variable_registers[x] as usize;
+        let vertical: usize = self.variable_registers[x + 1] as usize;
+        let colour: u8 = self.variable_registers[y];
+        match n {
+            0 => self.frame_buffer.set_zone_colours(
+                horizontal & 0x0F,
+                (vertical & 0x0F) * BLOCK_HEIGHT_PIXELS,
+                (horizontal >> 4) + 1,
+                ((vertical >> 4) + 1) * BLOCK_HEIGHT_PIXELS,
+                colour,
+            ),
+            _ => self.frame_buffer.set_zone_colours(
+                horizontal / CHIP8X_ZONE_WIDTH_PIXELS,
+                vertical,
+                1,
+                n as usize,
+                colour,
+            ),
+        }
+        Ok(CYCLES)
+    }
+
     /// Executes the CXNN instruction - RND Vx, byte
     /// Purpose: set Vx = random byte & NN (bitwise AND)
     pub(super) fn execute_CXNN(&mut self, x: usize, nn: u8) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 104;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         // Generate a random u8 value and store in temp variable
-        let mut rng = rand::thread_rng();
-        let rand: u8 = rng.gen();
+        let rand: u8 = match self.vip_random_generator {
+            true => self.vip_random_byte()?,
+            false => self.rng.gen(),
+        };
         // Set Vx = bitwise AND of value NN and random value
         self.variable_registers[x] = nn & rand;
         Ok(CYCLES)
@@ -518,42 +670,77 @@ impl Processor {
     ///                           n is rows that collide or clip screen bottom
     pub(super) fn execute_DXYN(&mut self, x: usize, y: usize, n: u8) -> Result<u64, ErrorDetail> {
         if x >= VARIABLE_REGISTER_COUNT || y >= VARIABLE_REGISTER_COUNT || n > MAX_SPRITE_HEIGHT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            operands.insert("y".to_string(), y);
-            operands.insert("n".to_string(), n as usize);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x), Operand::Y(y), Operand::N(n)],
+            });
         }
+        // If drawing waits for the display (always in CHIP-8 mode; optionally in CHIP-48 and
+        // SUPER-CHIP 1.1 low resolution mode) then, when this instruction is executed from "Idle",
+        // we must first wait for the next vblank interrupt; during this time our status will be
+        // "WaitingforVBlank".  When the interrupt occurs, status will change to "ReadyToDraw", and
+        // we can proceed.  Immediately following the draw completion, status returns to "Idle".
+        // The same applies once the per-frame sprite budget (if any) has been used up; the budget
+        // is replenished by the vblank interrupt, so the wait may need completing after it is.
+        if self.sprite_draw_must_wait() || self.vblank_status != VBlankStatus::Idle {
+            match self.vblank_status {
+                VBlankStatus::Idle => {
+                    self.vblank_status = VBlankStatus::WaitingForVBlank;
+                    self.program_counter -= 2;
+                    return Ok(0);
+                }
+                VBlankStatus::WaitingForVBlank => {
+                    self.program_counter -= 2;
+                    return Ok(0);
+                }
+                VBlankStatus::ReadyToDraw => self.vblank_status = VBlankStatus::Idle,
+            }
+        }
+        // If instead the COSMAC VIP display is modelled scanline by scanline, we wait only until
+        // the raster has passed the rows the sprite occupies, so that drawing does not tear
+        if self.raster_wait_required(y, n) {
+            self.program_counter -= 2;
+            return Ok(0);
+        }
+        self.sprites_drawn_this_frame += 1;
         match self.emulation_level {
-            EmulationLevel::Chip8 { .. } => {
-                // When this instruction is executed from "Idle", we must first wait for the next
-                // vblank interrupt; during this time our status will be "WaitingforVBlank".  When
-                // the interrupt occurs, status will change to "ReadyToDraw", and we can proceed.
-                // Immediately following the draw completion, status returns to "Idle".
-                match self.vblank_status {
-                    VBlankStatus::Idle => {
-                        self.vblank_status = VBlankStatus::WaitingForVBlank;
-                        self.program_counter -= 2;
-                        Ok(0)
-                    }
-                    VBlankStatus::WaitingForVBlank => {
-                        self.program_counter -= 2;
-                        Ok(0)
-                    }
-                    VBlankStatus::ReadyToDraw => {
-                        self.vblank_status = VBlankStatus::Idle;
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+                self.execute_DXYN_chip8(x, y, n)
+            }
+            EmulationLevel::Chip48 { .. } => {
+                self.execute_DXYN_chip8(x, y, n) // delegate to standard CHIP-8 method
+            }
+            EmulationLevel::SuperChip10 { .. } => {
+                // SUPER-CHIP 1.0 draws DXY0 in low-resolution mode as an 8x16 sprite, rather than
+                // drawing nothing as SUPER-CHIP 1.1 does
+                let n: u8 = match (self.high_resolution_mode, n) {
+                    (false, 0) => SUPERCHIP10_LOW_RES_DXY0_HEIGHT,
+                    _ => n,
+                };
+                match (self.high_resolution_mode, n) {
+                    (true, 0) => self.execute_DXY0_superchip11(x, y), // special behaviour where n = 0
+                    // Once the display has been resized for low-resolution mode, sprites are drawn
+                    // at their natural size rather than as 2x2 pixels
+                    (false, _) if self.resize_low_resolution_display => {
                         self.execute_DXYN_chip8(x, y, n)
                     }
+                    (false, _) => self.execute_DXYN_superchip11_low_res(x, y, n),
+                    (true, _) => self.execute_DXYN_chip8(x, y, n), // delegate to standard CHIP-8 method
                 }
             }
-            EmulationLevel::Chip48 => {
-                self.execute_DXYN_chip8(x, y, n) // delegate to standard CHIP-8 method
-            }
             EmulationLevel::SuperChip11 {
                 octo_compatibility_mode,
+                variable_cycle_timing: _,
             } => {
                 match (self.high_resolution_mode, octo_compatibility_mode, n) {
                     (true, _, 0) => self.execute_DXY0_superchip11(x, y), // special behaviour where n = 0
+                    // Once the display has been resized for low-resolution mode, sprites are drawn
+                    // at their natural size rather than as 2x2 pixels
+                    (false, true, 0) if self.resize_low_resolution_display => {
+                        self.execute_DXY0_superchip11(x, y)
+                    }
+                    (false, ..) if self.resize_low_resolution_display => {
+                        self.execute_DXYN_chip8(x, y, n)
+                    }
                     (false, true, 0) => self.execute_DXY0_superchip11_low_res(x, y), // OCTO-only behaviour
                     (false, ..) => self.execute_DXYN_superchip11_low_res(x, y, n),
                     (true, ..) => self.execute_DXYN_chip8(x, y, n), // delegate to standard CHIP-8 method
@@ -580,6 +767,7 @@ impl Processor {
             self.variable_registers[y] as usize,
             sprite,
             false,
+            self.sprite_edge_mode,
         )?;
         // If in high-resolution mode for SUPER-CHIP 1.1 emulation level, set Vf to the number
         // of rows that either underwent collision or were clipped off the bottom of the screen
@@ -595,8 +783,7 @@ impl Processor {
             }
         };
         // Now calculate a randomised cycle execution value within possible range
-        let mut rng = rand::thread_rng();
-        Ok(BASE_CYCLES + rng.gen_range(0..=MAX_EXTRA_EXECUTE_CYCLES))
+        Ok(BASE_CYCLES + self.rng.gen_range(0..=MAX_EXTRA_EXECUTE_CYCLES))
     }
 
     // Private function to execute low-DXYN for SUPER-CHIP 1.1 emulation level
@@ -632,18 +819,22 @@ impl Processor {
             self.variable_registers[y] as usize * 2,
             &sprite_left,
             false,
+            self.sprite_edge_mode,
         )?;
-        // We cannot draw the right-hand sprite if it will wrap; instead we must clip
+        // Unless sprites are set to wrap, we cannot draw the right-hand sprite if it will wrap;
+        // instead we must clip
         let mut rows_with_collisions_right: u8 = 0;
-        if ((self.variable_registers[x] as usize * 2 / 8) + 1)
-            % self.frame_buffer.get_row_size_bytes()
-            != 0
+        if self.sprite_edge_mode == SpriteEdgeMode::Wrap
+            || ((self.variable_registers[x] as usize * 2 / 8) + 1)
+                % self.frame_buffer.get_row_size_bytes()
+                != 0
         {
             (rows_with_collisions_right, _) = self.frame_buffer.draw_sprite(
                 (self.variable_registers[x] as usize * 2) + 8,
                 self.variable_registers[y] as usize * 2,
                 &sprite_right,
                 false,
+                self.sprite_edge_mode,
             )?;
         }
         // Finally, set Vf according to whether any collisions occurred
@@ -678,10 +869,14 @@ impl Processor {
             self.variable_registers[y] as usize,
             sprite,
             true,
+            self.sprite_edge_mode,
         )?;
-        // Set Vf to the number of rows that underwent collision or were clipped off the bottom of
-        // the screen
-        self.variable_registers[0xF] = rows_with_collisions + rows_clipped;
+        // In high-resolution mode, set Vf to the number of rows that underwent collision or were
+        // clipped off the bottom of the screen; otherwise set Vf to 1 if collision occurred
+        self.variable_registers[0xF] = match self.high_resolution_mode {
+            true => rows_with_collisions + rows_clipped,
+            false => (rows_with_collisions > 0) as u8,
+        };
         Ok(0)
     }
 
@@ -724,18 +919,22 @@ impl Processor {
             self.variable_registers[y] as usize * 2,
             &sprite_left,
             true,
+            self.sprite_edge_mode,
         )?;
-        // We cannot draw the right-hand sprite if it will wrap; instead we must clip
+        // Unless sprites are set to wrap, we cannot draw the right-hand sprite if it will wrap;
+        // instead we must clip
         let mut rows_with_collisions_right: u8 = 0;
-        if ((self.variable_registers[x] as usize * 2 / 8) + 3)
-            % self.frame_buffer.get_row_size_bytes()
-            != 0
+        if self.sprite_edge_mode == SpriteEdgeMode::Wrap
+            || ((self.variable_registers[x] as usize * 2 / 8) + 3)
+                % self.frame_buffer.get_row_size_bytes()
+                != 0
         {
             (rows_with_collisions_right, _) = self.frame_buffer.draw_sprite(
                 (self.variable_registers[x] as usize * 2) + 16,
                 self.variable_registers[y] as usize * 2,
                 &sprite_right,
                 true,
+                self.sprite_edge_mode,
             )?;
         }
         // Finally, set Vf according to whether any collisions occurred
@@ -753,9 +952,9 @@ impl Processor {
         const CYCLES_IF_TRUE: u64 = 86;
         const CYCLES_IF_FALSE: u64 = 82;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         let key: u8 = self.variable_registers[x]; // get the value stored in Vx
                                                   // Check whether the current keystate indicates the corresponding key is pressed
@@ -763,7 +962,9 @@ impl Processor {
         if key_pressed {
             // If so, increment the program counter by 2 bytes (1 opcode)
             self.program_counter += 2;
-            //self.keystate.set_key_status(key, false)?; // Set key status to unpressed to prevent immediate repeats
+            if self.key_repeat_suppression {
+                self.keystate.set_key_status(key, false)?; // Set key status to unpressed to prevent immediate repeats
+            }
             Ok(CYCLES_IF_TRUE)
         } else {
             Ok(CYCLES_IF_FALSE)
@@ -776,9 +977,9 @@ impl Processor {
         const CYCLES_IF_TRUE: u64 = 86;
         const CYCLES_IF_FALSE: u64 = 82;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         let key: u8 = self.variable_registers[x]; // get the value stored in Vx
                                                   // Check whether the current keystate indicates the corresponding key is pressed
@@ -788,19 +989,87 @@ impl Processor {
             self.program_counter += 2;
             Ok(CYCLES_IF_TRUE)
         } else {
-            //self.keystate.set_key_status(key, false)?; // Set key status to unpressed to prevent immediate repeats
+            if self.key_repeat_suppression {
+                self.keystate.set_key_status(key, false)?; // Set key status to unpressed to prevent immediate repeats
+            }
             Ok(CYCLES_IF_FALSE)
         }
     }
 
+    /// Executes the EXF2 instruction - SKP2 Vx
+    /// Purpose: [CHIP-8X] skip next instruction if the key with value Vx on the second keypad is
+    ///          pressed
+    ///          [CHIP-8 / CHIP-48 / SUPER-CHIP 1.1] this will error as an [ErrorDetail::UnknownInstruction]
+    pub(super) fn execute_EXF2(&mut self, x: usize) -> Result<u64, ErrorDetail> {
+        const CYCLES_IF_TRUE: u64 = 86;
+        const CYCLES_IF_FALSE: u64 = 82;
+        match self.emulation_level {
+            EmulationLevel::Chip8X { .. } => {
+                if x >= VARIABLE_REGISTER_COUNT {
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::X(x)],
+                    });
+                }
+                let key: u8 = self.variable_registers[x]; // get the value stored in Vx
+                if self.second_keystate.is_key_pressed(key)? {
+                    // If so, increment the program counter by 2 bytes (1 opcode)
+                    self.program_counter += 2;
+                    Ok(CYCLES_IF_TRUE)
+                } else {
+                    Ok(CYCLES_IF_FALSE)
+                }
+            }
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => {
+                let opcode: u16 = 0xE0F2 | ((x as u16) << 8);
+                Err(ErrorDetail::UnknownInstruction { opcode })
+            }
+        }
+    }
+
+    /// Executes the EXF5 instruction - SKNP2 Vx
+    /// Purpose: [CHIP-8X] skip next instruction if the key with value Vx on the second keypad is
+    ///          not pressed
+    ///          [CHIP-8 / CHIP-48 / SUPER-CHIP 1.1] this will error as an [ErrorDetail::UnknownInstruction]
+    pub(super) fn execute_EXF5(&mut self, x: usize) -> Result<u64, ErrorDetail> {
+        const CYCLES_IF_TRUE: u64 = 86;
+        const CYCLES_IF_FALSE: u64 = 82;
+        match self.emulation_level {
+            EmulationLevel::Chip8X { .. } => {
+                if x >= VARIABLE_REGISTER_COUNT {
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::X(x)],
+                    });
+                }
+                let key: u8 = self.variable_registers[x]; // get the value stored in Vx
+                if !self.second_keystate.is_key_pressed(key)? {
+                    // If not, increment the program counter by 2 bytes (1 opcode)
+                    self.program_counter += 2;
+                    Ok(CYCLES_IF_TRUE)
+                } else {
+                    Ok(CYCLES_IF_FALSE)
+                }
+            }
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => {
+                let opcode: u16 = 0xE0F5 | ((x as u16) << 8);
+                Err(ErrorDetail::UnknownInstruction { opcode })
+            }
+        }
+    }
+
     /// Executes the FX07 instruction - LD Vx, DT
     /// Purpose: set Vx = delay timer value
     pub(super) fn execute_FX07(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 78;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         self.variable_registers[x] = self.delay_timer;
         Ok(CYCLES)
@@ -811,9 +1080,9 @@ impl Processor {
     pub(super) fn execute_FX0A(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 19072;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         match self.status {
             ProcessorStatus::Running => {
@@ -822,7 +1091,7 @@ impl Processor {
                 // Initialise the waiting key press vector
                 self.keys_pressed_since_wait = Vec::new();
                 // Set processor state to "Waiting"
-                self.status = ProcessorStatus::WaitingForKeypress;
+                self.set_status(ProcessorStatus::WaitingForKeypress);
                 // Decrement the program counter by by 2 bytes (1 opcode) repeat this instruction
                 self.program_counter -= 2;
             }
@@ -844,7 +1113,7 @@ impl Processor {
                 if keys_released.len() > 0 {
                     // We have a key released; stop waiting
                     self.variable_registers[x] = keys_released[0];
-                    self.status = ProcessorStatus::Running;
+                    self.set_status(ProcessorStatus::Running);
                 } else {
                     // Construct the vector of keys_pressed_now minus keys_pressed_since_wait minus
                     // keys_pressed_at_wait i.e. anything newly-pressed this cycle.  Add to
@@ -854,9 +1123,19 @@ impl Processor {
                         .filter(|key| !self.keys_pressed_since_wait.contains(key))
                         .filter(|key| !keys_pressed_at_wait.contains(key))
                         .collect();
-                    self.keys_pressed_since_wait.append(&mut keys_newly_pressed);
-                    // Decrement the program counter by by 2 bytes (1 opcode) repeat this instruction
-                    self.program_counter -= 2;
+                    match (self.key_wait_mode, keys_newly_pressed.first()) {
+                        (KeyWaitMode::OnPress, Some(key)) => {
+                            // We have a key pressed, and need not await its release; stop waiting
+                            self.variable_registers[x] = *key;
+                            self.set_status(ProcessorStatus::Running);
+                        }
+                        _ => {
+                            self.keys_pressed_since_wait.append(&mut keys_newly_pressed);
+                            // Decrement the program counter by by 2 bytes (1 opcode) repeat this
+                            // instruction
+                            self.program_counter -= 2;
+                        }
+                    }
                 }
             }
             _ => {
@@ -872,9 +1151,9 @@ impl Processor {
     pub(super) fn execute_FX15(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 78;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         self.delay_timer = self.variable_registers[x];
         Ok(CYCLES)
@@ -885,9 +1164,9 @@ impl Processor {
     pub(super) fn execute_FX18(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 78;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         self.sound_timer = self.variable_registers[x];
         Ok(CYCLES)
@@ -921,9 +1200,9 @@ impl Processor {
                 }
             }
         }
-        let mut operands: HashMap<String, usize> = HashMap::new();
-        operands.insert("x".to_string(), x);
-        return Err(ErrorDetail::OperandsOutOfBounds { operands });
+        return Err(ErrorDetail::OperandsOutOfBounds {
+            operands: vec![Operand::X(x)],
+        });
     }
 
     /// Executes the FX29 instruction - LD F, Vx
@@ -931,17 +1210,17 @@ impl Processor {
     pub(super) fn execute_FX29(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         const CYCLES: u64 = 88;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         // Fetch the character hex code in Vx and check it is within expected bounds
         let character = self.variable_registers[x];
         let font: &Font = &self.low_resolution_font;
         if character >= (font.font_data_size() / font.char_size()) as u8 {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("character".to_string(), character as usize);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::Character(character)],
+            });
         }
         // Calculate the corresponding font sprite location in memory based on the size per font
         // character (in bytes), the starting location of font data in memory, and the offset of
@@ -953,23 +1232,23 @@ impl Processor {
     }
 
     /// Executes the FX30 instruction - LD HF, Vx
-    /// Purpose: [SUPER-CHIP 1.1] point I to 10-byte font sprite for digit Vx
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    /// Purpose: [SUPER-CHIP 1.0 / 1.1] point I to 10-byte font sprite for digit Vx
+    ///          [CHIP-8 / CHIP-8X / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_FX30(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
-            EmulationLevel::SuperChip11 { .. } => {
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
                 if x >= VARIABLE_REGISTER_COUNT {
-                    let mut operands: HashMap<String, usize> = HashMap::new();
-                    operands.insert("x".to_string(), x);
-                    return Err(ErrorDetail::OperandsOutOfBounds { operands });
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::X(x)],
+                    });
                 }
                 // Fetch the character hex code in Vx and check it is within expected bounds
                 let character = self.variable_registers[x];
                 let font: &Font = self.high_resolution_font.as_ref().unwrap();
                 if character >= (font.font_data_size() / font.char_size()) as u8 {
-                    let mut operands: HashMap<String, usize> = HashMap::new();
-                    operands.insert("character".to_string(), character as usize);
-                    return Err(ErrorDetail::OperandsOutOfBounds { operands });
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::Character(character)],
+                    });
                 }
                 // Calculate the corresponding font sprite location in memory based on the size per font
                 // character (in bytes), the starting location of font data in memory, and the offset of
@@ -979,7 +1258,9 @@ impl Processor {
                 self.index_register = character_memory_location as u16;
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. } => {
                 let opcode: u16 = 0xF030 | ((x as u16) << 8);
                 Err(ErrorDetail::UnknownInstruction { opcode })
             }
@@ -992,15 +1273,16 @@ impl Processor {
         const CYCLES_BASE: u64 = 152;
         const CYCLES_INCREMENTAL: u64 = 16;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         let hex_number: u8 = self.variable_registers[x]; // get the hex value in Vx
         let decimal_first_digit: u8 = hex_number / 100; // get the "hundreds" decimal digit
         let decimal_second_digit: u8 = (hex_number % 100) / 10; // get the "tens" decimal digit
         let decimal_third_digit: u8 = hex_number % 10; // get the "units" decimal digit
         let index: usize = self.index_register as usize; // get the memory address in the index register
+        self.instruction_cache.invalidate(index..index + 3); // discard any instructions overwritten
         self.memory.write_byte(index, decimal_first_digit)?; // store the first digit at this address
         self.memory.write_byte(index + 1, decimal_second_digit)?; // store the second digit at the next address
         self.memory.write_byte(index + 2, decimal_third_digit)?; // store the third digit at the next address
@@ -1013,33 +1295,36 @@ impl Processor {
     /// Executes the FX55 instruction - LD [I], Vx
     /// Purpose: store registers V0 to Vx in memory starting at the address in I   
     ///          [CHIP-8] also set I to I + x + 1
-    ///          [CHIP-48] also set I to I + x
+    ///          [CHIP-48 / SUPER-CHIP 1.0] also set I to I + x
     ///          [SUPER-CHIP 1.1] do not modify I    
     pub(super) fn execute_FX55(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         const CYCLES_BASE: u64 = 86;
         const CYCLES_INCREMENTAL: u64 = 14;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         let original_index_register: usize = self.index_register as usize;
         match self.emulation_level {
-            EmulationLevel::Chip8 { .. } => {
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
                 // Original CHIP-8 behaviour incremented index register after each assignment
                 self.index_register = (original_index_register + x + 1) as u16;
             }
-            EmulationLevel::Chip48 => {
-                // CHIP-48 increments index register by one less than it should
+            EmulationLevel::Chip48 { .. } | EmulationLevel::SuperChip10 { .. } => {
+                // CHIP-48 (and SUPER-CHIP 1.0) increments index register by one less than it should
                 self.index_register = (original_index_register + x) as u16;
             }
             EmulationLevel::SuperChip11 { .. } => {
                 // SUPER-CHIP 1.1 does not increment the index register at all; do nothing here
             }
         }
-        // Construct an appropriate array slice from the variable register array and write to memory
+        // Construct an appropriate array slice from the variable register array and write to
+        // memory, discarding any instructions cached from the memory overwritten
         self.memory
             .write_bytes(original_index_register, &self.variable_registers[0..x + 1])?;
+        self.instruction_cache
+            .invalidate(original_index_register..original_index_register + x + 1);
         let variable_count: u64 = (x + 1) as u64;
         // Timing is calculated as base amount plus an increment multiplied by every variable stored
         Ok(CYCLES_BASE + (CYCLES_INCREMENTAL * variable_count))
@@ -1048,24 +1333,24 @@ impl Processor {
     /// Executes the FX65 instruction - LD Vx, [I]
     /// Purpose: populate registers V0 to Vx from memory starting at the address in I
     ///          [CHIP-8] also set I to I + x + 1
-    ///          [CHIP-48] also set I to I + x
+    ///          [CHIP-48 / SUPER-CHIP 1.0] also set I to I + x
     ///          [SUPER-CHIP 1.1] do not modify I
     pub(super) fn execute_FX65(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         const CYCLES_BASE: u64 = 86;
         const CYCLES_INCREMENTAL: u64 = 14;
         if x >= VARIABLE_REGISTER_COUNT {
-            let mut operands: HashMap<String, usize> = HashMap::new();
-            operands.insert("x".to_string(), x);
-            return Err(ErrorDetail::OperandsOutOfBounds { operands });
+            return Err(ErrorDetail::OperandsOutOfBounds {
+                operands: vec![Operand::X(x)],
+            });
         }
         let original_index_register: usize = self.index_register as usize;
         match self.emulation_level {
-            EmulationLevel::Chip8 { .. } => {
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
                 // Original CHIP-8 behaviour incremented index register after each assignment
                 self.index_register = (original_index_register + x + 1) as u16;
             }
-            EmulationLevel::Chip48 => {
-                // CHIP-48 increments index register by one less than it should
+            EmulationLevel::Chip48 { .. } | EmulationLevel::SuperChip10 { .. } => {
+                // CHIP-48 (and SUPER-CHIP 1.0) increments index register by one less than it should
                 self.index_register = (original_index_register + x) as u16;
             }
             EmulationLevel::SuperChip11 { .. } => {
@@ -1075,8 +1360,7 @@ impl Processor {
         // Iterate through the appropriate portion of the variable register array
         for i in 0..(x + 1) {
             // Set the new value by reading the appropriate byte from memory
-            self.variable_registers[i] =
-                self.memory.read_byte(original_index_register + i).unwrap();
+            self.variable_registers[i] = self.memory.read_byte(original_index_register + i)?;
         }
         let variable_count: u64 = (x + 1) as u64;
         // Timing is calculated as base amount plus an increment multiplied by every variable stored
@@ -1084,21 +1368,23 @@ impl Processor {
     }
 
     /// Executes the FX75 instruction - LD R, Vx
-    /// Purpose: [SUPER-CHIP 1.1] store registers V0 to Vx in RPL user flags starting at address in I
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    /// Purpose: [SUPER-CHIP 1.0 / 1.1] store registers V0 to Vx in RPL user flags starting at address in I
+    ///          [CHIP-8 / CHIP-8X / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_FX75(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
-            EmulationLevel::SuperChip11 { .. } => {
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
                 if x >= RPL_REGISTER_COUNT {
-                    let mut operands: HashMap<String, usize> = HashMap::new();
-                    operands.insert("x".to_string(), x);
-                    return Err(ErrorDetail::OperandsOutOfBounds { operands });
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::X(x)],
+                    });
                 }
                 // Iterate through the appropriate portion of the variable register array
                 self.rpl_registers[0..=x].copy_from_slice(&self.variable_registers[0..=x]);
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. } => {
                 let opcode: u16 = 0xF075 | ((x as u16) << 8);
                 Err(ErrorDetail::UnknownInstruction { opcode })
             }
@@ -1106,24 +1392,53 @@ impl Processor {
     }
 
     /// Executes the FX85 instruction - LD Vx, R
-    /// Purpose: [SUPER-CHIP 1.1] populate registers V0 to Vx from RPL user flags starting at address in I
-    ///          [CHIP-8 / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
+    /// Purpose: [SUPER-CHIP 1.0 / 1.1] populate registers V0 to Vx from RPL user flags starting at address in I
+    ///          [CHIP-8 / CHIP-8X / CHIP-48] this will error as an [ErrorDetail::UnknownInstruction]
     pub(super) fn execute_FX85(&mut self, x: usize) -> Result<u64, ErrorDetail> {
         match self.emulation_level {
-            EmulationLevel::SuperChip11 { .. } => {
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
                 if x >= RPL_REGISTER_COUNT {
-                    let mut operands: HashMap<String, usize> = HashMap::new();
-                    operands.insert("x".to_string(), x);
-                    return Err(ErrorDetail::OperandsOutOfBounds { operands });
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::X(x)],
+                    });
                 }
                 // Iterate through the appropriate portion of the variable register array
                 self.variable_registers[0..=x].copy_from_slice(&self.rpl_registers[0..=x]);
                 Ok(0)
             }
-            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip48 => {
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip8X { .. }
+            | EmulationLevel::Chip48 { .. } => {
                 let opcode: u16 = 0xF085 | ((x as u16) << 8);
                 Err(ErrorDetail::UnknownInstruction { opcode })
             }
         }
     }
+
+    /// Executes the FXF8 instruction - OUT Vx
+    /// Purpose: [CHIP-8X] output Vx to the tone generator, setting the pitch of the sound
+    ///          (passed on to the host through [SoundSink::set_pitch()])
+    ///          [CHIP-8 / CHIP-48 / SUPER-CHIP 1.1] this will error as an [ErrorDetail::UnknownInstruction]
+    pub(super) fn execute_FXF8(&mut self, x: usize) -> Result<u64, ErrorDetail> {
+        // Timing is estimated, as per the comparable FX18 instruction
+        const CYCLES: u64 = 78;
+        match self.emulation_level {
+            EmulationLevel::Chip8X { .. } => {
+                if x >= VARIABLE_REGISTER_COUNT {
+                    return Err(ErrorDetail::OperandsOutOfBounds {
+                        operands: vec![Operand::X(x)],
+                    });
+                }
+                self.sound_state.set_pitch(self.variable_registers[x]);
+                Ok(CYCLES)
+            }
+            EmulationLevel::Chip8 { .. }
+            | EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => {
+                let opcode: u16 = 0xF0F8 | ((x as u16) << 8);
+                Err(ErrorDetail::UnknownInstruction { opcode })
+            }
+        }
+    }
 }
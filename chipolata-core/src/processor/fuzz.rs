@@ -0,0 +1,121 @@
+use super::*;
+
+/// The random seed used by [Processor::run_program_fuzz()], so that every run of a given input
+/// is reproducible
+const FUZZ_RNG_SEED: u64 = 0;
+
+/// The coarse outcome of a fuzzing run, as returned by [Processor::run_program_fuzz()].  Every
+/// outcome is legitimate; a fuzzer is looking for panics and hangs rather than any particular
+/// outcome.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FuzzOutcome {
+    /// The input could not be loaded as a program (for example because it was empty, or too
+    /// large to fit in memory)
+    Rejected,
+    /// The program was still executing when the cycle limit was reached
+    CycleLimitReached,
+    /// The program exited the interpreter (SUPER-CHIP 1.1 emulation mode only)
+    Exited,
+    /// The program caused the processor to crash with a [ChipolataError] (as most random input
+    /// does, sooner or later)
+    Crashed,
+}
+
+impl Processor {
+    /// Runs an arbitrary byte stream as a program for at most the specified number of cycles,
+    /// returning a coarse [FuzzOutcome].  This is the entry point for fuzzing the interpreter
+    /// (see the `fuzz` folder for a `cargo-fuzz` target); it never returns an error, so any
+    /// panic raised while running the input (such as an arithmetic overflow in an instruction
+    /// handler) is a bug.
+    ///
+    /// The first byte of the input selects the configuration, so that a fuzzer explores every
+    /// emulation level and quirk alongside the program itself; the remaining bytes are loaded as
+    /// the program:
+    ///
+    /// * bits 0-2: the [EmulationLevel] (CHIP-8, CHIP-48, SUPER-CHIP 1.1, SUPER-CHIP 1.1 with
+    ///   Octo compatibility, CHIP-8X, SUPER-CHIP 1.0, CHIP-8 with the Hi-Res mode, or CHIP-8
+    ///   with the 2k memory limit)
+    /// * bit 3: variable cycle timing
+    /// * bit 4: sprite wrapping ([SpriteEdgeMode::Wrap])
+    /// * bits 5-6: the [UnknownInstructionPolicy] (crash, skip, no-op, crash)
+    /// * bit 7: the low-resolution display wait
+    ///
+    /// Execution is fully deterministic and unthrottled: a fixed random seed is used, and timers
+    /// are driven by cycle count rather than wall-clock time.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - the configuration byte followed by the program
+    /// * `cycle_limit` - the maximum number of cycles to execute
+    pub fn run_program_fuzz(bytes: &[u8], cycle_limit: usize) -> FuzzOutcome {
+        let (configuration, program): (u8, &[u8]) = match bytes.split_first() {
+            Some((configuration, program)) => (*configuration, program),
+            None => return FuzzOutcome::Rejected,
+        };
+        let mut processor: Processor = match Processor::initialise_and_load(
+            Program::new(program.to_vec()),
+            fuzz_options(configuration),
+        ) {
+            Ok(processor) => processor,
+            Err(_) => return FuzzOutcome::Rejected,
+        };
+        processor.set_turbo_mode(true);
+        processor.set_cycle_based_timing(true);
+        for _ in 0..cycle_limit {
+            if processor.status == ProcessorStatus::Completed {
+                return FuzzOutcome::Exited;
+            }
+            if processor.execute_cycle().is_err() {
+                return FuzzOutcome::Crashed;
+            }
+        }
+        FuzzOutcome::CycleLimitReached
+    }
+}
+
+/// Helper function that returns the [Options] selected by the configuration byte of a fuzzing
+/// input (see [Processor::run_program_fuzz()])
+///
+/// # Arguments
+///
+/// * `configuration` - the configuration byte
+pub(super) fn fuzz_options(configuration: u8) -> Options {
+    let flag = |bit: u8| configuration & (1 << bit) > 0;
+    let variable_cycle_timing: bool = flag(3);
+    let level_selector: u8 = configuration & 0b111;
+    let emulation_level: EmulationLevel = match level_selector {
+        1 => EmulationLevel::Chip48 {
+            variable_cycle_timing,
+        },
+        2 | 3 => EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: level_selector == 3,
+            variable_cycle_timing,
+        },
+        4 => EmulationLevel::Chip8X {
+            variable_cycle_timing,
+        },
+        5 => EmulationLevel::SuperChip10 {
+            variable_cycle_timing,
+        },
+        _ => EmulationLevel::Chip8 {
+            memory_limit_2k: level_selector == 7,
+            variable_cycle_timing,
+        },
+    };
+    Options {
+        emulation_level,
+        rng_seed: Some(FUZZ_RNG_SEED),
+        sprite_edge_mode: match flag(4) {
+            true => SpriteEdgeMode::Wrap,
+            false => SpriteEdgeMode::Clip,
+        },
+        on_unknown_instruction: match (configuration >> 5) & 0b11 {
+            1 => UnknownInstructionPolicy::Skip,
+            2 => UnknownInstructionPolicy::Nop,
+            _ => UnknownInstructionPolicy::Crash,
+        },
+        chip8_hires_mode: level_selector == 6,
+        lowres_display_wait: flag(7),
+        ..Options::default()
+    }
+}
@@ -0,0 +1,152 @@
+use super::*;
+
+/// The processor state sampled whenever a backward jump is executed.  If the state at one
+/// backward jump matches that at the previous one, and nothing observable happened in between,
+/// then the program will repeat the same instructions forever.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct LoopState {
+    jump_address: u16,
+    variable_registers: [u8; VARIABLE_REGISTER_COUNT],
+    index_register: u16,
+    stack: Vec<u16>, // The occupied stack entries (reused between samples, to avoid allocating)
+}
+
+/// The state used to detect programs stuck in an infinite loop: the [LoopState] sampled at the
+/// most recent backward jump (if one has been sampled since the watch was last cleared), and
+/// whether anything observable (input, output, randomness or a memory write) has happened since
+#[derive(Debug, Clone, Default)]
+pub(super) struct HaltWatch {
+    loop_state: LoopState,
+    sampled: bool,
+    observable_since_jump: bool,
+}
+
+impl HaltWatch {
+    /// Forgets the state sampled at the most recent backward jump, for use whenever processor
+    /// state is changed other than by executing instructions (for example on reset)
+    pub(super) fn clear(&mut self) {
+        self.sampled = false;
+        self.observable_since_jump = false;
+    }
+
+    /// Returns true if the passed state matches that sampled at the most recent backward jump
+    ///
+    /// # Arguments
+    ///
+    /// * `jump_address` - the address of the backward jump just executed
+    /// * `variable_registers` - the current variable register values
+    /// * `index_register` - the current index register value
+    /// * `stack` - the occupied stack entries
+    fn matches(
+        &self,
+        jump_address: u16,
+        variable_registers: &[u8; VARIABLE_REGISTER_COUNT],
+        index_register: u16,
+        stack: &[u16],
+    ) -> bool {
+        self.sampled
+            && self.loop_state.jump_address == jump_address
+            && self.loop_state.variable_registers == *variable_registers
+            && self.loop_state.index_register == index_register
+            && self.loop_state.stack == stack
+    }
+
+    /// Records the passed state as that sampled at the most recent backward jump, copying the
+    /// stack into the buffer kept from the previous sample so that no allocation is needed once
+    /// the buffer has grown to the program's stack depth
+    ///
+    /// # Arguments
+    ///
+    /// * `jump_address` - the address of the backward jump just executed
+    /// * `variable_registers` - the current variable register values
+    /// * `index_register` - the current index register value
+    /// * `stack` - the occupied stack entries
+    fn sample(
+        &mut self,
+        jump_address: u16,
+        variable_registers: &[u8; VARIABLE_REGISTER_COUNT],
+        index_register: u16,
+        stack: &[u16],
+    ) {
+        self.loop_state.jump_address = jump_address;
+        self.loop_state.variable_registers = *variable_registers;
+        self.loop_state.index_register = index_register;
+        self.loop_state.stack.clear();
+        self.loop_state.stack.extend_from_slice(stack);
+        self.sampled = true;
+        self.observable_since_jump = false;
+    }
+}
+
+impl Processor {
+    /// Internal helper function that, if halt detection is enabled, sets the processor status to
+    /// [ProcessorStatus::Halted] when the instruction just executed leaves the program stuck in
+    /// an infinite loop.  Two cases are recognised: the common `1NNN` jump-to-self "halt" idiom
+    /// (once any sound has finished playing), and a tight loop that arrives back at the same
+    /// backward jump with the registers, index register and stack unchanged, having neither
+    /// read input nor the random number generator, written to memory, changed the display nor
+    /// used the timers in between.
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - the instruction just executed
+    /// * `display_changed` - whether the instruction may have changed the display
+    pub(super) fn detect_halt(&mut self, instruction: &Instruction, display_changed: bool) {
+        if !self.halt_detection {
+            return;
+        }
+        let observable: bool = display_changed
+            || self.sound_timer > 0
+            || self.delay_timer > 0
+            || matches!(
+                instruction,
+                Instruction::Op0NNN { .. }
+                    | Instruction::OpCXNN { .. }
+                    | Instruction::OpEX9E { .. }
+                    | Instruction::OpEXA1 { .. }
+                    | Instruction::OpEXF2 { .. }
+                    | Instruction::OpEXF5 { .. }
+                    | Instruction::OpFX0A { .. }
+                    | Instruction::OpFX33 { .. }
+                    | Instruction::OpFX55 { .. }
+                    | Instruction::OpFX75 { .. }
+                    | Instruction::OpFXF8 { .. }
+            );
+        if observable {
+            self.halt_watch.observable_since_jump = true;
+        }
+        // Only a jump backwards (or to itself) can close a loop
+        let jump_address: u16 = self.execution_context.program_counter;
+        let target_address: u16 = match *instruction {
+            Instruction::Op1NNN { nnn } if nnn <= jump_address => nnn,
+            _ => return,
+        };
+        let stack: &[u16] = &self.stack.bytes[..self.stack.pointer];
+        let halted: bool = match target_address == jump_address {
+            true => self.sound_timer == 0,
+            false => {
+                !self.halt_watch.observable_since_jump
+                    && self.halt_watch.matches(
+                        jump_address,
+                        &self.variable_registers,
+                        self.index_register,
+                        stack,
+                    )
+            }
+        };
+        self.halt_watch.sample(
+            jump_address,
+            &self.variable_registers,
+            self.index_register,
+            stack,
+        );
+        if halted {
+            #[cfg(feature = "tracing")]
+            tracing::info!(
+                address = jump_address,
+                "infinite loop detected; program halted"
+            );
+            self.set_status(ProcessorStatus::Halted);
+        }
+    }
+}
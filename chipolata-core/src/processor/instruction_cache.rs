@@ -0,0 +1,69 @@
+use crate::instruction::Instruction;
+use crate::prelude::*;
+use core::ops::Range;
+
+/// A cache of the instructions decoded from each memory address, so that instructions executed
+/// repeatedly (as in any program loop) are fetched and decoded only once.  Each entry holds the
+/// opcode read from the address alongside the [Instruction] decoded from it.  Entries must be
+/// invalidated whenever the memory they were read from is written, which keeps the cache safe
+/// for self-modifying programs.
+#[derive(Debug, Clone)]
+pub(crate) struct InstructionCache {
+    entries: Vec<Option<(u16, Instruction)>>,
+}
+
+impl InstructionCache {
+    /// Constructor that returns an empty [InstructionCache] covering the specified number of
+    /// memory addresses
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - the number of memory addresses from which instructions may be cached
+    pub(crate) fn new(size: usize) -> Self {
+        InstructionCache {
+            entries: vec![None; size],
+        }
+    }
+
+    /// Returns the opcode and decoded instruction cached for the specified address, if any
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address from which the instruction was fetched
+    pub(crate) fn get(&self, address: usize) -> Option<(u16, Instruction)> {
+        self.entries.get(address).copied().flatten()
+    }
+
+    /// Caches the opcode fetched from the specified address, and the instruction decoded from it
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address from which the instruction was fetched
+    /// * `opcode` - the opcode read from the address
+    /// * `instruction` - the instruction decoded from the opcode
+    pub(crate) fn insert(&mut self, address: usize, opcode: u16, instruction: Instruction) {
+        if let Some(entry) = self.entries.get_mut(address) {
+            *entry = Some((opcode, instruction));
+        }
+    }
+
+    /// Discards every cached instruction that overlaps the specified range of memory addresses,
+    /// including any instruction fetched from the address immediately before it (whose second
+    /// byte falls within the range)
+    ///
+    /// # Arguments
+    ///
+    /// * `range` - the range of memory addresses written
+    pub(crate) fn invalidate(&mut self, range: Range<usize>) {
+        let start: usize = range.start.saturating_sub(1).min(self.entries.len());
+        let end: usize = range.end.min(self.entries.len());
+        if start < end {
+            self.entries[start..end].fill(None);
+        }
+    }
+
+    /// Discards every cached instruction
+    pub(crate) fn clear(&mut self) {
+        self.entries.fill(None);
+    }
+}
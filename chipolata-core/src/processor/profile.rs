@@ -0,0 +1,60 @@
+use super::*;
+
+impl Processor {
+    /// Internal helper function that records, in the memory profile (if profiling is enabled),
+    /// the memory reads and writes the specified instruction is about to perform.  This must be
+    /// called prior to execution, as the addresses accessed depend on the pre-execution state.
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - the decoded instruction about to be executed
+    pub(super) fn profile_instruction_memory_accesses(&mut self, instruction: &Instruction) {
+        // When waiting for the display, the sprite is only drawn (and so read) once the vblank
+        // interrupt occurs
+        let waiting_for_display: bool = match self.vblank_status {
+            VBlankStatus::Idle => self.sprite_draw_must_wait(),
+            VBlankStatus::WaitingForVBlank => true,
+            VBlankStatus::ReadyToDraw => false,
+        };
+        // Likewise if the draw must wait for the raster to pass the sprite's rows
+        let raster_wait_required: bool = match *instruction {
+            Instruction::OpDXYN { y, n, .. } => self.raster_wait_required(y, n),
+            _ => false,
+        };
+        let profile: &mut MemoryProfile = match &mut self.memory_profile {
+            Some(profile) => profile,
+            None => return,
+        };
+        let index: usize = self.index_register as usize;
+        match *instruction {
+            Instruction::OpDXYN { n, .. } => {
+                let sprite_bytes: usize = match (self.emulation_level, self.high_resolution_mode, n)
+                {
+                    _ if waiting_for_display || raster_wait_required => 0,
+                    (
+                        EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. },
+                        true,
+                        0,
+                    ) => 32,
+                    (EmulationLevel::SuperChip10 { .. }, false, 0) => {
+                        SUPERCHIP10_LOW_RES_DXY0_HEIGHT as usize
+                    }
+                    (
+                        EmulationLevel::SuperChip11 {
+                            octo_compatibility_mode: true,
+                            ..
+                        },
+                        false,
+                        0,
+                    ) => 32,
+                    _ => n as usize,
+                };
+                profile.record_read(index, sprite_bytes);
+            }
+            Instruction::OpFX33 { .. } => profile.record_write(index, 3),
+            Instruction::OpFX55 { x } => profile.record_write(index, x + 1),
+            Instruction::OpFX65 { x } => profile.record_read(index, x + 1),
+            _ => (),
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use super::*;
+
+/// The processor state set aside before frames are run speculatively, from which the processor
+/// is rolled back afterwards.  Unlike a [SaveState] this includes the frame pacing and timing
+/// state (so that rolling back leaves no trace of the speculative frames), and it is never
+/// serialised.
+struct Checkpoint {
+    frame_buffer: Display,
+    stack: Stack,
+    memory: Memory,
+    program_counter: u16,
+    index_register: u16,
+    variable_registers: [u8; VARIABLE_REGISTER_COUNT],
+    rpl_registers: [u8; RPL_REGISTER_COUNT],
+    delay_timer: u8,
+    sound_timer: u8,
+    cycles: usize,
+    execution_context: ExecutionContext,
+    trace: VecDeque<ExecutionContext>,
+    high_resolution_mode: bool,
+    keystate: KeyState,
+    second_keystate: KeyState,
+    waiting_original_keystate: KeyState,
+    keys_pressed_since_wait: Vec<u8>,
+    pending_key_events: Vec<(usize, Key, bool)>,
+    status: ProcessorStatus,
+    last_timer_decrement: Instant,
+    last_execution_cycle_complete: Instant,
+    cycle_deadline: Instant,
+    last_vblank_interrupt: Instant,
+    vblank_status: VBlankStatus,
+    rng: StdRng,
+    vip_random_register: u8,
+    sprites_drawn_this_frame: usize,
+    frames: usize,
+    frame_start_cycle: usize,
+    halt_watch: HaltWatch,
+    break_watch_id: Option<usize>,
+}
+
+/// The host-facing hooks and recorders detached from the processor while frames are run
+/// speculatively, so that the host observes nothing of frames that are later rolled back
+struct DetachedHooks {
+    pre_execute_hook: Option<PreExecuteHook>,
+    sound_state: SoundState,
+    frame_sink: FrameSinkState,
+    memory_profile: Option<MemoryProfile>,
+    watches: Vec<RegisteredWatch>,
+    input_recording: Option<InputRecording>,
+    timing_recorder: TimingRecorder,
+}
+
+impl Processor {
+    /// Executes the specified number of frames as per [Processor::run_frames()], then runs a
+    /// further `run_ahead_frames` frames speculatively (with the keys as currently held) and
+    /// returns a minimal snapshot of the state so reached, before rolling the processor back to
+    /// the state at the end of the executed frames.  Displaying the speculative snapshot hides
+    /// the latency with which programs typically respond to input (often a frame or more, as
+    /// they poll the keys once per frame), since each key press is reflected in the frames shown
+    /// as soon as it is applied.
+    ///
+    /// Nothing of the speculative frames is observable by the host: the sound sink, pre-execute
+    /// hook, memory profile, watch expressions, input recording and timing statistics are all
+    /// suspended while they run, and the snapshot is of the executed frames if speculation
+    /// crashes (the crash being left to happen for real, should the program reach it).  No
+    /// speculation takes place (and so the snapshot is of the executed frames) if the processor
+    /// is paused or has stopped, or while a recording is replayed.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - the number of frames to execute
+    /// * `run_ahead_frames` - the number of frames to run speculatively beyond those executed
+    pub fn run_frames_ahead(
+        &mut self,
+        frames: usize,
+        run_ahead_frames: usize,
+    ) -> Result<StateSnapshot, ChipolataError> {
+        self.run_frames(frames)?;
+        let speculate: bool = run_ahead_frames > 0
+            && self.input_replay.is_none()
+            && matches!(
+                self.status,
+                ProcessorStatus::StartingUp
+                    | ProcessorStatus::Running
+                    | ProcessorStatus::WaitingForKeypress
+            );
+        if !speculate {
+            return Ok(self.export_state_snapshot(StateSnapshotVerbosity::Minimal));
+        }
+        let checkpoint: Checkpoint = self.checkpoint();
+        let hooks: DetachedHooks = self.detach_hooks();
+        let snapshot: Option<StateSnapshot> = match self.run_frames(run_ahead_frames) {
+            Ok(_) => Some(self.export_state_snapshot(StateSnapshotVerbosity::Minimal)),
+            Err(_) => None,
+        };
+        self.roll_back(checkpoint);
+        self.reattach_hooks(hooks);
+        Ok(snapshot.unwrap_or_else(|| self.export_state_snapshot(StateSnapshotVerbosity::Minimal)))
+    }
+
+    /// Internal helper function that captures the state from which the processor is rolled back
+    /// once frames have been run speculatively
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            frame_buffer: self.frame_buffer.clone(),
+            stack: self.stack.clone(),
+            memory: self.memory.clone(),
+            program_counter: self.program_counter,
+            index_register: self.index_register,
+            variable_registers: self.variable_registers,
+            rpl_registers: self.rpl_registers,
+            delay_timer: self.delay_timer,
+            sound_timer: self.sound_timer,
+            cycles: self.cycles,
+            execution_context: self.execution_context,
+            trace: self.trace.clone(),
+            high_resolution_mode: self.high_resolution_mode,
+            keystate: self.keystate,
+            second_keystate: self.second_keystate,
+            waiting_original_keystate: self.waiting_original_keystate,
+            keys_pressed_since_wait: self.keys_pressed_since_wait.clone(),
+            pending_key_events: self.pending_key_events.clone(),
+            status: self.status,
+            last_timer_decrement: self.last_timer_decrement,
+            last_execution_cycle_complete: self.last_execution_cycle_complete,
+            cycle_deadline: self.cycle_deadline,
+            last_vblank_interrupt: self.last_vblank_interrupt,
+            vblank_status: self.vblank_status,
+            rng: self.rng.clone(),
+            vip_random_register: self.vip_random_register,
+            sprites_drawn_this_frame: self.sprites_drawn_this_frame,
+            frames: self.frames,
+            frame_start_cycle: self.frame_start_cycle,
+            halt_watch: self.halt_watch.clone(),
+            break_watch_id: self.break_watch_id,
+        }
+    }
+
+    /// Internal helper function that returns the processor to the passed checkpoint.  As the
+    /// host may have been shown the speculative display, the display is reported as changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `checkpoint` - the state captured before frames were run speculatively
+    fn roll_back(&mut self, checkpoint: Checkpoint) {
+        // Instructions decoded from memory written speculatively must not be executed for real
+        if self.memory != checkpoint.memory {
+            self.instruction_cache.clear();
+        }
+        self.frame_buffer = checkpoint.frame_buffer;
+        self.stack = checkpoint.stack;
+        self.memory = checkpoint.memory;
+        self.program_counter = checkpoint.program_counter;
+        self.index_register = checkpoint.index_register;
+        self.variable_registers = checkpoint.variable_registers;
+        self.rpl_registers = checkpoint.rpl_registers;
+        self.delay_timer = checkpoint.delay_timer;
+        self.sound_timer = checkpoint.sound_timer;
+        self.cycles = checkpoint.cycles;
+        self.execution_context = checkpoint.execution_context;
+        self.trace = checkpoint.trace;
+        self.high_resolution_mode = checkpoint.high_resolution_mode;
+        self.keystate = checkpoint.keystate;
+        self.second_keystate = checkpoint.second_keystate;
+        self.waiting_original_keystate = checkpoint.waiting_original_keystate;
+        self.keys_pressed_since_wait = checkpoint.keys_pressed_since_wait;
+        self.pending_key_events = checkpoint.pending_key_events;
+        self.status = checkpoint.status;
+        self.last_timer_decrement = checkpoint.last_timer_decrement;
+        self.last_execution_cycle_complete = checkpoint.last_execution_cycle_complete;
+        self.cycle_deadline = checkpoint.cycle_deadline;
+        self.last_vblank_interrupt = checkpoint.last_vblank_interrupt;
+        self.vblank_status = checkpoint.vblank_status;
+        self.rng = checkpoint.rng;
+        self.vip_random_register = checkpoint.vip_random_register;
+        self.sprites_drawn_this_frame = checkpoint.sprites_drawn_this_frame;
+        self.frames = checkpoint.frames;
+        self.frame_start_cycle = checkpoint.frame_start_cycle;
+        self.halt_watch = checkpoint.halt_watch;
+        self.break_watch_id = checkpoint.break_watch_id;
+        self.display_changed.set(true);
+    }
+
+    /// Internal helper function that detaches the host-facing hooks and recorders from the
+    /// processor, for the duration of frames run speculatively
+    fn detach_hooks(&mut self) -> DetachedHooks {
+        DetachedHooks {
+            pre_execute_hook: self.pre_execute_hook.take(),
+            sound_state: core::mem::take(&mut self.sound_state),
+            frame_sink: core::mem::take(&mut self.frame_sink),
+            memory_profile: self.memory_profile.take(),
+            watches: core::mem::take(&mut self.watches),
+            input_recording: self.input_recording.take(),
+            timing_recorder: core::mem::take(&mut self.timing_recorder),
+        }
+    }
+
+    /// Internal helper function that reattaches the host-facing hooks and recorders detached by
+    /// [Processor::detach_hooks()]
+    ///
+    /// # Arguments
+    ///
+    /// * `hooks` - the hooks and recorders detached
+    fn reattach_hooks(&mut self, hooks: DetachedHooks) {
+        self.pre_execute_hook = hooks.pre_execute_hook;
+        self.sound_state = hooks.sound_state;
+        self.frame_sink = hooks.frame_sink;
+        self.memory_profile = hooks.memory_profile;
+        self.watches = hooks.watches;
+        self.input_recording = hooks.input_recording;
+        self.timing_recorder = hooks.timing_recorder;
+    }
+}
@@ -0,0 +1,257 @@
+use super::*;
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// A copy of the complete emulated state of a [Processor] at a point in time, as returned by
+/// [Processor::save_state()], which can later be restored (any number of times) with
+/// [Processor::restore_state()].  Host configuration, such as registered callbacks, watches and
+/// the processor speed, is not part of the saved state.
+///
+/// A [SaveState] can also be kept on disk with [SaveState::save_to_file()] and
+/// [SaveState::load_from_file()].  The state of the random number generator cannot be written
+/// out as such, so a seed drawn from it is written instead: a state loaded from file therefore
+/// generates a different (though, each time it is loaded, identical) sequence of random numbers
+/// to the state from which it was saved.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SaveState {
+    emulation_level: EmulationLevel,
+    frame_buffer: Display,
+    stack: Stack,
+    memory: Memory,
+    program_counter: u16,
+    index_register: u16,
+    variable_registers: [u8; VARIABLE_REGISTER_COUNT],
+    rpl_registers: [u8; RPL_REGISTER_COUNT],
+    delay_timer: u8,
+    sound_timer: u8,
+    cycles: usize,
+    execution_context: ExecutionContext,
+    high_resolution_mode: bool,
+    keystate: KeyState,
+    second_keystate: KeyState,
+    waiting_original_keystate: KeyState,
+    keys_pressed_since_wait: Vec<u8>,
+    status: ProcessorStatus,
+    #[serde(serialize_with = "serialize_rng", deserialize_with = "deserialize_rng")]
+    rng: StdRng,
+    #[serde(default)]
+    vip_random_register: u8,
+}
+
+/// Serialises a random number generator as a seed drawn from (a copy of) it, from which
+/// [deserialize_rng()] creates a new generator
+fn serialize_rng<S: serde::Serializer>(rng: &StdRng, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(rng.clone().gen())
+}
+
+/// Deserialises a random number generator serialised by [serialize_rng()]
+fn deserialize_rng<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<StdRng, D::Error> {
+    <u64 as serde::Deserialize>::deserialize(deserializer).map(StdRng::seed_from_u64)
+}
+
+/// The greatest length of a JSON number in a serialised state (that of `u64::MAX`, as long as
+/// that of `i64::MIN`), which is also longer than `null`
+const MAX_JSON_NUMBER_LENGTH: usize = 20;
+
+/// Returns an upper bound on the compact JSON length of the passed value were each number it
+/// holds (or each `null`, which may later hold a number) at its longest
+///
+/// # Arguments
+///
+/// * `value` - the JSON value to measure
+fn json_length_bound(value: &serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::Null | serde_json::Value::Number(_) => MAX_JSON_NUMBER_LENGTH,
+        serde_json::Value::Bool(_) => "false".len(),
+        serde_json::Value::String(_) => serde_json::to_string(value).map_or(0, |s| s.len()),
+        serde_json::Value::Array(elements) => {
+            // brackets, then each element with the comma separating it from the next
+            2 + elements.iter().map(json_length_bound).sum::<usize>()
+                + elements.len().saturating_sub(1)
+        }
+        serde_json::Value::Object(members) => {
+            // braces, then each quoted key, colon and value, with separating commas
+            2 + members
+                .iter()
+                .map(|(key, member)| key.len() + 3 + json_length_bound(member))
+                .sum::<usize>()
+                + members.len().saturating_sub(1)
+        }
+    }
+}
+
+impl SaveState {
+    /// Returns the emulation level of the processor from which the state was saved
+    pub fn emulation_level(&self) -> EmulationLevel {
+        self.emulation_level
+    }
+
+    /// Returns the display frame buffer as at the moment the state was saved (for example, to
+    /// render a thumbnail of the saved state)
+    pub fn frame_buffer(&self) -> &Display {
+        &self.frame_buffer
+    }
+
+    /// Returns the number of processor cycles that had been executed when the state was saved
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    /// Builder method that instantiates a [SaveState] from the specified JSON file, as written by
+    /// [SaveState::save_to_file()].  Returns [ErrorDetail::FileError] if the file cannot be read
+    /// or does not hold a valid state.
+    #[cfg(feature = "std")]
+    pub fn load_from_file(file_path: &Path) -> Result<SaveState, ErrorDetail> {
+        // attempt to open the file
+        if let Ok(json_file) = File::open(file_path) {
+            // parse the file as JSON and deserialise into a SaveState instance, refusing any
+            // whose parts are inconsistent (as restoring it could then panic)
+            if let Ok(state) = serde_json::from_reader::<File, SaveState>(json_file) {
+                if state.is_consistent() {
+                    return Ok(state);
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred reading from the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Method that serialises the passed [SaveState] instance to the specified JSON file
+    #[cfg(feature = "std")]
+    pub fn save_to_file(state: &SaveState, file_path: &Path) -> Result<(), ErrorDetail> {
+        // attempt to open the file; create it if it does not exist and truncate if it does
+        if let Ok(_) = File::create(file_path) {
+            if let Ok(serialised_state) = serde_json::to_string(state) {
+                if std::fs::write(file_path, serialised_state).is_ok() {
+                    return Ok(());
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred writing to the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Returns an upper bound on the length of the compact JSON serialisation (as written by
+    /// [SaveState::save_to_file()]) of any state saved from the same processor, so long as its
+    /// options are unchanged: the bound allows for the high-resolution display, ghost trail
+    /// intensities, every key pressed during a key wait and each number at its longest.  Hosts
+    /// that must reserve space for states in advance (such as libretro frontends) can size
+    /// their buffers by this.
+    pub fn max_json_len(&self) -> usize {
+        let mut worst: SaveState = self.clone();
+        worst.frame_buffer.resize(self.emulation_level, true);
+        worst.frame_buffer.track_intensities(true);
+        worst.keys_pressed_since_wait = vec![0; Key::ALL.len()];
+        worst.status = ProcessorStatus::WaitingForKeypress;
+        serde_json::to_value(&worst).map_or(0, |value| json_length_bound(&value))
+    }
+
+    /// Returns true if the display, stack and memory are each internally consistent, as is always
+    /// so of a state returned by [Processor::save_state()].  A state deserialised by other means
+    /// than [SaveState::load_from_file()] (for example as part of a larger file) should be
+    /// checked with this before being restored, as restoring an inconsistent state could panic.
+    pub fn is_consistent(&self) -> bool {
+        self.frame_buffer.is_consistent()
+            && self.memory.is_consistent()
+            && self.stack.pointer <= self.stack.bytes.len()
+    }
+}
+
+impl Processor {
+    /// Returns a [SaveState] holding a copy of the processor's complete emulated state, for
+    /// restoring later with [Processor::restore_state()].
+    pub fn save_state(&self) -> SaveState {
+        SaveState {
+            emulation_level: self.emulation_level,
+            frame_buffer: self.frame_buffer.clone(),
+            stack: self.stack.clone(),
+            memory: self.memory.clone(),
+            program_counter: self.program_counter,
+            index_register: self.index_register,
+            variable_registers: self.variable_registers,
+            rpl_registers: self.rpl_registers,
+            delay_timer: self.delay_timer,
+            sound_timer: self.sound_timer,
+            cycles: self.cycles,
+            execution_context: self.execution_context,
+            high_resolution_mode: self.high_resolution_mode,
+            keystate: self.keystate,
+            second_keystate: self.second_keystate,
+            waiting_original_keystate: self.waiting_original_keystate,
+            keys_pressed_since_wait: self.keys_pressed_since_wait.clone(),
+            // A key wait interrupted by pausing is saved as such, so that restoring carries on
+            // waiting
+            status: match self.resumed_status() {
+                ProcessorStatus::WaitingForKeypress => ProcessorStatus::WaitingForKeypress,
+                _ => self.status,
+            },
+            rng: self.rng.clone(),
+            vip_random_register: self.vip_random_register,
+        }
+    }
+
+    /// Restores the processor's emulated state from the specified [SaveState], so that execution
+    /// continues exactly as it would have from the moment the state was saved (including the
+    /// sequence of random numbers generated).  This is possible whatever the processor's current
+    /// status, including after a crash.  Whether execution is paused is left to the host: a
+    /// paused processor remains paused, while a state saved when paused is otherwise restored as
+    /// running (though an FX0A key wait in progress when saved carries on waiting).  The timers
+    /// and any display wait restart from the moment of restoring (or of resuming, if paused).  Returns
+    /// [ErrorDetail::IncompatibleSaveState] if the state was saved by a processor with a
+    /// different emulation level.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - the [SaveState] to restore
+    pub fn restore_state(&mut self, state: &SaveState) -> Result<(), ChipolataError> {
+        if state.emulation_level != self.emulation_level {
+            return Err(self.crash(ErrorDetail::IncompatibleSaveState {
+                saved: state.emulation_level,
+                current: self.emulation_level,
+            }));
+        }
+        self.frame_buffer = state.frame_buffer.clone();
+        self.frame_buffer
+            .track_intensities(self.options.ghost_trail_decay.is_some());
+        let mut stack: Stack = state.stack.clone();
+        stack.adopt_recording(&mut self.stack);
+        self.stack = stack;
+        self.memory = state.memory.clone();
+        self.instruction_cache.clear();
+        self.halt_watch.clear();
+        self.program_counter = state.program_counter;
+        self.index_register = state.index_register;
+        self.variable_registers = state.variable_registers;
+        self.rpl_registers = state.rpl_registers;
+        self.delay_timer = state.delay_timer;
+        self.sound_timer = state.sound_timer;
+        self.cycles = state.cycles;
+        self.execution_context = state.execution_context;
+        self.high_resolution_mode = state.high_resolution_mode;
+        self.keystate = state.keystate;
+        self.second_keystate = state.second_keystate;
+        self.waiting_original_keystate = state.waiting_original_keystate;
+        self.keys_pressed_since_wait = state.keys_pressed_since_wait.clone();
+        self.rng = state.rng.clone();
+        self.vip_random_register = state.vip_random_register;
+        self.restart_clocks(state.status == ProcessorStatus::WaitingForKeypress);
+        self.vblank_status = VBlankStatus::Idle;
+        self.sprites_drawn_this_frame = 0;
+        self.frame_start_cycle = state.cycles;
+        self.display_changed.set(true);
+        self.timing_recorder.interrupt();
+        let status: ProcessorStatus = match (self.status, state.status) {
+            (ProcessorStatus::Paused, _) => ProcessorStatus::Paused,
+            (_, ProcessorStatus::Paused) => ProcessorStatus::Running,
+            (_, status) => status,
+        };
+        self.set_status(status);
+        Ok(())
+    }
+}
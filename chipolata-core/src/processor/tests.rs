@@ -0,0 +1,4767 @@
+use super::*;
+use crate::clock::VirtualClock;
+use crate::font::{CustomFont, FontPreset};
+use crate::frame_sink::FrameDamage;
+use crate::palette::PaletteTheme;
+use crate::recording::RecordedKeyEvent;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+fn setup_test_processor_chip8() -> Processor {
+    let program: Program = Program::default();
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    };
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+fn setup_test_processor_chip48() -> Processor {
+    let program: Program = Program::default();
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+fn setup_test_processor_superchip10() -> Processor {
+    let program: Program = Program::default();
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::SuperChip10 {
+        variable_cycle_timing: false,
+    };
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+fn setup_test_processor_superchip11() -> Processor {
+    let program: Program = Program::default();
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::SuperChip11 {
+        octo_compatibility_mode: false,
+        variable_cycle_timing: false,
+    };
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+fn setup_test_processor_superchip11_octo() -> Processor {
+    let program: Program = Program::default();
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::SuperChip11 {
+        octo_compatibility_mode: true,
+        variable_cycle_timing: false,
+    };
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+fn setup_test_processor_chip8x() -> Processor {
+    let program: Program = Program::default();
+    let options: Options = Options::new(
+        crate::options::COSMAC_VIP_PROCESSOR_SPEED_HERTZ,
+        EmulationLevel::Chip8X {
+            variable_cycle_timing: false,
+        },
+    );
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+#[test]
+fn test_load_font_data() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let stored_font: Vec<u8> = Vec::from(
+        processor
+            .memory
+            .read_bytes(
+                processor.font_start_address,
+                processor.low_resolution_font.font_data_size(),
+            )
+            .unwrap(),
+    );
+    assert!(processor.load_font_data().is_ok());
+    assert_eq!(stored_font, *processor.low_resolution_font.font_data());
+}
+
+#[test]
+fn test_load_font_data_overflow_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.font_start_address = processor.memory.max_addressable_size() - 0x1;
+    assert_eq!(
+        processor.load_font_data().unwrap_err(),
+        ErrorDetail::MemoryAddressOutOfBounds {
+            address: (processor.font_start_address + processor.low_resolution_font.font_data_size())
+                as u16
+        }
+    );
+}
+
+#[test]
+fn test_load_font_data_superchip11_low_resolution() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let stored_font: Vec<u8> = Vec::from(
+        processor
+            .memory
+            .read_bytes(
+                processor.font_start_address,
+                processor.low_resolution_font.font_data_size(),
+            )
+            .unwrap(),
+    );
+    assert!(processor.load_font_data().is_ok());
+    assert_eq!(stored_font, *processor.low_resolution_font.font_data());
+}
+
+#[test]
+fn test_load_font_data_superchip11_high_resolution() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let stored_font: Vec<u8> = Vec::from(
+        processor
+            .memory
+            .read_bytes(
+                processor.high_resolution_font_start_address,
+                processor
+                    .high_resolution_font
+                    .as_ref()
+                    .unwrap()
+                    .font_data_size(),
+            )
+            .unwrap(),
+    );
+    assert!(processor.load_font_data().is_ok());
+    assert_eq!(
+        stored_font,
+        *processor.high_resolution_font.unwrap().font_data()
+    );
+}
+
+#[test]
+fn test_load_font_data_superchip11_high_resolution_overflow_error() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    // This leaves space for the low-resolution font, but not the high-resolution one
+    processor.font_start_address = 0x1AF;
+    assert_eq!(
+        processor.load_font_data().unwrap_err(),
+        ErrorDetail::MemoryAddressOutOfBounds {
+            address: (processor.high_resolution_font_start_address
+                + processor
+                    .high_resolution_font
+                    .as_ref()
+                    .unwrap()
+                    .font_data_size()) as u16
+        }
+    );
+}
+
+#[test]
+fn test_load_custom_fonts() {
+    let mut options: Options = Options::default();
+    options.custom_low_res_font = Some(FontPreset::Eti660.font());
+    options.custom_high_res_font = Some(FontPreset::Octo.font());
+    let processor: Processor = Processor::initialise_and_load(Program::default(), options).unwrap();
+    let low_res_font: CustomFont = FontPreset::Eti660.font();
+    let high_res_font: CustomFont = FontPreset::Octo.font();
+    assert_eq!(
+        processor
+            .memory
+            .read_bytes(processor.font_start_address, low_res_font.data().len())
+            .unwrap(),
+        low_res_font.data()
+    );
+    assert_eq!(
+        processor
+            .memory
+            .read_bytes(
+                processor.high_resolution_font_start_address,
+                high_res_font.data().len()
+            )
+            .unwrap(),
+        high_res_font.data()
+    );
+}
+
+#[test]
+fn test_load_program() {
+    let program_data: Vec<u8> = vec![0xFF, 0x0A, 0x12, 0xC4, 0xD1];
+    let program: Program = Program::new(program_data.clone());
+    let processor: Processor = Processor::initialise_and_load(program, Options::default()).unwrap();
+    assert_eq!(
+        program_data,
+        processor
+            .memory
+            .read_bytes(processor.program_start_address, program_data.len())
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_load_program_overflow_error() {
+    let program_data: Vec<u8> = vec![0xFF, 0x0A, 0x12, 0xC4, 0xD1];
+    let program: Program = Program::new(program_data);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    processor.program_start_address = processor.memory.max_addressable_size() - 0x1;
+    assert_eq!(
+        processor.load_program().unwrap_err(),
+        ErrorDetail::MemoryAddressOutOfBounds {
+            address: (processor.program_start_address + processor.program.program_data_size())
+                as u16
+        }
+    );
+}
+
+#[test]
+fn test_export_state_snapshot_minimal() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.frame_buffer[0][0] = 0xC3;
+    processor.cycles = 37;
+    let state_snapshot: StateSnapshot =
+        processor.export_state_snapshot(StateSnapshotVerbosity::Minimal);
+    assert!(
+        matches!(state_snapshot, StateSnapshot::MinimalSnapshot { .. })
+            && match state_snapshot {
+                StateSnapshot::MinimalSnapshot {
+                    frame_buffer,
+                    palette: _,
+                    status: _,
+                    processor_speed: _,
+                    play_sound: _,
+                    delay_timer: _,
+                    sound_timer: _,
+                    cycles,
+                    frames: _,
+                    cycles_since_frame: _,
+                    display_changed: _,
+                } => (frame_buffer[0][0] == 0xC3) && (cycles == 37),
+                _ => false,
+            }
+    );
+}
+
+#[test]
+fn test_export_state_snapshot_minimal_timers() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.delay_timer = 0x2A;
+    processor.sound_timer = 0x07;
+    let mut state_snapshot: StateSnapshot =
+        processor.export_state_snapshot(StateSnapshotVerbosity::Minimal);
+    match &state_snapshot {
+        StateSnapshot::MinimalSnapshot {
+            delay_timer,
+            sound_timer,
+            ..
+        } => assert!(*delay_timer == 0x2A && *sound_timer == 0x07),
+        _ => panic!("expected a minimal snapshot"),
+    }
+    processor.delay_timer = 0x0;
+    processor.export_state_snapshot_into(StateSnapshotVerbosity::Minimal, &mut state_snapshot);
+    match state_snapshot {
+        StateSnapshot::MinimalSnapshot {
+            delay_timer,
+            sound_timer,
+            ..
+        } => assert!(delay_timer == 0x0 && sound_timer == 0x07),
+        _ => panic!("expected a minimal snapshot"),
+    }
+}
+
+#[test]
+fn test_export_state_snapshot_frames() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.set_cycle_based_timing(true);
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // 1200 (loop forever)
+    let frame_cycles: usize =
+        processor.interval_cycles(processor.timer_interval_microseconds) as usize;
+    for _ in 0..(frame_cycles * 3 + 2) {
+        processor.execute_cycle().unwrap();
+    }
+    match processor.export_state_snapshot(StateSnapshotVerbosity::Minimal) {
+        StateSnapshot::MinimalSnapshot {
+            frames,
+            cycles_since_frame,
+            ..
+        } => assert!(frames == 3 && cycles_since_frame == 2),
+        _ => panic!("expected a minimal snapshot"),
+    }
+}
+
+#[test]
+fn test_export_state_snapshot_palette() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let mut options: Options = processor.options;
+    options.palette = PaletteTheme::LcdGreen.palette();
+    processor.apply_options(&options).unwrap();
+    match processor.export_state_snapshot(StateSnapshotVerbosity::Minimal) {
+        StateSnapshot::MinimalSnapshot { palette, .. } => {
+            assert_eq!(palette, PaletteTheme::LcdGreen.palette())
+        }
+        _ => panic!("expected a minimal snapshot"),
+    }
+}
+
+#[test]
+fn test_export_state_snapshot_display_changed() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    // 00E0 (clear screen), 1202 (loop forever)
+    processor
+        .memory
+        .write_bytes(0x200, &[0x00, 0xE0, 0x12, 0x02])
+        .unwrap();
+    let display_changed = |processor: &Processor| match processor
+        .export_state_snapshot(StateSnapshotVerbosity::Minimal)
+    {
+        StateSnapshot::MinimalSnapshot {
+            display_changed, ..
+        } => display_changed,
+        _ => panic!("expected a minimal snapshot"),
+    };
+    // The display is reported as changed after loading, then after clearing the screen, but not
+    // while only looping
+    assert!(display_changed(&processor) && !display_changed(&processor));
+    processor.execute_cycle().unwrap();
+    assert!(display_changed(&processor));
+    processor.execute_cycle().unwrap();
+    assert!(!display_changed(&processor));
+}
+
+#[test]
+fn test_state_snapshot_verbose() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.frame_buffer[0][0] = 0xC3;
+    processor.status = ProcessorStatus::Running;
+    processor.set_processor_speed(2635);
+    processor.program_counter = 0x1DF1;
+    processor.index_register = 0x3CC2;
+    processor.variable_registers[0x4] = 0xB2;
+    processor.rpl_registers[0x2] = 0x13;
+    processor.delay_timer = 0x3;
+    processor.sound_timer = 0x4;
+    processor.stack.push(0x30E1).unwrap();
+    processor.memory.bytes[0x33] = 0x44;
+    processor.cycles = 16473;
+    processor.high_resolution_mode = true;
+    let state_snapshot: StateSnapshot =
+        processor.export_state_snapshot(StateSnapshotVerbosity::Extended);
+    assert!(
+        matches!(state_snapshot, StateSnapshot::ExtendedSnapshot { .. })
+            && match state_snapshot {
+                StateSnapshot::ExtendedSnapshot {
+                    frame_buffer,
+                    palette: _,
+                    status,
+                    processor_speed,
+                    play_sound: _,
+                    program_counter,
+                    index_register,
+                    variable_registers,
+                    rpl_registers,
+                    delay_timer,
+                    sound_timer,
+                    mut stack,
+                    memory,
+                    cycles,
+                    high_resolution_mode,
+                    emulation_level,
+                } =>
+                    frame_buffer[0][0] == 0xC3
+                        && status == ProcessorStatus::Running
+                        && processor_speed == 2635
+                        && program_counter == 0x1DF1
+                        && index_register == 0x3CC2
+                        && variable_registers[0x4] == 0xB2
+                        && rpl_registers[0x2] == 0x13
+                        && delay_timer == 0x3
+                        && sound_timer == 0x4
+                        && stack.pop().unwrap() == 0x30E1
+                        && memory.bytes[0x33] == 0x44
+                        && cycles == 16473
+                        && high_resolution_mode == true
+                        && emulation_level
+                            == EmulationLevel::Chip8 {
+                                memory_limit_2k: false,
+                                variable_cycle_timing: false
+                            },
+                _ => false,
+            }
+    );
+}
+
+#[test]
+fn test_stack_depth_override() {
+    let mut options: Options = Options::default();
+    options.stack_depth_override = Some(64);
+    let processor: Processor = Processor::initialise_and_load(Program::default(), options).unwrap();
+    assert_eq!(processor.stack.max_stack_size(), 64);
+}
+
+#[test]
+fn test_stack_depth_override_invalid() {
+    let mut options: Options = Options::default();
+    options.stack_depth_override = Some(0);
+    let operands: Vec<Operand> = vec![Operand::StackDepthOverride(0)];
+    assert_eq!(
+        Processor::initialise_and_load(Program::default(), options)
+            .err()
+            .unwrap()
+            .inner_error,
+        ErrorDetail::OperandsOutOfBounds { operands }
+    );
+}
+
+#[test]
+fn test_stack_overflow_call_trace() {
+    // Program calls a subroutine at 0x202 which recursively calls itself
+    let program: Program = Program::new(vec![0x22, 0x02, 0x22, 0x02]);
+    let mut options: Options = Options::default();
+    options.stack_depth_override = Some(3);
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    for _ in 0..3 {
+        processor.execute_cycle().unwrap();
+    }
+    assert_eq!(
+        processor.execute_cycle().unwrap_err().inner_error,
+        ErrorDetail::PushFullStack {
+            call_trace: vec![0x202, 0x204, 0x204]
+        }
+    );
+}
+
+#[test]
+fn test_state_view() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.frame_buffer[0][0] = 0xC3;
+    processor.program_counter = 0x1DF1;
+    processor.variable_registers[0x4] = 0xB2;
+    processor.stack.push(0x30E1).unwrap();
+    processor.memory.bytes[0x33] = 0x44;
+    processor.cycles = 16473;
+    let state_view: StateView = processor.state_view();
+    assert!(
+        state_view.frame_buffer[0][0] == 0xC3
+            && std::ptr::eq(state_view.memory, &processor.memory)
+            && state_view.program_counter == 0x1DF1
+            && state_view.variable_registers[0x4] == 0xB2
+            && *state_view.stack == processor.stack
+            && state_view.memory.bytes[0x33] == 0x44
+            && state_view.cycles == 16473
+    );
+}
+
+#[test]
+fn test_state_view_to_snapshot() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x3CC2;
+    processor.memory.bytes[0x33] = 0x44;
+    assert_eq!(
+        processor.state_view().to_snapshot(),
+        processor.export_state_snapshot(StateSnapshotVerbosity::Extended)
+    );
+}
+
+#[test]
+fn test_export_state_snapshot_into() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let mut snapshot: StateSnapshot =
+        processor.export_state_snapshot(StateSnapshotVerbosity::Extended);
+    processor.frame_buffer[0][0] = 0xC3;
+    processor.index_register = 0x3CC2;
+    processor.stack.push(0x20A).unwrap();
+    processor.export_state_snapshot_into(StateSnapshotVerbosity::Extended, &mut snapshot);
+    assert_eq!(
+        snapshot,
+        processor.export_state_snapshot(StateSnapshotVerbosity::Extended)
+    );
+    // A snapshot of the wrong verbosity is replaced
+    processor.export_state_snapshot_into(StateSnapshotVerbosity::Minimal, &mut snapshot);
+    assert!(matches!(
+        snapshot,
+        StateSnapshot::MinimalSnapshot { frame_buffer, cycles: 0, .. } if frame_buffer[0][0] == 0xC3
+    ));
+}
+
+#[test]
+fn test_memory_profile() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert!(processor.export_memory_profile().is_none());
+    processor.set_memory_profiling(true);
+    processor.turbo_mode = true;
+    processor.program_counter = 0x200;
+    processor.index_register = 0x300;
+    // D012 (draw 2-byte sprite), F155 (store V0-V1), F265 (load V0-V2)
+    let instructions: [u8; 6] = [0xD0, 0x12, 0xF1, 0x55, 0xF2, 0x65];
+    processor.memory.write_bytes(0x200, &instructions).unwrap();
+    for _ in 0..3 {
+        processor.execute_cycle().unwrap();
+    }
+    let profile: MemoryProfile = processor.export_memory_profile().unwrap();
+    assert!(
+        profile.executes[0x200..0x206] == [1; 6]
+            && profile.executes[0x206] == 0
+            && profile.reads[0x300..0x304] == [1, 2, 1, 1] // CHIP-48 FX55 leaves I at 0x301
+            && profile.writes[0x300..0x303] == [1, 1, 0]
+            && profile.reads[0x200] == 0
+    );
+}
+
+#[test]
+fn test_memory_profile_reset() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.set_memory_profiling(true);
+    processor.turbo_mode = true;
+    processor.memory.write_bytes(0x200, &[0x60, 0x01]).unwrap();
+    processor.execute_cycle().unwrap();
+    processor.reset().unwrap();
+    assert_eq!(
+        processor
+            .export_memory_profile()
+            .unwrap()
+            .max_total_accesses(),
+        0
+    );
+    processor.set_memory_profiling(false);
+    assert!(processor.export_memory_profile().is_none());
+}
+
+#[test]
+fn test_execute_cycle() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x0BC1;
+    let instruction: [u8; 2] = [0xA1, 0x11];
+    processor.memory.write_bytes(0x0BC1, &instruction).unwrap();
+    assert!(processor.execute_cycle().is_ok() && processor.program_counter == 0x0BC3);
+}
+
+#[test]
+fn test_execute_cycle_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x0BC1;
+    let instruction: [u8; 2] = [0xFF, 0xFF]; // invalid instruction
+    processor.memory.write_bytes(0x0BC1, &instruction).unwrap();
+    assert_eq!(
+        processor.execute_cycle().unwrap_err().inner_error,
+        ErrorDetail::UnknownInstruction { opcode: 0xFFFF }
+    );
+}
+
+#[test]
+fn test_execute_cycle_error_suggests_emulation_level() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.memory.write_bytes(0x200, &[0x00, 0xFF]).unwrap();
+    assert_eq!(
+        processor
+            .execute_cycle()
+            .unwrap_err()
+            .suggested_emulation_level(),
+        Some(EmulationLevel::SuperChip10 {
+            variable_cycle_timing: false
+        })
+    );
+}
+
+#[test]
+fn test_instruction_cache() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x0BC2;
+    processor.memory.write_bytes(0x0BC2, &[0xA1, 0x11]).unwrap();
+    processor.execute_cycle().unwrap();
+    assert_eq!(
+        processor.instruction_cache.get(0x0BC2),
+        Some((0xA111, Instruction::OpANNN { nnn: 0x111 }))
+    );
+}
+
+#[test]
+fn test_instruction_cache_invalidate() {
+    let mut cache: InstructionCache = InstructionCache::new(CHIPOLATA_MEMORY_SIZE_BYTES);
+    cache.insert(0x200, 0x00E0, Instruction::Op00E0);
+    cache.insert(0x202, 0x00E0, Instruction::Op00E0);
+    cache.invalidate(0x203..0x204);
+    assert!(cache.get(0x200).is_some() && cache.get(0x202).is_none());
+    cache.invalidate(0x201..0x202);
+    assert!(cache.get(0x200).is_none());
+}
+
+#[test]
+fn test_instruction_cache_self_modifying_program() {
+    // 6A01 (VA = 1), 606A (V0 = 6A), 6105 (V1 = 5), A200 (I = 200), F155 (store V0 and V1 at
+    // 200, overwriting the first instruction with 6A05), 1200 (jump back to 200)
+    let program: Program = Program::new(vec![
+        0x6A, 0x01, 0x60, 0x6A, 0x61, 0x05, 0xA2, 0x00, 0xF1, 0x55, 0x12, 0x00,
+    ]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    for _ in 0..6 {
+        processor.execute_cycle().unwrap();
+    }
+    assert_eq!(processor.variable_registers[0xA], 0x1);
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.variable_registers[0xA], 0x5);
+}
+
+#[test]
+fn test_error_execution_context() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor
+        .memory
+        .write_bytes(
+            processor.program_counter as usize,
+            &[0x60, 0x01, 0x00, 0xEE],
+        )
+        .unwrap();
+    processor.execute_cycle().unwrap();
+    let error: ChipolataError = processor.execute_cycle().unwrap_err();
+    assert_eq!(
+        error.context,
+        ExecutionContext {
+            program_counter: 0x202,
+            opcode: Some(0x00EE),
+            cycle: 2,
+        }
+    );
+    assert!(error
+        .to_string()
+        .contains("opcode 0x00EE at address 0x0202, cycle 2"));
+}
+
+#[test]
+fn test_error_execution_context_fetch_out_of_bounds() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.program_counter = 0xFFF;
+    let error: ChipolataError = processor.execute_cycle().unwrap_err();
+    assert_eq!(error.context.program_counter, 0xFFF);
+    assert_eq!(error.context.opcode, None);
+}
+
+#[test]
+fn test_error_offending_opcode() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let instruction: [u8; 2] = [0x00, 0xEE]; // return from subroutine, with an empty stack
+    processor
+        .memory
+        .write_bytes(processor.program_counter as usize, &instruction)
+        .unwrap();
+    assert_eq!(
+        processor.execute_cycle().unwrap_err().offending_opcode(),
+        Some(0x00EE)
+    );
+}
+
+#[test]
+fn test_error_text_report() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x0BC1;
+    let instruction: [u8; 2] = [0xFF, 0xFF]; // invalid instruction
+    processor.memory.write_bytes(0x0BC1, &instruction).unwrap();
+    let report: String = processor.execute_cycle().unwrap_err().to_text_report();
+    assert!(
+        report.contains("Opcode: 0xFFFF")
+            && report.contains("Program counter: 0x0BC3")
+            && report.contains("0x0BC0: 00 FF FF")
+    );
+}
+
+#[test]
+fn test_error_json_report() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x0BC1;
+    let instruction: [u8; 2] = [0xFF, 0xFF]; // invalid instruction
+    processor.memory.write_bytes(0x0BC1, &instruction).unwrap();
+    let report: String = processor.execute_cycle().unwrap_err().to_json();
+    assert!(
+        report.contains("\"opcode\": \"0xFFFF\"")
+            && report.contains("\"program_counter\": \"0x0BC3\"")
+    );
+}
+
+fn setup_test_processor_chip8_hires(program_data: Vec<u8>) -> Processor {
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    };
+    options.chip8_hires_mode = true;
+    Processor::initialise_and_load(Program::new(program_data), options).unwrap()
+}
+
+#[test]
+fn test_chip8_hires_startup() {
+    let mut processor: Processor = setup_test_processor_chip8_hires(vec![0x12, 0x60]);
+    assert!(
+        processor.frame_buffer.get_row_size_bytes() == 8
+            && processor.frame_buffer.get_column_size_pixels() == 64
+            && processor.program_counter == 0x2C0
+    );
+    processor.program_counter = 0x300;
+    processor.reset().unwrap();
+    assert!(
+        processor.frame_buffer.get_column_size_pixels() == 64 && processor.program_counter == 0x2C0
+    );
+}
+
+#[test]
+fn test_chip8_hires_startup_without_entry_jump() {
+    let processor: Processor = setup_test_processor_chip8_hires(vec![0x12, 0x00]);
+    assert!(
+        processor.frame_buffer.get_column_size_pixels() == 64 && processor.program_counter == 0x200
+    );
+}
+
+#[test]
+fn test_chip8_hires_ignored_outside_chip8() {
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    options.chip8_hires_mode = true;
+    let processor: Processor =
+        Processor::initialise_and_load(Program::new(vec![0x12, 0x60]), options).unwrap();
+    assert!(
+        processor.frame_buffer.get_column_size_pixels() == 32 && processor.program_counter == 0x200
+    );
+}
+
+fn setup_test_processor_unknown_instruction_policy(policy: UnknownInstructionPolicy) -> Processor {
+    // FFFF (unknown), 00FB (SUPER-CHIP only), 02F5 (machine code routine), 6A12 (VA = 0x12)
+    let program_data: Vec<u8> = vec![0xFF, 0xFF, 0x00, 0xFB, 0x02, 0xF5, 0x6A, 0x12];
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    options.on_unknown_instruction = policy;
+    let mut processor: Processor =
+        Processor::initialise_and_load(Program::new(program_data), options).unwrap();
+    processor.turbo_mode = true;
+    processor
+}
+
+#[test]
+fn test_unknown_instruction_policy_skip() {
+    let mut processor: Processor =
+        setup_test_processor_unknown_instruction_policy(UnknownInstructionPolicy::Skip);
+    processor.execute_cycle().unwrap();
+    assert!(
+        processor.variable_registers[0xA] == 0x12
+            && processor.program_counter == 0x208
+            && processor.cycles == 1
+    );
+}
+
+#[test]
+fn test_unknown_instruction_policy_nop() {
+    let mut processor: Processor =
+        setup_test_processor_unknown_instruction_policy(UnknownInstructionPolicy::Nop);
+    for _ in 0..3 {
+        processor.execute_cycle().unwrap();
+    }
+    assert!(processor.variable_registers[0xA] == 0x00 && processor.program_counter == 0x206);
+    processor.execute_cycle().unwrap();
+    assert!(
+        processor.variable_registers[0xA] == 0x12 && processor.status == ProcessorStatus::Running
+    );
+}
+
+#[test]
+fn test_unknown_instruction_policy_other_errors() {
+    let mut processor: Processor =
+        setup_test_processor_unknown_instruction_policy(UnknownInstructionPolicy::Skip);
+    processor.memory.write_bytes(0x200, &[0x00, 0xEE]).unwrap(); // return with empty stack
+    assert_eq!(
+        processor.execute_cycle().unwrap_err().inner_error,
+        ErrorDetail::PopEmptyStack
+    );
+}
+
+#[test]
+fn test_rng_seed_option() {
+    // V0 = random byte; loop
+    let program_data: Vec<u8> = vec![0xC0, 0xFF, 0x12, 0x00];
+    let mut options: Options = Options::default();
+    options.processor_speed_hertz = 1_000_000;
+    options.rng_seed = Some(0xC0FFEE);
+    let mut first: Processor =
+        Processor::initialise_and_load(Program::new(program_data.clone()), options).unwrap();
+    let mut second: Processor =
+        Processor::initialise_and_load(Program::new(program_data), options).unwrap();
+    assert!(first.rng_seed() == 0xC0FFEE && second.rng_seed() == 0xC0FFEE);
+    for _ in 0..10 {
+        first.execute_cycle().unwrap();
+        second.execute_cycle().unwrap();
+        assert_eq!(
+            first.variable_registers[0x0],
+            second.variable_registers[0x0]
+        );
+    }
+    assert_eq!(first.memory, second.memory);
+}
+
+#[test]
+fn test_run_benchmark() {
+    // Program is a single instruction that jumps to itself
+    let program: Program = Program::new(vec![0x12, 0x00]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let report: BenchmarkReport = processor.run_benchmark(500).unwrap();
+    assert!(report.cycles_executed == 500 && processor.cycles == 500 && !processor.turbo_mode());
+}
+
+#[test]
+fn test_run_benchmark_stops_on_exit() {
+    // Program is a single SUPER-CHIP 1.1 exit instruction
+    let program: Program = Program::new(vec![0x00, 0xFD]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let report: BenchmarkReport = processor.run_benchmark(500).unwrap();
+    assert_eq!(report.cycles_executed, 1);
+}
+
+fn setup_test_processor_halt_detection(program_data: Vec<u8>) -> Processor {
+    let mut options: Options = Options::default();
+    options.halt_detection = true;
+    let mut processor: Processor =
+        Processor::initialise_and_load(Program::new(program_data), options).unwrap();
+    processor.set_turbo_mode(true);
+    processor
+}
+
+#[test]
+fn test_halt_detection_jump_to_self() {
+    // Program is a single instruction that jumps to itself
+    let mut processor: Processor = setup_test_processor_halt_detection(vec![0x12, 0x00]);
+    let report: BenchmarkReport = processor.run_benchmark(500).unwrap();
+    assert!(report.cycles_executed == 1 && processor.status == ProcessorStatus::Halted);
+}
+
+#[test]
+fn test_halt_detection_waits_for_sound() {
+    // ST = 0x02, then jump to self
+    let mut processor: Processor =
+        setup_test_processor_halt_detection(vec![0x6A, 0x02, 0xFA, 0x18, 0x12, 0x04]);
+    processor.set_cycle_based_timing(true);
+    let frame_cycles: usize =
+        processor.interval_cycles(processor.timer_interval_microseconds) as usize;
+    processor.run_benchmark(frame_cycles).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Running);
+    processor.run_benchmark(3 * frame_cycles).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Halted);
+}
+
+#[test]
+fn test_halt_detection_tight_loop() {
+    // V0 = 0x01 then jump back, forever
+    let mut processor: Processor =
+        setup_test_processor_halt_detection(vec![0x00, 0xE0, 0x60, 0x01, 0x12, 0x02]);
+    let report: BenchmarkReport = processor.run_benchmark(500).unwrap();
+    assert!(report.cycles_executed == 5 && processor.status == ProcessorStatus::Halted);
+    assert!(processor.execute_cycle().is_err());
+}
+
+#[test]
+fn test_halt_detection_changing_loop() {
+    // V0 += 0x01 then jump back, forever
+    let mut processor: Processor =
+        setup_test_processor_halt_detection(vec![0x70, 0x01, 0x12, 0x00]);
+    processor.run_benchmark(500).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Running);
+}
+
+#[test]
+fn test_halt_detection_key_polling_loop() {
+    // Skip back to the start unless key V0 is pressed, then jump to self
+    let mut processor: Processor =
+        setup_test_processor_halt_detection(vec![0xE0, 0x9E, 0x12, 0x00, 0x12, 0x04]);
+    processor.run_benchmark(500).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Running);
+    processor.set_key_status(Key::Key0, true);
+    processor.run_benchmark(500).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Halted);
+}
+
+#[test]
+fn test_halt_detection_disabled() {
+    let program: Program = Program::new(vec![0x12, 0x00]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    processor.run_benchmark(500).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Running);
+}
+
+fn setup_test_processor_run_frames() -> Processor {
+    // DT = 0x05, then loop forever
+    let program: Program = Program::new(vec![0x6A, 0x05, 0xFA, 0x15, 0x12, 0x04]);
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+#[test]
+fn test_run_frames() {
+    let mut processor: Processor = setup_test_processor_run_frames();
+    let frame_cycles: usize =
+        processor.interval_cycles(processor.timer_interval_microseconds) as usize;
+    processor.run_frames(3).unwrap();
+    assert_eq!(processor.cycles, 3 * frame_cycles);
+    assert_eq!(processor.delay_timer, 0x2);
+    assert!(!processor.turbo_mode() && !processor.cycle_based_timing);
+    assert_eq!(processor.status, ProcessorStatus::Running);
+}
+
+#[test]
+fn test_ghost_trails() {
+    // I = 0x208; draw 1-byte sprite at (V0, V0); clear screen; loop; 0x80: sprite data (single
+    // top-left pixel)
+    let program: Program = Program::new(vec![0xA2, 0x08, 0xD0, 0x01, 0x00, 0xE0, 0x12, 0x06, 0x80]);
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    options.ghost_trail_decay = Some(0x40);
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    processor.run_frames(1).unwrap();
+    let intensity: u8 = processor.frame_buffer.get_pixel_intensity(0, 0);
+    assert!(!processor.frame_buffer.get_pixel(0, 0) && intensity > 0x0 && intensity < 0xFF);
+    processor.run_frames(5).unwrap();
+    assert_eq!(processor.frame_buffer.get_pixel_intensity(0, 0), 0x0);
+    options.ghost_trail_decay = None;
+    processor.apply_options(&options).unwrap();
+    assert!(!processor.frame_buffer.has_intensities());
+}
+
+#[test]
+fn test_run_frames_aligns_to_timer() {
+    let mut processor: Processor = setup_test_processor_run_frames();
+    let frame_cycles: usize =
+        processor.interval_cycles(processor.timer_interval_microseconds) as usize;
+    processor.execute_cycle().unwrap();
+    processor.run_frames(1).unwrap();
+    assert_eq!(processor.cycles, frame_cycles);
+}
+
+#[test]
+fn test_run_frames_while_paused() {
+    let mut processor: Processor = setup_test_processor_run_frames();
+    let frame_cycles: usize =
+        processor.interval_cycles(processor.timer_interval_microseconds) as usize;
+    processor.pause_execution().unwrap();
+    processor.run_frames(1).unwrap();
+    assert_eq!(processor.cycles, frame_cycles);
+    assert_eq!(processor.status, ProcessorStatus::Paused);
+}
+
+#[test]
+fn test_run_frames_ahead() {
+    let mut processor: Processor = setup_test_processor_run_frames();
+    let frame_cycles: usize =
+        processor.interval_cycles(processor.timer_interval_microseconds) as usize;
+    match processor.run_frames_ahead(1, 2).unwrap() {
+        StateSnapshot::MinimalSnapshot { frames, .. } => assert_eq!(frames, 3),
+        _ => panic!("expected a minimal snapshot"),
+    }
+    assert_eq!(processor.cycles, frame_cycles);
+    assert_eq!(processor.frames, 1);
+    assert_eq!(processor.delay_timer, 0x4);
+    assert_eq!(processor.status, ProcessorStatus::Running);
+}
+
+#[test]
+fn test_run_frames_ahead_matches_run_frames() {
+    let mut processor: Processor = setup_test_processor_run_frames();
+    let mut reference: Processor = setup_test_processor_run_frames();
+    for _ in 0..3 {
+        processor.run_frames_ahead(1, 1).unwrap();
+        reference.run_frames(1).unwrap();
+    }
+    assert_eq!(processor.cycles, reference.cycles);
+    assert_eq!(processor.frames, reference.frames);
+    assert_eq!(processor.delay_timer, reference.delay_timer);
+    assert_eq!(processor.program_counter, reference.program_counter);
+    assert_eq!(processor.frame_buffer, reference.frame_buffer);
+}
+
+#[test]
+fn test_run_frames_ahead_suspends_sound_sink() {
+    // Program sets V0 to 0x05, sets the sound timer to V0 then jumps to itself
+    let program: Program = Program::new(vec![0x60, 0x05, 0xF0, 0x18, 0x12, 0x04]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let (sender, receiver) = std::sync::mpsc::channel::<SoundEvent>();
+    processor.set_sound_sink(sender);
+    processor.run_frames_ahead(0, 1).unwrap();
+    assert_eq!(receiver.try_iter().count(), 0);
+    assert_eq!(processor.sound_timer, 0x0);
+    processor.run_frames(1).unwrap();
+    assert_eq!(
+        receiver.try_iter().collect::<Vec<SoundEvent>>(),
+        vec![SoundEvent {
+            playing: true,
+            cycle: 2
+        }]
+    );
+}
+
+#[test]
+fn test_run_frames_ahead_when_paused() {
+    let mut processor: Processor = setup_test_processor_run_frames();
+    processor.pause_execution().unwrap();
+    match processor.run_frames_ahead(0, 2).unwrap() {
+        StateSnapshot::MinimalSnapshot { frames, .. } => assert_eq!(frames, 0),
+        _ => panic!("expected a minimal snapshot"),
+    }
+    assert_eq!(processor.cycles, 0);
+}
+
+#[test]
+fn test_pause_suspends_timers() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.status = ProcessorStatus::Running;
+    processor.delay_timer = 0x5;
+    processor.last_timer_decrement = processor.now() - processor.frame_duration() / 2;
+    processor.pause_execution().unwrap();
+    std::thread::sleep(processor.frame_duration() * 2);
+    processor.resume_execution().unwrap();
+    processor.decrement_timers();
+    assert_eq!(processor.delay_timer, 0x5);
+    assert!(processor.elapsed(processor.last_timer_decrement) >= processor.frame_duration() / 2);
+}
+
+#[test]
+fn test_pause_freezes_timer_phase() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.status = ProcessorStatus::Running;
+    processor.last_timer_decrement = processor.now() - processor.frame_duration() / 4;
+    processor.pause_execution().unwrap();
+    let phase: f64 = processor.timer_phase();
+    std::thread::sleep(processor.frame_duration());
+    assert_eq!(processor.timer_phase(), phase);
+}
+
+#[test]
+fn test_turbo_mode_ignores_processor_speed() {
+    let program: Program = Program::new(vec![0x12, 0x00]);
+    let mut options: Options = Options::default();
+    options.processor_speed_hertz = 1;
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    processor.set_turbo_mode(true);
+    let start_time: Instant = processor.now();
+    for _ in 0..10 {
+        processor.execute_cycle().unwrap();
+    }
+    assert!(processor.elapsed(start_time) < Duration::from_secs(1));
+}
+
+#[test]
+fn test_virtual_clock_paces_timers() {
+    // V0 = 0x05; DT = V0; loop
+    let program: Program = Program::new(vec![0x60, 0x05, 0xF0, 0x15, 0x12, 0x04]);
+    let options: Options = Options {
+        emulation_level: EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        },
+        processor_speed_hertz: 600,
+        ..Options::default()
+    };
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    processor.set_clock(VirtualClock::new());
+    let start_time: std::time::Instant = std::time::Instant::now();
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.delay_timer, 0x05);
+    // One second of cycles at the processor speed elapses without waiting in real time
+    for _ in 0..598 {
+        processor.execute_cycle().unwrap();
+    }
+    assert!(processor.delay_timer == 0x0 && start_time.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn test_frame_sink_pushes_changed_frames() {
+    struct CountingSink {
+        frames: Arc<Mutex<Vec<(bool, FrameDamage)>>>,
+    }
+    impl FrameSink for CountingSink {
+        fn present_frame(&mut self, frame_buffer: &Display, damage: FrameDamage) {
+            let pixel_on: bool = frame_buffer.get_pixel(0, 0);
+            self.frames.lock().unwrap().push((pixel_on, damage));
+        }
+    }
+    // 00E0: clear screen; A208: I = 0x208; D001: draw 1-byte sprite at (V0, V0); 1206: loop;
+    // 0x80: sprite data (single top-left pixel)
+    let program: Program = Program::new(vec![0x00, 0xE0, 0xA2, 0x08, 0xD0, 0x01, 0x12, 0x06, 0x80]);
+    let options: Options = Options {
+        emulation_level: EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        },
+        ..Options::default()
+    };
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    let frames: Arc<Mutex<Vec<(bool, FrameDamage)>>> = Arc::new(Mutex::new(Vec::new()));
+    processor.set_frame_sink(CountingSink {
+        frames: Arc::clone(&frames),
+    });
+    processor.run_frames(3).unwrap();
+    // The blank first frame is pushed whole, then the frame in which the pixel is drawn (which
+    // waits for the vblank interrupt); the display is unchanged thereafter
+    let pixel_damage: FrameDamage = FrameDamage {
+        x: 0,
+        y: 0,
+        width: 1,
+        height: 1,
+        full_frame: false,
+    };
+    {
+        let frames = frames.lock().unwrap();
+        assert!(
+            frames.len() == 2
+                && !frames[0].0
+                && frames[0].1.full_frame
+                && frames[1] == (true, pixel_damage)
+        );
+    }
+    processor.frame_buffer.clear();
+    processor.present_frame();
+    let frames = frames.lock().unwrap();
+    assert!(frames.len() == 3 && frames[2] == (false, pixel_damage));
+}
+
+#[test]
+fn test_pre_execute_hook() {
+    // Program sets V0 to 0x12 then jumps back to the start
+    let program: Program = Program::new(vec![0x60, 0x12, 0x12, 0x00]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let names: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let hook_names: Arc<Mutex<Vec<String>>> = Arc::clone(&names);
+    processor.set_pre_execute_hook(move |processor: &Processor, instruction: &Instruction| {
+        // The hook runs before execution, so V0 should not yet have been set by 6XNN
+        if let Instruction::Op6XNN { .. } = instruction {
+            assert_eq!(processor.variable_registers[0x0], 0x0);
+        }
+        hook_names
+            .lock()
+            .unwrap()
+            .push(instruction.name().to_string());
+    });
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    assert_eq!(*names.lock().unwrap(), vec!["6XNN", "1NNN"]);
+}
+
+#[test]
+fn test_audio_callback() {
+    // Program sets V0 to 0x02, sets the sound timer to V0 then jumps to itself
+    let program: Program = Program::new(vec![0x60, 0x02, 0xF0, 0x18, 0x12, 0x04]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let events: Arc<Mutex<Vec<SoundEvent>>> = Arc::new(Mutex::new(Vec::new()));
+    let callback_events: Arc<Mutex<Vec<SoundEvent>>> = Arc::clone(&events);
+    processor.set_audio_callback(move |event: SoundEvent| {
+        callback_events.lock().unwrap().push(event);
+    });
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    // Let the sound timer run down to zero
+    processor.sound_timer = 0x0;
+    processor.execute_cycle().unwrap();
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![
+            SoundEvent {
+                playing: true,
+                cycle: 2
+            },
+            SoundEvent {
+                playing: false,
+                cycle: 4
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_clear_audio_callback() {
+    let program: Program = Program::new(vec![0x60, 0x02, 0xF0, 0x18]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let count: Arc<Mutex<usize>> = Arc::new(Mutex::new(0));
+    let callback_count: Arc<Mutex<usize>> = Arc::clone(&count);
+    processor.set_audio_callback(move |_: SoundEvent| {
+        *callback_count.lock().unwrap() += 1;
+    });
+    processor.clear_audio_callback();
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    assert_eq!(*count.lock().unwrap(), 0);
+}
+
+#[test]
+fn test_sound_sink() {
+    // Program sets V0 to 0x02, sets the sound timer to V0 then jumps to itself
+    let program: Program = Program::new(vec![0x60, 0x02, 0xF0, 0x18, 0x12, 0x04]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let (sender, receiver) = std::sync::mpsc::channel::<SoundEvent>();
+    processor.set_sound_sink(sender);
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    processor.sound_timer = 0x0;
+    processor.execute_cycle().unwrap();
+    assert_eq!(
+        receiver.try_iter().collect::<Vec<SoundEvent>>(),
+        vec![
+            SoundEvent {
+                playing: true,
+                cycle: 2
+            },
+            SoundEvent {
+                playing: false,
+                cycle: 3
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_clear_pre_execute_hook() {
+    let program: Program = Program::new(vec![0x12, 0x00]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let count: Arc<Mutex<usize>> = Arc::new(Mutex::new(0));
+    let hook_count: Arc<Mutex<usize>> = Arc::clone(&count);
+    processor.set_pre_execute_hook(move |_: &Processor, _: &Instruction| {
+        *hook_count.lock().unwrap() += 1;
+    });
+    processor.execute_cycle().unwrap();
+    processor.clear_pre_execute_hook();
+    processor.execute_cycle().unwrap();
+    assert_eq!(*count.lock().unwrap(), 1);
+}
+
+#[test]
+fn test_reset() {
+    // Program sets V0 to 0x12, clears the screen then calls a subroutine at 0x200
+    let program: Program = Program::new(vec![0x60, 0x12, 0x00, 0xE0, 0x22, 0x00]);
+    let mut options: Options = Options::default();
+    options.rng_seed = Some(0x1234);
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    let initial_memory: Memory = processor.memory.clone();
+    for _ in 0..3 {
+        processor.execute_cycle().unwrap();
+    }
+    processor.delay_timer = 0x20;
+    processor.frame_buffer[0][0] = 0xFF;
+    processor.memory.write_byte(0x300, 0xAA).unwrap();
+    processor.reset().unwrap();
+    assert!(
+        processor.status == ProcessorStatus::ProgramLoaded
+            && processor.program_counter == 0x200
+            && processor.variable_registers[0x0] == 0x0
+            && processor.delay_timer == 0x0
+            && processor.cycles == 0
+            && processor.stack.pop().is_err()
+            && processor.frame_buffer[0][0] == 0x0
+            && processor.memory == initial_memory
+            && processor.rng_seed() == 0x1234
+    );
+}
+
+#[test]
+fn test_reset_after_crash() {
+    // Program is a single unknown instruction
+    let program: Program = Program::new(vec![0xFF, 0xFF]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    assert!(processor.execute_cycle().is_err());
+    assert_eq!(processor.status, ProcessorStatus::Crashed);
+    processor.reset().unwrap();
+    assert_eq!(processor.status, ProcessorStatus::ProgramLoaded);
+}
+
+#[test]
+fn test_trace_tail() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // jump-to-self loop
+    for _ in 0..CRASH_DUMP_TRACE_LENGTH + 2 {
+        processor.execute_cycle().unwrap();
+    }
+    let trace: Vec<ExecutionContext> = processor.trace_tail();
+    assert!(
+        trace.len() == CRASH_DUMP_TRACE_LENGTH
+            && trace[0].cycle == 3
+            && trace.last()
+                == Some(&ExecutionContext {
+                    program_counter: 0x200,
+                    opcode: Some(0x1200),
+                    cycle: CRASH_DUMP_TRACE_LENGTH + 2,
+                })
+    );
+    processor.reset().unwrap();
+    assert!(processor.trace_tail().is_empty());
+}
+
+#[test]
+fn test_crash_dump() {
+    // V0 = 0x01; unknown instruction
+    let program: Program = Program::new(vec![0x60, 0x01, 0xFF, 0xFF]);
+    let mut options: Options = Options::default();
+    options.processor_speed_hertz = 1_000_000;
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    processor.set_processor_speed(2_000_000);
+    processor.execute_cycle().unwrap();
+    let error: ChipolataError = processor.execute_cycle().unwrap_err();
+    let dump: CrashDump = processor.crash_dump(&error);
+    assert!(
+        dump.program_data() == [0x60, 0x01, 0xFF, 0xFF]
+            && dump.options().processor_speed_hertz == 2_000_000
+            && dump.report() == error.to_json()
+            && dump.trace().len() == 2
+            && dump.trace()[1] == error.context
+            && dump.trace_text().lines().count() == 2
+    );
+}
+
+#[test]
+fn test_crash_dump_save_to_file() {
+    const FILENAME: &str = "unit_test_crash_dump.chipolata-dump";
+    let program: Program = Program::new(vec![0xFF, 0xFF]); // unknown instruction
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let error: ChipolataError = processor.execute_cycle().unwrap_err();
+    let dump: CrashDump = processor.crash_dump(&error);
+    CrashDump::save_to_file(&dump, std::path::Path::new(FILENAME)).unwrap();
+    let mut archive: zip::ZipArchive<std::fs::File> =
+        zip::ZipArchive::new(std::fs::File::open(FILENAME).unwrap()).unwrap();
+    let mut program_data: Vec<u8> = Vec::new();
+    std::io::Read::read_to_end(
+        &mut archive.by_name(CRASH_DUMP_PROGRAM_FILE).unwrap(),
+        &mut program_data,
+    )
+    .unwrap();
+    let mut trace_text: String = String::new();
+    std::io::Read::read_to_string(
+        &mut archive.by_name(CRASH_DUMP_TRACE_FILE).unwrap(),
+        &mut trace_text,
+    )
+    .unwrap();
+    std::fs::remove_file(FILENAME).unwrap();
+    assert!(
+        program_data == vec![0xFF, 0xFF]
+            && trace_text == dump.trace_text()
+            && archive.by_name(CRASH_DUMP_OPTIONS_FILE).is_ok()
+            && archive.by_name(CRASH_DUMP_REPORT_FILE).is_ok()
+    );
+}
+
+#[test]
+fn test_save_restore_state() {
+    // Program repeatedly sets V0 to a random number and increments V1
+    let program: Program = Program::new(vec![0xC0, 0xFF, 0x71, 0x01, 0x12, 0x00]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    processor.execute_cycle().unwrap();
+    let state: SaveState = processor.save_state();
+    for _ in 0..6 {
+        processor.execute_cycle().unwrap();
+    }
+    let variable_registers: [u8; VARIABLE_REGISTER_COUNT] = processor.variable_registers;
+    processor.restore_state(&state).unwrap();
+    assert!(processor.cycles == 1 && processor.program_counter == 0x202);
+    for _ in 0..6 {
+        processor.execute_cycle().unwrap();
+    }
+    assert_eq!(processor.variable_registers, variable_registers);
+}
+
+#[test]
+fn test_save_load_state_file() {
+    const FILENAME: &str = "unit_test_save_load_state.json";
+    // Program repeatedly sets V0 to a random number and increments V1
+    let program: Program = Program::new(vec![0xC0, 0xFF, 0x71, 0x01, 0x12, 0x00]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    for _ in 0..3 {
+        processor.execute_cycle().unwrap();
+    }
+    SaveState::save_to_file(&processor.save_state(), Path::new(FILENAME)).unwrap();
+    let state: SaveState = SaveState::load_from_file(Path::new(FILENAME)).unwrap();
+    std::fs::remove_file(FILENAME).unwrap();
+    let (variable_registers, memory) = (processor.variable_registers, processor.memory.clone());
+    processor.reset().unwrap();
+    processor.restore_state(&state).unwrap();
+    assert!(
+        processor.cycles == 3
+            && processor.program_counter == 0x200
+            && processor.variable_registers == variable_registers
+            && processor.memory == memory
+            && processor.frame_buffer == *state.frame_buffer()
+    );
+}
+
+#[test]
+fn test_save_state_max_json_len() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let bound: usize = processor.save_state().max_json_len();
+    // Grow every part of the state to its longest serialisation
+    processor.high_resolution_mode = true;
+    processor
+        .frame_buffer
+        .resize(processor.emulation_level, true);
+    processor.frame_buffer.track_intensities(true);
+    processor.memory.bytes.fill(0xFF);
+    processor.stack.bytes.fill(0xFFFF);
+    processor.stack.pointer = processor.stack.bytes.len();
+    processor.program_counter = 0xFFFF;
+    processor.index_register = 0xFFFF;
+    processor.variable_registers = [0xFF; VARIABLE_REGISTER_COUNT];
+    processor.rpl_registers = [0xFF; RPL_REGISTER_COUNT];
+    processor.cycles = usize::MAX;
+    processor.keys_pressed_since_wait = Key::ALL.iter().map(|key| *key as u8).collect();
+    processor.status = ProcessorStatus::WaitingForKeypress;
+    let json: String = serde_json::to_string(&processor.save_state()).unwrap();
+    assert!(json.len() <= bound);
+}
+
+#[test]
+fn test_load_state_file_inconsistent() {
+    const FILENAME: &str = "unit_test_load_state_inconsistent.json";
+    let state: SaveState = setup_test_processor_chip48().save_state();
+    let mut json: serde_json::Value = serde_json::to_value(&state).unwrap();
+    json["stack"]["pointer"] = serde_json::Value::from(MAX_STACK_DEPTH + 1);
+    std::fs::write(FILENAME, json.to_string()).unwrap();
+    let result: Result<SaveState, ErrorDetail> = SaveState::load_from_file(Path::new(FILENAME));
+    std::fs::remove_file(FILENAME).unwrap();
+    assert!(matches!(result, Err(ErrorDetail::FileError { .. })));
+}
+
+#[test]
+fn test_restore_state_after_crash() {
+    // Program is a single unknown instruction
+    let program: Program = Program::new(vec![0xFF, 0xFF]);
+    let mut processor: Processor =
+        Processor::initialise_and_load(program, Options::default()).unwrap();
+    let state: SaveState = processor.save_state();
+    assert!(processor.execute_cycle().is_err());
+    processor.restore_state(&state).unwrap();
+    assert!(
+        processor.status == ProcessorStatus::ProgramLoaded && processor.program_counter == 0x200
+    );
+}
+
+#[test]
+fn test_restore_state_leaves_pause_to_host() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // jump-to-self loop
+    processor.execute_cycle().unwrap();
+    let running_state: SaveState = processor.save_state();
+    processor.pause_execution().unwrap();
+    let paused_state: SaveState = processor.save_state();
+    processor.restore_state(&running_state).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Paused);
+    processor.resume_execution().unwrap();
+    processor.restore_state(&paused_state).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Running);
+}
+
+#[test]
+fn test_restore_state_incompatible() {
+    let state: SaveState = setup_test_processor_chip48().save_state();
+    let mut processor: Processor = setup_test_processor_superchip11();
+    assert_eq!(
+        processor.restore_state(&state).unwrap_err().inner_error,
+        ErrorDetail::IncompatibleSaveState {
+            saved: EmulationLevel::Chip48 {
+                variable_cycle_timing: false
+            },
+            current: processor.emulation_level,
+        }
+    );
+}
+
+#[test]
+fn test_input_recording() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // jump-to-self loop
+    processor.start_input_recording().unwrap();
+    processor.set_key_status(Key::Key4, true);
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    processor.set_key_status(Key::Key4, false);
+    let recording: InputRecording = processor.stop_input_recording().unwrap();
+    assert!(
+        recording.rng_seed == processor.rng_seed()
+            && recording.events
+                == vec![
+                    RecordedKeyEvent {
+                        cycle: 0,
+                        key: Key::Key4,
+                        pressed: true
+                    },
+                    RecordedKeyEvent {
+                        cycle: 2,
+                        key: Key::Key4,
+                        pressed: false
+                    }
+                ]
+    );
+}
+
+#[test]
+fn test_input_recording_after_start_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // jump-to-self loop
+    processor.execute_cycle().unwrap();
+    assert_eq!(
+        processor.start_input_recording().unwrap_err().inner_error,
+        ErrorDetail::InputRecordingError {
+            status: ProcessorStatus::Running
+        }
+    );
+}
+
+#[test]
+fn test_apply_input_events_press_and_release_within_frame() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    // FX0A (V3); loop
+    processor
+        .memory
+        .write_bytes(0x200, &[0xF3, 0x0A, 0x12, 0x02])
+        .unwrap();
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.apply_input_events(&[
+        KeyEvent {
+            key: Key::KeyB,
+            pressed: true,
+            cycle_offset: None,
+        },
+        KeyEvent {
+            key: Key::KeyB,
+            pressed: false,
+            cycle_offset: Some(1),
+        },
+    ]);
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.execute_cycle().unwrap();
+    assert!(
+        processor.status == ProcessorStatus::Running
+            && processor.variable_registers[0x3] == 0xB
+            && !processor.keystate.is_key_pressed(0xB).unwrap()
+            && processor.pending_key_events.is_empty()
+    );
+}
+
+#[test]
+fn test_idle_duration_waiting_for_keypress() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    // FX0A (V3); loop
+    processor
+        .memory
+        .write_bytes(0x200, &[0xF3, 0x0A, 0x12, 0x02])
+        .unwrap();
+    assert_eq!(processor.idle_duration(), None);
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    let duration: Duration = processor.idle_duration().unwrap();
+    assert!(duration > Duration::ZERO && duration <= processor.frame_duration());
+}
+
+#[test]
+fn test_idle_duration_none_with_unseen_input() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    // FX0A (V3); loop
+    processor
+        .memory
+        .write_bytes(0x200, &[0xF3, 0x0A, 0x12, 0x02])
+        .unwrap();
+    processor.execute_cycle().unwrap();
+    processor.set_key_status(Key::KeyB, true);
+    assert_eq!(processor.idle_duration(), None);
+    processor.execute_cycle().unwrap();
+    assert!(processor.idle_duration().is_some());
+    processor.apply_input_events(&[KeyEvent {
+        key: Key::KeyB,
+        pressed: false,
+        cycle_offset: Some(2),
+    }]);
+    assert_eq!(processor.idle_duration(), None);
+}
+
+#[test]
+fn test_apply_input_events_preserves_order() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // jump-to-self loop
+    processor.start_input_recording().unwrap();
+    processor.apply_input_events(&[
+        KeyEvent {
+            key: Key::Key1,
+            pressed: true,
+            cycle_offset: Some(2),
+        },
+        KeyEvent {
+            key: Key::Key2,
+            pressed: true,
+            cycle_offset: Some(1),
+        },
+        KeyEvent {
+            key: Key::Key1,
+            pressed: false,
+            cycle_offset: Some(2),
+        },
+    ]);
+    for _ in 0..3 {
+        processor.execute_cycle().unwrap();
+    }
+    let recording: InputRecording = processor.stop_input_recording().unwrap();
+    assert_eq!(
+        recording.events,
+        vec![
+            RecordedKeyEvent {
+                cycle: 1,
+                key: Key::Key2,
+                pressed: true
+            },
+            RecordedKeyEvent {
+                cycle: 2,
+                key: Key::Key1,
+                pressed: true
+            },
+            RecordedKeyEvent {
+                cycle: 2,
+                key: Key::Key1,
+                pressed: false
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_input_replay() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // jump-to-self loop
+    let mut recording: InputRecording = InputRecording::new(0x5EED);
+    recording.record_key_event(1, Key::Key7, true);
+    processor.start_input_replay(recording).unwrap();
+    processor.memory.write_bytes(0x200, &[0x12, 0x00]).unwrap(); // memory was rebuilt
+    processor.set_key_status(Key::Key2, true); // host input is ignored during replay
+    processor.execute_cycle().unwrap();
+    let key_pressed_after_first_cycle: bool = processor.keystate.is_key_pressed(0x7).unwrap();
+    processor.execute_cycle().unwrap();
+    assert!(
+        !key_pressed_after_first_cycle
+            && processor.keystate.is_key_pressed(0x7).unwrap()
+            && !processor.keystate.is_key_pressed(0x2).unwrap()
+            && !processor.input_replay_active()
+            && processor.rng_seed() == 0x5EED
+    );
+}
+
+#[test]
+fn test_input_replay_deterministic() {
+    // V0 = random byte; loop
+    let program_data: Vec<u8> = vec![0xC0, 0xFF, 0x12, 0x00];
+    let mut options: Options = Options::default();
+    options.processor_speed_hertz = 1_000_000;
+    let mut first: Processor =
+        Processor::initialise_and_load(Program::new(program_data.clone()), options).unwrap();
+    first.start_input_recording().unwrap();
+    let mut second: Processor =
+        Processor::initialise_and_load(Program::new(program_data), options).unwrap();
+    second
+        .start_input_replay(first.stop_input_recording().unwrap())
+        .unwrap();
+    for _ in 0..10 {
+        first.execute_cycle().unwrap();
+        second.execute_cycle().unwrap();
+        assert_eq!(
+            first.variable_registers[0x0],
+            second.variable_registers[0x0]
+        );
+    }
+    assert_eq!(first.memory, second.memory);
+}
+
+#[test]
+fn test_debug_set_state() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.pause_execution().unwrap();
+    processor.set_variable_register(0xA, 0x3C).unwrap();
+    processor.set_index_register(0x0F12).unwrap();
+    processor.set_program_counter(0x0246).unwrap();
+    processor.set_delay_timer(0x21).unwrap();
+    processor.set_sound_timer(0x12).unwrap();
+    processor.write_memory_byte(0x0300, 0xE7).unwrap();
+    assert!(
+        processor.variable_registers[0xA] == 0x3C
+            && processor.index_register == 0x0F12
+            && processor.program_counter == 0x0246
+            && processor.delay_timer == 0x21
+            && processor.sound_timer == 0x12
+            && processor.memory.bytes[0x0300] == 0xE7
+    );
+}
+
+#[test]
+fn test_debug_set_state_not_paused_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.set_index_register(0x0F12).unwrap_err(),
+        ErrorDetail::ProcessorNotPaused {
+            status: ProcessorStatus::ProgramLoaded
+        }
+    );
+    // A misused debugger call does not crash the program
+    assert_eq!(processor.status, ProcessorStatus::ProgramLoaded);
+}
+
+#[test]
+fn test_debug_set_variable_register_invalid_register_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.pause_execution().unwrap();
+    let operands: Vec<Operand> = vec![Operand::Register(0x10)];
+    assert_eq!(
+        processor.set_variable_register(0x10, 0x3C).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands }
+    );
+    assert_eq!(processor.status, ProcessorStatus::Paused);
+}
+
+#[test]
+fn test_debug_write_memory_byte_out_of_bounds_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.pause_execution().unwrap();
+    let address: usize = processor.memory.max_addressable_size();
+    assert_eq!(
+        processor.write_memory_byte(address, 0xE7).unwrap_err(),
+        ErrorDetail::MemoryAddressOutOfBounds {
+            address: address as u16
+        }
+    );
+}
+
+#[test]
+fn test_check_sound_timer() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.sound_timer = 0;
+    let result_one: bool = processor.sound_timer_active();
+    processor.sound_timer = 1;
+    let result_two: bool = processor.sound_timer_active();
+    assert!(!result_one && result_two);
+}
+
+#[test]
+fn test_decrement_timers() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.delay_timer = 0x1B;
+    processor.sound_timer = 0xEC;
+    let duration: Duration =
+        Duration::from_micros(100 + processor.timer_interval_microseconds as u64);
+    let last_time: Instant = processor.now() - duration;
+    processor.last_timer_decrement = last_time;
+    processor.decrement_timers();
+    assert!(
+        processor.delay_timer == 0x1A
+            && processor.sound_timer == 0xEB
+            && processor
+                .last_timer_decrement
+                .saturating_duration_since(last_time)
+                .as_nanos()
+                > 0
+    );
+}
+
+#[test]
+fn test_decrement_timers_too_early() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.delay_timer = 0x1B;
+    processor.sound_timer = 0xEC;
+    let last_time: Instant = processor.now();
+    processor.last_timer_decrement = last_time;
+    processor.decrement_timers();
+    assert!(
+        processor.delay_timer == 0x1B
+            && processor.sound_timer == 0xEC
+            && processor
+                .last_timer_decrement
+                .saturating_duration_since(last_time)
+                .as_nanos()
+                == 0
+    );
+}
+
+#[test]
+fn test_decrement_timers_stopped() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.delay_timer = 0x00;
+    processor.sound_timer = 0x00;
+    let duration: Duration =
+        Duration::from_micros(100 + processor.timer_interval_microseconds as u64);
+    let last_time: Instant = processor.now() - duration;
+    processor.last_timer_decrement = last_time;
+    processor.decrement_timers();
+    assert!(processor.delay_timer == 0x0 && processor.sound_timer == 0x0);
+}
+
+#[test]
+fn test_decrement_timers_phase_emulation_idle() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.timer_phase_emulation = true;
+    let duration: Duration =
+        Duration::from_micros(100 + processor.timer_interval_microseconds as u64);
+    let last_time: Instant = processor.now() - duration;
+    processor.last_timer_decrement = last_time;
+    processor.decrement_timers();
+    // The tick occurs although the timers are idle, exactly one interval after the last
+    assert!(
+        processor.delay_timer == 0x0
+            && processor.last_timer_decrement == last_time + processor.frame_duration()
+    );
+}
+
+#[test]
+fn test_decrement_timers_phase_emulation_resynchronises() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.timer_phase_emulation = true;
+    processor.delay_timer = 0x1B;
+    let last_time: Instant = processor.now() - processor.frame_duration() * 3;
+    processor.last_timer_decrement = last_time;
+    processor.decrement_timers();
+    assert!(
+        processor.delay_timer == 0x1A
+            && processor.last_timer_decrement > last_time + processor.frame_duration() * 2
+    );
+}
+
+#[test]
+fn test_timer_phase() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.last_timer_decrement = processor.now() - processor.frame_duration() / 2;
+    let wall_clock_phase: f64 = processor.timer_phase();
+    processor.last_timer_decrement = processor.now() - processor.frame_duration() * 2;
+    let overdue_phase: f64 = processor.timer_phase();
+    processor.cycle_based_timing = true;
+    processor.processor_speed_hertz = 1_000;
+    processor.timer_interval_microseconds = 10_000; // i.e. 10 cycles per interval
+    processor.cycles = 25;
+    assert!(
+        (0.5..0.6).contains(&wall_clock_phase)
+            && overdue_phase < 1.0
+            && processor.timer_phase() == 0.5
+    );
+}
+
+#[test]
+fn test_decrement_vblankinterrupt() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.vblank_status = VBlankStatus::WaitingForVBlank;
+    let mut duration: Duration =
+        Duration::from_micros(processor.timer_interval_microseconds as u64 - 100);
+    let mut last_time: Instant = processor.now() - duration;
+    processor.last_vblank_interrupt = last_time;
+    processor.decrement_timers();
+    assert_eq!(processor.vblank_status, VBlankStatus::WaitingForVBlank);
+    duration = Duration::from_micros(processor.timer_interval_microseconds as u64 + 100);
+    last_time = processor.now() - duration;
+    processor.last_vblank_interrupt = last_time;
+    processor.decrement_timers();
+    assert_eq!(processor.vblank_status, VBlankStatus::ReadyToDraw);
+}
+
+#[test]
+fn test_decrement_vblankinterrupt_superchip11_lowres_display_wait() {
+    let mut processor: Processor =
+        setup_test_processor_lowres_display_wait(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+    processor.vblank_status = VBlankStatus::WaitingForVBlank;
+    processor.last_vblank_interrupt =
+        processor.now() - Duration::from_micros(processor.timer_interval_microseconds as u64 + 100);
+    processor.decrement_timers();
+    assert_eq!(processor.vblank_status, VBlankStatus::ReadyToDraw);
+}
+
+#[test]
+fn test_timer_frequency() {
+    let mut options: Options = Options::default();
+    options.timer_frequency_hertz = 50;
+    let processor: Processor = Processor::initialise_and_load(Program::default(), options).unwrap();
+    assert_eq!(processor.timer_interval_microseconds, 20000);
+}
+
+#[test]
+fn test_timer_frequency_invalid() {
+    let mut options: Options = Options::default();
+    options.timer_frequency_hertz = 0;
+    let operands: Vec<Operand> = vec![Operand::TimerFrequencyHertz(0)];
+    assert_eq!(
+        Processor::initialise_and_load(Program::default(), options)
+            .err()
+            .unwrap()
+            .inner_error,
+        ErrorDetail::OperandsOutOfBounds { operands }
+    );
+}
+
+#[test]
+fn test_decrement_timers_cycle_synced() {
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    options.processor_speed_hertz = 1000;
+    options.timer_frequency_hertz = 50;
+    options.cycle_synced_timers = true;
+    let mut processor: Processor =
+        Processor::initialise_and_load(Program::default(), options).unwrap();
+    processor.delay_timer = 0x10;
+    // At 1000Hz and 50Hz timers, the timers are decremented once every 20 cycles regardless of
+    // wall-clock time
+    processor.last_timer_decrement = processor.now() - Duration::from_secs(1);
+    processor.cycles = 19;
+    processor.decrement_timers();
+    assert_eq!(processor.delay_timer, 0x10);
+    processor.last_timer_decrement = processor.now();
+    processor.cycles = 20;
+    processor.decrement_timers();
+    assert_eq!(processor.delay_timer, 0x0F);
+}
+
+#[test]
+fn test_execute_004B() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_004B().unwrap_err(),
+        ErrorDetail::UnimplementedInstruction { opcode: 0x4B }
+    );
+}
+
+#[test]
+fn test_execute_00CN_superchip11() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    // Set the first byte of the first row to be 11111111 (i.e. 0xFF) and the first byte of the
+    // 10th row to be 00000000 (i.e. 0x00)
+    processor.frame_buffer[0][0] = 0xFF;
+    processor.frame_buffer[9][0] = 0x00;
+    // When scrolled down by 9 pixels, this first byte of the 10th row should become 111111111 (i.e. 0xFF)
+    assert!(
+        processor.frame_buffer.scroll_display_down(9).is_ok()
+            && processor.frame_buffer[9][0] == 0xFF
+    );
+}
+
+#[test]
+fn test_execute_00CN_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_00CN(2).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00C2 }
+    );
+}
+
+#[test]
+fn test_execute_00CN_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_00CN(2).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00C2 }
+    );
+}
+
+#[test]
+fn test_execute_00E0() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    // Set every pixel to 1
+    for column in 0..processor.frame_buffer.get_row_size_bytes() {
+        for row in 0..processor.frame_buffer.get_column_size_pixels() {
+            processor.frame_buffer[row][column] = 0xFF;
+        }
+    }
+    // Now execute the instruction to clear the display
+    processor.execute_00E0().unwrap();
+    // Now check that every pixel is 0
+    let mut pixel_is_set: bool = false;
+    'outer: for column in 0..processor.frame_buffer.get_row_size_bytes() {
+        for row in 0..processor.frame_buffer.get_column_size_pixels() {
+            if processor.frame_buffer[row][column] > 0x00 {
+                pixel_is_set = true;
+                break 'outer;
+            }
+        }
+    }
+    assert!(!pixel_is_set);
+}
+
+#[test]
+fn test_execute_00EE() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.stack.push(0xB35E).unwrap();
+    assert!(
+        processor.execute_00EE().is_ok()
+            && processor.stack.pop().is_err()
+            && processor.program_counter == 0xB35E
+    );
+}
+
+#[test]
+fn test_execute_00EE_empty_stack_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_00EE().unwrap_err(),
+        ErrorDetail::PopEmptyStack
+    );
+}
+
+#[test]
+fn test_execute_00FB_superchip11() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let row_size: usize = processor.frame_buffer.get_row_size_bytes();
+    // Set the last byte of the first row to be 11110000 (i.e. 0xF0)
+    processor.frame_buffer[0][row_size - 1] = 0xF0;
+    // When scrolled right by 4 pixels, this last byte should become 00001111 (i.e. 0x0F)
+    assert!(
+        processor.frame_buffer.scroll_display_right().is_ok()
+            && processor.frame_buffer[0][row_size - 1] == 0x0F
+    );
+}
+
+#[test]
+fn test_execute_00FB_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_00FB().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FB }
+    );
+}
+
+#[test]
+fn test_execute_00FB_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_00FB().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FB }
+    );
+}
+
+#[test]
+fn test_execute_00FB_superchip10_error() {
+    let mut processor: Processor = setup_test_processor_superchip10();
+    assert_eq!(
+        processor.execute_00FB().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FB }
+    );
+}
+
+#[test]
+fn test_execute_00CN_superchip10_error() {
+    let mut processor: Processor = setup_test_processor_superchip10();
+    assert_eq!(
+        processor.execute_00CN(0x4).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00C4 }
+    );
+}
+
+#[test]
+fn test_execute_00FF_superchip10() {
+    let mut processor: Processor = setup_test_processor_superchip10();
+    assert!(processor.execute_00FF().is_ok() && processor.high_resolution_mode);
+}
+
+#[test]
+fn test_execute_00FC_superchip11() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    // Set the first byte of the first row to be 00001111 (i.e. 0x0F)
+    processor.frame_buffer[0][0] = 0x0F;
+    // When scrolled left by 4 pixels, this first byte should become 11110000 (i.e. 0xF0)
+    assert!(
+        processor.frame_buffer.scroll_display_left().is_ok()
+            && processor.frame_buffer[0][0] == 0xF0
+    );
+}
+
+#[test]
+fn test_execute_00FC_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_00FC().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FC }
+    );
+}
+
+#[test]
+fn test_execute_00FC_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_00FC().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FC }
+    );
+}
+
+#[test]
+fn test_execute_00FD() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    assert!(processor.execute_00FD().is_ok() && processor.status == ProcessorStatus::Completed);
+}
+
+#[test]
+fn test_execute_00FD_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_00FD().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FD }
+    );
+}
+
+#[test]
+fn test_execute_00FD_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_00FD().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FD }
+    );
+}
+
+#[test]
+fn test_execute_00FE() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.high_resolution_mode = true;
+    processor.frame_buffer[0][0] = 0xFF; // set some pixels
+    assert!(
+        processor.execute_00FE().is_ok()
+            && processor.high_resolution_mode == false
+            && processor.frame_buffer[0][0] == 0xFF // check the pixels are intact
+    );
+}
+
+#[test]
+fn test_execute_00FE_octo() {
+    let mut processor: Processor = setup_test_processor_superchip11_octo();
+    processor.high_resolution_mode = true;
+    processor.frame_buffer[0][0] = 0xFF; // set some pixels
+    assert!(
+        processor.execute_00FE().is_ok()
+            && processor.high_resolution_mode == false
+            && processor.frame_buffer[0][0] == 0x0 // check the pixels are reset
+    );
+}
+
+#[test]
+fn test_execute_00FE_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_00FE().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FE }
+    );
+}
+
+#[test]
+fn test_execute_00FE_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_00FE().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FE }
+    );
+}
+
+#[test]
+fn test_execute_00FF() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.high_resolution_mode = false;
+    processor.frame_buffer[0][0] = 0xFF; // set some pixels
+    assert!(
+        processor.execute_00FF().is_ok()
+            && processor.high_resolution_mode == true
+            && processor.frame_buffer[0][0] == 0xFF // check the pixels are intact
+    );
+}
+
+#[test]
+fn test_execute_00FF_octo() {
+    let mut processor: Processor = setup_test_processor_superchip11_octo();
+    processor.high_resolution_mode = false;
+    processor.frame_buffer[0][0] = 0xFF; // set some pixels
+    assert!(
+        processor.execute_00FF().is_ok()
+            && processor.high_resolution_mode == true
+            && processor.frame_buffer[0][0] == 0x0 // check the pixels are reset
+    );
+}
+
+#[test]
+fn test_execute_00FF_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_00FF().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FF }
+    );
+}
+
+#[test]
+fn test_execute_00FF_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_00FF().unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0x00FF }
+    );
+}
+
+fn setup_test_processor_superchip11_resize_low_res() -> Processor {
+    let program: Program = Program::default();
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::SuperChip11 {
+        octo_compatibility_mode: false,
+        variable_cycle_timing: false,
+    };
+    options.resize_low_resolution_display = true;
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+#[test]
+fn test_execute_00FF_00FE_resize_low_res() {
+    let mut processor: Processor = setup_test_processor_superchip11_resize_low_res();
+    assert_eq!(processor.frame_buffer.get_resolution(), (64, 32));
+    processor.frame_buffer[0][0] = 0x80; // set the top-left pixel
+    processor.execute_00FF().unwrap();
+    assert!(
+        processor.high_resolution_mode
+            && processor.frame_buffer.get_resolution() == (128, 64)
+            && processor.frame_buffer[0][0] == 0xC0 // the pixel is scaled up to 2x2
+            && processor.frame_buffer[1][0] == 0xC0
+    );
+    processor.execute_00FE().unwrap();
+    assert!(
+        !processor.high_resolution_mode
+            && processor.frame_buffer.get_resolution() == (64, 32)
+            && processor.frame_buffer[0][0] == 0x80
+    );
+}
+
+#[test]
+fn test_execute_DXYN_superchip11_resize_low_res() {
+    let mut processor: Processor = setup_test_processor_superchip11_resize_low_res();
+    processor.index_register = processor.font_start_address as u16;
+    processor
+        .memory
+        .write_bytes(processor.font_start_address, &[0x80])
+        .unwrap();
+    processor.variable_registers[0x3] = 0x1;
+    processor.variable_registers[0xA] = 0x1;
+    // The sprite is drawn at its natural size on the resized display
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert!(
+        processor.variable_registers[0xF] == 0x0
+            && processor.frame_buffer[0][0] == 0x00
+            && processor.frame_buffer[1][0] == 0x40
+            && processor.frame_buffer[2][0] == 0x00
+    );
+}
+
+#[test]
+fn test_set_high_resolution_mode() {
+    let mut processor: Processor = setup_test_processor_superchip11_resize_low_res();
+    processor.set_high_resolution_mode(true).unwrap();
+    assert!(
+        processor.high_resolution_mode
+            && processor
+                .export_state_snapshot(StateSnapshotVerbosity::Minimal)
+                .resolution()
+                == (128, 64)
+    );
+}
+
+#[test]
+fn test_set_high_resolution_mode_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor
+            .set_high_resolution_mode(true)
+            .unwrap_err()
+            .inner_error,
+        ErrorDetail::UnknownInstruction { opcode: 0x00FF }
+    );
+}
+
+#[test]
+fn test_execute_0NNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_0NNN(0x2F5).unwrap_err(),
+        ErrorDetail::UnimplementedInstruction { opcode: 0x02F5 }
+    );
+}
+
+#[test]
+fn test_execute_0NNN_chip8_hires_clear_screen() {
+    let mut processor: Processor = setup_test_processor_chip8_hires(vec![0x12, 0x60]);
+    processor.frame_buffer[63][7] = 0x01;
+    assert!(
+        processor.execute_0NNN(0x230).is_ok()
+            && processor
+                .frame_buffer
+                .get_colour_indices()
+                .iter()
+                .all(|&pixel| pixel == 0)
+    );
+}
+
+/// Test handler that sets VF to the low byte of the routine address, and rejects address 0x000
+struct TestSystemRoutineHandler;
+
+impl SystemRoutineHandler for TestSystemRoutineHandler {
+    fn call_routine(
+        &mut self,
+        address: u16,
+        context: &mut SystemRoutineContext,
+    ) -> Result<u64, ErrorDetail> {
+        if address == 0x000 {
+            return Err(ErrorDetail::UnimplementedInstruction { opcode: address });
+        }
+        context.variable_registers[0xF] = (address & 0xFF) as u8;
+        context.write_memory_byte(0x300, 0xAB)?;
+        Ok(crate::DEFAULT_SYSTEM_ROUTINE_CYCLES)
+    }
+}
+
+#[test]
+fn test_execute_0NNN_with_handler() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.set_system_routine_handler(TestSystemRoutineHandler);
+    assert!(
+        processor.execute_0NNN(0x2F5).unwrap() == crate::DEFAULT_SYSTEM_ROUTINE_CYCLES
+            && processor.variable_registers[0xF] == 0xF5
+            && processor.memory.read_byte(0x300).unwrap() == 0xAB
+            && processor.system_routine_handler.is_some()
+    );
+}
+
+#[test]
+fn test_execute_0NNN_with_handler_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.set_system_routine_handler(TestSystemRoutineHandler);
+    assert_eq!(
+        processor.execute_0NNN(0x000).unwrap_err(),
+        ErrorDetail::UnimplementedInstruction { opcode: 0x000 }
+    );
+}
+
+#[test]
+fn test_clear_system_routine_handler() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.set_system_routine_handler(TestSystemRoutineHandler);
+    processor.clear_system_routine_handler();
+    assert_eq!(
+        processor.execute_0NNN(0x2F5).unwrap_err(),
+        ErrorDetail::UnimplementedInstruction { opcode: 0x02F5 }
+    );
+}
+
+#[test]
+fn test_execute_1NNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert!(processor.execute_1NNN(0xEA5).is_ok() && processor.program_counter == 0xEA5);
+}
+
+#[test]
+fn test_execute_2NNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0xF03;
+    assert!(
+        processor.execute_2NNN(0x44F).is_ok()
+            && processor.stack.pop().unwrap() == 0xF03
+            && processor.program_counter == 0x44F
+    );
+}
+
+#[test]
+fn test_stack_event_recording() {
+    // Calls a subroutine at 0x206, which calls one at 0x20A, then both return
+    let program: Program = Program::new(vec![
+        0x22, 0x06, 0x00, 0x00, 0x00, 0x00, 0x22, 0x0A, 0x00, 0xEE, 0x00, 0xEE,
+    ]);
+    let mut processor: Processor = Processor::initialise_and_load(
+        program,
+        Options::new(
+            1000,
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+        ),
+    )
+    .unwrap();
+    processor.set_stack_event_recording(true);
+    processor.turbo_mode = true;
+    for _ in 0..4 {
+        processor.execute_cycle().unwrap();
+    }
+    let events: Vec<StackEvent> = processor.take_stack_events();
+    assert!(
+        events.len() == 4
+            && events[1]
+                == StackEvent::Call {
+                    target: 0x20A,
+                    return_address: 0x208,
+                    cycle: 2,
+                    depth: 2
+                }
+            && processor.stack.max_depth() == 2
+            && processor.stack.depth() == 0
+    );
+    let tree: CallTreeNode = processor.call_tree().unwrap();
+    assert!(
+        tree.children.len() == 1
+            && tree.children[0].address == Some(0x206)
+            && tree.children[0].children[0].address == Some(0x20A)
+    );
+    processor.reset().unwrap();
+    assert!(
+        processor.stack.is_recording_events() && processor.call_tree().unwrap().children.is_empty()
+    );
+}
+
+#[test]
+fn test_execute_3XNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBB;
+    assert!(processor.execute_3XNN(0x3, 0xBB).is_ok() && processor.program_counter == 0x15);
+}
+
+#[test]
+fn test_execute_3XNN_no_action() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBA;
+    assert!(processor.execute_3XNN(0x3, 0xBB).is_ok() && processor.program_counter == 0x13);
+}
+
+#[test]
+fn test_execute_3XNN_invalid_register_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_3XNN(0x10, 0x2F).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_4XNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBB;
+    assert!(processor.execute_4XNN(0x3, 0xBB).is_ok() && processor.program_counter == 0x13);
+}
+
+#[test]
+fn test_execute_4XNN_no_action() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBA;
+    assert!(processor.execute_4XNN(0x3, 0xBB).is_ok() && processor.program_counter == 0x15);
+}
+
+#[test]
+fn test_execute_4XNN_invalid_register_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_4XNN(0x10, 0x2F).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_5XY0() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBA;
+    processor.variable_registers[0xD] = 0xBA;
+    assert!(processor.execute_5XY0(0x3, 0xD).is_ok() && processor.program_counter == 0x15);
+}
+
+#[test]
+fn test_execute_5XY0_no_action() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBA;
+    processor.variable_registers[0xD] = 0xBB;
+    assert!(processor.execute_5XY0(0x3, 0xD).is_ok() && processor.program_counter == 0x13);
+}
+
+#[test]
+fn test_execute_5XY0_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_5XY0(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_5XY0_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_5XY0(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_6XNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert!(processor.execute_6XNN(0xB, 0x2F).is_ok() && processor.variable_registers[0xB] == 0x2F);
+}
+
+#[test]
+fn test_execute_6XNN_invalid_register_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_6XNN(0x10, 0x2F).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_7XNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x9] = 0x3;
+    assert!(processor.execute_7XNN(0x9, 0xE0).is_ok() && processor.variable_registers[0x9] == 0xE3);
+}
+
+#[test]
+fn test_execute_7XNN_overflow() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x1] = 0xFF;
+    assert!(processor.execute_7XNN(0x1, 0x05).is_ok() && processor.variable_registers[0x1] == 0x04);
+}
+
+#[test]
+fn test_execute_7XNN_invalid_register_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_7XNN(0x10, 0x1E).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY0() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x01;
+    processor.variable_registers[0x7] = 0x51;
+    assert!(processor.execute_8XY0(0xE, 0x7).is_ok() && processor.variable_registers[0xE] == 0x51);
+}
+
+#[test]
+fn test_execute_8XY0_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XY0(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY0_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY0(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY1() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x9B;
+    processor.variable_registers[0x7] = 0xAA;
+    assert!(processor.execute_8XY1(0xE, 0x7).is_ok() && processor.variable_registers[0xE] == 0xBB);
+}
+
+#[test]
+fn test_execute_8XY1_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XY1(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY1_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY1(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY2() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x9B;
+    processor.variable_registers[0x7] = 0xAA;
+    assert!(processor.execute_8XY2(0xE, 0x7).is_ok() && processor.variable_registers[0xE] == 0x8A);
+}
+
+#[test]
+fn test_execute_8XY2_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XY2(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY2_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY2(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY3() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x9B;
+    processor.variable_registers[0x7] = 0xAA;
+    assert!(processor.execute_8XY3(0xE, 0x7).is_ok() && processor.variable_registers[0xE] == 0x31);
+}
+
+#[test]
+fn test_execute_8XY3_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY3(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY3_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY3(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY4() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0xF2;
+    processor.variable_registers[0x7] = 0x06;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY4(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0xF8
+            && processor.variable_registers[0xF] == 0x00
+    );
+}
+
+#[test]
+fn test_execute_8XY4_overflow() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0xF2;
+    processor.variable_registers[0x7] = 0x16;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY4(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x08
+            && processor.variable_registers[0xF] == 0x01
+    );
+}
+
+#[test]
+fn test_execute_8XY4_flag_order() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xF] = 0xF2;
+    processor.variable_registers[0x7] = 0x16;
+    assert!(processor.execute_8XY4(0xF, 0x7).is_ok() && processor.variable_registers[0xF] == 0x01);
+}
+
+#[test]
+fn test_execute_8XY4_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XY4(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY4_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY4(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY5() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0xF2;
+    processor.variable_registers[0x7] = 0x06;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY5(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0xEC
+            && processor.variable_registers[0xF] == 0x01
+    );
+}
+
+#[test]
+fn test_execute_8XY5_underflow() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x16;
+    processor.variable_registers[0x7] = 0xF2;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY5(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x24
+            && processor.variable_registers[0xF] == 0x00
+    );
+}
+
+#[test]
+fn test_execute_8XY5_flag_order() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xF] = 0xF2;
+    processor.variable_registers[0x7] = 0x16;
+    assert!(processor.execute_8XY5(0xF, 0x7).is_ok() && processor.variable_registers[0xF] == 0x01);
+}
+
+#[test]
+fn test_execute_8XY5_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XY5(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY5_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY5(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY6_1_shifted() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x06;
+    processor.variable_registers[0x7] = 0xD9;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY6(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x6C
+            && processor.variable_registers[0xF] == 0x01
+    );
+}
+
+#[test]
+fn test_execute_8XY6_0_shifted() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x06;
+    processor.variable_registers[0x7] = 0x62;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY6(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x31
+            && processor.variable_registers[0xF] == 0x00
+    );
+}
+
+#[test]
+fn test_execute_8XY6_1_shifted_chip48_mode() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.variable_registers[0xE] = 0xD9;
+    processor.variable_registers[0x7] = 0x06;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY6(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x6C
+            && processor.variable_registers[0xF] == 0x01
+    );
+}
+
+#[test]
+fn test_execute_8XY6_0_shifted_chip48_mode() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.variable_registers[0xE] = 0x62;
+    processor.variable_registers[0x7] = 0x06;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY6(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x31
+            && processor.variable_registers[0xF] == 0x00
+    );
+}
+
+#[test]
+fn test_execute_8XY6_1_shifted_superchip11_mode() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.variable_registers[0xE] = 0xD9;
+    processor.variable_registers[0x7] = 0x06;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY6(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x6C
+            && processor.variable_registers[0xF] == 0x01
+    );
+}
+
+#[test]
+fn test_execute_8XY6_0_shifted_superchip11_mode() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.variable_registers[0xE] = 0x62;
+    processor.variable_registers[0x7] = 0x06;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY6(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x31
+            && processor.variable_registers[0xF] == 0x00
+    );
+}
+
+#[test]
+fn test_execute_8XY6_flag_order() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xF] = 0x44;
+    processor.variable_registers[0x7] = 0xFF;
+    assert!(processor.execute_8XY6(0xF, 0x7).is_ok() && processor.variable_registers[0xF] == 0x01);
+}
+
+#[test]
+fn test_execute_8XY6_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XY6(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY6_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY6(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY7() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x06;
+    processor.variable_registers[0x7] = 0xF2;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY7(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0xEC
+            && processor.variable_registers[0xF] == 0x01
+    );
+}
+
+#[test]
+fn test_execute_8XY7_underflow() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0xF2;
+    processor.variable_registers[0x7] = 0x16;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XY7(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x24
+            && processor.variable_registers[0xF] == 0x00
+    );
+}
+
+#[test]
+fn test_execute_8XY7_flag_order() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xF] = 0x44;
+    processor.variable_registers[0x7] = 0xFF;
+    assert!(processor.execute_8XY7(0xF, 0x7).is_ok() && processor.variable_registers[0xF] == 0x01);
+}
+
+#[test]
+fn test_execute_8XY7_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XY7(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XY7_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XY7(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XYE_1_shifted() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x06;
+    processor.variable_registers[0x7] = 0x9B;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XYE(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0x36
+            && processor.variable_registers[0xF] == 0x01
+    );
+}
+
+#[test]
+fn test_execute_8XYE_0_shifted() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xE] = 0x06;
+    processor.variable_registers[0x7] = 0x62;
+    processor.variable_registers[0xF] = 0x44;
+    assert!(
+        processor.execute_8XYE(0xE, 0x7).is_ok()
+            && processor.variable_registers[0xE] == 0xC4
+            && processor.variable_registers[0xF] == 0x00
+    );
+}
+
+#[test]
+fn test_execute_8XYE_flag_order() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0xF] = 0x44;
+    processor.variable_registers[0x7] = 0xFF;
+    assert!(processor.execute_8XYE(0xF, 0x7).is_ok() && processor.variable_registers[0xF] == 0x01);
+}
+
+#[test]
+fn test_execute_8XYE_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_8XYE(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_8XYE_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_8XYE(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_9XY0() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBA;
+    processor.variable_registers[0xD] = 0xBB;
+    assert!(processor.execute_9XY0(0x3, 0xD).is_ok() && processor.program_counter == 0x15);
+}
+
+#[test]
+fn test_execute_9XY0_no_action() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x3] = 0xBA;
+    processor.variable_registers[0xD] = 0xBA;
+    assert!(processor.execute_9XY0(0x3, 0xD).is_ok() && processor.program_counter == 0x13);
+}
+
+#[test]
+fn test_execute_9XY0_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0xD)];
+    assert_eq!(
+        processor.execute_9XY0(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_9XY0_invalid_register_y_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x3), Operand::Y(0x10)];
+    assert_eq!(
+        processor.execute_9XY0(0x3, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_ANNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert!(processor.execute_ANNN(0x0A5).is_ok() && processor.index_register == 0x0A5);
+}
+
+#[test]
+fn test_execute_BNNN() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0] = 0x42;
+    processor.variable_registers[3] = 0x1B;
+    assert!(processor.execute_BNNN(0x3A5).is_ok() && processor.program_counter == 0x3E7);
+}
+
+#[test]
+fn test_execute_BNNN_chip48_mode() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0] = 0x42;
+    processor.variable_registers[3] = 0x1B;
+    assert!(processor.execute_BNNN(0x3A5).is_ok() && processor.program_counter == 0x3C0);
+}
+
+#[test]
+fn test_execute_BNNN_superchip11_mode() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0] = 0x42;
+    processor.variable_registers[3] = 0x1B;
+    assert!(processor.execute_BNNN(0x3A5).is_ok() && processor.program_counter == 0x3C0);
+}
+
+#[test]
+fn test_execute_BXY0_chip8x_mode() {
+    let mut processor: Processor = setup_test_processor_chip8x();
+    processor.program_counter = 0x13;
+    // Two blocks across from column 1, one block down from row 2, in yellow
+    processor.variable_registers[3] = 0x11;
+    processor.variable_registers[4] = 0x02;
+    processor.variable_registers[5] = 0x05;
+    assert!(
+        processor.execute_BNNN(0x350).is_ok()
+            && processor.program_counter == 0x13
+            && processor.frame_buffer.get_zone_colour(8, 8) == Some(0x5)
+            && processor.frame_buffer.get_zone_colour(23, 11) == Some(0x5)
+            && processor.frame_buffer.get_zone_colour(24, 8) == Some(0x1)
+            && processor.frame_buffer.get_zone_colour(8, 12) == Some(0x1)
+    );
+}
+
+#[test]
+fn test_execute_BXYN_chip8x_mode() {
+    let mut processor: Processor = setup_test_processor_chip8x();
+    processor.variable_registers[3] = 0x11;
+    processor.variable_registers[4] = 0x06;
+    processor.variable_registers[5] = 0x06;
+    assert!(
+        processor.execute_BNNN(0x352).is_ok()
+            && processor.frame_buffer.get_zone_colour(16, 5) == Some(0x1)
+            && processor.frame_buffer.get_zone_colour(16, 6) == Some(0x6)
+            && processor.frame_buffer.get_zone_colour(23, 7) == Some(0x6)
+            && processor.frame_buffer.get_zone_colour(24, 7) == Some(0x1)
+            && processor.frame_buffer.get_zone_colour(16, 8) == Some(0x1)
+    );
+}
+
+#[test]
+fn test_execute_BXYN_chip8x_mode_operands_out_of_bounds() {
+    let mut processor: Processor = setup_test_processor_chip8x();
+    assert!(matches!(
+        processor.execute_BNNN(0xF00).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { .. }
+    ));
+}
+
+#[test]
+fn test_execute_02A0_chip8x_mode() {
+    let mut processor: Processor = setup_test_processor_chip8x();
+    assert!(
+        processor.execute_02A0().is_ok()
+            && processor.frame_buffer.get_background_colour() == Some(0x0)
+    );
+}
+
+#[test]
+fn test_execute_02A0_chip8_mode_calls_routine() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_02A0().unwrap_err(),
+        ErrorDetail::UnimplementedInstruction { opcode: 0x2A0 }
+    );
+}
+
+#[test]
+fn test_execute_5XY1_chip8x_mode() {
+    let mut processor: Processor = setup_test_processor_chip8x();
+    processor.variable_registers[0x1] = 0x63;
+    processor.variable_registers[0x2] = 0x36;
+    assert!(processor.execute_5XY1(0x1, 0x2).is_ok() && processor.variable_registers[0x1] == 0x11);
+}
+
+#[test]
+fn test_execute_5XY1_chip8_mode_compares() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x200;
+    processor.variable_registers[0x1] = 0x63;
+    processor.variable_registers[0x2] = 0x63;
+    assert!(processor.execute_5XY1(0x1, 0x2).is_ok() && processor.program_counter == 0x202);
+}
+
+#[test]
+fn test_execute_EXF2_EXF5_chip8x_mode() {
+    let mut processor: Processor = setup_test_processor_chip8x();
+    processor.program_counter = 0x300;
+    processor.variable_registers[0x4] = 0xA;
+    processor.set_second_key_status(Key::KeyA, true);
+    processor.execute_EXF2(0x4).unwrap();
+    processor.execute_EXF5(0x4).unwrap();
+    // The first keypad is unaffected
+    processor.execute_EX9E(0x4).unwrap();
+    assert_eq!(processor.program_counter, 0x302);
+}
+
+#[test]
+fn test_execute_EXF2_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_EXF2(0x4).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0xE4F2 }
+    );
+}
+
+#[test]
+fn test_execute_FXF8_chip8x_mode() {
+    struct PitchSink(Arc<Mutex<Vec<u8>>>);
+    impl SoundSink for PitchSink {
+        fn start_beep(&mut self, _cycle: usize) {}
+        fn stop_beep(&mut self, _cycle: usize) {}
+        fn set_pitch(&mut self, pitch: u8) {
+            self.0.lock().unwrap().push(pitch);
+        }
+    }
+    let mut processor: Processor = setup_test_processor_chip8x();
+    let pitches: Arc<Mutex<Vec<u8>>> = Arc::new(Mutex::new(Vec::new()));
+    processor.set_sound_sink(PitchSink(Arc::clone(&pitches)));
+    processor.variable_registers[0x2] = 0x80;
+    assert!(processor.execute_FXF8(0x2).is_ok() && *pitches.lock().unwrap() == vec![0x80]);
+}
+
+#[test]
+fn test_chip8x_program_start_address() {
+    let processor: Processor = setup_test_processor_chip8x();
+    assert_eq!(processor.program_counter, 0x300);
+}
+
+#[test]
+fn test_execute_CXNN_0_operand() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x5] = 0xF;
+    assert!(processor.execute_CXNN(0x5, 0x0).is_ok() && processor.variable_registers[0x5] == 0x0);
+}
+
+#[test]
+fn test_execute_CXNN_0_nondeterministic() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x5] = 0xF;
+    processor.execute_CXNN(0x5, 0xFF).unwrap();
+    let result_one: u8 = processor.variable_registers[0x5];
+    processor.execute_CXNN(0x5, 0xFF).unwrap();
+    let result_two: u8 = processor.variable_registers[0x5];
+    assert_ne!(result_one, result_two);
+}
+
+#[test]
+fn test_execute_CXNN_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_CXNN(0x10, 0xD).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+fn setup_test_processor_vip_random_generator() -> Processor {
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    };
+    options.vip_random_generator = true;
+    let mut processor: Processor =
+        Processor::initialise_and_load(Program::default(), options).unwrap();
+    let interpreter_image: Vec<u8> = (0..=0x1FF).map(|i: usize| (i * 7) as u8).collect();
+    processor
+        .load_interpreter_image(&interpreter_image)
+        .unwrap();
+    processor
+}
+
+#[test]
+fn test_execute_CXNN_vip_random_generator() {
+    let mut processor: Processor = setup_test_processor_vip_random_generator();
+    processor.cycles = 0x1FF;
+    // R9.0 is incremented to 0x00, and the byte at 0x100 (0x00) added to R9.1
+    processor.execute_CXNN(0x5, 0xFF).unwrap();
+    assert_eq!(processor.variable_registers[0x5], 0x00);
+    processor.cycles = 0x104;
+    // R9.0 is incremented to 0x05, and the byte at 0x105 (0x23) added to R9.1
+    processor.execute_CXNN(0x5, 0x0F).unwrap();
+    assert_eq!(processor.variable_registers[0x5], 0x03);
+    assert_eq!(processor.vip_random_register, 0x23);
+}
+
+#[test]
+fn test_execute_CXNN_vip_random_generator_reproducible() {
+    let mut results: Vec<Vec<u8>> = Vec::new();
+    for _ in 0..2 {
+        let mut processor: Processor = setup_test_processor_vip_random_generator();
+        let mut sequence: Vec<u8> = Vec::new();
+        for cycles in [3, 17, 40, 41, 200] {
+            processor.cycles = cycles;
+            processor.execute_CXNN(0x5, 0xFF).unwrap();
+            sequence.push(processor.variable_registers[0x5]);
+        }
+        results.push(sequence);
+    }
+    assert_eq!(results[0], results[1]);
+}
+
+fn fill_row(display: &mut Display, y: usize) {
+    for i in &mut display[y] {
+        *i = 0xFF;
+    }
+}
+
+#[test]
+fn test_execute_DXYN_Idle_to_Waiting() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.vblank_status = VBlankStatus::Idle;
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::WaitingForVBlank);
+}
+
+#[test]
+fn test_execute_DXYN_Waiting_to_Waiting() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.vblank_status = VBlankStatus::WaitingForVBlank;
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::WaitingForVBlank);
+}
+
+#[test]
+fn test_execute_DXYN_Ready_To_Idle() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = processor.font_start_address as u16;
+    let sprite: [u8; 1] = [0xFF]; // create single-byte sprite with all pixels on
+    processor
+        .memory
+        .write_bytes(processor.font_start_address, &sprite)
+        .unwrap(); // write sprite to memory at default font location
+    processor.variable_registers[0x3] = 0x8; // set V3 to 0 (X coordinate)
+    processor.variable_registers[0xA] = 0x1; // set V10 to 1 (Y coordinate)
+    processor.vblank_status = VBlankStatus::ReadyToDraw;
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::Idle);
+}
+
+fn setup_test_processor_lowres_display_wait(emulation_level: EmulationLevel) -> Processor {
+    let mut options: Options = Options::default();
+    options.emulation_level = emulation_level;
+    options.lowres_display_wait = true;
+    Processor::initialise_and_load(Program::default(), options).unwrap()
+}
+
+#[test]
+fn test_execute_DXYN_chip48_display_wait() {
+    let mut processor: Processor =
+        setup_test_processor_lowres_display_wait(EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        });
+    processor.vblank_status = VBlankStatus::Idle;
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::WaitingForVBlank);
+}
+
+#[test]
+fn test_execute_DXYN_chip48_no_display_wait() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.vblank_status = VBlankStatus::Idle;
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::Idle);
+}
+
+#[test]
+fn test_execute_DXYN_superchip11_hires_ignores_display_wait() {
+    let mut processor: Processor =
+        setup_test_processor_lowres_display_wait(EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        });
+    processor.high_resolution_mode = true;
+    processor.vblank_status = VBlankStatus::Idle;
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::Idle);
+}
+
+fn setup_test_processor_accurate_display_timing() -> Processor {
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    };
+    options.accurate_display_timing = true;
+    options.cycle_synced_timers = true;
+    Processor::initialise_and_load(Program::default(), options).unwrap()
+}
+
+#[test]
+fn test_execute_DXYN_accurate_display_timing_waits_for_raster() {
+    let mut processor: Processor = setup_test_processor_accurate_display_timing();
+    processor.variable_registers[0xA] = 0x0; // set V10 to 0 (Y coordinate)
+    let program_counter: u16 = processor.program_counter;
+    // At the start of the frame the raster has not yet reached the sprite's row
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::Idle);
+    assert_eq!(processor.program_counter, program_counter - 2);
+    assert_eq!(processor.sprites_drawn_this_frame, 0);
+}
+
+#[test]
+fn test_execute_DXYN_accurate_display_timing_depends_on_sprite_y() {
+    let mut processor: Processor = setup_test_processor_accurate_display_timing();
+    // Move the raster a quarter of the way through the frame (scanline 65, just past display
+    // row 15)
+    processor.cycles =
+        processor.interval_cycles(processor.timer_interval_microseconds) as usize / 4;
+    processor.variable_registers[0xA] = 0x0; // set V10 to 0 (Y coordinate)
+    let program_counter: u16 = processor.program_counter;
+    processor.execute_DXYN(0x3, 0xA, 5).unwrap();
+    assert_eq!(processor.program_counter, program_counter);
+    assert_eq!(processor.sprites_drawn_this_frame, 1);
+    processor.variable_registers[0xA] = 0x14; // set V10 to 20 (Y coordinate)
+    processor.execute_DXYN(0x3, 0xA, 5).unwrap();
+    assert_eq!(processor.program_counter, program_counter - 2);
+    assert_eq!(processor.sprites_drawn_this_frame, 1);
+}
+
+#[test]
+fn test_execute_DXYN_accurate_display_timing_ignored_chip48() {
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    options.accurate_display_timing = true;
+    let mut processor: Processor =
+        Processor::initialise_and_load(Program::default(), options).unwrap();
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::Idle);
+    assert_eq!(processor.sprites_drawn_this_frame, 1);
+}
+
+fn setup_test_processor_sprites_per_frame(sprites_per_frame: usize) -> Processor {
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    options.sprites_per_frame = Some(sprites_per_frame);
+    Processor::initialise_and_load(Program::default(), options).unwrap()
+}
+
+#[test]
+fn test_execute_DXYN_sprites_per_frame_budget() {
+    let mut processor: Processor = setup_test_processor_sprites_per_frame(2);
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::Idle);
+    assert_eq!(processor.sprites_drawn_this_frame, 2);
+    let program_counter: u16 = processor.program_counter;
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::WaitingForVBlank);
+    assert_eq!(processor.program_counter, program_counter - 2);
+    assert_eq!(processor.sprites_drawn_this_frame, 2);
+}
+
+#[test]
+fn test_execute_DXYN_sprites_per_frame_replenished_by_vblank() {
+    let mut processor: Processor = setup_test_processor_sprites_per_frame(1);
+    processor.last_vblank_interrupt = processor.now();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::WaitingForVBlank);
+    processor.last_vblank_interrupt =
+        processor.now() - Duration::from_micros(processor.timer_interval_microseconds as u64 + 100);
+    processor.decrement_timers();
+    assert_eq!(processor.vblank_status, VBlankStatus::ReadyToDraw);
+    assert_eq!(processor.sprites_drawn_this_frame, 0);
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.vblank_status, VBlankStatus::Idle);
+    assert_eq!(processor.sprites_drawn_this_frame, 1);
+}
+
+#[test]
+fn test_sprites_per_frame_invalid() {
+    let mut options: Options = Options::default();
+    options.sprites_per_frame = Some(0);
+    let operands: Vec<Operand> = vec![Operand::SpritesPerFrame(0)];
+    assert_eq!(
+        Processor::initialise_and_load(Program::default(), options)
+            .err()
+            .unwrap()
+            .inner_error,
+        ErrorDetail::OperandsOutOfBounds { operands }
+    );
+}
+
+#[test]
+fn test_execute_DXYN_pixel_turned_off() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    fill_row(&mut processor.frame_buffer, 0x1); // all display pixels on in second row
+    processor.frame_buffer[0x1][0x0] = 0x0; // turn off first byte of pixels only
+    processor.variable_registers[0xF] = 0x2; // only possible values later are 0x0 and 0x1
+    processor.index_register = processor.font_start_address as u16;
+    let sprite: [u8; 1] = [0xFF]; // create single-byte sprite with all pixels on
+    processor
+        .memory
+        .write_bytes(processor.font_start_address, &sprite)
+        .unwrap(); // write sprite to memory at default font location
+    processor.variable_registers[0x3] = 0x8; // set V3 to 0 (X coordinate)
+    processor.variable_registers[0xA] = 0x1; // set V10 to 1 (Y coordinate)
+    processor.vblank_status = VBlankStatus::ReadyToDraw;
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.variable_registers[0xF], 0x1); // at least one pixel will flip if successful
+}
+
+#[test]
+fn test_execute_DXYN_no_pixel_turned_off() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    fill_row(&mut processor.frame_buffer, 0x1); // all display pixels on in second row
+    processor.variable_registers[0xF] = 0x2; // only possible values later are 0x0 and 0x1
+    processor.index_register = processor.font_start_address as u16;
+    let sprite: [u8; 1] = [0x0]; // create single-byte sprite with all pixels off
+    processor
+        .memory
+        .write_bytes(processor.font_start_address, &sprite)
+        .unwrap(); // write sprite to memory at default font location
+    processor.variable_registers[0x3] = 0x0; // set V3 to 0 (X coordinate)
+    processor.variable_registers[0xA] = 0x1; // set V10 to 1 (Y coordinate)
+    processor.vblank_status = VBlankStatus::ReadyToDraw;
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert_eq!(processor.variable_registers[0xF], 0x0); // no pixel will flip if successful
+}
+
+#[test]
+fn test_execute_DXYN_zero_height_at_address_zero() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.index_register = 0x0;
+    assert!(processor.execute_DXYN(0x0, 0x0, 0).is_ok());
+}
+
+#[test]
+fn test_duplicate_bits() {
+    let (a, b) = Processor::duplicate_bits(0b10110101);
+    assert!(a == 0b11001111 && b == 0b00110011);
+    let (a, b) = Processor::duplicate_bits(0b11100110);
+    assert!(a == 0b11111100 && b == 0b00111100);
+    let (a, b) = Processor::duplicate_bits(0b11111111);
+    assert!(a == 0b11111111 && b == 0b11111111);
+}
+
+#[test]
+fn test_execute_DXYN_superchip11_low_res_trivial_sprite() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.high_resolution_mode = false;
+    processor.variable_registers[0xF] = 0x1; // set Vf to 1
+    processor.index_register = processor.font_start_address as u16;
+    // Create a trivial 1-bit sprite: 10000000
+    let sprite: [u8; 1] = [0x80];
+    // Write sprite to memory at default font location
+    processor
+        .memory
+        .write_bytes(processor.font_start_address, &sprite)
+        .unwrap();
+    // Set V3 to 1 (X coordinate) and V10 to 1 (Y coordinate)
+    processor.variable_registers[0x3] = 0x1;
+    processor.variable_registers[0xA] = 0x1;
+    // The following should execute a low-res SUPER-CHIP draw, which should up-scale the sprite to 2x2
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert!(
+        processor.variable_registers[0xF] == 0x0 // no collisions occurred
+            && processor.frame_buffer[0][0] == 0x00 // 00000000
+            && processor.frame_buffer[1][0] == 0x00 // 00000000
+            && processor.frame_buffer[2][0] == 0x30 // 00110000
+            && processor.frame_buffer[3][0] == 0x30 // 00110000
+    );
+}
+
+#[test]
+fn test_execute_DXY0_superchip10_low_res() {
+    let mut processor: Processor = setup_test_processor_superchip10();
+    processor.high_resolution_mode = false;
+    processor.index_register = 0x300;
+    // Create an 8x16 sprite with the leftmost pixel of each row lit: 10000000
+    processor.memory.write_bytes(0x300, &[0x80; 16]).unwrap();
+    processor.variable_registers[0x3] = 0x0;
+    processor.variable_registers[0xA] = 0x0;
+    // SUPER-CHIP 1.0 draws all 16 rows, up-scaling each pixel to 2x2
+    processor.execute_DXYN(0x3, 0xA, 0).unwrap();
+    assert!(
+        processor.frame_buffer[0][0] == 0xC0 // 11000000
+            && processor.frame_buffer[31][0] == 0xC0 // 11000000
+            && processor.frame_buffer[32][0] == 0x00 // 00000000
+            && processor.frame_buffer[0][1] == 0x00 // 00000000
+    );
+}
+
+#[test]
+fn test_execute_DXYN_superchip11_low_res_wide_sprite() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.high_resolution_mode = false;
+    processor.variable_registers[0xF] = 0x1; // set Vf to 1
+    processor.index_register = processor.font_start_address as u16;
+    // Create a full-width sprite: 10011011
+    let sprite: [u8; 1] = [0x9B];
+    // Write sprite to memory at default font location
+    processor
+        .memory
+        .write_bytes(processor.font_start_address, &sprite)
+        .unwrap();
+    // Set V3 to 2 (X coordinate) and V10 to 2 (Y coordinate)
+    processor.variable_registers[0x3] = 0x2;
+    processor.variable_registers[0xA] = 0x2;
+    // The following should execute a low-res SUPER-CHIP draw, which should up-scale the sprite 2x
+    processor.execute_DXYN(0x3, 0xA, 1).unwrap();
+    assert!(
+        processor.variable_registers[0xF] == 0x0 // no collisions occurred
+            && processor.frame_buffer[0][0] == 0x00 // 00000000
+            && processor.frame_buffer[0][1] == 0x00 // 00000000
+            && processor.frame_buffer[0][2] == 0x00 // 00000000
+            && processor.frame_buffer[1][0] == 0x00 // 00000000
+            && processor.frame_buffer[1][1] == 0x00 // 00000000
+            && processor.frame_buffer[1][2] == 0x00 // 00000000
+            && processor.frame_buffer[2][0] == 0x00 // 00000000
+            && processor.frame_buffer[2][1] == 0x00 // 00000000
+            && processor.frame_buffer[2][2] == 0x00 // 00000000
+            && processor.frame_buffer[3][0] == 0x00 // 00000000
+            && processor.frame_buffer[3][1] == 0x00 // 00000000
+            && processor.frame_buffer[3][2] == 0x00 // 00000000
+            && processor.frame_buffer[4][0] == 0x0C // 00001100
+            && processor.frame_buffer[4][1] == 0x3C // 00111100
+            && processor.frame_buffer[4][2] == 0xF0 // 11110000
+            && processor.frame_buffer[5][0] == 0x0C // 00001100
+            && processor.frame_buffer[5][1] == 0x3C // 00111100
+            && processor.frame_buffer[5][2] == 0xF0 // 11110000
+    );
+}
+
+#[test]
+fn test_execute_DXY0_superchip11() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.high_resolution_mode = true;
+    let display_rows: usize = processor.frame_buffer.get_column_size_pixels();
+    fill_row(&mut processor.frame_buffer, display_rows - 2); // all display pixels on in penultimate row
+    fill_row(&mut processor.frame_buffer, display_rows - 1); // all display pixels on in final row
+    processor.variable_registers[0xF] = 0x0; // set Vf to 0
+    processor.index_register = processor.font_start_address as u16;
+    let sprite: [u8; 32] = [0xFF; 32]; // create 32-byte sprite with all pixels on
+    processor
+        .memory
+        .write_bytes(processor.font_start_address, &sprite)
+        .unwrap(); // write sprite to memory at default font location
+    processor.variable_registers[0x3] = 0x8; // set V3 to 0 (X coordinate)
+                                             // set V10 (Y coord) to 3rd final row // execute a DXY0 instruction
+    processor.variable_registers[0xA] = (display_rows - 3) as u8;
+    // This operation should cause pixel collison on two rows (penultimate and final but not third last)
+    // and should also cause clipping of 13 rows (16-byte high sprite with only 3 rows on-screen)
+    // however clipping is currently disabled by design, so 0 for this component
+    assert!(
+        processor.execute_DXYN(0x3, 0xA, 0).unwrap() == 0
+            && processor.variable_registers[0xF] == 0x2 // 2 (if not disabled would be 2 + 13 = 15 = 0xF)
+    );
+}
+
+#[test]
+fn test_execute_DXYN_invalid_x_register_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10), Operand::Y(0x2), Operand::N(0x5)];
+    assert_eq!(
+        processor.execute_DXYN(0x10, 0x2, 0x5).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_DXYN_invalid_y_register_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x2), Operand::Y(0x10), Operand::N(0x5)];
+    assert_eq!(
+        processor.execute_DXYN(0x2, 0x10, 0x5).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_DXYN_invalid_n_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x2), Operand::Y(0x5), Operand::N(0x10)];
+    assert_eq!(
+        processor.execute_DXYN(0x2, 0x5, 0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_operands_out_of_bounds_display() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor
+            .execute_DXYN(0x2, 0x5, 0x10)
+            .unwrap_err()
+            .to_string(),
+        "an opcode contains invalid operands: x = 0x2, y = 0x5, n = 0x10"
+    );
+}
+
+#[test]
+fn test_execute_EX9E_pressed() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0xA;
+    processor.keystate.set_key_status(0xA, true).unwrap();
+    assert!(processor.execute_EX9E(0x9).is_ok() && processor.program_counter == 0x15);
+}
+
+#[test]
+fn test_execute_EX9E_pressed_held() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x9] = 0xA;
+    processor.keystate.set_key_status(0xA, true).unwrap();
+    processor.execute_EX9E(0x9).unwrap();
+    assert!(processor.keystate.is_key_pressed(0xA).unwrap());
+}
+
+#[test]
+fn test_execute_EX9E_pressed_key_repeat_suppression() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.key_repeat_suppression = true;
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0xA;
+    processor.keystate.set_key_status(0xA, true).unwrap();
+    processor.execute_EX9E(0x9).unwrap();
+    assert!(processor.program_counter == 0x15 && !processor.keystate.is_key_pressed(0xA).unwrap());
+    processor.execute_EX9E(0x9).unwrap();
+    assert_eq!(processor.program_counter, 0x15);
+}
+
+#[test]
+fn test_execute_EX9E_not_pressed() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0xA;
+    processor.keystate.set_key_status(0xA, false).unwrap();
+    assert!(processor.execute_EX9E(0x9).is_ok() && processor.program_counter == 0x13);
+}
+
+#[test]
+fn test_execute_EX9E_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_EX9E(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_EX9E_invalid_key_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0x10;
+    assert_eq!(
+        processor.execute_EX9E(0x9).unwrap_err(),
+        ErrorDetail::InvalidKey { key: 0x10 }
+    );
+}
+
+#[test]
+fn test_execute_EXA1_pressed() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0xA;
+    processor.keystate.set_key_status(0xA, true).unwrap();
+    assert!(processor.execute_EXA1(0x9).is_ok() && processor.program_counter == 0x13);
+}
+
+#[test]
+fn test_execute_EXA1_pressed_key_repeat_suppression() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.key_repeat_suppression = true;
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0xA;
+    processor.keystate.set_key_status(0xA, true).unwrap();
+    processor.execute_EXA1(0x9).unwrap();
+    assert!(processor.program_counter == 0x13 && !processor.keystate.is_key_pressed(0xA).unwrap());
+    processor.execute_EXA1(0x9).unwrap();
+    assert_eq!(processor.program_counter, 0x15);
+}
+
+#[test]
+fn test_execute_EXA1_not_pressed() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0xA;
+    processor.keystate.set_key_status(0xA, false).unwrap();
+    assert!(processor.execute_EXA1(0x9).is_ok() && processor.program_counter == 0x15);
+}
+
+#[test]
+fn test_execute_EXA1_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_EXA1(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_EXA1_invalid_key_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0x13;
+    processor.variable_registers[0x9] = 0x10;
+    assert_eq!(
+        processor.execute_EXA1(0x9).unwrap_err(),
+        ErrorDetail::InvalidKey { key: 0x10 }
+    );
+}
+
+#[test]
+fn test_execute_FX07() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.delay_timer = 0xF3;
+    assert!(processor.execute_FX07(0x7).is_ok() && processor.variable_registers[0x7] == 0xF3);
+}
+
+#[test]
+fn test_execute_FX07_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX07(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX0A_block() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.program_counter = 0xC5;
+    processor.status = ProcessorStatus::Running;
+    processor.execute_FX0A(0x3).unwrap();
+    assert!(
+        processor.status == ProcessorStatus::WaitingForKeypress
+            && processor.program_counter == 0xC3
+    );
+}
+
+#[test]
+fn test_execute_FX0A_press_and_release() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.status = ProcessorStatus::Running;
+    processor.program_counter = 0xC5;
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xB, true).unwrap(); // Simulate key press
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xB, false).unwrap(); // Simulate key release
+    processor.execute_FX0A(0x3).unwrap();
+    assert!(
+        processor.status == ProcessorStatus::Running
+            && processor.program_counter == 0xC1
+            && processor.variable_registers[0x3] == 0xB
+    );
+}
+
+#[test]
+fn test_execute_FX0A_pause_during_wait() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.status = ProcessorStatus::Running;
+    processor.program_counter = 0xC5;
+    processor.execute_FX0A(0x3).unwrap();
+    processor.keystate.set_key_status(0xB, true).unwrap(); // Simulate key press
+    processor.execute_FX0A(0x3).unwrap();
+    processor.pause_execution().unwrap();
+    processor.resume_execution().unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xB, false).unwrap(); // Simulate key release
+    processor.execute_FX0A(0x3).unwrap();
+    assert!(
+        processor.status == ProcessorStatus::Running && processor.variable_registers[0x3] == 0xB
+    );
+}
+
+#[test]
+fn test_execute_FX0A_press_and_release_multiple() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.status = ProcessorStatus::Running;
+    processor.program_counter = 0xC5;
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xA, true).unwrap(); // Simulate key press
+    processor.keystate.set_key_status(0xB, true).unwrap(); // Simulate key press
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xB, false).unwrap(); // Simulate key release
+    processor.execute_FX0A(0x3).unwrap();
+    assert!(
+        processor.status == ProcessorStatus::Running
+            && processor.program_counter == 0xC1
+            && processor.variable_registers[0x3] == 0xB
+    );
+}
+
+#[test]
+fn test_execute_FX0A_on_press() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.key_wait_mode = KeyWaitMode::OnPress;
+    processor.status = ProcessorStatus::Running;
+    processor.set_key_status(Key::Key5, true);
+    processor.program_counter = 0xC5;
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    // A key already held when the wait began is not registered
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xB, true).unwrap(); // Simulate key press
+    processor.execute_FX0A(0x3).unwrap();
+    assert!(
+        processor.status == ProcessorStatus::Running
+            && processor.program_counter == 0xC1
+            && processor.variable_registers[0x3] == 0xB
+    );
+}
+
+#[test]
+fn test_execute_FX0A_press_and_release_existing_keys() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.status = ProcessorStatus::Running;
+    processor.set_key_status(Key::Key5, true);
+    processor.set_key_status(Key::Key9, true);
+    processor.program_counter = 0xC5;
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xB, true).unwrap(); // Simulate key press
+    processor.execute_FX0A(0x3).unwrap();
+    assert_eq!(processor.status, ProcessorStatus::WaitingForKeypress);
+    processor.keystate.set_key_status(0xB, false).unwrap(); // Simulate key release
+    processor.execute_FX0A(0x3).unwrap();
+    assert!(
+        processor.status == ProcessorStatus::Running
+            && processor.program_counter == 0xC1
+            && processor.variable_registers[0x3] == 0xB
+    );
+}
+
+#[test]
+fn test_execute_FX0A_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX0A(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX15() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x7] = 0xF3;
+    assert!(processor.execute_FX15(0x7).is_ok() && processor.delay_timer == 0xF3);
+}
+
+#[test]
+fn test_execute_FX15_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX15(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX18() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x7] = 0xF3;
+    assert!(processor.execute_FX18(0x7).is_ok() && processor.sound_timer == 0xF3);
+}
+
+#[test]
+fn test_execute_FX18_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX18(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX1E() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x3A;
+    processor.variable_registers[0xB] = 0xA2;
+    assert!(
+        processor.execute_FX1E(0xB).is_ok()
+            && processor.index_register == 0xDC
+            && processor.variable_registers[0xF] == 0
+    );
+}
+
+#[test]
+fn test_execute_FX1E_outside_memory() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x0FF2;
+    processor.variable_registers[0xB] = 0xA2;
+    assert!(
+        processor.execute_FX1E(0xB).is_ok()
+            && processor.index_register == 0x1094
+            && processor.variable_registers[0xF] == 1
+    );
+}
+
+#[test]
+fn test_execute_FX1E_overflow_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    processor.index_register = 0x0FF2;
+    assert_eq!(
+        processor.execute_FX1E(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX1E_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX1E(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX29() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.variable_registers[0x7] = 0xA;
+    assert!(processor.execute_FX29(0x7).is_ok() && processor.index_register == 0x82);
+}
+
+#[test]
+fn test_execute_FX29_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX29(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX29_invalid_register_x_value_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::Character(0x10)];
+    processor.variable_registers[0x7] = 0x10;
+    assert_eq!(
+        processor.execute_FX29(0x7).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX30() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.variable_registers[0x7] = 0x02;
+    assert!(processor.execute_FX30(0x7).is_ok() && processor.index_register == 0xB4);
+}
+
+#[test]
+fn test_execute_FX30_octo() {
+    let mut processor: Processor = setup_test_processor_superchip11_octo();
+    processor.variable_registers[0x7] = 0x0A;
+    assert!(processor.execute_FX30(0x7).is_ok() && processor.index_register == 0x104);
+}
+
+#[test]
+fn test_execute_FX30_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX30(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX30_invalid_register_x_value_error() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let operands: Vec<Operand> = vec![Operand::Character(0x0A)];
+    processor.variable_registers[0x7] = 0x0A;
+    assert_eq!(
+        processor.execute_FX30(0x7).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX30_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_FX30(0x3).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0xF330 }
+    );
+}
+
+#[test]
+fn test_execute_FX30_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_FX30(0x3).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0xF330 }
+    );
+}
+
+#[test]
+fn test_execute_FX33_one_digit() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x3] = 0x06;
+    assert!(
+        processor.execute_FX33(0x3).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 0
+            && processor.memory.read_byte(0x025B).unwrap() == 0
+            && processor.memory.read_byte(0x025C).unwrap() == 6
+    )
+}
+
+#[test]
+fn test_execute_FX33_two_digits() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x3] = 0x5B;
+    assert!(
+        processor.execute_FX33(0x3).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 0
+            && processor.memory.read_byte(0x025B).unwrap() == 9
+            && processor.memory.read_byte(0x025C).unwrap() == 1
+    )
+}
+
+#[test]
+fn test_execute_FX33_three_digits() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x3] = 0x9C;
+    assert!(
+        processor.execute_FX33(0x3).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 1
+            && processor.memory.read_byte(0x025B).unwrap() == 5
+            && processor.memory.read_byte(0x025C).unwrap() == 6
+    )
+}
+
+#[test]
+fn test_execute_FX33_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX33(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX55_one_register() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x0] = 0x3C;
+    processor.variable_registers[0x1] = 0x12;
+    processor.variable_registers[0x2] = 0xF4;
+    processor.variable_registers[0x3] = 0x2D;
+    processor.variable_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX55(0x0).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 0x3C
+            && processor.memory.read_byte(0x025B).unwrap() == 0x0
+    );
+}
+
+#[test]
+fn test_execute_FX55_multiple_registers() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x0] = 0x3C;
+    processor.variable_registers[0x1] = 0x12;
+    processor.variable_registers[0x2] = 0xF4;
+    processor.variable_registers[0x3] = 0x2D;
+    processor.variable_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX55(0x03).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 0x3C
+            && processor.memory.read_byte(0x025B).unwrap() == 0x12
+            && processor.memory.read_byte(0x025C).unwrap() == 0xF4
+            && processor.memory.read_byte(0x025D).unwrap() == 0x2D
+            && processor.memory.read_byte(0x025E).unwrap() == 0x0
+            && processor.index_register == 0x025E
+    );
+}
+
+#[test]
+fn test_execute_FX55_multiple_registers_chip48_mode() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x0] = 0x3C;
+    processor.variable_registers[0x1] = 0x12;
+    processor.variable_registers[0x2] = 0xF4;
+    processor.variable_registers[0x3] = 0x2D;
+    processor.variable_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX55(0x03).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 0x3C
+            && processor.memory.read_byte(0x025B).unwrap() == 0x12
+            && processor.memory.read_byte(0x025C).unwrap() == 0xF4
+            && processor.memory.read_byte(0x025D).unwrap() == 0x2D
+            && processor.memory.read_byte(0x025E).unwrap() == 0x0
+            && processor.index_register == 0x025D
+    );
+}
+
+#[test]
+fn test_execute_FX55_multiple_registers_superchip11_mode() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x0] = 0x3C;
+    processor.variable_registers[0x1] = 0x12;
+    processor.variable_registers[0x2] = 0xF4;
+    processor.variable_registers[0x3] = 0x2D;
+    processor.variable_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX55(0x03).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 0x3C
+            && processor.memory.read_byte(0x025B).unwrap() == 0x12
+            && processor.memory.read_byte(0x025C).unwrap() == 0xF4
+            && processor.memory.read_byte(0x025D).unwrap() == 0x2D
+            && processor.index_register == 0x025A
+    );
+}
+
+#[test]
+fn test_execute_FX55_multiple_registers_superchip10_mode() {
+    let mut processor: Processor = setup_test_processor_superchip10();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x0] = 0x3C;
+    processor.variable_registers[0x1] = 0x12;
+    processor.variable_registers[0x2] = 0xF4;
+    processor.variable_registers[0x3] = 0x2D;
+    assert!(
+        processor.execute_FX55(0x03).is_ok()
+            && processor.memory.read_byte(0x025A).unwrap() == 0x3C
+            && processor.memory.read_byte(0x025D).unwrap() == 0x2D
+            && processor.index_register == 0x025D
+    );
+}
+
+#[test]
+fn test_execute_FX55_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX55(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX65_one_register() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x025A;
+    processor.memory.write_byte(0x025A, 0x3C).unwrap();
+    processor.memory.write_byte(0x025B, 0x12).unwrap();
+    processor.memory.write_byte(0x025C, 0xF4).unwrap();
+    processor.memory.write_byte(0x025D, 0x2D).unwrap();
+    processor.memory.write_byte(0x025E, 0x07).unwrap();
+    assert!(
+        processor.execute_FX65(0x0).is_ok()
+            && processor.variable_registers[0x0] == 0x3C
+            && processor.variable_registers[0x1] == 0x0
+    );
+}
+
+#[test]
+fn test_execute_FX65_multiple_registers() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x025A;
+    processor.memory.write_byte(0x025A, 0x3C).unwrap();
+    processor.memory.write_byte(0x025B, 0x12).unwrap();
+    processor.memory.write_byte(0x025C, 0xF4).unwrap();
+    processor.memory.write_byte(0x025D, 0x2D).unwrap();
+    processor.memory.write_byte(0x025E, 0x07).unwrap();
+    assert!(
+        processor.execute_FX65(0x03).is_ok()
+            && processor.variable_registers[0x0] == 0x3C
+            && processor.variable_registers[0x1] == 0x12
+            && processor.variable_registers[0x2] == 0xF4
+            && processor.variable_registers[0x3] == 0x2D
+            && processor.variable_registers[0x4] == 0x0
+            && processor.index_register == 0x025E
+    );
+}
+
+#[test]
+fn test_execute_FX65_out_of_bounds() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.index_register = 0x0FFF;
+    assert_eq!(
+        processor.execute_FX65(0x1),
+        Err(ErrorDetail::MemoryAddressOutOfBounds { address: 0x1000 })
+    );
+}
+
+#[test]
+fn test_execute_FX65_multiple_registers_chip48_mode() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    processor.index_register = 0x025A;
+    processor.memory.write_byte(0x025A, 0x3C).unwrap();
+    processor.memory.write_byte(0x025B, 0x12).unwrap();
+    processor.memory.write_byte(0x025C, 0xF4).unwrap();
+    processor.memory.write_byte(0x025D, 0x2D).unwrap();
+    processor.memory.write_byte(0x025E, 0x07).unwrap();
+    assert!(
+        processor.execute_FX65(0x03).is_ok()
+            && processor.variable_registers[0x0] == 0x3C
+            && processor.variable_registers[0x1] == 0x12
+            && processor.variable_registers[0x2] == 0xF4
+            && processor.variable_registers[0x3] == 0x2D
+            && processor.variable_registers[0x4] == 0x0
+            && processor.index_register == 0x025D
+    );
+}
+
+#[test]
+fn test_execute_FX65_multiple_registers_superchip11_mode() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.index_register = 0x025A;
+    processor.memory.write_byte(0x025A, 0x3C).unwrap();
+    processor.memory.write_byte(0x025B, 0x12).unwrap();
+    processor.memory.write_byte(0x025C, 0xF4).unwrap();
+    processor.memory.write_byte(0x025D, 0x2D).unwrap();
+    processor.memory.write_byte(0x025E, 0x07).unwrap();
+    assert!(
+        processor.execute_FX65(0x03).is_ok()
+            && processor.variable_registers[0x0] == 0x3C
+            && processor.variable_registers[0x1] == 0x12
+            && processor.variable_registers[0x2] == 0xF4
+            && processor.variable_registers[0x3] == 0x2D
+            && processor.variable_registers[0x4] == 0x0
+            && processor.index_register == 0x025A
+    );
+}
+
+#[test]
+fn test_execute_FX65_multiple_registers_superchip10_mode() {
+    let mut processor: Processor = setup_test_processor_superchip10();
+    processor.index_register = 0x025A;
+    processor
+        .memory
+        .write_bytes(0x025A, &[0x3C, 0x12, 0xF4, 0x2D])
+        .unwrap();
+    assert!(
+        processor.execute_FX65(0x03).is_ok()
+            && processor.variable_registers[0x0..0x4] == [0x3C, 0x12, 0xF4, 0x2D]
+            && processor.index_register == 0x025D
+    );
+}
+
+#[test]
+fn test_execute_FX65_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let operands: Vec<Operand> = vec![Operand::X(0x10)];
+    assert_eq!(
+        processor.execute_FX65(0x10).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX75_one_register() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.variable_registers[0x0] = 0x3C;
+    processor.variable_registers[0x1] = 0x12;
+    processor.variable_registers[0x2] = 0xF4;
+    processor.variable_registers[0x3] = 0x2D;
+    processor.variable_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX75(0x0).is_ok()
+            && processor.rpl_registers[0x0] == 0x3C
+            && processor.rpl_registers[0x1] == 0x0
+    );
+}
+
+#[test]
+fn test_execute_FX75_multiple_registers() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.variable_registers[0x0] = 0x3C;
+    processor.variable_registers[0x1] = 0x12;
+    processor.variable_registers[0x2] = 0xF4;
+    processor.variable_registers[0x3] = 0x2D;
+    processor.variable_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX75(0x03).is_ok()
+            && processor.rpl_registers[0x0] == 0x3C
+            && processor.rpl_registers[0x1] == 0x12
+            && processor.rpl_registers[0x2] == 0xF4
+            && processor.rpl_registers[0x3] == 0x2D
+            && processor.rpl_registers[0x4] == 0x0
+    );
+}
+
+#[test]
+fn test_execute_FX75_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let operands: Vec<Operand> = vec![Operand::X(0x8)];
+    assert_eq!(
+        processor.execute_FX75(0x8).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX75_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_FX75(0x3).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0xF375 }
+    );
+}
+
+#[test]
+fn test_execute_FX75_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_FX75(0x3).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0xF375 }
+    );
+}
+
+#[test]
+fn test_execute_FX85_one_register() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.rpl_registers[0x0] = 0x3C;
+    processor.rpl_registers[0x1] = 0x12;
+    processor.rpl_registers[0x2] = 0xF4;
+    processor.rpl_registers[0x3] = 0x2D;
+    processor.rpl_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX85(0x0).is_ok()
+            && processor.variable_registers[0x0] == 0x3C
+            && processor.variable_registers[0x1] == 0x0
+    );
+}
+
+#[test]
+fn test_execute_FX85_multiple_registers() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    processor.rpl_registers[0x0] = 0x3C;
+    processor.rpl_registers[0x1] = 0x12;
+    processor.rpl_registers[0x2] = 0xF4;
+    processor.rpl_registers[0x3] = 0x2D;
+    processor.rpl_registers[0x4] = 0x07;
+    assert!(
+        processor.execute_FX85(0x03).is_ok()
+            && processor.variable_registers[0x0] == 0x3C
+            && processor.variable_registers[0x1] == 0x12
+            && processor.variable_registers[0x2] == 0xF4
+            && processor.variable_registers[0x3] == 0x2D
+            && processor.variable_registers[0x4] == 0x0
+    );
+}
+
+#[test]
+fn test_execute_FX85_invalid_register_x_error() {
+    let mut processor: Processor = setup_test_processor_superchip11();
+    let operands: Vec<Operand> = vec![Operand::X(0x8)];
+    assert_eq!(
+        processor.execute_FX85(0x8).unwrap_err(),
+        ErrorDetail::OperandsOutOfBounds { operands: operands }
+    );
+}
+
+#[test]
+fn test_execute_FX85_chip8_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    assert_eq!(
+        processor.execute_FX85(0x3).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0xF385 }
+    );
+}
+
+#[test]
+fn test_execute_FX85_chip48_error() {
+    let mut processor: Processor = setup_test_processor_chip48();
+    assert_eq!(
+        processor.execute_FX85(0x3).unwrap_err(),
+        ErrorDetail::UnknownInstruction { opcode: 0xF385 }
+    );
+}
+
+fn setup_test_processor_vip_memory_image(emulation_level: EmulationLevel) -> Processor {
+    let mut options: Options = Options::default();
+    options.emulation_level = emulation_level;
+    options.vip_memory_image = true;
+    Processor::initialise_and_load(Program::default(), options).unwrap()
+}
+
+#[test]
+fn test_vip_memory_image_font_chip8() {
+    let processor: Processor = setup_test_processor_vip_memory_image(EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    });
+    // the VIP's "1" has its serif on the first row
+    assert_eq!(
+        processor.memory.bytes[processor.font_start_address + 0x5],
+        0x60
+    );
+}
+
+#[test]
+fn test_vip_memory_image_ignored_chip48() {
+    let processor: Processor = setup_test_processor_vip_memory_image(EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    });
+    assert_eq!(
+        processor.memory.bytes[processor.font_start_address + 0x5],
+        0x20
+    );
+}
+
+#[test]
+fn test_load_interpreter_image() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.font_start_address = 0x100;
+    processor
+        .load_interpreter_image(&[0x91, 0xBB, 0xFF])
+        .unwrap();
+    assert_eq!(processor.memory.bytes[0x0..0x3], [0x91, 0xBB, 0xFF]);
+    assert_eq!(processor.memory.bytes[0x100], 0xF0);
+    processor.reset().unwrap();
+    assert_eq!(processor.memory.bytes[0x1], 0xBB);
+}
+
+#[test]
+fn test_load_interpreter_image_overlaps_program() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let image: Vec<u8> = vec![0x0; processor.program_start_address + 1];
+    assert!(matches!(
+        processor.load_interpreter_image(&image),
+        Err(ChipolataError {
+            inner_error: ErrorDetail::MemoryAddressOutOfBounds { .. },
+            ..
+        })
+    ));
+}
+
+fn setup_test_processor_protect_reserved_memory() -> Processor {
+    // I = 0x050 (the font), then store V0 there
+    let program: Program = Program::new(vec![0xA0, 0x50, 0xF0, 0x55]);
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    };
+    options.protect_reserved_memory = true;
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+#[test]
+fn test_protect_reserved_memory_error() {
+    let mut processor: Processor = setup_test_processor_protect_reserved_memory();
+    processor.execute_cycle().unwrap();
+    assert_eq!(
+        processor.execute_cycle().unwrap_err().inner_error,
+        ErrorDetail::ReservedMemoryWrite { address: 0x50 }
+    );
+    assert_eq!(processor.memory.bytes[0x50], 0xF0);
+}
+
+#[test]
+fn test_protect_reserved_memory_program_area() {
+    let mut processor: Processor = setup_test_processor_protect_reserved_memory();
+    processor.index_register = 0x025A;
+    processor.variable_registers[0x0] = 0x3C;
+    assert!(
+        processor.execute_FX55(0x0).is_ok() && processor.memory.read_byte(0x025A).unwrap() == 0x3C
+    );
+}
+
+#[test]
+fn test_protect_reserved_memory_after_reset() {
+    let mut processor: Processor = setup_test_processor_protect_reserved_memory();
+    processor.reset().unwrap();
+    processor.index_register = 0x0050;
+    assert_eq!(
+        processor.execute_FX55(0x0).unwrap_err(),
+        ErrorDetail::ReservedMemoryWrite { address: 0x50 }
+    );
+}
+
+#[test]
+fn test_protect_reserved_memory_load_interpreter_image() {
+    let mut processor: Processor = setup_test_processor_protect_reserved_memory();
+    processor
+        .load_interpreter_image(&[0x91, 0xBB, 0xFF])
+        .unwrap();
+    assert_eq!(processor.memory.bytes[0x0..0x3], [0x91, 0xBB, 0xFF]);
+    processor.index_register = 0x0000;
+    assert_eq!(
+        processor.execute_FX55(0x0).unwrap_err(),
+        ErrorDetail::ReservedMemoryWrite { address: 0x0 }
+    );
+}
+
+#[test]
+fn test_reserved_memory_unprotected_by_default() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    processor.index_register = 0x0050;
+    processor.variable_registers[0x0] = 0x3C;
+    assert!(processor.execute_FX55(0x0).is_ok() && processor.memory.bytes[0x50] == 0x3C);
+}
+
+#[test]
+fn test_apply_options() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let mut options: Options = processor.options;
+    options.processor_speed_hertz = 2000;
+    options.sprite_edge_mode = SpriteEdgeMode::Wrap;
+    options.timer_frequency_hertz = 50;
+    options.protect_reserved_memory = true;
+    processor.apply_options(&options).unwrap();
+    processor.index_register = 0x0050;
+    assert!(
+        processor.processor_speed() == 2000
+            && processor.sprite_edge_mode == SpriteEdgeMode::Wrap
+            && processor.timer_interval_microseconds == 20000
+            && processor.execute_FX55(0x0)
+                == Err(ErrorDetail::ReservedMemoryWrite { address: 0x50 })
+    );
+}
+
+#[test]
+fn test_apply_options_structural_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let mut options: Options = processor.options;
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    assert_eq!(
+        processor.apply_options(&options).unwrap_err().inner_error,
+        ErrorDetail::OptionNotChangeable {
+            option: "emulation_level".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_apply_options_out_of_bounds_error() {
+    let mut processor: Processor = setup_test_processor_chip8();
+    let mut options: Options = processor.options;
+    options.timer_frequency_hertz = 0;
+    let operands: Vec<Operand> = vec![Operand::TimerFrequencyHertz(0)];
+    assert_eq!(
+        processor.apply_options(&options).unwrap_err().inner_error,
+        ErrorDetail::OperandsOutOfBounds { operands }
+    );
+}
+
+fn setup_test_processor_watch_program() -> Processor {
+    // V3 = 0x05, V4 = 0x04, then loop forever
+    let program: Program = Program::new(vec![0x63, 0x05, 0x64, 0x04, 0x12, 0x04]);
+    let mut options: Options = Options::default();
+    options.emulation_level = EmulationLevel::Chip48 {
+        variable_cycle_timing: false,
+    };
+    let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+    processor.turbo_mode = true;
+    processor
+}
+
+#[test]
+fn test_watch_evaluated_each_cycle() {
+    let mut processor: Processor = setup_test_processor_watch_program();
+    let id: usize = processor.add_watch("V3 + V4".parse().unwrap(), false);
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.debug_report().watches[0].value, Some(0x5));
+    processor.execute_cycle().unwrap();
+    let report: DebugReport = processor.debug_report();
+    assert_eq!(report.watches[0].id, id);
+    assert_eq!(report.watches[0].expression, "V3 + V4");
+    assert_eq!(report.watches[0].value, Some(0x9));
+    assert_eq!(report.break_watch_id, None);
+    assert_eq!(processor.status, ProcessorStatus::Running);
+}
+
+#[test]
+fn test_watch_break_on_true() {
+    let mut processor: Processor = setup_test_processor_watch_program();
+    processor.add_watch("mem[PC] == 0x12".parse().unwrap(), false);
+    let id: usize = processor.add_watch("PC == 0x204".parse().unwrap(), true);
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Running);
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.status, ProcessorStatus::Paused);
+    let report: DebugReport = processor.debug_report();
+    assert_eq!(report.break_watch_id, Some(id));
+    assert_eq!(report.watches[0].value, Some(1));
+    assert_eq!(report.program_counter, 0x204);
+}
+
+#[test]
+fn test_watch_remove_and_reset() {
+    let mut processor: Processor = setup_test_processor_watch_program();
+    let first: usize = processor.add_watch("V3".parse().unwrap(), false);
+    let second: usize = processor.add_watch("V4".parse().unwrap(), false);
+    processor.execute_cycle().unwrap();
+    assert!(processor.remove_watch(first));
+    assert!(!processor.remove_watch(first));
+    processor.reset().unwrap();
+    let report: DebugReport = processor.debug_report();
+    assert_eq!(report.watches.len(), 1);
+    assert_eq!(report.watches[0].id, second);
+    assert_eq!(report.watches[0].value, None);
+}
+
+#[test]
+fn test_run_program_fuzz_rejected() {
+    assert_eq!(Processor::run_program_fuzz(&[], 100), FuzzOutcome::Rejected);
+    let too_large: Vec<u8> = vec![0x0; 0x1000];
+    assert_eq!(
+        Processor::run_program_fuzz(&too_large, 100),
+        FuzzOutcome::Rejected
+    );
+}
+
+#[test]
+fn test_run_program_fuzz_outcomes() {
+    // CHIP-48: 1200 (loop forever)
+    assert_eq!(
+        Processor::run_program_fuzz(&[0b01, 0x12, 0x00], 100),
+        FuzzOutcome::CycleLimitReached
+    );
+    // CHIP-48: 00EE (return from subroutine, with an empty stack)
+    assert_eq!(
+        Processor::run_program_fuzz(&[0b01, 0x00, 0xEE], 100),
+        FuzzOutcome::Crashed
+    );
+    // SUPER-CHIP 1.1: 00FD (exit interpreter)
+    assert_eq!(
+        Processor::run_program_fuzz(&[0b10, 0x00, 0xFD], 100),
+        FuzzOutcome::Exited
+    );
+}
+
+#[test]
+fn test_run_program_fuzz_unknown_instruction_policy() {
+    // CHIP-48 skipping unknown opcodes: FFFF (unknown), 1202 (loop forever)
+    assert_eq!(
+        Processor::run_program_fuzz(&[0b010_0001, 0xFF, 0xFF, 0x12, 0x02], 100),
+        FuzzOutcome::CycleLimitReached
+    );
+    assert_eq!(
+        Processor::run_program_fuzz(&[0b000_0001, 0xFF, 0xFF, 0x12, 0x02], 100),
+        FuzzOutcome::Crashed
+    );
+}
+
+#[test]
+fn test_run_program_fuzz_reaches_every_emulation_level() {
+    // Each variant (and SUPER-CHIP 1.1 with and without Octo compatibility) must be selectable
+    let variant_index = |emulation_level: EmulationLevel| match emulation_level {
+        EmulationLevel::Chip8 { .. } => 0,
+        EmulationLevel::Chip8X { .. } => 1,
+        EmulationLevel::Chip48 { .. } => 2,
+        EmulationLevel::SuperChip10 { .. } => 3,
+        EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            ..
+        } => 4,
+        EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: true,
+            ..
+        } => 5,
+    };
+    let mut reached: [bool; 6] = [false; 6];
+    for configuration in 0..=u8::MAX {
+        reached[variant_index(fuzz::fuzz_options(configuration).emulation_level)] = true;
+    }
+    assert!(reached.iter().all(|reached| *reached));
+}
+
+#[test]
+fn test_instruction_set_cosmac_cycles() {
+    // Each instruction's nominal cost must be the minimum its execute method returns; DXYN and
+    // FX0A are excluded as they first wait for the vblank interrupt and a key press respectively
+    for setup in [setup_test_processor_chip8, setup_test_processor_chip8x] {
+        let emulation_level: EmulationLevel = setup().emulation_level;
+        for info in crate::instruction_set(emulation_level) {
+            let (Some(cycles), false) = (info.cycles, ["DXYN", "FX0A"].contains(&info.mnemonic))
+            else {
+                continue;
+            };
+            let mut processor: Processor = setup();
+            if info.mnemonic == "00EE" {
+                processor
+                    .execute(Instruction::Op2NNN { nnn: 0x300 })
+                    .unwrap();
+            }
+            let instruction: Instruction = Instruction::decode_from(info.opcode_value).unwrap();
+            let executed_cycles: u64 = processor.execute(instruction).unwrap();
+            assert!(executed_cycles >= cycles, "{}", info.mnemonic);
+        }
+    }
+}
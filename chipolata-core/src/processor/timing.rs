@@ -0,0 +1,283 @@
+use super::*;
+
+// Approximate HP48 Saturn processor cycle costs for the CHIP-48 and SUPER-CHIP interpreters,
+// used to pace execution when HP48 variable cycle timing is selected.  Unlike the COSMAC VIP
+// timings (which are returned by each execute method, and follow Laurence Scotford's
+// documentation of the cycles taken by each opcode of the original interpreter), there is no
+// comparable per-opcode documentation of the HP48 interpreters to draw on, so these figures are
+// not measurements.  They are rough estimates of the relative cost of each class of instruction
+// (display operations being far slower than register operations, and sprite drawing scaling
+// with the sprite height), chosen so that programs run at roughly the overall speed of the
+// original calculators.  They should be treated as approximate pacing, not as an emulation of
+// HP48 timing.
+
+/// The cycles taken to fetch and decode an instruction and dispatch to its handler
+const DISPATCH_CYCLES: u64 = 700;
+/// The COSMAC VIP cycles taken to fetch and decode an instruction; used as the cost of unknown
+/// instructions treated as no-ops, whose COSMAC VIP timing is otherwise undefined
+const COSMAC_VIP_DECODE_CYCLES: u64 = 68;
+/// The cycles taken by simple register loads, jumps and timer accesses
+const SIMPLE_CYCLES: u64 = 90;
+/// The cycles taken by register arithmetic and logic instructions
+const ALU_CYCLES: u64 = 160;
+/// The cycles taken by the conditional skip instructions
+const SKIP_CYCLES: u64 = 130;
+/// The cycles taken by subroutine call and return instructions
+const SUBROUTINE_CYCLES: u64 = 150;
+/// The cycles taken to generate a random number
+const RANDOM_CYCLES: u64 = 420;
+/// The cycles taken to scan the calculator keyboard
+const KEYBOARD_SCAN_CYCLES: u64 = 320;
+/// The cycles taken to convert a register to binary-coded decimal
+const BCD_CYCLES: u64 = 520;
+/// The base cycles taken by instructions that copy a run of registers to or from memory
+const REGISTER_COPY_BASE_CYCLES: u64 = 70;
+/// The additional cycles taken for each register copied to or from memory
+const REGISTER_COPY_CYCLES_PER_REGISTER: u64 = 60;
+/// The cycles taken to clear the display
+const CLEAR_DISPLAY_CYCLES: u64 = 2200;
+/// The cycles taken to scroll the display left, right or down
+const SCROLL_DISPLAY_CYCLES: u64 = 3400;
+/// The cycles taken to switch display resolution or exit the interpreter
+const DISPLAY_MODE_CYCLES: u64 = 150;
+/// The base cycles taken to draw a sprite (set up and collision flag handling)
+const DRAW_BASE_CYCLES: u64 = 900;
+/// The additional cycles taken for each sprite byte drawn
+const DRAW_CYCLES_PER_BYTE: u64 = 260;
+
+impl Processor {
+    /// Returns the approximate number of HP48 Saturn processor cycles the CHIP-48 and SUPER-CHIP
+    /// interpreters would take to execute the passed [Instruction], given the current processor
+    /// state.  Only used when pacing execution with HP48 variable cycle timing.
+    ///
+    /// # Arguments
+    ///
+    /// * `instruction` - the instruction about to be executed
+    pub(super) fn hp48_approximate_instruction_cycles(&self, instruction: &Instruction) -> u64 {
+        hp48_approximate_instruction_cycles(
+            instruction,
+            self.emulation_level,
+            self.high_resolution_mode,
+        )
+    }
+
+    /// Returns the number of machine cycles (COSMAC VIP or HP48, as appropriate for the emulation
+    /// level) taken by an unknown instruction treated as a no-op under
+    /// [UnknownInstructionPolicy::Nop]: the cost of fetching and decoding it only.
+    pub(super) fn unknown_instruction_cycles(&self) -> u64 {
+        match self.emulation_level {
+            EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+                COSMAC_VIP_DECODE_CYCLES
+            }
+            EmulationLevel::Chip48 { .. }
+            | EmulationLevel::SuperChip10 { .. }
+            | EmulationLevel::SuperChip11 { .. } => DISPATCH_CYCLES,
+        }
+    }
+}
+
+/// Returns the nominal number of machine cycles (COSMAC VIP or HP48, as appropriate for the
+/// emulation level) taken to execute the passed [Instruction], as reported by
+/// [instruction_set()](crate::instruction_set).  For COSMAC VIP timings this is the minimum cost
+/// (costs that vary do so with the operand values and processor state); for HP48 timings it is
+/// the cost in low-resolution mode.  Returns None where the cost is not defined: for machine
+/// language routines, whose cost depends upon the routine, and for 004B, which is unimplemented.
+///
+/// # Arguments
+///
+/// * `instruction` - the instruction whose cost is required
+/// * `emulation_level` - the emulation level whose timings are to be used
+pub(crate) fn nominal_instruction_cycles(
+    instruction: &Instruction,
+    emulation_level: EmulationLevel,
+) -> Option<u64> {
+    if let Instruction::Op004B | Instruction::Op0NNN { .. } = instruction {
+        return None;
+    }
+    match emulation_level {
+        EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => {
+            cosmac_vip_minimum_instruction_cycles(instruction, emulation_level)
+        }
+        EmulationLevel::Chip48 { .. }
+        | EmulationLevel::SuperChip10 { .. }
+        | EmulationLevel::SuperChip11 { .. } => Some(hp48_approximate_instruction_cycles(
+            instruction,
+            emulation_level,
+            false,
+        )),
+    }
+}
+
+/// Helper function that returns the minimum number of COSMAC VIP cycles taken to execute the
+/// passed [Instruction].  These mirror the costs returned by each execute method, so must be kept
+/// in step with them (which the processor tests check).  Returns None for instructions the
+/// COSMAC VIP interpreters do not support.
+///
+/// # Arguments
+///
+/// * `instruction` - the instruction whose cost is required
+/// * `emulation_level` - the emulation level (CHIP-8 or CHIP-8X)
+fn cosmac_vip_minimum_instruction_cycles(
+    instruction: &Instruction,
+    emulation_level: EmulationLevel,
+) -> Option<u64> {
+    let chip8x: bool = matches!(emulation_level, EmulationLevel::Chip8X { .. });
+    match instruction {
+        Instruction::Op004B
+        | Instruction::Op00CN { .. }
+        | Instruction::Op00FB
+        | Instruction::Op00FC
+        | Instruction::Op00FD
+        | Instruction::Op00FE
+        | Instruction::Op00FF
+        | Instruction::Op0NNN { .. }
+        | Instruction::OpFX30 { .. }
+        | Instruction::OpFX75 { .. }
+        | Instruction::OpFX85 { .. } => None,
+        Instruction::OpEXF2 { .. } | Instruction::OpEXF5 { .. } | Instruction::OpFXF8 { .. }
+            if !chip8x =>
+        {
+            None
+        }
+        Instruction::Op02A0 => Some(48),
+        Instruction::Op00E0 => Some(64),
+        Instruction::OpBNNN { .. } if chip8x => Some(64),
+        Instruction::Op00EE => Some(50),
+        Instruction::Op6XNN { .. } => Some(74),
+        Instruction::Op3XNN { .. }
+        | Instruction::Op4XNN { .. }
+        | Instruction::Op7XNN { .. }
+        | Instruction::OpFX07 { .. }
+        | Instruction::OpFX15 { .. }
+        | Instruction::OpFX18 { .. }
+        | Instruction::OpFXF8 { .. } => Some(78),
+        Instruction::Op1NNN { .. } | Instruction::Op8XY0 { .. } | Instruction::OpANNN { .. } => {
+            Some(80)
+        }
+        Instruction::Op5XY0 { .. }
+        | Instruction::Op9XY0 { .. }
+        | Instruction::OpEX9E { .. }
+        | Instruction::OpEXA1 { .. }
+        | Instruction::OpEXF2 { .. }
+        | Instruction::OpEXF5 { .. } => Some(82),
+        Instruction::OpFX1E { .. } => Some(84),
+        Instruction::OpFX29 { .. } => Some(88),
+        Instruction::OpBNNN { .. } => Some(90),
+        Instruction::Op2NNN { .. } => Some(94),
+        Instruction::OpFX55 { .. } | Instruction::OpFX65 { .. } => Some(100),
+        Instruction::OpCXNN { .. } => Some(104),
+        Instruction::Op5XY1 { .. }
+        | Instruction::Op8XY1 { .. }
+        | Instruction::Op8XY2 { .. }
+        | Instruction::Op8XY3 { .. }
+        | Instruction::Op8XY4 { .. }
+        | Instruction::Op8XY5 { .. }
+        | Instruction::Op8XY6 { .. }
+        | Instruction::Op8XY7 { .. }
+        | Instruction::Op8XYE { .. } => Some(112),
+        Instruction::OpFX33 { .. } => Some(152),
+        Instruction::OpDXYN { .. } => Some(238),
+        Instruction::OpFX0A { .. } => Some(19072),
+    }
+}
+
+/// Helper function that returns the approximate number of HP48 Saturn processor cycles the
+/// CHIP-48 and SUPER-CHIP interpreters would take to execute the passed [Instruction] (see the
+/// note at the top of this file on how the figures were arrived at)
+///
+/// # Arguments
+///
+/// * `instruction` - the instruction about to be executed
+/// * `emulation_level` - the emulation level being emulated
+/// * `high_resolution_mode` - whether SUPER-CHIP 1.1 high-resolution mode is enabled
+fn hp48_approximate_instruction_cycles(
+    instruction: &Instruction,
+    emulation_level: EmulationLevel,
+    high_resolution_mode: bool,
+) -> u64 {
+    let execution_cycles: u64 = match instruction {
+        Instruction::Op00CN { .. } | Instruction::Op00FB | Instruction::Op00FC => {
+            SCROLL_DISPLAY_CYCLES
+        }
+        Instruction::Op00E0 => CLEAR_DISPLAY_CYCLES,
+        Instruction::Op00FD | Instruction::Op00FE | Instruction::Op00FF => DISPLAY_MODE_CYCLES,
+        Instruction::Op00EE | Instruction::Op2NNN { .. } => SUBROUTINE_CYCLES,
+        Instruction::Op004B
+        | Instruction::Op02A0
+        | Instruction::Op0NNN { .. }
+        | Instruction::OpEXF2 { .. }
+        | Instruction::OpEXF5 { .. }
+        | Instruction::OpFXF8 { .. } => 0, // not supported on the HP48
+        Instruction::Op1NNN { .. }
+        | Instruction::Op6XNN { .. }
+        | Instruction::OpANNN { .. }
+        | Instruction::OpFX07 { .. }
+        | Instruction::OpFX15 { .. }
+        | Instruction::OpFX18 { .. } => SIMPLE_CYCLES,
+        Instruction::Op3XNN { .. }
+        | Instruction::Op4XNN { .. }
+        | Instruction::Op5XY0 { .. }
+        | Instruction::Op5XY1 { .. }
+        | Instruction::Op9XY0 { .. } => SKIP_CYCLES,
+        Instruction::Op7XNN { .. }
+        | Instruction::Op8XY0 { .. }
+        | Instruction::Op8XY1 { .. }
+        | Instruction::Op8XY2 { .. }
+        | Instruction::Op8XY3 { .. }
+        | Instruction::Op8XY4 { .. }
+        | Instruction::Op8XY5 { .. }
+        | Instruction::Op8XY6 { .. }
+        | Instruction::Op8XY7 { .. }
+        | Instruction::Op8XYE { .. }
+        | Instruction::OpBNNN { .. }
+        | Instruction::OpFX1E { .. }
+        | Instruction::OpFX29 { .. }
+        | Instruction::OpFX30 { .. } => ALU_CYCLES,
+        Instruction::OpCXNN { .. } => RANDOM_CYCLES,
+        Instruction::OpDXYN { n, .. } => {
+            DRAW_BASE_CYCLES + hp48_draw_cycles(emulation_level, high_resolution_mode, *n)
+        }
+        Instruction::OpEX9E { .. } | Instruction::OpEXA1 { .. } | Instruction::OpFX0A { .. } => {
+            KEYBOARD_SCAN_CYCLES
+        }
+        Instruction::OpFX33 { .. } => BCD_CYCLES,
+        Instruction::OpFX55 { x }
+        | Instruction::OpFX65 { x }
+        | Instruction::OpFX75 { x }
+        | Instruction::OpFX85 { x } => {
+            REGISTER_COPY_BASE_CYCLES + (*x as u64 + 1) * REGISTER_COPY_CYCLES_PER_REGISTER
+        }
+    };
+    DISPATCH_CYCLES + execution_cycles
+}
+
+/// Helper function that returns the cycles taken to draw the sprite bytes of a DXYN instruction.
+/// SUPER-CHIP draws 16x16 sprites (two bytes per row) when N is zero in high-resolution mode,
+/// and doubles each sprite pixel (so draws each byte twice) in low-resolution mode, where
+/// SUPER-CHIP 1.0 draws 8x16 sprites when N is zero.
+///
+/// # Arguments
+///
+/// * `emulation_level` - the emulation level being emulated
+/// * `high_resolution_mode` - whether SUPER-CHIP high-resolution mode is enabled
+/// * `n` - the height of the sprite in rows (or 0 for a SUPER-CHIP 16x16 or 8x16 sprite)
+fn hp48_draw_cycles(emulation_level: EmulationLevel, high_resolution_mode: bool, n: u8) -> u64 {
+    let rows: u64 = n as u64;
+    let bytes_drawn: u64 = match emulation_level {
+        EmulationLevel::SuperChip10 { .. } => match (high_resolution_mode, n) {
+            (true, 0) => (MAX_SPRITE_HEIGHT as u64 + 1) * 2,
+            (true, _) => rows,
+            (false, 0) => SUPERCHIP10_LOW_RES_DXY0_HEIGHT as u64 * 2,
+            (false, _) => rows * 2,
+        },
+        EmulationLevel::SuperChip11 { .. } => match (high_resolution_mode, n) {
+            (true, 0) => (MAX_SPRITE_HEIGHT as u64 + 1) * 2,
+            (true, _) => rows,
+            (false, _) => rows * 2,
+        },
+        EmulationLevel::Chip8 { .. }
+        | EmulationLevel::Chip8X { .. }
+        | EmulationLevel::Chip48 { .. } => rows,
+    };
+    bytes_drawn * DRAW_CYCLES_PER_BYTE
+}
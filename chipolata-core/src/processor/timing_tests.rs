@@ -1,5 +1,5 @@
 use super::*;
-use crate::{program::Program, COSMAC_VIP_PROCESSOR_SPEED_HERTZ};
+use crate::{program::Program, COSMAC_VIP_PROCESSOR_SPEED_HERTZ, HP48_PROCESSOR_SPEED_HERTZ};
 use std::time::{Duration, Instant};
 
 fn get_variable_timing_options() -> Options {
@@ -92,6 +92,82 @@ fn test_calculate_cycle_duration_fixed() {
     );
 }
 
+#[test]
+fn test_calculate_cycle_duration_timing_mode_fixed() {
+    let mut options: Options = Options::default();
+    options.timing_mode = Some(TimingMode::Fixed {
+        instruction_microseconds: 250,
+    });
+    let processor = Processor::initialise_and_load(Program::default(), options).unwrap();
+    assert_eq!(
+        processor.calculate_cycle_duration(100),
+        Duration::from_micros(250)
+    );
+    assert_eq!(processor.interval_cycles(1_000), 4);
+}
+
+#[test]
+fn test_calculate_cycle_duration_timing_mode_cosmac_variable() {
+    let mut options: Options = get_variable_timing_options();
+    options.emulation_level = EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    };
+    options.timing_mode = Some(TimingMode::CosmacVariable);
+    let processor = Processor::initialise_and_load(Program::default(), options).unwrap();
+    let expected_result: u64 = COSMAC_VIP_MACHINE_CYCLES_PER_CYCLE * 100_u64 * 1_000_000_u64
+        / processor.processor_speed_hertz;
+    assert_eq!(
+        processor.calculate_cycle_duration(100),
+        Duration::from_micros(expected_result)
+    );
+}
+
+#[test]
+fn test_calculate_cycle_duration_timing_mode_instructions_per_frame() {
+    let mut options: Options = get_variable_timing_options();
+    options.timing_mode = Some(TimingMode::InstructionsPerFrame { instructions: 1000 });
+    let processor = Processor::initialise_and_load(Program::default(), options).unwrap();
+    // Variable cycle timing is overridden, so the machine cycles are ignored
+    assert_eq!(
+        processor.calculate_cycle_duration(100),
+        Duration::from_nanos(16_666)
+    );
+    assert_eq!(
+        processor.interval_cycles(processor.timer_interval_microseconds),
+        1000
+    );
+}
+
+#[test]
+fn test_timing_mode_instructions_per_frame_cycle_synced() {
+    let program: Program = Program::new(vec![0x12, 0x00]); // jump to self
+    let mut options: Options = Options::default();
+    options.timing_mode = Some(TimingMode::InstructionsPerFrame { instructions: 30 });
+    let mut processor = Processor::initialise_and_load(program, options).unwrap();
+    processor.run_frames(2).unwrap();
+    assert_eq!(processor.frames, 2);
+    assert_eq!(processor.cycles, 60);
+}
+
+#[test]
+fn test_timing_mode_zero_error() {
+    let mut options: Options = Options::default();
+    options.timing_mode = Some(TimingMode::InstructionsPerFrame { instructions: 0 });
+    assert!(matches!(
+        Processor::initialise_and_load(Program::default(), options),
+        Err(ChipolataError {
+            inner_error: ErrorDetail::OperandsOutOfBounds { .. },
+            ..
+        })
+    ));
+    options.timing_mode = Some(TimingMode::Fixed {
+        instruction_microseconds: 0,
+    });
+    let mut processor = setup_test_processor_fixed_timing();
+    assert!(processor.apply_options(&options).is_err());
+}
+
 #[test]
 fn test_execute_00E0_timing() {
     const EXPECTED_CYCLES: u64 = 64;
@@ -425,3 +501,129 @@ fn test_execute_FX65_timing() {
     let mut processor: Processor = setup_test_processor_variable_timing();
     assert_eq!(processor.execute_FX65(0x7).unwrap(), EXPECTED_CYCLES);
 }
+
+fn setup_test_processor_hp48_variable_timing() -> Processor {
+    let program: Program = Program::default();
+    let options: Options = Options::new(
+        HP48_PROCESSOR_SPEED_HERTZ,
+        EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: true,
+        },
+    );
+    Processor::initialise_and_load(program, options).unwrap()
+}
+
+#[test]
+fn test_calculate_cycle_duration_hp48_variable() {
+    let processor = setup_test_processor_hp48_variable_timing();
+    let expected_result: u64 = 2000_u64 * 1_000_000_u64 / processor.processor_speed_hertz;
+    assert_eq!(
+        processor.calculate_cycle_duration(2000),
+        Duration::from_micros(expected_result)
+    );
+}
+
+#[test]
+fn test_hp48_6XNN_timing() {
+    const EXPECTED_CYCLES: u64 = 700 + 90;
+    let processor: Processor = setup_test_processor_hp48_variable_timing();
+    assert_eq!(
+        processor.hp48_approximate_instruction_cycles(&Instruction::Op6XNN { x: 0x1, nn: 0xFF }),
+        EXPECTED_CYCLES
+    );
+}
+
+#[test]
+fn test_hp48_FX55_timing() {
+    const EXPECTED_CYCLES: u64 = 700 + 70 + (60 * 8); // 0x7 registers + 1
+    let processor: Processor = setup_test_processor_hp48_variable_timing();
+    assert_eq!(
+        processor.hp48_approximate_instruction_cycles(&Instruction::OpFX55 { x: 0x7 }),
+        EXPECTED_CYCLES
+    );
+}
+
+#[test]
+fn test_hp48_DXYN_low_res_timing() {
+    const EXPECTED_CYCLES: u64 = 700 + 900 + (260 * 5 * 2); // each byte drawn twice in low-res
+    let processor: Processor = setup_test_processor_hp48_variable_timing();
+    assert_eq!(
+        processor.hp48_approximate_instruction_cycles(&Instruction::OpDXYN { x: 0, y: 0, n: 5 }),
+        EXPECTED_CYCLES
+    );
+}
+
+#[test]
+fn test_hp48_DXY0_high_res_timing() {
+    const EXPECTED_CYCLES: u64 = 700 + 900 + (260 * 32); // 16 rows of 2 bytes
+    let mut processor: Processor = setup_test_processor_hp48_variable_timing();
+    processor.high_resolution_mode = true;
+    assert_eq!(
+        processor.hp48_approximate_instruction_cycles(&Instruction::OpDXYN { x: 0, y: 0, n: 0 }),
+        EXPECTED_CYCLES
+    );
+}
+
+#[test]
+fn test_timing_statistics() {
+    let program: Program = Program::new(vec![0x12, 0x00]); // 0x200 loop
+    let mut options: Options = Options::default();
+    options.processor_speed_hertz = 10000;
+    let mut processor = Processor::initialise_and_load(program, options).unwrap();
+    for _ in 0..10 {
+        processor.execute_cycle().unwrap();
+    }
+    let statistics: TimingStatistics = processor.timing_statistics();
+    // Cycles may run up to one batch ahead of schedule before execution is paced
+    assert!(
+        statistics.cycles_measured == 9
+            && statistics.average_target_cycle_duration == Duration::from_micros(100)
+            && statistics.drift_microseconds >= -(PACING_BATCH_MICROSECONDS as i64)
+    );
+}
+
+#[test]
+fn test_timing_statistics_exclude_pause_and_turbo() {
+    let program: Program = Program::new(vec![0x12, 0x00]); // 0x200 loop
+    let mut processor = Processor::initialise_and_load(program, Options::default()).unwrap();
+    processor.execute_cycle().unwrap();
+    processor.execute_cycle().unwrap();
+    processor.pause_execution().unwrap();
+    processor.resume_execution().unwrap();
+    processor.execute_cycle().unwrap();
+    processor.run_frames(1).unwrap();
+    processor.execute_cycle().unwrap();
+    assert_eq!(processor.timing_statistics().cycles_measured, 1);
+}
+
+#[test]
+fn test_pacing_keeps_to_schedule() {
+    let program: Program = Program::new(vec![0x12, 0x00]); // 0x200 loop
+    let mut options: Options = Options::default();
+    options.processor_speed_hertz = 1000;
+    let start_time: Instant = Instant::now();
+    let mut processor = Processor::initialise_and_load(program, options).unwrap();
+    for _ in 0..20 {
+        processor.execute_cycle().unwrap();
+    }
+    // 20 cycles at 1000hz take 20ms, less at most one batch executed ahead of schedule
+    assert!(start_time.elapsed() >= Duration::from_micros(20_000 - PACING_BATCH_MICROSECONDS));
+}
+
+#[test]
+fn test_pacing_limits_catch_up() {
+    let program: Program = Program::new(vec![0x12, 0x00]); // 0x200 loop
+    let mut options: Options = Options::default();
+    options.processor_speed_hertz = 1000;
+    let mut processor = Processor::initialise_and_load(program, options).unwrap();
+    processor.execute_cycle().unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+    // Having fallen 20ms behind, only one batch of cycles may be run to catch up (plus one batch
+    // ahead of schedule)
+    let start_time: Instant = Instant::now();
+    for _ in 0..10 {
+        processor.execute_cycle().unwrap();
+    }
+    assert!(start_time.elapsed() >= Duration::from_micros(10_000 - 2 * PACING_BATCH_MICROSECONDS));
+}
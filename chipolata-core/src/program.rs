@@ -0,0 +1,758 @@
+use crate::analysis::is_superchip_instruction;
+use crate::error::ErrorDetail;
+use crate::instruction::Instruction;
+#[cfg(feature = "std")]
+use crate::memory::CHIPOLATA_MEMORY_SIZE_BYTES;
+use crate::options::DEFAULT_PROGRAM_ADDRESS;
+use crate::prelude::*;
+use crate::processor::{
+    EmulationLevel, CHIP8_HIRES_ENTRY_OPCODE, CHIP8_HIRES_PROGRAM_START_ADDRESS,
+};
+#[cfg(feature = "std")]
+use std::fs::{self, File};
+#[cfg(feature = "std")]
+use std::io::Read;
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use zip::ZipArchive;
+
+/// The file extensions (compared case-insensitively) identifying CHIP-8 ROM files, including
+/// the entries within zip archives loaded by [Program::load_from_archive()]
+pub const PROGRAM_FILE_EXTENSIONS: [&str; 3] = ["ch8", "sc8", "c8x"];
+
+/// The largest program (in bytes) that will be read from a zip archive entry; no program can
+/// be larger than the emulated memory, so larger entries are rejected rather than decompressed
+#[cfg(feature = "std")]
+const MAX_PROGRAM_SIZE: usize = CHIPOLATA_MEMORY_SIZE_BYTES;
+
+/// An enum to indicate the format in which program data is encoded, for use with
+/// [Program::load_from_bytes_with_format()] and [Program::load_from_file_with_format()].
+///
+/// Only raw binary images and plain hex text dumps are supported.  Files with binary headers
+/// and Octo "cartridges" (programs embedded in GIF images) are not recognised: they are treated
+/// as [ProgramFormat::Binary] by [ProgramFormat::Auto], so must be converted to a raw binary
+/// image before loading.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProgramFormat {
+    /// Detect the format from the content of the data: data consisting solely of text in the
+    /// [ProgramFormat::HexText] format is treated as such, and anything else as binary
+    Auto,
+    /// A raw binary ROM image (as used by `.ch8`, `.sc8` and `.c8x` files)
+    Binary,
+    /// A plain text dump of the program bytes in hexadecimal.  Bytes may be written
+    /// individually (optionally prefixed with `0x` or `$`) or as runs of hex digit pairs,
+    /// separated by whitespace or commas.  Comments beginning `#`, `;` or `//` run to the end of
+    /// the line, so lines of commentary describing the program may be included.
+    HexText,
+}
+
+/// A summary of a [Program]'s data, as returned by [Program::analyse()], for display to the user
+/// (for example in a ROM information dialogue) and to help in choosing an emulation level.  The
+/// instruction scan is heuristic: instructions cannot be told apart from sprites and other data,
+/// so instructions may be "found" within data areas.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgramAnalysis {
+    /// The size of the program data, in bytes
+    pub size_bytes: usize,
+    /// The CRC-32 checksum of the program data
+    pub crc32: u32,
+    /// The SHA-1 digest of the program data, as a lower-case hexadecimal string (as used to
+    /// identify ROMs in community ROM databases)
+    pub sha1: String,
+    /// The address at which execution is expected to begin, if the program is loaded at the
+    /// default program start address: 0x2C0 for Hi-Res CHIP-8 programs, otherwise 0x200
+    pub inferred_start_address: u16,
+    /// Whether the program begins with the jump to 0x260 with which Hi-Res CHIP-8 programs begin
+    pub hires_chip8_entry: bool,
+    /// The addresses (if loaded at the default program start address) of any instructions found
+    /// that are specific to SUPER-CHIP 1.1
+    pub superchip_instruction_addresses: Vec<u16>,
+}
+
+impl ProgramAnalysis {
+    /// Returns true if any instructions specific to SUPER-CHIP 1.1 were found in the program
+    pub fn uses_superchip_instructions(&self) -> bool {
+        !self.superchip_instruction_addresses.is_empty()
+    }
+
+    /// Returns true unless the analysis suggests the program was not written for the specified
+    /// emulation level: that is, if SUPER-CHIP instructions were found but the level is not
+    /// SUPER-CHIP 1.0 or 1.1, or the program looks like a Hi-Res CHIP-8 program but the level is not
+    /// CHIP-8 (CHIP-8X being treated as suiting any program without such indicators)
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the emulation level to check the program against
+    pub fn suits_emulation_level(&self, emulation_level: EmulationLevel) -> bool {
+        match emulation_level {
+            EmulationLevel::Chip8 { .. } => !self.uses_superchip_instructions(),
+            EmulationLevel::Chip8X { .. } | EmulationLevel::Chip48 { .. } => {
+                !self.uses_superchip_instructions() && !self.hires_chip8_entry
+            }
+            EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
+                !self.hires_chip8_entry
+            }
+        }
+    }
+}
+
+/// An abstraction of a CHIP-8 ROM, ready for loading into the Chipolata emulator.
+#[derive(Debug, PartialEq)]
+pub struct Program {
+    /// A byte vector containing the program data as loading from the ROM.
+    program_data: Vec<u8>,
+}
+
+impl Default for Program {
+    /// Constructor that returns an empty [Program] instance.
+    fn default() -> Self {
+        Program {
+            program_data: Vec::new(),
+        }
+    }
+}
+
+impl Program {
+    /// Constructor that returns a [Program] instance representing the passed program data.
+    pub fn new(data: Vec<u8>) -> Self {
+        Program { program_data: data }
+    }
+
+    /// Builder method that instantiates [Program] from the specified binary ROM file
+    #[cfg(feature = "std")]
+    pub fn load_from_file(file_path: &Path) -> Result<Program, ErrorDetail> {
+        // attempt to open the file and read as a byte vector
+        if let Ok(program_data) = fs::read(file_path) {
+            return Ok(Program::new(program_data));
+        }
+        // if we fall through to here, an error has occurred reading from the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Returns the names of the CHIP-8 ROM entries (those with one of the
+    /// [PROGRAM_FILE_EXTENSIONS]) within the specified zip archive, in the order they are stored,
+    /// so that the user can choose which to load with [Program::load_from_archive()].  Returns
+    /// [ErrorDetail::FileError] if the file cannot be read as a zip archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the location of the zip archive
+    #[cfg(feature = "std")]
+    pub fn list_archive_entries(file_path: &Path) -> Result<Vec<String>, ErrorDetail> {
+        let mut archive: ZipArchive<File> = Program::open_archive(file_path)?;
+        // the entries are read by index, as ZipArchive::file_names() does not keep archive order
+        let mut entries: Vec<String> = Vec::new();
+        for index in 0..archive.len() {
+            let entry = archive
+                .by_index_raw(index)
+                .map_err(|_| ErrorDetail::FileError {
+                    file_path: file_path.to_str().unwrap_or_default().to_owned(),
+                })?;
+            if Program::is_program_file(Path::new(entry.name())) {
+                entries.push(entry.name().to_owned());
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Builder method that instantiates [Program] from a binary ROM held in the specified zip
+    /// archive (as many ROM packs are distributed).  The entry to load is named as returned by
+    /// [Program::list_archive_entries()]; if no name is given, the first CHIP-8 ROM entry in the
+    /// archive is loaded.  Returns [ErrorDetail::ArchiveEntryNotFound] if there is no such entry,
+    /// [ErrorDetail::MalformedProgram] if the entry is too large to fit in memory, or
+    /// [ErrorDetail::FileError] if the archive cannot be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the location of the zip archive
+    /// * `entry` - the name of the entry to load, or None to load the first CHIP-8 ROM entry
+    #[cfg(feature = "std")]
+    pub fn load_from_archive(
+        file_path: &Path,
+        entry: Option<&str>,
+    ) -> Result<Program, ErrorDetail> {
+        let entry_name: String = match entry {
+            Some(name) => name.to_owned(),
+            None => match Program::list_archive_entries(file_path)?.into_iter().next() {
+                Some(name) => name,
+                None => {
+                    return Err(ErrorDetail::ArchiveEntryNotFound {
+                        file_path: file_path.to_str().unwrap_or_default().to_owned(),
+                        entry: None,
+                    })
+                }
+            },
+        };
+        let mut archive: ZipArchive<File> = Program::open_archive(file_path)?;
+        let mut program_data: Vec<u8> = Vec::new();
+        match archive.by_name(&entry_name) {
+            Ok(file) => {
+                // read at most one byte beyond the limit, so that an oversized (or maliciously
+                // compressed) entry is detected without being decompressed in full
+                if file
+                    .take(MAX_PROGRAM_SIZE as u64 + 1)
+                    .read_to_end(&mut program_data)
+                    .is_err()
+                {
+                    return Err(ErrorDetail::FileError {
+                        file_path: file_path.to_str().unwrap_or_default().to_owned(),
+                    });
+                }
+                if program_data.len() > MAX_PROGRAM_SIZE {
+                    return Err(ErrorDetail::MalformedProgram {
+                        line: 1,
+                        detail: format!(
+                            "archive entry '{}' is larger than {} bytes",
+                            entry_name, MAX_PROGRAM_SIZE
+                        ),
+                    });
+                }
+            }
+            Err(_) => {
+                return Err(ErrorDetail::ArchiveEntryNotFound {
+                    file_path: file_path.to_str().unwrap_or_default().to_owned(),
+                    entry: Some(entry_name),
+                })
+            }
+        }
+        Ok(Program::new(program_data))
+    }
+
+    /// Returns true if the specified file path has a `.zip` extension (case-insensitive), in
+    /// which case it should be loaded with [Program::load_from_archive()]
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the file path to check
+    #[cfg(feature = "std")]
+    pub fn is_archive_file(file_path: &Path) -> bool {
+        match file_path.extension() {
+            Some(extension) => extension.eq_ignore_ascii_case("zip"),
+            None => false,
+        }
+    }
+
+    /// Returns true if the specified file path has one of the [PROGRAM_FILE_EXTENSIONS]
+    /// (case-insensitive)
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the file path to check
+    #[cfg(feature = "std")]
+    pub fn is_program_file(file_path: &Path) -> bool {
+        match file_path.extension() {
+            Some(extension) => PROGRAM_FILE_EXTENSIONS
+                .iter()
+                .any(|program_extension| extension.eq_ignore_ascii_case(program_extension)),
+            None => false,
+        }
+    }
+
+    /// Helper function that opens the specified zip archive for reading
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the location of the zip archive
+    #[cfg(feature = "std")]
+    fn open_archive(file_path: &Path) -> Result<ZipArchive<File>, ErrorDetail> {
+        match File::open(file_path).map(ZipArchive::new) {
+            Ok(Ok(archive)) => Ok(archive),
+            _ => Err(ErrorDetail::FileError {
+                file_path: file_path.to_str().unwrap_or_default().to_owned(),
+            }),
+        }
+    }
+
+    /// Builder method that instantiates [Program] from the specified file, decoding the file
+    /// contents according to the specified [ProgramFormat]
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the location of the file to load
+    /// * `format` - the format of the file contents (or [ProgramFormat::Auto] to detect it)
+    #[cfg(feature = "std")]
+    pub fn load_from_file_with_format(
+        file_path: &Path,
+        format: ProgramFormat,
+    ) -> Result<Program, ErrorDetail> {
+        match fs::read(file_path) {
+            Ok(data) => Program::load_from_bytes_with_format(&data, format),
+            Err(_) => Err(ErrorDetail::FileError {
+                file_path: file_path.to_str().unwrap_or_default().to_owned(),
+            }),
+        }
+    }
+
+    /// Builder method that instantiates [Program] from the passed bytes, decoding them according
+    /// to the specified [ProgramFormat].  Returns [ErrorDetail::MalformedProgram] if the data
+    /// cannot be decoded in that format.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the encoded program data
+    /// * `format` - the format of the data (or [ProgramFormat::Auto] to detect it)
+    pub fn load_from_bytes_with_format(
+        data: &[u8],
+        format: ProgramFormat,
+    ) -> Result<Program, ErrorDetail> {
+        match format {
+            ProgramFormat::Auto => {
+                Program::load_from_bytes_with_format(data, Program::detect_format(data))
+            }
+            ProgramFormat::Binary => Ok(Program::new(data.to_vec())),
+            ProgramFormat::HexText => match core::str::from_utf8(data) {
+                Ok(text) => Ok(Program::new(Program::parse_hex_text(text)?)),
+                Err(e) => Err(ErrorDetail::MalformedProgram {
+                    line: text_line_number(&data[..e.valid_up_to()]),
+                    detail: "data is not valid text".to_string(),
+                }),
+            },
+        }
+    }
+
+    /// Returns the [ProgramFormat] the passed data appears to be encoded in: data that is valid
+    /// [ProgramFormat::HexText] (containing at least one byte) is assumed to be so, and anything
+    /// else is assumed to be [ProgramFormat::Binary]
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the encoded program data
+    pub fn detect_format(data: &[u8]) -> ProgramFormat {
+        match core::str::from_utf8(data).map(Program::parse_hex_text) {
+            Ok(Ok(_)) => ProgramFormat::HexText,
+            _ => ProgramFormat::Binary,
+        }
+    }
+
+    /// Helper function that decodes [ProgramFormat::HexText] data into program bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - the hex text to decode
+    fn parse_hex_text(text: &str) -> Result<Vec<u8>, ErrorDetail> {
+        let mut bytes: Vec<u8> = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            // Discard any comment running to the end of the line
+            let content: &str = match line.find(['#', ';']) {
+                Some(position) => &line[..position],
+                None => line,
+            };
+            let content: &str = match content.find("//") {
+                Some(position) => &content[..position],
+                None => content,
+            };
+            for token in content
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|token| !token.is_empty())
+            {
+                let digits: &str = token
+                    .strip_prefix("0x")
+                    .or_else(|| token.strip_prefix("0X"))
+                    .or_else(|| token.strip_prefix('$'))
+                    .unwrap_or(token);
+                if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(ErrorDetail::MalformedProgram {
+                        line: index + 1,
+                        detail: format!("'{}' is not a hexadecimal value", token),
+                    });
+                }
+                if !digits.len().is_multiple_of(2) {
+                    return Err(ErrorDetail::MalformedProgram {
+                        line: index + 1,
+                        detail: format!("'{}' has an odd number of hexadecimal digits", token),
+                    });
+                }
+                for pair in digits.as_bytes().chunks(2) {
+                    // Safe to unwrap, as the digits have already been validated as ASCII hex
+                    let pair: &str = core::str::from_utf8(pair).unwrap();
+                    bytes.push(u8::from_str_radix(pair, 16).unwrap());
+                }
+            }
+        }
+        if bytes.is_empty() {
+            return Err(ErrorDetail::MalformedProgram {
+                line: text.lines().count().max(1),
+                detail: "no program data was found".to_string(),
+            });
+        }
+        Ok(bytes)
+    }
+
+    /// Method that serialises the passed [Program] instance to the specified binary file
+    #[cfg(feature = "std")]
+    pub fn save_to_file(program: &Program, file_path: &Path) -> Result<(), ErrorDetail> {
+        // attempt to open the file and write to it; create it if it does not exist and truncate if it does
+        if let Ok(_) = fs::write(file_path, &program.program_data) {
+            return Ok(());
+        }
+        // if we fall through to here, an error has occurred writing to the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Sets the program data as per the specified byte vector.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - the byte vector containing the program data to use
+    pub fn set_program_data(&mut self, data: Vec<u8>) -> Result<(), ErrorDetail> {
+        self.program_data = data;
+        Ok(())
+    }
+
+    /// Returns a reference to the program data held in this instance.
+    pub fn program_data(&self) -> &Vec<u8> {
+        &self.program_data
+    }
+
+    /// Returns the size of the instance's program data (in bytes).
+    pub fn program_data_size(&self) -> usize {
+        self.program_data.len()
+    }
+
+    /// Returns the CRC-32 checksum (as used by zip files and most ROM managers) of the program data.
+    pub fn crc32(&self) -> u32 {
+        let mut crc: u32 = 0xFFFFFFFF;
+        for byte in &self.program_data {
+            crc ^= *byte as u32;
+            for _ in 0..8 {
+                // Shift out each bit in turn, applying the (reversed) polynomial if it was set
+                crc = (crc >> 1) ^ (0xEDB88320 & (crc & 1).wrapping_neg());
+            }
+        }
+        !crc
+    }
+
+    /// Returns the SHA-1 digest of the program data, as a lower-case hexadecimal string.
+    pub fn sha1(&self) -> String {
+        sha1_digest(&self.program_data)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Returns a [ProgramAnalysis] of the program data, summarising its size and checksums and
+    /// the results of a heuristic scan for the instructions it uses.
+    pub fn analyse(&self) -> ProgramAnalysis {
+        let hires_chip8_entry: bool = self.program_data.starts_with(&CHIP8_HIRES_ENTRY_OPCODE);
+        // Instructions are assumed to be aligned to even addresses, as is almost always the case
+        let superchip_instruction_addresses: Vec<u16> = self
+            .program_data
+            .chunks_exact(2)
+            .enumerate()
+            .filter(|(_, opcode)| {
+                Instruction::decode_from(u16::from_be_bytes([opcode[0], opcode[1]]))
+                    .is_ok_and(|instruction| is_superchip_instruction(&instruction))
+            })
+            .map(|(index, _)| DEFAULT_PROGRAM_ADDRESS.wrapping_add(index as u16 * 2))
+            .collect();
+        ProgramAnalysis {
+            size_bytes: self.program_data_size(),
+            crc32: self.crc32(),
+            sha1: self.sha1(),
+            inferred_start_address: match hires_chip8_entry {
+                true => CHIP8_HIRES_PROGRAM_START_ADDRESS,
+                false => DEFAULT_PROGRAM_ADDRESS,
+            },
+            hires_chip8_entry,
+            superchip_instruction_addresses,
+        }
+    }
+}
+
+/// Helper function that returns the SHA-1 digest of the passed data
+fn sha1_digest(data: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+    // Pad the message with a single set bit, then zeroes up to 8 bytes short of a multiple of
+    // 64 bytes, then the message length in bits
+    let mut message: Vec<u8> = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0x0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+    for block in message.chunks_exact(64) {
+        let mut words: [u32; 80] = [0; 80];
+        for (index, word) in block.chunks_exact(4).enumerate() {
+            words[index] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for index in 16..80 {
+            words[index] =
+                (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16])
+                    .rotate_left(1);
+        }
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (index, word) in words.iter().enumerate() {
+            let (f, k): (u32, u32) = match index {
+                0..=19 => ((b & c) | (!b & d), 0x5A827999),
+                20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+                _ => (b ^ c ^ d, 0xCA62C1D6),
+            };
+            let temp: u32 = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (value, working) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(working);
+        }
+    }
+    let mut digest: [u8; 20] = [0; 20];
+    for (bytes, value) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+/// Helper function that returns the (1-based) line number reached at the end of the passed bytes
+fn text_line_number(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|&&byte| byte == b'\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_test_program() -> Vec<u8> {
+        vec![0xA1, 0x14, 0x0C, 0xFD, 0xA3]
+    }
+
+    #[test]
+    fn test_program_data() {
+        let mut program: Program = Program::default();
+        let test_program: Vec<u8> = setup_test_program();
+        program.set_program_data(test_program.clone()).unwrap();
+        assert_eq!(program.program_data(), &test_program);
+    }
+
+    #[test]
+    fn test_program_data_size() {
+        let mut program: Program = Program::default();
+        let test_program: Vec<u8> = setup_test_program();
+        program.set_program_data(test_program.clone()).unwrap();
+        assert_eq!(program.program_data_size(), test_program.len());
+    }
+
+    #[test]
+    fn test_checksums() {
+        let program: Program = Program::new(b"123456789".to_vec());
+        assert!(
+            program.crc32() == 0xCBF43926
+                && program.sha1() == "f7c3bc1d808e04732adf679965ccc34ca7ae3441"
+                && Program::default().sha1() == "da39a3ee5e6b4b0d3255bfef95601890afd80709"
+        );
+    }
+
+    #[test]
+    fn test_analyse_superchip() {
+        let program: Program = Program::new(vec![0x00, 0xE0, 0x00, 0xFF, 0xA2, 0x00, 0xF3, 0x30]);
+        let analysis: ProgramAnalysis = program.analyse();
+        assert_eq!(analysis.superchip_instruction_addresses, vec![0x202, 0x206]);
+        assert!(
+            analysis.size_bytes == 8
+                && analysis.inferred_start_address == 0x200
+                && !analysis.suits_emulation_level(EmulationLevel::Chip48 {
+                    variable_cycle_timing: false
+                })
+                && analysis.suits_emulation_level(EmulationLevel::SuperChip11 {
+                    octo_compatibility_mode: false,
+                    variable_cycle_timing: false
+                })
+        );
+    }
+
+    #[test]
+    fn test_analyse_hires_chip8() {
+        let analysis: ProgramAnalysis = Program::new(vec![0x12, 0x60, 0x00, 0xE0]).analyse();
+        assert!(
+            analysis.hires_chip8_entry
+                && analysis.inferred_start_address == 0x2C0
+                && !analysis.uses_superchip_instructions()
+                && analysis.suits_emulation_level(EmulationLevel::Chip8 {
+                    memory_limit_2k: false,
+                    variable_cycle_timing: false
+                })
+        );
+    }
+
+    #[test]
+    fn test_load_hex_text() {
+        let text: &str = "# Test program\n0x60 0x12, $A2 00 // set registers\n\n00E0D015 ; draw\n";
+        let program: Program =
+            Program::load_from_bytes_with_format(text.as_bytes(), ProgramFormat::HexText).unwrap();
+        assert_eq!(
+            program.program_data(),
+            &vec![0x60, 0x12, 0xA2, 0x00, 0x00, 0xE0, 0xD0, 0x15]
+        );
+    }
+
+    #[test]
+    fn test_load_hex_text_invalid_token() {
+        let text: &str = "60 12\nA2 0G\n";
+        assert_eq!(
+            Program::load_from_bytes_with_format(text.as_bytes(), ProgramFormat::HexText)
+                .unwrap_err(),
+            ErrorDetail::MalformedProgram {
+                line: 2,
+                detail: "'0G' is not a hexadecimal value".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_load_hex_text_odd_digits() {
+        let text: &str = "6012A";
+        assert!(matches!(
+            Program::load_from_bytes_with_format(text.as_bytes(), ProgramFormat::HexText),
+            Err(ErrorDetail::MalformedProgram { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn test_load_hex_text_empty() {
+        let text: &str = "# comment only\n";
+        assert!(matches!(
+            Program::load_from_bytes_with_format(text.as_bytes(), ProgramFormat::HexText),
+            Err(ErrorDetail::MalformedProgram { .. })
+        ));
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert!(
+            Program::detect_format(b"0x60 0x12") == ProgramFormat::HexText
+                && Program::detect_format(&setup_test_program()) == ProgramFormat::Binary
+                && Program::detect_format(&[]) == ProgramFormat::Binary
+        );
+    }
+
+    #[test]
+    fn test_load_auto_binary() {
+        let test_program: Vec<u8> = setup_test_program();
+        let program: Program =
+            Program::load_from_bytes_with_format(&test_program, ProgramFormat::Auto).unwrap();
+        assert_eq!(program.program_data(), &test_program);
+    }
+
+    #[test]
+    fn test_save_load() {
+        const FILENAME: &str = "unit_test_save_load.ch8";
+        let program: Program = Program {
+            program_data: vec![0x3, 0xFF, 0x2, 0xA1],
+        };
+        Program::save_to_file(&program, Path::new(FILENAME)).unwrap();
+        let new_program = Program::load_from_file(Path::new(FILENAME)).unwrap();
+        assert_eq!(program, new_program);
+        std::fs::remove_file(FILENAME).unwrap();
+    }
+
+    /// Writes a zip archive holding the specified entries to the specified file
+    fn write_test_archive(file_name: &str, entries: &[(&str, &[u8])]) {
+        let mut archive: zip::ZipWriter<File> =
+            zip::ZipWriter::new(File::create(file_name).unwrap());
+        for (name, contents) in entries {
+            archive
+                .start_file(*name, zip::write::FileOptions::default())
+                .unwrap();
+            std::io::Write::write_all(&mut archive, contents).unwrap();
+        }
+        archive.finish().unwrap();
+    }
+
+    #[test]
+    fn test_list_archive_entries() {
+        const FILENAME: &str = "unit_test_list_archive_entries.zip";
+        write_test_archive(
+            FILENAME,
+            &[
+                ("readme.txt", b"Games"),
+                ("pong.ch8", &[0x12, 0x00]),
+                ("CAR.SC8", &[0x00, 0xFD]),
+            ],
+        );
+        let entries: Vec<String> = Program::list_archive_entries(Path::new(FILENAME)).unwrap();
+        std::fs::remove_file(FILENAME).unwrap();
+        assert_eq!(entries, vec!["pong.ch8".to_string(), "CAR.SC8".to_string()]);
+    }
+
+    #[test]
+    fn test_load_from_archive() {
+        const FILENAME: &str = "unit_test_load_from_archive.zip";
+        write_test_archive(
+            FILENAME,
+            &[
+                ("readme.txt", b"Games"),
+                ("pong.ch8", &[0x12, 0x00]),
+                ("car.sc8", &[0x00, 0xFD]),
+            ],
+        );
+        let first: Program = Program::load_from_archive(Path::new(FILENAME), None).unwrap();
+        let named: Program =
+            Program::load_from_archive(Path::new(FILENAME), Some("car.sc8")).unwrap();
+        let missing = Program::load_from_archive(Path::new(FILENAME), Some("tank.ch8"));
+        std::fs::remove_file(FILENAME).unwrap();
+        assert!(
+            first.program_data() == &vec![0x12, 0x00]
+                && named.program_data() == &vec![0x00, 0xFD]
+                && missing
+                    == Err(ErrorDetail::ArchiveEntryNotFound {
+                        file_path: FILENAME.to_string(),
+                        entry: Some("tank.ch8".to_string()),
+                    })
+        );
+    }
+
+    #[test]
+    fn test_load_from_archive_without_programs() {
+        const FILENAME: &str = "unit_test_load_from_archive_without_programs.zip";
+        write_test_archive(FILENAME, &[("readme.txt", b"Games")]);
+        let result = Program::load_from_archive(Path::new(FILENAME), None);
+        std::fs::remove_file(FILENAME).unwrap();
+        assert_eq!(
+            result,
+            Err(ErrorDetail::ArchiveEntryNotFound {
+                file_path: FILENAME.to_string(),
+                entry: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_from_archive_oversized_entry() {
+        const FILENAME: &str = "unit_test_load_from_archive_oversized_entry.zip";
+        let oversized: Vec<u8> = vec![0; MAX_PROGRAM_SIZE + 1];
+        write_test_archive(
+            FILENAME,
+            &[("big.ch8", &oversized), ("fits.ch8", &oversized[1..])],
+        );
+        let big = Program::load_from_archive(Path::new(FILENAME), Some("big.ch8"));
+        let fits = Program::load_from_archive(Path::new(FILENAME), Some("fits.ch8"));
+        std::fs::remove_file(FILENAME).unwrap();
+        assert!(
+            matches!(big, Err(ErrorDetail::MalformedProgram { .. }))
+                && fits.unwrap().program_data().len() == MAX_PROGRAM_SIZE
+        );
+    }
+
+    #[test]
+    fn test_is_archive_file() {
+        assert!(
+            Program::is_archive_file(Path::new("roms/games.ZIP"))
+                && !Program::is_archive_file(Path::new("roms/pong.ch8"))
+                && Program::is_program_file(Path::new("roms/pong.C8X"))
+                && !Program::is_program_file(Path::new("roms/games.zip"))
+        );
+    }
+}
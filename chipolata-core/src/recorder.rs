@@ -0,0 +1,336 @@
+use crate::display::Display;
+use crate::error::ErrorDetail;
+use crate::processor::StateSnapshot;
+use gif::{Encoder, Frame, Repeat};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// The default palette used by [GifRecorder] for each display colour index, as RGB triples:
+/// background, foreground, pixels set only in the second plane, and pixels set in both planes
+pub const RECORDER_DEFAULT_PALETTE: [[u8; 3]; 4] = [
+    [0x09, 0x49, 0x92],
+    [0x00, 0xDC, 0xFF],
+    [0xFF, 0xAA, 0x00],
+    [0xFF, 0xFF, 0xFF],
+];
+/// The shortest frame delay, in hundredths of a second, written to the GIF.  Most viewers treat
+/// shorter delays as a request to use a (much slower) default, so displays lasting less than
+/// this are dropped in favour of the display that replaced them.
+const MINIMUM_FRAME_DELAY_CENTISECONDS: usize = 2;
+
+/// A display captured by [GifRecorder] but not yet written, as its duration is only known once
+/// the display changes again
+struct PendingFrame {
+    colour_indices: Vec<u8>,
+    start_centiseconds: usize,
+}
+
+/// A screen recorder that captures the frame buffer as an animated GIF, viewable in any web
+/// browser and in the same format as the recordings exported by Octo.
+///
+/// The recorder is fed frame buffers as they are rendered, either directly via
+/// [GifRecorder::record_frame()] or from state snapshots via [GifRecorder::record_snapshot()],
+/// along with the number of emulated frames elapsed.  Each display is shown in the GIF for as
+/// long as it was shown by the emulator, so the recording plays back at the emulated frame rate
+/// however irregularly frames are captured; consecutive identical displays are merged.  The
+/// recording is scaled by a whole number of GIF pixels per display pixel, and its size is fixed
+/// by the first frame captured (any later frame of a different resolution, such as on switching
+/// SUPER-CHIP 1.1 resolution mode with display resizing enabled, is stretched to fit).
+pub struct GifRecorder {
+    writer: Option<BufWriter<File>>,
+    encoder: Option<Encoder<BufWriter<File>>>,
+    file_path: String,
+    palette: [[u8; 3]; 4],
+    pixel_scale: usize,
+    frame_rate_hertz: usize,
+    width: usize,
+    height: usize,
+    first_frame: Option<usize>,
+    last_frame: usize,
+    pending: Option<PendingFrame>,
+    frames_written: usize,
+}
+
+impl GifRecorder {
+    /// Constructor that creates (or truncates) the specified GIF file, ready for frames to be
+    /// recorded to it.  Returns an [ErrorDetail::FileError] if the file cannot be created.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - the path of the GIF file to write
+    /// * `palette` - the RGB colour of each display colour index (see [RECORDER_DEFAULT_PALETTE])
+    /// * `pixel_scale` - the number of GIF pixels across (and down) each display pixel
+    /// * `frame_rate_hertz` - the rate at which the frame numbers passed to the recorder advance;
+    ///   normally the processor's timer frequency
+    pub fn start(
+        file_path: &Path,
+        palette: [[u8; 3]; 4],
+        pixel_scale: usize,
+        frame_rate_hertz: u64,
+    ) -> Result<Self, ErrorDetail> {
+        let file_error = || ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        };
+        let file: File = File::create(file_path).map_err(|_| file_error())?;
+        Ok(GifRecorder {
+            writer: Some(BufWriter::new(file)),
+            encoder: None,
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+            palette,
+            pixel_scale: pixel_scale.max(1),
+            frame_rate_hertz: frame_rate_hertz.max(1) as usize,
+            width: 0,
+            height: 0,
+            first_frame: None,
+            last_frame: 0,
+            pending: None,
+            frames_written: 0,
+        })
+    }
+
+    /// Captures the specified frame buffer, as displayed from the specified frame onwards.
+    /// Frames must be recorded in order; a frame buffer recorded with the same frame number as
+    /// the last replaces it.  Returns an [ErrorDetail::FileError] if writing to the file fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_buffer` - the display to capture
+    /// * `frame` - the number of frames elapsed (for example, as reported in
+    ///   [StateSnapshot::MinimalSnapshot]) when the display was rendered
+    pub fn record_frame(
+        &mut self,
+        frame_buffer: &Display,
+        frame: usize,
+    ) -> Result<(), ErrorDetail> {
+        if self.encoder.is_none() {
+            self.begin(frame_buffer)?;
+        }
+        let first_frame: usize = *self.first_frame.get_or_insert(frame);
+        self.last_frame = frame.max(self.last_frame);
+        let centiseconds: usize = frame.saturating_sub(first_frame) * 100 / self.frame_rate_hertz;
+        let colour_indices: Vec<u8> = self.scale_frame(frame_buffer);
+        match self.pending.take() {
+            // Identical displays are merged into one frame
+            Some(pending) if pending.colour_indices == colour_indices => {
+                self.pending = Some(pending);
+            }
+            // Displays shown too briefly are replaced by the display that followed them
+            Some(pending)
+                if centiseconds < pending.start_centiseconds + MINIMUM_FRAME_DELAY_CENTISECONDS =>
+            {
+                self.pending = Some(PendingFrame {
+                    colour_indices,
+                    start_centiseconds: pending.start_centiseconds,
+                });
+            }
+            Some(pending) => {
+                self.write_frame(&pending, centiseconds - pending.start_centiseconds)?;
+                self.pending = Some(PendingFrame {
+                    colour_indices,
+                    start_centiseconds: centiseconds,
+                });
+            }
+            None => {
+                self.pending = Some(PendingFrame {
+                    colour_indices,
+                    start_centiseconds: centiseconds,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Captures the frame buffer held in the specified state snapshot.  Minimal snapshots report
+    /// the frame number at which they were taken; extended snapshots do not, so are each taken
+    /// to be one frame after the last frame recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - the state snapshot holding the display to capture
+    pub fn record_snapshot(&mut self, snapshot: &StateSnapshot) -> Result<(), ErrorDetail> {
+        match snapshot {
+            StateSnapshot::MinimalSnapshot {
+                frame_buffer,
+                frames,
+                ..
+            } => self.record_frame(frame_buffer, *frames),
+            StateSnapshot::ExtendedSnapshot { frame_buffer, .. } => {
+                let frame: usize = match self.first_frame {
+                    Some(_) => self.last_frame + 1,
+                    None => 0,
+                };
+                self.record_frame(frame_buffer, frame)
+            }
+        }
+    }
+
+    /// Returns the number of frames written to the GIF so far (the most recent display captured
+    /// is only written once it changes, or the recording finishes)
+    pub fn frames_written(&self) -> usize {
+        self.frames_written
+    }
+
+    /// Writes the last display captured, showing it for one frame, and completes the GIF file.
+    /// Returns the total number of frames written, or an [ErrorDetail::FileError] if writing to
+    /// the file fails.  If no frames were captured the file is left empty.
+    pub fn finish(mut self) -> Result<usize, ErrorDetail> {
+        if let Some(pending) = self.pending.take() {
+            let delay: usize = (100 / self.frame_rate_hertz).max(MINIMUM_FRAME_DELAY_CENTISECONDS);
+            self.write_frame(&pending, delay)?;
+        }
+        let writer: Option<BufWriter<File>> = match self.encoder.take() {
+            Some(encoder) => encoder.into_inner().ok(),
+            None => self.writer.take(),
+        };
+        match writer.map(|mut writer| writer.flush()) {
+            Some(Ok(())) => Ok(self.frames_written),
+            _ => Err(self.file_error()),
+        }
+    }
+
+    /// Internal helper function that fixes the size of the recording from the first frame
+    /// captured, and writes the GIF header
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_buffer` - the first display captured
+    fn begin(&mut self, frame_buffer: &Display) -> Result<(), ErrorDetail> {
+        self.width = frame_buffer.get_row_size_bytes() * 8 * self.pixel_scale;
+        self.height = frame_buffer.get_column_size_pixels() * self.pixel_scale;
+        let global_palette: Vec<u8> = self.palette.concat();
+        let writer: BufWriter<File> = match self.writer.take() {
+            Some(writer) => writer,
+            None => return Err(self.file_error()),
+        };
+        let mut encoder: Encoder<BufWriter<File>> = Encoder::new(
+            writer,
+            self.width as u16,
+            self.height as u16,
+            &global_palette,
+        )
+        .map_err(|_| self.file_error())?;
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .map_err(|_| self.file_error())?;
+        self.encoder = Some(encoder);
+        Ok(())
+    }
+
+    /// Internal helper function that returns the colour index of every pixel of the recording
+    /// for the specified display, row by row, scaling it to the size of the recording
+    ///
+    /// # Arguments
+    ///
+    /// * `frame_buffer` - the display to scale
+    fn scale_frame(&self, frame_buffer: &Display) -> Vec<u8> {
+        let source_width: usize = frame_buffer.get_row_size_bytes() * 8;
+        let source_height: usize = frame_buffer.get_column_size_pixels();
+        let source: Vec<u8> = frame_buffer.get_colour_indices();
+        let mut colour_indices: Vec<u8> = Vec::with_capacity(self.width * self.height);
+        for y in 0..self.height {
+            let source_row: usize = y * source_height / self.height;
+            for x in 0..self.width {
+                let source_column: usize = x * source_width / self.width;
+                colour_indices.push(source[source_row * source_width + source_column]);
+            }
+        }
+        colour_indices
+    }
+
+    /// Internal helper function that writes a captured display to the GIF
+    ///
+    /// # Arguments
+    ///
+    /// * `pending` - the captured display
+    /// * `delay_centiseconds` - how long the display is shown for, in hundredths of a second
+    fn write_frame(
+        &mut self,
+        pending: &PendingFrame,
+        delay_centiseconds: usize,
+    ) -> Result<(), ErrorDetail> {
+        let mut frame: Frame = Frame::from_indexed_pixels(
+            self.width as u16,
+            self.height as u16,
+            pending.colour_indices.clone(),
+            None,
+        );
+        frame.delay = delay_centiseconds.min(u16::MAX as usize) as u16;
+        let written: bool = match &mut self.encoder {
+            Some(encoder) => encoder.write_frame(&frame).is_ok(),
+            None => false,
+        };
+        match written {
+            true => {
+                self.frames_written += 1;
+                Ok(())
+            }
+            false => Err(self.file_error()),
+        }
+    }
+
+    /// Internal helper function that returns an [ErrorDetail::FileError] for the GIF file
+    fn file_error(&self) -> ErrorDetail {
+        ErrorDetail::FileError {
+            file_path: self.file_path.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EmulationLevel;
+
+    fn chip8_display() -> Display {
+        Display::new(EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        })
+    }
+
+    fn decode_gif(file_path: &Path) -> Vec<(u16, u16, u16)> {
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(File::open(file_path).unwrap()).unwrap();
+        let mut frames: Vec<(u16, u16, u16)> = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.width, frame.height, frame.delay));
+        }
+        frames
+    }
+
+    #[test]
+    fn test_record_frames() {
+        const FILENAME: &str = "unit_test_record_frames.gif";
+        let mut recorder: GifRecorder =
+            GifRecorder::start(Path::new(FILENAME), RECORDER_DEFAULT_PALETTE, 2, 60).unwrap();
+        let mut display: Display = chip8_display();
+        recorder.record_frame(&display, 0).unwrap();
+        recorder.record_frame(&display, 3).unwrap(); // unchanged, so merged
+        display.set_pixel_colour_index(0, 0, 1);
+        recorder.record_frame(&display, 6).unwrap();
+        let frames_written: usize = recorder.finish().unwrap();
+        let frames: Vec<(u16, u16, u16)> = decode_gif(Path::new(FILENAME));
+        std::fs::remove_file(FILENAME).unwrap();
+        assert!(frames_written == 2 && frames == vec![(128, 64, 10), (128, 64, 2)]);
+    }
+
+    #[test]
+    fn test_record_frames_drops_brief_displays() {
+        const FILENAME: &str = "unit_test_record_brief_frames.gif";
+        let mut recorder: GifRecorder =
+            GifRecorder::start(Path::new(FILENAME), RECORDER_DEFAULT_PALETTE, 1, 60).unwrap();
+        let mut display: Display = chip8_display();
+        recorder.record_frame(&display, 0).unwrap();
+        display.set_pixel_colour_index(0, 0, 1);
+        recorder.record_frame(&display, 0).unwrap();
+        display.set_pixel_colour_index(1, 0, 1);
+        recorder.record_frame(&display, 6).unwrap();
+        assert_eq!(recorder.frames_written(), 1);
+        recorder.finish().unwrap();
+        let frames: Vec<(u16, u16, u16)> = decode_gif(Path::new(FILENAME));
+        std::fs::remove_file(FILENAME).unwrap();
+        assert_eq!(frames, vec![(64, 32, 10), (64, 32, 2)]);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use crate::ErrorDetail;
+use crate::Key;
+use serde_derive::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::fs::File;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+/// A single key press or release event captured while recording input.
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq)]
+pub struct RecordedKeyEvent {
+    /// The number of processor cycles that had completed when the event was received; upon
+    /// replay the event is applied immediately before the following cycle executes.
+    pub cycle: usize,
+    /// The key pressed or released (recorded as its hex ordinal)
+    pub key: Key,
+    /// The new state of the key (true means pressed)
+    pub pressed: bool,
+}
+
+/// An abstraction of a recorded play session, holding everything required to replay the
+/// session deterministically against the same ROM and [Options](crate::Options).
+///
+/// Recordings are captured through
+/// [Processor::start_input_recording()](crate::Processor::start_input_recording) and played back
+/// through [Processor::start_input_replay()](crate::Processor::start_input_replay).  Events are
+/// timestamped by cycle count rather than wall-clock time, so that replay does not depend on the
+/// speed of the host.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct InputRecording {
+    /// The seed of the random number generator used by the recorded session.
+    pub rng_seed: u64,
+    /// The key events captured, in the order they were received.
+    pub events: Vec<RecordedKeyEvent>,
+}
+
+impl InputRecording {
+    /// Constructor that returns an empty [InputRecording] for the specified random seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng_seed` - the seed of the random number generator used by the session being recorded
+    pub fn new(rng_seed: u64) -> Self {
+        InputRecording {
+            rng_seed,
+            events: Vec::new(),
+        }
+    }
+
+    /// Appends a key event to the recording.
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the number of processor cycles completed when the event was received
+    /// * `key` - the key pressed or released
+    /// * `pressed` - the new state of the key (true means pressed)
+    pub(crate) fn record_key_event(&mut self, cycle: usize, key: Key, pressed: bool) {
+        self.events.push(RecordedKeyEvent {
+            cycle,
+            key,
+            pressed,
+        });
+    }
+
+    /// Builder method that instantiates an [InputRecording] from the specified JSON file
+    #[cfg(feature = "std")]
+    pub fn load_from_file(file_path: &Path) -> Result<InputRecording, ErrorDetail> {
+        // attempt to open the file
+        if let Ok(json_file) = File::open(file_path) {
+            // parse the file as JSON and deserialise into an InputRecording instance
+            if let Ok(recording) = serde_json::from_reader(json_file) {
+                return Ok(recording);
+            }
+        }
+        // if we fall through to here, an error has occurred reading from the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Method that serialises the passed [InputRecording] instance to the specified JSON file
+    #[cfg(feature = "std")]
+    pub fn save_to_file(recording: &InputRecording, file_path: &Path) -> Result<(), ErrorDetail> {
+        // attempt to open the file; create it if it does not exist and truncate if it does
+        if let Ok(_) = File::create(file_path) {
+            if let Ok(serialised_recording) = serde_json::to_string(recording) {
+                if std::fs::write(file_path, serialised_recording).is_ok() {
+                    return Ok(());
+                }
+            }
+        }
+        // if we fall through to here, an error has occurred writing to the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_key_event() {
+        let mut recording: InputRecording = InputRecording::new(0x1234);
+        recording.record_key_event(12, Key::KeyA, true);
+        assert_eq!(
+            recording.events,
+            vec![RecordedKeyEvent {
+                cycle: 12,
+                key: Key::KeyA,
+                pressed: true
+            }]
+        );
+    }
+
+    #[test]
+    fn test_save_load() {
+        const FILENAME: &str = "unit_test_save_load_recording.json";
+        let mut recording: InputRecording = InputRecording::new(0x1234);
+        recording.record_key_event(12, Key::KeyA, true);
+        recording.record_key_event(40, Key::KeyA, false);
+        InputRecording::save_to_file(&recording, Path::new(FILENAME)).unwrap();
+        let new_recording = InputRecording::load_from_file(Path::new(FILENAME)).unwrap();
+        assert_eq!(recording, new_recording);
+        std::fs::remove_file(FILENAME).unwrap();
+    }
+}
@@ -0,0 +1,403 @@
+//! A minimal TCP protocol for running Chipolata on one machine while rendering its display and
+//! reading its keypad on another.
+//!
+//! All multi-byte values are big-endian.  The server sends the following messages:
+//!
+//! * Frame ([MESSAGE_FRAME]): the row size in bytes (u16), the column size in pixels (u16), then
+//! the contents of every bit plane of the frame buffer in turn (laid out as per
+//! [Display::get_plane()]).  Sent whenever the frame buffer has changed, at most once per
+//! [REMOTE_FRAME_INTERVAL_MICROSECONDS].
+//! * Error ([MESSAGE_ERROR]): the length of the message in bytes (u16), then the UTF-8 error
+//! message.  Sent if the processor crashes, after which the connection is closed.
+//!
+//! The client sends the following message:
+//!
+//! * Key event ([MESSAGE_KEY_EVENT]): the key ordinal (u8, 0x0 to 0xF), then 1 if the key was
+//! pressed or 0 if it was released (u8).
+
+use crate::{ChipolataError, Display, Key, Processor, ProcessorStatus, DISPLAY_PLANE_COUNT};
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Instant;
+
+/// The message type byte preceding a frame buffer update sent by the server
+pub const MESSAGE_FRAME: u8 = 0x01;
+/// The message type byte preceding an error report sent by the server
+pub const MESSAGE_ERROR: u8 = 0x02;
+/// The message type byte preceding a key event sent by the client
+pub const MESSAGE_KEY_EVENT: u8 = 0x10;
+/// The minimum number of microseconds between frame buffer updates sent by the server
+pub const REMOTE_FRAME_INTERVAL_MICROSECONDS: u128 = 16666;
+
+/// An enum with variants representing the reasons [RemoteDisplayServer::serve()] returns
+#[derive(Debug, PartialEq)]
+pub enum RemoteServeOutcome {
+    /// The client disconnected
+    Disconnected,
+    /// The program exited (SUPER-CHIP emulation mode only)
+    Completed,
+    /// The processor crashed, holding the error raised
+    Crashed(ChipolataError),
+}
+
+/// An enum with variants representing the messages received by a [RemoteDisplayClient]
+#[derive(Debug, PartialEq)]
+pub enum RemoteMessage {
+    /// An updated frame buffer
+    Frame { frame_buffer: Display },
+    /// A report that the processor has crashed
+    Error { message: String },
+}
+
+/// A server that runs a [Processor] on behalf of a single remote client at a time, streaming
+/// frame buffer updates to the client and applying the key events it sends.
+///
+/// Only available when the `remote` feature is enabled.
+pub struct RemoteDisplayServer {
+    listener: TcpListener,
+}
+
+impl RemoteDisplayServer {
+    /// Constructor that returns a [RemoteDisplayServer] listening on the specified address
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the address on which to listen (e.g. "0.0.0.0:8088")
+    pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(RemoteDisplayServer {
+            listener: TcpListener::bind(address)?,
+        })
+    }
+
+    /// Returns the address on which the server is listening
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Waits for a client to connect, then continually executes cycles of the processor, sending
+    /// the client a frame whenever the frame buffer has changed and applying the key events it
+    /// sends, until the client disconnects, the program exits or the processor crashes.  The
+    /// current frame is sent as soon as the client connects.  May be called again to serve
+    /// another client (the processor is not reset in between).
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor to run
+    pub fn serve(&self, processor: &mut Processor) -> io::Result<RemoteServeOutcome> {
+        let (mut stream, _) = self.listener.accept()?;
+        stream.set_nodelay(true)?;
+        let outcome: io::Result<RemoteServeOutcome> = self.serve_client(processor, &mut stream);
+        // Shut down the connection explicitly, as the reader thread holds its own handle to it
+        if let Err(_) = stream.shutdown(Shutdown::Both) {
+            // absorb the error; the client may already have disconnected
+        }
+        outcome
+    }
+
+    /// Helper function that runs the processor on behalf of a connected client, as described for
+    /// [RemoteDisplayServer::serve()]
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor to run
+    /// * `stream` - the connection to the client
+    fn serve_client(
+        &self,
+        processor: &mut Processor,
+        stream: &mut TcpStream,
+    ) -> io::Result<RemoteServeOutcome> {
+        // Key events are read by a separate thread and passed back through a channel, so that
+        // reading never stalls execution; the channel disconnects when the client does
+        let (key_event_tx, key_event_rx) = mpsc::channel();
+        let mut reader: TcpStream = stream.try_clone()?;
+        thread::spawn(move || {
+            let mut message: [u8; 3] = [0x0; 3];
+            while reader.read_exact(&mut message).is_ok() {
+                if message[0] == MESSAGE_KEY_EVENT
+                    && key_event_tx.send((message[1], message[2] != 0)).is_err()
+                {
+                    break;
+                }
+            }
+        });
+        let mut last_frame: Option<Display> = None;
+        let mut last_frame_sent: Instant = Instant::now();
+        loop {
+            // Apply the next key event waiting from the client, if any, ignoring invalid keys.
+            // Only one is applied per cycle so that a press and release arriving together are
+            // still both seen by the program (e.g. by FX0A)
+            match key_event_rx.try_recv() {
+                Ok((key, pressed)) => {
+                    if let Ok(key) = Key::try_from(key) {
+                        processor.set_key_status(key, pressed);
+                    }
+                }
+                Err(mpsc::TryRecvError::Empty) => (),
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    return Ok(RemoteServeOutcome::Disconnected)
+                }
+            }
+            if let Err(error) = processor.execute_cycle() {
+                return self.report_crash(stream, error);
+            }
+            let completed: bool = matches!(
+                processor.state_view().status,
+                ProcessorStatus::Completed | ProcessorStatus::Halted
+            );
+            // Send the frame buffer if it has changed, limiting the frame rate (other than for
+            // the first and last frames)
+            if completed
+                || last_frame.is_none()
+                || last_frame_sent.elapsed().as_micros() >= REMOTE_FRAME_INTERVAL_MICROSECONDS
+            {
+                let frame_buffer: &Display = processor.state_view().frame_buffer;
+                if last_frame.as_ref() != Some(frame_buffer) {
+                    if let Err(error) = stream.write_all(&encode_frame(frame_buffer)) {
+                        return disconnected_or(error);
+                    }
+                    last_frame = Some(frame_buffer.clone());
+                    last_frame_sent = Instant::now();
+                }
+            }
+            if completed {
+                return Ok(RemoteServeOutcome::Completed);
+            }
+        }
+    }
+
+    /// Helper function that sends an error report to the client following a processor crash,
+    /// then returns the corresponding [RemoteServeOutcome]
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - the connection to the client
+    /// * `error` - the error raised by the processor
+    fn report_crash(
+        &self,
+        stream: &mut TcpStream,
+        error: ChipolataError,
+    ) -> io::Result<RemoteServeOutcome> {
+        let message: String = error.inner_error.to_string();
+        let length: u16 = message.len().min(u16::MAX as usize) as u16;
+        let mut bytes: Vec<u8> = vec![MESSAGE_ERROR];
+        bytes.extend(length.to_be_bytes());
+        bytes.extend(&message.as_bytes()[..length as usize]);
+        if let Err(_) = stream.write_all(&bytes) {
+            // absorb the error; the client has disconnected, but the crash is still reported
+        }
+        Ok(RemoteServeOutcome::Crashed(error))
+    }
+}
+
+/// A client connected to a [RemoteDisplayServer], receiving frame buffer updates and sending key
+/// events.
+///
+/// Only available when the `remote` feature is enabled.
+pub struct RemoteDisplayClient {
+    stream: TcpStream,
+}
+
+impl RemoteDisplayClient {
+    /// Constructor that returns a [RemoteDisplayClient] connected to the server at the specified
+    /// address
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the address of the server
+    pub fn connect(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let stream: TcpStream = TcpStream::connect(address)?;
+        stream.set_nodelay(true)?;
+        Ok(RemoteDisplayClient { stream })
+    }
+
+    /// Sends a key press or release event to the server
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - the hex ordinal of the key
+    /// * `pressed` - true if the key was pressed, false if released
+    pub fn send_key_event(&mut self, key: u8, pressed: bool) -> io::Result<()> {
+        self.stream
+            .write_all(&[MESSAGE_KEY_EVENT, key, pressed as u8])
+    }
+
+    /// Waits for the next message from the server.  Returns an error of kind
+    /// [io::ErrorKind::UnexpectedEof] once the server has closed the connection, or
+    /// [io::ErrorKind::InvalidData] if the message is malformed.
+    pub fn receive(&mut self) -> io::Result<RemoteMessage> {
+        let mut message_type: [u8; 1] = [0x0; 1];
+        self.stream.read_exact(&mut message_type)?;
+        match message_type[0] {
+            MESSAGE_FRAME => {
+                let row_size_bytes: usize = self.read_u16()? as usize;
+                let column_size_pixels: usize = self.read_u16()? as usize;
+                let mut planes: Vec<u8> =
+                    vec![0x0; row_size_bytes * column_size_pixels * DISPLAY_PLANE_COUNT];
+                self.stream.read_exact(&mut planes)?;
+                match Display::from_planes(row_size_bytes, column_size_pixels, planes) {
+                    Some(frame_buffer) => Ok(RemoteMessage::Frame { frame_buffer }),
+                    None => Err(io::Error::from(io::ErrorKind::InvalidData)),
+                }
+            }
+            MESSAGE_ERROR => {
+                let mut message: Vec<u8> = vec![0x0; self.read_u16()? as usize];
+                self.stream.read_exact(&mut message)?;
+                match String::from_utf8(message) {
+                    Ok(message) => Ok(RemoteMessage::Error { message }),
+                    Err(_) => Err(io::Error::from(io::ErrorKind::InvalidData)),
+                }
+            }
+            _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+        }
+    }
+
+    /// Helper function that reads a big-endian u16 from the server
+    fn read_u16(&mut self) -> io::Result<u16> {
+        let mut bytes: [u8; 2] = [0x0; 2];
+        self.stream.read_exact(&mut bytes)?;
+        Ok(u16::from_be_bytes(bytes))
+    }
+}
+
+/// Helper function that encodes a frame buffer as a frame message
+///
+/// # Arguments
+///
+/// * `frame_buffer` - the frame buffer to encode
+fn encode_frame(frame_buffer: &Display) -> Vec<u8> {
+    let mut bytes: Vec<u8> = vec![MESSAGE_FRAME];
+    bytes.extend((frame_buffer.get_row_size_bytes() as u16).to_be_bytes());
+    bytes.extend((frame_buffer.get_column_size_pixels() as u16).to_be_bytes());
+    for plane in 0..DISPLAY_PLANE_COUNT {
+        if let Some(plane_bytes) = frame_buffer.get_plane(plane) {
+            bytes.extend(plane_bytes);
+        }
+    }
+    bytes
+}
+
+/// Helper function that treats a failure to write to the client as the client disconnecting
+///
+/// # Arguments
+///
+/// * `error` - the error raised writing to the client
+fn disconnected_or(error: io::Error) -> io::Result<RemoteServeOutcome> {
+    match error.kind() {
+        io::ErrorKind::BrokenPipe
+        | io::ErrorKind::ConnectionReset
+        | io::ErrorKind::ConnectionAborted => Ok(RemoteServeOutcome::Disconnected),
+        _ => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EmulationLevel, Options, Program};
+
+    fn setup_test_processor(program_data: Vec<u8>) -> Processor {
+        let mut options: Options = Options::default();
+        options.emulation_level = EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        };
+        Processor::initialise_and_load(Program::new(program_data), options).unwrap()
+    }
+
+    /// Runs the processor on a server thread, returning a connected client and the handle of the
+    /// server thread
+    fn setup_test_server(
+        mut processor: Processor,
+    ) -> (
+        RemoteDisplayClient,
+        thread::JoinHandle<io::Result<RemoteServeOutcome>>,
+    ) {
+        let server: RemoteDisplayServer = RemoteDisplayServer::bind("127.0.0.1:0").unwrap();
+        let address: SocketAddr = server.local_addr().unwrap();
+        let handle = thread::spawn(move || server.serve(&mut processor));
+        (RemoteDisplayClient::connect(address).unwrap(), handle)
+    }
+
+    #[test]
+    fn test_frame_and_completion() {
+        // 00FF (high resolution mode), 600A (V0 = A), F029 (I = font character A),
+        // D005 (draw at 10,10), 00FD (exit)
+        let processor: Processor = setup_test_processor(vec![
+            0x00, 0xFF, 0x60, 0x0A, 0xF0, 0x29, 0xD0, 0x05, 0x00, 0xFD,
+        ]);
+        let (mut client, handle) = setup_test_server(processor);
+        let mut frame_buffer: Option<Display> = None;
+        // Keep the most recent frame until the server closes the connection
+        while let Ok(message) = client.receive() {
+            match message {
+                RemoteMessage::Frame {
+                    frame_buffer: frame,
+                } => frame_buffer = Some(frame),
+                RemoteMessage::Error { message } => panic!("unexpected error {}", message),
+            }
+        }
+        let frame_buffer: Display = frame_buffer.unwrap();
+        // Font character A begins 11110000
+        assert!(
+            handle.join().unwrap().unwrap() == RemoteServeOutcome::Completed
+                && (10..14).all(|x| frame_buffer.get_pixel(x, 10))
+                && !frame_buffer.get_pixel(14, 10)
+        );
+    }
+
+    #[test]
+    fn test_key_event_and_disconnect() {
+        // 00FF (high resolution mode), 6105 (V1 = 5), E19E (skip if key V1 pressed), 1204 (jump
+        // back to E19E), F129 (I = font character V1), 6000 (V0 = 0), D005 (draw at 0,0),
+        // 120E (loop forever)
+        let processor: Processor = setup_test_processor(vec![
+            0x00, 0xFF, 0x61, 0x05, 0xE1, 0x9E, 0x12, 0x04, 0xF1, 0x29, 0x60, 0x00, 0xD0, 0x05,
+            0x12, 0x0E,
+        ]);
+        let (mut client, handle) = setup_test_server(processor);
+        // The initial blank frame is sent on connection
+        match client.receive().unwrap() {
+            RemoteMessage::Frame { frame_buffer } => assert!(!frame_buffer.get_pixel(0, 0)),
+            RemoteMessage::Error { message } => panic!("unexpected error {}", message),
+        }
+        client.send_key_event(0x5, true).unwrap();
+        // Wait for font character 5 (beginning 11110000) to be drawn
+        loop {
+            match client.receive().unwrap() {
+                RemoteMessage::Frame { frame_buffer } if frame_buffer.get_pixel(0, 0) => {
+                    assert!((0..4).all(|x| frame_buffer.get_pixel(x, 0)));
+                    break;
+                }
+                RemoteMessage::Frame { .. } => (),
+                RemoteMessage::Error { message } => panic!("unexpected error {}", message),
+            }
+        }
+        drop(client);
+        assert_eq!(
+            handle.join().unwrap().unwrap(),
+            RemoteServeOutcome::Disconnected
+        );
+    }
+
+    #[test]
+    fn test_crash_reported() {
+        // 00EE (return with an empty stack)
+        let processor: Processor = setup_test_processor(vec![0x00, 0xEE]);
+        let (mut client, handle) = setup_test_server(processor);
+        let mut error_message: Option<String> = None;
+        while let Ok(message) = client.receive() {
+            if let RemoteMessage::Error { message } = message {
+                error_message = Some(message);
+            }
+        }
+        assert!(
+            error_message.is_some()
+                && matches!(
+                    handle.join().unwrap().unwrap(),
+                    RemoteServeOutcome::Crashed(_)
+                )
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use crate::{ErrorDetail, Key, Processor, ProcessorStatus, WatchExpression};
+use rhai::{CallFnOptions, Dynamic, Engine, EvalAltResult, FuncArgs, Map, Scope, AST, INT};
+use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+/// The maximum number of operations a script may perform while handling a single event, beyond
+/// which the script is abandoned with [ErrorDetail::ScriptError] (so that a script stuck in a
+/// loop cannot hang the host)
+pub const SCRIPT_MAX_OPERATIONS: u64 = 1_000_000;
+
+/// The result type of the functions made available to scripts
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// A change to the processor state requested by a script, applied once the script's event
+/// function returns
+#[derive(Debug, Clone)]
+enum ScriptCommand {
+    SetVariableRegister(usize, u8),
+    SetIndexRegister(u16),
+    SetProgramCounter(u16),
+    SetDelayTimer(u8),
+    SetSoundTimer(u8),
+    WriteMemoryByte(usize, u8),
+    SetKeyStatus(Key, bool),
+    AddBreakpoint(String, WatchExpression),
+    RemoveBreakpoint(String),
+    Pause,
+    Resume,
+}
+
+impl ScriptCommand {
+    /// Returns whether the command modifies processor state that may only be changed while the
+    /// processor is paused
+    fn requires_pause(&self) -> bool {
+        match self {
+            ScriptCommand::SetVariableRegister(..)
+            | ScriptCommand::SetIndexRegister(_)
+            | ScriptCommand::SetProgramCounter(_)
+            | ScriptCommand::SetDelayTimer(_)
+            | ScriptCommand::SetSoundTimer(_)
+            | ScriptCommand::WriteMemoryByte(..) => true,
+            ScriptCommand::SetKeyStatus(..)
+            | ScriptCommand::AddBreakpoint(..)
+            | ScriptCommand::RemoveBreakpoint(_)
+            | ScriptCommand::Pause
+            | ScriptCommand::Resume => false,
+        }
+    }
+}
+
+/// The copy of the processor state that scripts read, shared with the functions registered with
+/// the scripting engine.  Writes made by a script are applied to the copy immediately (so the
+/// script reads back what it wrote) and queued for application to the processor itself.
+#[derive(Debug, Default)]
+struct ScriptMachine {
+    variable_registers: Vec<u8>,
+    index_register: u16,
+    program_counter: u16,
+    delay_timer: u8,
+    sound_timer: u8,
+    cycles: usize,
+    memory: Vec<u8>,
+    commands: Vec<ScriptCommand>,
+    output: Vec<String>,
+}
+
+impl ScriptMachine {
+    /// Refreshes the copy from the current state of the passed processor
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor whose state scripts are to read
+    fn load(&mut self, processor: &Processor) {
+        let view = processor.state_view();
+        self.variable_registers = view.variable_registers.to_vec();
+        self.index_register = view.index_register;
+        self.program_counter = view.program_counter;
+        self.delay_timer = view.delay_timer;
+        self.sound_timer = view.sound_timer;
+        self.cycles = view.cycles;
+        self.memory = view
+            .memory
+            .read_bytes(0, view.memory.max_addressable_size())
+            .unwrap_or_default()
+            .to_vec();
+        self.commands.clear();
+    }
+
+    /// Returns the index of the specified variable register, raising a script error if it is
+    /// outside the valid range
+    fn register(&self, register: INT) -> ScriptResult<usize> {
+        match usize::try_from(register) {
+            Ok(register) if register < self.variable_registers.len() => Ok(register),
+            _ => Err(format!("variable register {} does not exist", register).into()),
+        }
+    }
+
+    /// Returns the specified memory address, raising a script error if it is outside the
+    /// addressable range
+    fn address(&self, address: INT) -> ScriptResult<usize> {
+        match usize::try_from(address) {
+            Ok(address) if address < self.memory.len() => Ok(address),
+            _ => Err(format!("memory address {:#X} is not addressable", address).into()),
+        }
+    }
+}
+
+/// Returns the passed script value as a byte, raising a script error if it does not fit
+fn to_byte(value: INT) -> ScriptResult<u8> {
+    u8::try_from(value).map_err(|_| format!("value {} does not fit in a byte", value).into())
+}
+
+/// Returns the passed script value as a 16-bit word, raising a script error if it does not fit
+fn to_word(value: INT) -> ScriptResult<u16> {
+    u16::try_from(value).map_err(|_| format!("value {} does not fit in 16 bits", value).into())
+}
+
+/// Returns the key with the passed ordinal, raising a script error if there is no such key
+fn to_key(key: INT) -> ScriptResult<Key> {
+    match u8::try_from(key).map(Key::try_from) {
+        Ok(Ok(key)) => Ok(key),
+        _ => Err(format!("key {} does not exist", key).into()),
+    }
+}
+
+/// Hosts a [Rhai](https://rhai.rs) script that reacts to events in the running program through
+/// the debugging API, for example to implement trainers, automated ROM tests or accessibility
+/// aids without recompiling the host.  Only available with the `scripting` feature.
+///
+/// The script defines any of the following event functions, each of which is optional:
+///
+/// * `on_start()` - called before the first frame
+/// * `on_frame(frame)` - called after each frame, with the number of frames seen by the script
+/// * `on_break(name)` - called when a breakpoint added by the script pauses execution; execution
+///   remains paused unless the script calls `resume()`
+///
+/// Within these functions the script may call `reg(x)` and `set_reg(x, value)` for the variable
+/// registers, `index()`, `pc()`, `delay_timer()` and `sound_timer()` (and the corresponding
+/// `set_` functions), `peek(address)` and `poke(address, value)` for memory, `cycles()`,
+/// `press_key(key)` and `release_key(key)`, `add_breakpoint(name, expression)` (with a
+/// [WatchExpression]) and `remove_breakpoint(name)`, and `pause()` and `resume()`.  Values
+/// printed with `print()` are collected for [ScriptHost::take_output()].  State may be kept
+/// between events in the properties of `this`, an object map that persists for the lifetime of
+/// the host.  Top-level statements outside the event functions are not run.
+///
+/// Writes are applied to the processor once the event function returns (pausing the processor
+/// around them if it is running), so the script reads back the values it has written but the
+/// program does not see them until then.
+pub struct ScriptHost {
+    engine: Engine,
+    ast: AST,
+    scope: Scope<'static>,
+    this: Dynamic,
+    machine: Rc<RefCell<ScriptMachine>>,
+    breakpoints: Vec<(String, usize)>,
+    handled_break: Option<(usize, usize)>,
+    frames: usize,
+    started: bool,
+}
+
+impl ScriptHost {
+    /// Compiles the passed script source, returning [ErrorDetail::ScriptError] if it cannot be
+    /// compiled.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - the Rhai script source
+    pub fn new(source: &str) -> Result<Self, ErrorDetail> {
+        let machine: Rc<RefCell<ScriptMachine>> = Rc::new(RefCell::new(ScriptMachine::default()));
+        let engine: Engine = ScriptHost::build_engine(&machine);
+        let ast: AST = engine
+            .compile(source)
+            .map_err(|e| ErrorDetail::ScriptError {
+                function: None,
+                message: e.to_string(),
+            })?;
+        Ok(ScriptHost {
+            engine,
+            ast,
+            scope: Scope::new(),
+            this: Dynamic::from_map(Map::new()),
+            machine,
+            breakpoints: Vec::new(),
+            handled_break: None,
+            frames: 0,
+            started: false,
+        })
+    }
+
+    /// Builder method that compiles the script held in the specified file
+    pub fn load_from_file(file_path: &Path) -> Result<Self, ErrorDetail> {
+        // attempt to open the file and read as a string
+        if let Ok(source) = fs::read_to_string(file_path) {
+            return ScriptHost::new(&source);
+        }
+        // if we fall through to here, an error has occurred reading from the file
+        return Err(ErrorDetail::FileError {
+            file_path: file_path.to_str().unwrap_or_default().to_owned(),
+        });
+    }
+
+    /// Runs the script's event functions for the frame just executed by the passed processor,
+    /// applying any changes the script makes to the processor state.  Hosts call this once per
+    /// frame, however frames are executed.  Nothing is run unless a program is loaded and the
+    /// processor has neither stopped nor crashed.  Returns [ErrorDetail::ScriptError] if the
+    /// script raises an error (in which case none of its writes for that event are applied).
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor the script observes and controls
+    pub fn on_frame(&mut self, processor: &mut Processor) -> Result<(), ErrorDetail> {
+        match processor.state_view().status {
+            ProcessorStatus::ProgramLoaded
+            | ProcessorStatus::Running
+            | ProcessorStatus::WaitingForKeypress
+            | ProcessorStatus::Paused => (),
+            _ => return Ok(()),
+        }
+        if !self.started {
+            self.started = true;
+            self.call(processor, "on_start", ())?;
+        }
+        if let Some((id, name)) = self.new_break(processor) {
+            self.call(processor, "on_break", (name,))?;
+            self.handled_break = Some((id, processor.state_view().cycles));
+        }
+        self.frames += 1;
+        self.call(processor, "on_frame", (self.frames as INT,))
+    }
+
+    /// Removes and returns the lines printed by the script since this was last called
+    pub fn take_output(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.machine.borrow_mut().output)
+    }
+
+    /// Internal helper function that returns the identifier and name of the script breakpoint
+    /// that has paused execution, if it has not already been reported to the script
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor the script observes
+    fn new_break(&self, processor: &Processor) -> Option<(usize, String)> {
+        if processor.state_view().status != ProcessorStatus::Paused {
+            return None;
+        }
+        let report = processor.debug_report();
+        let id: usize = report.break_watch_id?;
+        // A break remains recorded once the processor resumes, so is only new if not yet
+        // reported at this cycle and the breakpoint's condition still holds
+        if self.handled_break == Some((id, report.cycles)) {
+            return None;
+        }
+        let triggered: bool = report
+            .watches
+            .iter()
+            .any(|watch| watch.id == id && watch.value.unwrap_or(0) != 0);
+        self.breakpoints
+            .iter()
+            .find(|(_, breakpoint_id)| *breakpoint_id == id)
+            .filter(|_| triggered)
+            .map(|(name, _)| (id, name.clone()))
+    }
+
+    /// Internal helper function that calls the named event function, if the script defines it,
+    /// then applies the changes it requested to the processor
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor the script observes and controls
+    /// * `function` - the name of the event function
+    /// * `args` - the arguments passed to the event function
+    fn call(
+        &mut self,
+        processor: &mut Processor,
+        function: &str,
+        args: impl FuncArgs,
+    ) -> Result<(), ErrorDetail> {
+        if !self.ast.iter_functions().any(|f| f.name == function) {
+            return Ok(());
+        }
+        self.machine.borrow_mut().load(processor);
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .bind_this_ptr(&mut self.this);
+        let result = self.engine.call_fn_with_options::<Dynamic>(
+            options,
+            &mut self.scope,
+            &self.ast,
+            function,
+            args,
+        );
+        let commands: Vec<ScriptCommand> = std::mem::take(&mut self.machine.borrow_mut().commands);
+        if let Err(e) = result {
+            return Err(ErrorDetail::ScriptError {
+                function: Some(function.to_string()),
+                message: e.to_string(),
+            });
+        }
+        self.apply(processor, commands)
+    }
+
+    /// Internal helper function that applies the changes requested by the script to the
+    /// processor, pausing it around any changes that may only be made while paused
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor to change
+    /// * `commands` - the changes requested by the script, in the order requested
+    fn apply(
+        &mut self,
+        processor: &mut Processor,
+        commands: Vec<ScriptCommand>,
+    ) -> Result<(), ErrorDetail> {
+        let was_paused: bool = processor.state_view().status == ProcessorStatus::Paused;
+        let mut paused: bool = was_paused;
+        if !was_paused && commands.iter().any(ScriptCommand::requires_pause) {
+            processor.pause_execution().map_err(|e| e.inner_error)?;
+        }
+        for command in commands {
+            let result = match command {
+                ScriptCommand::SetVariableRegister(register, value) => {
+                    processor.set_variable_register(register, value)
+                }
+                ScriptCommand::SetIndexRegister(value) => processor.set_index_register(value),
+                ScriptCommand::SetProgramCounter(value) => processor.set_program_counter(value),
+                ScriptCommand::SetDelayTimer(value) => processor.set_delay_timer(value),
+                ScriptCommand::SetSoundTimer(value) => processor.set_sound_timer(value),
+                ScriptCommand::WriteMemoryByte(address, value) => {
+                    processor.write_memory_byte(address, value)
+                }
+                ScriptCommand::SetKeyStatus(key, status) => {
+                    processor.set_key_status(key, status);
+                    Ok(())
+                }
+                ScriptCommand::AddBreakpoint(name, expression) => {
+                    self.remove_breakpoint(processor, &name);
+                    let id: usize = processor.add_watch(expression, true);
+                    self.breakpoints.push((name, id));
+                    Ok(())
+                }
+                ScriptCommand::RemoveBreakpoint(name) => {
+                    self.remove_breakpoint(processor, &name);
+                    Ok(())
+                }
+                ScriptCommand::Pause => {
+                    paused = true;
+                    Ok(())
+                }
+                ScriptCommand::Resume => {
+                    paused = false;
+                    Ok(())
+                }
+            };
+            result?;
+        }
+        let is_paused: bool = processor.state_view().status == ProcessorStatus::Paused;
+        match (paused, is_paused) {
+            (true, false) => processor.pause_execution().map_err(|e| e.inner_error),
+            (false, true) => processor.resume_execution().map_err(|e| e.inner_error),
+            _ => Ok(()),
+        }
+    }
+
+    /// Internal helper function that removes the named script breakpoint, if present
+    ///
+    /// # Arguments
+    ///
+    /// * `processor` - the processor with which the breakpoint's watch is registered
+    /// * `name` - the name given to the breakpoint by the script
+    fn remove_breakpoint(&mut self, processor: &mut Processor, name: &str) {
+        if let Some(position) = self.breakpoints.iter().position(|(n, _)| n == name) {
+            let (_, id): (String, usize) = self.breakpoints.remove(position);
+            processor.remove_watch(id);
+        }
+    }
+
+    /// Internal helper function that creates the scripting engine, registering the functions
+    /// through which scripts access the shared copy of the processor state
+    ///
+    /// # Arguments
+    ///
+    /// * `machine` - the copy of the processor state shared with the registered functions
+    fn build_engine(machine: &Rc<RefCell<ScriptMachine>>) -> Engine {
+        let mut engine: Engine = Engine::new();
+        engine.set_max_operations(SCRIPT_MAX_OPERATIONS);
+        let m = machine.clone();
+        engine.on_print(move |text| m.borrow_mut().output.push(text.to_string()));
+        let m = machine.clone();
+        engine.register_fn("reg", move |register: INT| -> ScriptResult<INT> {
+            let machine = m.borrow();
+            Ok(machine.variable_registers[machine.register(register)?] as INT)
+        });
+        let m = machine.clone();
+        engine.register_fn("set_reg", move |register: INT, value: INT| {
+            let mut machine = m.borrow_mut();
+            let (register, value): (usize, u8) = (machine.register(register)?, to_byte(value)?);
+            machine.variable_registers[register] = value;
+            machine
+                .commands
+                .push(ScriptCommand::SetVariableRegister(register, value));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("index", move || m.borrow().index_register as INT);
+        let m = machine.clone();
+        engine.register_fn("set_index", move |value: INT| {
+            let value: u16 = to_word(value)?;
+            let mut machine = m.borrow_mut();
+            machine.index_register = value;
+            machine
+                .commands
+                .push(ScriptCommand::SetIndexRegister(value));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("pc", move || m.borrow().program_counter as INT);
+        let m = machine.clone();
+        engine.register_fn("set_pc", move |value: INT| {
+            let value: u16 = to_word(value)?;
+            let mut machine = m.borrow_mut();
+            machine.program_counter = value;
+            machine
+                .commands
+                .push(ScriptCommand::SetProgramCounter(value));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("delay_timer", move || m.borrow().delay_timer as INT);
+        let m = machine.clone();
+        engine.register_fn("set_delay_timer", move |value: INT| {
+            let value: u8 = to_byte(value)?;
+            let mut machine = m.borrow_mut();
+            machine.delay_timer = value;
+            machine.commands.push(ScriptCommand::SetDelayTimer(value));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("sound_timer", move || m.borrow().sound_timer as INT);
+        let m = machine.clone();
+        engine.register_fn("set_sound_timer", move |value: INT| {
+            let value: u8 = to_byte(value)?;
+            let mut machine = m.borrow_mut();
+            machine.sound_timer = value;
+            machine.commands.push(ScriptCommand::SetSoundTimer(value));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("peek", move |address: INT| -> ScriptResult<INT> {
+            let machine = m.borrow();
+            Ok(machine.memory[machine.address(address)?] as INT)
+        });
+        let m = machine.clone();
+        engine.register_fn("poke", move |address: INT, value: INT| {
+            let mut machine = m.borrow_mut();
+            let (address, value): (usize, u8) = (machine.address(address)?, to_byte(value)?);
+            machine.memory[address] = value;
+            machine
+                .commands
+                .push(ScriptCommand::WriteMemoryByte(address, value));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("cycles", move || m.borrow().cycles as INT);
+        let m = machine.clone();
+        engine.register_fn("press_key", move |key: INT| {
+            let key: Key = to_key(key)?;
+            m.borrow_mut()
+                .commands
+                .push(ScriptCommand::SetKeyStatus(key, true));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("release_key", move |key: INT| {
+            let key: Key = to_key(key)?;
+            m.borrow_mut()
+                .commands
+                .push(ScriptCommand::SetKeyStatus(key, false));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("add_breakpoint", move |name: &str, expression: &str| {
+            let expression: WatchExpression =
+                WatchExpression::parse(expression).map_err(|e| e.to_string())?;
+            m.borrow_mut()
+                .commands
+                .push(ScriptCommand::AddBreakpoint(name.to_string(), expression));
+            ScriptResult::Ok(())
+        });
+        let m = machine.clone();
+        engine.register_fn("remove_breakpoint", move |name: &str| {
+            m.borrow_mut()
+                .commands
+                .push(ScriptCommand::RemoveBreakpoint(name.to_string()));
+        });
+        let m = machine.clone();
+        engine.register_fn("pause", move || {
+            m.borrow_mut().commands.push(ScriptCommand::Pause);
+        });
+        let m = machine.clone();
+        engine.register_fn("resume", move || {
+            m.borrow_mut().commands.push(ScriptCommand::Resume);
+        });
+        engine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Options, Program};
+
+    fn setup_processor(program_data: Vec<u8>) -> Processor {
+        Processor::initialise_and_load(Program::new(program_data), Options::default()).unwrap()
+    }
+
+    #[test]
+    fn test_compile_error() {
+        assert!(matches!(
+            ScriptHost::new("fn on_frame( {"),
+            Err(ErrorDetail::ScriptError { function: None, .. })
+        ));
+    }
+
+    #[test]
+    fn test_on_frame_writes_state() {
+        // 6005: V0 = 5; 1202: jump to self
+        let mut processor: Processor = setup_processor(vec![0x60, 0x05, 0x12, 0x02]);
+        processor.run_frames(1).unwrap();
+        let mut script: ScriptHost = ScriptHost::new(
+            "fn on_frame(frame) { set_reg(1, reg(0) + frame); poke(0x300, reg(1)); print(peek(0x300)); }",
+        )
+        .unwrap();
+        script.on_frame(&mut processor).unwrap();
+        let view = processor.state_view();
+        assert_eq!(view.variable_registers[1], 6);
+        assert_eq!(view.memory.read_byte(0x300).unwrap(), 6);
+        assert_eq!(view.status, ProcessorStatus::Running);
+        assert_eq!(script.take_output(), vec!["6".to_string()]);
+    }
+
+    #[test]
+    fn test_state_persists_between_events() {
+        let mut processor: Processor = setup_processor(vec![0x12, 0x00]);
+        let mut script: ScriptHost = ScriptHost::new(
+            "fn on_start() { this.count = 10; } fn on_frame(frame) { this.count += 1; print(this.count); }",
+        )
+        .unwrap();
+        script.on_frame(&mut processor).unwrap();
+        script.on_frame(&mut processor).unwrap();
+        assert_eq!(
+            script.take_output(),
+            vec!["11".to_string(), "12".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_calls_on_break() {
+        // 7001: V0 += 1; 1200: jump back
+        let mut processor: Processor = setup_processor(vec![0x70, 0x01, 0x12, 0x00]);
+        let mut script: ScriptHost = ScriptHost::new(
+            r#"fn on_start() { add_breakpoint("three", "V0 == 3"); }
+               fn on_break(name) { print(name); set_reg(0, 10); resume(); }"#,
+        )
+        .unwrap();
+        script.on_frame(&mut processor).unwrap();
+        processor.run_frames(1).unwrap();
+        assert_eq!(processor.state_view().status, ProcessorStatus::Paused);
+        script.on_frame(&mut processor).unwrap();
+        assert_eq!(script.take_output(), vec!["three".to_string()]);
+        assert_eq!(processor.state_view().variable_registers[0], 10);
+        assert_eq!(processor.state_view().status, ProcessorStatus::Running);
+    }
+
+    #[test]
+    fn test_script_error_discards_writes() {
+        let mut processor: Processor = setup_processor(vec![0x12, 0x00]);
+        let mut script: ScriptHost =
+            ScriptHost::new("fn on_frame(frame) { set_reg(0, 1); set_reg(16, 1); }").unwrap();
+        assert!(matches!(
+            script.on_frame(&mut processor),
+            Err(ErrorDetail::ScriptError {
+                function: Some(_),
+                ..
+            })
+        ));
+        assert_eq!(processor.state_view().variable_registers[0], 0);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::prelude::*;
+use crate::{Display, Memory, StateSnapshot, DISPLAY_PLANE_COUNT};
+use core::ops::Range;
+
+/// A report of the differences between two [StateSnapshot] instances, as returned by
+/// [StateSnapshot::diff()].
+///
+/// Register, timer, stack and memory differences are only reported when both snapshots are
+/// [StateSnapshot::ExtendedSnapshot] instances; for any other combination only the frame buffer
+/// and processor status are compared.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotDiff {
+    /// True if the processor status differs
+    pub status: bool,
+    /// True if the program counter differs
+    pub program_counter: bool,
+    /// True if the index register differs
+    pub index_register: bool,
+    /// The indices of the variable registers (V0 to VF) that differ
+    pub variable_registers: Vec<usize>,
+    /// The indices of the RPL user flags that differ (SUPER-CHIP 1.1 emulation mode only)
+    pub rpl_registers: Vec<usize>,
+    /// True if the delay timer differs
+    pub delay_timer: bool,
+    /// True if the sound timer differs
+    pub sound_timer: bool,
+    /// True if the high-resolution mode flag differs
+    pub high_resolution_mode: bool,
+    /// True if the contents of the stack differ
+    pub stack: bool,
+    /// The contiguous ranges of memory addresses whose contents differ
+    pub memory_ranges: Vec<Range<usize>>,
+    /// The contiguous ranges of display rows containing at least one pixel that differs (in any
+    /// bit plane).  If the display dimensions differ, every row is reported as changed.
+    pub display_rows: Vec<Range<usize>>,
+}
+
+impl SnapshotDiff {
+    /// Returns true if no differences were found
+    pub fn is_empty(&self) -> bool {
+        *self == SnapshotDiff::default()
+    }
+}
+
+impl StateSnapshot {
+    /// Compares this snapshot against another (typically later) snapshot, and returns a
+    /// [SnapshotDiff] reporting which registers, memory ranges and display rows differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - the snapshot to compare against
+    pub fn diff(&self, other: &StateSnapshot) -> SnapshotDiff {
+        match (self, other) {
+            (
+                StateSnapshot::ExtendedSnapshot {
+                    frame_buffer,
+                    status,
+                    stack,
+                    memory,
+                    program_counter,
+                    index_register,
+                    variable_registers,
+                    rpl_registers,
+                    delay_timer,
+                    sound_timer,
+                    high_resolution_mode,
+                    ..
+                },
+                StateSnapshot::ExtendedSnapshot {
+                    frame_buffer: other_frame_buffer,
+                    status: other_status,
+                    stack: other_stack,
+                    memory: other_memory,
+                    program_counter: other_program_counter,
+                    index_register: other_index_register,
+                    variable_registers: other_variable_registers,
+                    rpl_registers: other_rpl_registers,
+                    delay_timer: other_delay_timer,
+                    sound_timer: other_sound_timer,
+                    high_resolution_mode: other_high_resolution_mode,
+                    ..
+                },
+            ) => SnapshotDiff {
+                status: status != other_status,
+                program_counter: program_counter != other_program_counter,
+                index_register: index_register != other_index_register,
+                variable_registers: changed_indices(variable_registers, other_variable_registers),
+                rpl_registers: changed_indices(rpl_registers, other_rpl_registers),
+                delay_timer: delay_timer != other_delay_timer,
+                sound_timer: sound_timer != other_sound_timer,
+                high_resolution_mode: high_resolution_mode != other_high_resolution_mode,
+                stack: stack != other_stack,
+                memory_ranges: changed_memory_ranges(memory, other_memory),
+                display_rows: changed_display_rows(frame_buffer, other_frame_buffer),
+            },
+            _ => SnapshotDiff {
+                status: self.status() != other.status(),
+                display_rows: changed_display_rows(self.frame_buffer(), other.frame_buffer()),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Helper function that returns the frame buffer held by either snapshot variant
+    fn frame_buffer(&self) -> &Display {
+        match self {
+            StateSnapshot::MinimalSnapshot { frame_buffer, .. } => frame_buffer,
+            StateSnapshot::ExtendedSnapshot { frame_buffer, .. } => frame_buffer,
+        }
+    }
+
+    /// Helper function that returns the processor status held by either snapshot variant
+    fn status(&self) -> crate::ProcessorStatus {
+        match self {
+            StateSnapshot::MinimalSnapshot { status, .. } => *status,
+            StateSnapshot::ExtendedSnapshot { status, .. } => *status,
+        }
+    }
+}
+
+/// Helper function that returns the indices at which two equally-sized register arrays differ
+fn changed_indices(registers: &[u8], other_registers: &[u8]) -> Vec<usize> {
+    registers
+        .iter()
+        .zip(other_registers.iter())
+        .enumerate()
+        .filter(|(_, (value, other_value))| value != other_value)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Helper function that collapses a sorted sequence of indices into contiguous ranges
+fn collapse_to_ranges(indices: impl Iterator<Item = usize>) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for index in indices {
+        match ranges.last_mut() {
+            Some(range) if range.end == index => range.end += 1,
+            _ => ranges.push(index..index + 1),
+        }
+    }
+    ranges
+}
+
+/// Helper function that returns the contiguous ranges of addresses at which two memories differ
+/// (any addresses present in only one of the memories are treated as differing)
+fn changed_memory_ranges(memory: &Memory, other_memory: &Memory) -> Vec<Range<usize>> {
+    let size: usize = memory.max_addressable_size();
+    let other_size: usize = other_memory.max_addressable_size();
+    let bytes: &[u8] = memory.read_bytes(0x0, size).unwrap_or_default();
+    let other_bytes: &[u8] = other_memory.read_bytes(0x0, other_size).unwrap_or_default();
+    collapse_to_ranges(
+        (0..size.max(other_size)).filter(|&address| bytes.get(address) != other_bytes.get(address)),
+    )
+}
+
+/// Helper function that returns the contiguous ranges of rows in which two displays differ
+fn changed_display_rows(display: &Display, other_display: &Display) -> Vec<Range<usize>> {
+    let rows: usize = display.get_column_size_pixels();
+    let row_size_bytes: usize = display.get_row_size_bytes();
+    // If the display dimensions differ then every row is treated as changed
+    if rows != other_display.get_column_size_pixels()
+        || row_size_bytes != other_display.get_row_size_bytes()
+    {
+        return vec![0..rows.max(other_display.get_column_size_pixels())];
+    }
+    collapse_to_ranges((0..rows).filter(|&row| {
+        (0..DISPLAY_PLANE_COUNT).any(|plane| {
+            let row_bytes = row * row_size_bytes..(row + 1) * row_size_bytes;
+            display
+                .get_plane(plane)
+                .map(|bytes| &bytes[row_bytes.clone()])
+                != other_display
+                    .get_plane(plane)
+                    .map(|bytes| &bytes[row_bytes])
+        })
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EmulationLevel, Options, Processor, Program, StateSnapshotVerbosity};
+
+    #[test]
+    fn test_collapse_to_ranges() {
+        let indices: Vec<usize> = vec![1, 2, 3, 7, 9, 10];
+        assert_eq!(
+            collapse_to_ranges(indices.into_iter()),
+            vec![1..4, 7..8, 9..11]
+        );
+    }
+
+    #[test]
+    fn test_diff_identical() {
+        let processor: Processor =
+            Processor::initialise_and_load(Program::default(), Options::default()).unwrap();
+        let snapshot: StateSnapshot =
+            processor.export_state_snapshot(StateSnapshotVerbosity::Extended);
+        assert!(snapshot.diff(&snapshot).is_empty());
+    }
+
+    #[test]
+    fn test_diff_instruction_side_effects() {
+        // Program sets V3 to 0x12, sets I to 0x300, stores V0 to V3 at I then draws font
+        // character 0 (at I = 0x050 after FX29) at (V0, V0)
+        let program: Program = Program::new(vec![
+            0x63, 0x12, 0xA3, 0x00, 0xF3, 0x55, 0xF0, 0x29, 0xD0, 0x05,
+        ]);
+        let mut options: Options = Options::default();
+        options.emulation_level = EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        };
+        let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+        let before: StateSnapshot =
+            processor.export_state_snapshot(StateSnapshotVerbosity::Extended);
+        for _ in 0..5 {
+            processor.execute_cycle().unwrap();
+        }
+        let after: StateSnapshot =
+            processor.export_state_snapshot(StateSnapshotVerbosity::Extended);
+        let diff: SnapshotDiff = before.diff(&after);
+        assert!(
+            diff.status
+                && diff.program_counter
+                && diff.index_register
+                && diff.variable_registers == vec![0x3]
+                && diff.memory_ranges == vec![0x303..0x304]
+                && diff.display_rows == vec![0..5]
+                && !diff.stack
+                && !diff.delay_timer
+        );
+    }
+}
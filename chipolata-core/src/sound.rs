@@ -0,0 +1,183 @@
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use std::sync::mpsc;
+
+/// A struct describing a change in whether sound should be playing, as reported to a [SoundSink]
+/// (or to the callback registered through
+/// [Processor::set_audio_callback()](crate::Processor::set_audio_callback))
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SoundEvent {
+    /// True if the sound timer has become non-zero (sound should start), false if it has
+    /// reached zero (sound should stop)
+    pub playing: bool,
+    /// The value of the processor's cycle counter at the moment of the transition
+    pub cycle: usize,
+}
+
+impl SoundEvent {
+    /// Forwards this event to the passed [SoundSink], calling [SoundSink::start_beep()] or
+    /// [SoundSink::stop_beep()] as appropriate.  Useful for hosts that collect events on one
+    /// thread (for example through an [mpsc::Sender] sink) and play them on another
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - the [SoundSink] to receive the event
+    pub fn dispatch(&self, sink: &mut dyn SoundSink) {
+        match self.playing {
+            true => sink.start_beep(self.cycle),
+            false => sink.stop_beep(self.cycle),
+        }
+    }
+}
+
+/// The type of the callback that may be registered through
+/// [Processor::set_audio_callback()](crate::Processor::set_audio_callback)
+pub type AudioCallback = Box<dyn FnMut(SoundEvent) + Send>;
+
+/// An extensibility point allowing hosting applications to receive the processor's sound output,
+/// whichever audio backend (if any) they use.
+///
+/// Chipolata does not produce audio itself; instead, whenever the sound timer transitions between
+/// zero and non-zero the processor notifies the sink registered through
+/// [Processor::set_sound_sink()](crate::Processor::set_sound_sink), at the cycle the transition
+/// occurs.  Desktop, headless and wasm hosts can therefore all consume sound uniformly.
+///
+/// The pitch method is called by the CHIP-8X FXF8 instruction.  The pattern method is reserved
+/// for XO-CHIP audio, which Chipolata does not yet emulate, so is never called at present.  Both
+/// do nothing by default.
+pub trait SoundSink {
+    /// Called when the sound timer becomes non-zero i.e. the tone should start playing
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the value of the processor's cycle counter at the moment of the transition
+    fn start_beep(&mut self, cycle: usize);
+
+    /// Called when the sound timer reaches zero i.e. the tone should stop playing
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the value of the processor's cycle counter at the moment of the transition
+    fn stop_beep(&mut self, cycle: usize);
+
+    /// Called when the 16-byte (128 sample) audio pattern buffer changes (XO-CHIP F002)
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - the new audio pattern, one bit per sample
+    fn set_pattern(&mut self, _pattern: &[u8; 16]) {}
+
+    /// Called when the pitch of the tone changes (CHIP-8X FXF8, or XO-CHIP FX3A)
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - the new pitch value written by the program
+    fn set_pitch(&mut self, _pitch: u8) {}
+}
+
+/// A [SoundSink] that forwards each transition as a [SoundEvent] over a channel, ignoring any
+/// error should the receiver have been dropped
+#[cfg(feature = "std")]
+impl SoundSink for mpsc::Sender<SoundEvent> {
+    fn start_beep(&mut self, cycle: usize) {
+        // absorb any error; no need to handle
+        let _ = self.send(SoundEvent {
+            playing: true,
+            cycle,
+        });
+    }
+
+    fn stop_beep(&mut self, cycle: usize) {
+        // absorb any error; no need to handle
+        let _ = self.send(SoundEvent {
+            playing: false,
+            cycle,
+        });
+    }
+}
+
+/// Adapter allowing a plain [AudioCallback] closure to be registered as a [SoundSink]
+struct CallbackSoundSink {
+    callback: AudioCallback,
+}
+
+impl SoundSink for CallbackSoundSink {
+    fn start_beep(&mut self, cycle: usize) {
+        (self.callback)(SoundEvent {
+            playing: true,
+            cycle,
+        });
+    }
+
+    fn stop_beep(&mut self, cycle: usize) {
+        (self.callback)(SoundEvent {
+            playing: false,
+            cycle,
+        });
+    }
+}
+
+/// The processor's sound output state: the registered [SoundSink] (if any), and whether sound
+/// was playing when the sink was last notified
+#[derive(Default)]
+pub(crate) struct SoundState {
+    sink: Option<Box<dyn SoundSink + Send>>,
+    playing_reported: bool,
+}
+
+impl SoundState {
+    /// Registers the passed sink, replacing any existing one.  Sound is assumed to be stopped at
+    /// the point of registration
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - the [SoundSink] to be notified of sound transitions
+    pub(crate) fn set_sink(&mut self, sink: Box<dyn SoundSink + Send>) {
+        self.sink = Some(sink);
+        self.playing_reported = false;
+    }
+
+    /// Registers the passed callback, wrapped as a [SoundSink], replacing any existing sink
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - the callback to be invoked on sound transitions
+    pub(crate) fn set_callback(&mut self, callback: AudioCallback) {
+        self.set_sink(Box::new(CallbackSoundSink { callback }));
+    }
+
+    /// Removes any registered sink
+    pub(crate) fn clear_sink(&mut self) {
+        self.sink = None;
+    }
+
+    /// Passes a change of tone pitch on to the registered sink, if any
+    ///
+    /// # Arguments
+    ///
+    /// * `pitch` - the new pitch value written by the program
+    pub(crate) fn set_pitch(&mut self, pitch: u8) {
+        if let Some(sink) = &mut self.sink {
+            sink.set_pitch(pitch);
+        }
+    }
+
+    /// Notifies the registered sink, if any, when sound has started or stopped since the sink
+    /// was last notified
+    ///
+    /// # Arguments
+    ///
+    /// * `playing` - whether sound should currently be playing
+    /// * `cycle` - the value of the processor's cycle counter
+    pub(crate) fn update(&mut self, playing: bool, cycle: usize) {
+        if playing != self.playing_reported {
+            if let Some(sink) = &mut self.sink {
+                match playing {
+                    true => sink.start_beep(cycle),
+                    false => sink.stop_beep(cycle),
+                }
+                self.playing_reported = playing;
+            }
+        }
+    }
+}
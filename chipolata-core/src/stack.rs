@@ -0,0 +1,630 @@
+use crate::prelude::*;
+use crate::{error::ErrorDetail, EmulationLevel};
+use alloc::collections::VecDeque;
+use serde_derive::{Deserialize, Serialize};
+
+/// The maximum stack size that may be specified through [Options::stack_depth_override]
+/// (in terms of u16 values).
+///
+/// [Options::stack_depth_override]: crate::Options::stack_depth_override
+pub const MAX_STACK_DEPTH: usize = 256;
+const CHIP8_STACK_DEPTH: usize = 12;
+const CHIP48_STACK_DEPTH: usize = 16;
+const SUPERCHIP11_STACK_DEPTH: usize = 16;
+/// The maximum number of call and return events held while recording; once reached, the oldest
+/// events are discarded to make room for new ones.
+pub const STACK_EVENT_CAPACITY: usize = 4096;
+
+/// An event recorded by the [Stack] (when recording is enabled via
+/// [Stack::set_event_recording()]) each time a subroutine is called or returns.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StackEvent {
+    /// A subroutine was called (by instruction 2NNN)
+    Call {
+        /// The address of the subroutine called
+        target: u16,
+        /// The address to which the subroutine will return
+        return_address: u16,
+        /// The processor cycle count at which the call was made
+        cycle: usize,
+        /// The number of entries on the stack after the call
+        depth: usize,
+    },
+    /// A subroutine returned (by instruction 00EE)
+    Return {
+        /// The address to which the subroutine returned
+        return_address: u16,
+        /// The processor cycle count at which the return was made
+        cycle: usize,
+        /// The number of entries on the stack after the return
+        depth: usize,
+    },
+}
+
+/// A node in the call tree summarising the subroutine calls recorded by the [Stack]: the
+/// subroutine at a given address, the number of times it was called from its parent, and the
+/// subroutines it in turn called.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallTreeNode {
+    /// The address of the subroutine, or None for the root of the tree (the program's top level,
+    /// outside any subroutine called since recording began)
+    pub address: Option<u16>,
+    /// The number of times the subroutine was called from its parent
+    pub calls: usize,
+    /// The subroutines called from within this one, in order of first call
+    pub children: Vec<CallTreeNode>,
+}
+
+/// The call tree as it is built, with nodes held in a flat vector and referring to their children
+/// by index (the root being held at index 0)
+#[derive(Clone, Debug, PartialEq)]
+struct CallTreeEntry {
+    address: Option<u16>,
+    calls: usize,
+    children: Vec<usize>,
+}
+
+/// The call and return events, and call tree, recorded while recording is enabled
+#[derive(Clone, Debug, PartialEq)]
+struct StackRecording {
+    events: VecDeque<StackEvent>, // the events recorded, oldest first
+    tree: Vec<CallTreeEntry>,     // the call tree, with the root at index 0
+    path: Vec<usize>,             // the tree indices of the subroutines currently being executed
+}
+
+impl StackRecording {
+    /// Constructor that returns an empty [StackRecording]
+    fn new() -> Self {
+        StackRecording {
+            events: VecDeque::new(),
+            tree: vec![CallTreeEntry {
+                address: None,
+                calls: 0,
+                children: Vec::new(),
+            }],
+            path: Vec::new(),
+        }
+    }
+
+    /// Adds the passed event to the back of the queue, discarding the oldest event if full
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - the event to add
+    fn push_event(&mut self, event: StackEvent) {
+        if self.events.len() == STACK_EVENT_CAPACITY {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+
+    /// Records a call to the subroutine at the specified address from the subroutine currently
+    /// being executed, adding it to the call tree if not called from there before
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - the address of the subroutine called
+    fn enter(&mut self, target: u16) {
+        let parent: usize = self.path.last().copied().unwrap_or(0);
+        let existing: Option<usize> = self.tree[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|&child| self.tree[child].address == Some(target));
+        let node: usize = match existing {
+            Some(node) => node,
+            None => {
+                self.tree.push(CallTreeEntry {
+                    address: Some(target),
+                    calls: 0,
+                    children: Vec::new(),
+                });
+                let node: usize = self.tree.len() - 1;
+                self.tree[parent].children.push(node);
+                node
+            }
+        };
+        self.tree[node].calls += 1;
+        self.path.push(node);
+    }
+
+    /// Returns the subtree of the call tree rooted at the specified index
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - the index of the root of the subtree
+    fn subtree(&self, index: usize) -> CallTreeNode {
+        let entry: &CallTreeEntry = &self.tree[index];
+        CallTreeNode {
+            address: entry.address,
+            calls: entry.calls,
+            children: entry
+                .children
+                .iter()
+                .map(|&child| self.subtree(child))
+                .collect(),
+        }
+    }
+}
+
+/// An abstraction of the CHIP-8 stack, used for holding return addresses from function calls.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+pub struct Stack {
+    /// A vector of 16-bit values representing the entire CHIP-8 stack.
+    pub bytes: Vec<u16>,
+    /// A pointer to the current top of the stack (i.e. the next available empty slot).
+    pub pointer: usize,
+    /// The stack size limit (number of entries).
+    stack_size_limit: usize,
+    /// The greatest number of entries the stack has held.
+    max_depth: usize,
+    /// The call and return events and call tree recorded, if recording is enabled.  Not part of
+    /// a serialised save state.
+    #[serde(skip)]
+    recording: Option<Box<StackRecording>>,
+}
+
+impl Clone for Stack {
+    fn clone(&self) -> Self {
+        Stack {
+            bytes: self.bytes.clone(),
+            pointer: self.pointer,
+            stack_size_limit: self.stack_size_limit,
+            max_depth: self.max_depth,
+            recording: self.recording.clone(),
+        }
+    }
+
+    // Copy into the existing entries where possible, so that refreshing a snapshot's stack need
+    // not allocate
+    fn clone_from(&mut self, source: &Self) {
+        self.bytes.clone_from(&source.bytes);
+        self.pointer = source.pointer;
+        self.stack_size_limit = source.stack_size_limit;
+        self.max_depth = source.max_depth;
+        self.recording.clone_from(&source.recording);
+    }
+}
+
+impl Stack {
+    /// Constructor that returns a [Stack] instance, initialised to zero entries.  The stack size
+    /// will be limited depending on emulation level, unless an override is specified (in which
+    /// case it is clamped to the range 1 to [MAX_STACK_DEPTH] inclusive).
+    ///
+    /// # Arguments
+    ///
+    /// * `emulation_level` - the CHIP-8 variant to be emulated (impacts permitted stack entries)
+    /// * `depth_override` - the number of permitted stack entries, if not the emulation default
+    pub(crate) fn new(emulation_level: EmulationLevel, depth_override: Option<usize>) -> Self {
+        let stack_size_limit: usize = match depth_override {
+            Some(depth) => depth.clamp(1, MAX_STACK_DEPTH),
+            None => match emulation_level {
+                EmulationLevel::Chip8 { .. } | EmulationLevel::Chip8X { .. } => CHIP8_STACK_DEPTH,
+                EmulationLevel::Chip48 { .. } => CHIP48_STACK_DEPTH,
+                EmulationLevel::SuperChip10 { .. } | EmulationLevel::SuperChip11 { .. } => {
+                    SUPERCHIP11_STACK_DEPTH
+                }
+            },
+        };
+        Stack {
+            bytes: vec![0x0; stack_size_limit],
+            pointer: 0,
+            stack_size_limit,
+            max_depth: 0,
+            recording: None,
+        }
+    }
+
+    /// Pushes the specified 16-bit value on to the top of the stack.  If the stack is already
+    /// full, returns [ErrorDetail::PushFullStack] holding the current call trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - the value to push on to the stack
+    pub(crate) fn push(&mut self, value: u16) -> Result<(), ErrorDetail> {
+        if self.pointer >= self.stack_size_limit {
+            return Err(ErrorDetail::PushFullStack {
+                call_trace: self.call_trace(),
+            });
+        }
+        self.bytes[self.pointer] = value;
+        // Increment the stack pointer to point to the next free slot
+        self.pointer += 1;
+        self.max_depth = self.max_depth.max(self.pointer);
+        Ok(())
+    }
+
+    /// Pops the top entry off the stack and returns it.  If the stack is already empty, returns
+    /// [ErrorDetail::PopEmptyStack].
+    pub(crate) fn pop(&mut self) -> Result<u16, ErrorDetail> {
+        if self.pointer <= 0 {
+            return Err(ErrorDetail::PopEmptyStack);
+        }
+        // Decrement the stack pointer (before accessing the item at this index)
+        self.pointer -= 1;
+        Ok(self.bytes[self.pointer])
+    }
+
+    /// Pushes the specified return address on to the top of the stack as a call to the subroutine
+    /// at the specified target address, recording the call if recording is enabled.  If the stack
+    /// is already full, returns [ErrorDetail::PushFullStack] holding the current call trace.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - the address of the subroutine called
+    /// * `return_address` - the address to which the subroutine will return
+    /// * `cycle` - the processor cycle count at which the call is made
+    pub(crate) fn call(
+        &mut self,
+        target: u16,
+        return_address: u16,
+        cycle: usize,
+    ) -> Result<(), ErrorDetail> {
+        self.push(return_address)?;
+        let depth: usize = self.pointer;
+        if let Some(recording) = &mut self.recording {
+            recording.push_event(StackEvent::Call {
+                target,
+                return_address,
+                cycle,
+                depth,
+            });
+            recording.enter(target);
+        }
+        Ok(())
+    }
+
+    /// Pops the top entry off the stack as a return from a subroutine and returns it, recording
+    /// the return if recording is enabled.  If the stack is already empty, returns
+    /// [ErrorDetail::PopEmptyStack].
+    ///
+    /// # Arguments
+    ///
+    /// * `cycle` - the processor cycle count at which the return is made
+    pub(crate) fn ret(&mut self, cycle: usize) -> Result<u16, ErrorDetail> {
+        let return_address: u16 = self.pop()?;
+        let depth: usize = self.pointer;
+        if let Some(recording) = &mut self.recording {
+            recording.push_event(StackEvent::Return {
+                return_address,
+                cycle,
+                depth,
+            });
+            recording.path.pop();
+        }
+        Ok(return_address)
+    }
+
+    /// Returns the number of entries currently on the stack
+    pub fn depth(&self) -> usize {
+        self.pointer
+    }
+
+    /// Returns the greatest number of entries the stack has held since it was created
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Starts or stops recording subroutine calls and returns.  While recording, each call and
+    /// return is added to a queue of [StackEvent]s (holding at most [STACK_EVENT_CAPACITY]), and
+    /// summarised in a call tree.  Starting recording when already recording has no effect;
+    /// stopping it discards the events and call tree recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - true to start recording, false to stop
+    pub fn set_event_recording(&mut self, enabled: bool) {
+        match (enabled, self.recording.is_some()) {
+            (true, false) => self.recording = Some(Box::new(StackRecording::new())),
+            (false, _) => self.recording = None,
+            _ => (),
+        }
+    }
+
+    /// Returns true if subroutine calls and returns are being recorded
+    pub fn is_recording_events(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Removes and returns the call and return events recorded since this was last called (oldest
+    /// first), or an empty vector if recording is not enabled
+    pub fn take_events(&mut self) -> Vec<StackEvent> {
+        match &mut self.recording {
+            Some(recording) => recording.events.drain(..).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns a summary of the subroutine calls recorded since recording began, as a tree whose
+    /// root is the program's top level, or None if recording is not enabled
+    pub fn call_tree(&self) -> Option<CallTreeNode> {
+        self.recording
+            .as_ref()
+            .map(|recording| recording.subtree(0))
+    }
+
+    /// Moves any recording from the passed stack into this one, for use when the stack is
+    /// replaced (for example on reset or on restoring a saved state).  As the subroutines
+    /// being executed may differ, subsequent calls are recorded from the top level of the call
+    /// tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - the stack being replaced
+    pub(crate) fn adopt_recording(&mut self, previous: &mut Stack) {
+        self.recording = previous.recording.take();
+        if let Some(recording) = &mut self.recording {
+            recording.path.clear();
+        }
+    }
+
+    /// Returns the maximum permitted stack size (number of entries)
+    pub fn max_stack_size(&self) -> usize {
+        self.stack_size_limit
+    }
+
+    /// Returns the return addresses currently held on the stack, from the outermost subroutine
+    /// call (bottom of the stack) to the innermost (top of the stack)
+    pub fn call_trace(&self) -> Vec<u16> {
+        self.bytes[..self.pointer].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pop() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.bytes[0] = 0xC4;
+        stack.pointer = 1;
+        assert!(stack.pop().unwrap() == 0xC4 && stack.pointer == 0);
+    }
+
+    #[test]
+    fn test_pop_empty_error() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        assert_eq!(stack.pop().unwrap_err(), ErrorDetail::PopEmptyStack);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.bytes[0] = 0xC4;
+        stack.pointer = 1;
+        assert!(stack.push(0xFF).is_ok() && stack.bytes[1] == 0xFF && stack.pointer == 2);
+    }
+
+    #[test]
+    fn test_push_full_chip8_mode_error() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.pointer = CHIP8_STACK_DEPTH;
+        assert_eq!(
+            stack.push(0xFF).unwrap_err(),
+            ErrorDetail::PushFullStack {
+                call_trace: vec![0x0; CHIP8_STACK_DEPTH]
+            }
+        );
+    }
+
+    #[test]
+    fn test_push_full_chip48_mode_error() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip48 {
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.pointer = CHIP48_STACK_DEPTH;
+        assert_eq!(
+            stack.push(0xFF).unwrap_err(),
+            ErrorDetail::PushFullStack {
+                call_trace: vec![0x0; CHIP48_STACK_DEPTH]
+            }
+        );
+    }
+
+    #[test]
+    fn test_push_full_superchip11_mode_error() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.pointer = SUPERCHIP11_STACK_DEPTH;
+        assert_eq!(
+            stack.push(0xFF).unwrap_err(),
+            ErrorDetail::PushFullStack {
+                call_trace: vec![0x0; SUPERCHIP11_STACK_DEPTH]
+            }
+        );
+    }
+
+    #[test]
+    fn test_depth_override() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            Some(2),
+        );
+        stack.push(0x202).unwrap();
+        stack.push(0x30A).unwrap();
+        assert!(
+            stack.max_stack_size() == 2
+                && stack.push(0x40C).unwrap_err()
+                    == ErrorDetail::PushFullStack {
+                        call_trace: vec![0x202, 0x30A]
+                    }
+        );
+    }
+
+    #[test]
+    fn test_depth_override_clamped() {
+        let stack: Stack = Stack::new(
+            EmulationLevel::Chip48 {
+                variable_cycle_timing: false,
+            },
+            Some(MAX_STACK_DEPTH + 1),
+        );
+        assert_eq!(stack.max_stack_size(), MAX_STACK_DEPTH);
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.push(0x202).unwrap();
+        stack.push(0x30A).unwrap();
+        stack.pop().unwrap();
+        assert!(stack.depth() == 1 && stack.max_depth() == 2);
+    }
+
+    #[test]
+    fn test_call_return_events() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.call(0x300, 0x202, 1).unwrap();
+        stack.set_event_recording(true);
+        stack.call(0x400, 0x302, 2).unwrap();
+        assert_eq!(stack.ret(3).unwrap(), 0x302);
+        assert_eq!(
+            stack.take_events(),
+            vec![
+                StackEvent::Call {
+                    target: 0x400,
+                    return_address: 0x302,
+                    cycle: 2,
+                    depth: 2
+                },
+                StackEvent::Return {
+                    return_address: 0x302,
+                    cycle: 3,
+                    depth: 1
+                }
+            ]
+        );
+        assert!(stack.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_call_tree() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        assert_eq!(stack.call_tree(), None);
+        stack.set_event_recording(true);
+        for cycle in 0..2 {
+            stack.call(0x300, 0x202, cycle).unwrap();
+            stack.call(0x400, 0x302, cycle).unwrap();
+            stack.ret(cycle).unwrap();
+            stack.ret(cycle).unwrap();
+        }
+        stack.call(0x400, 0x204, 2).unwrap();
+        let leaf = |address: u16, calls: usize| CallTreeNode {
+            address: Some(address),
+            calls,
+            children: Vec::new(),
+        };
+        assert_eq!(
+            stack.call_tree().unwrap(),
+            CallTreeNode {
+                address: None,
+                calls: 0,
+                children: vec![
+                    CallTreeNode {
+                        address: Some(0x300),
+                        calls: 2,
+                        children: vec![leaf(0x400, 2)]
+                    },
+                    leaf(0x400, 1)
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_event_capacity() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.set_event_recording(true);
+        for cycle in 0..STACK_EVENT_CAPACITY {
+            stack.call(0x300, 0x202, cycle).unwrap();
+            stack.ret(cycle).unwrap();
+        }
+        let events: Vec<StackEvent> = stack.take_events();
+        assert!(
+            events.len() == STACK_EVENT_CAPACITY
+                && events[0]
+                    == StackEvent::Call {
+                        target: 0x300,
+                        return_address: 0x202,
+                        cycle: STACK_EVENT_CAPACITY / 2,
+                        depth: 1
+                    }
+        );
+    }
+
+    #[test]
+    fn test_call_trace() {
+        let mut stack: Stack = Stack::new(
+            EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            None,
+        );
+        stack.push(0x202).unwrap();
+        stack.push(0x30A).unwrap();
+        stack.push(0x40C).unwrap();
+        stack.pop().unwrap();
+        assert_eq!(stack.call_trace(), vec![0x202, 0x30A]);
+    }
+}
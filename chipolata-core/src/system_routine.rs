@@ -0,0 +1,79 @@
+use crate::display::Display;
+use crate::error::ErrorDetail;
+use crate::memory::Memory;
+
+/// The number of cycles a system routine is deemed to take if the handler does not specify otherwise
+pub const DEFAULT_SYSTEM_ROUTINE_CYCLES: u64 = 100;
+
+/// An extensibility point allowing hosting applications to emulate the machine code routines
+/// invoked by the 0NNN instruction (SYS addr).
+///
+/// On the original COSMAC VIP, 0NNN jumped to a machine code subroutine at address NNN.  As
+/// Chipolata does not emulate the underlying host processor, by default 0NNN returns
+/// [ErrorDetail::UnimplementedInstruction].  Registering a handler through
+/// [Processor::set_system_routine_handler()](crate::Processor::set_system_routine_handler) allows
+/// embedders to supply their own behaviour for specific routines (for example, the simple display
+/// or timer routines used by some early ROMs) instead of the processor crashing.
+pub trait SystemRoutineHandler: Send {
+    /// Called when a 0NNN instruction is executed.  Returns the number of COSMAC VIP machine
+    /// cycles the routine should be deemed to have taken (see [DEFAULT_SYSTEM_ROUTINE_CYCLES]),
+    /// or an [ErrorDetail] if the routine is not supported (in which case the processor crashes
+    /// with that error, as it would with no handler registered).
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address NNN of the machine code routine being called
+    /// * `context` - a [SystemRoutineContext] giving the handler access to the processor state
+    fn call_routine(
+        &mut self,
+        address: u16,
+        context: &mut SystemRoutineContext,
+    ) -> Result<u64, ErrorDetail>;
+}
+
+/// A view of the processor state that may be inspected and modified by a [SystemRoutineHandler].
+///
+/// Registers and timers are exposed directly; memory and the display are accessed through methods
+/// that enforce the same bounds checks as instructions executed by the processor.
+pub struct SystemRoutineContext<'a> {
+    /// The general purpose variable registers (V0 to VF)
+    pub variable_registers: &'a mut [u8],
+    /// The index register
+    pub index_register: &'a mut u16,
+    /// The program counter (already advanced past the 0NNN opcode)
+    pub program_counter: &'a mut u16,
+    /// The delay timer
+    pub delay_timer: &'a mut u8,
+    /// The sound timer
+    pub sound_timer: &'a mut u8,
+    pub(crate) memory: &'a mut Memory,
+    pub(crate) frame_buffer: &'a mut Display,
+}
+
+impl<'a> SystemRoutineContext<'a> {
+    /// Returns a reference to the processor's [Memory]
+    pub fn memory(&self) -> &Memory {
+        self.memory
+    }
+
+    /// Writes a byte to the specified memory address.  If the address is outside the addressable
+    /// range, returns [ErrorDetail::MemoryAddressOutOfBounds].
+    ///
+    /// # Arguments
+    ///
+    /// * `address` - the memory address at which the byte should be written
+    /// * `value` - the byte value to be written
+    pub fn write_memory_byte(&mut self, address: usize, value: u8) -> Result<(), ErrorDetail> {
+        self.memory.write_byte_privileged(address, value)
+    }
+
+    /// Returns a reference to the processor's frame buffer [Display]
+    pub fn frame_buffer(&self) -> &Display {
+        self.frame_buffer
+    }
+
+    /// Clears the display (equivalent to instruction 00E0)
+    pub fn clear_display(&mut self) {
+        self.frame_buffer.clear();
+    }
+}
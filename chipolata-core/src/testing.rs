@@ -0,0 +1,246 @@
+//! Helpers for verifying, in automated tests, that a CHIP-8 program renders the expected
+//! screen.  These allow developers of CHIP-8 games to use Chipolata as a library within their
+//! own continuous integration, for example checking that a title screen draws correctly under
+//! a particular set of quirk [Options].
+
+use crate::display::Display;
+use crate::headless::{frame_buffer_hash, run_rom_to_completion, HeadlessRunReport};
+use crate::options::Options;
+use crate::prelude::*;
+use crate::program::Program;
+
+/// The expected final state of the display, against which [assert_display_matches()] compares
+/// that actually produced.  Built from a `u64` (a hash as returned by
+/// [frame_buffer_hash()](crate::frame_buffer_hash)) or from a `&str` or `String` (an image in
+/// plain PBM format, as returned by [display_to_pbm()]).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpectedDisplay {
+    /// The hash of the expected display
+    Hash(u64),
+    /// The expected display as a plain (P1) PBM image, in which 1 denotes a lit pixel
+    Pbm(String),
+}
+
+impl From<u64> for ExpectedDisplay {
+    fn from(hash: u64) -> Self {
+        ExpectedDisplay::Hash(hash)
+    }
+}
+
+impl From<&str> for ExpectedDisplay {
+    fn from(pbm: &str) -> Self {
+        ExpectedDisplay::Pbm(pbm.to_owned())
+    }
+}
+
+impl From<String> for ExpectedDisplay {
+    fn from(pbm: String) -> Self {
+        ExpectedDisplay::Pbm(pbm)
+    }
+}
+
+/// Runs the specified program headlessly for the specified number of cycles (or until it exits)
+/// as per [run_rom_to_completion()](crate::run_rom_to_completion), which guarantees a
+/// deterministic result, and panics unless the final display matches that expected.  The panic
+/// message includes a text rendering of the display actually produced, together with its hash
+/// and PBM image, so that a correct result can be adopted as the new expected value.  Also
+/// panics if the program crashes, or if an expected PBM image cannot be parsed.
+///
+/// # Arguments
+///
+/// * `program` - a [Program] instance holding the bytes of the ROM to be executed
+/// * `options` - an [Options] instance holding the quirk configuration under which to run
+/// * `cycles` - the maximum number of cycles to execute
+/// * `expected` - the expected display, as a hash (`u64`) or plain PBM image (`&str`)
+pub fn assert_display_matches(
+    program: Program,
+    options: Options,
+    cycles: usize,
+    expected: impl Into<ExpectedDisplay>,
+) {
+    let report: HeadlessRunReport = match run_rom_to_completion(program, options, cycles) {
+        Ok(report) => report,
+        Err(error) => panic!("program crashed: {}", error),
+    };
+    let matches: bool = match expected.into() {
+        ExpectedDisplay::Hash(hash) => report.frame_buffer_hash == hash,
+        ExpectedDisplay::Pbm(pbm) => match pbm_pixels(&pbm) {
+            Some(pixels) => pixels == display_pixels(&report.frame_buffer),
+            None => panic!("expected display is not a valid plain (P1) PBM image"),
+        },
+    };
+    assert!(
+        matches,
+        "display does not match that expected after {} cycles (hash {:#018X}):\n{}\n{}",
+        report.cycles_executed,
+        frame_buffer_hash(&report.frame_buffer),
+        display_to_text(&report.frame_buffer),
+        display_to_pbm(&report.frame_buffer)
+    );
+}
+
+/// Returns the specified display as an image in plain (P1) PBM format, in which 1 denotes a lit
+/// pixel, for storing as the expected output of [assert_display_matches()]
+///
+/// # Arguments
+///
+/// * `display` - the display to convert
+pub fn display_to_pbm(display: &Display) -> String {
+    let (width, height): (usize, usize) = display.get_resolution();
+    let mut pbm: String = format!("P1\n{} {}\n", width, height);
+    for row in display.iter_rows() {
+        let values: Vec<&str> = row
+            .iter()
+            .map(|&on| match on {
+                true => "1",
+                false => "0",
+            })
+            .collect();
+        pbm.push_str(&values.join(" "));
+        pbm.push('\n');
+    }
+    pbm
+}
+
+/// Returns a text rendering of the specified display, one line per row, in which `#` denotes a
+/// lit pixel and `.` an unlit one
+///
+/// # Arguments
+///
+/// * `display` - the display to render
+pub fn display_to_text(display: &Display) -> String {
+    let mut text: String = String::new();
+    for row in display.iter_rows() {
+        text.extend(row.iter().map(|&on| match on {
+            true => '#',
+            false => '.',
+        }));
+        text.push('\n');
+    }
+    text
+}
+
+/// Internal helper function that returns the dimensions and on/off state of every pixel of the
+/// specified display, row by row from the top-left
+///
+/// # Arguments
+///
+/// * `display` - the display to read
+fn display_pixels(display: &Display) -> (usize, usize, Vec<bool>) {
+    let (width, height): (usize, usize) = display.get_resolution();
+    let pixels: Vec<bool> = display.iter_pixels().map(|(_, on)| on).collect();
+    (width, height, pixels)
+}
+
+/// Internal helper function that parses an image in plain (P1) PBM format, returning its
+/// dimensions and the state of every pixel row by row from the top-left (1 being lit), or None
+/// if the image is not valid.  Comments (from `#` to the end of the line) are ignored.
+///
+/// # Arguments
+///
+/// * `pbm` - the text of the image
+fn pbm_pixels(pbm: &str) -> Option<(usize, usize, Vec<bool>)> {
+    let mut tokens = pbm
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .flat_map(|line| line.split_whitespace());
+    if tokens.next()? != "P1" {
+        return None;
+    }
+    let width: usize = tokens.next()?.parse().ok()?;
+    let height: usize = tokens.next()?.parse().ok()?;
+    // Pixel values need not be separated by whitespace in plain PBM images
+    let pixels: Vec<bool> = tokens
+        .flat_map(|token| token.chars())
+        .map(|value| match value {
+            '0' => Some(false),
+            '1' => Some(true),
+            _ => None,
+        })
+        .collect::<Option<Vec<bool>>>()?;
+    match pixels.len() == width * height {
+        true => Some((width, height, pixels)),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EmulationLevel;
+
+    // 6005 (V0 = 5), F029 (I = font character 5), D115 (draw at 0,0), 1206 (loop forever)
+    const DRAW_FIVE: [u8; 8] = [0x60, 0x05, 0xF0, 0x29, 0xD1, 0x15, 0x12, 0x06];
+
+    fn setup_test_options() -> Options {
+        let mut options: Options = Options::default();
+        options.emulation_level = EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        };
+        options
+    }
+
+    #[test]
+    fn test_assert_display_matches_hash() {
+        let report: HeadlessRunReport =
+            run_rom_to_completion(Program::new(DRAW_FIVE.to_vec()), setup_test_options(), 10)
+                .unwrap();
+        assert_display_matches(
+            Program::new(DRAW_FIVE.to_vec()),
+            setup_test_options(),
+            10,
+            report.frame_buffer_hash,
+        );
+    }
+
+    #[test]
+    fn test_assert_display_matches_pbm() {
+        let report: HeadlessRunReport =
+            run_rom_to_completion(Program::new(DRAW_FIVE.to_vec()), setup_test_options(), 10)
+                .unwrap();
+        assert_display_matches(
+            Program::new(DRAW_FIVE.to_vec()),
+            setup_test_options(),
+            10,
+            display_to_pbm(&report.frame_buffer),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "display does not match")]
+    fn test_assert_display_matches_mismatch() {
+        let blank: Display = Display::new(setup_test_options().emulation_level);
+        assert_display_matches(
+            Program::new(DRAW_FIVE.to_vec()),
+            setup_test_options(),
+            10,
+            display_to_pbm(&blank).as_str(),
+        );
+    }
+
+    #[test]
+    fn test_pbm_pixels() {
+        let pixels: Option<(usize, usize, Vec<bool>)> =
+            pbm_pixels("P1\n# comment\n3 2\n1 0 1\n010\n");
+        assert_eq!(
+            pixels,
+            Some((3, 2, vec![true, false, true, false, true, false]))
+        );
+    }
+
+    #[test]
+    fn test_pbm_pixels_invalid() {
+        assert!(
+            pbm_pixels("P4\n1 1\n1").is_none()
+                && pbm_pixels("P1\n2 2\n1 0 1").is_none()
+                && pbm_pixels("P1\n1 1\n2").is_none()
+        );
+    }
+
+    #[test]
+    fn test_display_to_text() {
+        let display: Display = Display::new(setup_test_options().emulation_level);
+        let text: String = display_to_text(&display);
+        assert!(text.lines().count() == 32 && text.lines().all(|line| line == ".".repeat(64)));
+    }
+}
@@ -0,0 +1,239 @@
+use crate::clock::Instant;
+use core::time::Duration;
+
+/// Timing statistics gathered while executing cycles, as returned by
+/// [Processor::timing_statistics()](crate::Processor::timing_statistics), comparing the
+/// wall-clock timing actually achieved with that configured.  Cycles executed in turbo mode
+/// (including those run by [Processor::run_frames()](crate::Processor::run_frames)) are not
+/// measured, and measurement restarts after the processor is paused.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct TimingStatistics {
+    /// The number of cycles whose duration was measured
+    pub cycles_measured: usize,
+    /// The average wall-clock time between the completion of successive measured cycles
+    pub average_cycle_duration: Duration,
+    /// The average time each measured cycle should have taken at the configured processor speed
+    /// (and instruction timings, if variable cycle timing is enabled)
+    pub average_target_cycle_duration: Duration,
+    /// The cumulative difference in microseconds between the time the measured cycles took and
+    /// the time they should have taken; positive if execution is running behind the configured
+    /// speed, negative if ahead
+    pub drift_microseconds: i64,
+    /// The number of delay and sound timer decrements measured (the first decrement after the
+    /// timers start is not, as it has no predecessor)
+    pub timer_decrements_measured: usize,
+    /// The average wall-clock time between successive timer decrements
+    pub average_timer_interval: Duration,
+    /// The average absolute difference between the measured timer decrement intervals and the
+    /// configured timer interval
+    pub timer_jitter: Duration,
+    /// The number of vblank interrupt intervals measured
+    pub vblank_interrupts_measured: usize,
+    /// The average wall-clock time between successive vblank interrupts
+    pub average_vblank_interval: Duration,
+    /// The average absolute difference between the measured vblank interrupt intervals and the
+    /// configured timer interval
+    pub vblank_jitter: Duration,
+}
+
+impl TimingStatistics {
+    /// Returns the drift as a percentage of the time the measured cycles should have taken
+    /// (positive if running behind the configured speed), or 0 if no cycles were measured
+    pub fn drift_percent(&self) -> f64 {
+        let target_microseconds: u128 =
+            self.average_target_cycle_duration.as_micros() * self.cycles_measured as u128;
+        match target_microseconds {
+            0 => 0.,
+            target => self.drift_microseconds as f64 * 100. / target as f64,
+        }
+    }
+}
+
+/// Running totals for one kind of periodic event (timer decrements or vblank interrupts)
+#[derive(Debug, Default)]
+struct IntervalRecorder {
+    previous: Option<Instant>, // the moment of the previous event, if the interval is unbroken
+    count: u32,                // the number of intervals measured
+    total: Duration,           // the sum of the intervals measured
+    total_deviation: Duration, // the sum of absolute deviations from the nominal interval
+}
+
+impl IntervalRecorder {
+    /// Records an event, measuring the interval since the previous one (if any)
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - the moment of the event
+    /// * `nominal` - the configured interval between events
+    /// * `continues` - false if no further event is expected to follow on at the nominal interval
+    fn record(&mut self, now: Instant, nominal: Duration, continues: bool) {
+        if let Some(previous) = self.previous {
+            let interval: Duration = now.saturating_duration_since(previous);
+            self.count += 1;
+            self.total += interval;
+            self.total_deviation += match interval > nominal {
+                true => interval - nominal,
+                false => nominal - interval,
+            };
+        }
+        self.previous = match continues {
+            true => Some(now),
+            false => None,
+        };
+    }
+
+    /// Returns the number of intervals measured, their average, and their average deviation
+    fn summarise(&self) -> (usize, Duration, Duration) {
+        match self.count {
+            0 => (0, Duration::ZERO, Duration::ZERO),
+            count => (
+                count as usize,
+                self.total / count,
+                self.total_deviation / count,
+            ),
+        }
+    }
+}
+
+/// Gathers the measurements from which [TimingStatistics] are calculated
+#[derive(Debug, Default)]
+pub(crate) struct TimingRecorder {
+    last_cycle_complete: Option<Instant>, // the moment the previous measured cycle completed
+    cycles: u32,                          // the number of cycles measured
+    total_actual: Duration,               // the sum of the measured cycle durations
+    total_target: Duration,               // the sum of the target cycle durations
+    timer: IntervalRecorder,              // timer decrement intervals
+    vblank: IntervalRecorder,             // vblank interrupt intervals
+}
+
+impl TimingRecorder {
+    /// Records the completion of a cycle
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - the moment the cycle completed
+    /// * `target` - the time the cycle should have taken, or None if it was not throttled (in
+    ///   which case it is not measured)
+    pub(crate) fn record_cycle(&mut self, now: Instant, target: Option<Duration>) {
+        if let (Some(previous), Some(target)) = (self.last_cycle_complete, target) {
+            self.cycles += 1;
+            self.total_actual += now.saturating_duration_since(previous);
+            self.total_target += target;
+        }
+        self.last_cycle_complete = target.map(|_| now);
+    }
+
+    /// Records a decrement of the delay and sound timers
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - the moment of the decrement
+    /// * `nominal` - the configured timer interval
+    /// * `timers_running` - true if either timer is still non-zero after the decrement
+    pub(crate) fn record_timer_decrement(
+        &mut self,
+        now: Instant,
+        nominal: Duration,
+        timers_running: bool,
+    ) {
+        self.timer.record(now, nominal, timers_running);
+    }
+
+    /// Records a vblank interrupt
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - the moment of the interrupt
+    /// * `nominal` - the configured timer interval
+    pub(crate) fn record_vblank_interrupt(&mut self, now: Instant, nominal: Duration) {
+        self.vblank.record(now, nominal, true);
+    }
+
+    /// Breaks the measured intervals (for example while execution is paused), so the time until
+    /// the next cycle, timer decrement or vblank interrupt is not measured
+    pub(crate) fn interrupt(&mut self) {
+        self.last_cycle_complete = None;
+        self.timer.previous = None;
+        self.vblank.previous = None;
+    }
+
+    /// Returns the [TimingStatistics] calculated from the measurements gathered so far
+    pub(crate) fn statistics(&self) -> TimingStatistics {
+        let (timer_decrements_measured, average_timer_interval, timer_jitter) =
+            self.timer.summarise();
+        let (vblank_interrupts_measured, average_vblank_interval, vblank_jitter) =
+            self.vblank.summarise();
+        let (average_cycle_duration, average_target_cycle_duration) = match self.cycles {
+            0 => (Duration::ZERO, Duration::ZERO),
+            cycles => (self.total_actual / cycles, self.total_target / cycles),
+        };
+        TimingStatistics {
+            cycles_measured: self.cycles as usize,
+            average_cycle_duration,
+            average_target_cycle_duration,
+            drift_microseconds: self.total_actual.as_micros() as i64
+                - self.total_target.as_micros() as i64,
+            timer_decrements_measured,
+            average_timer_interval,
+            timer_jitter,
+            vblank_interrupts_measured,
+            average_vblank_interval,
+            vblank_jitter,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_cycles() {
+        let mut recorder: TimingRecorder = TimingRecorder::default();
+        let start: Instant = Instant::default();
+        let target: Duration = Duration::from_micros(100);
+        // The first cycle only starts the measurement
+        recorder.record_cycle(start, Some(target));
+        recorder.record_cycle(start + Duration::from_micros(110), Some(target));
+        recorder.record_cycle(start + Duration::from_micros(230), Some(target));
+        let statistics: TimingStatistics = recorder.statistics();
+        assert!(
+            statistics.cycles_measured == 2
+                && statistics.average_cycle_duration == Duration::from_micros(115)
+                && statistics.average_target_cycle_duration == target
+                && statistics.drift_microseconds == 30
+                && statistics.drift_percent() == 15.
+        );
+    }
+
+    #[test]
+    fn test_record_cycles_unthrottled_and_interrupted() {
+        let mut recorder: TimingRecorder = TimingRecorder::default();
+        let start: Instant = Instant::default();
+        let target: Duration = Duration::from_micros(100);
+        recorder.record_cycle(start, Some(target));
+        recorder.record_cycle(start + Duration::from_micros(10), None);
+        recorder.record_cycle(start + Duration::from_micros(20), Some(target));
+        recorder.interrupt();
+        recorder.record_cycle(start + Duration::from_micros(5000), Some(target));
+        assert_eq!(recorder.statistics().cycles_measured, 0);
+    }
+
+    #[test]
+    fn test_record_timer_decrements() {
+        let mut recorder: TimingRecorder = TimingRecorder::default();
+        let start: Instant = Instant::default();
+        let nominal: Duration = Duration::from_micros(16667);
+        recorder.record_timer_decrement(start, nominal, true);
+        recorder.record_timer_decrement(start + Duration::from_micros(16000), nominal, true);
+        recorder.record_timer_decrement(start + Duration::from_micros(33334), nominal, false);
+        // The timers stopped, so the interval to the next decrement is not measured
+        recorder.record_timer_decrement(start + Duration::from_secs(5), nominal, true);
+        let statistics: TimingStatistics = recorder.statistics();
+        assert!(
+            statistics.timer_decrements_measured == 2
+                && statistics.average_timer_interval == Duration::from_micros(16667)
+                && statistics.timer_jitter == Duration::from_micros(667)
+        );
+    }
+}
@@ -0,0 +1,525 @@
+use crate::prelude::*;
+use crate::ErrorDetail;
+use core::fmt;
+use core::str::FromStr;
+
+/// A parsed watch expression, registered with [crate::Processor::add_watch()] and evaluated
+/// against the processor state after each instruction executes.
+///
+/// Expressions are case-insensitive and may reference the variable registers (`V0` to `VF`), the
+/// index register (`I`), the program counter (`PC`), the delay and sound timers (`DT` and `ST`)
+/// and individual memory bytes (`mem[...]`, where the address is itself an expression).  Numeric
+/// literals may be decimal or hexadecimal (prefixed `0x`).  The supported operators, from
+/// lowest to highest precedence, are `||`, `&&`, the comparisons `==`, `!=`, `<`, `<=`, `>`
+/// and `>=`, the bitwise `|`, `^` and `&`, the arithmetic `+` and `-`, and finally the unary `!`
+/// and `-`; parentheses may be used for grouping.  Arithmetic is not truncated to register
+/// width, and comparison and logical operators yield 1 (true) or 0 (false).
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchExpression {
+    /// The expression text, as originally supplied
+    source: String,
+    /// The root of the parsed expression tree
+    root: Node,
+}
+
+/// The result of evaluating a single registered watch, as included in a [DebugReport]
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchResult {
+    /// The identifier returned when the watch was registered
+    pub id: usize,
+    /// The text of the watch expression
+    pub expression: String,
+    /// Whether execution pauses when the expression evaluates to a non-zero value
+    pub break_on_true: bool,
+    /// The value the expression had after the most recent instruction, or `None` if it has not
+    /// yet been evaluated or could not be evaluated (e.g. it read an unaddressable memory byte)
+    pub value: Option<i64>,
+}
+
+/// A report of the processor's debugging state, as returned by
+/// [crate::Processor::debug_report()]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugReport {
+    /// The number of cycles executed when the report was taken
+    pub cycles: usize,
+    /// The program counter when the report was taken
+    pub program_counter: u16,
+    /// The latest result of each registered watch, in order of registration
+    pub watches: Vec<WatchResult>,
+    /// The identifier of the watch that most recently paused execution, if any
+    pub break_watch_id: Option<usize>,
+}
+
+/// A watch expression registered with the processor, together with its latest result
+#[derive(Debug, Clone)]
+pub(crate) struct RegisteredWatch {
+    pub(crate) id: usize,
+    pub(crate) expression: WatchExpression,
+    pub(crate) break_on_true: bool,
+    pub(crate) value: Option<i64>,
+}
+
+/// The processor state a [WatchExpression] may reference, abstracted so that the evaluator need
+/// not know how the processor holds it
+pub(crate) trait WatchContext {
+    /// Returns the value of the specified variable register (0x0 to 0xF)
+    fn variable_register(&self, register: usize) -> u8;
+    /// Returns the value of the index register
+    fn index_register(&self) -> u16;
+    /// Returns the value of the program counter
+    fn program_counter(&self) -> u16;
+    /// Returns the value of the delay timer
+    fn delay_timer(&self) -> u8;
+    /// Returns the value of the sound timer
+    fn sound_timer(&self) -> u8;
+    /// Returns the byte at the specified memory address, or `None` if it is not addressable
+    fn memory_byte(&self, address: usize) -> Option<u8>;
+}
+
+/// A node of a parsed watch expression tree
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Literal(i64),
+    VariableRegister(usize),
+    IndexRegister,
+    ProgramCounter,
+    DelayTimer,
+    SoundTimer,
+    Memory(Box<Node>),
+    Not(Box<Node>),
+    Negate(Box<Node>),
+    Binary(BinaryOperator, Box<Node>, Box<Node>),
+}
+
+/// The binary operators supported in watch expressions
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BinaryOperator {
+    Or,
+    And,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    BitOr,
+    BitXor,
+    BitAnd,
+    Add,
+    Subtract,
+}
+
+/// A lexical token of a watch expression
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(i64),
+    Identifier(String),
+    Symbol(&'static str),
+    LeftParen,
+    RightParen,
+    LeftBracket,
+    RightBracket,
+}
+
+/// The symbolic operators recognised by the tokeniser; two-character operators are listed first
+/// so that they take priority over their one-character prefixes
+const SYMBOLS: [&str; 15] = [
+    "||", "&&", "==", "!=", "<=", ">=", "<", ">", "|", "^", "&", "+", "-", "!", "=",
+];
+
+impl WatchExpression {
+    /// Parses the passed text as a watch expression.  Returns an
+    /// [ErrorDetail::MalformedWatchExpression] describing the first problem found if the text
+    /// cannot be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - the expression text (e.g. `V3 + V4`, `mem[I]` or `PC == 0x358`)
+    pub fn parse(source: &str) -> Result<Self, ErrorDetail> {
+        let tokens: Vec<(Token, usize)> = tokenise(source)?;
+        let mut parser: Parser = Parser {
+            tokens: &tokens,
+            position: 0,
+            source_length: source.len(),
+        };
+        let root: Node = parser.parse_or()?;
+        if let Some((_, position)) = parser.tokens.get(parser.position) {
+            return Err(malformed(*position, "unexpected trailing input"));
+        }
+        Ok(WatchExpression {
+            source: source.trim().to_string(),
+            root,
+        })
+    }
+
+    /// Evaluates the expression against the passed processor state, returning `None` if it
+    /// references an unaddressable memory byte
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - the processor state to evaluate against
+    pub(crate) fn evaluate(&self, context: &impl WatchContext) -> Option<i64> {
+        self.root.evaluate(context)
+    }
+}
+
+impl FromStr for WatchExpression {
+    type Err = ErrorDetail;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        WatchExpression::parse(source)
+    }
+}
+
+impl fmt::Display for WatchExpression {
+    /// Returns the expression text, as originally supplied
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+impl Node {
+    /// Recursively evaluates this node against the passed processor state
+    fn evaluate(&self, context: &impl WatchContext) -> Option<i64> {
+        Some(match self {
+            Node::Literal(value) => *value,
+            Node::VariableRegister(register) => context.variable_register(*register) as i64,
+            Node::IndexRegister => context.index_register() as i64,
+            Node::ProgramCounter => context.program_counter() as i64,
+            Node::DelayTimer => context.delay_timer() as i64,
+            Node::SoundTimer => context.sound_timer() as i64,
+            Node::Memory(address) => {
+                let address: usize = usize::try_from(address.evaluate(context)?).ok()?;
+                context.memory_byte(address)? as i64
+            }
+            Node::Not(operand) => (operand.evaluate(context)? == 0) as i64,
+            Node::Negate(operand) => operand.evaluate(context)?.wrapping_neg(),
+            Node::Binary(operator, left, right) => {
+                let left: i64 = left.evaluate(context)?;
+                // Logical operators short-circuit, so the right-hand side is not evaluated (and
+                // so cannot fail) unless it determines the result
+                match operator {
+                    BinaryOperator::Or if left != 0 => return Some(1),
+                    BinaryOperator::And if left == 0 => return Some(0),
+                    _ => (),
+                }
+                let right: i64 = right.evaluate(context)?;
+                match operator {
+                    BinaryOperator::Or | BinaryOperator::And => (right != 0) as i64,
+                    BinaryOperator::Equal => (left == right) as i64,
+                    BinaryOperator::NotEqual => (left != right) as i64,
+                    BinaryOperator::Less => (left < right) as i64,
+                    BinaryOperator::LessOrEqual => (left <= right) as i64,
+                    BinaryOperator::Greater => (left > right) as i64,
+                    BinaryOperator::GreaterOrEqual => (left >= right) as i64,
+                    BinaryOperator::BitOr => left | right,
+                    BinaryOperator::BitXor => left ^ right,
+                    BinaryOperator::BitAnd => left & right,
+                    BinaryOperator::Add => left.wrapping_add(right),
+                    BinaryOperator::Subtract => left.wrapping_sub(right),
+                }
+            }
+        })
+    }
+}
+
+/// Helper function that returns an [ErrorDetail::MalformedWatchExpression]
+///
+/// # Arguments
+///
+/// * `position` - the (0-based) character position at which the problem was found
+/// * `detail` - a description of the problem
+fn malformed(position: usize, detail: &str) -> ErrorDetail {
+    ErrorDetail::MalformedWatchExpression {
+        position,
+        detail: detail.to_string(),
+    }
+}
+
+/// Splits the passed expression text into tokens, each paired with its character position
+///
+/// # Arguments
+///
+/// * `source` - the expression text
+fn tokenise(source: &str) -> Result<Vec<(Token, usize)>, ErrorDetail> {
+    let characters: Vec<char> = source.chars().collect();
+    let mut tokens: Vec<(Token, usize)> = Vec::new();
+    let mut position: usize = 0;
+    while position < characters.len() {
+        let start: usize = position;
+        let character: char = characters[position];
+        if character.is_whitespace() {
+            position += 1;
+            continue;
+        }
+        if character.is_ascii_alphanumeric() || character == '_' {
+            while position < characters.len()
+                && (characters[position].is_ascii_alphanumeric() || characters[position] == '_')
+            {
+                position += 1;
+            }
+            let word: String = characters[start..position].iter().collect();
+            let token: Token = if character.is_ascii_digit() {
+                let lower: String = word.to_ascii_lowercase();
+                let parsed = match lower.strip_prefix("0x") {
+                    Some(hex) => i64::from_str_radix(hex, 16),
+                    None => lower.parse::<i64>(),
+                };
+                Token::Number(parsed.map_err(|_| malformed(start, "invalid number"))?)
+            } else {
+                Token::Identifier(word.to_ascii_uppercase())
+            };
+            tokens.push((token, start));
+            continue;
+        }
+        let token: Token = match character {
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            _ => {
+                let remaining: String = characters[position..].iter().take(2).collect();
+                match SYMBOLS
+                    .iter()
+                    .find(|symbol| remaining.starts_with(**symbol))
+                {
+                    Some(&"=") => return Err(malformed(start, "assignment is not supported")),
+                    Some(symbol) => {
+                        position += symbol.len();
+                        tokens.push((Token::Symbol(symbol), start));
+                        continue;
+                    }
+                    None => return Err(malformed(start, "unexpected character")),
+                }
+            }
+        };
+        tokens.push((token, start));
+        position += 1;
+    }
+    Ok(tokens)
+}
+
+/// A recursive-descent parser over a tokenised watch expression, with one method per level of
+/// operator precedence
+struct Parser<'a> {
+    tokens: &'a [(Token, usize)],
+    position: usize,
+    source_length: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Returns the character position of the next token (or the end of the text)
+    fn current_position(&self) -> usize {
+        match self.tokens.get(self.position) {
+            Some((_, position)) => *position,
+            None => self.source_length,
+        }
+    }
+
+    /// Consumes the next token if it is the specified symbol, returning whether it was
+    fn accept_symbol(&mut self, symbol: &str) -> bool {
+        match self.tokens.get(self.position) {
+            Some((Token::Symbol(next), _)) if *next == symbol => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consumes the next token, which must equal the specified token
+    fn expect(&mut self, expected: Token, detail: &str) -> Result<(), ErrorDetail> {
+        match self.tokens.get(self.position) {
+            Some((token, _)) if *token == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(malformed(self.current_position(), detail)),
+        }
+    }
+
+    /// Parses a left-associative chain of the specified operators, using the passed method to
+    /// parse each operand
+    fn parse_chain(
+        &mut self,
+        operators: &[(&str, BinaryOperator)],
+        parse_operand: fn(&mut Self) -> Result<Node, ErrorDetail>,
+    ) -> Result<Node, ErrorDetail> {
+        let mut node: Node = parse_operand(self)?;
+        'chain: loop {
+            for (symbol, operator) in operators {
+                if self.accept_symbol(symbol) {
+                    node = Node::Binary(*operator, Box::new(node), Box::new(parse_operand(self)?));
+                    continue 'chain;
+                }
+            }
+            return Ok(node);
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Node, ErrorDetail> {
+        self.parse_chain(&[("||", BinaryOperator::Or)], Parser::parse_and)
+    }
+
+    fn parse_and(&mut self) -> Result<Node, ErrorDetail> {
+        self.parse_chain(&[("&&", BinaryOperator::And)], Parser::parse_comparison)
+    }
+
+    fn parse_comparison(&mut self) -> Result<Node, ErrorDetail> {
+        self.parse_chain(
+            &[
+                ("==", BinaryOperator::Equal),
+                ("!=", BinaryOperator::NotEqual),
+                ("<=", BinaryOperator::LessOrEqual),
+                (">=", BinaryOperator::GreaterOrEqual),
+                ("<", BinaryOperator::Less),
+                (">", BinaryOperator::Greater),
+            ],
+            Parser::parse_bitwise,
+        )
+    }
+
+    fn parse_bitwise(&mut self) -> Result<Node, ErrorDetail> {
+        self.parse_chain(
+            &[
+                ("|", BinaryOperator::BitOr),
+                ("^", BinaryOperator::BitXor),
+                ("&", BinaryOperator::BitAnd),
+            ],
+            Parser::parse_additive,
+        )
+    }
+
+    fn parse_additive(&mut self) -> Result<Node, ErrorDetail> {
+        self.parse_chain(
+            &[("+", BinaryOperator::Add), ("-", BinaryOperator::Subtract)],
+            Parser::parse_unary,
+        )
+    }
+
+    fn parse_unary(&mut self) -> Result<Node, ErrorDetail> {
+        if self.accept_symbol("!") {
+            return Ok(Node::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.accept_symbol("-") {
+            return Ok(Node::Negate(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Node, ErrorDetail> {
+        let position: usize = self.current_position();
+        let token: Token = match self.tokens.get(self.position) {
+            Some((token, _)) => token.clone(),
+            None => return Err(malformed(position, "unexpected end of expression")),
+        };
+        self.position += 1;
+        match token {
+            Token::Number(value) => Ok(Node::Literal(value)),
+            Token::LeftParen => {
+                let node: Node = self.parse_or()?;
+                self.expect(Token::RightParen, "expected ')'")?;
+                Ok(node)
+            }
+            Token::Identifier(name) => match name.as_str() {
+                "I" => Ok(Node::IndexRegister),
+                "PC" => Ok(Node::ProgramCounter),
+                "DT" => Ok(Node::DelayTimer),
+                "ST" => Ok(Node::SoundTimer),
+                "MEM" => {
+                    self.expect(Token::LeftBracket, "expected '[' after mem")?;
+                    let address: Node = self.parse_or()?;
+                    self.expect(Token::RightBracket, "expected ']'")?;
+                    Ok(Node::Memory(Box::new(address)))
+                }
+                _ => match name.strip_prefix('V') {
+                    Some(index) if index.len() == 1 => match usize::from_str_radix(index, 16) {
+                        Ok(register) => Ok(Node::VariableRegister(register)),
+                        Err(_) => Err(malformed(position, "unknown register")),
+                    },
+                    _ => Err(malformed(position, "unknown identifier")),
+                },
+            },
+            _ => Err(malformed(position, "expected a value")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestContext {
+        memory: [u8; 0x10],
+    }
+
+    impl WatchContext for TestContext {
+        fn variable_register(&self, register: usize) -> u8 {
+            register as u8 * 0x10
+        }
+        fn index_register(&self) -> u16 {
+            0x3
+        }
+        fn program_counter(&self) -> u16 {
+            0x358
+        }
+        fn delay_timer(&self) -> u8 {
+            0x7
+        }
+        fn sound_timer(&self) -> u8 {
+            0x0
+        }
+        fn memory_byte(&self, address: usize) -> Option<u8> {
+            self.memory.get(address).copied()
+        }
+    }
+
+    fn evaluate(source: &str) -> Option<i64> {
+        let mut context: TestContext = TestContext {
+            memory: [0x0; 0x10],
+        };
+        context.memory[0x3] = 0xAB;
+        WatchExpression::parse(source).unwrap().evaluate(&context)
+    }
+
+    #[test]
+    fn test_evaluate_arithmetic() {
+        assert_eq!(evaluate("V3 + V4"), Some(0x70));
+        assert_eq!(evaluate("vf - 0x100"), Some(0xF0 - 0x100));
+    }
+
+    #[test]
+    fn test_evaluate_memory() {
+        assert_eq!(evaluate("mem[I]"), Some(0xAB));
+        assert_eq!(evaluate("MEM[I + 0x100]"), None);
+    }
+
+    #[test]
+    fn test_evaluate_comparison_and_logic() {
+        assert_eq!(evaluate("PC == 0x358"), Some(1));
+        assert_eq!(evaluate("PC == 0x358 && (DT > 7 || !ST)"), Some(1));
+        assert_eq!(evaluate("V1 & 0x0F"), Some(0));
+    }
+
+    #[test]
+    fn test_evaluate_short_circuit() {
+        assert_eq!(evaluate("ST && mem[0x1000]"), Some(0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for (source, expected_position) in [
+            ("V3 +", 4),
+            ("VG", 0),
+            ("mem[I", 5),
+            ("PC = 2", 3),
+            ("1 2", 2),
+        ] {
+            assert!(matches!(
+                WatchExpression::parse(source),
+                Err(ErrorDetail::MalformedWatchExpression { position, .. })
+                    if position == expected_position
+            ));
+        }
+    }
+}
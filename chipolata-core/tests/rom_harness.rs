@@ -0,0 +1,160 @@
+// Integration tests running the public test ROMs shipped in resources/roms/tests headlessly for a
+// fixed cycle budget, and comparing a hash of the final frame buffer against stored golden
+// values.  Each ROM is a separate test so that the ROMs run in parallel.
+//
+// If an intentional change in emulation behaviour alters a ROM's output, confirm the new output
+// is correct (the assertion message includes a text rendering of the display) before updating
+// its golden hash.
+
+use chipolata::*;
+use std::path::PathBuf;
+
+/// The address at which Timendus' CHIP-8 test suite looks for the number of a test to run
+/// automatically, bypassing its menu
+const TIMENDUS_TEST_SELECTOR_ADDRESS: u16 = 0x1FF;
+
+/// Returns a [Program] holding the named ROM from the test ROMs folder
+fn load_test_rom(file_name: &str) -> Program {
+    let file_path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        "resources",
+        "roms",
+        "tests",
+        file_name,
+    ]
+    .iter()
+    .collect();
+    Program::load_from_file(&file_path).unwrap()
+}
+
+/// Returns a [Program] that runs the specified test from Timendus' CHIP-8 test suite directly.
+/// The suite ROM is preceded by a jump to its usual start address, followed by the test number
+/// positioned so that it is loaded into the test selector address.
+fn timendus_test_program(test_number: u8) -> Program {
+    let mut data: Vec<u8> = vec![0x12, 0x00, 0x00, test_number]; // 1200 (jump to 0x200)
+    data.extend(load_test_rom("chip8-test-suite.ch8").program_data());
+    Program::new(data)
+}
+
+/// Runs the program headlessly at the specified emulation level, and asserts that the final
+/// frame buffer hash matches the expected golden value
+fn assert_program_output(
+    name: &str,
+    program: Program,
+    options: Options,
+    cycle_budget: usize,
+    expected_hash: u64,
+) {
+    let report: HeadlessRunReport = run_rom_to_completion(program, options, cycle_budget).unwrap();
+    assert_eq!(
+        report.frame_buffer_hash,
+        expected_hash,
+        "{} produced unexpected output (hash {:#018X}):\n{}",
+        name,
+        report.frame_buffer_hash,
+        testing::display_to_text(&report.frame_buffer)
+    );
+}
+
+/// Runs the named ROM from the test ROMs folder at the specified emulation level, and asserts
+/// that the final frame buffer hash matches the expected golden value
+fn assert_rom_output(
+    file_name: &str,
+    emulation_level: EmulationLevel,
+    cycle_budget: usize,
+    expected_hash: u64,
+) {
+    let mut options: Options = Options::default();
+    options.emulation_level = emulation_level;
+    assert_program_output(
+        file_name,
+        load_test_rom(file_name),
+        options,
+        cycle_budget,
+        expected_hash,
+    );
+}
+
+fn chip8() -> EmulationLevel {
+    EmulationLevel::Chip8 {
+        memory_limit_2k: false,
+        variable_cycle_timing: false,
+    }
+}
+
+fn superchip11() -> EmulationLevel {
+    EmulationLevel::SuperChip11 {
+        octo_compatibility_mode: false,
+        variable_cycle_timing: false,
+    }
+}
+
+#[test]
+fn test_ibm_logo() {
+    assert_rom_output("IBM Logo.ch8", chip8(), 1000, 0xE2B469D06EA428D1);
+}
+
+#[test]
+fn test_bc_test() {
+    // BC_test expects modern (SUPER-CHIP) instruction behaviour, displaying "BON" on success
+    assert_rom_output("BonCoder.ch8", superchip11(), 5000, 0x1A59A10941817845);
+}
+
+#[test]
+fn test_timendus_corax89_opcode_test() {
+    let mut options: Options = Options::default();
+    options.emulation_level = chip8();
+    options.program_start_address = TIMENDUS_TEST_SELECTOR_ADDRESS - 3;
+    assert_program_output(
+        "Timendus corax89 opcode test",
+        timendus_test_program(2),
+        options,
+        100000,
+        0xE47AF12705CE571D,
+    );
+}
+
+#[test]
+fn test_timendus_flags_test() {
+    let mut options: Options = Options::default();
+    options.emulation_level = chip8();
+    options.program_start_address = TIMENDUS_TEST_SELECTOR_ADDRESS - 3;
+    assert_program_output(
+        "Timendus flags test",
+        timendus_test_program(3),
+        options,
+        100000,
+        0xB71B0EE181FC56FD,
+    );
+}
+
+#[test]
+fn test_division() {
+    assert_rom_output(
+        "Division Test [Sergey Naydenov, 2010].ch8",
+        chip8(),
+        20000,
+        0x4DC0945129D2CE8F,
+    );
+}
+
+#[test]
+fn test_sqrt() {
+    assert_rom_output(
+        "SQRT Test [Sergey Naydenov, 2010].ch8",
+        chip8(),
+        20000,
+        0xFC7C86437435C926,
+    );
+}
+
+#[test]
+fn test_delay_timer() {
+    assert_rom_output(
+        "Delay Timer Test [Matthew Mikolay, 2010].ch8",
+        chip8(),
+        2000,
+        0x9A0DD7FE3A82D613,
+    );
+}
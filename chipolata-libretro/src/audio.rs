@@ -0,0 +1,69 @@
+/// The rate at which audio samples are generated
+pub(crate) const SAMPLE_RATE_HERTZ: f64 = 44100.;
+
+/// The pitch of the tone played while the sound timer is active (that of the desktop app's
+/// default beep)
+const TONE_FREQUENCY_HERTZ: f64 = 440.;
+
+/// The amplitude of the tone, as a fraction of full scale
+const TONE_VOLUME: f64 = 0.25;
+
+/// Generates the sine wave tone played while the sound timer is active, continuing its phase
+/// from one frame to the next so that the tone has no discontinuities
+#[derive(Debug, Default)]
+pub(crate) struct Tone {
+    phase: f64,
+    carry: f64,
+    samples: Vec<i16>,
+}
+
+impl Tone {
+    /// Returns the interleaved stereo samples for a frame of audio, of the tone if playing and
+    /// silence otherwise.  The fractional samples left over at the frame rate are carried into
+    /// later frames, so that exactly [SAMPLE_RATE_HERTZ] samples are produced each second.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames_per_second` - the frame rate of the emulated system
+    /// * `playing` - whether the sound timer is active
+    pub(crate) fn frame(&mut self, frames_per_second: f64, playing: bool) -> &[i16] {
+        self.carry += SAMPLE_RATE_HERTZ / frames_per_second;
+        let sample_count: usize = self.carry as usize;
+        self.carry -= sample_count as f64;
+        self.samples.clear();
+        for _ in 0..sample_count {
+            let sample: i16 = match playing {
+                true => {
+                    let value: f64 = (2. * std::f64::consts::PI * self.phase).sin();
+                    self.phase = (self.phase + TONE_FREQUENCY_HERTZ / SAMPLE_RATE_HERTZ).fract();
+                    (value * TONE_VOLUME * i16::MAX as f64) as i16
+                }
+                false => 0,
+            };
+            self.samples.push(sample);
+            self.samples.push(sample);
+        }
+        &self.samples
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_sample_count() {
+        let mut tone: Tone = Tone::default();
+        let mut total: usize = 0;
+        for _ in 0..60 {
+            total += tone.frame(60., true).len() / 2;
+        }
+        assert_eq!(total, SAMPLE_RATE_HERTZ as usize);
+    }
+
+    #[test]
+    fn test_frame_silence() {
+        let mut tone: Tone = Tone::default();
+        assert!(tone.frame(60., false).iter().all(|s| *s == 0));
+    }
+}
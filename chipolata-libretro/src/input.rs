@@ -0,0 +1,95 @@
+use crate::libretro::*;
+use chipolata::Key;
+use std::os::raw::c_uint;
+
+/// The RetroPad buttons mapped to CHIP-8 keys.  The D-pad drives the 2/4/6/8 keys that most
+/// programs use for movement, A presses 5 (the usual action key) and the remaining buttons
+/// cover the other keys most commonly used by games.
+pub(crate) const JOYPAD_MAPPING: [(c_uint, Key); 12] = [
+    (DEVICE_ID_JOYPAD_UP, Key::Key2),
+    (DEVICE_ID_JOYPAD_DOWN, Key::Key8),
+    (DEVICE_ID_JOYPAD_LEFT, Key::Key4),
+    (DEVICE_ID_JOYPAD_RIGHT, Key::Key6),
+    (DEVICE_ID_JOYPAD_A, Key::Key5),
+    (DEVICE_ID_JOYPAD_B, Key::Key0),
+    (DEVICE_ID_JOYPAD_X, Key::KeyE),
+    (DEVICE_ID_JOYPAD_Y, Key::KeyF),
+    (DEVICE_ID_JOYPAD_L, Key::Key1),
+    (DEVICE_ID_JOYPAD_R, Key::Key3),
+    (DEVICE_ID_JOYPAD_SELECT, Key::KeyA),
+    (DEVICE_ID_JOYPAD_START, Key::KeyB),
+];
+
+/// The keyboard keys mapped to CHIP-8 keys, using the conventional layout in which the left
+/// of a QWERTY keyboard (1234/QWER/ASDF/ZXCV) stands in for the COSMAC VIP hex keypad
+/// (123C/456D/789E/A0BF).  Libretro key codes for these keys are their lower case ASCII codes.
+pub(crate) const KEYBOARD_MAPPING: [(u8, Key); 16] = [
+    (b'1', Key::Key1),
+    (b'2', Key::Key2),
+    (b'3', Key::Key3),
+    (b'4', Key::KeyC),
+    (b'q', Key::Key4),
+    (b'w', Key::Key5),
+    (b'e', Key::Key6),
+    (b'r', Key::KeyD),
+    (b'a', Key::Key7),
+    (b's', Key::Key8),
+    (b'd', Key::Key9),
+    (b'f', Key::KeyE),
+    (b'z', Key::KeyA),
+    (b'x', Key::Key0),
+    (b'c', Key::KeyB),
+    (b'v', Key::KeyF),
+];
+
+/// Returns the state of each CHIP-8 key (indexed by key ordinal) as reported by the passed
+/// frontend input callback, a key being pressed if any RetroPad button or keyboard key mapped
+/// to it is held
+///
+/// # Arguments
+///
+/// * `input_state` - the frontend's input state callback
+pub(crate) fn poll_keys(input_state: InputStateFn) -> [bool; 16] {
+    let mut keys: [bool; 16] = [false; 16];
+    for (id, key) in JOYPAD_MAPPING {
+        if input_state(0, DEVICE_JOYPAD, 0, id) != 0 {
+            keys[u8::from(key) as usize] = true;
+        }
+    }
+    for (code, key) in KEYBOARD_MAPPING {
+        if input_state(0, DEVICE_KEYBOARD, 0, code as c_uint) != 0 {
+            keys[u8::from(key) as usize] = true;
+        }
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "C" fn press_up_and_x(_: c_uint, device: c_uint, _: c_uint, id: c_uint) -> i16 {
+        match (device, id) {
+            (DEVICE_JOYPAD, DEVICE_ID_JOYPAD_UP) => 1,
+            (DEVICE_KEYBOARD, 0x78) => 1,
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_poll_keys() {
+        let keys: [bool; 16] = poll_keys(press_up_and_x);
+        let pressed: Vec<usize> = (0..16).filter(|key| keys[*key]).collect();
+        assert_eq!(pressed, vec![0x0, 0x2]);
+    }
+
+    #[test]
+    fn test_keyboard_mapping_covers_keypad() {
+        for key in Key::ALL {
+            assert_eq!(
+                KEYBOARD_MAPPING.iter().filter(|(_, k)| *k == key).count(),
+                1
+            );
+        }
+    }
+}
@@ -0,0 +1,561 @@
+mod audio; // Sub-module generating the tone played while the sound timer is active
+mod input; // Sub-module mapping RetroPad buttons and keyboard keys to CHIP-8 keys
+mod libretro; // Sub-module holding the libretro API types and constants
+mod video; // Sub-module converting the frame buffer to the frontend's pixel format
+
+use audio::{Tone, SAMPLE_RATE_HERTZ};
+use chipolata::{
+    EmulationLevel, Key, Options, Processor, ProcessorStatus, Program, SaveState,
+    PROGRAM_FILE_EXTENSIONS,
+};
+use libretro::*;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_uint, c_void};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+use video::{MAX_HEIGHT_PIXELS, MAX_WIDTH_PIXELS};
+
+/// The name and version of the core, as shown by the frontend
+const LIBRARY_NAME: &str = "Chipolata\0";
+const LIBRARY_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// The core option through which the user selects the emulation level, and its values (the
+/// first being the default)
+const VARIABLE_EMULATION_LEVEL: &str = "chipolata_emulation_level\0";
+const VARIABLE_EMULATION_LEVEL_VALUES: &str =
+    "Emulation level; Auto|CHIP-8|CHIP-48|SUPER-CHIP 1.0|SUPER-CHIP 1.1\0";
+
+/// The emulation levels selectable through the core option, each with the option value naming it
+const EMULATION_LEVELS: [(&str, EmulationLevel); 4] = [
+    (
+        "CHIP-8",
+        EmulationLevel::Chip8 {
+            memory_limit_2k: false,
+            variable_cycle_timing: false,
+        },
+    ),
+    (
+        "CHIP-48",
+        EmulationLevel::Chip48 {
+            variable_cycle_timing: false,
+        },
+    ),
+    (
+        "SUPER-CHIP 1.0",
+        EmulationLevel::SuperChip10 {
+            variable_cycle_timing: false,
+        },
+    ),
+    (
+        "SUPER-CHIP 1.1",
+        EmulationLevel::SuperChip11 {
+            octo_compatibility_mode: false,
+            variable_cycle_timing: false,
+        },
+    ),
+];
+
+/// The number of bytes preceding a serialised state, holding the length of the state's JSON
+const SERIALIZE_HEADER_BYTES: usize = 8;
+
+/// The callbacks registered by the frontend
+struct Callbacks {
+    environment: Option<EnvironmentFn>,
+    video_refresh: Option<VideoRefreshFn>,
+    audio_sample_batch: Option<AudioSampleBatchFn>,
+    input_poll: Option<InputPollFn>,
+    input_state: Option<InputStateFn>,
+}
+
+/// The state of the loaded game
+struct Game {
+    program_data: Vec<u8>,
+    emulation_level: EmulationLevel,
+    processor: Processor,
+    /// The greatest length of the JSON of any state the program can save at any selectable
+    /// emulation level, which frontends require to stay fixed while the game is loaded
+    max_state_len: usize,
+    frames_per_second: f64,
+    keys: [bool; 16],
+    pixels: Vec<u32>,
+    tone: Tone,
+}
+
+static CALLBACKS: Mutex<Callbacks> = Mutex::new(Callbacks {
+    environment: None,
+    video_refresh: None,
+    audio_sample_batch: None,
+    input_poll: None,
+    input_state: None,
+});
+static GAME: Mutex<Option<Game>> = Mutex::new(None);
+static VALID_EXTENSIONS: OnceLock<CString> = OnceLock::new();
+
+/// Helper function that locks the passed mutex, recovering the data from a mutex poisoned by a
+/// panic while it was held rather than panicking in turn
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Helper function that returns the emulation level selected through the core options, if the
+/// frontend reports one, or otherwise infers it from the program (SUPER-CHIP 1.1 if the program
+/// appears to use SUPER-CHIP instructions, otherwise the default emulation level)
+///
+/// # Arguments
+///
+/// * `environment` - the frontend's environment callback
+/// * `program` - the program to be run
+fn selected_emulation_level(
+    environment: Option<EnvironmentFn>,
+    program: &Program,
+) -> EmulationLevel {
+    let mut variable: Variable = Variable {
+        key: VARIABLE_EMULATION_LEVEL.as_ptr() as *const c_char,
+        value: std::ptr::null(),
+    };
+    let value: &str = match environment {
+        Some(environment)
+            if environment(
+                ENVIRONMENT_GET_VARIABLE,
+                &mut variable as *mut Variable as *mut c_void,
+            ) && !variable.value.is_null() =>
+        {
+            // Safety: the frontend returns a nul-terminated string that outlives this call
+            unsafe { CStr::from_ptr(variable.value) }
+                .to_str()
+                .unwrap_or_default()
+        }
+        _ => "",
+    };
+    match EMULATION_LEVELS.iter().find(|(name, _)| *name == value) {
+        Some((_, emulation_level)) => *emulation_level,
+        None => match program.analyse().uses_superchip_instructions() {
+            true => EmulationLevel::SuperChip11 {
+                octo_compatibility_mode: false,
+                variable_cycle_timing: false,
+            },
+            false => Options::default().emulation_level,
+        },
+    }
+}
+
+/// Helper function that returns the greatest length of the JSON of any state that can be saved
+/// while running the passed program, at any emulation level the core option can select (so that
+/// the size reported for serialised states is unchanged when the level is changed mid-game)
+///
+/// # Arguments
+///
+/// * `program_data` - the bytes of the program ROM
+fn max_state_len(program_data: &[u8]) -> usize {
+    EMULATION_LEVELS
+        .iter()
+        .map(|(_, emulation_level)| *emulation_level)
+        .chain([Options::default().emulation_level])
+        .filter_map(|emulation_level| {
+            let options: Options = Options {
+                emulation_level,
+                ..Options::default()
+            };
+            Processor::initialise_and_load(Program::new(program_data.to_vec()), options).ok()
+        })
+        .map(|processor| processor.save_state().max_json_len())
+        .max()
+        .unwrap_or_default()
+}
+
+/// Helper function that creates the game state, initialising a processor at the passed
+/// emulation level and loading the passed program into it
+///
+/// # Arguments
+///
+/// * `program_data` - the bytes of the program ROM
+/// * `emulation_level` - the emulation level at which to run the program
+fn start_game(program_data: Vec<u8>, emulation_level: EmulationLevel) -> Option<Game> {
+    let options: Options = Options {
+        emulation_level,
+        ..Options::default()
+    };
+    let processor: Processor =
+        Processor::initialise_and_load(Program::new(program_data.clone()), options).ok()?;
+    Some(Game {
+        max_state_len: max_state_len(&program_data),
+        program_data,
+        emulation_level,
+        processor,
+        frames_per_second: options.timer_frequency_hertz as f64,
+        keys: [false; 16],
+        pixels: Vec::with_capacity(MAX_WIDTH_PIXELS * MAX_HEIGHT_PIXELS),
+        tone: Tone::default(),
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn retro_api_version() -> c_uint {
+    API_VERSION
+}
+
+#[no_mangle]
+pub extern "C" fn retro_set_environment(environment: EnvironmentFn) {
+    lock(&CALLBACKS).environment = Some(environment);
+    let mut variables: [Variable; 2] = [
+        Variable {
+            key: VARIABLE_EMULATION_LEVEL.as_ptr() as *const c_char,
+            value: VARIABLE_EMULATION_LEVEL_VALUES.as_ptr() as *const c_char,
+        },
+        Variable {
+            key: std::ptr::null(),
+            value: std::ptr::null(),
+        },
+    ];
+    environment(
+        ENVIRONMENT_SET_VARIABLES,
+        variables.as_mut_ptr() as *mut c_void,
+    );
+}
+
+#[no_mangle]
+pub extern "C" fn retro_set_video_refresh(video_refresh: VideoRefreshFn) {
+    lock(&CALLBACKS).video_refresh = Some(video_refresh);
+}
+
+#[no_mangle]
+pub extern "C" fn retro_set_audio_sample(_: AudioSampleFn) {
+    // audio is always delivered a frame at a time through the batch callback
+}
+
+#[no_mangle]
+pub extern "C" fn retro_set_audio_sample_batch(audio_sample_batch: AudioSampleBatchFn) {
+    lock(&CALLBACKS).audio_sample_batch = Some(audio_sample_batch);
+}
+
+#[no_mangle]
+pub extern "C" fn retro_set_input_poll(input_poll: InputPollFn) {
+    lock(&CALLBACKS).input_poll = Some(input_poll);
+}
+
+#[no_mangle]
+pub extern "C" fn retro_set_input_state(input_state: InputStateFn) {
+    lock(&CALLBACKS).input_state = Some(input_state);
+}
+
+#[no_mangle]
+pub extern "C" fn retro_init() {}
+
+#[no_mangle]
+pub extern "C" fn retro_deinit() {
+    *lock(&GAME) = None;
+}
+
+/// # Safety
+///
+/// `info` must point to a writable [SystemInfo]
+#[no_mangle]
+pub unsafe extern "C" fn retro_get_system_info(info: *mut SystemInfo) {
+    let valid_extensions: &CString = VALID_EXTENSIONS
+        .get_or_init(|| CString::new(PROGRAM_FILE_EXTENSIONS.join("|")).unwrap_or_default());
+    *info = SystemInfo {
+        library_name: LIBRARY_NAME.as_ptr() as *const c_char,
+        library_version: LIBRARY_VERSION.as_ptr() as *const c_char,
+        valid_extensions: valid_extensions.as_ptr(),
+        need_fullpath: false,
+        block_extract: false,
+    };
+}
+
+/// # Safety
+///
+/// `info` must point to a writable [SystemAvInfo]
+#[no_mangle]
+pub unsafe extern "C" fn retro_get_system_av_info(info: *mut SystemAvInfo) {
+    *info = system_av_info(lock(&GAME).as_ref());
+}
+
+/// Helper function that returns the audio/video information of the passed game (or of the
+/// default emulation level if no game is loaded)
+///
+/// # Arguments
+///
+/// * `game` - the loaded game, if any
+fn system_av_info(game: Option<&Game>) -> SystemAvInfo {
+    let ((width, height), fps): ((usize, usize), f64) = match game {
+        Some(game) => (
+            game.processor.state_view().frame_buffer.get_resolution(),
+            game.frames_per_second,
+        ),
+        None => ((64, 32), Options::default().timer_frequency_hertz as f64),
+    };
+    SystemAvInfo {
+        geometry: GameGeometry {
+            base_width: width as c_uint,
+            base_height: height as c_uint,
+            max_width: MAX_WIDTH_PIXELS as c_uint,
+            max_height: MAX_HEIGHT_PIXELS as c_uint,
+            aspect_ratio: 2.,
+        },
+        timing: SystemTiming {
+            fps,
+            sample_rate: SAMPLE_RATE_HERTZ,
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn retro_set_controller_port_device(_: c_uint, _: c_uint) {}
+
+#[no_mangle]
+pub extern "C" fn retro_reset() {
+    if let Some(game) = lock(&GAME).as_mut() {
+        if let Err(_) = game.processor.reset() {
+            // absorb the error; the processor reports itself as crashed and is not run further
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn retro_run() {
+    let (environment, video_refresh, audio_sample_batch, input_poll, input_state) = {
+        let callbacks = lock(&CALLBACKS);
+        (
+            callbacks.environment,
+            callbacks.video_refresh,
+            callbacks.audio_sample_batch,
+            callbacks.input_poll,
+            callbacks.input_state,
+        )
+    };
+    let mut game = lock(&GAME);
+    // A change of emulation level through the core options restarts the program, informing the
+    // frontend of the new level's resolution
+    if let Some(environment) = environment {
+        let mut updated: bool = false;
+        environment(
+            ENVIRONMENT_GET_VARIABLE_UPDATE,
+            &mut updated as *mut bool as *mut c_void,
+        );
+        if let (true, Some(current)) = (updated, game.as_ref()) {
+            let program: Program = Program::new(current.program_data.clone());
+            let emulation_level: EmulationLevel =
+                selected_emulation_level(Some(environment), &program);
+            if emulation_level != current.emulation_level {
+                if let Some(restarted) = start_game(current.program_data.clone(), emulation_level) {
+                    let mut av_info: SystemAvInfo = system_av_info(Some(&restarted));
+                    environment(
+                        ENVIRONMENT_SET_SYSTEM_AV_INFO,
+                        &mut av_info as *mut SystemAvInfo as *mut c_void,
+                    );
+                    *game = Some(restarted);
+                }
+            }
+        }
+    }
+    let game: &mut Game = match game.as_mut() {
+        Some(game) => game,
+        None => return,
+    };
+    if let (Some(input_poll), Some(input_state)) = (input_poll, input_state) {
+        input_poll();
+        let keys: [bool; 16] = input::poll_keys(input_state);
+        for (key, pressed) in Key::ALL.into_iter().zip(keys) {
+            if pressed != game.keys[u8::from(key) as usize] {
+                game.processor.set_key_status(key, pressed);
+            }
+        }
+        game.keys = keys;
+    }
+    match game.processor.state_view().status {
+        ProcessorStatus::Crashed | ProcessorStatus::Completed | ProcessorStatus::Halted => (),
+        _ => {
+            if let Err(_) = game.processor.run_frames(1) {
+                // absorb the error; the processor reports itself as crashed and is not run further
+            }
+        }
+    }
+    let view = game.processor.state_view();
+    let (width, height): (usize, usize) =
+        video::to_xrgb8888(view.frame_buffer, &view.palette, &mut game.pixels);
+    if let Some(video_refresh) = video_refresh {
+        video_refresh(
+            game.pixels.as_ptr() as *const c_void,
+            width as c_uint,
+            height as c_uint,
+            width * std::mem::size_of::<u32>(),
+        );
+    }
+    let samples: &[i16] = game.tone.frame(game.frames_per_second, view.play_sound);
+    if let Some(audio_sample_batch) = audio_sample_batch {
+        audio_sample_batch(samples.as_ptr(), samples.len() / 2);
+    }
+}
+
+/// Helper function that returns the loaded game's current state serialised as JSON
+fn serialised_state(game: &Game) -> Vec<u8> {
+    serde_json::to_vec(&game.processor.save_state()).unwrap_or_default()
+}
+
+#[no_mangle]
+pub extern "C" fn retro_serialize_size() -> usize {
+    match lock(&GAME).as_ref() {
+        Some(game) => SERIALIZE_HEADER_BYTES + game.max_state_len,
+        None => 0,
+    }
+}
+
+/// # Safety
+///
+/// `data` must point to `size` writable bytes
+#[no_mangle]
+pub unsafe extern "C" fn retro_serialize(data: *mut c_void, size: usize) -> bool {
+    let game = lock(&GAME);
+    let state: Vec<u8> = match game.as_ref() {
+        Some(game) => serialised_state(game),
+        None => return false,
+    };
+    if data.is_null() || SERIALIZE_HEADER_BYTES + state.len() > size {
+        return false;
+    }
+    let buffer: &mut [u8] = std::slice::from_raw_parts_mut(data as *mut u8, size);
+    buffer.fill(0);
+    buffer[..SERIALIZE_HEADER_BYTES].copy_from_slice(&(state.len() as u64).to_le_bytes());
+    buffer[SERIALIZE_HEADER_BYTES..SERIALIZE_HEADER_BYTES + state.len()].copy_from_slice(&state);
+    true
+}
+
+/// # Safety
+///
+/// `data` must point to `size` readable bytes
+#[no_mangle]
+pub unsafe extern "C" fn retro_unserialize(data: *const c_void, size: usize) -> bool {
+    if data.is_null() || size < SERIALIZE_HEADER_BYTES {
+        return false;
+    }
+    let buffer: &[u8] = std::slice::from_raw_parts(data as *const u8, size);
+    let mut length: [u8; SERIALIZE_HEADER_BYTES] = [0; SERIALIZE_HEADER_BYTES];
+    length.copy_from_slice(&buffer[..SERIALIZE_HEADER_BYTES]);
+    let state: &[u8] =
+        match buffer[SERIALIZE_HEADER_BYTES..].get(..u64::from_le_bytes(length) as usize) {
+            Some(state) => state,
+            None => return false,
+        };
+    let state: SaveState = match serde_json::from_slice(state) {
+        Ok(state) => state,
+        Err(_) => return false,
+    };
+    match lock(&GAME).as_mut() {
+        Some(game) => game.processor.restore_state(&state).is_ok(),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn retro_cheat_reset() {}
+
+#[no_mangle]
+pub extern "C" fn retro_cheat_set(_: c_uint, _: bool, _: *const c_char) {}
+
+/// # Safety
+///
+/// `game` must be null or point to a [GameInfo] whose data is `size` readable bytes
+#[no_mangle]
+pub unsafe extern "C" fn retro_load_game(game: *const GameInfo) -> bool {
+    if game.is_null() || (*game).data.is_null() {
+        return false;
+    }
+    let program_data: Vec<u8> =
+        std::slice::from_raw_parts((*game).data as *const u8, (*game).size).to_vec();
+    let environment: Option<EnvironmentFn> = lock(&CALLBACKS).environment;
+    let mut pixel_format: c_uint = PIXEL_FORMAT_XRGB8888;
+    match environment {
+        Some(environment)
+            if environment(
+                ENVIRONMENT_SET_PIXEL_FORMAT,
+                &mut pixel_format as *mut c_uint as *mut c_void,
+            ) => {}
+        _ => return false,
+    }
+    let emulation_level: EmulationLevel =
+        selected_emulation_level(environment, &Program::new(program_data.clone()));
+    let started: Option<Game> = start_game(program_data, emulation_level);
+    let loaded: bool = started.is_some();
+    *lock(&GAME) = started;
+    loaded
+}
+
+#[no_mangle]
+pub extern "C" fn retro_load_game_special(_: c_uint, _: *const GameInfo, _: usize) -> bool {
+    false
+}
+
+#[no_mangle]
+pub extern "C" fn retro_unload_game() {
+    *lock(&GAME) = None;
+}
+
+#[no_mangle]
+pub extern "C" fn retro_get_region() -> c_uint {
+    REGION_NTSC
+}
+
+#[no_mangle]
+pub extern "C" fn retro_get_memory_data(_: c_uint) -> *mut c_void {
+    // the emulated memory is only exposed through the processor's checked accessors
+    std::ptr::null_mut()
+}
+
+#[no_mangle]
+pub extern "C" fn retro_get_memory_size(_: c_uint) -> usize {
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern "C" fn accept_environment(_: c_uint, _: *mut c_void) -> bool {
+        true
+    }
+
+    #[test]
+    fn test_load_run_serialize() {
+        retro_set_environment(accept_environment);
+        // 7001: V0 += 1; 1200: jump back
+        let program_data: [u8; 4] = [0x70, 0x01, 0x12, 0x00];
+        let game: GameInfo = GameInfo {
+            path: std::ptr::null(),
+            data: program_data.as_ptr() as *const c_void,
+            size: program_data.len(),
+            meta: std::ptr::null(),
+        };
+        unsafe {
+            assert!(retro_load_game(&game));
+        }
+        retro_run();
+        let size: usize = retro_serialize_size();
+        let mut state: Vec<u8> = vec![0; size];
+        unsafe {
+            assert!(retro_serialize(state.as_mut_ptr() as *mut c_void, size));
+        }
+        retro_run();
+        assert_eq!(retro_serialize_size(), size);
+        let cycles: usize = lock(&GAME).as_ref().unwrap().processor.state_view().cycles;
+        unsafe {
+            assert!(retro_unserialize(state.as_ptr() as *const c_void, size));
+        }
+        let restored: usize = lock(&GAME).as_ref().unwrap().processor.state_view().cycles;
+        assert!(restored > 0 && restored < cycles);
+        retro_unload_game();
+        assert_eq!(retro_serialize_size(), 0);
+    }
+
+    #[test]
+    fn test_max_state_len_covers_every_level() {
+        // 00FF: high-resolution mode; 1202: jump to self
+        let program_data: Vec<u8> = vec![0x00, 0xFF, 0x12, 0x02];
+        let max_len: usize = max_state_len(&program_data);
+        for (_, emulation_level) in EMULATION_LEVELS {
+            let game: Game = start_game(program_data.clone(), emulation_level).unwrap();
+            assert!(
+                game.max_state_len == max_len
+                    && game.processor.save_state().max_json_len() <= max_len
+            );
+        }
+    }
+}
@@ -0,0 +1,91 @@
+// The subset of the libretro API (libretro.h) used by the Chipolata core.  Names follow the C
+// header with the `retro_`/`RETRO_` prefixes dropped.
+use std::os::raw::{c_char, c_uint, c_void};
+
+/// The version of the libretro API implemented
+pub(crate) const API_VERSION: c_uint = 1;
+
+/// Input device types
+pub(crate) const DEVICE_JOYPAD: c_uint = 1;
+pub(crate) const DEVICE_KEYBOARD: c_uint = 3;
+
+/// RetroPad button identifiers
+pub(crate) const DEVICE_ID_JOYPAD_B: c_uint = 0;
+pub(crate) const DEVICE_ID_JOYPAD_Y: c_uint = 1;
+pub(crate) const DEVICE_ID_JOYPAD_SELECT: c_uint = 2;
+pub(crate) const DEVICE_ID_JOYPAD_START: c_uint = 3;
+pub(crate) const DEVICE_ID_JOYPAD_UP: c_uint = 4;
+pub(crate) const DEVICE_ID_JOYPAD_DOWN: c_uint = 5;
+pub(crate) const DEVICE_ID_JOYPAD_LEFT: c_uint = 6;
+pub(crate) const DEVICE_ID_JOYPAD_RIGHT: c_uint = 7;
+pub(crate) const DEVICE_ID_JOYPAD_A: c_uint = 8;
+pub(crate) const DEVICE_ID_JOYPAD_X: c_uint = 9;
+pub(crate) const DEVICE_ID_JOYPAD_L: c_uint = 10;
+pub(crate) const DEVICE_ID_JOYPAD_R: c_uint = 11;
+
+/// Region reported for the loaded game
+pub(crate) const REGION_NTSC: c_uint = 0;
+
+/// Environment commands
+pub(crate) const ENVIRONMENT_SET_PIXEL_FORMAT: c_uint = 10;
+pub(crate) const ENVIRONMENT_GET_VARIABLE: c_uint = 15;
+pub(crate) const ENVIRONMENT_SET_VARIABLES: c_uint = 16;
+pub(crate) const ENVIRONMENT_GET_VARIABLE_UPDATE: c_uint = 17;
+pub(crate) const ENVIRONMENT_SET_SYSTEM_AV_INFO: c_uint = 32;
+
+/// Pixel format of 32-bit pixels laid out as 0x00RRGGBB
+pub(crate) const PIXEL_FORMAT_XRGB8888: c_uint = 1;
+
+/// Frontend callbacks registered through the `retro_set_*` functions
+pub(crate) type EnvironmentFn = extern "C" fn(cmd: c_uint, data: *mut c_void) -> bool;
+pub(crate) type VideoRefreshFn =
+    extern "C" fn(data: *const c_void, width: c_uint, height: c_uint, pitch: usize);
+pub(crate) type AudioSampleFn = extern "C" fn(left: i16, right: i16);
+pub(crate) type AudioSampleBatchFn = extern "C" fn(data: *const i16, frames: usize) -> usize;
+pub(crate) type InputPollFn = extern "C" fn();
+pub(crate) type InputStateFn =
+    extern "C" fn(port: c_uint, device: c_uint, index: c_uint, id: c_uint) -> i16;
+
+#[repr(C)]
+pub struct SystemInfo {
+    pub library_name: *const c_char,
+    pub library_version: *const c_char,
+    pub valid_extensions: *const c_char,
+    pub need_fullpath: bool,
+    pub block_extract: bool,
+}
+
+#[repr(C)]
+pub struct GameGeometry {
+    pub base_width: c_uint,
+    pub base_height: c_uint,
+    pub max_width: c_uint,
+    pub max_height: c_uint,
+    pub aspect_ratio: f32,
+}
+
+#[repr(C)]
+pub struct SystemTiming {
+    pub fps: f64,
+    pub sample_rate: f64,
+}
+
+#[repr(C)]
+pub struct SystemAvInfo {
+    pub geometry: GameGeometry,
+    pub timing: SystemTiming,
+}
+
+#[repr(C)]
+pub struct GameInfo {
+    pub path: *const c_char,
+    pub data: *const c_void,
+    pub size: usize,
+    pub meta: *const c_char,
+}
+
+#[repr(C)]
+pub struct Variable {
+    pub key: *const c_char,
+    pub value: *const c_char,
+}
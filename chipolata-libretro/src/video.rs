@@ -0,0 +1,82 @@
+use chipolata::{Display, Palette};
+
+/// The largest display resolution of any emulation level (that of SUPER-CHIP high-resolution
+/// mode), as reported to the frontend as the maximum geometry
+pub(crate) const MAX_WIDTH_PIXELS: usize = 128;
+pub(crate) const MAX_HEIGHT_PIXELS: usize = 64;
+
+/// Converts the passed frame buffer to XRGB8888 pixels (0x00RRGGBB, row by row from the
+/// top-left) in the passed palette, writing them into the passed buffer (which is resized as
+/// necessary so that it can be reused between frames).  Returns the resolution of the frame as
+/// (width, height).
+///
+/// # Arguments
+///
+/// * `frame_buffer` - the frame buffer to convert
+/// * `palette` - the colours in which to render each pixel colour index
+/// * `pixels` - the buffer to receive the converted pixels
+pub(crate) fn to_xrgb8888(
+    frame_buffer: &Display,
+    palette: &Palette,
+    pixels: &mut Vec<u32>,
+) -> (usize, usize) {
+    let (width, height): (usize, usize) = frame_buffer.get_resolution();
+    pixels.clear();
+    pixels.extend(
+        frame_buffer
+            .get_colour_indices()
+            .into_iter()
+            .map(|colour_index| {
+                let [red, green, blue]: [u8; 3] = palette.colour(colour_index);
+                (red as u32) << 16 | (green as u32) << 8 | blue as u32
+            }),
+    );
+    (width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chipolata::{
+        EmulationLevel, Options, Processor, Program, StateSnapshot, StateSnapshotVerbosity,
+    };
+
+    #[test]
+    fn test_to_xrgb8888() {
+        // 00E0: clear screen; A208: I = 0x208; D001: draw 1-byte sprite at (V0, V0); 1206: loop;
+        // 0x80: sprite data (single top-left pixel)
+        let program: Program =
+            Program::new(vec![0x00, 0xE0, 0xA2, 0x08, 0xD0, 0x01, 0x12, 0x06, 0x80]);
+        let options: Options = Options {
+            emulation_level: EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            ..Options::default()
+        };
+        let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+        processor.run_frames(1).unwrap();
+        let (frame_buffer, palette) =
+            match processor.export_state_snapshot(StateSnapshotVerbosity::Extended) {
+                StateSnapshot::ExtendedSnapshot {
+                    frame_buffer,
+                    palette,
+                    ..
+                } => (frame_buffer, palette),
+                _ => panic!("expected an extended snapshot"),
+            };
+        let mut pixels: Vec<u32> = Vec::new();
+        assert_eq!(to_xrgb8888(&frame_buffer, &palette, &mut pixels), (64, 32));
+        assert_eq!(pixels.len(), 64 * 32);
+        let [red, green, blue]: [u8; 3] = palette.foreground();
+        assert_eq!(
+            pixels[0],
+            (red as u32) << 16 | (green as u32) << 8 | blue as u32
+        );
+        let [red, green, blue]: [u8; 3] = palette.background();
+        assert_eq!(
+            pixels[1],
+            (red as u32) << 16 | (green as u32) << 8 | blue as u32
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! A sample adapter between Chipolata's [FrameSink] and the
+//! [embedded-graphics](https://crates.io/crates/embedded-graphics) [DrawTarget] trait, which is
+//! implemented by most embedded display drivers (for example the `ssd1306` crate for SSD1306
+//! OLED modules and the `mipidsi` crate for ST7789 TFT panels).  Registering a [DrawTargetSink]
+//! with [Processor::set_frame_sink()](chipolata::Processor::set_frame_sink) has each frame pushed
+//! straight to the screen, redrawing only the region that changed.  Like the core built without
+//! its `std` feature, the adapter needs no standard library.
+#![no_std]
+
+use chipolata::{Display, FrameDamage, FrameSink};
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+
+/// A [FrameSink] drawing the frames pushed to it onto an embedded-graphics [DrawTarget], scaled
+/// up by the largest whole factor at which they fit and centred.  Lit pixels are drawn in one
+/// colour and unlit pixels in another, whatever the colour index.  Should drawing fail, the next
+/// frame is drawn whole.
+pub struct DrawTargetSink<D: DrawTarget, F> {
+    target: D,     // The display driver (or frame buffer) drawn to
+    on: D::Color,  // The colour of lit pixels
+    off: D::Color, // The colour of unlit pixels
+    flush: F,      // Called after each frame is drawn, to send it to the screen
+    redraw: bool,  // True if drawing the last frame failed, so the next must be drawn whole
+}
+
+impl<D, F> DrawTargetSink<D, F>
+where
+    D: DrawTarget,
+    F: FnMut(&mut D),
+{
+    /// Constructor that returns a [DrawTargetSink] drawing onto the passed target
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - the display driver to draw to
+    /// * `on` - the colour of lit pixels (for example `BinaryColor::On`)
+    /// * `off` - the colour of unlit pixels (for example `BinaryColor::Off`)
+    /// * `flush` - called after each frame is drawn, for drivers that buffer drawing until
+    ///   flushed (such as the SSD1306 driver's buffered graphics mode); otherwise `|_| {}`
+    pub fn new(target: D, on: D::Color, off: D::Color, flush: F) -> Self {
+        DrawTargetSink {
+            target,
+            on,
+            off,
+            flush,
+            redraw: false,
+        }
+    }
+
+    /// Returns a reference to the draw target
+    pub fn target(&self) -> &D {
+        &self.target
+    }
+
+    /// Internal helper function that returns the top-left point on the target at which a frame
+    /// of the specified resolution is drawn, and the factor by which it is scaled up
+    ///
+    /// # Arguments
+    ///
+    /// * `resolution` - the resolution of the frame as (width, height)
+    fn placement(&self, (width, height): (usize, usize)) -> (Point, u32) {
+        let bounds: Rectangle = self.target.bounding_box();
+        let (width, height): (u32, u32) = (width as u32, height as u32);
+        let scale: u32 = (bounds.size.width / width)
+            .min(bounds.size.height / height)
+            .max(1);
+        let margin: Point = Point::new(
+            (bounds.size.width.saturating_sub(width * scale) / 2) as i32,
+            (bounds.size.height.saturating_sub(height * scale) / 2) as i32,
+        );
+        (bounds.top_left + margin, scale)
+    }
+}
+
+impl<D, F> FrameSink for DrawTargetSink<D, F>
+where
+    D: DrawTarget,
+    F: FnMut(&mut D),
+{
+    fn present_frame(&mut self, frame_buffer: &Display, damage: FrameDamage) {
+        let (width, height): (usize, usize) = frame_buffer.get_resolution();
+        let damage: FrameDamage = match self.redraw {
+            true => FrameDamage {
+                x: 0,
+                y: 0,
+                width,
+                height,
+                full_frame: true,
+            },
+            false => damage,
+        };
+        let (origin, scale): (Point, u32) = self.placement((width, height));
+        let area: Rectangle = Rectangle::new(
+            origin
+                + Point::new(
+                    (damage.x as u32 * scale) as i32,
+                    (damage.y as u32 * scale) as i32,
+                ),
+            Size::new(damage.width as u32 * scale, damage.height as u32 * scale),
+        );
+        let (on, off): (D::Color, D::Color) = (self.on, self.off);
+        // The colours of the damaged region's target pixels, row by row from the top-left
+        let colours = (0..area.size.height).flat_map(move |row| {
+            (0..area.size.width).map(move |column| {
+                let x: usize = damage.x + (column / scale) as usize;
+                let y: usize = damage.y + (row / scale) as usize;
+                match frame_buffer.get_pixel(x, y) {
+                    true => on,
+                    false => off,
+                }
+            })
+        });
+        // Any margin around a frame drawn whole (for example after the resolution has changed) is
+        // cleared first
+        let cleared: bool = !damage.full_frame || self.target.clear(off).is_ok();
+        self.redraw = !(cleared && self.target.fill_contiguous(&area, colours).is_ok());
+        if !self.redraw {
+            (self.flush)(&mut self.target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chipolata::{
+        EmulationLevel, Options, Processor, Program, StateSnapshot, StateSnapshotVerbosity,
+    };
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    extern crate std;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::vec;
+
+    #[test]
+    fn test_frames_drawn_centred() {
+        // 00E0: clear screen; A208: I = 0x208; D001: draw 1-byte sprite at (V0, V0); 1206: loop;
+        // 0x80: sprite data (single top-left pixel)
+        let program: Program =
+            Program::new(vec![0x00, 0xE0, 0xA2, 0x08, 0xD0, 0x01, 0x12, 0x06, 0x80]);
+        let options: Options = Options {
+            emulation_level: EmulationLevel::Chip8 {
+                memory_limit_2k: false,
+                variable_cycle_timing: false,
+            },
+            ..Options::default()
+        };
+        let mut processor: Processor = Processor::initialise_and_load(program, options).unwrap();
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let flushes: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+        let counter: Arc<AtomicUsize> = Arc::clone(&flushes);
+        let mut sink = DrawTargetSink::new(display, BinaryColor::On, BinaryColor::Off, move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        processor.run_frames(3).unwrap();
+        let frame_buffer: Display =
+            match processor.export_state_snapshot(StateSnapshotVerbosity::Extended) {
+                StateSnapshot::ExtendedSnapshot { frame_buffer, .. } => frame_buffer,
+                _ => panic!("expected an extended snapshot"),
+            };
+        // The 64x32 CHIP-8 display fits the 64x64 mock display unscaled, 16 rows down
+        sink.present_frame(
+            &frame_buffer,
+            FrameDamage {
+                x: 0,
+                y: 0,
+                width: 64,
+                height: 32,
+                full_frame: true,
+            },
+        );
+        assert!(
+            sink.target().get_pixel(Point::new(0, 16)) == Some(BinaryColor::On)
+                && sink.target().get_pixel(Point::new(1, 16)) == Some(BinaryColor::Off)
+                && sink.target().get_pixel(Point::new(0, 15)) == Some(BinaryColor::Off)
+                && flushes.load(Ordering::Relaxed) == 1
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Runs the built-in IBM Logo ROM for a second of emulated time, pushing each frame to a
+//! simulated 128x64 monochrome panel (the size of a typical SSD1306 OLED module) through a
+//! [DrawTargetSink], then prints the panel's contents.  On real hardware the simulated panel is
+//! replaced by the display driver, and the flush closure sends the driver's buffer to the screen.
+use chipolata::builtin_roms::{self, BuiltinRom};
+use chipolata::{Options, Processor};
+use chipolata_embedded_graphics::DrawTargetSink;
+use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::prelude::*;
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+const PANEL_WIDTH: usize = 128;
+const PANEL_HEIGHT: usize = 64;
+
+/// A simulated monochrome panel, whose pixels are shared so they can be read back once the
+/// processor owns the sink drawing to it
+#[derive(Clone, Default)]
+struct Panel {
+    pixels: Arc<Mutex<Vec<bool>>>,
+}
+
+impl OriginDimensions for Panel {
+    fn size(&self) -> Size {
+        Size::new(PANEL_WIDTH as u32, PANEL_HEIGHT as u32)
+    }
+}
+
+impl DrawTarget for Panel {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let mut panel = self.pixels.lock().unwrap();
+        panel.resize(PANEL_WIDTH * PANEL_HEIGHT, false);
+        for Pixel(point, colour) in pixels {
+            if (0..PANEL_WIDTH as i32).contains(&point.x)
+                && (0..PANEL_HEIGHT as i32).contains(&point.y)
+            {
+                panel[point.y as usize * PANEL_WIDTH + point.x as usize] = colour.is_on();
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() {
+    let rom: &BuiltinRom = builtin_roms::list()
+        .iter()
+        .find(|rom| rom.name == "IBM Logo")
+        .expect("the IBM Logo ROM is built in");
+    let options: Options = Options {
+        emulation_level: rom.emulation_level,
+        ..Options::default()
+    };
+    let mut processor: Processor =
+        Processor::initialise_and_load(rom.program(), options).expect("the ROM loads");
+    let panel: Panel = Panel::default();
+    let flushes: Arc<AtomicUsize> = Arc::new(AtomicUsize::new(0));
+    let counter: Arc<AtomicUsize> = Arc::clone(&flushes);
+    processor.set_frame_sink(DrawTargetSink::new(
+        panel.clone(),
+        BinaryColor::On,
+        BinaryColor::Off,
+        move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        },
+    ));
+    processor.run_frames(60).expect("the ROM runs");
+    println!(
+        "{} frames pushed to the panel",
+        flushes.load(Ordering::Relaxed)
+    );
+    for row in panel.pixels.lock().unwrap().chunks(PANEL_WIDTH) {
+        let line: String = row
+            .iter()
+            .map(|lit| match lit {
+                true => '#',
+                false => '.',
+            })
+            .collect();
+        println!("{}", line);
+    }
+}
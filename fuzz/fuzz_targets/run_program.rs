@@ -0,0 +1,14 @@
+#![no_main]
+
+use chipolata::Processor;
+use libfuzzer_sys::fuzz_target;
+
+/// The maximum number of cycles each input is run for; enough for most inputs to crash or settle
+/// into a loop, while keeping the fuzzer fast
+const FUZZ_CYCLE_LIMIT: usize = 10_000;
+
+// Run each input as a program (its first byte selecting the emulation level and quirks); any
+// panic is reported by the fuzzer as a crash
+fuzz_target!(|bytes: &[u8]| {
+    Processor::run_program_fuzz(bytes, FUZZ_CYCLE_LIMIT);
+});
@@ -1,20 +0,0 @@
-mod display;
-mod error;
-mod font;
-mod instruction;
-mod keystate;
-mod memory;
-mod options;
-mod processor;
-mod program;
-mod stack;
-
-// Re-exports
-pub use crate::display::Display;
-pub use crate::error::*;
-pub use crate::memory::Memory;
-pub use crate::options::Options;
-pub use crate::options::COSMAC_VIP_PROCESSOR_SPEED_HERTZ;
-pub use crate::processor::*;
-pub use crate::program::Program;
-pub use crate::stack::Stack;